@@ -1,10 +1,16 @@
-use arrow::array::{Array as _, ListArray as ArrowListArray, RecordBatch as ArrowRecordBatch};
+use std::collections::BTreeMap;
+
+use arrow::array::{
+    Array as _, BooleanArray as ArrowBooleanArray, ListArray as ArrowListArray,
+    RecordBatch as ArrowRecordBatch,
+};
 use itertools::Itertools as _;
 use nohash_hasher::IntMap;
 
 use re_arrow_util::{into_arrow_ref, ArrowArrayDowncastRef as _};
 use re_byte_size::SizeBytes as _;
-use re_types_core::{arrow_helpers::as_array_ref, ComponentDescriptor};
+use re_log_types::EntityPath;
+use re_types_core::{arrow_helpers::as_array_ref, ChunkId, ComponentDescriptor};
 
 use crate::{chunk::ChunkComponents, Chunk, ChunkError, ChunkResult, TimeColumn};
 
@@ -136,12 +142,42 @@ impl Chunk {
     }
 
     pub fn from_record_batch(batch: &ArrowRecordBatch) -> ChunkResult<Self> {
+        Self::from_record_batch_recorded_by(batch, None)
+    }
+
+    /// Same as [`Self::from_record_batch`], but only applies the migrations that data written by
+    /// the given SDK version needs, if known.
+    ///
+    /// See [`re_sorbet::MIGRATIONS`].
+    pub fn from_record_batch_recorded_by(
+        batch: &ArrowRecordBatch,
+        recorded_version: Option<re_build_info::CrateVersion>,
+    ) -> ChunkResult<Self> {
+        re_tracing::profile_function!(format!(
+            "num_columns={} num_rows={}",
+            batch.num_columns(),
+            batch.num_rows()
+        ));
+        Self::from_chunk_batch(&re_sorbet::ChunkBatch::try_from_record_batch_recorded_by(
+            batch,
+            recorded_version,
+        )?)
+    }
+
+    /// Same as [`Self::from_record_batch`], but doesn't apply any migrations: the chunk is read as
+    /// it was written.
+    pub fn from_record_batch_unmigrated(batch: &ArrowRecordBatch) -> ChunkResult<Self> {
         re_tracing::profile_function!(format!(
             "num_columns={} num_rows={}",
             batch.num_columns(),
             batch.num_rows()
         ));
-        Self::from_chunk_batch(&re_sorbet::ChunkBatch::try_from(batch)?)
+        Self::from_chunk_batch(&re_sorbet::ChunkBatch::try_from(
+            re_sorbet::SorbetBatch::try_from_record_batch_unmigrated(
+                batch,
+                re_sorbet::BatchType::Chunk,
+            )?,
+        )?)
     }
 
     pub fn from_chunk_batch(batch: &re_sorbet::ChunkBatch) -> ChunkResult<Self> {
@@ -237,6 +273,134 @@ impl Chunk {
     }
 }
 
+impl Chunk {
+    /// Splits a dataframe batch (e.g. the output of a dataframe query) back into [`Chunk`]s.
+    ///
+    /// One temporal chunk is created per entity, containing only the rows where at least one of
+    /// that entity's components is non-null. Index columns that have nulls in any of those rows
+    /// are dropped for that entity, since a [`TimeColumn`] cannot contain nulls.
+    ///
+    /// Static component columns end up in a separate static chunk per entity, using the first
+    /// non-null value of each column.
+    ///
+    /// Row IDs are always freshly generated: the resulting chunks are new data, even if they were
+    /// derived from existing data.
+    pub fn from_dataframe_batch(batch: &re_sorbet::SorbetBatch) -> ChunkResult<Vec<Self>> {
+        re_tracing::profile_function!(format!(
+            "num_columns={} num_rows={}",
+            batch.num_columns(),
+            batch.num_rows()
+        ));
+
+        let mut temporal: BTreeMap<EntityPath, Vec<_>> = BTreeMap::default();
+        let mut statics: BTreeMap<EntityPath, Vec<_>> = BTreeMap::default();
+        for (schema, column) in batch.component_columns() {
+            let column = column
+                .downcast_array_ref::<ArrowListArray>()
+                .ok_or_else(|| ChunkError::Malformed {
+                    reason: format!(
+                        "The outer array of column '{}' must be a list, got {:?}",
+                        schema.column_name(re_sorbet::BatchType::Dataframe),
+                        column.data_type(),
+                    ),
+                })?;
+
+            let component_desc = ComponentDescriptor {
+                archetype_name: schema.archetype_name,
+                archetype_field_name: schema.archetype_field_name,
+                component_name: schema.component_name,
+            };
+
+            let columns = if schema.is_static {
+                &mut statics
+            } else {
+                &mut temporal
+            };
+            columns
+                .entry(schema.entity_path.clone())
+                .or_default()
+                .push((component_desc, column.clone()));
+        }
+
+        let mut chunks = Vec::with_capacity(temporal.len() + statics.len());
+
+        for (entity_path, columns) in temporal {
+            let mask = ArrowBooleanArray::from(
+                (0..batch.num_rows())
+                    .map(|row| columns.iter().any(|(_, column)| column.is_valid(row)))
+                    .collect_vec(),
+            );
+            if mask.true_count() == 0 {
+                continue;
+            }
+            let rows = (0..mask.len()).filter(|&row| mask.value(row)).collect_vec();
+
+            let mut timelines = IntMap::default();
+            for (schema, column) in batch.index_columns() {
+                let (times, nulls) =
+                    TimeColumn::read_nullable_array(column.as_ref()).map_err(|err| {
+                        ChunkError::Malformed {
+                            reason: format!("Bad time column '{}': {err}", schema.column_name()),
+                        }
+                    })?;
+                if nulls.is_some_and(|nulls| rows.iter().any(|&row| nulls.is_null(row))) {
+                    continue;
+                }
+
+                let times = rows.iter().map(|&row| times[row]).collect();
+                timelines.insert(
+                    schema.timeline_name(),
+                    TimeColumn::new(None, schema.timeline(), times),
+                );
+            }
+
+            if timelines.is_empty() {
+                return Err(ChunkError::Malformed {
+                    reason: format!(
+                        "no index column is fully populated for the rows of entity {entity_path}"
+                    ),
+                });
+            }
+
+            let components = columns
+                .into_iter()
+                .map(|(component_desc, column)| {
+                    (component_desc, re_arrow_util::filter_array(&column, &mask))
+                })
+                .collect();
+
+            chunks.push(Self::from_auto_row_ids(
+                ChunkId::new(),
+                entity_path,
+                timelines,
+                components,
+            )?);
+        }
+
+        for (entity_path, columns) in statics {
+            let mut components = ChunkComponents::default();
+            for (component_desc, column) in columns {
+                if let Some(row) = (0..column.len()).find(|&row| column.is_valid(row)) {
+                    components.insert(component_desc, column.slice(row, 1));
+                }
+            }
+
+            if components.is_empty() {
+                continue;
+            }
+
+            chunks.push(Self::from_auto_row_ids(
+                ChunkId::new(),
+                entity_path,
+                IntMap::default(),
+                components,
+            )?);
+        }
+
+        Ok(chunks)
+    }
+}
+
 impl Chunk {
     #[inline]
     pub fn from_arrow_msg(msg: &re_log_types::ArrowMsg) -> ChunkResult<Self> {
@@ -376,4 +540,100 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn from_dataframe_batch() -> anyhow::Result<()> {
+        let frame = Timeline::new_sequence("frame");
+        let log_time = Timeline::new_duration("log_time");
+
+        let column =
+            |entity_path: &str, component_desc: ComponentDescriptor, datatype, is_static| {
+                re_sorbet::ComponentColumnDescriptor {
+                    store_datatype: arrow::datatypes::DataType::new_list(datatype, true),
+                    entity_path: EntityPath::parse_forgiving(entity_path),
+                    archetype_name: component_desc.archetype_name,
+                    archetype_field_name: component_desc.archetype_field_name,
+                    component_name: component_desc.component_name,
+                    is_static,
+                    is_indicator: false,
+                    is_tombstone: false,
+                    is_semantically_empty: false,
+                }
+            };
+
+        let points = MyPoint::to_arrow([MyPoint::new(1.0, 2.0)])?;
+        let colors = MyColor::to_arrow([MyColor::from_rgb(1, 2, 3)])?;
+
+        let schema = re_sorbet::SorbetSchema {
+            columns: re_sorbet::SorbetColumnDescriptors {
+                row_id: None,
+                indices: vec![
+                    re_sorbet::IndexColumnDescriptor::from_timeline(frame, true),
+                    re_sorbet::IndexColumnDescriptor::from_timeline(log_time, false),
+                ],
+                components: vec![
+                    column("a", MyPoint::descriptor(), MyPoint::arrow_datatype(), false),
+                    column("b", MyColor::descriptor(), MyColor::arrow_datatype(), false),
+                    column("a", MyColor::descriptor(), MyColor::arrow_datatype(), true),
+                ],
+            },
+            chunk_id: None,
+            entity_path: None,
+            heap_size_bytes: None,
+        };
+
+        let list = |rows: &[Option<&dyn arrow::array::Array>]| {
+            into_arrow_ref(re_arrow_util::arrays_to_list_array_opt(rows).unwrap())
+        };
+
+        let batch = re_sorbet::SorbetBatch::try_new(
+            re_sorbet::BatchType::Dataframe,
+            schema,
+            None,
+            vec![
+                into_arrow_ref(arrow::array::Int64Array::from(vec![1, 2, 3])),
+                into_arrow_ref(arrow::array::DurationNanosecondArray::from(vec![
+                    Some(10),
+                    None,
+                    Some(30),
+                ])),
+            ],
+            vec![
+                list(&[Some(&*points), None, Some(&*points)]),
+                list(&[None, Some(&*colors), Some(&*colors)]),
+                list(&[Some(&*colors), Some(&*colors), Some(&*colors)]),
+            ],
+        )?;
+
+        let chunks = Chunk::from_dataframe_batch(&batch)?;
+        assert_eq!(3, chunks.len());
+
+        let (a, b, a_static) = (&chunks[0], &chunks[1], &chunks[2]);
+
+        assert_eq!(&EntityPath::parse_forgiving("a"), a.entity_path());
+        assert_eq!(2, a.num_rows());
+        assert_eq!(
+            vec![*frame.name(), *log_time.name()],
+            a.timelines().keys().copied().sorted().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            &[1, 3],
+            a.timelines()[frame.name()].times_raw(),
+            "only the rows where `a` has data",
+        );
+
+        assert_eq!(&EntityPath::parse_forgiving("b"), b.entity_path());
+        assert_eq!(2, b.num_rows());
+        assert_eq!(
+            vec![*frame.name()],
+            b.timelines().keys().copied().collect::<Vec<_>>(),
+            "`log_time` has a null in the rows of `b`",
+        );
+
+        assert_eq!(&EntityPath::parse_forgiving("a"), a_static.entity_path());
+        assert!(a_static.is_static());
+        assert_eq!(1, a_static.num_rows());
+
+        Ok(())
+    }
 }
@@ -36,8 +36,41 @@ pub enum SparseFillStrategy {
     /// The latest-at semantics are applied on the entire dataset as opposed to just the current
     /// view contents: it is possible to end up with values from outside the view!
     LatestAtGlobal,
-    //
-    // TODO(cmc): `LatestAtView`?
+
+    /// Fill null values using view-scope latest-at semantics.
+    ///
+    /// Same as [`Self::LatestAtGlobal`], except that values that were logged before the start of
+    /// [`QueryExpression::filtered_index_range`] are never used: nulls stay nulls until the first
+    /// value within the view shows up.
+    LatestAtView,
+
+    /// Fill null values by linearly interpolating between the closest values on either side, on
+    /// the filtered index.
+    ///
+    /// Only applies to floating-point components, including fixed-size lists and structs thereof
+    /// (e.g. `Scalar`, `Position3D`), and only when both values have the same number of instances.
+    /// Integers are left alone, as they usually aren't continuous quantities (e.g. colors, class IDs).
+    /// In every other case (non-numeric data, no value on one side, etc), this falls back to
+    /// [`Self::LatestAtGlobal`].
+    ///
+    /// Values logged after the end of [`QueryExpression::filtered_index_range`] are never used for
+    /// interpolation: the cells that follow the last value within the view are filled using
+    /// [`Self::LatestAtGlobal`] semantics.
+    ///
+    /// The index values reported for interpolated cells are the ones of the preceding value, except
+    /// for the filtered index.
+    Linear,
+
+    /// Fill null values using the closest value on the filtered index, in either direction.
+    ///
+    /// Ties are resolved in favor of the preceding value.
+    Nearest {
+        /// Values further away than this, in units of the filtered index (e.g. nanoseconds for
+        /// temporal timelines), are ignored.
+        ///
+        /// `None` means no limit.
+        max_gap: Option<u64>,
+    },
 }
 
 impl std::fmt::Display for SparseFillStrategy {
@@ -45,10 +78,181 @@ impl std::fmt::Display for SparseFillStrategy {
         match self {
             Self::None => f.write_str("none"),
             Self::LatestAtGlobal => f.write_str("latest-at (global)"),
+            Self::LatestAtView => f.write_str("latest-at (view)"),
+            Self::Linear => f.write_str("linear"),
+            Self::Nearest { max_gap: None } => f.write_str("nearest"),
+            Self::Nearest {
+                max_gap: Some(max_gap),
+            } => write!(f, "nearest (max gap: {max_gap})"),
+        }
+    }
+}
+
+/// Specifies which value should be returned for each index column, when the cells that make up a
+/// row of the returned dataframe come from different times on that index.
+///
+/// This never affects the filtered index (see [`QueryExpression::filtered_index`]), whose value is
+/// the same for all cells by definition.
+/// It does however affect all other indices, which can disagree with one another, e.g. two cells
+/// were found at `log_time=100`, but one of them has `frame=3` and the other `frame=5`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexValuePolicy {
+    /// Return the maximum value of each index among the cells of the row.
+    #[default]
+    Max,
+
+    /// Return the minimum value of each index among the cells of the row.
+    Min,
+
+    /// Same as [`Self::Max`], plus one extra index column per selected component column and per
+    /// selected index, holding the exact index value of that specific cell.
+    ///
+    /// The extra columns are appended at the end of the selection, and named
+    /// `<component column>@<index>`, e.g. `/world/points:Position3D@frame_nr`.
+    /// They are data columns, not index columns: they are told apart by their
+    /// `rerun.exact_index_of` (the component column) and `rerun.exact_index_name` (the index)
+    /// metadata.
+    ///
+    /// This is useful to figure out how stale each cell is, e.g. when using
+    /// [`SparseFillStrategy::LatestAtGlobal`].
+    /// Beware: this can add a _lot_ of columns.
+    ExactPerComponent,
+}
+
+impl std::fmt::Display for IndexValuePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Max => f.write_str("max"),
+            Self::Min => f.write_str("min"),
+            Self::ExactPerComponent => f.write_str("exact (per component)"),
+        }
+    }
+}
+
+/// Specifies how all the cells of a column that fall within a same bucket are aggregated into one.
+///
+/// See [`Bucketing`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    /// The element-wise minimum of all the instances in the bucket.
+    ///
+    /// Only applies to numeric data, and fixed-size lists thereof. Yields `null` otherwise.
+    Min,
+
+    /// The element-wise maximum of all the instances in the bucket.
+    ///
+    /// Only applies to numeric data, and fixed-size lists thereof. Yields `null` otherwise.
+    Max,
+
+    /// The element-wise mean of all the instances in the bucket.
+    ///
+    /// Only applies to numeric data, and fixed-size lists thereof. Yields `null` otherwise.
+    /// The mean of integers is rounded to the nearest integer.
+    Mean,
+
+    /// The first cell in the bucket, as-is.
+    First,
+
+    /// The last cell in the bucket, as-is.
+    #[default]
+    Last,
+
+    /// The number of non-null instances in the bucket, across all of its cells.
+    ///
+    /// This always yields a `UInt64` column, whatever the datatype of the original column.
+    Count,
+}
+
+impl std::fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Min => f.write_str("min"),
+            Self::Max => f.write_str("max"),
+            Self::Mean => f.write_str("mean"),
+            Self::First => f.write_str("first"),
+            Self::Last => f.write_str("last"),
+            Self::Count => f.write_str("count"),
         }
     }
 }
 
+/// Specifies how to downsample the results of a query, by bucketing them on the filtered index.
+///
+/// See [`QueryExpression::bucketing`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bucketing {
+    /// The width of each bucket, in units of the filtered index (e.g. nanoseconds for temporal
+    /// timelines).
+    ///
+    /// Buckets are aligned on multiples of this width, i.e. `[k * width, (k + 1) * width)`.
+    pub width: std::num::NonZeroU64,
+
+    /// The aggregation to use for specific component columns.
+    ///
+    /// Component columns that aren't listed here use the default aggregation, i.e.
+    /// [`Aggregation::Last`].
+    pub aggregations: Vec<(ComponentColumnSelector, Aggregation)>,
+}
+
+impl Bucketing {
+    #[inline]
+    pub fn new(width: std::num::NonZeroU64) -> Self {
+        Self {
+            width,
+            aggregations: Vec::new(),
+        }
+    }
+
+    /// Use the given `aggregation` for all component columns matching `selector`.
+    #[inline]
+    pub fn with_aggregation(
+        mut self,
+        selector: ComponentColumnSelector,
+        aggregation: Aggregation,
+    ) -> Self {
+        self.aggregations.push((selector, aggregation));
+        self
+    }
+
+    /// Returns the aggregation to use for the given component column.
+    ///
+    /// If several selectors match, the last one wins.
+    pub fn aggregation_for(&self, descr: &ComponentColumnDescriptor) -> Aggregation {
+        self.aggregations
+            .iter()
+            .rev()
+            .find(|(selector, _)| {
+                selector.entity_path == descr.entity_path
+                    && descr.component_name.matches(&selector.component_name)
+            })
+            .map(|(_, aggregation)| *aggregation)
+            .unwrap_or_default()
+    }
+
+    /// Returns the start of the bucket that contains the given index value.
+    #[inline]
+    pub fn bucket_start(&self, index_value: TimeInt) -> TimeInt {
+        let width = i64::try_from(self.width.get()).unwrap_or(i64::MAX);
+        index_value
+            .as_i64()
+            .div_euclid(width)
+            .checked_mul(width)
+            // The first bucket would start before the earliest possible index value.
+            .map_or(TimeInt::MIN, TimeInt::new_temporal)
+            .max(TimeInt::MIN)
+    }
+
+    /// Returns the range of index values covered by the bucket starting at `bucket_start`.
+    #[inline]
+    pub fn bucket_range(&self, bucket_start: TimeInt) -> ResolvedTimeRange {
+        let width = i64::try_from(self.width.get()).unwrap_or(i64::MAX);
+        ResolvedTimeRange::new(
+            bucket_start,
+            TimeInt::new_temporal(bucket_start.as_i64().saturating_add(width - 1)),
+        )
+    }
+}
+
 /// The view contents specify which subset of the database (i.e., which columns) the query runs on,
 /// expressed as a set of [`EntityPath`]s and their associated [`ComponentName`]s.
 ///
@@ -227,6 +431,25 @@ pub struct QueryExpression {
     /// Defaults to [`SparseFillStrategy::None`].
     pub sparse_fill_strategy: SparseFillStrategy,
 
+    /// Specifies which value to return for each index, when the cells of a row disagree.
+    ///
+    /// Defaults to [`IndexValuePolicy::Max`].
+    pub index_value_policy: IndexValuePolicy,
+
+    /// If specified, the results are downsampled: one row is returned per bucket on the filtered
+    /// index, with the cells of each component column aggregated as configured.
+    ///
+    /// A bucket is returned if at least one of the rows that would otherwise have been returned
+    /// falls within it. Aggregations are computed over all the data in the view that falls within
+    /// the bucket (and [`QueryExpression::filtered_index_range`], if any).
+    ///
+    /// In the returned rows, the filtered index holds the start of each bucket, while all other
+    /// indices are `null`.
+    /// Sparse-filling does not apply: a column without any data within a bucket yields `null`.
+    ///
+    /// Defaults to `None`.
+    pub bucketing: Option<Bucketing>,
+
     /// The specific _columns_ to sample from the final view contents.
     ///
     /// The order of the samples will be respected in the final result.
@@ -490,6 +713,8 @@ impl ChunkStore {
             using_index_values: _,
             filtered_is_not_null: _,
             sparse_fill_strategy: _,
+            index_value_policy: _,
+            bucketing: _,
             selection: _,
         } = query;
 
@@ -522,3 +747,33 @@ impl ChunkStore {
         self.schema().filter_components(filter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_start() {
+        let bucketing = Bucketing::new(std::num::NonZeroU64::new(10).unwrap());
+
+        assert_eq!(
+            bucketing.bucket_start(TimeInt::new_temporal(25)).as_i64(),
+            20
+        );
+        assert_eq!(
+            bucketing.bucket_start(TimeInt::new_temporal(-25)).as_i64(),
+            -30
+        );
+
+        // The bucket containing the earliest index value starts before it: clamp instead of overflowing.
+        assert_eq!(
+            bucketing.bucket_start(TimeInt::new_temporal(i64::MIN + 1)),
+            TimeInt::MIN
+        );
+        assert_eq!(bucketing.bucket_start(TimeInt::MIN), TimeInt::MIN);
+        assert_eq!(
+            bucketing.bucket_start(TimeInt::MAX).as_i64(),
+            i64::MAX / 10 * 10
+        );
+    }
+}
@@ -80,6 +80,132 @@ impl GarbageCollectionOptions {
     }
 }
 
+/// Declarative retention rules for a [`ChunkStore`], see [`crate::ChunkStoreConfig::retention`].
+///
+/// These are enforced on every call to [`ChunkStore::gc`], regardless of the
+/// [`GarbageCollectionTarget`]: chunks that fall outside of the retention window are always dropped,
+/// and chunks belonging to protected entities are never dropped.
+///
+/// Like the rest of the garbage collector, retention works at the chunk level: a chunk is only
+/// dropped once _all_ of its rows fall outside of the retention window, so slightly more data than
+/// requested may be kept around.
+/// Static data is never affected by retention.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Only keep the data that was logged within the given window, relative to the most recent
+    /// time on that timeline.
+    pub time_window: Option<RetentionTimeWindow>,
+
+    /// Only keep (approximately) this many rows for each entity, dropping the oldest ones first,
+    /// in [`RowId`] order.
+    pub max_rows_per_entity: Option<u64>,
+
+    /// Never garbage collect these entities, nor any of their descendants.
+    ///
+    /// This applies to all garbage collections, including the ones driven by memory pressure.
+    pub protected_entities: Vec<EntityPath>,
+}
+
+impl RetentionPolicy {
+    /// No retention rules whatsoever: only the [`GarbageCollectionTarget`] drives garbage collection.
+    pub const NONE: Self = Self {
+        time_window: None,
+        max_rows_per_entity: None,
+        protected_entities: Vec::new(),
+    };
+
+    /// Does this policy specify any rule at all?
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self == &Self::NONE
+    }
+
+    /// Is the given entity, or any of its ancestors, protected from garbage collection?
+    #[inline]
+    pub fn is_entity_protected(&self, entity_path: &EntityPath) -> bool {
+        self.protected_entities
+            .iter()
+            .any(|protected| entity_path.starts_with(protected))
+    }
+
+    /// Replaces the rules of this policy with the ones that are set in `overrides`.
+    ///
+    /// Rules that `overrides` leaves unset (`None`, or no protected entities) are kept as-is.
+    pub fn overridden_by(mut self, overrides: &Self) -> Self {
+        let Self {
+            time_window,
+            max_rows_per_entity,
+            protected_entities,
+        } = overrides;
+
+        if time_window.is_some() {
+            self.time_window = *time_window;
+        }
+        if max_rows_per_entity.is_some() {
+            self.max_rows_per_entity = *max_rows_per_entity;
+        }
+        if !protected_entities.is_empty() {
+            self.protected_entities = protected_entities.clone();
+        }
+
+        self
+    }
+}
+
+/// Keep only the data within `window` of the most recent time on `timeline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionTimeWindow {
+    pub timeline: TimelineName,
+
+    /// The size of the window, in the timeline's native unit (i.e. nanoseconds for temporal
+    /// timelines, raw indices for sequence timelines).
+    pub window: u64,
+}
+
+impl std::str::FromStr for RetentionTimeWindow {
+    type Err = String;
+
+    /// Parses `<timeline>=<window>`, e.g. `log_time=30s` or `frame_nr=100`.
+    ///
+    /// The window can either be an integer (in the timeline's native unit) or a duration such as
+    /// `30s`, `5m` or `250ms`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((timeline, window)) = s.split_once('=') else {
+            return Err(format!(
+                "Expected '<timeline>=<window>' (e.g. 'log_time=30s'), got {s:?}"
+            ));
+        };
+
+        let timeline = timeline.trim();
+        if timeline.is_empty() {
+            return Err(format!("Missing timeline name in {s:?}"));
+        }
+
+        let window = window.trim();
+        let window = if let Ok(window) = window.parse::<u64>() {
+            window
+        } else {
+            let duration: re_log_types::Duration = window
+                .parse()
+                .map_err(|err| format!("Invalid retention window {window:?}: {err}"))?;
+            u64::try_from(duration.as_nanos())
+                .map_err(|_err| format!("Retention window must be positive, got {window:?}"))?
+        };
+
+        Ok(Self {
+            timeline: TimelineName::new(timeline),
+            window,
+        })
+    }
+}
+
+impl std::fmt::Display for RetentionTimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { timeline, window } = self;
+        write!(f, "{timeline}={window}")
+    }
+}
+
 impl std::fmt::Display for GarbageCollectionTarget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -120,6 +246,12 @@ impl ChunkStore {
     /// component on each timeline. The only practical guarantee this gives is that a latest-at query
     /// with a value of max-int will be unchanged. However, latest-at queries from other arbitrary
     /// points in time may provide different results pre- and post- GC.
+    ///
+    /// ## Retention
+    ///
+    /// The store's [`RetentionPolicy`] (see [`crate::ChunkStoreConfig::retention`]) is enforced on
+    /// top of the `target`: chunks that fall outside of the retention window are dropped first, no
+    /// matter how much data the target asks for, while chunks of protected entities are never dropped.
     pub fn gc(
         &mut self,
         options: &GarbageCollectionOptions,
@@ -135,6 +267,7 @@ impl ChunkStore {
         let total_num_rows_before = stats_before.total().num_rows;
 
         let protected_chunk_ids = self.find_all_protected_chunk_ids(options.protect_latest);
+        let expired_chunk_ids = self.find_all_expired_chunk_ids();
 
         let diffs = match options.target {
             GarbageCollectionTarget::DropAtLeastFraction(p) => {
@@ -155,7 +288,12 @@ impl ChunkStore {
                     "starting GC"
                 );
 
-                self.gc_drop_at_least_num_bytes(options, num_bytes_to_drop, &protected_chunk_ids)
+                self.gc_drop_at_least_num_bytes(
+                    options,
+                    num_bytes_to_drop,
+                    &protected_chunk_ids,
+                    &expired_chunk_ids,
+                )
             }
             GarbageCollectionTarget::Everything => {
                 re_log::trace!(
@@ -167,7 +305,12 @@ impl ChunkStore {
                     "starting GC"
                 );
 
-                self.gc_drop_at_least_num_bytes(options, f64::INFINITY, &protected_chunk_ids)
+                self.gc_drop_at_least_num_bytes(
+                    options,
+                    f64::INFINITY,
+                    &protected_chunk_ids,
+                    &expired_chunk_ids,
+                )
             }
         };
 
@@ -264,11 +407,85 @@ impl ChunkStore {
             .collect()
     }
 
+    /// Finds all temporal [`ChunkId`]s that violate the store's [`RetentionPolicy`].
+    ///
+    /// This does not account for protected entities: that is the job of the caller.
+    fn find_all_expired_chunk_ids(&self) -> BTreeSet<ChunkId> {
+        re_tracing::profile_function!();
+
+        let RetentionPolicy {
+            time_window,
+            max_rows_per_entity,
+            protected_entities: _, // handled during the mark phase
+        } = &self.config.retention;
+
+        let mut expired_chunk_ids = BTreeSet::default();
+
+        if let Some(RetentionTimeWindow { timeline, window }) = time_window {
+            if let Some(time_range) = self.time_range(timeline) {
+                let window = i64::try_from(*window).unwrap_or(i64::MAX);
+                let cutoff =
+                    TimeInt::new_temporal(time_range.max().as_i64().saturating_sub(window));
+
+                for temporal_chunk_ids_per_timeline in self.temporal_chunk_ids_per_entity.values() {
+                    let Some(temporal_chunk_ids_per_time) =
+                        temporal_chunk_ids_per_timeline.get(timeline)
+                    else {
+                        continue;
+                    };
+
+                    // A chunk has expired once _all_ of its data is older than the cutoff, i.e.
+                    // once it ends before the cutoff.
+                    expired_chunk_ids.extend(
+                        temporal_chunk_ids_per_time
+                            .per_end_time
+                            .range(..cutoff)
+                            .flat_map(|(_, chunk_ids)| chunk_ids.iter().copied()),
+                    );
+                }
+            }
+        }
+
+        if let Some(max_rows_per_entity) = *max_rows_per_entity {
+            for temporal_chunk_ids_per_timeline in self.temporal_chunk_ids_per_entity.values() {
+                let chunk_ids: BTreeSet<ChunkId> = temporal_chunk_ids_per_timeline
+                    .values()
+                    .flat_map(|temporal_chunk_ids_per_time| {
+                        temporal_chunk_ids_per_time
+                            .per_start_time
+                            .values()
+                            .flatten()
+                    })
+                    .copied()
+                    .collect();
+
+                let mut chunks: Vec<_> = chunk_ids
+                    .iter()
+                    .filter_map(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
+                    .collect();
+                chunks.sort_by_key(|chunk| chunk.row_id_range().map(|(row_id_min, _)| row_id_min));
+
+                // Walk backwards from the most recent data, keeping chunks until we have enough rows.
+                let mut num_rows_kept = 0u64;
+                for chunk in chunks.into_iter().rev() {
+                    if num_rows_kept >= max_rows_per_entity {
+                        expired_chunk_ids.insert(chunk.id());
+                    } else {
+                        num_rows_kept += chunk.num_rows() as u64;
+                    }
+                }
+            }
+        }
+
+        expired_chunk_ids
+    }
+
     fn gc_drop_at_least_num_bytes(
         &mut self,
         options: &GarbageCollectionOptions,
         mut num_bytes_to_drop: f64,
         protected_chunk_ids: &BTreeSet<ChunkId>,
+        expired_chunk_ids: &BTreeSet<ChunkId>,
     ) -> Vec<ChunkStoreDiff> {
         re_tracing::profile_function!(re_format::format_bytes(num_bytes_to_drop));
 
@@ -281,58 +498,69 @@ impl ChunkStore {
         {
             re_tracing::profile_scope!("mark");
 
-            for chunk_id in self
-                .chunk_ids_per_min_row_id
-                .values()
-                .flatten()
-                .filter(|chunk_id| !protected_chunk_ids.contains(chunk_id))
-            {
-                if let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) {
-                    if options.is_chunk_protected(chunk) {
-                        continue;
-                    }
+            let retention = &self.config.retention;
+            let is_chunk_protected = |chunk: &Chunk| {
+                protected_chunk_ids.contains(&chunk.id())
+                    || options.is_chunk_protected(chunk)
+                    || retention.is_entity_protected(chunk.entity_path())
+            };
 
-                    // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
-                    // and would count as amortized (i.e. 0 bytes).
-                    num_bytes_to_drop -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
-
-                    // NOTE: We cannot blindly `retain` across all temporal tables, it's way too costly
-                    // and slow. Rather we need to surgically remove the superfluous chunks.
-                    let entity_path = chunk.entity_path();
-                    let per_timeline = chunk_ids_to_be_removed
-                        .entry(entity_path.clone())
-                        .or_default();
-                    for (&timeline, time_column) in chunk.timelines() {
-                        let per_component = per_timeline.entry(timeline).or_default();
-                        for component_descr in chunk.component_descriptors() {
-                            let per_time = per_component.entry(component_descr).or_default();
-
-                            // NOTE: As usual, these are vectors of `ChunkId`s, as it is legal to
-                            // have perfectly overlapping chunks.
-                            let time_range = time_column.time_range();
-                            per_time
-                                .entry(time_range.min())
-                                .or_default()
-                                .push(chunk.id());
-                            if time_range.min() != time_range.max() {
-                                per_time
-                                    .entry(time_range.max())
-                                    .or_default()
-                                    .push(chunk.id());
-                            }
-                        }
-                    }
-                } else {
-                    chunk_ids_dangling.insert(*chunk_id);
+            let mut chunk_ids_marked = HashSet::default();
+
+            // Retention comes first: expired chunks must go, whatever the target.
+            for chunk_id in expired_chunk_ids {
+                let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) else {
+                    continue;
+                };
+                if is_chunk_protected(chunk) {
+                    continue;
                 }
 
+                // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
+                // and would count as amortized (i.e. 0 bytes).
+                num_bytes_to_drop -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
+
+                mark_chunk_for_removal(&mut chunk_ids_to_be_removed, chunk);
+                chunk_ids_marked.insert(*chunk_id);
+
                 // NOTE: There is no point in spending more than a fourth of the time budget on the
                 // mark phase or there is no way the sweep phase will have any time to do anything
                 // with the results anyhow.
-                if start_time.elapsed() >= options.time_budget / 4 || num_bytes_to_drop <= 0.0 {
+                // Whatever expired chunks are left will be taken care of by the next GC.
+                if start_time.elapsed() >= options.time_budget / 4 {
                     break;
                 }
             }
+
+            if num_bytes_to_drop > 0.0 {
+                for chunk_id in self
+                    .chunk_ids_per_min_row_id
+                    .values()
+                    .flatten()
+                    .filter(|chunk_id| !chunk_ids_marked.contains(*chunk_id))
+                {
+                    if let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) {
+                        if is_chunk_protected(chunk) {
+                            continue;
+                        }
+
+                        // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
+                        // and would count as amortized (i.e. 0 bytes).
+                        num_bytes_to_drop -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
+
+                        mark_chunk_for_removal(&mut chunk_ids_to_be_removed, chunk);
+                    } else {
+                        chunk_ids_dangling.insert(*chunk_id);
+                    }
+
+                    // NOTE: There is no point in spending more than a fourth of the time budget on the
+                    // mark phase or there is no way the sweep phase will have any time to do anything
+                    // with the results anyhow.
+                    if start_time.elapsed() >= options.time_budget / 4 || num_bytes_to_drop <= 0.0 {
+                        break;
+                    }
+                }
+            }
         }
 
         {
@@ -691,3 +919,36 @@ impl ChunkStore {
             .collect()
     }
 }
+
+/// Adds the given temporal `chunk` to the set of chunks to be removed during the sweep phase.
+fn mark_chunk_for_removal(
+    chunk_ids_to_be_removed: &mut RemovableChunkIdPerTimePerComponentPerTimelinePerEntity,
+    chunk: &Chunk,
+) {
+    // NOTE: We cannot blindly `retain` across all temporal tables, it's way too costly
+    // and slow. Rather we need to surgically remove the superfluous chunks.
+    let entity_path = chunk.entity_path();
+    let per_timeline = chunk_ids_to_be_removed
+        .entry(entity_path.clone())
+        .or_default();
+    for (&timeline, time_column) in chunk.timelines() {
+        let per_component = per_timeline.entry(timeline).or_default();
+        for component_descr in chunk.component_descriptors() {
+            let per_time = per_component.entry(component_descr).or_default();
+
+            // NOTE: As usual, these are vectors of `ChunkId`s, as it is legal to
+            // have perfectly overlapping chunks.
+            let time_range = time_column.time_range();
+            per_time
+                .entry(time_range.min())
+                .or_default()
+                .push(chunk.id());
+            if time_range.min() != time_range.max() {
+                per_time
+                    .entry(time_range.max())
+                    .or_default()
+                    .push(chunk.id());
+            }
+        }
+    }
+}
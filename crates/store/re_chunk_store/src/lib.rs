@@ -26,10 +26,11 @@ mod writes;
 
 pub use self::{
     dataframe::{
-        Index, IndexRange, IndexValue, QueryExpression, SparseFillStrategy, ViewContentsSelector,
+        Aggregation, Bucketing, Index, IndexRange, IndexValue, IndexValuePolicy, QueryExpression,
+        SparseFillStrategy, ViewContentsSelector,
     },
     events::{ChunkCompactionReport, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent},
-    gc::{GarbageCollectionOptions, GarbageCollectionTarget},
+    gc::{GarbageCollectionOptions, GarbageCollectionTarget, RetentionPolicy, RetentionTimeWindow},
     stats::{ChunkStoreChunkStats, ChunkStoreStats},
     store::{ChunkStore, ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreHandle, ColumnMetadata},
     subscribers::{ChunkStoreSubscriber, ChunkStoreSubscriberHandle, PerStoreChunkSubscriber},
@@ -9,7 +9,7 @@ use re_chunk::{Chunk, ChunkId, RowId, TimelineName};
 use re_log_types::{EntityPath, StoreId, StoreInfo, TimeInt, TimeType};
 use re_types_core::{ComponentDescriptor, ComponentName};
 
-use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult};
+use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult, RetentionPolicy};
 
 // ---
 
@@ -72,6 +72,11 @@ pub struct ChunkStoreConfig {
     /// The default byte threshold is set to 8MiB, which is a reasonable unit of work when e.g.
     /// sending chunks over the network.
     pub chunk_max_rows_if_unsorted: u64,
+
+    /// Declarative retention rules, enforced on every garbage collection.
+    ///
+    /// See [`RetentionPolicy`] for details.
+    pub retention: RetentionPolicy,
     //
     // TODO(cmc): It could make sense to have time-range-based thresholds in here, since the time
     // range covered by a chunk has direct effects on A) the complexity of backward walks and
@@ -92,37 +97,47 @@ impl Default for ChunkStoreConfig {
 }
 
 impl ChunkStoreConfig {
-    /// Default configuration, applicable to most use cases, according to empirical testing.
-    pub const DEFAULT: Self = Self {
-        enable_changelog: true,
+    // This gives us 96 bytes per row (assuming a default limit of 4096 rows), which is enough to
+    // fit a couple scalar columns, a RowId column, a handful of timeline columns, all the
+    // necessary offsets, etc.
+    //
+    // A few megabytes turned out to be way too costly to concatenate in real-time in the
+    // Viewer (see <https://github.com/rerun-io/rerun/issues/7222>).
+    const DEFAULT_CHUNK_MAX_BYTES: u64 = 12 * 8 * 4096;
 
-        // This gives us 96 bytes per row (assuming a default limit of 4096 rows), which is enough to
-        // fit a couple scalar columns, a RowId column, a handful of timeline columns, all the
-        // necessary offsets, etc.
-        //
-        // A few megabytes turned out to be way too costly to concatenate in real-time in the
-        // Viewer (see <https://github.com/rerun-io/rerun/issues/7222>).
-        chunk_max_bytes: 12 * 8 * 4096,
+    // Empirical testing shows that 4096 is the threshold after which we really start to get
+    // dimishing returns space and compute wise.
+    const DEFAULT_CHUNK_MAX_ROWS: u64 = 4096;
 
-        // Empirical testing shows that 4096 is the threshold after which we really start to get
-        // dimishing returns space and compute wise.
-        chunk_max_rows: 4096,
+    const DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED: u64 = 1024;
 
-        chunk_max_rows_if_unsorted: 1024,
+    /// Default configuration, applicable to most use cases, according to empirical testing.
+    pub const DEFAULT: Self = Self {
+        enable_changelog: true,
+        chunk_max_bytes: Self::DEFAULT_CHUNK_MAX_BYTES,
+        chunk_max_rows: Self::DEFAULT_CHUNK_MAX_ROWS,
+        chunk_max_rows_if_unsorted: Self::DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED,
+        retention: RetentionPolicy::NONE,
     };
 
     /// [`Self::DEFAULT`], but with compaction entirely disabled.
+    //
+    // NOTE: `..Self::DEFAULT` cannot be used in constants, since `RetentionPolicy` owns heap data.
     pub const COMPACTION_DISABLED: Self = Self {
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
-        ..Self::DEFAULT
+        enable_changelog: true,
+        retention: RetentionPolicy::NONE,
     };
 
     /// [`Self::DEFAULT`], but with changelog disabled.
     pub const CHANGELOG_DISABLED: Self = Self {
         enable_changelog: false,
-        ..Self::DEFAULT
+        chunk_max_bytes: Self::DEFAULT_CHUNK_MAX_BYTES,
+        chunk_max_rows: Self::DEFAULT_CHUNK_MAX_ROWS,
+        chunk_max_rows_if_unsorted: Self::DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED,
+        retention: RetentionPolicy::NONE,
     };
 
     /// All features disabled.
@@ -131,6 +146,7 @@ impl ChunkStoreConfig {
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
+        retention: RetentionPolicy::NONE,
     };
 
     /// Environment variable to configure [`Self::enable_changelog`].
@@ -147,6 +163,20 @@ impl ChunkStoreConfig {
     // NOTE: Shared with the same env-var on the batcher side, for consistency.
     pub const ENV_CHUNK_MAX_ROWS_IF_UNSORTED: &'static str = "RERUN_CHUNK_MAX_ROWS_IF_UNSORTED";
 
+    /// Environment variable to configure [`RetentionPolicy::time_window`].
+    ///
+    /// Expects `<timeline>=<window>`, e.g. `log_time=30s` or `frame_nr=100`.
+    pub const ENV_RETENTION_TIME_WINDOW: &'static str = "RERUN_RETENTION_TIME_WINDOW";
+
+    /// Environment variable to configure [`RetentionPolicy::max_rows_per_entity`].
+    pub const ENV_RETENTION_MAX_ROWS_PER_ENTITY: &'static str =
+        "RERUN_RETENTION_MAX_ROWS_PER_ENTITY";
+
+    /// Environment variable to configure [`RetentionPolicy::protected_entities`].
+    ///
+    /// Expects a comma-separated list of entity paths, e.g. `/config,/world/map`.
+    pub const ENV_RETENTION_PROTECTED_ENTITIES: &'static str = "RERUN_RETENTION_PROTECTED_ENTITIES";
+
     /// Creates a new `ChunkStoreConfig` using the default values, optionally overridden
     /// through the environment.
     ///
@@ -160,7 +190,8 @@ impl ChunkStoreConfig {
     /// they are present.
     ///
     /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`], [`Self::ENV_RETENTION_TIME_WINDOW`],
+    /// [`Self::ENV_RETENTION_MAX_ROWS_PER_ENTITY`] and [`Self::ENV_RETENTION_PROTECTED_ENTITIES`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -197,6 +228,36 @@ impl ChunkStoreConfig {
                 })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_RETENTION_TIME_WINDOW) {
+            new.retention.time_window =
+                Some(
+                    s.parse()
+                        .map_err(|err: String| ChunkStoreError::ParseConfig {
+                            name: Self::ENV_RETENTION_TIME_WINDOW,
+                            value: s.clone(),
+                            err: err.into(),
+                        })?,
+                );
+        }
+
+        if let Ok(s) = std::env::var(Self::ENV_RETENTION_MAX_ROWS_PER_ENTITY) {
+            new.retention.max_rows_per_entity =
+                Some(s.parse().map_err(|err| ChunkStoreError::ParseConfig {
+                    name: Self::ENV_RETENTION_MAX_ROWS_PER_ENTITY,
+                    value: s.clone(),
+                    err: Box::new(err),
+                })?);
+        }
+
+        if let Ok(s) = std::env::var(Self::ENV_RETENTION_PROTECTED_ENTITIES) {
+            new.retention.protected_entities = s
+                .split(',')
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(EntityPath::from)
+                .collect();
+        }
+
         Ok(new)
     }
 }
@@ -208,6 +269,9 @@ fn chunk_store_config() {
     std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
     std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
     std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
+    std::env::set_var("RERUN_RETENTION_TIME_WINDOW", "log_time=30s");
+    std::env::set_var("RERUN_RETENTION_MAX_ROWS_PER_ENTITY", "1000");
+    std::env::set_var("RERUN_RETENTION_PROTECTED_ENTITIES", "/config, /world/map");
 
     let config = ChunkStoreConfig::from_env().unwrap();
 
@@ -216,6 +280,14 @@ fn chunk_store_config() {
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
+        retention: RetentionPolicy {
+            time_window: Some(crate::RetentionTimeWindow {
+                timeline: TimelineName::log_time(),
+                window: 30_000_000_000,
+            }),
+            max_rows_per_entity: Some(1000),
+            protected_entities: vec!["/config".into(), "/world/map".into()],
+        },
     };
 
     assert_eq!(expected, config);
@@ -478,6 +550,9 @@ impl Drop for ChunkStore {
         if self.config.enable_changelog {
             // Then, if the changelog is enabled, trigger a full GC: this will notify all remaining
             // subscribers of all the chunks that were dropped by dropping the store itself.
+            //
+            // Retention rules must not get in the way: everything goes.
+            self.config.retention = RetentionPolicy::NONE;
             _ = self.gc(&crate::GarbageCollectionOptions::gc_everything());
         }
     }
@@ -636,6 +711,14 @@ impl ChunkStore {
         &self.config
     }
 
+    /// Replaces the [`RetentionPolicy`] of this store.
+    ///
+    /// The new policy is enforced starting with the next call to [`Self::gc`].
+    #[inline]
+    pub fn set_retention_policy(&mut self, retention: RetentionPolicy) {
+        self.config.retention = retention;
+    }
+
     /// Iterate over all chunks in the store, in ascending [`ChunkId`] order.
     #[inline]
     pub fn iter_chunks(&self) -> impl Iterator<Item = &Arc<Chunk>> + '_ {
@@ -713,6 +796,24 @@ impl ChunkStore {
     pub fn from_rrd_filepath(
         store_config: &ChunkStoreConfig,
         path_to_rrd: impl AsRef<std::path::Path>,
+    ) -> anyhow::Result<BTreeMap<StoreId, Self>> {
+        Self::from_rrd_filepath_with_query(store_config, path_to_rrd, &Default::default())
+    }
+
+    /// Instantiate a new `ChunkStore` with the given [`ChunkStoreConfig`].
+    ///
+    /// The stores will be prefilled with the data at the specified path that matches the given
+    /// query.
+    ///
+    /// If the file has a footer index, only the relevant chunks are read from disk.
+    /// Otherwise the whole file is decoded, and the chunks that don't match are dropped.
+    ///
+    /// See also:
+    /// * [`ChunkStore::from_rrd_filepath`]
+    pub fn from_rrd_filepath_with_query(
+        store_config: &ChunkStoreConfig,
+        path_to_rrd: impl AsRef<std::path::Path>,
+        query: &re_log_encoding::footer::ChunkIndexQuery,
     ) -> anyhow::Result<BTreeMap<StoreId, Self>> {
         let path_to_rrd = path_to_rrd.as_ref();
 
@@ -725,11 +826,13 @@ impl ChunkStore {
         let rrd_file = std::fs::File::open(path_to_rrd)
             .with_context(|| format!("couldn't open {path_to_rrd:?}"))?;
 
-        let mut decoder = re_log_encoding::decoder::Decoder::new(rrd_file)
-            .with_context(|| format!("couldn't decode {path_to_rrd:?}"))?;
+        let mut decoder = re_log_encoding::decoder::indexed::IndexedDecoder::new(
+            std::io::BufReader::new(rrd_file),
+        )
+        .with_context(|| format!("couldn't decode {path_to_rrd:?}"))?;
 
         // TODO(cmc): offload the decoding to a background thread.
-        for res in &mut decoder {
+        for res in decoder.query(query) {
             let msg = res.with_context(|| format!("couldn't decode message {path_to_rrd:?}"))?;
             match msg {
                 re_log_types::LogMsg::SetStoreInfo(info) => {
@@ -355,13 +355,45 @@ impl ChunkStore {
             .or_default()
             .push(chunk.id());
 
+        self.register_columns(&chunk);
+
+        let events = if self.config.enable_changelog {
+            let events: Vec<_> = diffs
+                .into_iter()
+                .map(|diff| ChunkStoreEvent {
+                    store_id: self.id.clone(),
+                    store_generation: self.generation(),
+                    event_id: self
+                        .event_id
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                    diff,
+                })
+                .collect();
+
+            Self::on_events(&events);
+
+            events
+        } else {
+            Vec::new()
+        };
+
+        Ok(events)
+    }
+
+    /// Adds the timelines and component columns of `chunk` to the schema of the store, without
+    /// inserting any of its data.
+    ///
+    /// This is what [`Self::insert_chunk`] does with the columns of every chunk it inserts.
+    /// Use this directly when loading a subset of a recording, so that the columns that didn't
+    /// make the cut still show up in dataframe queries (as all-null columns).
+    pub fn register_columns(&mut self, chunk: &Chunk) {
         for (name, columns) in chunk.timelines() {
             let new_typ = columns.timeline().typ();
             if let Some(old_typ) = self.time_type_registry.insert(*name, new_typ) {
                 if old_typ != new_typ {
                     re_log::warn_once!(
                         "Timeline '{name}' changed type from {old_typ:?} to {new_typ:?}. \
-                        Rerun does not support using different types for the same timeline.",
+                    Rerun does not support using different types for the same timeline.",
                     );
                 }
             }
@@ -398,28 +430,6 @@ impl ChunkStore {
                 column_metadata_state.is_semantically_empty &= is_semantically_empty;
             }
         }
-
-        let events = if self.config.enable_changelog {
-            let events: Vec<_> = diffs
-                .into_iter()
-                .map(|diff| ChunkStoreEvent {
-                    store_id: self.id.clone(),
-                    store_generation: self.generation(),
-                    event_id: self
-                        .event_id
-                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
-                    diff,
-                })
-                .collect();
-
-            Self::on_events(&events);
-
-            events
-        } else {
-            Vec::new()
-        };
-
-        Ok(events)
     }
 
     /// Finds the most appropriate candidate for compaction.
@@ -443,6 +453,7 @@ impl ChunkStore {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    retention: _,
                 } = store.config;
 
                 *candidates_below_threshold
@@ -6,7 +6,7 @@ use rand::Rng as _;
 use re_chunk::{Chunk, ChunkId, LatestAtQuery, RowId, TimeInt, TimePoint, TimelineName};
 use re_chunk_store::{
     ChunkStore, ChunkStoreConfig, ChunkStoreDiffKind, GarbageCollectionOptions,
-    GarbageCollectionTarget,
+    GarbageCollectionTarget, RetentionPolicy, RetentionTimeWindow,
 };
 use re_log_types::{
     build_frame_nr, build_log_time,
@@ -401,6 +401,229 @@ fn protected_time_ranges() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Only enforce retention, don't drop anything on top of it.
+fn enforce_retention() -> GarbageCollectionOptions {
+    GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(0.0),
+        ..GarbageCollectionOptions::gc_everything()
+    }
+}
+
+fn new_store_with_retention(retention: RetentionPolicy) -> ChunkStore {
+    ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig {
+            retention,
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        },
+    )
+}
+
+#[test]
+fn retention_time_window() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = new_store_with_retention(RetentionPolicy {
+        time_window: Some("frame_nr=10".parse().unwrap()),
+        ..Default::default()
+    });
+
+    let entity_path = EntityPath::from("this/that");
+
+    let mut chunks = Vec::new();
+    for frame_nr in [0, 5, 10, 15, 20] {
+        let chunk = Arc::new(
+            Chunk::builder(entity_path.clone())
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    [&MyIndex::from_iter(0..3) as _],
+                )
+                .build()?,
+        );
+        store.insert_chunk(&chunk)?;
+        chunks.push(chunk);
+    }
+
+    // Window is [10, 20]: frames 0 and 5 must go, and nothing else.
+    let (events, _) = store.gc(&enforce_retention());
+    let mut dropped: Vec<_> = events.iter().map(|event| event.diff.chunk.id()).collect();
+    dropped.sort();
+    let mut expected = vec![chunks[0].id(), chunks[1].id()];
+    expected.sort();
+    assert_eq!(expected, dropped);
+
+    // The window slides forward as new data comes in.
+    store.insert_chunk(&Arc::new(
+        Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                RowId::new(),
+                [build_frame_nr(25)],
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    ))?;
+
+    let (events, _) = store.gc(&enforce_retention());
+    assert_eq!(events.len(), 1);
+    assert!(Arc::ptr_eq(&events[0].diff.chunk, &chunks[2]));
+
+    // Nothing left to enforce.
+    let (events, _) = store.gc(&enforce_retention());
+    assert!(events.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn retention_time_window_parsing() {
+    assert_eq!(
+        RetentionTimeWindow {
+            timeline: TimelineName::new("frame_nr"),
+            window: 100,
+        },
+        "frame_nr=100".parse().unwrap()
+    );
+    assert_eq!(
+        RetentionTimeWindow {
+            timeline: TimelineName::log_time(),
+            window: 30_000_000_000,
+        },
+        "log_time=30s".parse().unwrap()
+    );
+    assert_eq!(
+        RetentionTimeWindow {
+            timeline: TimelineName::log_time(),
+            window: 250_000_000,
+        },
+        " log_time = 250ms ".parse().unwrap()
+    );
+
+    assert!("log_time".parse::<RetentionTimeWindow>().is_err());
+    assert!("=30s".parse::<RetentionTimeWindow>().is_err());
+    assert!("log_time=soon".parse::<RetentionTimeWindow>().is_err());
+    assert!("log_time=-30s".parse::<RetentionTimeWindow>().is_err());
+}
+
+#[test]
+fn retention_overrides() {
+    let env = RetentionPolicy {
+        time_window: Some("frame_nr=100".parse().unwrap()),
+        max_rows_per_entity: Some(10),
+        protected_entities: vec![EntityPath::from("/config")],
+    };
+
+    assert_eq!(env.clone().overridden_by(&RetentionPolicy::NONE), env);
+
+    let retention = env.overridden_by(&RetentionPolicy {
+        time_window: None,
+        max_rows_per_entity: Some(20),
+        protected_entities: vec![EntityPath::from("/calibration")],
+    });
+    assert_eq!(
+        retention,
+        RetentionPolicy {
+            time_window: Some("frame_nr=100".parse().unwrap()),
+            max_rows_per_entity: Some(20),
+            protected_entities: vec![EntityPath::from("/calibration")],
+        }
+    );
+}
+
+#[test]
+fn retention_max_rows_per_entity() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = new_store_with_retention(RetentionPolicy {
+        max_rows_per_entity: Some(3),
+        ..Default::default()
+    });
+
+    let entity_path1 = EntityPath::from("this/that");
+    let entity_path2 = EntityPath::from("this/other");
+
+    let mut chunks1 = Vec::new();
+    for frame_nr in 0..5 {
+        let chunk = Arc::new(
+            Chunk::builder(entity_path1.clone())
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    [&MyIndex::from_iter(0..3) as _],
+                )
+                .build()?,
+        );
+        store.insert_chunk(&chunk)?;
+        chunks1.push(chunk);
+    }
+
+    // This one is within its own budget and must be left alone.
+    store.insert_chunk(&Arc::new(
+        Chunk::builder(entity_path2.clone())
+            .with_component_batches(
+                RowId::new(),
+                [build_frame_nr(0)],
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    ))?;
+
+    let (events, _) = store.gc(&enforce_retention());
+    let mut dropped: Vec<_> = events.iter().map(|event| event.diff.chunk.id()).collect();
+    dropped.sort();
+    let mut expected: Vec<_> = chunks1[..2].iter().map(|chunk| chunk.id()).collect();
+    expected.sort();
+    assert_eq!(expected, dropped);
+
+    assert_eq!(4, store.num_chunks());
+
+    Ok(())
+}
+
+#[test]
+fn retention_protected_entities() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = new_store_with_retention(RetentionPolicy {
+        max_rows_per_entity: Some(1),
+        protected_entities: vec!["config".into()],
+        ..Default::default()
+    });
+
+    let entity_paths = [
+        EntityPath::from("config"),
+        EntityPath::from("config/camera"),
+        EntityPath::from("configuration"),
+    ];
+
+    for entity_path in &entity_paths {
+        for frame_nr in 0..3 {
+            store.insert_chunk(&Arc::new(
+                Chunk::builder(entity_path.clone())
+                    .with_component_batches(
+                        RowId::new(),
+                        [build_frame_nr(frame_nr)],
+                        [&MyIndex::from_iter(0..3) as _],
+                    )
+                    .build()?,
+            ))?;
+        }
+    }
+
+    let (events, _) = store.gc(&enforce_retention());
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|event| event.diff.chunk.entity_path() == &entity_paths[2]));
+
+    // Protected entities survive even a full GC.
+    let (events, _) = store.gc(&GarbageCollectionOptions::gc_everything());
+    assert_eq!(events.len(), 1);
+    assert_eq!(6, store.num_chunks());
+
+    Ok(())
+}
+
 // ---
 
 #[test]
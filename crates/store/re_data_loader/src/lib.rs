@@ -444,7 +444,7 @@ pub const SUPPORTED_IMAGE_EXTENSIONS: &[&str] = &[
     "pbm", "pgm", "png", "ppm", "tga", "tif", "tiff", "webp",
 ];
 
-pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] = &["mp4"];
+pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] = &["mkv", "mp4", "webm"];
 
 pub const SUPPORTED_MESH_EXTENSIONS: &[&str] = &["glb", "gltf", "obj", "stl"];
 
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use re_chunk::EntityPath;
 use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreHandle, QueryExpression};
 use re_log_types::{EntityPathFilter, StoreId};
-use re_query::{QueryCache, QueryCacheHandle, StorageEngine, StorageEngineLike};
+use re_query::{CachesStats, QueryCache, QueryCacheHandle, StorageEngine, StorageEngineLike};
 use re_sorbet::SorbetColumnDescriptors;
 
 use crate::QueryHandle;
@@ -14,6 +14,41 @@ use re_chunk_store::ComponentColumnDescriptor;
 
 // --- Queries ---
 
+/// How the dataframe queries of a [`QueryEngine`] make use of its [`QueryCache`].
+///
+/// Caching is what you want in a Viewer context, where the same data gets queried over and over.
+/// One-shot batch exports, on the other hand, touch every chunk exactly once, and caching their
+/// results just grows memory for no benefit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QueryCachePolicy {
+    /// Go through the cache, and keep everything that ends up in there.
+    #[default]
+    Cached,
+
+    /// Go straight to the store and never populate the cache.
+    ///
+    /// This applies to every query a handle runs: the initial range queries, as well as the
+    /// latest-at and range lookups used for static columns and sparse-filling.
+    Bypass,
+
+    /// Go through the cache, but evict the least recently used cached data once the cache grows
+    /// larger than `max_bytes`.
+    ///
+    /// The budget is enforced once the query is initialized, and then again for every yielded
+    /// row that had to be sparse-filled.
+    Bounded { max_bytes: u64 },
+}
+
+impl std::fmt::Display for QueryCachePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cached => f.write_str("cached"),
+            Self::Bypass => f.write_str("bypass"),
+            Self::Bounded { max_bytes } => write!(f, "bounded({max_bytes} bytes)"),
+        }
+    }
+}
+
 /// A handle to our user-facing query engine.
 ///
 /// Cheap to clone.
@@ -24,6 +59,9 @@ use re_chunk_store::ComponentColumnDescriptor;
 #[derive(Clone)]
 pub struct QueryEngine<E: StorageEngineLike> {
     pub engine: E,
+
+    /// See [`QueryCachePolicy`].
+    pub cache_policy: QueryCachePolicy,
 }
 
 impl QueryEngine<StorageEngine> {
@@ -34,7 +72,10 @@ impl QueryEngine<StorageEngine> {
         #[allow(unsafe_code)]
         let engine = unsafe { StorageEngine::new(store, cache) };
 
-        Self { engine }
+        Self {
+            engine,
+            cache_policy: QueryCachePolicy::default(),
+        }
     }
 
     /// This will automatically instantiate a new empty [`QueryCache`].
@@ -60,6 +101,19 @@ impl QueryEngine<StorageEngine> {
 }
 
 impl<E: StorageEngineLike + Clone> QueryEngine<E> {
+    /// Sets the [`QueryCachePolicy`] used by all subsequent queries.
+    #[inline]
+    pub fn with_cache_policy(mut self, cache_policy: QueryCachePolicy) -> Self {
+        self.cache_policy = cache_policy;
+        self
+    }
+
+    /// Hit/miss and memory stats of the underlying [`QueryCache`].
+    #[inline]
+    pub fn cache_stats(&self) -> CachesStats {
+        self.engine.with(|_store, cache| cache.stats())
+    }
+
     /// Returns the full schema of the store.
     ///
     /// This will include a column descriptor for every timeline and every component on every
@@ -87,7 +141,7 @@ impl<E: StorageEngineLike + Clone> QueryEngine<E> {
     /// Starts a new query by instantiating a [`QueryHandle`].
     #[inline]
     pub fn query(&self, query: QueryExpression) -> QueryHandle<E> {
-        QueryHandle::new(self.engine.clone(), query)
+        QueryHandle::new(self.engine.clone(), query, self.cache_policy)
     }
 
     /// Returns an iterator over all the [`EntityPath`]s present in the database.
@@ -3,13 +3,13 @@
 mod engine;
 mod query;
 
-pub use self::engine::QueryEngine;
+pub use self::engine::{QueryCachePolicy, QueryEngine};
 pub use self::query::QueryHandle;
 
 #[doc(no_inline)]
 pub use self::external::re_chunk_store::{
-    ChunkStoreConfig, ChunkStoreHandle, Index, IndexRange, IndexValue, QueryExpression,
-    SparseFillStrategy, ViewContentsSelector,
+    Aggregation, Bucketing, ChunkStoreConfig, ChunkStoreHandle, Index, IndexRange, IndexValue,
+    IndexValuePolicy, QueryExpression, SparseFillStrategy, ViewContentsSelector,
 };
 #[doc(no_inline)]
 pub use self::external::re_log_types::{
@@ -17,7 +17,7 @@ pub use self::external::re_log_types::{
     StoreKind, TimeCell, TimeInt, Timeline, TimelineName,
 };
 #[doc(no_inline)]
-pub use self::external::re_query::{QueryCache, QueryCacheHandle, StorageEngine};
+pub use self::external::re_query::{CachesStats, QueryCache, QueryCacheHandle, StorageEngine};
 
 #[doc(no_inline)]
 pub use self::external::re_types_core::{ComponentDescriptor, ComponentName};
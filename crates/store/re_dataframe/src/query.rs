@@ -1,20 +1,23 @@
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeSet, VecDeque},
     sync::{
         atomic::{AtomicU64, Ordering},
-        OnceLock,
+        Arc, OnceLock,
     },
 };
 
 use arrow::{
     array::{
-        ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
+        Array as _, ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
+        FixedSizeListArray as ArrowFixedSizeListArray, Float32Array as ArrowFloat32Array,
+        Float64Array as ArrowFloat64Array, ListArray as ArrowListArray,
         PrimitiveArray as ArrowPrimitiveArray, RecordBatch as ArrowRecordBatch,
+        StructArray as ArrowStructArray, UInt64Array as ArrowUInt64Array,
     },
-    buffer::ScalarBuffer as ArrowScalarBuffer,
+    buffer::{OffsetBuffer as ArrowOffsetBuffer, ScalarBuffer as ArrowScalarBuffer},
     datatypes::{
-        DataType as ArrowDataType, Fields as ArrowFields, Schema as ArrowSchema,
-        SchemaRef as ArrowSchemaRef,
+        DataType as ArrowDataType, Field as ArrowField, Fields as ArrowFields,
+        Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
     },
 };
 use itertools::{Either, Itertools as _};
@@ -22,15 +25,17 @@ use nohash_hasher::{IntMap, IntSet};
 
 use re_arrow_util::{into_arrow_ref, ArrowArrayDowncastRef as _};
 use re_chunk::{
-    external::arrow::array::ArrayRef, Chunk, ComponentName, EntityPath, RangeQuery, RowId, TimeInt,
-    TimelineName, UnitChunkShared,
+    external::arrow::array::ArrayRef, Chunk, ChunkId, ComponentName, EntityPath, RangeQuery, RowId,
+    TimeColumn, TimeInt, TimelineName, UnitChunkShared,
 };
 use re_chunk_store::{
-    ChunkStore, ColumnDescriptor, ComponentColumnDescriptor, Index, IndexColumnDescriptor,
-    IndexValue, QueryExpression, SparseFillStrategy,
+    Aggregation, Bucketing, ChunkStore, ColumnDescriptor, ComponentColumnDescriptor, Index,
+    IndexColumnDescriptor, IndexValue, IndexValuePolicy, QueryExpression, SparseFillStrategy,
 };
 use re_log_types::ResolvedTimeRange;
 use re_query::{QueryCache, StorageEngineLike};
+
+use crate::QueryCachePolicy;
 use re_sorbet::{
     ColumnSelector, ComponentColumnSelector, SorbetColumnDescriptors, TimeColumnSelector,
 };
@@ -53,7 +58,7 @@ use re_types_core::{archetypes, ComponentDescriptor};
 // * [ ] allocate null arrays once
 // * [ ] overlaps (less dumb)
 // * [ ] selector-based `filtered_index`
-// * [ ] configurable cache bypass
+// * [x] configurable cache bypass
 
 /// A handle to a dataframe query, ready to be executed.
 ///
@@ -67,6 +72,9 @@ pub struct QueryHandle<E: StorageEngineLike> {
     /// The original query expression used to instantiate this handle.
     pub(crate) query: QueryExpression,
 
+    /// Inherited from the `QueryEngine`.
+    pub(crate) cache_policy: QueryCachePolicy,
+
     /// Internal private state. Lazily computed.
     ///
     /// It is important that handles stay cheap to create.
@@ -92,6 +100,13 @@ struct QueryHandleState {
     /// See also [`QueryHandleState::arrow_schema`].
     selected_contents: Vec<(usize, ColumnDescriptor)>,
 
+    /// The extra per-component index columns, appended after the `selected_contents`.
+    ///
+    /// Always empty unless using [`IndexValuePolicy::ExactPerComponent`].
+    ///
+    /// `selected_contents`: [`QueryHandleState::selected_contents`]
+    exact_index_columns: Vec<ExactIndexColumn>,
+
     /// This keeps track of the static data associated with each entry in `selected_contents`, if any.
     ///
     /// This is queried only once during init, and will override all cells that follow.
@@ -144,13 +159,40 @@ struct QueryHandleState {
     ///
     /// See also [`QueryHandleState::cur_row`].
     unique_index_values: Vec<IndexValue>,
+
+    /// The [`LookaheadCursor`] of every component column that had to be sparse-filled so far, by
+    /// index in [`QueryHandleState::view_contents`].
+    ///
+    /// Only used by the sparse-fill strategies that look ahead.
+    lookahead_cursors: parking_lot::Mutex<IntMap<usize, LookaheadCursor>>,
+
+    /// The [`BucketCursor`] of every component column, by index in
+    /// [`QueryHandleState::view_contents`].
+    ///
+    /// Only used when bucketing.
+    bucket_cursors: parking_lot::Mutex<IntMap<usize, BucketCursor>>,
+}
+
+/// An extra index column holding the exact index value of every cell of a specific component column.
+///
+/// See [`IndexValuePolicy::ExactPerComponent`].
+struct ExactIndexColumn {
+    /// The index in [`QueryHandleState::view_contents`] of the component column.
+    view_idx: usize,
+
+    /// The index whose value is reported.
+    index: IndexColumnDescriptor,
+
+    /// The resulting Arrow field.
+    field: ArrowField,
 }
 
 impl<E: StorageEngineLike> QueryHandle<E> {
-    pub(crate) fn new(engine: E, query: QueryExpression) -> Self {
+    pub(crate) fn new(engine: E, query: QueryExpression, cache_policy: QueryCachePolicy) -> Self {
         Self {
             engine,
             query,
+            cache_policy,
             state: Default::default(),
         }
     }
@@ -175,6 +217,13 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             .filtered_index
             .unwrap_or_else(|| TimelineName::new(""));
 
+        // Bucketing doesn't apply in static-only mode.
+        let bucketing = self
+            .query
+            .bucketing
+            .as_ref()
+            .filter(|_| self.query.filtered_index.is_some());
+
         // 1. Compute the schema for the query.
         let view_contents_schema = store.schema_for_query(&self.query);
         let view_contents = view_contents_schema.indices_and_components();
@@ -190,13 +239,37 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             view_contents.clone().into_iter().enumerate().collect()
         };
 
-        // 3. Compute the Arrow schema of the selected components.
+        // Bucketing might change the datatype of some columns, see [`Aggregation::Count`]: these
+        // get a descriptor of their own.
+        let selected_contents = selected_contents
+            .into_iter()
+            .map(|(view_idx, descr)| match (bucketing, descr) {
+                (Some(bucketing), ColumnDescriptor::Component(descr))
+                    if bucketing.aggregation_for(&descr) == Aggregation::Count =>
+                {
+                    (
+                        view_idx,
+                        ColumnDescriptor::Component(count_descriptor(descr)),
+                    )
+                }
+                (_, descr) => (view_idx, descr),
+            })
+            .collect_vec();
+
+        // 3. Compute the Arrow schema of the selected components, as well as any extra index
+        // columns required by the index value policy.
         //
         // Every result returned using this `QueryHandle` will match this schema exactly.
+        let exact_index_columns = self.compute_exact_index_columns(&selected_contents);
         let arrow_schema = ArrowSchemaRef::from(ArrowSchema::new_with_metadata(
             selected_contents
                 .iter()
                 .map(|(_, descr)| descr.to_arrow_field(re_sorbet::BatchType::Dataframe))
+                .chain(
+                    exact_index_columns
+                        .iter()
+                        .map(|column| column.field.clone()),
+                )
                 .collect::<ArrowFields>(),
             Default::default(),
         ));
@@ -290,13 +363,22 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         // 6. Collect all unique index values.
         //
         // Used to achieve ~O(log(n)) pagination.
+        //
+        // When bucketing, each row corresponds to a bucket instead: index values are mapped to the
+        // start of their bucket right away, so that only the buckets are ever collected.
+        let row_index_value = |index_value: TimeInt| {
+            bucketing.map_or(index_value, |bucketing| bucketing.bucket_start(index_value))
+        };
+
         let unique_index_values = if self.query.filtered_index.is_none() {
             vec![TimeInt::STATIC]
         } else if let Some(using_index_values) = self.query.using_index_values.as_ref() {
+            // NOTE: The index values are sorted, and so are their respective buckets.
             using_index_values
                 .iter()
                 .filter(|index_value| !index_value.is_static())
-                .copied()
+                .map(|index_value| row_index_value(*index_value))
+                .dedup()
                 .collect_vec()
         } else {
             re_tracing::profile_scope!("index_values");
@@ -308,7 +390,9 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                 Either::Right(view_chunks)
             };
 
-            let mut all_unique_index_values: BTreeSet<TimeInt> = view_chunks
+            let filtered_index_values = self.query.filtered_index_values.as_ref();
+
+            let all_unique_index_values: BTreeSet<TimeInt> = view_chunks
                 .flat_map(|chunks| {
                     chunks.iter().filter_map(|(_cursor, chunk)| {
                         chunk
@@ -318,16 +402,14 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                     })
                 })
                 .flatten()
+                .filter(|time| {
+                    !time.is_static()
+                        && filtered_index_values.is_none_or(|values| values.contains(time))
+                })
+                .map(row_index_value)
                 .collect();
 
-            if let Some(filtered_index_values) = self.query.filtered_index_values.as_ref() {
-                all_unique_index_values.retain(|time| filtered_index_values.contains(time));
-            }
-
-            all_unique_index_values
-                .into_iter()
-                .filter(|index_value| !index_value.is_static())
-                .collect_vec()
+            all_unique_index_values.into_iter().collect_vec()
         };
 
         let selected_static_values = {
@@ -343,12 +425,13 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                         let query =
                             re_chunk::LatestAtQuery::new(TimelineName::new(""), TimeInt::STATIC);
 
-                        let results = cache.latest_at(
+                        let results = self.latest_at(
+                            cache,
                             &query,
                             &descr.entity_path,
                             // TODO(#6889): We don't allow passing in full descriptors to dataframe queries yet. Everything works via component name.
                             //[ComponentDescriptor::from(descr.clone())],
-                            [descr.component_name],
+                            descr.component_name,
                         );
 
                         results.components.into_values().next()
@@ -361,16 +444,94 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             descr.sanity_check();
         }
 
+        if let QueryCachePolicy::Bounded { max_bytes } = self.cache_policy {
+            // The chunks we need are now owned by this handle, the cache can safely let go of them.
+            cache.purge_least_recently_used(max_bytes);
+        }
+
         QueryHandleState {
             view_contents: view_contents_schema,
             selected_contents,
+            exact_index_columns,
             selected_static_values,
             filtered_index,
             arrow_schema,
             view_chunks,
             cur_row: AtomicU64::new(0),
             unique_index_values,
+            lookahead_cursors: Default::default(),
+            bucket_cursors: Default::default(),
+        }
+    }
+
+    /// Computes the extra index columns required by [`IndexValuePolicy::ExactPerComponent`], if in use.
+    fn compute_exact_index_columns(
+        &self,
+        selected_contents: &[(usize, ColumnDescriptor)],
+    ) -> Vec<ExactIndexColumn> {
+        if self.query.index_value_policy != IndexValuePolicy::ExactPerComponent {
+            return Vec::new();
         }
+
+        // NOTE: Indices that aren't part of the view cannot possibly yield any data.
+        let selected_indices = selected_contents
+            .iter()
+            .filter(|(view_idx, _)| *view_idx != usize::MAX)
+            .filter_map(|(_, descr)| match descr {
+                ColumnDescriptor::Time(descr) => Some(descr),
+                ColumnDescriptor::Component(_) => None,
+            })
+            .collect_vec();
+
+        selected_contents
+            .iter()
+            .filter_map(|(view_idx, descr)| match descr {
+                ColumnDescriptor::Time(_) => None,
+                ColumnDescriptor::Component(descr) => Some((*view_idx, descr)),
+            })
+            .flat_map(|(view_idx, component_descr)| {
+                let component_column_name =
+                    component_descr.column_name(re_sorbet::BatchType::Dataframe);
+
+                selected_indices.iter().map(move |index| {
+                    let column_name = format!("{component_column_name}@{}", index.column_name());
+
+                    // NOTE: These are not index columns: they come after the data columns, and
+                    // there can be many of them per timeline. Tagging them as data, with their own
+                    // metadata, keeps the schema parseable as a regular sorbet schema.
+                    let nullable = true;
+                    let field = ArrowField::new(&column_name, index.datatype().clone(), nullable)
+                        .with_metadata(
+                            [
+                                (
+                                    "rerun.kind".to_owned(),
+                                    re_sorbet::ColumnKind::Component.to_string(),
+                                ),
+                                (
+                                    "rerun.entity_path".to_owned(),
+                                    component_descr.entity_path.to_string(),
+                                ),
+                                (
+                                    "rerun.exact_index_of".to_owned(),
+                                    component_column_name.clone(),
+                                ),
+                                (
+                                    "rerun.exact_index_name".to_owned(),
+                                    index.column_name().to_owned(),
+                                ),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        );
+
+                    ExactIndexColumn {
+                        view_idx,
+                        index: (*index).clone(),
+                        field,
+                    }
+                })
+            })
+            .collect()
     }
 
     #[allow(clippy::unused_self)]
@@ -596,20 +757,19 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             .collect()
     }
 
-    fn fetch_chunks<'a>(
+    /// Runs a single-component range query, honoring the [`QueryCachePolicy`].
+    fn range<'a>(
         &self,
-        _store: &ChunkStore,
+        store: &ChunkStore,
         cache: &QueryCache,
         query: &RangeQuery,
         entity_path: &EntityPath,
         component_descrs: impl IntoIterator<Item = &'a ComponentDescriptor>,
-    ) -> Option<Vec<(AtomicU64, Chunk)>> {
-        // NOTE: Keep in mind that the range APIs natively make sure that we will
-        // either get a bunch of relevant _static_ chunks, or a bunch of relevant
-        // _temporal_ chunks, but never both.
-        //
-        // TODO(cmc): Going through the cache is very useful in a Viewer context, but
-        // not so much in an SDK context. Make it configurable.
+    ) -> Option<Vec<Chunk>> {
+        if self.cache_policy == QueryCachePolicy::Bypass {
+            return range_uncached(store, query, entity_path, component_descrs);
+        }
+
         let results = cache.range(query, entity_path, component_descrs);
 
         debug_assert!(
@@ -621,7 +781,38 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             .components
             .into_iter()
             .next()
-            .map(|(_component_name, chunks)| {
+            .map(|(_component_name, chunks)| chunks)
+    }
+
+    /// Runs a single-component latest-at query, honoring the [`QueryCachePolicy`].
+    fn latest_at(
+        &self,
+        cache: &QueryCache,
+        query: &re_chunk::LatestAtQuery,
+        entity_path: &EntityPath,
+        component_name: ComponentName,
+    ) -> re_query::LatestAtResults {
+        if self.cache_policy == QueryCachePolicy::Bypass {
+            cache.latest_at_uncached(query, entity_path, [component_name])
+        } else {
+            cache.latest_at(query, entity_path, [component_name])
+        }
+    }
+
+    fn fetch_chunks<'a>(
+        &self,
+        store: &ChunkStore,
+        cache: &QueryCache,
+        query: &RangeQuery,
+        entity_path: &EntityPath,
+        component_descrs: impl IntoIterator<Item = &'a ComponentDescriptor>,
+    ) -> Option<Vec<(AtomicU64, Chunk)>> {
+        // NOTE: Keep in mind that the range APIs natively make sure that we will
+        // either get a bunch of relevant _static_ chunks, or a bunch of relevant
+        // _temporal_ chunks, but never both.
+        let chunks = self.range(store, cache, query, entity_path, component_descrs);
+
+        chunks.map(|chunks| {
                 chunks
                     .into_iter()
                     .map(|chunk| {
@@ -658,6 +849,20 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         &self.query
     }
 
+    /// The [`QueryCachePolicy`] this handle was created with.
+    #[inline]
+    pub fn cache_policy(&self) -> QueryCachePolicy {
+        self.cache_policy
+    }
+
+    /// Hit/miss and memory stats of the underlying [`QueryCache`].
+    ///
+    /// These are shared with all other users of the same cache.
+    #[inline]
+    pub fn cache_stats(&self) -> re_query::CachesStats {
+        self.engine.with(|_store, cache| cache.stats())
+    }
+
     /// Describes the columns that make up this view.
     ///
     /// See [`QueryExpression::view_contents`].
@@ -898,6 +1103,12 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         let row_idx = state.cur_row.fetch_add(1, Ordering::Relaxed);
         let cur_index_value = state.unique_index_values.get(row_idx as usize)?;
 
+        if let Some(bucketing) = self.query.bucketing.as_ref() {
+            if !cur_index_value.is_static() {
+                return Some(self.next_bucket_row(state, bucketing, *cur_index_value));
+            }
+        }
+
         // First, we need to find, among all the chunks available for the current view contents,
         // what is their index value for the current row?
         //
@@ -1022,52 +1233,52 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             }
         }
 
-        match self.query.sparse_fill_strategy {
-            SparseFillStrategy::None => {}
+        let mut num_sparse_filled = 0;
+        if self.query.sparse_fill_strategy != SparseFillStrategy::None {
+            let mut lookahead_cursors = state.lookahead_cursors.lock();
 
-            SparseFillStrategy::LatestAtGlobal => {
-                // Everything that yielded `null` for the current iteration.
-                let null_streaming_states = view_streaming_state
-                    .iter_mut()
-                    .enumerate()
-                    .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
+            // Everything that yielded `null` for the current iteration.
+            let null_streaming_states = view_streaming_state
+                .iter_mut()
+                .enumerate()
+                .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
 
-                for (view_idx, streaming_state) in null_streaming_states {
-                    let Some(ColumnDescriptor::Component(descr)) =
-                        state.view_contents.get_index_or_component(view_idx)
-                    else {
-                        continue;
-                    };
+            for (view_idx, streaming_state) in null_streaming_states {
+                let Some(ColumnDescriptor::Component(descr)) =
+                    state.view_contents.get_index_or_component(view_idx)
+                else {
+                    continue;
+                };
 
-                    // NOTE: While it would be very tempting to resolve the latest-at state
-                    // of the entire view contents at `filtered_index_range.start - 1` once
-                    // during `QueryHandle` initialization, and then bootstrap off of that, that
-                    // would effectively close the door to efficient pagination forever, since
-                    // we'd have to iterate over all the pages to compute the right latest-at
-                    // value at t+n (i.e. no more random access).
-                    // Therefore, it is better to simply do this the "dumb" way.
-                    //
-                    // TODO(cmc): Still, as always, this can be made faster and smarter at
-                    // the cost of some extra complexity (e.g. caching the result across
-                    // consecutive nulls etc). Later.
-
-                    let query =
-                        re_chunk::LatestAtQuery::new(state.filtered_index, *cur_index_value);
-
-                    let results = cache.latest_at(
-                        &query,
-                        &descr.entity_path.clone(),
-                        // TODO(#6889): We don't allow passing in full descriptors to dataframe queries yet. Everything works via component name.
-                        //[ComponentDescriptor::from(descr.clone())],
-                        [descr.component_name],
-                    );
+                // NOTE: While it would be very tempting to resolve the latest-at state
+                // of the entire view contents at `filtered_index_range.start - 1` once
+                // during `QueryHandle` initialization, and then bootstrap off of that, that
+                // would effectively close the door to efficient pagination forever, since
+                // we'd have to iterate over all the pages to compute the right latest-at
+                // value at t+n (i.e. no more random access).
+                // Therefore, the latest-at side is resolved on a per-row basis, while the
+                // lookahead side is carried across consecutive nulls by the column's
+                // `LookaheadCursor`.
+
+                *streaming_state = self
+                    .sparse_fill(
+                        store,
+                        cache,
+                        state.filtered_index,
+                        &descr,
+                        *cur_index_value,
+                        lookahead_cursors.entry(view_idx).or_default(),
+                    )
+                    .map(StreamingJoinState::Retrofilled);
+                num_sparse_filled += 1;
+            }
+        }
 
-                    *streaming_state = results
-                        .components
-                        .into_values()
-                        .next()
-                        .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
-                }
+        if num_sparse_filled > 0 {
+            if let QueryCachePolicy::Bounded { max_bytes } = self.cache_policy {
+                // Sparse-filling goes through the cache: keep it within budget as the rows go by.
+                // Everything we still need is owned by the lookahead cursors.
+                cache.purge_least_recently_used(max_bytes);
             }
         }
 
@@ -1081,14 +1292,11 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         // these different cells won't share the same values (e.g. two cells were found at
         // `log_time=100`, but one of them has `frame=3` and the other `frame=5`, for whatever
         // reason).
-        // In order to deal with this, we keep track of the maximum value for every possible index
-        // within the returned set of cells, and return that.
-        //
-        // TODO(cmc): In the future, it would be nice to make that either configurable, e.g.:
-        // * return the minimum value instead of the max
-        // * return the exact value for each component (that would be a _lot_ of columns!)
-        // * etc
-        let mut max_value_per_index: IntMap<TimelineName, (TimeInt, ArrowScalarBuffer<i64>)> =
+        // In order to deal with this, we keep track of either the maximum or minimum value for every
+        // possible index within the returned set of cells, and return that.
+        // See [`QueryExpression::index_value_policy`].
+        let keep_min = self.query.index_value_policy == IndexValuePolicy::Min;
+        let mut value_per_index: IntMap<TimelineName, (TimeInt, ArrowScalarBuffer<i64>)> =
             IntMap::default();
         {
             view_streaming_state
@@ -1120,12 +1328,17 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                     })
                 })
                 .for_each(|(timeline, (time, time_sliced))| {
-                    max_value_per_index
+                    value_per_index
                         .entry(*timeline.name())
-                        .and_modify(|(max_time, max_time_sliced)| {
-                            if time > *max_time {
-                                *max_time = time;
-                                *max_time_sliced = time_sliced.clone();
+                        .and_modify(|(cur_time, cur_time_sliced)| {
+                            let is_better = if keep_min {
+                                time < *cur_time
+                            } else {
+                                time > *cur_time
+                            };
+                            if is_better {
+                                *cur_time = time;
+                                *cur_time_sliced = time_sliced.clone();
                             }
                         })
                         .or_insert((time, time_sliced));
@@ -1134,7 +1347,7 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             if !cur_index_value.is_static() {
                 // The current index value (if temporal) should be the one returned for the
                 // queried index, no matter what.
-                max_value_per_index.insert(
+                value_per_index.insert(
                     state.filtered_index,
                     (
                         *cur_index_value,
@@ -1204,14 +1417,14 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             .selected_contents
             .iter()
             .map(|(view_idx, column)| match column {
-                ColumnDescriptor::Time(descr) => max_value_per_index
-                    .get(descr.timeline().name())
-                    .map_or_else(
+                ColumnDescriptor::Time(descr) => {
+                    value_per_index.get(descr.timeline().name()).map_or_else(
                         || arrow::array::new_null_array(&column.arrow_datatype(), 1),
                         |(_time, time_sliced)| {
                             descr.timeline().typ().make_arrow_array(time_sliced.clone())
                         },
-                    ),
+                    )
+                }
 
                 ColumnDescriptor::Component(_descr) => view_sliced_arrays
                     .get(*view_idx)
@@ -1220,6 +1433,35 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                     .map(into_arrow_ref)
                     .unwrap_or_else(|| arrow::array::new_null_array(&column.arrow_datatype(), 1)),
             })
+            .chain(state.exact_index_columns.iter().map(|column| {
+                let ExactIndexColumn {
+                    view_idx,
+                    index,
+                    field,
+                } = column;
+
+                view_streaming_state
+                    .get(*view_idx)
+                    .and_then(|streaming_state| {
+                        let (timelines, cursor) = match streaming_state.as_ref()? {
+                            StreamingJoinState::StreamingJoinState(s) => {
+                                (s.chunk.timelines(), s.cursor as usize)
+                            }
+                            StreamingJoinState::Retrofilled(unit) => (unit.timelines(), 0),
+                        };
+
+                        let time_column = timelines.get(index.timeline().name())?;
+                        time_column.times_raw().get(cursor)?;
+
+                        Some(
+                            index
+                                .timeline()
+                                .typ()
+                                .make_arrow_array(time_column.times_buffer().slice(cursor, 1)),
+                        )
+                    })
+                    .unwrap_or_else(|| arrow::array::new_null_array(field.data_type(), 1))
+            }))
             .collect_vec();
 
         debug_assert_eq!(state.arrow_schema.fields.len(), selected_arrays.len());
@@ -1270,6 +1512,207 @@ impl<E: StorageEngineLike> QueryHandle<E> {
     }
 }
 
+impl<E: StorageEngineLike> QueryHandle<E> {
+    /// Computes the row for the bucket starting at `bucket_start`.
+    ///
+    /// See [`QueryExpression::bucketing`].
+    fn next_bucket_row(
+        &self,
+        state: &QueryHandleState,
+        bucketing: &Bucketing,
+        bucket_start: TimeInt,
+    ) -> Vec<ArrowArrayRef> {
+        re_tracing::profile_function!();
+
+        let mut bucket_range = bucketing.bucket_range(bucket_start);
+        if let Some(filtered_index_range) = self.query.filtered_index_range {
+            bucket_range = ResolvedTimeRange::new(
+                TimeInt::max(bucket_range.min(), filtered_index_range.min()),
+                TimeInt::min(bucket_range.max(), filtered_index_range.max()),
+            );
+        }
+
+        let mut bucket_cursors = state.bucket_cursors.lock();
+
+        state
+            .selected_contents
+            .iter()
+            .enumerate()
+            .map(|(selected_idx, (view_idx, column))| {
+                let datatype = state.arrow_schema.field(selected_idx).data_type();
+
+                let array = match column {
+                    ColumnDescriptor::Time(descr) => {
+                        (*descr.timeline().name() == state.filtered_index).then(|| {
+                            descr
+                                .timeline()
+                                .typ()
+                                .make_arrow_array(ArrowScalarBuffer::from(vec![
+                                    bucket_start.as_i64()
+                                ]))
+                        })
+                    }
+
+                    ColumnDescriptor::Component(descr) => {
+                        let cells = if let Some(Some(unit)) =
+                            state.selected_static_values.get(selected_idx)
+                        {
+                            unit.components()
+                                .get(&ComponentDescriptor::from(descr.clone()))
+                                .cloned()
+                                .into_iter()
+                                .collect_vec()
+                        } else {
+                            state
+                                .view_chunks
+                                .get(*view_idx)
+                                .map(|view_chunks| {
+                                    bucket_cursors.entry(*view_idx).or_default().bucket_cells(
+                                        view_chunks,
+                                        &state.filtered_index,
+                                        bucket_range,
+                                    )
+                                })
+                                .unwrap_or_default()
+                        };
+
+                        aggregate_cells(&cells, bucketing.aggregation_for(descr), datatype)
+                    }
+                };
+
+                array.unwrap_or_else(|| arrow::array::new_null_array(datatype, 1))
+            })
+            .chain(
+                state
+                    .exact_index_columns
+                    .iter()
+                    .map(|column| arrow::array::new_null_array(column.field.data_type(), 1)),
+            )
+            .collect()
+    }
+
+    /// Resolves the value to use in place of a `null` cell of the given component column, at the
+    /// given index value.
+    ///
+    /// `lookahead` must be the cursor of that specific column.
+    ///
+    /// See [`QueryExpression::sparse_fill_strategy`].
+    fn sparse_fill(
+        &self,
+        store: &ChunkStore,
+        cache: &QueryCache,
+        filtered_index: TimelineName,
+        descr: &ComponentColumnDescriptor,
+        index_value: TimeInt,
+        lookahead: &mut LookaheadCursor,
+    ) -> Option<UnitChunkShared> {
+        let range = |query: &RangeQuery| {
+            self.range(
+                store,
+                cache,
+                query,
+                &descr.entity_path,
+                [&ComponentDescriptor::from(descr.clone())],
+            )
+        };
+
+        let latest_at = || {
+            let query = re_chunk::LatestAtQuery::new(filtered_index, index_value);
+
+            let results = self.latest_at(
+                cache,
+                &query,
+                &descr.entity_path,
+                // TODO(#6889): We don't allow passing in full descriptors to dataframe queries yet. Everything works via component name.
+                //[ComponentDescriptor::from(descr.clone())],
+                descr.component_name,
+            );
+
+            results.components.into_values().next()
+        };
+
+        match self.query.sparse_fill_strategy {
+            SparseFillStrategy::None => None,
+
+            SparseFillStrategy::LatestAtGlobal => latest_at(),
+
+            SparseFillStrategy::LatestAtView => {
+                let unit = latest_at()?;
+                if unit.is_static() {
+                    return Some(unit);
+                }
+
+                let (time, _row_id) = unit.index(&filtered_index)?;
+                let range_start = self
+                    .query
+                    .filtered_index_range
+                    .map_or(TimeInt::MIN, |range| range.min());
+
+                (time >= range_start).then_some(unit)
+            }
+
+            SparseFillStrategy::Linear => {
+                let before = latest_at()?;
+                if before.is_static() {
+                    return Some(before);
+                }
+
+                // NOTE: This may look past the end of the view, so that the last rows of the view
+                // still get interpolated rather than held.
+                let component_descr = ComponentDescriptor::from(descr.clone());
+                let interpolated = lookahead
+                    .earliest_after(
+                        range,
+                        filtered_index,
+                        index_value,
+                        u64::MAX,
+                        self.query.filtered_index_range,
+                    )
+                    .and_then(|after| {
+                        lerp_unit_chunks(
+                            &before,
+                            &after,
+                            &filtered_index,
+                            index_value,
+                            &component_descr,
+                        )
+                    });
+
+                interpolated.or(Some(before))
+            }
+
+            SparseFillStrategy::Nearest { max_gap } => {
+                let max_gap = max_gap.unwrap_or(u64::MAX);
+
+                let before = latest_at();
+                if let Some(before) = before.as_ref().filter(|unit| unit.is_static()) {
+                    return Some(before.clone());
+                }
+
+                let after = lookahead.earliest_after(
+                    range,
+                    filtered_index,
+                    index_value,
+                    max_gap,
+                    self.query.filtered_index_range,
+                );
+
+                // NOTE: `min_by_key` returns the first minimum, i.e. ties favor the preceding value.
+                [before, after]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|unit| {
+                        let (time, _row_id) = unit.index(&filtered_index)?;
+                        let gap = time.as_i64().abs_diff(index_value.as_i64());
+                        (gap <= max_gap).then_some((gap, unit))
+                    })
+                    .min_by_key(|(gap, _unit)| *gap)
+                    .map(|(_gap, unit)| unit)
+            }
+        }
+    }
+}
+
 impl<E: StorageEngineLike> QueryHandle<E> {
     /// Returns an iterator backed by [`Self::next_row`].
     #[allow(clippy::should_implement_trait)] // we need an anonymous closure, this won't work
@@ -1296,54 +1739,651 @@ impl<E: StorageEngineLike> QueryHandle<E> {
     }
 }
 
-// ---
+/// Walks over the cells of a component column one bucket at a time, see [`Bucketing`].
+///
+/// Buckets are visited in ascending index order: a chunk is only looked at once the buckets have
+/// reached its first index value, and is dropped as soon as they are past its last one.
+/// Within each chunk, rows are consumed through the chunk's cursor in
+/// [`QueryHandleState::view_chunks`], just like for regular rows, so that every row is visited
+/// once overall.
+#[derive(Default)]
+struct BucketCursor {
+    /// The chunks that the buckets haven't reached yet, by index in the view chunks.
+    next_chunk: usize,
+
+    /// The chunks that the buckets are currently going through, by index in the view chunks.
+    active_chunks: Vec<usize>,
+
+    /// The start of the last bucket, to detect seeking.
+    last_bucket_start: Option<TimeInt>,
+}
 
-#[cfg(test)]
-#[allow(clippy::iter_on_single_items)]
-mod tests {
-    use std::sync::Arc;
+impl BucketCursor {
+    /// Returns all the cells of the column whose value on the `filtered_index` falls within
+    /// `range`, sorted by index value.
+    ///
+    /// Just like for regular rows, the data with the highest [`RowId`] wins when there are several
+    /// cells at the same index value.
+    fn bucket_cells(
+        &mut self,
+        view_chunks: &[(AtomicU64, Chunk)],
+        filtered_index: &TimelineName,
+        range: ResolvedTimeRange,
+    ) -> Vec<ArrowListArray> {
+        if self
+            .last_bucket_start
+            .is_some_and(|last_bucket_start| range.min() <= last_bucket_start)
+        {
+            // Seeking backwards: the chunk cursors were reset, so start over.
+            *self = Self::default();
+        }
+        self.last_bucket_start = Some(range.min());
+
+        // NOTE: View chunks are always sorted on their first value on the filtered index.
+        while let Some((_cursor, chunk)) = view_chunks.get(self.next_chunk) {
+            let time_range = chunk
+                .timelines()
+                .get(filtered_index)
+                .map(|time_column| time_column.time_range());
+            if time_range.is_some_and(|time_range| range.max() < time_range.min()) {
+                break;
+            }
 
-    use arrow::compute::concat_batches;
-    use insta::assert_snapshot;
+            if time_range.is_some() {
+                self.active_chunks.push(self.next_chunk);
+            }
+            self.next_chunk += 1;
+        }
 
-    use re_chunk::{Chunk, ChunkId, RowId, TimePoint};
-    use re_chunk_store::{
-        ChunkStore, ChunkStoreConfig, ChunkStoreHandle, ResolvedTimeRange, TimeInt,
-    };
-    use re_format_arrow::format_record_batch;
-    use re_log_types::{
-        build_frame_nr, build_log_time,
-        example_components::{MyColor, MyLabel, MyPoint, MyPoints},
-        EntityPath, Timeline,
-    };
-    use re_query::StorageEngine;
-    use re_types_core::{components, Component as _};
+        let mut indices = Vec::new();
+        self.active_chunks.retain(|chunk_idx| {
+            let (cursor, chunk) = &view_chunks[*chunk_idx];
+            let Some(times) = chunk
+                .timelines()
+                .get(filtered_index)
+                .map(|time_column| time_column.times_raw())
+            else {
+                return false;
+            };
 
-    use crate::{QueryCache, QueryEngine};
+            // NOTE: View chunks are always sorted on the filtered index.
+            // Rows before the bucket (e.g. after seeking forward) are skipped.
+            let cursor_value = (cursor.load(Ordering::Relaxed) as usize).min(times.len());
+            let start = cursor_value
+                + times[cursor_value..].partition_point(|time| *time < range.min().as_i64());
+            let end = start + times[start..].partition_point(|time| *time <= range.max().as_i64());
+            cursor.store(end as u64, Ordering::Relaxed);
+
+            indices.extend((start..end).map(|row_idx| {
+                (
+                    (times[row_idx], chunk.row_ids_slice()[row_idx]),
+                    chunk,
+                    row_idx,
+                )
+            }));
+
+            end < times.len()
+        });
 
-    use super::*;
+        indices.sort_by_key(|(index, _chunk, _row_idx)| *index);
 
-    /// Implement `Display` for `ArrowRecordBatch`
-    struct DisplayRB(ArrowRecordBatch);
+        let mut cells: Vec<(i64, ArrowListArray)> = Vec::with_capacity(indices.len());
+        for ((time, _row_id), chunk, row_idx) in indices {
+            let Some((_, list_array)) = chunk.components().iter().next() else {
+                continue;
+            };
 
-    impl std::fmt::Display for DisplayRB {
-        #[inline]
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let width = 200;
-            re_format_arrow::format_record_batch_with_width(&self.0, Some(width)).fmt(f)
+            let cell = list_array.slice(row_idx, 1);
+            match cells.last_mut() {
+                // Sorted by `RowId` too: the last one wins.
+                Some((last_time, last_cell)) if *last_time == time => *last_cell = cell,
+                _ => cells.push((time, cell)),
+            }
         }
+
+        cells.into_iter().map(|(_time, cell)| cell).collect()
     }
+}
 
-    // NOTE: The best way to understand what these tests are doing is to run them in verbose mode,
-    // e.g. `cargo t -p re_dataframe -- --show-output barebones`.
-    // Each test will print the state of the store, the query being run, and the results that were
-    // returned in the usual human-friendly format.
-    // From there it is generally straightforward to infer what's going on.
+/// The descriptor of the column holding the [`Aggregation::Count`] of the given component column.
+///
+/// Rather than lists of component instances, such a column holds a single `UInt64` per bucket.
+fn count_descriptor(descr: ComponentColumnDescriptor) -> ComponentColumnDescriptor {
+    ComponentColumnDescriptor {
+        store_datatype: ArrowDataType::UInt64,
+        is_semantically_empty: false,
+        ..descr
+    }
+}
 
-    // TODO(cmc): at least one basic test for every feature in `QueryExpression`.
-    // In no particular order:
-    // * [x] filtered_index
-    // * [x] filtered_index_range
+/// Aggregates all the `cells` of a bucket into a single one, of the given `datatype`.
+///
+/// Returns `None` if the result is `null`.
+fn aggregate_cells(
+    cells: &[ArrowListArray],
+    aggregation: Aggregation,
+    datatype: &ArrowDataType,
+) -> Option<ArrowArrayRef> {
+    match aggregation {
+        Aggregation::Count => {
+            let num_instances: usize = cells
+                .iter()
+                .filter(|cell| !cell.is_null(0))
+                .map(|cell| {
+                    let instances = cell.value(0);
+                    instances.len() - instances.null_count()
+                })
+                .sum();
+
+            Some(into_arrow_ref(ArrowUInt64Array::from(vec![
+                num_instances as u64,
+            ])))
+        }
+
+        Aggregation::First => cells.first().cloned().map(into_arrow_ref),
+
+        Aggregation::Last => cells.last().cloned().map(into_arrow_ref),
+
+        Aggregation::Min | Aggregation::Max | Aggregation::Mean => {
+            let ArrowDataType::List(field) = datatype else {
+                return None;
+            };
+
+            let instances = cells
+                .iter()
+                .filter(|cell| !cell.is_null(0))
+                .map(|cell| cell.value(0));
+            let values = reduce_instances(instances, aggregation, field.data_type())?;
+
+            ArrowListArray::try_new(
+                field.clone(),
+                ArrowOffsetBuffer::from_lengths([values.len()]),
+                values,
+                None,
+            )
+            .ok()
+            .map(into_arrow_ref)
+        }
+    }
+}
+
+/// Reduces all the given instances into a single one, element-wise.
+///
+/// Only numeric data and fixed-size lists thereof are supported.
+///
+/// [`Aggregation::Min`] and [`Aggregation::Max`] are computed in the native domain of the data
+/// (i.e. as `i64`, `u64` or `f64`), so that large integers don't lose any precision.
+/// Only [`Aggregation::Mean`] goes through `f64`.
+fn reduce_instances(
+    instances: impl Iterator<Item = ArrowArrayRef>,
+    aggregation: Aggregation,
+    datatype: &ArrowDataType,
+) -> Option<ArrowArrayRef> {
+    let (num_lanes, leaf_datatype) = match datatype {
+        ArrowDataType::FixedSizeList(field, size) => (*size as usize, field.data_type()),
+        datatype => (1, datatype),
+    };
+    if num_lanes == 0 || !(leaf_datatype.is_integer() || leaf_datatype.is_floating()) {
+        return None;
+    }
+
+    let leaves = instances
+        .map(|instances| match datatype {
+            ArrowDataType::FixedSizeList(_, _) => instances
+                .downcast_array_ref::<ArrowFixedSizeListArray>()
+                .map(|list_array| list_array.values().clone()),
+            _ => Some(instances),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let reduced = match aggregation {
+        Aggregation::Min | Aggregation::Max if leaf_datatype.is_floating() => {
+            reduce_extrema::<arrow::datatypes::Float64Type>(&leaves, num_lanes, aggregation)?
+        }
+
+        Aggregation::Min | Aggregation::Max if leaf_datatype.is_unsigned_integer() => {
+            reduce_extrema::<arrow::datatypes::UInt64Type>(&leaves, num_lanes, aggregation)?
+        }
+
+        Aggregation::Min | Aggregation::Max => {
+            reduce_extrema::<arrow::datatypes::Int64Type>(&leaves, num_lanes, aggregation)?
+        }
+
+        _ => reduce_mean(&leaves, num_lanes, leaf_datatype.is_integer())?,
+    };
+
+    // NOTE: Casting always yields a validity buffer, which non-nullable fields don't tolerate.
+    let leaves = arrow::compute::cast(&reduced, leaf_datatype).ok()?;
+    let leaves =
+        arrow::array::make_array(leaves.into_data().into_builder().nulls(None).build().ok()?);
+
+    match datatype {
+        ArrowDataType::FixedSizeList(field, size) => {
+            ArrowFixedSizeListArray::try_new(field.clone(), *size, leaves, None)
+                .ok()
+                .map(into_arrow_ref)
+        }
+        _ => Some(leaves),
+    }
+}
+
+/// Computes the element-wise minimum or maximum of the given `leaves`, as `T`.
+///
+/// The leaves of every instance are laid out contiguously, `num_lanes` at a time.
+/// Returns `None` if any lane ends up without a single value.
+fn reduce_extrema<T: arrow::datatypes::ArrowPrimitiveType>(
+    leaves: &[ArrowArrayRef],
+    num_lanes: usize,
+    aggregation: Aggregation,
+) -> Option<ArrowArrayRef> {
+    let mut extrema: Vec<Option<T::Native>> = vec![None; num_lanes];
+
+    for leaves in leaves {
+        let leaves = arrow::compute::cast(leaves, &T::DATA_TYPE).ok()?;
+        let leaves = leaves.downcast_array_ref::<ArrowPrimitiveArray<T>>()?;
+        for (idx, value) in leaves.iter().enumerate() {
+            // NOTE: NaNs are ignored, just like `f64::min` and `f64::max` do.
+            let Some(value) = value.filter(|value| value.partial_cmp(value).is_some()) else {
+                continue;
+            };
+
+            let extremum = &mut extrema[idx % num_lanes];
+            let is_new_extremum = extremum.is_none_or(|extremum| match aggregation {
+                Aggregation::Min => value < extremum,
+                _ => value > extremum,
+            });
+            if is_new_extremum {
+                *extremum = Some(value);
+            }
+        }
+    }
+
+    let extrema = extrema.into_iter().collect::<Option<Vec<_>>>()?;
+
+    Some(into_arrow_ref(ArrowPrimitiveArray::<T>::from_iter_values(
+        extrema,
+    )))
+}
+
+/// Computes the element-wise mean of the given `leaves`, as `f64`.
+///
+/// The leaves of every instance are laid out contiguously, `num_lanes` at a time.
+/// Returns `None` if any lane ends up without a single value.
+fn reduce_mean(leaves: &[ArrowArrayRef], num_lanes: usize, round: bool) -> Option<ArrowArrayRef> {
+    let mut sums = vec![(0.0, 0u64); num_lanes];
+
+    for leaves in leaves {
+        let leaves = arrow::compute::cast(leaves, &ArrowDataType::Float64).ok()?;
+        let leaves = leaves.downcast_array_ref::<ArrowFloat64Array>()?;
+        for (idx, value) in leaves.iter().enumerate() {
+            let Some(value) = value else {
+                continue;
+            };
+
+            let (sum, count) = &mut sums[idx % num_lanes];
+            *sum += value;
+            *count += 1;
+        }
+    }
+
+    if sums.iter().any(|(_sum, count)| *count == 0) {
+        return None;
+    }
+
+    Some(into_arrow_ref(ArrowFloat64Array::from_iter_values(
+        sums.into_iter().map(|(sum, count)| {
+            let mean = sum / count as f64;
+            if round {
+                mean.round()
+            } else {
+                mean
+            }
+        }),
+    )))
+}
+
+/// Same as [`QueryCache::range`] for a single component, but without ever touching the cache.
+///
+/// See [`QueryCachePolicy::Bypass`].
+fn range_uncached<'a>(
+    store: &ChunkStore,
+    query: &RangeQuery,
+    entity_path: &EntityPath,
+    component_descrs: impl IntoIterator<Item = &'a ComponentDescriptor>,
+) -> Option<Vec<Chunk>> {
+    let component_descr = component_descrs.into_iter().next()?;
+
+    // TODO(#6889): Just like the cache, we ignore everything but the component name for now.
+    let component_descr = store
+        .entity_component_descriptors_with_name(entity_path, component_descr.component_name)
+        .into_iter()
+        .next()?;
+
+    let chunks = store
+        .range_relevant_chunks(query, entity_path, &component_descr)
+        .into_iter()
+        .map(|chunk| {
+            chunk
+                .densified(&component_descr)
+                .sorted_by_timeline_if_unsorted(query.timeline())
+                .range(query, &component_descr)
+        })
+        .filter(|chunk| !chunk.is_empty())
+        .collect_vec();
+
+    (!chunks.is_empty()).then_some(chunks)
+}
+
+/// Walks over the upcoming values of a component column, for the sparse-fill strategies that
+/// need to look ahead (i.e. [`SparseFillStrategy::Linear`] and [`SparseFillStrategy::Nearest`]).
+///
+/// Rows are yielded in ascending index order: rather than running a range query for every single
+/// null cell, the values that follow are loaded once, one window at a time, and then dropped as
+/// the rows go past them.
+struct LookaheadCursor {
+    /// All the values in `(start, loaded_until]` that haven't been passed yet.
+    ///
+    /// Sorted by index value, and deduped: the data with the highest [`RowId`] wins, just like for
+    /// latest-at queries.
+    values: VecDeque<(TimeInt, Arc<Chunk>, usize)>,
+
+    start: TimeInt,
+    loaded_until: TimeInt,
+
+    /// The size of the next window to load.
+    ///
+    /// It doubles after every load, so that reaching a value far away only takes a logarithmic
+    /// number of queries. `0` until the cursor is first used.
+    window: u64,
+}
+
+impl Default for LookaheadCursor {
+    fn default() -> Self {
+        Self {
+            values: VecDeque::new(),
+            start: TimeInt::MIN,
+            loaded_until: TimeInt::MIN,
+            window: 0,
+        }
+    }
+}
+
+impl LookaheadCursor {
+    /// Returns the earliest value of the given component column that is strictly after
+    /// `index_value` on the `filtered_index`, as long as it is at most `max_gap` away.
+    ///
+    /// `range` runs the actual range query for that column.
+    /// The first window spans the rest of the `view_range`, if any.
+    fn earliest_after(
+        &mut self,
+        range: impl Fn(&RangeQuery) -> Option<Vec<Chunk>>,
+        filtered_index: TimelineName,
+        index_value: TimeInt,
+        max_gap: u64,
+        view_range: Option<ResolvedTimeRange>,
+    ) -> Option<UnitChunkShared> {
+        let max_gap = i64::try_from(max_gap).unwrap_or(i64::MAX);
+        let end = TimeInt::new_temporal(index_value.as_i64().saturating_add(max_gap));
+
+        if self.window == 0 || index_value < self.start {
+            // First use, or seeking backwards: start over.
+            let view_end = view_range.map_or(TimeInt::MAX, |range| range.max());
+            *self = Self {
+                values: VecDeque::new(),
+                start: index_value,
+                loaded_until: index_value,
+                window: view_end
+                    .as_i64()
+                    .saturating_sub(index_value.as_i64())
+                    .clamp(1, i64::MAX) as u64,
+            };
+        } else if self.loaded_until < index_value {
+            // Seeking forward, past everything that was loaded.
+            self.values.clear();
+            self.start = index_value;
+            self.loaded_until = index_value;
+        }
+
+        loop {
+            while self
+                .values
+                .front()
+                .is_some_and(|(time, _chunk, _row_idx)| *time <= index_value)
+            {
+                self.values.pop_front();
+            }
+
+            if let Some((time, chunk, row_idx)) = self.values.front() {
+                return (*time <= end)
+                    .then(|| chunk.row_sliced(*row_idx, 1).into_unit())
+                    .flatten();
+            }
+
+            if self.loaded_until >= end || self.loaded_until == TimeInt::MAX {
+                return None;
+            }
+
+            self.load_next_window(&range, filtered_index);
+        }
+    }
+
+    fn load_next_window(
+        &mut self,
+        range: impl Fn(&RangeQuery) -> Option<Vec<Chunk>>,
+        filtered_index: TimelineName,
+    ) {
+        re_tracing::profile_function!();
+
+        let window = i64::try_from(self.window).unwrap_or(i64::MAX);
+        let time_range = ResolvedTimeRange::new(
+            self.loaded_until.inc(),
+            TimeInt::new_temporal(self.loaded_until.as_i64().saturating_add(window)),
+        );
+        let query = RangeQuery::new(filtered_index, time_range);
+
+        let mut values = range(&query)
+            .into_iter()
+            .flatten()
+            .flat_map(|chunk| {
+                let chunk = Arc::new(chunk);
+                chunk
+                    .iter_indices(&filtered_index)
+                    .enumerate()
+                    .map(|(row_idx, (time, row_id))| (time, row_id, chunk.clone(), row_idx))
+                    .collect_vec()
+            })
+            .collect_vec();
+        values.sort_by_key(|(time, row_id, _chunk, _row_idx)| (*time, std::cmp::Reverse(*row_id)));
+        values.dedup_by_key(|(time, _row_id, _chunk, _row_idx)| *time);
+
+        self.values.extend(
+            values
+                .into_iter()
+                .map(|(time, _row_id, chunk, row_idx)| (time, chunk, row_idx)),
+        );
+        self.loaded_until = time_range.max();
+        self.window = self.window.saturating_mul(2);
+    }
+}
+
+/// Linearly interpolates the `component_descr` cells of `before` and `after`, at `index_value` on
+/// the `filtered_index`.
+///
+/// The resulting unit chunk shares the index values of `before`, except for the `filtered_index`.
+///
+/// Returns `None` if the data cannot be interpolated (non-numeric data, different number of
+/// instances, etc).
+fn lerp_unit_chunks(
+    before: &UnitChunkShared,
+    after: &UnitChunkShared,
+    filtered_index: &TimelineName,
+    index_value: TimeInt,
+    component_descr: &ComponentDescriptor,
+) -> Option<UnitChunkShared> {
+    let (before_time, _row_id) = before.index(filtered_index)?;
+    let (after_time, _row_id) = after.index(filtered_index)?;
+    if before.is_static() || after.is_static() || after_time <= before_time {
+        return None;
+    }
+
+    let t = (index_value.as_i64() as f64 - before_time.as_i64() as f64)
+        / (after_time.as_i64() as f64 - before_time.as_i64() as f64);
+
+    let before_list_array = before.components().get(component_descr)?;
+    let after_list_array = after.components().get(component_descr)?;
+    if before_list_array.is_null(0) || after_list_array.is_null(0) {
+        return None;
+    }
+
+    let ArrowDataType::List(field) = before_list_array.data_type() else {
+        return None;
+    };
+
+    let values = lerp_arrays(&before_list_array.value(0), &after_list_array.value(0), t)?;
+    let list_array = ArrowListArray::try_new(
+        field.clone(),
+        ArrowOffsetBuffer::from_lengths([values.len()]),
+        values,
+        None,
+    )
+    .ok()?;
+
+    let mut timelines = before.timelines().clone();
+    if let Some(time_column) = timelines.get_mut(filtered_index) {
+        *time_column = TimeColumn::new(
+            Some(true),
+            *time_column.timeline(),
+            ArrowScalarBuffer::from(vec![index_value.as_i64()]),
+        );
+    }
+
+    Chunk::new(
+        ChunkId::new(),
+        before.entity_path().clone(),
+        Some(true),
+        before.row_ids_array().clone(),
+        timelines,
+        std::iter::once((component_descr.clone(), list_array)).collect(),
+    )
+    .ok()?
+    .into_unit()
+}
+
+/// Linearly interpolates between two arrays of floats, or fixed-size lists/structs thereof.
+///
+/// Returns `None` if the arrays cannot be interpolated.
+fn lerp_arrays(a: &ArrowArrayRef, b: &ArrowArrayRef, t: f64) -> Option<ArrowArrayRef> {
+    if a.data_type() != b.data_type()
+        || a.len() != b.len()
+        || a.null_count() > 0
+        || b.null_count() > 0
+    {
+        return None;
+    }
+
+    match a.data_type() {
+        ArrowDataType::FixedSizeList(field, size) => {
+            let a = a.downcast_array_ref::<ArrowFixedSizeListArray>()?;
+            let b = b.downcast_array_ref::<ArrowFixedSizeListArray>()?;
+            let values = lerp_arrays(a.values(), b.values(), t)?;
+
+            ArrowFixedSizeListArray::try_new(field.clone(), *size, values, None)
+                .ok()
+                .map(into_arrow_ref)
+        }
+
+        ArrowDataType::Struct(fields) => {
+            let a = a.downcast_array_ref::<ArrowStructArray>()?;
+            let b = b.downcast_array_ref::<ArrowStructArray>()?;
+            let columns = a
+                .columns()
+                .iter()
+                .zip(b.columns())
+                .map(|(a, b)| lerp_arrays(a, b, t))
+                .collect::<Option<Vec<_>>>()?;
+
+            ArrowStructArray::try_new(fields.clone(), columns, None)
+                .ok()
+                .map(into_arrow_ref)
+        }
+
+        ArrowDataType::Float32 => {
+            let a = a.downcast_array_ref::<ArrowFloat32Array>()?;
+            let b = b.downcast_array_ref::<ArrowFloat32Array>()?;
+            let t = t as f32;
+
+            Some(into_arrow_ref(ArrowFloat32Array::from_iter_values(
+                a.values()
+                    .iter()
+                    .zip(b.values())
+                    .map(|(a, b)| a + (b - a) * t),
+            )))
+        }
+
+        ArrowDataType::Float64 => {
+            let a = a.downcast_array_ref::<ArrowFloat64Array>()?;
+            let b = b.downcast_array_ref::<ArrowFloat64Array>()?;
+
+            Some(into_arrow_ref(ArrowFloat64Array::from_iter_values(
+                a.values()
+                    .iter()
+                    .zip(b.values())
+                    .map(|(a, b)| a + (b - a) * t),
+            )))
+        }
+
+        _ => None,
+    }
+}
+
+// ---
+
+#[cfg(test)]
+#[allow(clippy::iter_on_single_items)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::compute::concat_batches;
+    use insta::assert_snapshot;
+
+    use re_chunk::{Chunk, ChunkId, RowId, TimePoint};
+    use re_chunk_store::{
+        ChunkStore, ChunkStoreConfig, ChunkStoreHandle, ResolvedTimeRange, TimeInt,
+    };
+    use re_format_arrow::format_record_batch;
+    use re_log_types::{
+        build_frame_nr, build_log_time,
+        example_components::{MyColor, MyLabel, MyPoint, MyPoints},
+        EntityPath, Timeline,
+    };
+    use re_query::StorageEngine;
+    use re_types_core::{components, Component as _};
+
+    use crate::{QueryCache, QueryEngine};
+
+    use super::*;
+
+    /// Implement `Display` for `ArrowRecordBatch`
+    struct DisplayRB(ArrowRecordBatch);
+
+    impl std::fmt::Display for DisplayRB {
+        #[inline]
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let width = 200;
+            re_format_arrow::format_record_batch_with_width(&self.0, Some(width)).fmt(f)
+        }
+    }
+
+    // NOTE: The best way to understand what these tests are doing is to run them in verbose mode,
+    // e.g. `cargo t -p re_dataframe -- --show-output barebones`.
+    // Each test will print the state of the store, the query being run, and the results that were
+    // returned in the usual human-friendly format.
+    // From there it is generally straightforward to infer what's going on.
+
+    // TODO(cmc): at least one basic test for every feature in `QueryExpression`.
+    // In no particular order:
+    // * [x] filtered_index
+    // * [x] filtered_index_range
     // * [x] filtered_index_values
     // * [x] view_contents
     // * [x] selection
@@ -1450,6 +2490,413 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sparse_fill_strategies() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let entity_path = EntityPath::from("/this/that");
+
+        let points1 = MyPoint::from_iter(0..1);
+        let points3 = MyPoint::from_iter(2..3);
+        let points5 = MyPoint::from_iter(4..5);
+
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(10))],
+                [(MyPoints::descriptor_points(), Some(&points1 as _))],
+            )
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(30))],
+                [(MyPoints::descriptor_points(), Some(&points3 as _))],
+            )
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(50))],
+                [(MyPoints::descriptor_points(), Some(&points5 as _))],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+
+        let store = ChunkStoreHandle::new(store);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let filtered_index = Some(TimelineName::new("frame_nr"));
+
+        // NOTE: With `Linear`, frame 40 is interpolated between frames 30 and 50, even though
+        // frame 50 is past the end of the view.
+        for sparse_fill_strategy in [
+            SparseFillStrategy::LatestAtGlobal,
+            SparseFillStrategy::LatestAtView,
+            SparseFillStrategy::Linear,
+            SparseFillStrategy::Nearest { max_gap: None },
+            SparseFillStrategy::Nearest { max_gap: Some(5) },
+        ] {
+            let query = QueryExpression {
+                filtered_index,
+                filtered_index_range: Some(ResolvedTimeRange::new(15, 45)),
+                using_index_values: Some(
+                    [15, 20, 25, 30, 40]
+                        .into_iter()
+                        .map(TimeInt::new_temporal)
+                        .collect(),
+                ),
+                sparse_fill_strategy: sparse_fill_strategy.clone(),
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(
+                query_engine.query(query.clone()).into_iter().count() as u64,
+                query_handle.num_rows()
+            );
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            assert_snapshot!(DisplayRB(dataframe));
+        }
+
+        Ok(())
+    }
+
+    /// Many samples and many null rows, so that lookahead windows get reloaded and rows can be
+    /// revisited.
+    #[test]
+    fn sparse_fill_linear_lookahead() -> anyhow::Result<()> {
+        use re_types_core::Loggable as _;
+
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        // `x == frame_nr` on every even frame.
+        let mut builder = Chunk::builder(EntityPath::from("/this/that"));
+        for frame in (0..200).step_by(2) {
+            builder = builder.with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(frame))],
+                [(
+                    MyPoints::descriptor_points(),
+                    Some(&[MyPoint::new(frame as f32, 0.0)] as _),
+                )],
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+
+        let store = ChunkStoreHandle::new(store);
+        let query_engine = QueryEngine::new(store.clone(), QueryCache::new_handle(store));
+
+        // Only the odd frames, which are all nulls. The last one can only be interpolated using a
+        // value that is past the end of the view.
+        let odd_frames = (1..50).step_by(2).collect_vec();
+        let query_handle = query_engine.query(QueryExpression {
+            filtered_index: Some(TimelineName::new("frame_nr")),
+            filtered_index_range: Some(ResolvedTimeRange::new(0, 49)),
+            using_index_values: Some(
+                odd_frames
+                    .iter()
+                    .copied()
+                    .map(TimeInt::new_temporal)
+                    .collect(),
+            ),
+            sparse_fill_strategy: SparseFillStrategy::Linear,
+            ..Default::default()
+        });
+
+        let x_at_row = |row: Vec<ArrowArrayRef>| {
+            let cell = row[1]
+                .downcast_array_ref::<ArrowListArray>()
+                .unwrap()
+                .value(0);
+            MyPoint::from_arrow(&cell).unwrap()[0].x
+        };
+
+        let xs = query_handle.iter().map(x_at_row).collect_vec();
+        let expected = odd_frames.iter().map(|frame| *frame as f32).collect_vec();
+        assert_eq!(xs, expected);
+
+        // Seeking backwards must start over from the right place.
+        query_handle.seek_to_row(3);
+        let xs = query_handle.iter().map(x_at_row).collect_vec();
+        assert_eq!(xs, expected[3..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn index_value_policy() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        // The points and colors of a same row (on `frame_nr`) were logged at different `log_time`s.
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let entity_path = EntityPath::from("/this/that");
+
+        let frame10 = TimeInt::new_temporal(10);
+        let frame20 = TimeInt::new_temporal(20);
+        let time100 = TimeInt::new_temporal(100);
+        let time50 = TimeInt::new_temporal(50);
+
+        let points1 = MyPoint::from_iter(0..1);
+        let colors2 = MyColor::from_iter(1..2);
+
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(frame10), build_log_time(time100.into())],
+                [(MyPoints::descriptor_points(), Some(&points1 as _))],
+            )
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(frame20), build_log_time(time50.into())],
+                [(MyPoints::descriptor_colors(), Some(&colors2 as _))],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+
+        let store = ChunkStoreHandle::new(store);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let filtered_index = Some(TimelineName::new("frame_nr"));
+
+        for index_value_policy in [
+            IndexValuePolicy::Max,
+            IndexValuePolicy::Min,
+            IndexValuePolicy::ExactPerComponent,
+        ] {
+            let query = QueryExpression {
+                filtered_index,
+                sparse_fill_strategy: SparseFillStrategy::LatestAtGlobal,
+                index_value_policy,
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(
+                query_engine.query(query.clone()).into_iter().count() as u64,
+                query_handle.num_rows()
+            );
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            // The extra columns must not be mistaken for index columns.
+            let columns = re_sorbet::SorbetColumnDescriptors::try_from_arrow_fields(
+                None,
+                dataframe.schema_ref().fields(),
+            )?;
+            assert_eq!(columns.indices.len(), 2);
+
+            assert_snapshot!(DisplayRB(dataframe));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bucketing() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let store = ChunkStoreHandle::new(create_nasty_store()?);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let entity_path: EntityPath = "this/that".into();
+        let points = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component_name: MyPoint::name().to_string(),
+        };
+        let colors = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component_name: MyColor::name().to_string(),
+        };
+
+        let filtered_index = Some(TimelineName::new("frame_nr"));
+        #[allow(clippy::unwrap_used)]
+        let width = std::num::NonZeroU64::new(20).unwrap();
+
+        for bucketing in [
+            Bucketing::new(width)
+                .with_aggregation(points.clone(), Aggregation::Count)
+                .with_aggregation(colors.clone(), Aggregation::Max),
+            Bucketing::new(width)
+                .with_aggregation(points.clone(), Aggregation::First)
+                .with_aggregation(colors.clone(), Aggregation::Mean),
+        ] {
+            let query = QueryExpression {
+                filtered_index,
+                bucketing: Some(bucketing),
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(
+                query_engine.query(query.clone()).into_iter().count() as u64,
+                query_handle.num_rows()
+            );
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            assert_snapshot!(DisplayRB(dataframe));
+
+            // Count columns are described as such, not as their source component.
+            for (field, (_, descr)) in query_handle
+                .schema()
+                .fields()
+                .iter()
+                .zip(query_handle.selected_contents())
+            {
+                let ColumnDescriptor::Component(descr) = descr else {
+                    continue;
+                };
+                let is_count = query.bucketing.as_ref().is_some_and(|bucketing| {
+                    bucketing.aggregation_for(descr) == Aggregation::Count
+                });
+                assert_eq!(
+                    is_count,
+                    descr.store_datatype == ArrowDataType::UInt64,
+                    "{descr:?}"
+                );
+                assert_eq!(field.data_type(), &descr.store_datatype);
+            }
+
+            // Seeking yields the same buckets, in both directions.
+            let rows = query_engine.query(query.clone()).into_iter().collect_vec();
+            for row_idx in (0..rows.len()).rev().chain(0..rows.len()) {
+                query_handle.seek_to_row(row_idx);
+                assert_eq!(
+                    query_handle.next_row().as_ref(),
+                    Some(&rows[row_idx]),
+                    "row #{row_idx}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reduce_instances_integer_precision() {
+        use arrow::array::{Int64Array, UInt64Array};
+
+        // None of these survive a round-trip through `f64`.
+        let signed = [i64::MAX - 2, i64::MAX, i64::MAX - 1, i64::MIN + 1]
+            .map(|value| into_arrow_ref(Int64Array::from(vec![value])));
+        let unsigned = [u64::MAX - 2, u64::MAX, u64::MAX - 1]
+            .map(|value| into_arrow_ref(UInt64Array::from(vec![value])));
+
+        let reduce_signed = |aggregation| {
+            let reduced =
+                reduce_instances(signed.iter().cloned(), aggregation, &ArrowDataType::Int64)
+                    .unwrap();
+            reduced.downcast_array_ref::<Int64Array>().unwrap().values()[0]
+        };
+        assert_eq!(i64::MIN + 1, reduce_signed(Aggregation::Min));
+        assert_eq!(i64::MAX, reduce_signed(Aggregation::Max));
+
+        let reduce_unsigned = |aggregation| {
+            let reduced = reduce_instances(
+                unsigned.iter().cloned(),
+                aggregation,
+                &ArrowDataType::UInt64,
+            )
+            .unwrap();
+            reduced
+                .downcast_array_ref::<UInt64Array>()
+                .unwrap()
+                .values()[0]
+        };
+        assert_eq!(u64::MAX - 2, reduce_unsigned(Aggregation::Min));
+        assert_eq!(u64::MAX, reduce_unsigned(Aggregation::Max));
+    }
+
+    #[test]
+    fn cache_policy() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let store = ChunkStoreHandle::new(create_nasty_store()?);
+        eprintln!("{store}");
+
+        // Sparse-filling runs its own latest-at and range queries, row by row.
+        for sparse_fill_strategy in [SparseFillStrategy::None, SparseFillStrategy::Linear] {
+            let query = QueryExpression {
+                filtered_index: Some(TimelineName::new("frame_nr")),
+                sparse_fill_strategy,
+                ..Default::default()
+            };
+
+            let mut dataframes = Vec::new();
+            for cache_policy in [
+                QueryCachePolicy::Cached,
+                QueryCachePolicy::Bypass,
+                QueryCachePolicy::Bounded { max_bytes: 0 },
+            ] {
+                let query_cache = QueryCache::new_handle(store.clone());
+                let query_engine = QueryEngine::new(store.clone(), query_cache.clone())
+                    .with_cache_policy(cache_policy);
+
+                let query_handle = query_engine.query(query.clone());
+                let dataframe = concat_batches(
+                    query_handle.schema(),
+                    &query_handle.batch_iter().collect_vec(),
+                )?;
+                dataframes.push(DisplayRB(dataframe).to_string());
+
+                let stats = query_handle.cache_stats();
+                eprintln!(
+                    "{:?} {cache_policy}: {stats:#?}",
+                    query.sparse_fill_strategy
+                );
+                match cache_policy {
+                    QueryCachePolicy::Cached => {
+                        assert!(!stats.range.is_empty());
+                        assert!(stats.total_misses() > 0);
+                    }
+                    QueryCachePolicy::Bypass => {
+                        assert!(stats.latest_at.is_empty());
+                        assert!(stats.range.is_empty());
+                    }
+                    QueryCachePolicy::Bounded { max_bytes } => {
+                        assert!(stats.total_size_bytes() <= max_bytes);
+                    }
+                }
+            }
+
+            assert_eq!(dataframes[0], dataframes[1]);
+            assert_eq!(dataframes[0], dataframes[2]);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn filtered_index_range() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -0,0 +1,573 @@
+use std::{any::Any, sync::Arc};
+
+use arrow::{
+    array::RecordBatch,
+    datatypes::{Schema, SchemaRef},
+};
+use async_trait::async_trait;
+use datafusion::{
+    catalog::{Session, TableProvider},
+    datasource::TableType,
+    error::{DataFusionError, Result as DataFusionResult},
+    execution::{SendableRecordBatchStream, TaskContext},
+    logical_expr::dml::InsertOp,
+    physical_plan::{
+        insert::{DataSink, DataSinkExec},
+        DisplayAs, DisplayFormatType, ExecutionPlan,
+    },
+    prelude::Expr,
+};
+use futures_util::StreamExt as _;
+
+use re_chunk::Chunk;
+use re_chunk_store::ChunkStoreHandle;
+use re_grpc_client::redap::RedapClient;
+use re_log_encoding::codec::wire::encoder::Encode as _;
+use re_log_types::EntryId;
+use re_protos::{
+    common::v1alpha1::RerunChunk, frontend::v1alpha1::WriteChunksRequest,
+    manifest_registry::v1alpha1::DATASET_MANIFEST_ID_FIELD_NAME,
+};
+use re_sorbet::{BatchType, SorbetBatch};
+
+use crate::wasm_compat::make_future_send;
+
+#[cfg(not(target_arch = "wasm32"))]
+use re_log_types::{LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreSource};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Where the chunks written to a [`ChunkSinkTableProvider`] end up.
+#[derive(Clone)]
+pub enum ChunkDestination {
+    /// Insert the chunks into an existing store.
+    Store(ChunkStoreHandle),
+
+    /// Write the chunks to a new `.rrd` file, as a recording with the given ID.
+    ///
+    /// The file is overwritten if it already exists, once the whole input was written: a failing
+    /// insert leaves the previous file untouched.
+    #[cfg(not(target_arch = "wasm32"))]
+    RrdFile { path: PathBuf, store_id: StoreId },
+
+    /// Send the chunks to a partition of a dataset using `WriteChunks`.
+    Dataset {
+        client: RedapClient,
+        dataset_id: EntryId,
+        partition_id: String,
+    },
+}
+
+impl std::fmt::Debug for ChunkDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Store(store) => f.debug_tuple("Store").field(&store.read().id()).finish(),
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::RrdFile { path, store_id } => f
+                .debug_struct("RrdFile")
+                .field("path", path)
+                .field("store_id", store_id)
+                .finish(),
+            Self::Dataset {
+                client: _,
+                dataset_id,
+                partition_id,
+            } => f
+                .debug_struct("Dataset")
+                .field("dataset_id", dataset_id)
+                .field("partition_id", partition_id)
+                .finish(),
+        }
+    }
+}
+
+/// A write-only table that turns inserted rows back into [`Chunk`]s.
+///
+/// The schema must carry Rerun column metadata (see [`re_sorbet::SorbetSchema`]), e.g. the schema
+/// of a dataframe query, or one built from [`re_sorbet::ColumnDescriptor`]s. This metadata decides
+/// which entity, component and timeline each column is written to, so that rows produced by
+/// arbitrary SQL (`INSERT INTO my_sink SELECT …`) can be logged back next to the original data.
+///
+/// Component columns don't need to be lists: plain columns are wrapped into single-instance
+/// lists. See [`Chunk::from_dataframe_batch`] for how rows are split into chunks.
+///
+/// The partition ID column of [`crate::DataframeQueryTableProvider`] is ignored, if present.
+///
+/// Chunks are written to the destination as the input batches come in. Only `INSERT INTO` is
+/// supported: `COPY … TO` goes through `DataFusion`'s file formats, which `.rrd` is not one of.
+#[derive(Debug)]
+pub struct ChunkSinkTableProvider {
+    schema: SchemaRef,
+    destination: ChunkDestination,
+}
+
+impl ChunkSinkTableProvider {
+    pub fn new(schema: SchemaRef, destination: ChunkDestination) -> DataFusionResult<Self> {
+        // Fail early rather than on the first insert.
+        re_sorbet::SorbetSchema::try_from(without_partition_id(&schema).as_ref())
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+        Ok(Self {
+            schema,
+            destination,
+        })
+    }
+}
+
+#[async_trait]
+impl TableProvider for ChunkSinkTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        _projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        Err(DataFusionError::NotImplemented(
+            "chunk sinks are write-only: query the destination instead".to_owned(),
+        ))
+    }
+
+    async fn insert_into(
+        &self,
+        _state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        insert_op: InsertOp,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        if insert_op != InsertOp::Append {
+            return Err(DataFusionError::NotImplemented(format!(
+                "{insert_op} is not supported by chunk sinks, only appending is"
+            )));
+        }
+
+        let sink = ChunkSink {
+            schema: Arc::clone(&self.schema),
+            destination: self.destination.clone(),
+        };
+
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
+    }
+}
+
+#[derive(Debug)]
+struct ChunkSink {
+    schema: SchemaRef,
+    destination: ChunkDestination,
+}
+
+impl ChunkSink {
+    /// Converts an inserted batch into chunks.
+    ///
+    /// The input columns are matched by position and re-stamped with the sink schema: `DataFusion`
+    /// does not preserve field metadata through computed expressions.
+    fn chunks_from_batch(&self, batch: &RecordBatch) -> DataFusionResult<Vec<Chunk>> {
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), batch.columns().to_vec())?;
+
+        let batch = match batch.schema().index_of(DATASET_MANIFEST_ID_FIELD_NAME) {
+            Ok(index) => {
+                let mut batch = batch;
+                batch.remove_column(index);
+                batch
+            }
+            Err(_) => batch,
+        };
+
+        let batch = SorbetBatch::try_from_record_batch(&batch, BatchType::Dataframe)
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+        Chunk::from_dataframe_batch(&batch).map_err(|err| DataFusionError::External(Box::new(err)))
+    }
+}
+
+impl DisplayAs for ChunkSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "ChunkSink: destination={:?}", self.destination)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for ChunkSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> DataFusionResult<u64> {
+        let mut num_rows = 0;
+
+        // NOTE: Chunks are written batch by batch rather than buffered until the end, so that large
+        // inserts don't need to fit in memory. If a later batch fails, the earlier ones have
+        // already been written to stores and datasets.
+        match &self.destination {
+            ChunkDestination::Store(store) => {
+                while let Some(batch) = data.next().await {
+                    let batch = batch?;
+                    num_rows += batch.num_rows() as u64;
+
+                    let chunks = self.chunks_from_batch(&batch)?;
+                    let mut store = store.write();
+                    for chunk in chunks {
+                        store
+                            .insert_chunk(&Arc::new(chunk))
+                            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+                    }
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            ChunkDestination::RrdFile { path, store_id } => {
+                let (path, store_id) = (path.clone(), store_id.clone());
+                let mut file =
+                    spawn_blocking(move || RrdFileWriter::create(&path, &store_id)).await?;
+
+                while let Some(batch) = data.next().await {
+                    let batch = batch?;
+                    num_rows += batch.num_rows() as u64;
+
+                    let chunks = self.chunks_from_batch(&batch)?;
+                    file = spawn_blocking(move || {
+                        for chunk in &chunks {
+                            file.append(chunk)?;
+                        }
+                        Ok(file)
+                    })
+                    .await?;
+                }
+
+                spawn_blocking(move || file.finish()).await?;
+            }
+
+            ChunkDestination::Dataset {
+                client,
+                dataset_id,
+                partition_id,
+            } => {
+                while let Some(batch) = data.next().await {
+                    let batch = batch?;
+                    num_rows += batch.num_rows() as u64;
+
+                    let requests = self
+                        .chunks_from_batch(&batch)?
+                        .iter()
+                        .map(|chunk| {
+                            let chunk: RerunChunk = chunk
+                                .to_record_batch()
+                                .map_err(|err| DataFusionError::External(Box::new(err)))?
+                                .encode()
+                                .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+                            Ok(WriteChunksRequest {
+                                dataset_id: Some((*dataset_id).into()),
+                                partition_id: Some(partition_id.clone().into()),
+                                chunk: Some(chunk),
+                            })
+                        })
+                        .collect::<DataFusionResult<Vec<_>>>()?;
+
+                    let mut client = client.clone();
+                    make_future_send(async move {
+                        let mut responses = client
+                            .write_chunks(futures_util::stream::iter(requests))
+                            .await
+                            .map_err(|err| DataFusionError::External(Box::new(err)))?
+                            .into_inner();
+
+                        while let Some(response) = responses.next().await {
+                            response.map_err(|err| DataFusionError::External(Box::new(err)))?;
+                        }
+
+                        Ok(())
+                    })
+                    .await?;
+                }
+            }
+        }
+
+        Ok(num_rows)
+    }
+}
+
+/// Runs some blocking file I/O on a dedicated thread, so as not to stall the async runtime.
+#[cfg(not(target_arch = "wasm32"))]
+async fn spawn_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> anyhow::Result<T> + Send + 'static,
+) -> DataFusionResult<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| DataFusionError::External(Box::new(err)))?
+        .map_err(|err| DataFusionError::External(err.into()))
+}
+
+/// Writes a recording to a temporary file next to `path`, which replaces `path` on
+/// [`Self::finish`].
+///
+/// The temporary file is removed if the writer is dropped before that.
+#[cfg(not(target_arch = "wasm32"))]
+struct RrdFileWriter {
+    encoder: Option<re_log_encoding::encoder::DroppableEncoder<std::io::BufWriter<std::fs::File>>>,
+    store_id: StoreId,
+    path: PathBuf,
+    tmp_path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RrdFileWriter {
+    fn create(path: &Path, store_id: &StoreId) -> anyhow::Result<Self> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".part");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let file = std::fs::File::create(&tmp_path)?;
+        let mut writer = Self {
+            encoder: None,
+            store_id: store_id.clone(),
+            path: path.to_owned(),
+            tmp_path,
+        };
+
+        let encoder = writer
+            .encoder
+            .insert(re_log_encoding::encoder::DroppableEncoder::new(
+                re_build_info::CrateVersion::LOCAL,
+                re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+                std::io::BufWriter::new(file),
+            )?);
+
+        encoder.append(&LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *re_chunk::RowId::new(),
+            info: StoreInfo {
+                application_id: "rerun_datafusion".into(),
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Other("datafusion".to_owned()),
+                store_version: Some(re_build_info::CrateVersion::LOCAL),
+            },
+        }))?;
+
+        Ok(writer)
+    }
+
+    fn append(&mut self, chunk: &Chunk) -> anyhow::Result<()> {
+        let encoder = self
+            .encoder
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("already finished"))?;
+        encoder.append(&LogMsg::ArrowMsg(
+            self.store_id.clone(),
+            chunk.to_arrow_msg()?,
+        ))?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> anyhow::Result<()> {
+        let mut encoder = self
+            .encoder
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("already finished"))?;
+        encoder.finish()?;
+        encoder.flush_blocking()?;
+        drop(encoder);
+
+        std::fs::rename(&self.tmp_path, &self.path)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for RrdFileWriter {
+    fn drop(&mut self) {
+        if self.encoder.take().is_some() {
+            std::fs::remove_file(&self.tmp_path).ok();
+        }
+    }
+}
+
+fn without_partition_id(schema: &Schema) -> Arc<Schema> {
+    let fields = schema
+        .fields()
+        .iter()
+        .filter(|field| field.name() != DATASET_MANIFEST_ID_FIELD_NAME)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata.clone()))
+}
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use datafusion::prelude::SessionContext;
+
+    use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+    use re_dataframe::{QueryCache, QueryEngine, QueryExpression};
+    use re_log_types::{
+        build_frame_nr,
+        example_components::{MyPoint, MyPoints},
+        StoreKind, TimeInt,
+    };
+
+    use super::*;
+    use crate::DataframeQueryTableProvider;
+
+    /// A dataframe query over a recording of 3 frames.
+    fn source_table() -> DataframeQueryTableProvider {
+        let mut store = ChunkStore::new(
+            StoreId::random(StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+        let mut builder = Chunk::builder("/this/that".into());
+        for frame in [10, 20, 30] {
+            builder = builder.with_sparse_component_batches(
+                re_chunk::RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(frame))],
+                [(
+                    MyPoints::descriptor_points(),
+                    Some(&MyPoint::from_iter(0..2) as _),
+                )],
+            );
+        }
+        store
+            .insert_chunk(&Arc::new(builder.build().unwrap()))
+            .unwrap();
+
+        let store = ChunkStoreHandle::new(store);
+        let query_engine = QueryEngine::new(store.clone(), QueryCache::new_handle(store));
+        DataframeQueryTableProvider::new(
+            BTreeMap::from([("partition".to_owned(), query_engine)]),
+            QueryExpression {
+                filtered_index: Some("frame_nr".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    /// Runs `INSERT INTO sink SELECT * FROM source`, with [`source_table`] as the source.
+    async fn insert_into(destination: ChunkDestination) {
+        let source = source_table();
+        let sink = ChunkSinkTableProvider::new(Arc::clone(&source.schema), destination).unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.register_table("source", source.try_into().unwrap())
+            .unwrap();
+        ctx.register_table("sink", Arc::new(sink)).unwrap();
+        ctx.sql("INSERT INTO sink SELECT * FROM source")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+    }
+
+    fn num_rows(store: &ChunkStore) -> usize {
+        store.iter_chunks().map(|chunk| chunk.num_rows()).sum()
+    }
+
+    #[tokio::test]
+    async fn insert_into_store() {
+        let store = ChunkStoreHandle::new(ChunkStore::new(
+            StoreId::random(StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        ));
+
+        insert_into(ChunkDestination::Store(store.clone())).await;
+
+        assert_eq!(num_rows(&store.read()), 3);
+    }
+
+    #[tokio::test]
+    async fn insert_into_rrd_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("derived.rrd");
+        let store_id = StoreId::random(StoreKind::Recording);
+
+        insert_into(ChunkDestination::RrdFile {
+            path: path.clone(),
+            store_id: store_id.clone(),
+        })
+        .await;
+
+        // Only the final file is left behind.
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        let stores = ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, &path).unwrap();
+        assert_eq!(num_rows(&stores[&store_id]), 3);
+    }
+
+    #[tokio::test]
+    async fn insert_into_dataset() {
+        use re_protos::catalog::v1alpha1::{ext::DatasetEntry, CreateDatasetEntryRequest};
+
+        let root = tempfile::tempdir().unwrap();
+
+        let tcp_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = tcp_listener.local_addr().unwrap();
+        let (signal, shutdown) = re_grpc_server::shutdown::shutdown();
+        let server = tokio::spawn(re_server::serve_from_listener(
+            tcp_listener,
+            root.path().to_owned(),
+            Vec::new(),
+            None,
+            shutdown,
+        ));
+
+        let origin = format!("rerun+http://{addr}").parse().unwrap();
+        let mut client = re_grpc_client::redap::client(origin).await.unwrap();
+        let dataset = client
+            .create_dataset_entry(CreateDatasetEntryRequest {
+                name: Some("derived".to_owned()),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .dataset
+            .unwrap();
+        let dataset_id = DatasetEntry::try_from(dataset).unwrap().details.id;
+
+        // Writing twice appends to the partition.
+        for _ in 0..2 {
+            insert_into(ChunkDestination::Dataset {
+                client: client.clone(),
+                dataset_id,
+                partition_id: "episode".to_owned(),
+            })
+            .await;
+        }
+
+        let stores = ChunkStore::from_rrd_filepath(
+            &ChunkStoreConfig::ALL_DISABLED,
+            root.path().join("derived").join("episode.rrd"),
+        )
+        .unwrap();
+        assert_eq!(stores.values().map(num_rows).sum::<usize>(), 2 * 3);
+
+        signal.stop();
+        server.await.unwrap().unwrap();
+    }
+}
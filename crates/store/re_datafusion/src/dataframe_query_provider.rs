@@ -20,6 +20,11 @@ pub struct DataframeQueryTableProvider {
 }
 
 impl DataframeQueryTableProvider {
+    /// Creates a table provider that runs `query_expression` against each of the `query_engines`.
+    ///
+    /// The resulting schema is the union of the schemas returned by each engine, prepended with
+    /// the partition ID column. In particular, this includes the extra per-component index columns
+    /// when [`re_dataframe::IndexValuePolicy::ExactPerComponent`] is used.
     pub fn new(
         query_engines: BTreeMap<String, QueryEngine<StorageEngine>>,
         query_expression: QueryExpression,
@@ -150,3 +155,88 @@ pub fn align_record_batch_to_schema(
         aligned_columns,
     )?)
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{AsArray as _, Int64Array};
+    use arrow::datatypes::Int64Type;
+    use datafusion::prelude::SessionContext;
+
+    use re_chunk::{Chunk, RowId};
+    use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreHandle, IndexValuePolicy};
+    use re_dataframe::QueryCache;
+    use re_log_types::{
+        build_frame_nr, build_log_time,
+        example_components::{MyColor, MyPoint, MyPoints},
+        StoreId, StoreKind, TimeInt,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn exact_index_columns() {
+        let mut store = ChunkStore::new(
+            StoreId::random(StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+        let chunk = Chunk::builder("/this/that".into())
+            .with_sparse_component_batches(
+                RowId::new(),
+                [
+                    build_frame_nr(TimeInt::new_temporal(10)),
+                    build_log_time(TimeInt::new_temporal(100).into()),
+                ],
+                [(
+                    MyPoints::descriptor_points(),
+                    Some(&MyPoint::from_iter(0..1) as _),
+                )],
+            )
+            .with_sparse_component_batches(
+                RowId::new(),
+                [
+                    build_frame_nr(TimeInt::new_temporal(20)),
+                    build_log_time(TimeInt::new_temporal(50).into()),
+                ],
+                [(
+                    MyPoints::descriptor_colors(),
+                    Some(&MyColor::from_iter(1..2) as _),
+                )],
+            )
+            .build()
+            .unwrap();
+        store.insert_chunk(&Arc::new(chunk)).unwrap();
+
+        let store = ChunkStoreHandle::new(store);
+        let query_engine = QueryEngine::new(store.clone(), QueryCache::new_handle(store));
+
+        let provider = DataframeQueryTableProvider::new(
+            BTreeMap::from([("partition".to_owned(), query_engine)]),
+            QueryExpression {
+                filtered_index: Some("frame_nr".into()),
+                sparse_fill_strategy: re_dataframe::SparseFillStrategy::LatestAtGlobal,
+                index_value_policy: IndexValuePolicy::ExactPerComponent,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.register_table("dataframe", provider.try_into().unwrap())
+            .unwrap();
+        let batches = ctx
+            .sql(r#"SELECT frame_nr, "/this/that:example.MyPoint@frame_nr" FROM dataframe ORDER BY frame_nr"#)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let batch = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        let frames = batch.column(0).as_primitive::<Int64Type>();
+        let point_frames = batch.column(1).as_primitive::<Int64Type>();
+
+        // The points logged at frame 10 are still the latest ones at frame 20.
+        assert_eq!(frames, &Int64Array::from(vec![10, 20]));
+        assert_eq!(point_frames, &Int64Array::from(vec![10, 10]));
+    }
+}
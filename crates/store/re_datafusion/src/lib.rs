@@ -1,5 +1,6 @@
 //! The Rerun public data APIs. Access `DataFusion` `TableProviders`.
 
+mod chunk_sink;
 mod dataframe_query_provider;
 mod datafusion_connector;
 mod grpc_streaming_provider;
@@ -8,6 +9,7 @@ mod search_provider;
 mod table_entry_provider;
 mod wasm_compat;
 
+pub use chunk_sink::{ChunkDestination, ChunkSinkTableProvider};
 pub use dataframe_query_provider::DataframeQueryTableProvider;
 pub use datafusion_connector::DataFusionConnector;
 pub use partition_table::PartitionTableProvider;
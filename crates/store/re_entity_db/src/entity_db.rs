@@ -7,6 +7,7 @@ use re_chunk::{Chunk, ChunkResult, LatestAtQuery, RowId, TimeInt, Timeline, Time
 use re_chunk_store::{
     ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent,
     ChunkStoreHandle, ChunkStoreSubscriber, GarbageCollectionOptions, GarbageCollectionTarget,
+    RetentionPolicy,
 };
 use re_log_types::{
     ApplicationId, EntityPath, EntityPathHash, LogMsg, ResolvedTimeRange, ResolvedTimeRangeF,
@@ -81,7 +82,14 @@ pub struct EntityDb {
 
 impl EntityDb {
     pub fn new(store_id: StoreId) -> Self {
-        Self::with_store_config(store_id, ChunkStoreConfig::from_env().unwrap_or_default())
+        let mut store_config = ChunkStoreConfig::from_env().unwrap_or_default();
+
+        // Retention rules are meant for recorded data: blueprints have their own GC.
+        if store_id.kind == StoreKind::Blueprint {
+            store_config.retention = RetentionPolicy::NONE;
+        }
+
+        Self::with_store_config(store_id, store_config)
     }
 
     pub fn with_store_config(store_id: StoreId, store_config: ChunkStoreConfig) -> Self {
@@ -465,6 +473,48 @@ impl EntityDb {
         store_events
     }
 
+    /// Replaces the store's [`RetentionPolicy`], see [`Self::enforce_retention`].
+    ///
+    /// This is a no-op for blueprints, which have their own garbage collection.
+    ///
+    /// The new policy only takes effect on the next call to [`Self::enforce_retention`].
+    pub fn set_retention_policy(&self, retention: RetentionPolicy) {
+        if self.store_kind() == StoreKind::Blueprint {
+            return;
+        }
+
+        self.storage_engine
+            .write()
+            .store()
+            .set_retention_policy(retention);
+    }
+
+    /// Drop whatever data falls outside of the store's [`RetentionPolicy`], if any.
+    ///
+    /// Unlike [`Self::purge_fraction_of_ram`], this doesn't drop anything beyond what the
+    /// retention rules require.
+    pub fn enforce_retention(&mut self) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!();
+
+        if self
+            .storage_engine
+            .read()
+            .store()
+            .config()
+            .retention
+            .is_none()
+        {
+            return Vec::new();
+        }
+
+        self.gc(&GarbageCollectionOptions {
+            target: GarbageCollectionTarget::DropAtLeastFraction(0.0),
+            protect_latest: 0,
+            time_budget: DEFAULT_GC_TIME_BUDGET,
+            protected_time_ranges: Default::default(),
+        })
+    }
+
     pub fn gc(&mut self, gc_options: &GarbageCollectionOptions) -> Vec<ChunkStoreEvent> {
         re_tracing::profile_function!();
 
@@ -55,7 +55,7 @@ async fn stream_async(
     re_log::debug!("Streaming messages from gRPC endpoint {uri}");
 
     let mut stream = client
-        .read_messages(ReadMessagesRequest {})
+        .read_messages(ReadMessagesRequest { filter: None })
         .await
         .map_err(TonicStatusError)?
         .into_inner();
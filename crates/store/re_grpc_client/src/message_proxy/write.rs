@@ -1,9 +1,15 @@
+use std::collections::VecDeque;
+use std::io::{Read as _, Write as _};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::Duration;
 
 use re_log_encoding::Compression;
 use re_log_types::LogMsg;
+use re_protos::external::prost::Message as _;
 use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 use re_protos::sdk_comms::v1alpha1::WriteMessagesRequest;
 use re_uri::ProxyUri;
@@ -25,6 +31,14 @@ enum Cmd {
 pub struct Options {
     pub compression: Compression,
     pub flush_timeout: Option<Duration>,
+
+    /// How long to wait between connection attempts.
+    pub reconnect: ReconnectOptions,
+
+    /// Where to keep the messages logged while disconnected.
+    ///
+    /// If `None`, they are kept in memory, without any limit.
+    pub spool: Option<SpoolOptions>,
 }
 
 impl Default for Options {
@@ -32,6 +46,105 @@ impl Default for Options {
         Self {
             compression: Compression::LZ4,
             flush_timeout: Default::default(),
+            reconnect: Default::default(),
+            spool: None,
+        }
+    }
+}
+
+/// Exponential backoff between connection attempts.
+///
+/// The connection is re-established automatically whenever it is lost.
+/// The delay only goes back to [`Self::initial_backoff`] once some data was successfully written.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReconnectOptions {
+    /// Delay after the first failed attempt.
+    pub initial_backoff: Duration,
+
+    /// The delay never grows past this.
+    pub max_backoff: Duration,
+
+    /// The delay is multiplied by this after each failed attempt.
+    pub multiplier: f32,
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl ReconnectOptions {
+    fn next_backoff(&self, backoff: Duration) -> Duration {
+        backoff.mul_f32(self.multiplier).min(self.max_backoff)
+    }
+}
+
+/// A bounded, on-disk spool for the messages logged while disconnected.
+///
+/// It is replayed, in order, as soon as the connection is (re)established.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpoolOptions {
+    /// The spool file. Any existing file is overwritten.
+    pub path: PathBuf,
+
+    /// Messages that would make the spool grow past this size are dropped.
+    ///
+    /// Only the messages that have yet to be replayed count towards this limit.
+    pub max_bytes: u64,
+}
+
+/// Counters for the data that went through a [`Client`], see [`Client::stats`].
+///
+/// Use [`Client::stats_handle`] to keep track of these once the client itself is out of reach.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    /// How many times the connection was re-established after being lost.
+    pub num_reconnects: u64,
+
+    /// Total number of encoded bytes written to the spool.
+    pub spooled_bytes: u64,
+
+    /// Total number of encoded bytes that were lost because the spool was full or unwritable.
+    pub dropped_bytes: u64,
+}
+
+#[derive(Default)]
+struct AtomicClientStats {
+    num_reconnects: AtomicU64,
+    spooled_bytes: AtomicU64,
+    dropped_bytes: AtomicU64,
+}
+
+/// A shared handle to the live [`ClientStats`] of a [`Client`].
+///
+/// It stays valid, and keeps reporting the latest values, for as long as the client is alive.
+#[derive(Clone, Default)]
+pub struct ClientStatsHandle(Arc<AtomicClientStats>);
+
+impl ClientStatsHandle {
+    /// The current value of the counters.
+    pub fn load(&self) -> ClientStats {
+        self.0.load()
+    }
+}
+
+impl std::fmt::Debug for ClientStatsHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.load().fmt(f)
+    }
+}
+
+impl AtomicClientStats {
+    fn load(&self) -> ClientStats {
+        ClientStats {
+            num_reconnects: self.num_reconnects.load(Ordering::Relaxed),
+            spooled_bytes: self.spooled_bytes.load(Ordering::Relaxed),
+            dropped_bytes: self.dropped_bytes.load(Ordering::Relaxed),
         }
     }
 }
@@ -41,6 +154,7 @@ pub struct Client {
     cmd_tx: UnboundedSender<Cmd>,
     shutdown_tx: Sender<()>,
     flush_timeout: Option<Duration>,
+    stats: Arc<AtomicClientStats>,
 }
 
 impl Client {
@@ -48,21 +162,27 @@ impl Client {
     pub fn new(uri: ProxyUri, options: Options) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
+        let stats = Arc::new(AtomicClientStats::default());
 
         let thread = thread::Builder::new()
             .name("message_proxy_client".to_owned())
-            .spawn(move || {
-                let mut runtime = runtime::Builder::new_current_thread();
-                runtime.enable_all();
-                runtime
-                    .build()
-                    .expect("Failed to build tokio runtime")
-                    .block_on(message_proxy_client(
-                        uri,
-                        cmd_rx,
-                        shutdown_rx,
-                        options.compression,
-                    ));
+            .spawn({
+                let stats = Arc::clone(&stats);
+                let options = options.clone();
+                move || {
+                    let mut runtime = runtime::Builder::new_current_thread();
+                    runtime.enable_all();
+                    runtime
+                        .build()
+                        .expect("Failed to build tokio runtime")
+                        .block_on(message_proxy_client(
+                            uri,
+                            cmd_rx,
+                            shutdown_rx,
+                            options,
+                            stats,
+                        ));
+                }
             })
             .expect("Failed to spawn message proxy client thread");
 
@@ -71,9 +191,20 @@ impl Client {
             cmd_tx,
             shutdown_tx,
             flush_timeout: options.flush_timeout,
+            stats,
         }
     }
 
+    /// Counters for the reconnections, and for the spooled and dropped data.
+    pub fn stats(&self) -> ClientStats {
+        self.stats.load()
+    }
+
+    /// Like [`Self::stats`], but can be kept around to read the counters later on.
+    pub fn stats_handle(&self) -> ClientStatsHandle {
+        ClientStatsHandle(Arc::clone(&self.stats))
+    }
+
     pub fn send(&self, msg: LogMsg) {
         self.cmd_tx.send(Cmd::LogMsg(msg)).ok();
     }
@@ -135,11 +266,19 @@ impl Drop for Client {
     }
 }
 
+/// Messages handed over to a call are only known to be delivered once the server successfully
+/// completes that call: there are no per-message acknowledgements.
+///
+/// The current call is ended, and a new one started, whenever this many bytes are awaiting
+/// confirmation, so that they can all be sent again if the connection is lost in the meantime.
+const MAX_UNCONFIRMED_BYTES: usize = 16 * 1024 * 1024;
+
 async fn message_proxy_client(
     uri: ProxyUri,
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
-    compression: Compression,
+    options: Options,
+    stats: Arc<AtomicClientStats>,
 ) {
     let endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
         Ok(endpoint) => endpoint,
@@ -149,72 +288,733 @@ async fn message_proxy_client(
         }
     };
 
-    let channel = loop {
-        match endpoint.connect().await {
-            Ok(channel) => break channel,
-            Err(err) => {
-                re_log::debug!("failed to connect to message proxy server: {err}");
-                tokio::select! {
-                    _ = shutdown_rx.recv() => {
-                        re_log::debug!("shutting down client without flush");
-                        return;
-                    }
-                    _ = tokio::time::sleep(Duration::from_millis(100)) => {
-                        continue;
+    let mut spool = options.spool.clone().map(Spool::new);
+
+    // Messages that still need to be sent, before anything in the spool or the channel.
+    let mut pending: VecDeque<WriteMessagesRequest> = VecDeque::new();
+
+    // Messages handed over to the current call, until the server confirms them.
+    let mut unconfirmed: VecDeque<WriteMessagesRequest> = VecDeque::new();
+    let mut unconfirmed_bytes = 0;
+
+    // Flushes waiting for all the messages that came before them to be confirmed.
+    let mut flushes: Vec<oneshot::Sender<()>> = Vec::new();
+
+    let mut has_connected = false;
+    let mut backoff = options.reconnect.initial_backoff;
+
+    // Set after a failed connection attempt or call, so that we wait before the next attempt.
+    let mut should_wait = false;
+
+    loop {
+        let channel = loop {
+            if std::mem::take(&mut should_wait) {
+                let retry = tokio::time::sleep(backoff);
+                tokio::pin!(retry);
+
+                loop {
+                    tokio::select! {
+                        _ = shutdown_rx.recv() => {
+                            re_log::debug!("shutting down client without flush");
+                            return;
+                        }
+
+                        () = &mut retry => break,
+
+                        // Without a spool, new messages just wait in the channel.
+                        cmd = cmd_rx.recv(), if spool.is_some() => {
+                            let (Some(cmd), Some(spool)) = (cmd, spool.as_mut()) else {
+                                re_log::debug!("Channel closed");
+                                return;
+                            };
+
+                            match cmd {
+                                Cmd::LogMsg(msg) => {
+                                    if let Some(request) = encode(msg, options.compression) {
+                                        spool.push(&request, &stats);
+                                    }
+                                }
+
+                                Cmd::Flush(tx) => {
+                                    // Everything that isn't pending is safely on disk.
+                                    spool.flush();
+                                    if pending.is_empty() {
+                                        tx.send(()).ok();
+                                    } else {
+                                        flushes.push(tx);
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
+
+                backoff = options.reconnect.next_backoff(backoff);
             }
+
+            match endpoint.connect().await {
+                Ok(channel) => break channel,
+                Err(err) => {
+                    re_log::debug!("failed to connect to message proxy server: {err}");
+                    should_wait = true;
+                }
+            }
+        };
+
+        if has_connected {
+            re_log::debug!("Reconnected to message proxy server");
+            stats.num_reconnects.fetch_add(1, Ordering::Relaxed);
         }
-    };
-    let mut client = MessageProxyServiceClient::new(channel);
+        has_connected = true;
 
-    let stream = async_stream::stream! {
+        let mut client = MessageProxyServiceClient::new(channel);
+
+        // Calls are ended either by the server, or by us to get their messages confirmed.
         loop {
-            tokio::select! {
-                cmd = cmd_rx.recv() => {
-                    match cmd {
-                        Some(Cmd::LogMsg(msg)) => {
-                            let msg = match re_log_encoding::protobuf_conversions::log_msg_to_proto(msg, compression) {
-                                Ok(msg) => msg,
-                                Err(err) => {
-                                    re_log::error!("Failed to encode message: {err}");
-                                    break;
-                                }
-                            };
+            let (request_tx, mut request_rx) = mpsc::channel(1);
+            let mut request_tx = Some(request_tx);
+            let stream = async_stream::stream! {
+                while let Some(request) = request_rx.recv().await {
+                    yield request;
+                }
+            };
 
-                            let msg = WriteMessagesRequest {
-                                log_msg: Some(msg),
-                            };
+            let call = client.write_messages(stream);
+            tokio::pin!(call);
 
-                            yield msg;
-                        }
+            let result = loop {
+                let has_backlog =
+                    !pending.is_empty() || spool.as_ref().is_some_and(|spool| !spool.is_empty());
 
-                        Some(Cmd::Flush(tx)) => {
-                            // Messages are received in order, so once we receive a `flush`
-                            // we know we've sent all messages before that flush through already.
-                            re_log::debug!("Flush requested");
-                            if tx.send(()).is_err() {
-                                re_log::debug!("Failed to respond to flush: channel is closed");
-                                return;
+                if unconfirmed_bytes >= MAX_UNCONFIRMED_BYTES
+                    || (!flushes.is_empty() && !has_backlog)
+                {
+                    // Ending the call makes the server confirm everything sent through it.
+                    request_tx = None;
+                }
+
+                // Set when the call turns out to be over from our side.
+                let mut is_closed = false;
+
+                tokio::select! {
+                    biased;
+
+                    _ = shutdown_rx.recv() => {
+                        re_log::debug!("Shutting down client without flush");
+                        return;
+                    }
+
+                    result = &mut call => break result,
+
+                    // Catch up on what couldn't be sent before, first.
+                    permit = async { request_tx.as_ref()?.reserve().await.ok() }, if request_tx.is_some() && has_backlog => {
+                        if let Some(permit) = permit {
+                            let request = match pending.pop_front() {
+                                Some(request) => Some(request),
+                                None => spool.as_mut().and_then(|spool| spool.pop(&stats)),
                             };
+                            if let Some(request) = request {
+                                unconfirmed_bytes += request.encoded_len();
+                                unconfirmed.push_back(request.clone());
+                                permit.send(request);
+                            }
+                        } else {
+                            is_closed = true;
                         }
+                    }
 
-                        None => {
-                            re_log::debug!("Channel closed");
-                            break;
+                    cmd = cmd_rx.recv(), if request_tx.is_some() && !has_backlog => {
+                        match cmd {
+                            Some(Cmd::LogMsg(msg)) => {
+                                let Some(request) = encode(msg, options.compression) else {
+                                    continue;
+                                };
+                                let Some(sender) = &request_tx else {
+                                    pending.push_back(request);
+                                    continue;
+                                };
+
+                                match sender.send(request.clone()).await {
+                                    Ok(()) => {
+                                        unconfirmed_bytes += request.encoded_len();
+                                        unconfirmed.push_back(request);
+                                    }
+                                    Err(mpsc::error::SendError(request)) => {
+                                        pending.push_back(request);
+                                        is_closed = true;
+                                    }
+                                }
+                            }
+
+                            Some(Cmd::Flush(tx)) => {
+                                // Messages are received in order, so once the server has confirmed
+                                // everything sent so far, everything before that flush went through.
+                                re_log::debug!("Flush requested");
+                                flushes.push(tx);
+                            }
+
+                            None => {
+                                re_log::debug!("Channel closed");
+                                return;
+                            }
                         }
                     }
                 }
 
-                _ = shutdown_rx.recv() => {
-                    re_log::debug!("Shutting down client without flush");
-                    return;
+                if is_closed {
+                    request_tx = None;
+                }
+            };
+
+            if let Err(err) = result {
+                re_log::debug!("Write messages call failed: {err}");
+                should_wait = true;
+                break;
+            }
+
+            // The server got everything that went through this call.
+            backoff = options.reconnect.initial_backoff;
+            unconfirmed.clear();
+            unconfirmed_bytes = 0;
+
+            if pending.is_empty() && spool.as_ref().is_none_or(Spool::is_empty) {
+                for tx in flushes.drain(..) {
+                    tx.send(()).ok();
                 }
             }
         }
-    };
 
-    if let Err(err) = client.write_messages(stream).await {
-        re_log::error!("Write messages call failed: {err}");
+        re_log::debug!("Lost connection to message proxy server, reconnecting…");
+
+        // Whatever wasn't confirmed must be sent again, before anything else.
+        unconfirmed_bytes = 0;
+        for request in unconfirmed.drain(..).rev() {
+            pending.push_front(request);
+        }
+
+        // Anything that can't be sent right away goes to the spool, if any, unless that would put
+        // it after the older messages that are still in there.
+        if let Some(spool) = spool.as_mut().filter(|spool| spool.is_empty()) {
+            for request in pending.drain(..) {
+                spool.push(&request, &stats);
+            }
+
+            spool.flush();
+            for tx in flushes.drain(..) {
+                tx.send(()).ok();
+            }
+        }
+    }
+}
+
+fn encode(msg: LogMsg, compression: Compression) -> Option<WriteMessagesRequest> {
+    match re_log_encoding::protobuf_conversions::log_msg_to_proto(msg, compression) {
+        Ok(msg) => Some(WriteMessagesRequest { log_msg: Some(msg) }),
+        Err(err) => {
+            re_log::error!("Failed to encode message: {err}");
+            None
+        }
+    }
+}
+
+// ---
+
+/// A file of length-prefixed, encoded [`WriteMessagesRequest`]s, used as a queue.
+///
+/// Requests are appended at the end of the file and read back one at a time from its start, so
+/// that replaying the spool never requires loading all of it in memory.
+/// The file is removed once everything has been read back.
+struct Spool {
+    options: SpoolOptions,
+
+    /// Created lazily, on the first write.
+    writer: Option<std::io::BufWriter<std::fs::File>>,
+
+    /// Opened lazily, on the first read.
+    reader: Option<std::io::BufReader<std::fs::File>>,
+
+    /// The size of the spool file.
+    ///
+    /// The file is only truncated once everything has been read back, see [`Self::clear`].
+    num_bytes: u64,
+
+    /// How many of these bytes have been read back already.
+    num_bytes_read: u64,
+}
+
+impl Spool {
+    fn new(options: SpoolOptions) -> Self {
+        Self {
+            options,
+            writer: None,
+            reader: None,
+            num_bytes: 0,
+            num_bytes_read: 0,
+        }
+    }
+
+    /// Is there nothing left to read back?
+    fn is_empty(&self) -> bool {
+        self.num_bytes_read >= self.num_bytes
+    }
+
+    fn push(&mut self, request: &WriteMessagesRequest, stats: &AtomicClientStats) {
+        let bytes = request.encode_to_vec();
+        let num_bytes = (std::mem::size_of::<u64>() + bytes.len()) as u64;
+
+        // Whatever was read back already doesn't count, even though it's still in the file.
+        let num_bytes_left = self.num_bytes - self.num_bytes_read;
+        if num_bytes_left + num_bytes > self.options.max_bytes {
+            re_log::warn_once!(
+                "Message spool {:?} is full, dropping data",
+                self.options.path
+            );
+            stats.dropped_bytes.fetch_add(num_bytes, Ordering::Relaxed);
+            return;
+        }
+
+        if let Err(err) = self.write(&bytes) {
+            re_log::error!(
+                "Failed to write to message spool {:?}: {err}",
+                self.options.path
+            );
+            stats.dropped_bytes.fetch_add(num_bytes, Ordering::Relaxed);
+            return;
+        }
+
+        self.num_bytes += num_bytes;
+        stats.spooled_bytes.fetch_add(num_bytes, Ordering::Relaxed);
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        let file = match &mut self.writer {
+            Some(file) => file,
+            None => self
+                .writer
+                .insert(std::io::BufWriter::new(std::fs::File::create(
+                    &self.options.path,
+                )?)),
+        };
+
+        file.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        file.write_all(bytes)
+    }
+
+    fn flush(&mut self) {
+        if let Some(file) = &mut self.writer {
+            if let Err(err) = file.flush() {
+                re_log::error!(
+                    "Failed to flush message spool {:?}: {err}",
+                    self.options.path
+                );
+            }
+        }
+    }
+
+    /// Reads back the oldest request in the spool, if any.
+    ///
+    /// If the spool cannot be read, everything that is left in it is dropped.
+    fn pop(&mut self, stats: &AtomicClientStats) -> Option<WriteMessagesRequest> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let request = self.read().map_err(|err| {
+            re_log::error!(
+                "Failed to read message spool {:?}: {err}",
+                self.options.path
+            );
+            stats.dropped_bytes.fetch_add(
+                self.num_bytes.saturating_sub(self.num_bytes_read),
+                Ordering::Relaxed,
+            );
+            self.num_bytes_read = self.num_bytes;
+        });
+
+        if self.is_empty() {
+            self.clear();
+        }
+
+        request.ok()
+    }
+
+    fn read(&mut self) -> std::io::Result<WriteMessagesRequest> {
+        // Everything that was written must be on disk before it can be read back.
+        if let Some(writer) = &mut self.writer {
+            writer.flush()?;
+        }
+
+        let file = match &mut self.reader {
+            Some(file) => file,
+            None => self
+                .reader
+                .insert(std::io::BufReader::new(std::fs::File::open(
+                    &self.options.path,
+                )?)),
+        };
+
+        let mut len = [0_u8; std::mem::size_of::<u64>()];
+        file.read_exact(&mut len)?;
+
+        let mut bytes = vec![0_u8; u64::from_le_bytes(len) as usize];
+        file.read_exact(&mut bytes)?;
+
+        self.num_bytes_read += (len.len() + bytes.len()) as u64;
+
+        WriteMessagesRequest::decode(bytes.as_slice())
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Removes the spool file, once everything has been read back.
+    fn clear(&mut self) {
+        self.writer = None;
+        self.reader = None;
+        self.num_bytes = 0;
+        self.num_bytes_read = 0;
+
+        std::fs::remove_file(&self.options.path).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::atomic::AtomicBool;
+    use std::time::Instant;
+
+    use parking_lot::Mutex;
+
+    use re_log_types::{BlueprintActivationCommand, StoreId, StoreKind};
+    use re_protos::sdk_comms::v1alpha1::{
+        message_proxy_service_server::{MessageProxyService, MessageProxyServiceServer},
+        ReadMessagesRequest, ReadMessagesResponse, ReadTablesRequest, ReadTablesResponse,
+        WriteMessagesResponse, WriteTableRequest, WriteTableResponse,
     };
+    use tonic::{Request, Response, Status, Streaming};
+
+    use super::*;
+
+    /// Keeps track of everything it receives, across restarts.
+    #[derive(Clone, Default)]
+    struct MockProxy {
+        received: Arc<Mutex<Vec<WriteMessagesRequest>>>,
+
+        /// When each `WriteMessages` call started.
+        calls: Arc<Mutex<Vec<Instant>>>,
+
+        /// Fail all `WriteMessages` calls right away.
+        fail_calls: Arc<AtomicBool>,
+    }
+
+    #[tonic::async_trait]
+    impl MessageProxyService for MockProxy {
+        async fn write_messages(
+            &self,
+            request: Request<Streaming<WriteMessagesRequest>>,
+        ) -> Result<Response<WriteMessagesResponse>, Status> {
+            self.calls.lock().push(Instant::now());
+            if self.fail_calls.load(Ordering::Relaxed) {
+                return Err(Status::unavailable("mock failure"));
+            }
+
+            let mut stream = request.into_inner();
+            while let Some(request) = stream.message().await? {
+                self.received.lock().push(request);
+            }
+
+            Ok(Response::new(WriteMessagesResponse {}))
+        }
+
+        type ReadMessagesStream = tokio_stream::Empty<Result<ReadMessagesResponse, Status>>;
+
+        async fn read_messages(
+            &self,
+            _request: Request<ReadMessagesRequest>,
+        ) -> Result<Response<Self::ReadMessagesStream>, Status> {
+            Err(Status::unimplemented("read_messages"))
+        }
+
+        async fn write_table(
+            &self,
+            _request: Request<WriteTableRequest>,
+        ) -> Result<Response<WriteTableResponse>, Status> {
+            Err(Status::unimplemented("write_table"))
+        }
+
+        type ReadTablesStream = tokio_stream::Empty<Result<ReadTablesResponse, Status>>;
+
+        async fn read_tables(
+            &self,
+            _request: Request<ReadTablesRequest>,
+        ) -> Result<Response<Self::ReadTablesStream>, Status> {
+            Err(Status::unimplemented("read_tables"))
+        }
+    }
+
+    /// A running [`MockProxy`] server.
+    ///
+    /// Dropping it kills its runtime, and with it all the open connections, just like a crash.
+    struct MockServer {
+        shutdown_tx: Option<oneshot::Sender<()>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl MockServer {
+        fn start(addr: SocketAddr, proxy: MockProxy) -> Self {
+            let (shutdown_tx, shutdown_rx) = oneshot::channel();
+            let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+            let thread = thread::Builder::new()
+                .name("mock_message_proxy".to_owned())
+                .spawn(move || {
+                    let runtime = runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .unwrap();
+
+                    runtime.block_on(async move {
+                        let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+                        ready_tx.send(()).unwrap();
+
+                        let server = tonic::transport::Server::builder()
+                            .add_service(MessageProxyServiceServer::new(proxy))
+                            .serve_with_incoming(
+                                tonic::transport::server::TcpIncoming::from_listener(
+                                    listener, true, None,
+                                )
+                                .unwrap(),
+                            );
+
+                        tokio::select! {
+                            _ = server => {}
+                            _ = shutdown_rx => {}
+                        }
+                    });
+                })
+                .unwrap();
+
+            ready_rx.recv().unwrap();
+
+            Self {
+                shutdown_tx: Some(shutdown_tx),
+                thread: Some(thread),
+            }
+        }
+    }
+
+    impl Drop for MockServer {
+        fn drop(&mut self) {
+            if let Some(shutdown_tx) = self.shutdown_tx.take() {
+                shutdown_tx.send(()).ok();
+            }
+            if let Some(thread) = self.thread.take() {
+                thread.join().ok();
+            }
+        }
+    }
+
+    /// An address that nothing listens on, until a [`MockServer`] gets started there.
+    fn free_addr() -> SocketAddr {
+        std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    }
+
+    fn client(
+        addr: SocketAddr,
+        reconnect: ReconnectOptions,
+        spool: Option<SpoolOptions>,
+    ) -> Client {
+        let uri = format!("rerun+http://{addr}/proxy").parse().unwrap();
+        Client::new(
+            uri,
+            Options {
+                compression: Compression::Off,
+                flush_timeout: Some(Duration::from_secs(10)),
+                reconnect,
+                spool,
+            },
+        )
+    }
+
+    fn msg(i: usize) -> LogMsg {
+        LogMsg::BlueprintActivationCommand(BlueprintActivationCommand {
+            blueprint_id: StoreId::from_string(StoreKind::Blueprint, i.to_string()),
+            make_active: false,
+            make_default: false,
+        })
+    }
+
+    fn requests(range: std::ops::Range<usize>) -> Vec<WriteMessagesRequest> {
+        range
+            .map(|i| encode(msg(i), Compression::Off).unwrap())
+            .collect()
+    }
+
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let start = Instant::now();
+        while !condition() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "timed out waiting for condition"
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn backoff_grows_while_calls_fail() {
+        let addr = free_addr();
+        let proxy = MockProxy::default();
+        proxy.fail_calls.store(true, Ordering::Relaxed);
+        let _server = MockServer::start(addr, proxy.clone());
+
+        let reconnect = ReconnectOptions {
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(200),
+            multiplier: 2.0,
+        };
+        let client = client(addr, reconnect, None);
+
+        // Connecting works every time, so only the failed calls can slow the client down.
+        wait_until(|| proxy.calls.lock().len() >= 5);
+
+        let calls = proxy.calls.lock().clone();
+        let mut expected_backoff = reconnect.initial_backoff;
+        for (start, next_start) in calls.iter().zip(calls.iter().skip(1)).take(4) {
+            let waited = next_start.duration_since(*start);
+            assert!(
+                waited >= expected_backoff,
+                "waited {waited:?}, expected at least {expected_backoff:?}"
+            );
+            expected_backoff = reconnect.next_backoff(expected_backoff);
+        }
+
+        // Once the server recovers, everything goes through.
+        proxy.fail_calls.store(false, Ordering::Relaxed);
+        for i in 0..3 {
+            client.send(msg(i));
+        }
+        client.flush();
+        wait_until(|| proxy.received.lock().len() >= 3);
+        assert_eq!(*proxy.received.lock(), requests(0..3));
+    }
+
+    #[test]
+    fn spool_is_replayed_in_order_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let addr = free_addr();
+        let proxy = MockProxy::default();
+        let server = MockServer::start(addr, proxy.clone());
+
+        let reconnect = ReconnectOptions {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(50),
+            multiplier: 2.0,
+        };
+        let spool = SpoolOptions {
+            path: dir.path().join("spool"),
+            max_bytes: u64::MAX,
+        };
+        let client = client(addr, reconnect, Some(spool));
+
+        for i in 0..3 {
+            client.send(msg(i));
+        }
+        client.flush();
+        assert_eq!(*proxy.received.lock(), requests(0..3));
+
+        drop(server);
+
+        for i in 3..6 {
+            client.send(msg(i));
+        }
+        client.flush();
+        assert!(client.stats().spooled_bytes > 0, "{:?}", client.stats());
+
+        let _server = MockServer::start(addr, proxy.clone());
+
+        for i in 6..9 {
+            client.send(msg(i));
+        }
+        client.flush();
+        wait_until(|| proxy.received.lock().len() >= 9);
+
+        assert_eq!(*proxy.received.lock(), requests(0..9));
+
+        let stats = client.stats();
+        assert!(stats.num_reconnects >= 1, "{stats:?}");
+        assert_eq!(stats.dropped_bytes, 0, "{stats:?}");
+    }
+
+    #[test]
+    fn spool_drops_data_past_its_byte_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let addr = free_addr();
+        let proxy = MockProxy::default();
+
+        // All the messages have the same size, and the spool fits 2 of them.
+        let num_bytes_per_msg = (std::mem::size_of::<u64>()
+            + encode(msg(0), Compression::Off).unwrap().encoded_len())
+            as u64;
+
+        let reconnect = ReconnectOptions {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(50),
+            multiplier: 2.0,
+        };
+        let spool = SpoolOptions {
+            path: dir.path().join("spool"),
+            max_bytes: 2 * num_bytes_per_msg + num_bytes_per_msg / 2,
+        };
+        let client = client(addr, reconnect, Some(spool));
+
+        for i in 0..5 {
+            client.send(msg(i));
+        }
+        client.flush();
+
+        let stats = client.stats();
+        assert_eq!(stats.spooled_bytes, 2 * num_bytes_per_msg, "{stats:?}");
+        assert_eq!(stats.dropped_bytes, 3 * num_bytes_per_msg, "{stats:?}");
+
+        let _server = MockServer::start(addr, proxy.clone());
+        wait_until(|| proxy.received.lock().len() >= 2);
+        client.flush();
+
+        assert_eq!(*proxy.received.lock(), requests(0..2));
+        assert_eq!(client.stats().num_reconnects, 0);
+    }
+
+    #[test]
+    fn spool_byte_cap_frees_up_as_data_is_read_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let stats = AtomicClientStats::default();
+
+        let requests = requests(0..4);
+        let num_bytes_per_msg = (std::mem::size_of::<u64>() + requests[0].encoded_len()) as u64;
+
+        let mut spool = Spool::new(SpoolOptions {
+            path: dir.path().join("spool"),
+            max_bytes: 2 * num_bytes_per_msg,
+        });
+
+        spool.push(&requests[0], &stats);
+        spool.push(&requests[1], &stats);
+        assert_eq!(spool.pop(&stats).as_ref(), Some(&requests[0]));
+
+        // There's room for one more, now that the first one was read back…
+        spool.push(&requests[2], &stats);
+        assert_eq!(stats.load().dropped_bytes, 0);
+
+        // …but not for two.
+        spool.push(&requests[3], &stats);
+        assert_eq!(stats.load().dropped_bytes, num_bytes_per_msg);
+
+        assert_eq!(spool.pop(&stats).as_ref(), Some(&requests[1]));
+        assert_eq!(spool.pop(&stats).as_ref(), Some(&requests[2]));
+        assert_eq!(spool.pop(&stats), None);
+        assert!(spool.is_empty());
+    }
 }
@@ -0,0 +1,173 @@
+//! Per-client filtering of the message stream, see [`ReadMessagesFilter`].
+
+use std::collections::HashSet;
+
+use nohash_hasher::IntSet;
+
+use re_chunk::Chunk;
+use re_log_encoding::protobuf_conversions::{log_msg_from_proto, log_msg_to_proto};
+use re_log_types::{
+    ApplicationId, EntityPathFilter, EntityPathSubs, LogMsg, ResolvedEntityPathFilter, StoreId,
+    StoreKind, TimelineName,
+};
+use re_protos::log_msg::v1alpha1::{log_msg::Msg, LogMsg as LogMsgProto};
+use re_protos::sdk_comms::v1alpha1::ReadMessagesFilter;
+
+/// The parsed form of a [`ReadMessagesFilter`], applied to the messages sent to one client.
+///
+/// This is stateful: stores are matched to their application through their `SetStoreInfo`,
+/// so all messages of a client have to go through the same filter, in order.
+pub(crate) struct MessageFilter {
+    store_ids: Option<HashSet<StoreId>>,
+    application_ids: Option<HashSet<ApplicationId>>,
+    entity_path_filter: Option<ResolvedEntityPathFilter>,
+    timelines: Option<IntSet<TimelineName>>,
+
+    /// The stores seen so far that belong to one of [`Self::application_ids`].
+    application_stores: HashSet<StoreId>,
+}
+
+impl MessageFilter {
+    pub(crate) fn new(filter: ReadMessagesFilter) -> Result<Self, tonic::Status> {
+        let ReadMessagesFilter {
+            store_ids,
+            application_ids,
+            entity_path_filter,
+            timelines,
+        } = filter;
+
+        let entity_path_filter = entity_path_filter
+            .map(|rules| {
+                EntityPathFilter::parse_strict(&rules)
+                    .and_then(|filter| filter.resolve_strict(&EntityPathSubs::empty()))
+                    .map_err(|err| {
+                        tonic::Status::invalid_argument(format!(
+                            "invalid entity path filter {rules:?}: {err}"
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            store_ids: (!store_ids.is_empty())
+                .then(|| store_ids.into_iter().map(StoreId::from).collect()),
+            application_ids: (!application_ids.is_empty()).then(|| {
+                application_ids
+                    .into_iter()
+                    .map(ApplicationId::from)
+                    .collect()
+            }),
+            entity_path_filter,
+            timelines: (!timelines.is_empty())
+                .then(|| timelines.into_iter().map(TimelineName::from).collect()),
+            application_stores: HashSet::new(),
+        })
+    }
+
+    /// Returns the message to send in place of `msg`, if any.
+    ///
+    /// Chunks are only decoded if there are entity path or timeline restrictions, and only
+    /// re-encoded if some of their timelines were filtered out.
+    pub(crate) fn apply(&mut self, msg: LogMsgProto) -> Option<LogMsgProto> {
+        let store_id = match msg.msg.as_ref()? {
+            Msg::SetStoreInfo(set_store_info) => {
+                let info = set_store_info.info.as_ref()?;
+                let store_id = StoreId::from(info.store_id.clone()?);
+
+                if let Some(application_ids) = &self.application_ids {
+                    let application_id = ApplicationId::from(info.application_id.clone()?);
+                    if application_ids.contains(&application_id) {
+                        self.application_stores.insert(store_id.clone());
+                    }
+                }
+
+                store_id
+            }
+            Msg::ArrowMsg(arrow_msg) => StoreId::from(arrow_msg.store_id.clone()?),
+            Msg::BlueprintActivationCommand(cmd) => StoreId::from(cmd.blueprint_id.clone()?),
+        };
+
+        if let Some(store_ids) = &self.store_ids {
+            if !store_ids.contains(&store_id) {
+                return None;
+            }
+        }
+
+        if self.application_ids.is_some() && !self.application_stores.contains(&store_id) {
+            return None;
+        }
+
+        let is_recording_data =
+            matches!(msg.msg, Some(Msg::ArrowMsg(..))) && store_id.kind == StoreKind::Recording;
+        if is_recording_data && (self.entity_path_filter.is_some() || self.timelines.is_some()) {
+            self.apply_to_chunk(msg)
+        } else {
+            Some(msg)
+        }
+    }
+
+    fn apply_to_chunk(&self, msg: LogMsgProto) -> Option<LogMsgProto> {
+        re_tracing::profile_function!();
+
+        let (store_id, chunk) = match log_msg_from_proto(msg.clone()) {
+            Ok(LogMsg::ArrowMsg(store_id, arrow_msg)) => match Chunk::from_arrow_msg(&arrow_msg) {
+                Ok(chunk) => (store_id, chunk),
+                Err(err) => {
+                    re_log::warn_once!("Failed to filter chunk, sending it as-is: {err}");
+                    return Some(msg);
+                }
+            },
+            Ok(_) => return Some(msg),
+            Err(err) => {
+                re_log::warn_once!("Failed to filter chunk, sending it as-is: {err}");
+                return Some(msg);
+            }
+        };
+
+        if let Some(entity_path_filter) = &self.entity_path_filter {
+            if !entity_path_filter.matches(chunk.entity_path()) {
+                return None;
+            }
+        }
+
+        let Some(timelines) = &self.timelines else {
+            return Some(msg);
+        };
+
+        if chunk.is_static() {
+            return Some(msg);
+        }
+
+        let num_kept = chunk
+            .timelines()
+            .keys()
+            .filter(|timeline| timelines.contains(timeline))
+            .count();
+        if num_kept == 0 {
+            return None;
+        }
+        if num_kept == chunk.num_timelines() {
+            return Some(msg);
+        }
+
+        let chunk = chunk.timelines_sliced(timelines);
+        let encoded = chunk
+            .to_arrow_msg()
+            .map_err(|err| err.to_string())
+            .and_then(|arrow_msg| {
+                log_msg_to_proto(
+                    LogMsg::ArrowMsg(store_id, arrow_msg),
+                    re_log_encoding::Compression::LZ4,
+                )
+                .map_err(|err| err.to_string())
+            });
+
+        match encoded {
+            Ok(msg) => Some(msg),
+            Err(err) => {
+                re_log::warn_once!("Failed to filter chunk, sending it as-is: {err}");
+                Some(msg)
+            }
+        }
+    }
+}
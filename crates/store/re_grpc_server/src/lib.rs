@@ -1,7 +1,11 @@
 //! Server implementation of an in-memory Storage Node.
 
+mod filter;
+mod persist;
 pub mod shutdown;
 
+pub use persist::PersistOptions;
+
 use std::collections::VecDeque;
 use std::net::SocketAddr;
 use std::pin::Pin;
@@ -61,12 +65,13 @@ const MESSAGE_QUEUE_CAPACITY: usize =
 ///
 /// Clients send data to the server via `WriteMessages`. Any sent messages will be stored
 /// in the server's message queue. Messages are only removed if the server hits its configured
-/// memory limit.
+/// memory limit, unless they can be spilled to disk (see [`PersistOptions`]).
 ///
 /// Clients receive data from the server via `ReadMessages`. Upon establishing the stream,
 /// the server sends all messages stored in its message queue, and subscribes the client
 /// to the queue. Any messages sent to the server through `WriteMessages` will be proxied
 /// to the open `ReadMessages` stream.
+/// Clients may restrict which messages they receive, see `ReadMessagesFilter`.
 pub async fn serve(
     addr: SocketAddr,
     memory_limit: MemoryLimit,
@@ -204,7 +209,33 @@ pub fn spawn_from_rx_set(
     shutdown: shutdown::Shutdown,
     rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    spawn_from_rx_set_impl(addr, MessageProxy::new(memory_limit), shutdown, rxs);
+}
+
+/// Like [`spawn_from_rx_set`], but messages evicted from memory are spilled to disk
+/// instead of being dropped.
+///
+/// Fails if the persistence directory can't be set up.
+///
+/// See [`PersistOptions`] for more information.
+pub fn spawn_from_rx_set_with_persistence(
+    addr: SocketAddr,
+    memory_limit: MemoryLimit,
+    persist: PersistOptions,
+    shutdown: shutdown::Shutdown,
+    rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
+) -> std::io::Result<()> {
+    let message_proxy = MessageProxy::new_with_persistence(memory_limit, persist)?;
+    spawn_from_rx_set_impl(addr, message_proxy, shutdown, rxs);
+    Ok(())
+}
+
+fn spawn_from_rx_set_impl(
+    addr: SocketAddr,
+    message_proxy: MessageProxy,
+    shutdown: shutdown::Shutdown,
+    rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
+) {
     let event_tx = message_proxy.event_tx.clone();
 
     tokio::spawn(async move {
@@ -274,7 +305,7 @@ pub fn spawn_with_recv(
     );
     let (channel_table_tx, channel_table_rx) = crossbeam::channel::unbounded();
     let (message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
-        MessageProxy::new_with_recv(memory_limit);
+        MessageProxy::new_with_recv(memory_limit, None);
     tokio::spawn(async move {
         if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
             re_log::error!("message proxy server crashed: {err}");
@@ -352,13 +383,12 @@ pub fn spawn_with_recv(
 
 enum Event {
     /// New client connected, requesting full history and subscribing to new messages.
-    NewClient(
-        oneshot::Sender<(
-            Vec<Msg>,
-            broadcast::Receiver<LogMsgProto>,
-            broadcast::Receiver<TableMsgProto>,
-        )>,
-    ),
+    NewClient(oneshot::Sender<NewClient>),
+
+    /// New client connected, requesting the table history and subscribing to new tables.
+    ///
+    /// Unlike [`Self::NewClient`], this doesn't touch the on-disk history.
+    NewTableClient(oneshot::Sender<NewTableClient>),
 
     /// A client sent a message.
     Message(LogMsgProto),
@@ -367,6 +397,30 @@ enum Event {
     Table(TableMsgProto),
 }
 
+/// Everything a new client needs to catch up, and then follow along.
+struct NewClient {
+    /// Messages that are always sent first, see [`EventLoop::persistent_message_queue`].
+    persistent_messages: Vec<LogMsgProto>,
+
+    /// Messages evicted from memory to disk, oldest first, see [`PersistOptions`].
+    ///
+    /// Resolves once the disk has caught up with the rest of the history.
+    spilled_segments: Option<oneshot::Receiver<Vec<persist::SegmentSnapshot>>>,
+
+    /// The rest of the history, in order of arrival.
+    ordered_messages: Vec<Msg>,
+
+    log_rx: broadcast::Receiver<LogMsgProto>,
+}
+
+/// Everything a new table client needs to catch up, and then follow along.
+struct NewTableClient {
+    /// The table history, in order of arrival.
+    ordered_tables: Vec<TableMsgProto>,
+
+    table_rx: broadcast::Receiver<TableMsgProto>,
+}
+
 #[derive(Clone)]
 struct TableMsgProto {
     id: TableIdProto,
@@ -423,6 +477,9 @@ struct EventLoop {
 
     /// Messages potentially out of order with the rest of the message stream. These are never garbage collected.
     persistent_message_queue: VecDeque<LogMsgProto>,
+
+    /// Where garbage collected log messages go, if anywhere.
+    disk_history: Option<persist::DiskHistoryWriter>,
 }
 
 impl EventLoop {
@@ -431,6 +488,7 @@ impl EventLoop {
         event_rx: mpsc::Receiver<Event>,
         broadcast_log_tx: broadcast::Sender<LogMsgProto>,
         broadcast_table_tx: broadcast::Sender<TableMsgProto>,
+        disk_history: Option<persist::DiskHistoryWriter>,
     ) -> Self {
         Self {
             server_memory_limit,
@@ -440,6 +498,7 @@ impl EventLoop {
             ordered_message_queue: Default::default(),
             ordered_message_bytes: 0,
             persistent_message_queue: Default::default(),
+            disk_history,
         }
     }
 
@@ -451,32 +510,41 @@ impl EventLoop {
 
             match event {
                 Event::NewClient(channel) => self.handle_new_client(channel),
+                Event::NewTableClient(channel) => self.handle_new_table_client(channel),
                 Event::Message(msg) => self.handle_msg(msg),
                 Event::Table(table) => self.handle_table(table),
             }
         }
     }
 
-    fn handle_new_client(
-        &self,
-        channel: oneshot::Sender<(
-            Vec<Msg>,
-            broadcast::Receiver<LogMsgProto>,
-            broadcast::Receiver<TableMsgProto>,
-        )>,
-    ) {
+    fn handle_new_client(&self, channel: oneshot::Sender<NewClient>) {
         channel
-            .send((
+            .send(NewClient {
                 // static messages come first
-                self.persistent_message_queue
+                persistent_messages: self.persistent_message_queue.iter().cloned().collect(),
+                spilled_segments: self
+                    .disk_history
+                    .as_ref()
+                    .map(|disk_history| disk_history.snapshot()),
+                ordered_messages: self.ordered_message_queue.iter().cloned().collect(),
+                log_rx: self.broadcast_log_tx.subscribe(),
+            })
+            .ok();
+    }
+
+    fn handle_new_table_client(&self, channel: oneshot::Sender<NewTableClient>) {
+        channel
+            .send(NewTableClient {
+                ordered_tables: self
+                    .ordered_message_queue
                     .iter()
-                    .cloned()
-                    .map(Msg::from)
-                    .chain(self.ordered_message_queue.iter().cloned())
+                    .filter_map(|msg| match msg {
+                        Msg::Table(table) => Some(table.clone()),
+                        Msg::LogMsg(_) => None,
+                    })
                     .collect(),
-                self.broadcast_log_tx.subscribe(),
-                self.broadcast_table_tx.subscribe(),
-            ))
+                table_rx: self.broadcast_table_tx.subscribe(),
+            })
             .ok();
     }
 
@@ -497,8 +565,16 @@ impl EventLoop {
         // in a separate queue that does *not* get garbage collected.
         use re_protos::log_msg::v1alpha1::log_msg::Msg;
         match inner {
-            // Store info, blueprint activation commands
-            Msg::SetStoreInfo(..) | Msg::BlueprintActivationCommand(..) => {
+            // Store info
+            Msg::SetStoreInfo(..) => {
+                if let Some(disk_history) = &self.disk_history {
+                    disk_history.add_store_info(msg.clone());
+                }
+                self.persistent_message_queue.push_back(msg);
+            }
+
+            // Blueprint activation commands
+            Msg::BlueprintActivationCommand(..) => {
                 self.persistent_message_queue.push_back(msg);
             }
 
@@ -547,10 +623,18 @@ impl EventLoop {
 
         {
             re_tracing::profile_scope!("Drop messages");
-            re_log::info_once!(
-                "Memory limit ({}) exceeded. Dropping old log messages from the server. Clients connecting after this will not see the full history.",
-                re_format::format_bytes(max_bytes as _)
-            );
+            if let Some(disk_history) = &self.disk_history {
+                re_log::info_once!(
+                    "Memory limit ({}) exceeded. Moving old log messages from the server to {:?}.",
+                    re_format::format_bytes(max_bytes as _),
+                    disk_history.dir(),
+                );
+            } else {
+                re_log::info_once!(
+                    "Memory limit ({}) exceeded. Dropping old log messages from the server. Clients connecting after this will not see the full history.",
+                    re_format::format_bytes(max_bytes as _)
+                );
+            }
 
             let bytes_to_free = self.ordered_message_bytes - max_bytes;
 
@@ -562,10 +646,15 @@ impl EventLoop {
                 if let Some(msg) = self.ordered_message_queue.pop_front() {
                     bytes_dropped += msg.total_size_bytes();
                     messages_dropped += 1;
+
+                    if let (Some(disk_history), Msg::LogMsg(msg)) = (&self.disk_history, msg) {
+                        disk_history.spill(msg);
+                    }
                 } else {
                     break;
                 }
             }
+            self.ordered_message_bytes -= bytes_dropped.min(self.ordered_message_bytes);
 
             re_log::trace!(
                 "Dropped {} bytes in {messages_dropped} message(s)",
@@ -589,11 +678,23 @@ pub struct MessageProxy {
 
 impl MessageProxy {
     pub fn new(server_memory_limit: MemoryLimit) -> Self {
-        Self::new_with_recv(server_memory_limit).0
+        Self::new_with_recv(server_memory_limit, None).0
+    }
+
+    /// Messages evicted from memory are spilled to disk rather than dropped, see [`PersistOptions`].
+    ///
+    /// Fails if the persistence directory can't be set up.
+    pub fn new_with_persistence(
+        server_memory_limit: MemoryLimit,
+        persist: PersistOptions,
+    ) -> std::io::Result<Self> {
+        let disk_history = persist::DiskHistoryWriter::spawn(persist)?;
+        Ok(Self::new_with_recv(server_memory_limit, Some(disk_history)).0)
     }
 
     fn new_with_recv(
         server_memory_limit: MemoryLimit,
+        disk_history: Option<persist::DiskHistoryWriter>,
     ) -> (
         Self,
         broadcast::Receiver<LogMsgProto>,
@@ -609,6 +710,7 @@ impl MessageProxy {
                 event_rx,
                 broadcast_log_tx,
                 broadcast_table_tx,
+                disk_history,
             )
             .run_in_place()
             .await;
@@ -632,13 +734,21 @@ impl MessageProxy {
         self.event_tx.send(Event::Table(table)).await.ok();
     }
 
-    async fn new_client_message_stream(&self) -> ReadMessagesStream {
+    async fn new_client_message_stream(
+        &self,
+        mut filter: Option<filter::MessageFilter>,
+    ) -> ReadMessagesStream {
         let (sender, receiver) = oneshot::channel();
         if let Err(err) = self.event_tx.send(Event::NewClient(sender)).await {
             re_log::error!("Error accepting new client: {err}");
             return Box::pin(tokio_stream::empty());
         };
-        let (history, log_channel, _) = match receiver.await {
+        let NewClient {
+            persistent_messages,
+            spilled_segments,
+            ordered_messages,
+            log_rx,
+        } = match receiver.await {
             Ok(v) => v,
             Err(err) => {
                 re_log::error!("Error accepting new client: {err}");
@@ -646,41 +756,71 @@ impl MessageProxy {
             }
         };
 
-        let history = tokio_stream::iter(
-            history
-                .into_iter()
-                .filter_map(|log_msg| {
-                    if let Msg::LogMsg(log_msg) = log_msg {
-                        Some(ReadMessagesResponse {
-                            log_msg: Some(log_msg),
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .map(Ok),
-        );
-        let channel = BroadcastStream::new(log_channel).map(|result| {
-            result
-                .map(|log_msg| ReadMessagesResponse {
-                    log_msg: Some(log_msg),
-                })
-                .map_err(|err| {
-                    re_log::error!("Error reading message from broadcast channel: {err}");
-                    tonic::Status::internal("internal channel error")
-                })
+        let persistent_history = tokio_stream::iter(persistent_messages);
+
+        let spilled_segments = match spilled_segments {
+            Some(spilled_segments) => spilled_segments.await.unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        // Segments are read back lazily, as the client consumes them.
+        let (spilled_tx, spilled_rx) = mpsc::channel(16);
+        if !spilled_segments.is_empty() {
+            tokio::task::spawn_blocking(move || {
+                persist::replay_segments(&spilled_segments, &spilled_tx);
+            });
+        }
+        let spilled_history = tokio_stream::wrappers::ReceiverStream::new(spilled_rx);
+
+        let ordered_history = tokio_stream::iter(ordered_messages.into_iter().filter_map(|msg| {
+            if let Msg::LogMsg(log_msg) = msg {
+                Some(log_msg)
+            } else {
+                None
+            }
+        }));
+
+        let history = persistent_history
+            .chain(spilled_history)
+            .chain(ordered_history)
+            .map(Ok);
+
+        let channel = BroadcastStream::new(log_rx).map(|result| {
+            result.map_err(|err| {
+                re_log::error!("Error reading message from broadcast channel: {err}");
+                tonic::Status::internal("internal channel error")
+            })
         });
 
-        Box::pin(history.chain(channel))
+        // The same filter sees the history, then the live messages, in order.
+        let stream = history
+            .chain(channel)
+            .filter_map(move |result| match result {
+                Ok(log_msg) => {
+                    let log_msg = match &mut filter {
+                        Some(filter) => filter.apply(log_msg)?,
+                        None => log_msg,
+                    };
+                    Some(Ok(ReadMessagesResponse {
+                        log_msg: Some(log_msg),
+                    }))
+                }
+                Err(err) => Some(Err(err)),
+            });
+
+        Box::pin(stream)
     }
 
     async fn new_client_table_stream(&self) -> ReadTablesStream {
         let (sender, receiver) = oneshot::channel();
-        if let Err(err) = self.event_tx.send(Event::NewClient(sender)).await {
+        if let Err(err) = self.event_tx.send(Event::NewTableClient(sender)).await {
             re_log::error!("Error accepting new client: {err}");
             return Box::pin(tokio_stream::empty());
         };
-        let (history, _, table_channel) = match receiver.await {
+        let NewTableClient {
+            ordered_tables,
+            table_rx: table_channel,
+        } = match receiver.await {
             Ok(v) => v,
             Err(err) => {
                 re_log::error!("Error accepting new client: {err}");
@@ -689,17 +829,11 @@ impl MessageProxy {
         };
 
         let history = tokio_stream::iter(
-            history
+            ordered_tables
                 .into_iter()
-                .filter_map(|table| {
-                    if let Msg::Table(table) = table {
-                        Some(ReadTablesResponse {
-                            id: Some(table.id),
-                            data: Some(table.data),
-                        })
-                    } else {
-                        None
-                    }
+                .map(|table| ReadTablesResponse {
+                    id: Some(table.id),
+                    data: Some(table.data),
                 })
                 .map(Ok),
         );
@@ -760,9 +894,17 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
 
     async fn read_messages(
         &self,
-        _: tonic::Request<ReadMessagesRequest>,
+        request: tonic::Request<ReadMessagesRequest>,
     ) -> tonic::Result<tonic::Response<Self::ReadMessagesStream>> {
-        Ok(tonic::Response::new(self.new_client_message_stream().await))
+        let filter = request
+            .into_inner()
+            .filter
+            .map(filter::MessageFilter::new)
+            .transpose()?;
+
+        Ok(tonic::Response::new(
+            self.new_client_message_stream(filter).await,
+        ))
     }
 
     type ReadTablesStream = ReadTablesStream;
@@ -935,6 +1077,10 @@ mod tests {
     }
 
     async fn setup_with_memory_limit(memory_limit: MemoryLimit) -> (Completion, SocketAddr) {
+        setup_with_message_proxy(super::MessageProxy::new(memory_limit)).await
+    }
+
+    async fn setup_with_message_proxy(message_proxy: MessageProxy) -> (Completion, SocketAddr) {
         let completion = Completion::new();
 
         let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -944,9 +1090,7 @@ mod tests {
             let completion = completion.clone();
             async move {
                 tonic::transport::Server::builder()
-                    .add_service(MessageProxyServiceServer::new(super::MessageProxy::new(
-                        memory_limit,
-                    )))
+                    .add_service(MessageProxyServiceServer::new(message_proxy))
                     .serve_with_incoming_shutdown(
                         TcpIncoming::from_listener(tcp_listener, true, None).unwrap(),
                         completion.wait(),
@@ -991,7 +1135,10 @@ mod tests {
         let messages = fake_log_stream_blueprint(3);
 
         // start reading
-        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
 
         // write a few messages
         client
@@ -1040,7 +1187,10 @@ mod tests {
             .unwrap();
 
         // Start reading now - we should receive full history at this point:
-        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
 
         let actual = read_log_stream(&mut log_stream, messages.len()).await;
         assert_eq!(messages, actual);
@@ -1060,7 +1210,7 @@ mod tests {
         for consumer in &mut consumers {
             log_streams.push(
                 consumer
-                    .read_messages(ReadMessagesRequest {})
+                    .read_messages(ReadMessagesRequest { filter: None })
                     .await
                     .unwrap(),
             );
@@ -1099,7 +1249,7 @@ mod tests {
         for consumer in &mut consumers {
             log_streams.push(
                 consumer
-                    .read_messages(ReadMessagesRequest {})
+                    .read_messages(ReadMessagesRequest { filter: None })
                     .await
                     .unwrap(),
             );
@@ -1153,7 +1303,10 @@ mod tests {
             .unwrap();
 
         // Start reading
-        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
         let mut actual = vec![];
         loop {
             let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
@@ -1197,7 +1350,10 @@ mod tests {
             .unwrap();
 
         // Start reading
-        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
         let mut actual = vec![];
         loop {
             let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
@@ -1228,7 +1384,10 @@ mod tests {
         let messages = fake_log_stream_blueprint(3);
 
         // Start reading
-        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
 
         // Write a few messages
         client
@@ -1248,4 +1407,283 @@ mod tests {
 
         completion.finish();
     }
+
+    #[tokio::test]
+    async fn memory_limit_spills_messages_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let persist = PersistOptions {
+            dir: dir.path().to_owned(),
+            max_bytes: u64::MAX,
+        };
+
+        // Use an absurdly low memory limit to force all messages to be spilled immediately
+        let (completion, addr) = setup_with_message_proxy(
+            MessageProxy::new_with_persistence(MemoryLimit::from_bytes(1), persist).unwrap(),
+        )
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        // Write some messages
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .clone()
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+            ))
+            .await
+            .unwrap();
+
+        // A late client still gets the full history, in order:
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(messages, actual);
+
+        // Each spilled segment is a valid recording on its own.
+        for entry in std::fs::read_dir(dir.path()).unwrap() {
+            let file = std::io::BufReader::new(std::fs::File::open(entry.unwrap().path()).unwrap());
+            let segment = re_log_encoding::decoder::Decoder::new(file)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(&segment[0], &messages[0]);
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn table_clients_and_repeated_store_infos_do_not_grow_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let persist = PersistOptions {
+            dir: dir.path().to_owned(),
+            max_bytes: u64::MAX,
+        };
+
+        let (completion, addr) = setup_with_message_proxy(
+            MessageProxy::new_with_persistence(MemoryLimit::from_bytes(1), persist).unwrap(),
+        )
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        for _ in 0..2 {
+            // The store info is re-sent, like an SDK does after reconnecting.
+            client
+                .write_messages(tokio_stream::iter(
+                    messages
+                        .clone()
+                        .into_iter()
+                        .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                        .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+                ))
+                .await
+                .unwrap();
+
+            // Table clients don't care about the message history, and must not cut segments.
+            client.read_tables(ReadTablesRequest {}).await.unwrap();
+
+            // Neither do message clients: they read the current segment back as it is.
+            client
+                .read_messages(ReadMessagesRequest { filter: None })
+                .await
+                .unwrap();
+        }
+
+        let segments = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert_eq!(segments.len(), 1, "{segments:?}");
+
+        // Each segment starts with the latest store info of each store, once.
+        for path in segments {
+            let file = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+            let segment = re_log_encoding::decoder::Decoder::new(file)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let num_store_infos = segment
+                .iter()
+                .filter(|msg| matches!(msg, LogMsg::SetStoreInfo(..)))
+                .count();
+            assert_eq!(num_store_infos, 1, "{path:?}");
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn disk_limit_drops_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let persist = PersistOptions {
+            dir: dir.path().to_owned(),
+            max_bytes: 1,
+        };
+
+        // Use absurdly low limits to force all messages to be dropped immediately from history
+        let (completion, addr) = setup_with_message_proxy(
+            MessageProxy::new_with_persistence(MemoryLimit::from_bytes(1), persist).unwrap(),
+        )
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        // Write some messages
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .clone()
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+            ))
+            .await
+            .unwrap();
+
+        // Start reading
+        let mut log_stream = client
+            .read_messages(ReadMessagesRequest { filter: None })
+            .await
+            .unwrap();
+        let mut actual = vec![];
+        loop {
+            let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
+            tokio::pin!(timeout_stream);
+            let timeout_result = timeout_stream.try_next().await;
+            match timeout_result {
+                Ok(Some(value)) => {
+                    actual.push(log_msg_from_proto(value.unwrap().log_msg.unwrap()).unwrap());
+                }
+
+                // Stream closed | Timed out
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        // Nothing fits on disk either, so we should see the static message, and the last message sent.
+        assert_eq!(actual.len(), 2);
+        assert_eq!(&actual[0], &messages[0]);
+        assert_eq!(&actual[1], messages.last().unwrap());
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn filter_by_store_and_entity() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+        let other_messages = fake_log_stream_recording(3);
+
+        let store_id = messages[0].store_id().clone();
+        let filter = |entity_path_filter: &str| ReadMessagesRequest {
+            filter: Some(re_protos::sdk_comms::v1alpha1::ReadMessagesFilter {
+                store_ids: vec![store_id.clone().into()],
+                entity_path_filter: Some(entity_path_filter.to_owned()),
+                ..Default::default()
+            }),
+        };
+
+        // Write the first half of both recordings, which will be part of history
+        let producer = make_client(addr).await;
+        let write = |messages: Vec<LogMsg>| {
+            let mut client = producer.clone();
+            async move {
+                client
+                    .write_messages(tokio_stream::iter(
+                        messages
+                            .into_iter()
+                            .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                            .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+                    ))
+                    .await
+                    .unwrap();
+            }
+        };
+        write(messages[..2].to_vec()).await;
+        write(other_messages[..2].to_vec()).await;
+
+        let mut matching_stream = client
+            .read_messages(filter("+ /test_entity"))
+            .await
+            .unwrap();
+        let mut excluded_stream = client
+            .read_messages(filter("+ /**\n- /test_entity"))
+            .await
+            .unwrap();
+
+        // Then the second half, which will be received live
+        write(other_messages[2..].to_vec()).await;
+        write(messages[2..].to_vec()).await;
+
+        // Only the messages of the selected recording are received
+        let actual = read_log_stream(&mut matching_stream, messages.len()).await;
+        assert_eq!(messages, actual);
+
+        // Only its store info is left when excluding its only entity
+        let actual = read_log_stream(&mut excluded_stream, 1).await;
+        assert_eq!(&messages[..1], &actual);
+        let timeout_stream = excluded_stream
+            .get_mut()
+            .timeout(Duration::from_millis(100));
+        tokio::pin!(timeout_stream);
+        assert!(timeout_stream.try_next().await.is_err());
+
+        // Bad rules are rejected
+        let status = client
+            .read_messages(filter("+ /test_entity/{unknown}"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn filter_by_timeline() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .clone()
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+            ))
+            .await
+            .unwrap();
+
+        let filter = |timeline: &str| ReadMessagesRequest {
+            filter: Some(re_protos::sdk_comms::v1alpha1::ReadMessagesFilter {
+                timelines: vec![re_protos::common::v1alpha1::Timeline {
+                    name: timeline.to_owned(),
+                }],
+                ..Default::default()
+            }),
+        };
+
+        // The chunks only have this one timeline, so they are sent as-is
+        let mut log_stream = client.read_messages(filter("log_time")).await.unwrap();
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(messages, actual);
+
+        // Temporal chunks without the timeline are dropped
+        let mut log_stream = client.read_messages(filter("frame")).await.unwrap();
+        let actual = read_log_stream(&mut log_stream, 1).await;
+        assert_eq!(&messages[..1], &actual);
+        let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
+        tokio::pin!(timeout_stream);
+        assert!(timeout_stream.try_next().await.is_err());
+
+        completion.finish();
+    }
 }
@@ -0,0 +1,469 @@
+//! Spilling of the server's message history to disk, see [`PersistOptions`].
+
+use std::collections::{BTreeMap, VecDeque};
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use re_log_encoding::encoder::DroppableEncoder;
+use re_log_encoding::protobuf_conversions::{log_msg_from_proto, log_msg_to_proto};
+use re_log_types::{LogMsg, StoreId};
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
+
+/// Where, and how much of, the message history to keep on disk.
+///
+/// When persistence is enabled, the messages that would otherwise be dropped because the server
+/// hit its memory limit are instead appended to `.rrd` segments in [`Self::dir`].
+/// Clients that connect later are sent the content of these segments before the in-memory history.
+///
+/// Only recording data is spilled: tables are still dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersistOptions {
+    /// The directory the segments are written to. It is created if needed.
+    ///
+    /// Segments left over from a previous run are deleted on startup.
+    pub dir: PathBuf,
+
+    /// The oldest segments are deleted once the segments take up more than this.
+    pub max_bytes: u64,
+}
+
+impl PersistOptions {
+    /// Every segment starts with this prefix, followed by its index.
+    const SEGMENT_PREFIX: &'static str = "segment-";
+
+    fn segment_path(&self, index: u64) -> PathBuf {
+        self.dir
+            .join(format!("{}{index:06}.rrd", Self::SEGMENT_PREFIX))
+    }
+
+    fn is_segment(path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "rrd")
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(Self::SEGMENT_PREFIX))
+    }
+
+    /// Segments are rotated at this size, so that retention can free up space in small steps.
+    fn max_segment_bytes(&self) -> u64 {
+        (self.max_bytes / 8).max(1)
+    }
+}
+
+struct Segment {
+    path: PathBuf,
+    encoder: DroppableEncoder<std::io::BufWriter<std::fs::File>>,
+    num_bytes: u64,
+}
+
+/// How many evicted messages may wait to be written to disk at once.
+///
+/// If the disk can't keep up, further messages are dropped rather than piling up in memory, which
+/// would defeat the server's memory limit.
+const MAX_QUEUED_SPILLS: usize = 1024;
+
+/// The latest store info of each store, written at the start of each new segment.
+type StoreInfos = Arc<Mutex<BTreeMap<StoreId, LogMsg>>>;
+
+struct SnapshotRequest {
+    /// How many messages had been spilled when the snapshot was requested.
+    num_spilled: u64,
+    reply: tokio::sync::oneshot::Sender<Vec<SegmentSnapshot>>,
+}
+
+/// A segment to read back with [`replay_segments`].
+pub(crate) struct SegmentSnapshot {
+    path: PathBuf,
+
+    /// For the segment that is still being written to: how many bytes of it had been written when
+    /// the snapshot was taken. Anything after that is more recent than the snapshot.
+    written_bytes: Option<u64>,
+}
+
+/// Handle to a [`DiskHistory`] living on its own thread, so that the server's event loop never
+/// blocks on disk IO.
+///
+/// A snapshot always contains everything that was spilled before it was requested, and nothing
+/// that was spilled after.
+pub(crate) struct DiskHistoryWriter {
+    dir: PathBuf,
+    store_infos: StoreInfos,
+    spill_tx: crossbeam::channel::Sender<LogMsgProto>,
+    snapshot_tx: crossbeam::channel::Sender<SnapshotRequest>,
+
+    /// How many messages were handed over to the writer thread so far.
+    num_spilled: AtomicU64,
+}
+
+impl DiskHistoryWriter {
+    /// Sets up the directory, then starts the writer thread.
+    ///
+    /// The thread exits once the writer is dropped.
+    pub(crate) fn spawn(options: PersistOptions) -> std::io::Result<Self> {
+        let dir = options.dir.clone();
+        let store_infos = StoreInfos::default();
+        let disk_history = DiskHistory::new(options, store_infos.clone())?;
+
+        let (spill_tx, spill_rx) = crossbeam::channel::bounded(MAX_QUEUED_SPILLS);
+        // Unbounded: there is at most one request per connecting client, and they are tiny.
+        let (snapshot_tx, snapshot_rx) = crossbeam::channel::unbounded();
+
+        std::thread::Builder::new()
+            .name("grpc_server_disk_history".to_owned())
+            .spawn(move || disk_history.run(&spill_rx, &snapshot_rx))?;
+
+        Ok(Self {
+            dir,
+            store_infos,
+            spill_tx,
+            snapshot_tx,
+            num_spilled: AtomicU64::new(0),
+        })
+    }
+
+    pub(crate) fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Remember a store info, to be written at the start of each new segment.
+    ///
+    /// Replaces any earlier store info for the same store.
+    pub(crate) fn add_store_info(&self, msg: LogMsgProto) {
+        match log_msg_from_proto(msg) {
+            Ok(msg) => {
+                self.store_infos.lock().insert(msg.store_id().clone(), msg);
+            }
+            Err(err) => re_log::error!("Failed to decode store info: {err}"),
+        }
+    }
+
+    /// Append a message that is being evicted from memory.
+    ///
+    /// The message is dropped if too many messages are waiting to be written already.
+    pub(crate) fn spill(&self, msg: LogMsgProto) {
+        match self.spill_tx.try_send(msg) {
+            Ok(()) => {
+                self.num_spilled.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(crossbeam::channel::TrySendError::Full(_)) => {
+                re_log::warn_once!(
+                    "Writing the message history to {:?} can't keep up. Dropping old log messages instead. Clients connecting after this will not see the full history.",
+                    self.dir
+                );
+            }
+            Err(crossbeam::channel::TrySendError::Disconnected(_)) => {
+                re_log::error_once!("The message history writer for {:?} is gone", self.dir);
+            }
+        }
+    }
+
+    /// All the segments written so far, oldest first, ready to be read back.
+    ///
+    /// The segments are sent through the returned channel once everything spilled so far has
+    /// been written.
+    pub(crate) fn snapshot(&self) -> tokio::sync::oneshot::Receiver<Vec<SegmentSnapshot>> {
+        let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+        let request = SnapshotRequest {
+            num_spilled: self.num_spilled.load(Ordering::Relaxed),
+            reply: reply_tx,
+        };
+        if self.snapshot_tx.send(request).is_err() {
+            re_log::error_once!("The message history writer for {:?} is gone", self.dir);
+        }
+        reply_rx
+    }
+}
+
+/// The part of the message history that was evicted from memory, as a sequence of `.rrd` segments.
+struct DiskHistory {
+    options: PersistOptions,
+
+    /// Written at the start of each new segment, so that every segment is a valid recording on its own.
+    ///
+    /// Only the latest store info of each store is kept.
+    store_infos: StoreInfos,
+
+    /// Finished segments, oldest first, with their size in bytes.
+    segments: VecDeque<(PathBuf, u64)>,
+
+    /// The segment currently being written to, if any.
+    current: Option<Segment>,
+
+    next_index: u64,
+}
+
+impl DiskHistory {
+    fn new(options: PersistOptions, store_infos: StoreInfos) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&options.dir)?;
+
+        // The store infos of whatever was in there are gone, so these can't be replayed anyway.
+        for entry in std::fs::read_dir(&options.dir)? {
+            let path = entry?.path();
+            if PersistOptions::is_segment(&path) {
+                re_log::debug!("Removing stale segment {path:?}");
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(Self {
+            options,
+            store_infos,
+            segments: VecDeque::new(),
+            current: None,
+            next_index: 0,
+        })
+    }
+
+    /// Writes the spilled messages until the [`DiskHistoryWriter`] is dropped, answering snapshot
+    /// requests along the way.
+    fn run(
+        mut self,
+        spill_rx: &crossbeam::channel::Receiver<LogMsgProto>,
+        snapshot_rx: &crossbeam::channel::Receiver<SnapshotRequest>,
+    ) {
+        // Requests come in the order they were made, so these are sorted by `num_spilled`.
+        let mut pending_snapshots: VecDeque<SnapshotRequest> = VecDeque::new();
+        let mut num_spilled = 0;
+
+        loop {
+            self.answer_snapshots(&mut pending_snapshots, num_spilled);
+
+            // A pending snapshot is waiting for messages that were spilled before it was requested.
+            let msg = if pending_snapshots.is_empty() {
+                crossbeam::channel::select! {
+                    recv(spill_rx) -> msg => msg,
+                    recv(snapshot_rx) -> request => match request {
+                        Ok(request) => {
+                            pending_snapshots.push_back(request);
+                            continue;
+                        }
+                        Err(_) => break,
+                    },
+                }
+            } else {
+                spill_rx.recv()
+            };
+            let Ok(msg) = msg else {
+                break;
+            };
+
+            // Snapshots that were requested before this message was spilled must not contain it.
+            pending_snapshots.extend(snapshot_rx.try_iter());
+            self.answer_snapshots(&mut pending_snapshots, num_spilled);
+
+            self.spill(&msg);
+            num_spilled += 1;
+        }
+    }
+
+    /// Answers the pending requests for which all `num_spilled` messages have been written.
+    fn answer_snapshots(&mut self, pending: &mut VecDeque<SnapshotRequest>, num_spilled: u64) {
+        while pending
+            .front()
+            .is_some_and(|request| request.num_spilled <= num_spilled)
+        {
+            if let Some(request) = pending.pop_front() {
+                request.reply.send(self.snapshot()).ok();
+            }
+        }
+    }
+
+    /// Append a message that is being evicted from memory.
+    fn spill(&mut self, msg: &LogMsgProto) {
+        re_tracing::profile_function!();
+
+        if let Err(err) = self.try_spill(msg) {
+            re_log::error!(
+                "Failed to write message history to {:?}: {err}",
+                self.options.dir
+            );
+            // Start over with a fresh segment next time.
+            self.current = None;
+        }
+
+        self.gc();
+    }
+
+    fn try_spill(&mut self, msg: &LogMsgProto) -> anyhow::Result<()> {
+        let msg = log_msg_from_proto(msg.clone())?;
+
+        if self.current.is_none() {
+            self.current = Some(self.start_segment()?);
+        }
+
+        if let Some(segment) = &mut self.current {
+            segment.num_bytes += segment.encoder.append(&msg)?;
+
+            if self.options.max_segment_bytes() <= segment.num_bytes {
+                self.finish_segment();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn start_segment(&mut self) -> anyhow::Result<Segment> {
+        let path = self.options.segment_path(self.next_index);
+        self.next_index += 1;
+
+        re_log::debug!("Starting new segment {path:?}…");
+
+        let file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        let mut encoder = DroppableEncoder::new(
+            re_build_info::CrateVersion::LOCAL,
+            re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+            file,
+        )?;
+
+        let mut num_bytes = 0;
+        for store_info in self.store_infos.lock().values() {
+            num_bytes += encoder.append(store_info)?;
+        }
+
+        Ok(Segment {
+            path,
+            encoder,
+            num_bytes,
+        })
+    }
+
+    /// Close the current segment.
+    fn finish_segment(&mut self) {
+        let Some(mut segment) = self.current.take() else {
+            return;
+        };
+
+        let result = segment
+            .encoder
+            .finish()
+            .map_err(anyhow::Error::from)
+            .and_then(|()| Ok(segment.encoder.flush_blocking()?));
+
+        match result {
+            Ok(()) => self.segments.push_back((segment.path, segment.num_bytes)),
+            Err(err) => {
+                re_log::error!("Failed to finish segment {:?}: {err}", segment.path);
+                std::fs::remove_file(&segment.path).ok();
+            }
+        }
+    }
+
+    /// Delete the oldest segments until we are back under the disk limit.
+    fn gc(&mut self) {
+        let current_bytes = self.current.as_ref().map_or(0, |segment| segment.num_bytes);
+        let mut total_bytes = current_bytes
+            + self
+                .segments
+                .iter()
+                .map(|(_, num_bytes)| num_bytes)
+                .sum::<u64>();
+
+        while self.options.max_bytes < total_bytes {
+            let Some((path, num_bytes)) = self.segments.pop_front() else {
+                break;
+            };
+
+            re_log::info_once!(
+                "Disk limit ({}) of {:?} exceeded. Deleting the oldest message history. Clients connecting after this will not see the full history.",
+                re_format::format_bytes(self.options.max_bytes as _),
+                self.options.dir,
+            );
+
+            if let Err(err) = std::fs::remove_file(&path) {
+                re_log::warn!("Failed to remove segment {path:?}: {err}");
+            }
+            total_bytes -= num_bytes;
+        }
+    }
+
+    /// All the segments written so far, oldest first, ready to be read back.
+    ///
+    /// The current segment is flushed rather than finished, so that connecting clients don't each
+    /// start a new segment.
+    fn snapshot(&mut self) -> Vec<SegmentSnapshot> {
+        let mut snapshot = self
+            .segments
+            .iter()
+            .map(|(path, _)| SegmentSnapshot {
+                path: path.clone(),
+                written_bytes: None,
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(segment) = &mut self.current {
+            let written_bytes = segment
+                .encoder
+                .flush_blocking()
+                .and_then(|()| std::fs::metadata(&segment.path));
+            match written_bytes {
+                Ok(metadata) => snapshot.push(SegmentSnapshot {
+                    path: segment.path.clone(),
+                    written_bytes: Some(metadata.len()),
+                }),
+                Err(err) => re_log::error!("Failed to flush segment {:?}: {err}", segment.path),
+            }
+        }
+
+        snapshot
+    }
+}
+
+/// Read back the given segments, in order, and send their content through `tx`.
+///
+/// The store infos at the start of each segment are skipped: these are part of the in-memory
+/// history already.
+/// Segments that were deleted in the meantime are skipped as well.
+///
+/// This is blocking, and stops early if `tx` is closed.
+pub(crate) fn replay_segments(
+    segments: &[SegmentSnapshot],
+    tx: &tokio::sync::mpsc::Sender<LogMsgProto>,
+) {
+    re_tracing::profile_function!();
+
+    for SegmentSnapshot {
+        path,
+        written_bytes,
+    } in segments
+    {
+        let file = match std::fs::File::open(path) {
+            Ok(file) => std::io::BufReader::new(file.take(written_bytes.unwrap_or(u64::MAX))),
+            Err(err) => {
+                re_log::debug!("Skipping segment {path:?}: {err}");
+                continue;
+            }
+        };
+
+        let decoder = match re_log_encoding::decoder::Decoder::new(file) {
+            Ok(decoder) => decoder,
+            Err(err) => {
+                re_log::error!("Failed to read segment {path:?}: {err}");
+                continue;
+            }
+        };
+
+        for msg in decoder {
+            let msg = match msg {
+                Ok(LogMsg::SetStoreInfo(..)) => continue,
+                Ok(msg) => log_msg_to_proto(msg, re_log_encoding::Compression::LZ4),
+                Err(err) => {
+                    re_log::error!("Failed to read segment {path:?}: {err}");
+                    break;
+                }
+            };
+
+            match msg {
+                Ok(msg) => {
+                    if tx.blocking_send(msg).is_err() {
+                        return; // The client is gone.
+                    }
+                }
+                Err(err) => re_log::error!("Failed to encode message: {err}"),
+            }
+        }
+    }
+}
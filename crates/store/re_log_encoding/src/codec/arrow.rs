@@ -50,6 +50,15 @@ pub(crate) fn encode_arrow(
     let data = match compression {
         crate::Compression::Off => uncompressed,
         crate::Compression::LZ4 => lz4_flex::block::compress(&uncompressed),
+
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::Compression::Zstd { level } => zstd::bulk::compress(&uncompressed, level as i32)
+            .map_err(crate::encoder::EncodeError::Zstd)?,
+
+        #[cfg(target_arch = "wasm32")]
+        crate::Compression::Zstd { .. } => {
+            return Err(CodecError::UnsupportedCompression(compression).into());
+        }
     };
 
     Ok(Payload {
@@ -72,6 +81,26 @@ pub(crate) fn decode_arrow(
             lz4_flex::block::decompress_into(data, &mut uncompressed)?;
             uncompressed.as_slice()
         }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::Compression::Zstd { .. } => {
+            uncompressed = zstd::bulk::decompress(data, uncompressed_size)
+                .map_err(crate::decoder::DecodeError::Zstd)?;
+            uncompressed.as_slice()
+        }
+
+        // The `zstd` crate needs a C toolchain, so we decode with a pure-Rust implementation on web.
+        #[cfg(target_arch = "wasm32")]
+        crate::Compression::Zstd { .. } => {
+            use std::io::Read as _;
+
+            uncompressed.reserve(uncompressed_size);
+            ruzstd::StreamingDecoder::new(data)
+                .map_err(std::io::Error::other)
+                .and_then(|mut decoder| decoder.read_to_end(&mut uncompressed))
+                .map_err(crate::decoder::DecodeError::Zstd)?;
+            uncompressed.as_slice()
+        }
     };
 
     Ok(read_arrow_from_bytes(&mut &data[..])?)
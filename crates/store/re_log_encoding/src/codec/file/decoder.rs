@@ -2,10 +2,20 @@ use super::{MessageHeader, MessageKind};
 use crate::codec::arrow::decode_arrow;
 use crate::codec::CodecError;
 use crate::decoder::DecodeError;
+use crate::protobuf_conversions::compression_from_proto;
+use re_build_info::CrateVersion;
 use re_log_types::LogMsg;
 use re_protos::missing_field;
 
-pub(crate) fn decode(data: &mut impl std::io::Read) -> Result<(u64, Option<LogMsg>), DecodeError> {
+/// Decodes the next message in `data`.
+///
+/// See [`decode_bytes`] for `recorded_version`. If `apply_migrations` is false, chunks are left
+/// as they were written regardless of `recorded_version`.
+pub(crate) fn decode(
+    data: &mut impl std::io::Read,
+    recorded_version: Option<CrateVersion>,
+    apply_migrations: bool,
+) -> Result<(u64, Option<LogMsg>), DecodeError> {
     let mut read_bytes = 0u64;
     let header = MessageHeader::decode(data)?;
     read_bytes += std::mem::size_of::<MessageHeader>() as u64 + header.len;
@@ -13,15 +23,31 @@ pub(crate) fn decode(data: &mut impl std::io::Read) -> Result<(u64, Option<LogMs
     let mut buf = vec![0; header.len as usize];
     data.read_exact(&mut buf[..])?;
 
-    let msg = decode_bytes(header.kind, &buf)?;
+    let msg = decode_bytes_impl(header.kind, &buf, recorded_version, apply_migrations)?;
 
     Ok((read_bytes, msg))
 }
 
 /// Decode a message of kind `message_kind` from `buf`.
 ///
+/// `recorded_version` is the version of the SDK that wrote the data, if known: chunks only go
+/// through the migrations that it needs, see [`re_sorbet::MIGRATIONS`].
+///
 /// `Ok(None)` returned from this function marks the end of the file stream.
-pub fn decode_bytes(message_kind: MessageKind, buf: &[u8]) -> Result<Option<LogMsg>, DecodeError> {
+pub fn decode_bytes(
+    message_kind: MessageKind,
+    buf: &[u8],
+    recorded_version: Option<CrateVersion>,
+) -> Result<Option<LogMsg>, DecodeError> {
+    decode_bytes_impl(message_kind, buf, recorded_version, true)
+}
+
+fn decode_bytes_impl(
+    message_kind: MessageKind,
+    buf: &[u8],
+    recorded_version: Option<CrateVersion>,
+    apply_migrations: bool,
+) -> Result<Option<LogMsg>, DecodeError> {
     use re_protos::external::prost::Message as _;
     use re_protos::log_msg::v1alpha1::{
         ArrowMsg, BlueprintActivationCommand, Encoding, SetStoreInfo,
@@ -41,7 +67,7 @@ pub fn decode_bytes(message_kind: MessageKind, buf: &[u8]) -> Result<Option<LogM
             let batch = decode_arrow(
                 &arrow_msg.payload,
                 arrow_msg.uncompressed_size as usize,
-                arrow_msg.compression().into(),
+                compression_from_proto(arrow_msg.compression)?,
             )?;
 
             let store_id: re_log_types::StoreId = arrow_msg
@@ -49,7 +75,11 @@ pub fn decode_bytes(message_kind: MessageKind, buf: &[u8]) -> Result<Option<LogM
                 .ok_or_else(|| missing_field!(re_protos::log_msg::v1alpha1::ArrowMsg, "store_id"))?
                 .into();
 
-            let chunk = re_chunk::Chunk::from_record_batch(&batch)?;
+            let chunk = if apply_migrations {
+                re_chunk::Chunk::from_record_batch_recorded_by(&batch, recorded_version)?
+            } else {
+                re_chunk::Chunk::from_record_batch_unmigrated(&batch)?
+            };
 
             Some(LogMsg::ArrowMsg(store_id, chunk.to_arrow_msg()?))
         }
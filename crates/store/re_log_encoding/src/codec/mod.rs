@@ -30,4 +30,12 @@ pub enum CodecError {
 
     #[error("Unknown message header")]
     UnknownMessageHeader,
+
+    #[error(
+        "Unknown compression: {0}. The data was probably written by a newer version of Rerun."
+    )]
+    UnknownCompression(i32),
+
+    #[error("Compression {0} is not supported on this platform")]
+    UnsupportedCompression(crate::Compression),
 }
@@ -0,0 +1,481 @@
+//! Random access into `.rrd` files, using their footer index when they have one.
+
+use std::io::SeekFrom;
+
+use re_build_info::CrateVersion;
+use re_log_types::LogMsg;
+
+use crate::codec::file::decoder;
+use crate::footer::{ChunkIndexQuery, FooterHeader, FooterTrailer, MessageIndexEntry, RrdFooter};
+use crate::{EncodingOptions, FileHeader, Serializer};
+
+use super::{options_from_bytes, DecodeError};
+
+/// Reads the footer index at the end of an `.rrd` file, if any.
+///
+/// Returns `Ok(None)` if the file doesn't have a footer, e.g. because it was written by an older
+/// version of Rerun, or to a non-seekable output.
+/// This is also the case if the trailer is inconsistent with the file, e.g. because the file is
+/// made of several concatenated streams: the footer offsets are relative to the last stream, and
+/// can't be used to seek into the whole file.
+///
+/// The position of the reader is unspecified after this call.
+pub fn read_footer(
+    read: &mut (impl std::io::Read + std::io::Seek),
+) -> Result<Option<RrdFooter>, DecodeError> {
+    re_tracing::profile_function!();
+
+    let file_len = read.seek(SeekFrom::End(0))?;
+    let min_len = (FileHeader::SIZE + FooterHeader::SIZE_BYTES + FooterTrailer::SIZE_BYTES) as u64;
+    if file_len < min_len {
+        return Ok(None);
+    }
+
+    let mut trailer = [0_u8; FooterTrailer::SIZE_BYTES];
+    read.seek(SeekFrom::End(-(FooterTrailer::SIZE_BYTES as i64)))?;
+    read.read_exact(&mut trailer)?;
+    let Some(FooterTrailer { footer_offset }) = FooterTrailer::from_bytes(&trailer) else {
+        return Ok(None);
+    };
+
+    if footer_offset > file_len - (FooterHeader::SIZE_BYTES + FooterTrailer::SIZE_BYTES) as u64 {
+        re_log::debug!(
+            "Footer offset {footer_offset} is out of bounds of a {file_len} bytes file, ignoring the footer"
+        );
+        return Ok(None);
+    }
+
+    let mut header = [0_u8; FooterHeader::SIZE_BYTES];
+    read.seek(SeekFrom::Start(footer_offset))?;
+    read.read_exact(&mut header)?;
+    let Some(FooterHeader { len }) = FooterHeader::from_bytes(&header) else {
+        re_log::debug!("No footer header at offset {footer_offset}, ignoring the footer");
+        return Ok(None);
+    };
+
+    let expected_len =
+        file_len - footer_offset - (FooterHeader::SIZE_BYTES + FooterTrailer::SIZE_BYTES) as u64;
+    if len != expected_len {
+        re_log::debug!(
+            "Footer length {len} doesn't match its offset (expected {expected_len}), ignoring the footer"
+        );
+        return Ok(None);
+    }
+
+    let mut payload = vec![0_u8; len as usize];
+    read.read_exact(&mut payload)?;
+
+    Ok(Some(RrdFooter::from_ipc_bytes(&payload)?))
+}
+
+fn corrupt_footer(reason: &str) -> DecodeError {
+    DecodeError::Read(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("corrupt .rrd footer: {reason}"),
+    ))
+}
+
+// ---
+
+/// Decodes selected messages out of a seekable `.rrd` file.
+///
+/// If the file has a footer index (see [`crate::footer`]), only the messages matching a
+/// [`ChunkIndexQuery`] are read and decoded; everything else is skipped over.
+/// Otherwise, this falls back to decoding the whole file front to back and filtering the results.
+///
+/// Concatenated streams never use the footer index, see [`read_footer`].
+pub struct IndexedDecoder<R: std::io::Read + std::io::Seek> {
+    version: CrateVersion,
+    options: EncodingOptions,
+    read: R,
+    footer: Option<RrdFooter>,
+
+    /// Where the reader currently is, so we can avoid seeking (and thus dropping buffers) when
+    /// reading contiguous messages.
+    position: u64,
+}
+
+impl<R: std::io::Read + std::io::Seek> IndexedDecoder<R> {
+    pub fn new(mut read: R) -> Result<Self, DecodeError> {
+        re_tracing::profile_function!();
+
+        read.seek(SeekFrom::Start(0))?;
+        let mut data = [0_u8; FileHeader::SIZE];
+        read.read_exact(&mut data)?;
+        let (version, options) = options_from_bytes(&data)?;
+
+        let footer = read_footer(&mut read)?;
+
+        // Make sure we don't make any assumptions about the reader's position.
+        let position = read.seek(SeekFrom::Start(FileHeader::SIZE as _))?;
+
+        Ok(Self {
+            version,
+            options,
+            read,
+            footer,
+            position,
+        })
+    }
+
+    /// Returns the Rerun version that was used to encode the data in the first place.
+    #[inline]
+    pub fn version(&self) -> CrateVersion {
+        self.version
+    }
+
+    /// The footer index of the file, if it has one.
+    #[inline]
+    pub fn footer(&self) -> Option<&RrdFooter> {
+        self.footer.as_ref()
+    }
+
+    /// Reads and decodes the message described by the given index entry.
+    pub fn read_message_at(&mut self, entry: &MessageIndexEntry) -> Result<LogMsg, DecodeError> {
+        re_tracing::profile_function!();
+
+        if self.position != entry.byte_offset {
+            self.position = self.read.seek(SeekFrom::Start(entry.byte_offset))?;
+        }
+
+        let msg = match self.options.serializer {
+            Serializer::Protobuf => {
+                let (read_bytes, msg) = decoder::decode(&mut self.read, Some(self.version), true)?;
+                self.position += read_bytes;
+                msg
+            }
+        };
+
+        let Some(mut msg) = msg else {
+            return Err(corrupt_footer("index entry points to end-of-stream marker"));
+        };
+
+        if let LogMsg::SetStoreInfo(msg) = &mut msg {
+            msg.info.store_version = Some(self.version);
+        }
+
+        Ok(msg)
+    }
+
+    /// Returns all the messages matching the given query, in file order.
+    ///
+    /// Uses the footer index to only decode what's needed if available, otherwise decodes
+    /// everything.
+    pub fn query<'a>(
+        &'a mut self,
+        query: &'a ChunkIndexQuery,
+    ) -> Box<dyn Iterator<Item = Result<LogMsg, DecodeError>> + 'a> {
+        re_tracing::profile_function!();
+
+        if let Some(footer) = &self.footer {
+            let entries: Vec<MessageIndexEntry> = footer.query(query).cloned().collect();
+            return Box::new(
+                entries
+                    .into_iter()
+                    .map(move |entry| self.read_message_at(&entry)),
+            );
+        }
+
+        re_log::debug!("No footer index found, decoding the whole file");
+
+        if let Err(err) = self.read.seek(SeekFrom::Start(0)) {
+            return Box::new(std::iter::once(Err(err.into())));
+        }
+        // We don't track the position while decoding linearly.
+        self.position = u64::MAX;
+
+        let decoder =
+            match super::Decoder::new_concatenated(std::io::BufReader::new(&mut self.read)) {
+                Ok(decoder) => decoder,
+                Err(err) => return Box::new(std::iter::once(Err(err))),
+            };
+        Box::new(decoder.filter_map(move |res| {
+            let msg = match res {
+                Ok(msg) => msg,
+                Err(err) => return Some(Err(err)),
+            };
+
+            // Byte offsets are irrelevant for matching.
+            match MessageIndexEntry::from_log_msg(&msg, 0, 0) {
+                Ok(entry) => query.matches(&entry).then_some(Ok(msg)),
+                Err(err) => Some(Err(err.into())),
+            }
+        }))
+    }
+}
+
+#[cfg(all(test, feature = "decoder", feature = "encoder"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{
+        example_components::MyPoint, EntityPath, EntityPathFilter, ResolvedTimeRange, StoreId,
+        StoreKind, TimePoint, Timeline,
+    };
+
+    use super::*;
+
+    fn fake_chunks(store_id: &StoreId) -> Vec<LogMsg> {
+        let timeline = Timeline::new_sequence("frame");
+
+        let mut messages = Vec::new();
+        for entity_path in ["/robot/arm", "/robot/leg", "/world"] {
+            for frame in [0_i64, 10, 20] {
+                let chunk = Chunk::builder(entity_path.into())
+                    .with_component_batches(
+                        RowId::new(),
+                        TimePoint::default().with(timeline, frame),
+                        [&[MyPoint::new(1.0, 2.0)] as _],
+                    )
+                    .build()
+                    .unwrap();
+                messages.push(LogMsg::ArrowMsg(
+                    store_id.clone(),
+                    chunk.to_arrow_msg().unwrap(),
+                ));
+            }
+        }
+
+        let static_chunk = Chunk::builder("/world".into())
+            .with_component_batches(
+                RowId::new(),
+                TimePoint::default(),
+                [&[MyPoint::new(3.0, 4.0)] as _],
+            )
+            .build()
+            .unwrap();
+        messages.push(LogMsg::ArrowMsg(
+            store_id.clone(),
+            static_chunk.to_arrow_msg().unwrap(),
+        ));
+
+        messages
+    }
+
+    fn encode(messages: &[LogMsg], with_footer: bool) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = crate::encoder::Encoder::new(
+            CrateVersion::LOCAL,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            &mut bytes,
+        )
+        .unwrap();
+        if with_footer {
+            encoder = encoder.with_footer_index();
+        }
+        for msg in messages {
+            encoder.append(msg).unwrap();
+        }
+        encoder.finish().unwrap();
+        bytes
+    }
+
+    fn entity_paths(messages: &[LogMsg]) -> Vec<EntityPath> {
+        messages
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(
+                    Chunk::from_arrow_msg(arrow_msg)
+                        .unwrap()
+                        .entity_path()
+                        .clone(),
+                ),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn footer_roundtrip() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+
+        let bytes = encode(&messages, true);
+
+        // Regular decoders must not be bothered by the footer.
+        let decoded = super::super::Decoder::new(bytes.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        similar_asserts::assert_eq!(decoded, messages);
+
+        let footer = read_footer(&mut std::io::Cursor::new(&bytes))
+            .unwrap()
+            .unwrap();
+        assert_eq!(footer.entries.len(), messages.len());
+        assert_eq!(footer.chunks().filter(|(_, c)| c.is_static).count(), 1);
+
+        // The index is built without decoding the chunks, it must still agree with them.
+        for (entry, msg) in footer.entries.iter().zip(&messages) {
+            let LogMsg::ArrowMsg(_, arrow_msg) = msg else {
+                assert!(entry.chunk.is_none());
+                continue;
+            };
+            let chunk = Chunk::from_arrow_msg(arrow_msg).unwrap();
+            let info = entry.chunk.as_ref().unwrap();
+            assert_eq!(info.chunk_id, chunk.id());
+            assert_eq!(&info.entity_path, chunk.entity_path());
+            assert_eq!(info.is_static, chunk.is_static());
+            assert_eq!(info.num_rows, chunk.num_rows() as u64);
+            assert_eq!(
+                info.time_ranges,
+                chunk
+                    .timelines()
+                    .iter()
+                    .map(|(timeline, time_column)| (*timeline, time_column.time_range()))
+                    .collect()
+            );
+        }
+
+        // Every entry must point to exactly its own message.
+        let mut decoder = IndexedDecoder::new(std::io::Cursor::new(&bytes)).unwrap();
+        for (entry, msg) in footer.entries.iter().zip(&messages) {
+            similar_asserts::assert_eq!(&decoder.read_message_at(entry).unwrap(), msg);
+        }
+
+        // No footer, no index.
+        let bytes = encode(&messages, false);
+        assert!(read_footer(&mut std::io::Cursor::new(&bytes))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn query_with_and_without_footer() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+
+        let query = ChunkIndexQuery {
+            store_id: Some(store_id.clone()),
+            entity_path_filter: Some(
+                EntityPathFilter::parse_forgiving("+ /robot/arm\n+ /world")
+                    .resolve_without_substitutions(),
+            ),
+            time_range: Some(("frame".into(), ResolvedTimeRange::new(5, 15))),
+        };
+
+        for with_footer in [true, false] {
+            let bytes = encode(&messages, with_footer);
+            let mut decoder = IndexedDecoder::new(std::io::Cursor::new(&bytes)).unwrap();
+            assert_eq!(decoder.footer().is_some(), with_footer);
+
+            let results = decoder
+                .query(&query)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            // `/robot/arm` @ frame 10, `/world` @ frame 10, and the static `/world` chunk.
+            assert_eq!(
+                entity_paths(&results),
+                vec![
+                    EntityPath::from("/robot/arm"),
+                    EntityPath::from("/world"),
+                    EntityPath::from("/world"),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn concatenated_streams_with_footers() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+
+        let bytes = [encode(&messages, true), encode(&messages, true)].concat();
+
+        let decoded =
+            super::super::Decoder::new_concatenated(std::io::BufReader::new(bytes.as_slice()))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        similar_asserts::assert_eq!(decoded, [messages.clone(), messages.clone()].concat());
+
+        let mut decoder = super::super::stream::StreamDecoder::new();
+        decoder.push_chunk(bytes);
+        let mut decoded = Vec::new();
+        while let Some(msg) = decoder.try_read().unwrap() {
+            decoded.push(msg);
+        }
+        similar_asserts::assert_eq!(decoded, [messages.clone(), messages].concat());
+    }
+
+    #[test]
+    fn concatenated_file_ignores_footer() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+
+        let bytes = [encode(&messages, false), encode(&messages, true)].concat();
+
+        // The trailer of the last stream is relative to that stream, not to the whole file.
+        assert!(read_footer(&mut std::io::Cursor::new(&bytes))
+            .unwrap()
+            .is_none());
+
+        let mut decoder = IndexedDecoder::new(std::io::Cursor::new(&bytes)).unwrap();
+        assert!(decoder.footer().is_none());
+
+        let results = decoder
+            .query(&ChunkIndexQuery::default())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        similar_asserts::assert_eq!(results, [messages.clone(), messages].concat());
+    }
+
+    #[test]
+    fn footer_spanning_several_batches() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+
+        let footer = RrdFooter {
+            entries: (0..crate::footer::INDEX_BATCH_LEN + 10)
+                .map(|i| {
+                    MessageIndexEntry::from_log_msg(&messages[i % messages.len()], i as u64, 1)
+                        .unwrap()
+                })
+                .collect(),
+        };
+
+        let mut builder = crate::footer::FooterIndexBuilder::default();
+        for entry in &footer.entries {
+            builder.push(entry.clone()).unwrap();
+        }
+        let bytes = builder.finish().unwrap();
+
+        assert_eq!(RrdFooter::from_ipc_bytes(&bytes).unwrap(), footer);
+        assert_eq!(
+            RrdFooter::from_ipc_bytes(
+                &crate::footer::FooterIndexBuilder::default()
+                    .finish()
+                    .unwrap()
+            )
+            .unwrap(),
+            RrdFooter::default()
+        );
+    }
+
+    #[test]
+    fn malformed_footer() {
+        use arrow::array::{ArrayRef, RecordBatch, UInt8Array};
+
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = fake_chunks(&store_id);
+        let footer = RrdFooter {
+            entries: messages
+                .iter()
+                .map(|msg| MessageIndexEntry::from_log_msg(msg, 0, 0).unwrap())
+                .collect(),
+        };
+        let batch = footer.to_record_batch().unwrap();
+
+        // An unknown store kind must be reported, not panic.
+        let column = batch.schema().index_of("store_kind").unwrap();
+        let mut columns = batch.columns().to_vec();
+        columns[column] =
+            std::sync::Arc::new(UInt8Array::from(vec![42; batch.num_rows()])) as ArrayRef;
+        let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+        assert!(RrdFooter::from_record_batch(&batch).is_err());
+
+        assert!(RrdFooter::from_ipc_bytes(b"not arrow").is_err());
+    }
+}
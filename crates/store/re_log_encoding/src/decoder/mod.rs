@@ -1,5 +1,6 @@
 //! Decoding [`LogMsg`]:es from `.rrd` files/streams.
 
+pub mod indexed;
 pub mod stream;
 
 #[cfg(feature = "decoder")]
@@ -13,6 +14,7 @@ use re_log_types::LogMsg;
 
 use crate::codec;
 use crate::codec::file::decoder;
+use crate::footer::{FooterHeader, FooterTrailer};
 use crate::FileHeader;
 use crate::OLD_RRD_HEADERS;
 use crate::{EncodingOptions, Serializer};
@@ -70,6 +72,9 @@ pub enum DecodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::DecompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::DecodeError),
 
@@ -166,6 +171,16 @@ impl<R: std::io::Read> std::io::Read for Reader<R> {
 
 pub struct Decoder<R: std::io::Read> {
     version: CrateVersion,
+
+    /// The version of the file currently being decoded, which differs from [`Self::version`] for
+    /// concatenated streams.
+    ///
+    /// Decides which migrations get applied, see [`re_sorbet::MIGRATIONS`].
+    file_version: CrateVersion,
+
+    /// See [`Self::without_migrations`].
+    apply_migrations: bool,
+
     options: EncodingOptions,
     read: Reader<R>,
 
@@ -194,6 +209,8 @@ impl<R: std::io::Read> Decoder<R> {
 
         Ok(Self {
             version,
+            file_version: version,
+            apply_migrations: true,
             options,
             read: Reader::Raw(read),
             size_bytes: FileHeader::SIZE as _,
@@ -203,6 +220,8 @@ impl<R: std::io::Read> Decoder<R> {
     pub fn new_with_options(options: EncodingOptions, version: CrateVersion, read: R) -> Self {
         Self {
             version,
+            file_version: version,
+            apply_migrations: true,
             options,
             read: Reader::Raw(read),
             size_bytes: FileHeader::SIZE as _,
@@ -235,6 +254,8 @@ impl<R: std::io::Read> Decoder<R> {
 
         Ok(Self {
             version,
+            file_version: version,
+            apply_migrations: true,
             options,
             read: Reader::Buffered(read),
             size_bytes: FileHeader::SIZE as _,
@@ -247,6 +268,23 @@ impl<R: std::io::Read> Decoder<R> {
         self.version
     }
 
+    /// Returns the Rerun version that encoded the stream the last decoded message came from.
+    ///
+    /// Same as [`Self::version`], except for concatenated streams written by different versions.
+    #[inline]
+    pub fn file_version(&self) -> CrateVersion {
+        self.file_version
+    }
+
+    /// Decode chunks as they were written, without migrating them to the current schema.
+    ///
+    /// Useful to migrate them explicitly instead, see [`re_sorbet::migrate_record_batch_with_report`].
+    #[inline]
+    pub fn without_migrations(mut self) -> Self {
+        self.apply_migrations = false;
+        self
+    }
+
     // TODO(jan): stop returning number of read bytes, use cursors wrapping readers instead.
     /// Returns the size in bytes of the data that has been decoded up to now.
     #[inline]
@@ -254,6 +292,29 @@ impl<R: std::io::Read> Decoder<R> {
         self.size_bytes
     }
 
+    /// Peeks ahead in search of a footer index (see [`crate::footer`]) in the stream, and skips
+    /// over it if found.
+    ///
+    /// Returns true if a footer was skipped.
+    ///
+    /// No-op if the decoder wasn't initialized with [`Decoder::new_concatenated`].
+    fn skip_footer(&mut self) -> Result<bool, DecodeError> {
+        let Reader::Buffered(read) = &mut self.read else {
+            return Ok(false);
+        };
+
+        let Some(header) = FooterHeader::from_bytes(read.fill_buf()?) else {
+            return Ok(false);
+        };
+
+        let footer_size =
+            (FooterHeader::SIZE_BYTES + FooterTrailer::SIZE_BYTES) as u64 + header.len;
+        let skipped = std::io::copy(&mut read.take(footer_size), &mut std::io::sink())?;
+        self.size_bytes += skipped;
+
+        Ok(true)
+    }
+
     /// Peeks ahead in search of additional `FileHeader`s in the stream.
     ///
     /// Returns true if a valid header was found.
@@ -299,12 +360,17 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
             };
 
             self.version = CrateVersion::max(self.version, version);
+            self.file_version = version;
             self.options = options;
             self.size_bytes += FileHeader::SIZE as u64;
         }
 
         let msg = match self.options.serializer {
-            Serializer::Protobuf => match decoder::decode(&mut self.read) {
+            Serializer::Protobuf => match decoder::decode(
+                &mut self.read,
+                Some(self.file_version),
+                self.apply_migrations,
+            ) {
                 Ok((read_bytes, msg)) => {
                     self.size_bytes += read_bytes;
                     msg
@@ -319,6 +385,11 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
         };
 
         let Some(mut msg) = msg else {
+            // the end of file marker might be followed by a footer index, which we don't need here
+            if let Err(err) = self.skip_footer() {
+                return Some(Err(err));
+            }
+
             // we might have a concatenated stream, so we peek beyond end of file marker to see
             if self.peek_file_header() {
                 re_log::debug!(
@@ -412,6 +483,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::ZSTD,
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -428,6 +503,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compression_options() {
+        for compression in [Compression::Off, Compression::LZ4, Compression::ZSTD] {
+            let options = EncodingOptions {
+                compression,
+                serializer: Serializer::Protobuf,
+            };
+            assert_eq!(
+                EncodingOptions::from_bytes(options.to_bytes()).unwrap(),
+                options
+            );
+            assert_eq!(
+                compression.to_string().parse::<Compression>().unwrap(),
+                compression
+            );
+        }
+
+        // The zstd level is an encoder-side setting only.
+        let options = EncodingOptions {
+            compression: Compression::Zstd { level: 19 },
+            serializer: Serializer::Protobuf,
+        };
+        assert_eq!(
+            EncodingOptions::from_bytes(options.to_bytes())
+                .unwrap()
+                .compression,
+            Compression::ZSTD
+        );
+        assert_eq!(
+            "zstd:19".parse::<Compression>().unwrap(),
+            Compression::Zstd { level: 19 }
+        );
+        assert!("zstd:99".parse::<Compression>().is_err());
+
+        // Compression schemes from the future must be rejected, not misdecoded.
+        assert!(matches!(
+            EncodingOptions::from_bytes([42, Serializer::Protobuf as u8, 0, 0]),
+            Err(crate::OptionsError::UnknownCompression(42))
+        ));
+        assert!(matches!(
+            crate::protobuf_conversions::compression_from_proto(42),
+            Err(crate::codec::CodecError::UnknownCompression(42))
+        ));
+    }
+
     #[test]
     fn test_concatenated_streams() {
         let options = [
@@ -439,6 +559,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::ZSTD,
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
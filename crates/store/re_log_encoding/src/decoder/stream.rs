@@ -6,6 +6,7 @@ use re_build_info::CrateVersion;
 use re_log_types::LogMsg;
 
 use crate::decoder::options_from_bytes;
+use crate::footer::{FooterHeader, FooterTrailer};
 use crate::EncodingOptions;
 use crate::FileHeader;
 use crate::Serializer;
@@ -41,6 +42,9 @@ pub struct StreamDecoder {
 /// ^           |
 /// |           |
 /// ---Message<--
+///       |
+///       v (end-of-stream marker)
+///   StreamHeader (or Footer, then StreamHeader)
 /// ```
 #[derive(Clone, Copy, Debug)]
 enum State {
@@ -61,6 +65,11 @@ enum State {
     /// Compression is only applied to individual `ArrowMsg`s, instead of
     /// the entire stream.
     Message(crate::codec::file::MessageHeader),
+
+    /// The footer index that may follow the end-of-stream marker, see [`crate::footer`].
+    ///
+    /// We have no use for it when streaming, so its contents (i.e. this many bytes) are skipped.
+    Footer(u64),
 }
 
 impl StreamDecoder {
@@ -84,6 +93,11 @@ impl StreamDecoder {
         match self.state {
             State::StreamHeader => {
                 if let Some(header) = self.chunks.try_read(FileHeader::SIZE) {
+                    if let Some(footer) = FooterHeader::from_bytes(header) {
+                        self.state = State::Footer(footer.len + FooterTrailer::SIZE_BYTES as u64);
+                        return self.try_read();
+                    }
+
                     // header contains version and compression options
                     let (version, options) = options_from_bytes(header)?;
                     self.version = Some(version);
@@ -113,7 +127,11 @@ impl StreamDecoder {
             }
             State::Message(header) => {
                 if let Some(bytes) = self.chunks.try_read(header.len as usize) {
-                    let message = crate::codec::file::decoder::decode_bytes(header.kind, bytes)?;
+                    let message = crate::codec::file::decoder::decode_bytes(
+                        header.kind,
+                        bytes,
+                        self.version,
+                    )?;
                     if let Some(mut message) = message {
                         propagate_version(&mut message, self.version);
                         self.state = State::MessageHeader;
@@ -126,6 +144,13 @@ impl StreamDecoder {
                     }
                 }
             }
+            State::Footer(len) => {
+                if self.chunks.try_read(len as usize).is_some() {
+                    // there might be another concatenated stream after the footer
+                    self.state = State::StreamHeader;
+                    return self.try_read();
+                }
+            }
         }
 
         Ok(None)
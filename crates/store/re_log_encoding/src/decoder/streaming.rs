@@ -52,6 +52,13 @@ impl std::ops::DerefMut for StreamingLogMsg {
 
 pub struct StreamingDecoder<R: AsyncBufRead> {
     version: CrateVersion,
+
+    /// The version of the file currently being decoded, which differs from [`Self::version`] for
+    /// concatenated streams.
+    ///
+    /// Decides which migrations get applied, see [`re_sorbet::MIGRATIONS`].
+    file_version: CrateVersion,
+
     options: EncodingOptions,
     reader: R,
 
@@ -78,6 +85,7 @@ impl<R: AsyncBufRead + Unpin> StreamingDecoder<R> {
 
         Ok(Self {
             version,
+            file_version: version,
             options,
             reader,
             unprocessed_bytes: BytesMut::new(),
@@ -89,6 +97,7 @@ impl<R: AsyncBufRead + Unpin> StreamingDecoder<R> {
     pub fn new_with_options(version: CrateVersion, options: EncodingOptions, reader: R) -> Self {
         Self {
             version,
+            file_version: version,
             options,
             reader,
             unprocessed_bytes: BytesMut::new(),
@@ -116,6 +125,7 @@ impl<R: AsyncBufRead + Unpin> Stream for StreamingDecoder<R> {
     ) -> std::task::Poll<Option<Self::Item>> {
         loop {
             let Self {
+                file_version,
                 options,
                 reader,
                 unprocessed_bytes,
@@ -123,6 +133,7 @@ impl<R: AsyncBufRead + Unpin> Stream for StreamingDecoder<R> {
                 ..
             } = &mut *self;
 
+            let file_version = *file_version;
             let serializer = options.serializer;
             let mut buf_length = 0;
 
@@ -165,6 +176,7 @@ impl<R: AsyncBufRead + Unpin> Stream for StreamingDecoder<R> {
                 match options_from_bytes(data) {
                     Ok((version, options)) => {
                         self.version = CrateVersion::max(self.version, version);
+                        self.file_version = version;
                         self.options = options;
 
                         Pin::new(&mut self.reader).consume(buf_length);
@@ -200,7 +212,7 @@ impl<R: AsyncBufRead + Unpin> Stream for StreamingDecoder<R> {
 
                     // decode the message
                     let data = &unprocessed_bytes[header_size..header_size + header.len as usize];
-                    let msg = file::decoder::decode_bytes(header.kind, data)?;
+                    let msg = file::decoder::decode_bytes(header.kind, data, Some(file_version))?;
 
                     (msg, header.len as usize + header_size)
                 }
@@ -275,6 +287,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::ZSTD,
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -315,6 +331,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::ZSTD,
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -351,6 +371,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::ZSTD,
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -379,7 +403,7 @@ mod tests {
                     let header = file::MessageHeader::from_bytes(header_data).unwrap();
 
                     let data = &data[header_size..];
-                    let msg = file::decoder::decode_bytes(header.kind, data)
+                    let msg = file::decoder::decode_bytes(header.kind, data, None)
                         .unwrap()
                         .unwrap();
 
@@ -2,6 +2,7 @@
 
 use crate::codec;
 use crate::codec::file::{self, encoder};
+use crate::footer::{FooterHeader, FooterIndexBuilder, FooterTrailer, MessageIndexEntry};
 use crate::FileHeader;
 use crate::Serializer;
 use crate::{Compression, EncodingOptions};
@@ -20,6 +21,9 @@ pub enum EncodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::CompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::EncodeError),
 
@@ -84,6 +88,15 @@ impl<W: std::io::Write> DroppableEncoder<W> {
         self.encoder.append(message)
     }
 
+    /// Also write a [`crate::footer::RrdFooter`] index when finishing the stream.
+    ///
+    /// See [`Encoder::with_footer_index`].
+    #[inline]
+    pub fn with_footer_index(mut self) -> Self {
+        self.encoder.footer = Some(FooterIndexBuilder::default());
+        self
+    }
+
     #[inline]
     pub fn finish(&mut self) -> Result<(), EncodeError> {
         if !self.is_finished {
@@ -120,6 +133,12 @@ pub struct Encoder<W: std::io::Write> {
     compression: Compression,
     write: W,
     scratch: Vec<u8>,
+
+    /// How many bytes have been written so far, file header included.
+    num_bytes_written: u64,
+
+    /// Accumulates the footer index, if enabled.
+    footer: Option<FooterIndexBuilder>,
 }
 
 impl<W: std::io::Write> Encoder<W> {
@@ -140,24 +159,55 @@ impl<W: std::io::Write> Encoder<W> {
             compression: options.compression,
             write,
             scratch: Vec::new(),
+            num_bytes_written: FileHeader::SIZE as _,
+            footer: None,
         })
     }
 
+    /// Index every appended message, and write that index as a footer when finishing the stream.
+    ///
+    /// The footer makes it possible to seek directly to the chunks of interest when reading the
+    /// file back, see `decoder::indexed::IndexedDecoder`.
+    ///
+    /// This is opt-in: decoders that predate the footer index fail on the bytes that follow the
+    /// end-of-stream marker, so only enable it for files that will be read by up-to-date readers.
+    ///
+    /// The index is kept in memory until the stream is finished, serialized to Arrow every
+    /// few thousand messages to keep it compact.
+    ///
+    /// This is only useful for seekable outputs, i.e. files.
+    #[inline]
+    pub fn with_footer_index(mut self) -> Self {
+        self.footer = Some(FooterIndexBuilder::default());
+        self
+    }
+
     /// Returns the size in bytes of the encoded data.
     pub fn append(&mut self, message: &LogMsg) -> Result<u64, EncodeError> {
         re_tracing::profile_function!();
 
         self.scratch.clear();
-        match self.serializer {
+        let byte_len = match self.serializer {
             Serializer::Protobuf => {
                 encoder::encode(&mut self.scratch, message, self.compression)?;
 
                 self.write
                     .write_all(&self.scratch)
-                    .map(|_| self.scratch.len() as _)
-                    .map_err(EncodeError::Write)
+                    .map(|_| self.scratch.len() as u64)
+                    .map_err(EncodeError::Write)?
             }
+        };
+
+        if let Some(footer) = &mut self.footer {
+            footer.push(MessageIndexEntry::from_log_msg(
+                message,
+                self.num_bytes_written,
+                byte_len,
+            )?)?;
         }
+        self.num_bytes_written += byte_len;
+
+        Ok(byte_len)
     }
 
     // NOTE: This cannot be done in a `Drop` implementation because of `Self::into_inner` which
@@ -171,8 +221,34 @@ impl<W: std::io::Write> Encoder<W> {
                     len: 0,
                 }
                 .encode(&mut self.write)?;
+                self.num_bytes_written += file::MessageHeader::SIZE_BYTES as u64;
             }
         }
+
+        if let Some(footer) = self.footer.take() {
+            self.write_footer(footer)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_footer(&mut self, footer: FooterIndexBuilder) -> Result<(), EncodeError> {
+        re_tracing::profile_function!();
+
+        let footer_offset = self.num_bytes_written;
+
+        let index = footer.finish()?;
+
+        FooterHeader {
+            len: index.len() as u64,
+        }
+        .encode(&mut self.write)?;
+        self.write.write_all(&index)?;
+        FooterTrailer { footer_offset }.encode(&mut self.write)?;
+
+        self.num_bytes_written +=
+            (FooterHeader::SIZE_BYTES + index.len() + FooterTrailer::SIZE_BYTES) as u64;
+
         Ok(())
     }
 
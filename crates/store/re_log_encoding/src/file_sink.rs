@@ -24,13 +24,13 @@ pub enum FileSinkError {
     LogMsgEncode(#[from] crate::encoder::EncodeError),
 }
 
-enum Command {
+pub(crate) enum Command {
     Send(LogMsg),
     Flush(SyncSender<()>),
 }
 
 impl Command {
-    fn flush() -> (Self, Receiver<()>) {
+    pub(crate) fn flush() -> (Self, Receiver<()>) {
         let (tx, rx) = std::sync::mpsc::sync_channel(0); // oneshot
         (Self::Flush(tx), rx)
     }
@@ -60,13 +60,26 @@ impl Drop for FileSink {
 impl FileSink {
     /// Start writing log messages to a file at the given path.
     pub fn new(path: impl Into<std::path::PathBuf>) -> Result<Self, FileSinkError> {
+        Self::new_impl(path.into(), false)
+    }
+
+    /// Start writing log messages to a file at the given path, and end it with a footer index.
+    ///
+    /// The footer makes the file randomly accessible, see [`crate::footer`].
+    ///
+    /// Note that decoders older than the footer index fail to read such files.
+    pub fn new_with_footer_index(
+        path: impl Into<std::path::PathBuf>,
+    ) -> Result<Self, FileSinkError> {
+        Self::new_impl(path.into(), true)
+    }
+
+    fn new_impl(path: PathBuf, footer_index: bool) -> Result<Self, FileSinkError> {
         // We always compress on disk
         let encoding_options = crate::EncodingOptions::PROTOBUF_COMPRESSED;
 
         let (tx, rx) = std::sync::mpsc::channel();
 
-        let path = path.into();
-
         re_log::debug!("Saving file to {path:?}…");
 
         // TODO(andreas): Can we ensure that a single process doesn't
@@ -75,11 +88,14 @@ impl FileSink {
 
         let file = std::fs::File::create(&path)
             .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
-        let encoder = crate::encoder::DroppableEncoder::new(
+        let mut encoder = crate::encoder::DroppableEncoder::new(
             re_build_info::CrateVersion::LOCAL,
             encoding_options,
             file,
         )?;
+        if footer_index {
+            encoder = encoder.with_footer_index();
+        }
         let join_handle = spawn_and_stream(Some(&path), encoder, rx)?;
 
         Ok(Self {
@@ -0,0 +1,599 @@
+//! Optional footer at the end of `.rrd` files, indexing every message by byte offset.
+//!
+//! The footer lives after the end-of-stream marker, so that readers which don't know about it
+//! simply stop before reaching it:
+//!
+//! ```text,ignore
+//! FileHeader | (MessageHeader Message)* | End | FooterHeader | Index | FooterTrailer
+//! ```
+//!
+//! * `FooterHeader` is the magic `RRIX` followed by the length of the `Index` in bytes (u64, LE).
+//! * `Index` is an Arrow IPC stream of record batches, with one row per message (see [`RrdFooter`]).
+//! * `FooterTrailer` is the absolute byte offset of the `FooterHeader` (u64, LE), followed by the
+//!   magic `RRIX` and 4 reserved bytes. It always takes up the last 16 bytes of the file, which
+//!   makes it possible to locate the footer by seeking from the end.
+
+use std::collections::BTreeMap;
+
+use re_chunk::ChunkId;
+use re_log_types::{
+    EntityPath, LogMsg, ResolvedEntityPathFilter, ResolvedTimeRange, StoreId, StoreKind,
+    TimelineName,
+};
+
+// ---
+
+pub(crate) const FOOTER_MAGIC: &[u8; 4] = b"RRIX";
+
+/// Maximum number of entries in each record batch of the footer index.
+///
+/// The encoder serializes the index one batch at a time as the stream goes, so that it never
+/// holds more than this many entries in their decoded form.
+#[cfg(feature = "encoder")]
+pub(crate) const INDEX_BATCH_LEN: usize = 64 * 1024;
+
+/// The header that precedes the footer index.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FooterHeader {
+    /// Length of the Arrow IPC payload that follows, in bytes.
+    pub(crate) len: u64,
+}
+
+impl FooterHeader {
+    /// Size of an encoded footer header, in bytes.
+    ///
+    /// Same as [`crate::FileHeader::SIZE`] on purpose: streaming decoders that look for another
+    /// concatenated file after the end-of-stream marker can tell both apart with a single read.
+    pub const SIZE_BYTES: usize = 12;
+
+    #[cfg(feature = "encoder")]
+    pub(crate) fn encode(
+        &self,
+        buf: &mut impl std::io::Write,
+    ) -> Result<(), crate::encoder::EncodeError> {
+        buf.write_all(FOOTER_MAGIC)?;
+        buf.write_all(&self.len.to_le_bytes())?;
+        Ok(())
+    }
+
+    /// Returns `None` if `buf` doesn't start with a footer header.
+    #[cfg(feature = "decoder")]
+    pub(crate) fn from_bytes(buf: &[u8]) -> Option<Self> {
+        if buf.len() < Self::SIZE_BYTES || &buf[0..4] != FOOTER_MAGIC {
+            return None;
+        }
+
+        #[allow(clippy::unwrap_used)] // cannot fail
+        let len = u64::from_le_bytes(buf[4..12].try_into().unwrap());
+
+        Some(Self { len })
+    }
+}
+
+/// The trailer that closes the footer, always located in the last 16 bytes of the file.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FooterTrailer {
+    /// Absolute byte offset of the [`FooterHeader`].
+    pub(crate) footer_offset: u64,
+}
+
+impl FooterTrailer {
+    /// Size of an encoded footer trailer, in bytes.
+    pub const SIZE_BYTES: usize = 16;
+
+    #[cfg(feature = "encoder")]
+    pub(crate) fn encode(
+        &self,
+        buf: &mut impl std::io::Write,
+    ) -> Result<(), crate::encoder::EncodeError> {
+        buf.write_all(&self.footer_offset.to_le_bytes())?;
+        buf.write_all(FOOTER_MAGIC)?;
+        buf.write_all(&[0; 4])?; // reserved
+        Ok(())
+    }
+
+    /// Returns `None` if `buf` isn't a valid footer trailer.
+    #[cfg(feature = "decoder")]
+    pub(crate) fn from_bytes(buf: &[u8; Self::SIZE_BYTES]) -> Option<Self> {
+        if &buf[8..12] != FOOTER_MAGIC || buf[12..16] != [0; 4] {
+            return None;
+        }
+
+        #[allow(clippy::unwrap_used)] // cannot fail
+        let footer_offset = u64::from_le_bytes(buf[0..8].try_into().unwrap());
+
+        Some(Self { footer_offset })
+    }
+}
+
+// ---
+
+/// The index stored in the footer of an `.rrd` file.
+///
+/// Contains one entry per message in the file, in the order they were written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RrdFooter {
+    pub entries: Vec<MessageIndexEntry>,
+}
+
+/// Where to find a single message in an `.rrd` file, and what's in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageIndexEntry {
+    /// The store this message belongs to.
+    pub store_id: StoreId,
+
+    /// Byte offset of the message's header, from the start of the file.
+    pub byte_offset: u64,
+
+    /// Size of the message in bytes, header included.
+    pub byte_len: u64,
+
+    /// Chunk-level information, if this is an `ArrowMsg`.
+    ///
+    /// `None` for all other messages (`SetStoreInfo`, `BlueprintActivationCommand`).
+    pub chunk: Option<ChunkIndexInfo>,
+}
+
+/// What the index knows about a chunk, without having to decode it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkIndexInfo {
+    pub chunk_id: ChunkId,
+    pub entity_path: EntityPath,
+    pub is_static: bool,
+    pub num_rows: u64,
+
+    /// The time range covered by the chunk, on each of its timelines.
+    ///
+    /// Always empty for static chunks.
+    pub time_ranges: BTreeMap<TimelineName, ResolvedTimeRange>,
+}
+
+impl MessageIndexEntry {
+    /// Computes the index entry for a message that spans `byte_len` bytes at `byte_offset`.
+    ///
+    /// This is called for every message written with a footer index, so the chunk itself is never
+    /// decoded: everything is read from the schema metadata and the time columns of the batch.
+    pub fn from_log_msg(
+        msg: &LogMsg,
+        byte_offset: u64,
+        byte_len: u64,
+    ) -> re_chunk::ChunkResult<Self> {
+        let chunk = match msg {
+            LogMsg::ArrowMsg(_, arrow_msg) => {
+                Some(ChunkIndexInfo::from_record_batch(&arrow_msg.batch)?)
+            }
+
+            LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => None,
+        };
+
+        Ok(Self {
+            store_id: msg.store_id().clone(),
+            byte_offset,
+            byte_len,
+            chunk,
+        })
+    }
+}
+
+impl ChunkIndexInfo {
+    /// Reads the index information out of the record batch of a chunk, without decoding it.
+    pub fn from_record_batch(batch: &arrow::array::RecordBatch) -> re_chunk::ChunkResult<Self> {
+        re_tracing::profile_function!();
+
+        let schema = re_sorbet::ChunkSchema::try_from(batch.schema_ref().as_ref())?;
+
+        // Index columns always directly follow the row id column, see `re_sorbet::SorbetBatch`.
+        let num_row_id_columns = 1;
+        let time_ranges = schema
+            .index_columns()
+            .iter()
+            .zip(batch.columns().iter().skip(num_row_id_columns))
+            .map(|(descr, column)| {
+                let times = re_chunk::TimeColumn::read_array(column.as_ref()).map_err(|err| {
+                    re_chunk::ChunkError::Malformed {
+                        reason: format!("Bad time column '{}': {err}", descr.column_name()),
+                    }
+                })?;
+                let times = times.as_ref();
+
+                let (min, max) = if descr.is_sorted() {
+                    (times.first().copied(), times.last().copied())
+                } else {
+                    (times.iter().min().copied(), times.iter().max().copied())
+                };
+                let time_range = ResolvedTimeRange::new(
+                    min.map_or(
+                        re_log_types::TimeInt::MIN,
+                        re_log_types::TimeInt::new_temporal,
+                    ),
+                    max.map_or(
+                        re_log_types::TimeInt::MAX,
+                        re_log_types::TimeInt::new_temporal,
+                    ),
+                );
+
+                Ok((descr.timeline_name(), time_range))
+            })
+            .collect::<re_chunk::ChunkResult<BTreeMap<_, _>>>()?;
+
+        Ok(Self {
+            chunk_id: schema.chunk_id(),
+            entity_path: schema.entity_path().clone(),
+            is_static: time_ranges.is_empty(),
+            num_rows: batch.num_rows() as u64,
+            time_ranges,
+        })
+    }
+}
+
+// ---
+
+/// Selects which messages to read out of an indexed `.rrd` file.
+///
+/// Messages that aren't chunks (`SetStoreInfo`, `BlueprintActivationCommand`) only have to match
+/// [`Self::store_id`], since they are needed to make sense of the chunks that follow.
+///
+/// The default query matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkIndexQuery {
+    /// Only keep messages belonging to this store.
+    pub store_id: Option<StoreId>,
+
+    /// Only keep chunks whose entity path matches this filter.
+    pub entity_path_filter: Option<ResolvedEntityPathFilter>,
+
+    /// Only keep chunks that have data on the given timeline, within the given range.
+    ///
+    /// Static chunks always match, since they apply to all times.
+    pub time_range: Option<(TimelineName, ResolvedTimeRange)>,
+}
+
+impl ChunkIndexQuery {
+    /// Does the given message belong in the results of this query?
+    pub fn matches(&self, entry: &MessageIndexEntry) -> bool {
+        let Self {
+            store_id,
+            entity_path_filter,
+            time_range,
+        } = self;
+
+        if store_id.as_ref().is_some_and(|id| id != &entry.store_id) {
+            return false;
+        }
+
+        let Some(chunk) = &entry.chunk else {
+            return true;
+        };
+
+        if entity_path_filter
+            .as_ref()
+            .is_some_and(|filter| !filter.matches(&chunk.entity_path))
+        {
+            return false;
+        }
+
+        if let Some((timeline, query_range)) = time_range {
+            if !chunk.is_static {
+                return chunk
+                    .time_ranges
+                    .get(timeline)
+                    .is_some_and(|range| range.intersects(*query_range));
+            }
+        }
+
+        true
+    }
+}
+
+impl RrdFooter {
+    /// All the entries that match the given query, in file order.
+    pub fn query<'a>(
+        &'a self,
+        query: &'a ChunkIndexQuery,
+    ) -> impl Iterator<Item = &'a MessageIndexEntry> + 'a {
+        self.entries.iter().filter(|entry| query.matches(entry))
+    }
+
+    /// All the chunk entries in the index.
+    pub fn chunks(&self) -> impl Iterator<Item = (&MessageIndexEntry, &ChunkIndexInfo)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.chunk.as_ref().map(|chunk| (entry, chunk)))
+    }
+}
+
+// --- Arrow (de)serialization ---
+
+mod fields {
+    pub const STORE_KIND: &str = "store_kind";
+    pub const STORE_ID: &str = "store_id";
+    pub const BYTE_OFFSET: &str = "byte_offset";
+    pub const BYTE_LEN: &str = "byte_len";
+    pub const CHUNK_ID: &str = "chunk_id";
+    pub const ENTITY_PATH: &str = "entity_path";
+    pub const IS_STATIC: &str = "is_static";
+    pub const NUM_ROWS: &str = "num_rows";
+    pub const TIMELINES: &str = "timelines";
+    pub const TIME_MIN: &str = "time_min";
+    pub const TIME_MAX: &str = "time_max";
+}
+
+#[cfg(feature = "encoder")]
+fn store_kind_to_u8(kind: StoreKind) -> u8 {
+    match kind {
+        StoreKind::Recording => 0,
+        StoreKind::Blueprint => 1,
+    }
+}
+
+#[cfg(feature = "decoder")]
+fn store_kind_from_u8(kind: u8) -> Option<StoreKind> {
+    match kind {
+        0 => Some(StoreKind::Recording),
+        1 => Some(StoreKind::Blueprint),
+        _ => None,
+    }
+}
+
+impl RrdFooter {
+    /// Serializes the index as a single Arrow record batch.
+    #[cfg(feature = "encoder")]
+    pub fn to_record_batch(&self) -> Result<arrow::array::RecordBatch, arrow::error::ArrowError> {
+        use std::sync::Arc;
+
+        use arrow::array::{
+            ArrayRef, BooleanBuilder, FixedSizeBinaryBuilder, Int64Builder, ListBuilder,
+            StringBuilder, UInt64Builder, UInt8Builder,
+        };
+
+        let num_entries = self.entries.len();
+
+        let mut store_kind = UInt8Builder::with_capacity(num_entries);
+        let mut store_id = StringBuilder::with_capacity(num_entries, 0);
+        let mut byte_offset = UInt64Builder::with_capacity(num_entries);
+        let mut byte_len = UInt64Builder::with_capacity(num_entries);
+        let mut chunk_id = FixedSizeBinaryBuilder::with_capacity(num_entries, 16);
+        let mut entity_path = StringBuilder::with_capacity(num_entries, 0);
+        let mut is_static = BooleanBuilder::with_capacity(num_entries);
+        let mut num_rows = UInt64Builder::with_capacity(num_entries);
+        let mut timelines = ListBuilder::new(StringBuilder::new());
+        let mut time_min = ListBuilder::new(Int64Builder::new());
+        let mut time_max = ListBuilder::new(Int64Builder::new());
+
+        for entry in &self.entries {
+            store_kind.append_value(store_kind_to_u8(entry.store_id.kind));
+            store_id.append_value(entry.store_id.as_str());
+            byte_offset.append_value(entry.byte_offset);
+            byte_len.append_value(entry.byte_len);
+
+            if let Some(chunk) = &entry.chunk {
+                chunk_id.append_value(chunk.chunk_id.as_tuid().as_u128().to_be_bytes())?;
+                entity_path.append_value(chunk.entity_path.to_string());
+                is_static.append_value(chunk.is_static);
+                num_rows.append_value(chunk.num_rows);
+                for (timeline, range) in &chunk.time_ranges {
+                    timelines.values().append_value(timeline.as_str());
+                    time_min.values().append_value(range.min().as_i64());
+                    time_max.values().append_value(range.max().as_i64());
+                }
+            } else {
+                chunk_id.append_null();
+                entity_path.append_null();
+                is_static.append_null();
+                num_rows.append_null();
+            }
+
+            timelines.append(true);
+            time_min.append(true);
+            time_max.append(true);
+        }
+
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            (fields::STORE_KIND, Arc::new(store_kind.finish())),
+            (fields::STORE_ID, Arc::new(store_id.finish())),
+            (fields::BYTE_OFFSET, Arc::new(byte_offset.finish())),
+            (fields::BYTE_LEN, Arc::new(byte_len.finish())),
+            (fields::CHUNK_ID, Arc::new(chunk_id.finish())),
+            (fields::ENTITY_PATH, Arc::new(entity_path.finish())),
+            (fields::IS_STATIC, Arc::new(is_static.finish())),
+            (fields::NUM_ROWS, Arc::new(num_rows.finish())),
+            (fields::TIMELINES, Arc::new(timelines.finish())),
+            (fields::TIME_MIN, Arc::new(time_min.finish())),
+            (fields::TIME_MAX, Arc::new(time_max.finish())),
+        ];
+
+        arrow::array::RecordBatch::try_from_iter(columns)
+    }
+
+    /// Deserializes the index from the Arrow record batch written by [`Self::to_record_batch`].
+    #[cfg(feature = "decoder")]
+    pub fn from_record_batch(
+        batch: &arrow::array::RecordBatch,
+    ) -> Result<Self, crate::codec::CodecError> {
+        use arrow::array::{
+            Array as _, BooleanArray, FixedSizeBinaryArray, Int64Array, ListArray, StringArray,
+            UInt64Array, UInt8Array,
+        };
+        use arrow::error::ArrowError;
+
+        use crate::codec::CodecError;
+
+        fn column<'a, T: 'static>(
+            batch: &'a arrow::array::RecordBatch,
+            name: &str,
+        ) -> Result<&'a T, CodecError> {
+            batch
+                .column_by_name(name)
+                .and_then(|array| array.as_any().downcast_ref::<T>())
+                .ok_or_else(|| {
+                    CodecError::ArrowDeserialization(ArrowError::SchemaError(format!(
+                        "footer index: missing or malformed column {name:?}"
+                    )))
+                })
+        }
+
+        fn malformed(reason: &str) -> CodecError {
+            CodecError::ArrowDeserialization(ArrowError::InvalidArgumentError(format!(
+                "footer index: {reason}"
+            )))
+        }
+
+        fn list_values<T: 'static>(list: &ListArray) -> Result<&T, CodecError> {
+            list.values().as_any().downcast_ref::<T>().ok_or_else(|| {
+                CodecError::ArrowDeserialization(ArrowError::SchemaError(
+                    "footer index: malformed list column".to_owned(),
+                ))
+            })
+        }
+
+        let store_kind = column::<UInt8Array>(batch, fields::STORE_KIND)?;
+        let store_id = column::<StringArray>(batch, fields::STORE_ID)?;
+        let byte_offset = column::<UInt64Array>(batch, fields::BYTE_OFFSET)?;
+        let byte_len = column::<UInt64Array>(batch, fields::BYTE_LEN)?;
+        let chunk_id = column::<FixedSizeBinaryArray>(batch, fields::CHUNK_ID)?;
+        let entity_path = column::<StringArray>(batch, fields::ENTITY_PATH)?;
+        let is_static = column::<BooleanArray>(batch, fields::IS_STATIC)?;
+        let num_rows = column::<UInt64Array>(batch, fields::NUM_ROWS)?;
+        let timelines = column::<ListArray>(batch, fields::TIMELINES)?;
+        let time_min = column::<ListArray>(batch, fields::TIME_MIN)?;
+        let time_max = column::<ListArray>(batch, fields::TIME_MAX)?;
+
+        let timeline_names = list_values::<StringArray>(timelines)?;
+        let time_min_values = list_values::<Int64Array>(time_min)?;
+        let time_max_values = list_values::<Int64Array>(time_max)?;
+
+        if chunk_id.value_length() != 16 {
+            return Err(malformed(&format!(
+                "chunk ids are {} bytes long, expected 16",
+                chunk_id.value_length()
+            )));
+        }
+
+        // The time ranges are read by zipping all three lists together.
+        if time_min.value_offsets() != timelines.value_offsets()
+            || time_max.value_offsets() != timelines.value_offsets()
+        {
+            return Err(malformed("time ranges don't line up with their timelines"));
+        }
+
+        let mut entries = Vec::with_capacity(batch.num_rows());
+        for i in 0..batch.num_rows() {
+            let kind = store_kind_from_u8(store_kind.value(i))
+                .ok_or_else(|| malformed(&format!("unknown store kind {}", store_kind.value(i))))?;
+
+            let chunk = if chunk_id.is_valid(i) {
+                let id = <[u8; 16]>::try_from(chunk_id.value(i))
+                    .map_err(|_err| malformed("bad chunk id"))?;
+                let id = u128::from_be_bytes(id);
+
+                let offsets = timelines.value_offsets();
+                let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+                let time_ranges = (start..end)
+                    .map(|j| {
+                        (
+                            TimelineName::new(timeline_names.value(j)),
+                            ResolvedTimeRange::new(
+                                re_log_types::TimeInt::new_temporal(time_min_values.value(j)),
+                                re_log_types::TimeInt::new_temporal(time_max_values.value(j)),
+                            ),
+                        )
+                    })
+                    .collect();
+
+                Some(ChunkIndexInfo {
+                    chunk_id: ChunkId::from_u128(id),
+                    entity_path: EntityPath::parse_forgiving(entity_path.value(i)),
+                    is_static: is_static.value(i),
+                    num_rows: num_rows.value(i),
+                    time_ranges,
+                })
+            } else {
+                None
+            };
+
+            entries.push(MessageIndexEntry {
+                store_id: StoreId::from_string(kind, store_id.value(i).to_owned()),
+                byte_offset: byte_offset.value(i),
+                byte_len: byte_len.value(i),
+                chunk,
+            });
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Deserializes the index from the Arrow IPC stream stored in the footer.
+    ///
+    /// The index may be split across any number of record batches, see [`FooterIndexBuilder`].
+    #[cfg(feature = "decoder")]
+    pub(crate) fn from_ipc_bytes(bytes: &[u8]) -> Result<Self, crate::codec::CodecError> {
+        use crate::codec::CodecError;
+
+        let reader = arrow::ipc::reader::StreamReader::try_new(bytes, None)
+            .map_err(CodecError::ArrowDeserialization)?;
+
+        let mut entries = Vec::new();
+        for batch in reader {
+            let batch = batch.map_err(CodecError::ArrowDeserialization)?;
+            entries.extend(Self::from_record_batch(&batch)?.entries);
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+/// Builds the footer index of a stream, one message at a time.
+///
+/// Every [`INDEX_BATCH_LEN`] messages, the pending entries are serialized to the Arrow IPC stream
+/// that ends up in the footer, where they take up a fraction of their in-memory size.
+#[cfg(feature = "encoder")]
+#[derive(Default)]
+pub(crate) struct FooterIndexBuilder {
+    /// The entries that haven't been serialized yet.
+    pending: RrdFooter,
+
+    /// Everything serialized so far. Created along with the first batch.
+    ipc: Option<arrow::ipc::writer::StreamWriter<Vec<u8>>>,
+}
+
+#[cfg(feature = "encoder")]
+impl FooterIndexBuilder {
+    pub(crate) fn push(
+        &mut self,
+        entry: MessageIndexEntry,
+    ) -> Result<(), arrow::error::ArrowError> {
+        self.pending.entries.push(entry);
+        if self.pending.entries.len() >= INDEX_BATCH_LEN {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), arrow::error::ArrowError> {
+        let batch = self.pending.to_record_batch()?;
+        self.pending.entries.clear();
+
+        let ipc = match &mut self.ipc {
+            Some(ipc) => ipc,
+            None => self.ipc.insert(arrow::ipc::writer::StreamWriter::try_new(
+                Vec::new(),
+                batch.schema_ref(),
+            )?),
+        };
+        ipc.write(&batch)
+    }
+
+    /// Returns the Arrow IPC stream to store in the footer.
+    pub(crate) fn finish(mut self) -> Result<Vec<u8>, arrow::error::ArrowError> {
+        // An empty stream still gets a batch, so that readers know its schema.
+        if !self.pending.entries.is_empty() || self.ipc.is_none() {
+            self.flush()?;
+        }
+
+        let Some(mut ipc) = self.ipc.take() else {
+            return Ok(Vec::new());
+        };
+        ipc.finish()?;
+        ipc.into_inner()
+    }
+}
@@ -8,21 +8,35 @@ pub mod encoder;
 
 pub mod codec;
 
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+pub mod footer;
+
 pub mod protobuf_conversions;
 
 #[cfg(feature = "encoder")]
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+mod rotating_file_sink;
+
 #[cfg(feature = "stream_from_http")]
 pub mod stream_rrd_from_http;
 
+#[cfg(all(feature = "encoder", any(test, feature = "testing")))]
+pub mod testing;
+
 // ---------------------------------------------------------------------
 
 #[cfg(feature = "encoder")]
 #[cfg(not(target_arch = "wasm32"))]
 pub use file_sink::{FileSink, FileSinkError};
 
+#[cfg(feature = "encoder")]
+#[cfg(not(target_arch = "wasm32"))]
+pub use rotating_file_sink::{RotatingFileSink, RotationOptions};
+
 // ----------------------------------------------------------------------------
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
@@ -35,12 +49,87 @@ const OLD_RRD_HEADERS: &[[u8; 4]] = &[*b"RRF0", *b"RRF1"];
 
 /// Compression format used.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[repr(u8)]
 pub enum Compression {
-    Off = 0,
+    Off,
 
     /// Very fast compression and decompression, but not very good compression ratio.
-    LZ4 = 1,
+    LZ4,
+
+    /// Slower than [`Self::LZ4`], but with a much better compression ratio.
+    ///
+    /// Only available on native targets.
+    Zstd {
+        /// Compression level, see [`Self::ZSTD_DEFAULT_LEVEL`].
+        ///
+        /// The level only affects encoding: it is not stored in the file header,
+        /// and decoded headers always report [`Self::ZSTD_DEFAULT_LEVEL`].
+        level: i8,
+    },
+}
+
+impl Compression {
+    /// The default zstd compression level, same as the one used by the `zstd` CLI.
+    pub const ZSTD_DEFAULT_LEVEL: i8 = 3;
+
+    /// Zstd compression using [`Self::ZSTD_DEFAULT_LEVEL`].
+    pub const ZSTD: Self = Self::Zstd {
+        level: Self::ZSTD_DEFAULT_LEVEL,
+    };
+
+    /// The value stored in the file header.
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::LZ4 => 1,
+            Self::Zstd { .. } => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, OptionsError> {
+        match byte {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::LZ4),
+            2 => Ok(Self::ZSTD),
+            _ => Err(OptionsError::UnknownCompression(byte)),
+        }
+    }
+}
+
+impl std::fmt::Display for Compression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Off => f.write_str("off"),
+            Self::LZ4 => f.write_str("lz4"),
+            Self::Zstd { level } => write!(f, "zstd:{level}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Compression {
+    type Err = String;
+
+    /// Parses `off`, `lz4`, `zstd` or `zstd:<level>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" | "none" => Ok(Self::Off),
+            "lz4" => Ok(Self::LZ4),
+            "zstd" => Ok(Self::ZSTD),
+            other => {
+                let Some(level) = other.strip_prefix("zstd:") else {
+                    return Err(format!(
+                        "unknown compression {s:?}, expected one of: off, lz4, zstd, zstd:<level>"
+                    ));
+                };
+                let level = level
+                    .parse::<i8>()
+                    .map_err(|err| format!("invalid zstd level {level:?}: {err}"))?;
+                if !(1..=22).contains(&level) {
+                    return Err(format!("zstd level must be between 1 and 22, got {level}"));
+                }
+                Ok(Self::Zstd { level })
+            }
+        }
+    }
 }
 
 /// How we serialize the data
@@ -69,11 +158,7 @@ impl EncodingOptions {
     pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, OptionsError> {
         match bytes {
             [compression, serializer, 0, 0] => {
-                let compression = match compression {
-                    0 => Compression::Off,
-                    1 => Compression::LZ4,
-                    _ => return Err(OptionsError::UnknownCompression(compression)),
-                };
+                let compression = Compression::from_byte(compression)?;
                 let serializer = match serializer {
                     1 => return Err(OptionsError::RemovedMsgPackSerializer),
                     2 => Serializer::Protobuf,
@@ -90,7 +175,7 @@ impl EncodingOptions {
 
     pub fn to_bytes(self) -> [u8; 4] {
         [
-            self.compression as u8,
+            self.compression.to_byte(),
             self.serializer as u8,
             0, // reserved
             0, // reserved
@@ -105,7 +190,9 @@ pub enum OptionsError {
     #[error("Reserved bytes not zero")]
     UnknownReservedBytes,
 
-    #[error("Unknown compression: {0}")]
+    #[error(
+        "Unknown compression: {0}. The data was probably written by a newer version of Rerun."
+    )]
     UnknownCompression(u8),
 
     // TODO(jan): Remove this at some point, realistically 1-2 releases after 0.23
@@ -128,7 +215,6 @@ pub(crate) struct FileHeader {
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
 impl FileHeader {
-    #[cfg(feature = "decoder")]
     pub const SIZE: usize = 12;
 
     #[cfg(feature = "encoder")]
@@ -6,6 +6,7 @@ impl From<re_protos::log_msg::v1alpha1::Compression> for crate::Compression {
             re_protos::log_msg::v1alpha1::Compression::Unspecified
             | re_protos::log_msg::v1alpha1::Compression::None => Self::Off,
             re_protos::log_msg::v1alpha1::Compression::Lz4 => Self::LZ4,
+            re_protos::log_msg::v1alpha1::Compression::Zstd => Self::ZSTD,
         }
     }
 }
@@ -15,10 +16,24 @@ impl From<crate::Compression> for re_protos::log_msg::v1alpha1::Compression {
         match value {
             crate::Compression::Off => Self::None,
             crate::Compression::LZ4 => Self::Lz4,
+            crate::Compression::Zstd { .. } => Self::Zstd,
         }
     }
 }
 
+/// Converts the raw `compression` field of an `ArrowMsg`.
+///
+/// Unlike the prost-generated getter, this does not silently fall back to "no compression"
+/// for values we don't know about (e.g. written by a newer version of Rerun).
+#[cfg(feature = "decoder")]
+pub(crate) fn compression_from_proto(
+    compression: i32,
+) -> Result<crate::Compression, crate::codec::CodecError> {
+    re_protos::log_msg::v1alpha1::Compression::try_from(compression)
+        .map(Into::into)
+        .map_err(|_err| crate::codec::CodecError::UnknownCompression(compression))
+}
+
 #[cfg(feature = "decoder")]
 pub fn log_msg_from_proto(
     message: re_protos::log_msg::v1alpha1::LogMsg,
@@ -43,7 +58,7 @@ pub fn log_msg_from_proto(
             let batch = decode_arrow(
                 &arrow_msg.payload,
                 arrow_msg.uncompressed_size as usize,
-                arrow_msg.compression().into(),
+                compression_from_proto(arrow_msg.compression)?,
             )?;
 
             let store_id: re_log_types::StoreId = arrow_msg
@@ -92,14 +107,7 @@ pub fn log_msg_to_proto(
             let payload = encode_arrow(&arrow_msg.batch, compression)?;
             let arrow_msg = ArrowMsg {
                 store_id: Some(store_id.into()),
-                compression: match compression {
-                    crate::Compression::Off => {
-                        re_protos::log_msg::v1alpha1::Compression::None as i32
-                    }
-                    crate::Compression::LZ4 => {
-                        re_protos::log_msg::v1alpha1::Compression::Lz4 as i32
-                    }
-                },
+                compression: re_protos::log_msg::v1alpha1::Compression::from(compression) as i32,
                 uncompressed_size: payload.uncompressed_size as i32,
                 encoding: re_protos::log_msg::v1alpha1::Encoding::ArrowIpc as i32,
                 payload: payload.data,
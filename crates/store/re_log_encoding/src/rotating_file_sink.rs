@@ -0,0 +1,585 @@
+use std::fmt;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::mpsc::{Receiver, Sender},
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+
+use re_log_types::{ComponentPath, LogMsg, StoreId};
+
+use crate::file_sink::{Command, FileSinkError};
+
+/// When a [`RotatingFileSink`] starts a new segment, and which old segments it deletes.
+///
+/// The default never rotates nor deletes anything.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RotationOptions {
+    /// Start a new segment once the current one holds at least this many bytes of messages.
+    pub max_segment_bytes: Option<u64>,
+
+    /// Start a new segment once the current one has been open for this long.
+    ///
+    /// This is checked whenever a message comes in: an idle sink never rotates.
+    pub max_segment_duration: Option<Duration>,
+
+    /// Delete the oldest segments so that at most this many remain, including the one being
+    /// written to.
+    pub max_segments: Option<usize>,
+
+    /// Delete the oldest segments so that the finished ones take at most this many bytes on disk.
+    pub max_total_bytes: Option<u64>,
+
+    /// End every segment with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    pub footer_index: bool,
+}
+
+/// Stream log messages to a series of `.rrd` files, starting a new one whenever the current one
+/// grows too large or too old.
+///
+/// Segments are named after the given path, followed by an increasing index, see
+/// [`RotatingFileSink::segment_path`]. Numbering resumes after any segment already on disk, which
+/// also count towards the [`RotationOptions`] retention limits.
+///
+/// Each segment starts with the latest [`LogMsg::SetStoreInfo`] of every store and the latest
+/// static data of every entity and component seen so far, so that it can be opened on its own.
+pub struct RotatingFileSink {
+    // None = quit
+    tx: Mutex<Sender<Option<Command>>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+
+    /// Only used for diagnostics, not for access after `new()`.
+    path: PathBuf,
+}
+
+impl Drop for RotatingFileSink {
+    fn drop(&mut self) {
+        self.tx.lock().send(None).ok();
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
+impl RotatingFileSink {
+    /// Start writing log messages to segments named after the given path.
+    ///
+    /// The first segment is created right away.
+    pub fn new(path: impl Into<PathBuf>, options: RotationOptions) -> Result<Self, FileSinkError> {
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let path = path.into();
+
+        re_log::debug!("Saving rotating files to {path:?}…");
+
+        let mut writer = SegmentWriter {
+            segments: existing_segments(&path),
+            path: path.clone(),
+            options,
+            preamble: Vec::new(),
+            current: None,
+        };
+        writer.start_segment()?;
+
+        let join_handle = std::thread::Builder::new()
+            .name("rotating_file_writer".into())
+            .spawn(move || writer.run(&rx))
+            .map_err(FileSinkError::SpawnThread)?;
+
+        Ok(Self {
+            tx: tx.into(),
+            join_handle: Some(join_handle),
+            path,
+        })
+    }
+
+    /// The path of the segment with the given index, e.g. `recording.000042.rrd` for
+    /// `recording.rrd`.
+    pub fn segment_path(path: &Path, index: u64) -> PathBuf {
+        let stem = path
+            .file_stem()
+            .map_or_else(|| "recording".into(), |stem| stem.to_string_lossy());
+        let extension = path
+            .extension()
+            .map_or_else(|| "rrd".into(), |extension| extension.to_string_lossy());
+        path.with_file_name(format!("{stem}.{index:06}.{extension}"))
+    }
+
+    #[inline]
+    pub fn flush_blocking(&self) {
+        let (cmd, oneshot) = Command::flush();
+        self.tx.lock().send(Some(cmd)).ok();
+        oneshot.recv().ok();
+    }
+
+    #[inline]
+    pub fn send(&self, log_msg: LogMsg) {
+        self.tx.lock().send(Some(Command::Send(log_msg))).ok();
+    }
+}
+
+impl fmt::Debug for RotatingFileSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingFileSink")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+// ---
+
+struct Segment {
+    index: u64,
+    path: PathBuf,
+    encoder: crate::encoder::DroppableEncoder<std::fs::File>,
+    started_at: Instant,
+    num_bytes: u64,
+
+    /// Does this segment contain anything but the preamble?
+    has_new_data: bool,
+}
+
+struct SegmentWriter {
+    path: PathBuf,
+    options: RotationOptions,
+
+    /// `(index, path)` of all the segments on disk, oldest first, including the current one.
+    segments: Vec<(u64, PathBuf)>,
+
+    /// Store infos and static chunks, re-emitted at the start of every segment, in order.
+    ///
+    /// Messages are dropped once everything they provide has been superseded by later ones.
+    preamble: Vec<PreambleMsg>,
+
+    current: Option<Segment>,
+}
+
+impl SegmentWriter {
+    fn run(mut self, rx: &Receiver<Option<Command>>) {
+        let target = self.path.display().to_string();
+
+        while let Ok(Some(cmd)) = rx.recv() {
+            match cmd {
+                Command::Send(log_msg) => {
+                    if let Err(err) = self.append(log_msg) {
+                        re_log::error!("Failed to write log stream to {target}: {err}");
+                        return;
+                    }
+                }
+                Command::Flush(oneshot) => {
+                    re_log::trace!("Flushing…");
+                    if let Some(segment) = &mut self.current {
+                        if let Err(err) = segment.encoder.flush_blocking() {
+                            re_log::error!("Failed to flush log stream to {target}: {err}");
+                            return;
+                        }
+                    }
+                    drop(oneshot); // signals the oneshot
+                }
+            }
+        }
+
+        if let Err(err) = self.finish_segment() {
+            re_log::error!("Failed to end log stream for {target}: {err}");
+            return;
+        }
+        re_log::debug!("Log stream written to {target}");
+    }
+
+    fn append(&mut self, log_msg: LogMsg) -> Result<(), FileSinkError> {
+        if self.should_rotate() {
+            self.finish_segment()?;
+            self.start_segment()?;
+        }
+
+        let Some(segment) = &mut self.current else {
+            return Ok(());
+        };
+
+        segment.num_bytes += segment.encoder.append(&log_msg)?;
+        segment.has_new_data = true;
+
+        if is_preamble(&log_msg) {
+            self.add_to_preamble(log_msg);
+        }
+
+        Ok(())
+    }
+
+    /// Adds a message to the preamble, dropping whatever older messages it fully supersedes.
+    fn add_to_preamble(&mut self, msg: LogMsg) {
+        let keys = preamble_keys(&msg);
+
+        // Store infos keep their place, so that they always come before the data of their store.
+        if matches!(msg, LogMsg::SetStoreInfo(_)) {
+            if let Some(older) = self.preamble.iter_mut().find(|older| older.keys == keys) {
+                older.msg = msg;
+                return;
+            }
+        }
+
+        self.preamble.retain_mut(|older| {
+            let num_keys = older.keys.len();
+            older.keys.retain(|key| !keys.contains(key));
+
+            // NOTE: Messages whose keys are unknown are never dropped.
+            older.keys.len() == num_keys || !older.keys.is_empty()
+        });
+
+        self.preamble.push(PreambleMsg { msg, keys });
+    }
+
+    fn should_rotate(&self) -> bool {
+        let Some(segment) = &self.current else {
+            return false;
+        };
+
+        if !segment.has_new_data {
+            return false;
+        }
+
+        let RotationOptions {
+            max_segment_bytes,
+            max_segment_duration,
+            max_segments: _,
+            max_total_bytes: _,
+            footer_index: _,
+        } = self.options;
+
+        max_segment_bytes.is_some_and(|max| segment.num_bytes >= max)
+            || max_segment_duration.is_some_and(|max| segment.started_at.elapsed() >= max)
+    }
+
+    fn start_segment(&mut self) -> Result<(), FileSinkError> {
+        let index = self.segments.last().map_or(0, |(index, _)| index + 1);
+        let path = RotatingFileSink::segment_path(&self.path, index);
+
+        re_log::debug!("Starting new segment {path:?}…");
+
+        let file = std::fs::File::create(&path)
+            .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
+        let mut encoder = crate::encoder::DroppableEncoder::new(
+            re_build_info::CrateVersion::LOCAL,
+            crate::EncodingOptions::PROTOBUF_COMPRESSED,
+            file,
+        )?;
+        if self.options.footer_index {
+            encoder = encoder.with_footer_index();
+        }
+
+        let mut num_bytes = 0;
+        for PreambleMsg { msg, keys: _ } in &self.preamble {
+            num_bytes += encoder.append(msg)?;
+        }
+
+        self.segments.push((index, path.clone()));
+        self.current = Some(Segment {
+            index,
+            path,
+            encoder,
+            started_at: Instant::now(),
+            num_bytes,
+            has_new_data: false,
+        });
+
+        self.apply_retention();
+
+        Ok(())
+    }
+
+    fn finish_segment(&mut self) -> Result<(), FileSinkError> {
+        if let Some(mut segment) = self.current.take() {
+            segment.encoder.finish()?;
+            re_log::debug!(
+                "Finished segment #{} ({})",
+                segment.index,
+                segment.path.display()
+            );
+        }
+        Ok(())
+    }
+
+    /// Deletes the oldest finished segments until we're within budget.
+    fn apply_retention(&mut self) {
+        let RotationOptions {
+            max_segment_bytes: _,
+            max_segment_duration: _,
+            max_segments,
+            max_total_bytes,
+            footer_index: _,
+        } = self.options;
+
+        let current_index = self.current.as_ref().map(|segment| segment.index);
+        let mut finished: Vec<(u64, PathBuf, u64)> = self
+            .segments
+            .iter()
+            .filter(|(index, _)| Some(*index) != current_index)
+            .map(|(index, path)| {
+                let num_bytes = std::fs::metadata(path).map_or(0, |metadata| metadata.len());
+                (*index, path.clone(), num_bytes)
+            })
+            .collect();
+
+        let mut total_bytes: u64 = finished.iter().map(|(_, _, num_bytes)| num_bytes).sum();
+        let mut num_segments = finished.len() + usize::from(current_index.is_some());
+
+        let mut num_removed = 0;
+        for (_, path, num_bytes) in &finished {
+            let too_many = max_segments.is_some_and(|max| num_segments > max);
+            let too_large = max_total_bytes.is_some_and(|max| total_bytes > max);
+            if !too_many && !too_large {
+                break;
+            }
+
+            re_log::debug!("Deleting old segment {path:?}");
+            if let Err(err) = std::fs::remove_file(path) {
+                re_log::warn!("Failed to delete old segment {path:?}: {err}");
+            }
+
+            num_segments -= 1;
+            total_bytes -= num_bytes;
+            num_removed += 1;
+        }
+
+        let removed: Vec<u64> = finished
+            .drain(..num_removed)
+            .map(|(index, _, _)| index)
+            .collect();
+        self.segments.retain(|(index, _)| !removed.contains(index));
+    }
+}
+
+/// A message of the preamble of every segment, see [`SegmentWriter::preamble`].
+struct PreambleMsg {
+    msg: LogMsg,
+
+    /// Whatever this message provides that hasn't been superseded by a later message yet.
+    keys: HashSet<PreambleKey>,
+}
+
+/// Something that a preamble message provides, and that later messages can supersede.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum PreambleKey {
+    StoreInfo(StoreId),
+    StaticComponent(StoreId, ComponentPath),
+}
+
+/// Everything that the given preamble message provides.
+///
+/// Returns an empty set if that cannot be determined.
+fn preamble_keys(msg: &LogMsg) -> HashSet<PreambleKey> {
+    match msg {
+        LogMsg::SetStoreInfo(set_store_info) => {
+            std::iter::once(PreambleKey::StoreInfo(set_store_info.info.store_id.clone())).collect()
+        }
+
+        LogMsg::ArrowMsg(store_id, arrow_msg) => {
+            re_sorbet::ChunkSchema::try_from(arrow_msg.batch.schema_ref().as_ref())
+                .map(|schema| {
+                    schema
+                        .component_columns()
+                        .iter()
+                        .map(|descr| {
+                            PreambleKey::StaticComponent(store_id.clone(), descr.component_path())
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        LogMsg::BlueprintActivationCommand(_) => HashSet::default(),
+    }
+}
+
+/// Does this message need to be part of every segment?
+fn is_preamble(msg: &LogMsg) -> bool {
+    match msg {
+        LogMsg::SetStoreInfo(_) => true,
+
+        // Static chunks are the ones without any index column.
+        LogMsg::ArrowMsg(_, arrow_msg) => {
+            !arrow_msg.batch.schema_ref().fields().iter().any(|field| {
+                field
+                    .metadata()
+                    .get("rerun.kind")
+                    .is_some_and(|kind| kind == "index" || kind == "time")
+            })
+        }
+
+        LogMsg::BlueprintActivationCommand(_) => false,
+    }
+}
+
+/// All the segments for `path` that already exist on disk, oldest first.
+fn existing_segments(path: &Path) -> Vec<(u64, PathBuf)> {
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut segments: Vec<(u64, PathBuf)> = entries
+        .filter_map(|entry| {
+            let entry_path = entry.ok()?.path();
+            let file_name = entry_path.file_name()?.to_str()?;
+
+            // Parse the index back out of the name, and make sure we get the same name back.
+            let index: u64 = file_name.split('.').rev().nth(1)?.parse().ok()?;
+            (RotatingFileSink::segment_path(path, index).file_name()? == file_name)
+                .then_some((index, entry_path))
+        })
+        .collect();
+
+    segments.sort();
+    segments
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{example_components::MyPoint, StoreId, StoreKind, TimePoint, Timeline};
+
+    use super::*;
+
+    fn read_segment(path: &Path) -> Vec<LogMsg> {
+        let file = std::io::BufReader::new(std::fs::File::open(path).unwrap());
+        crate::decoder::Decoder::new(file)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn rotation_and_retention() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.rrd");
+
+        let store_id = StoreId::from_string(StoreKind::Recording, "test".into());
+        let store_info =
+            crate::testing::store_info_message(&store_id, re_build_info::CrateVersion::LOCAL);
+
+        let chunk = |timepoint: TimePoint| {
+            let chunk = Chunk::builder("points".into())
+                .with_component_batches(RowId::new(), timepoint, [&[MyPoint::new(1.0, 2.0)] as _])
+                .build()
+                .unwrap();
+            LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+        };
+
+        let sink = RotatingFileSink::new(
+            &path,
+            RotationOptions {
+                max_segment_bytes: Some(1),
+                max_segments: Some(3),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        sink.send(store_info);
+        sink.send(chunk(TimePoint::default()));
+        for frame in 0..10 {
+            sink.send(chunk(TimePoint::from([(
+                Timeline::new_sequence("frame"),
+                frame,
+            )])));
+        }
+        drop(sink);
+
+        let segments = existing_segments(&path);
+        assert_eq!(
+            segments.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![9, 10, 11]
+        );
+
+        for (_, segment_path) in &segments {
+            let msgs = read_segment(segment_path);
+            assert_eq!(
+                msgs.len(),
+                3,
+                "store info + static chunk + one temporal chunk"
+            );
+            assert!(matches!(msgs[0], LogMsg::SetStoreInfo(_)));
+            assert!(is_preamble(&msgs[1]));
+            assert!(!is_preamble(&msgs[2]));
+        }
+
+        // Numbering resumes where it left off.
+        let sink = RotatingFileSink::new(&path, RotationOptions::default()).unwrap();
+        drop(sink);
+        assert_eq!(existing_segments(&path).last().unwrap().0, 12);
+    }
+
+    #[test]
+    fn preamble_keeps_latest_static_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.rrd");
+
+        let store_id = StoreId::from_string(StoreKind::Recording, "test".into());
+        let store_info =
+            || crate::testing::store_info_message(&store_id, re_build_info::CrateVersion::LOCAL);
+
+        let chunk = |entity_path: &str, timepoint: TimePoint| {
+            Chunk::builder(entity_path.into())
+                .with_component_batches(RowId::new(), timepoint, [&[MyPoint::new(1.0, 2.0)] as _])
+                .build()
+                .unwrap()
+        };
+        let to_msg =
+            |chunk: &Chunk| LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap());
+
+        let points_old = chunk("points", TimePoint::default());
+        let points_new = chunk("points", TimePoint::default());
+        let other = chunk("other", TimePoint::default());
+        let temporal = chunk(
+            "points",
+            TimePoint::from([(Timeline::new_sequence("frame"), 0)]),
+        );
+
+        let sink = RotatingFileSink::new(
+            &path,
+            RotationOptions {
+                max_segment_bytes: Some(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        sink.send(store_info());
+        sink.send(to_msg(&points_old));
+        sink.send(to_msg(&points_new));
+        sink.send(to_msg(&other));
+        sink.send(store_info());
+        sink.send(to_msg(&temporal));
+        drop(sink);
+
+        let (_, last_segment) = existing_segments(&path).pop().unwrap();
+        let msgs = read_segment(&last_segment);
+        assert_eq!(
+            msgs.len(),
+            4,
+            "store info + 2 static chunks + temporal chunk"
+        );
+        assert!(matches!(msgs[0], LogMsg::SetStoreInfo(_)));
+
+        let chunk_ids: Vec<_> = msgs[1..]
+            .iter()
+            .map(|msg| {
+                let LogMsg::ArrowMsg(_, arrow_msg) = msg else {
+                    panic!("expected a chunk, got {msg:?}");
+                };
+                Chunk::from_arrow_msg(arrow_msg).unwrap().id()
+            })
+            .collect();
+        assert_eq!(chunk_ids, vec![points_new.id(), other.id(), temporal.id()]);
+    }
+}
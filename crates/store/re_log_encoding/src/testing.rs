@@ -0,0 +1,61 @@
+//! Helpers to write `.rrd` files in tests.
+
+use re_build_info::CrateVersion;
+use re_chunk::{Chunk, RowId};
+use re_log_types::{ApplicationId, LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreSource};
+
+use crate::encoder::{DroppableEncoder, EncodeError};
+use crate::EncodingOptions;
+
+/// The [`SetStoreInfo`] message of a recording logged by the given SDK `version`.
+pub fn store_info_message(store_id: &StoreId, version: CrateVersion) -> LogMsg {
+    LogMsg::SetStoreInfo(SetStoreInfo {
+        row_id: *RowId::new(),
+        info: StoreInfo {
+            application_id: ApplicationId("rerun_example_test".to_owned()),
+            store_id: store_id.clone(),
+            cloned_from: None,
+            store_source: StoreSource::Unknown,
+            store_version: Some(version),
+        },
+    })
+}
+
+/// The messages of a recording made of the given `chunks`, as logged by the given SDK `version`.
+///
+/// That is a [`SetStoreInfo`], followed by one [`LogMsg::ArrowMsg`] per chunk.
+pub fn recording_messages(
+    store_id: &StoreId,
+    version: CrateVersion,
+    chunks: impl IntoIterator<Item = Chunk>,
+) -> Result<Vec<LogMsg>, EncodeError> {
+    std::iter::once(Ok(store_info_message(store_id, version)))
+        .chain(
+            chunks
+                .into_iter()
+                .map(|chunk| Ok(LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?))),
+        )
+        .collect()
+}
+
+/// Encodes `messages` to a new `.rrd` file at `path`, as written by the given SDK `version`.
+pub fn write_rrd_file(
+    path: &std::path::Path,
+    version: CrateVersion,
+    messages: &[LogMsg],
+    footer_index: bool,
+) -> Result<(), EncodeError> {
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = DroppableEncoder::new(version, EncodingOptions::PROTOBUF_COMPRESSED, file)?;
+    if footer_index {
+        encoder = encoder.with_footer_index();
+    }
+
+    for msg in messages {
+        encoder.append(msg)?;
+    }
+    encoder.finish()?;
+    encoder.flush_blocking()?;
+
+    Ok(())
+}
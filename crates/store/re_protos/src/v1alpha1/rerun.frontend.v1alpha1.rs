@@ -22,10 +22,16 @@ impl ::prost::Name for RegisterWithDatasetRequest {
         "/rerun.frontend.v1alpha1.RegisterWithDatasetRequest".into()
     }
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WriteChunksRequest {
     #[prost(message, optional, tag = "1")]
     pub dataset_id: ::core::option::Option<super::super::common::v1alpha1::EntryId>,
+    /// The partition the chunk should be written to.
+    #[prost(message, optional, tag = "2")]
+    pub partition_id: ::core::option::Option<super::super::common::v1alpha1::PartitionId>,
+    /// The chunk to write.
+    #[prost(message, optional, tag = "3")]
+    pub chunk: ::core::option::Option<super::super::common::v1alpha1::RerunChunk>,
 }
 impl ::prost::Name for WriteChunksRequest {
     const NAME: &'static str = "WriteChunksRequest";
@@ -254,6 +254,8 @@ pub enum Compression {
     None = 1,
     /// LZ4 block compression.
     Lz4 = 2,
+    /// Zstandard compression.
+    Zstd = 3,
 }
 impl Compression {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -265,6 +267,7 @@ impl Compression {
             Self::Unspecified => "COMPRESSION_UNSPECIFIED",
             Self::None => "COMPRESSION_NONE",
             Self::Lz4 => "COMPRESSION_LZ4",
+            Self::Zstd => "COMPRESSION_ZSTD",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -273,6 +276,7 @@ impl Compression {
             "COMPRESSION_UNSPECIFIED" => Some(Self::Unspecified),
             "COMPRESSION_NONE" => Some(Self::None),
             "COMPRESSION_LZ4" => Some(Self::Lz4),
+            "COMPRESSION_ZSTD" => Some(Self::Zstd),
             _ => None,
         }
     }
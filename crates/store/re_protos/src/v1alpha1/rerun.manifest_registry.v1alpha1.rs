@@ -99,6 +99,12 @@ impl ::prost::Name for RegisterWithDatasetBlockingResponse {
 pub struct WriteChunksRequest {
     #[prost(message, optional, tag = "1")]
     pub entry: ::core::option::Option<super::super::common::v1alpha1::DatasetHandle>,
+    /// The partition the chunk should be written to.
+    #[prost(message, optional, tag = "2")]
+    pub partition_id: ::core::option::Option<super::super::common::v1alpha1::PartitionId>,
+    /// The chunk to write.
+    #[prost(message, optional, tag = "3")]
+    pub chunk: ::core::option::Option<super::super::common::v1alpha1::RerunChunk>,
 }
 impl ::prost::Name for WriteChunksRequest {
     const NAME: &'static str = "WriteChunksRequest";
@@ -26,8 +26,14 @@ impl ::prost::Name for WriteMessagesResponse {
         "/rerun.sdk_comms.v1alpha1.WriteMessagesResponse".into()
     }
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ReadMessagesRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadMessagesRequest {
+    /// Only the messages matching this filter are sent.
+    ///
+    /// If unset, all messages are sent.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<ReadMessagesFilter>,
+}
 impl ::prost::Name for ReadMessagesRequest {
     const NAME: &'static str = "ReadMessagesRequest";
     const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
@@ -38,6 +44,44 @@ impl ::prost::Name for ReadMessagesRequest {
         "/rerun.sdk_comms.v1alpha1.ReadMessagesRequest".into()
     }
 }
+/// Restricts which messages are sent to a `ReadMessages` subscriber.
+///
+/// This applies both to the buffered history, and to the live messages that follow.
+/// Each empty/unset field means "no restriction".
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadMessagesFilter {
+    /// Only messages from these stores are sent.
+    #[prost(message, repeated, tag = "1")]
+    pub store_ids: ::prost::alloc::vec::Vec<super::super::common::v1alpha1::StoreId>,
+    /// Only messages from stores of these applications are sent.
+    #[prost(message, repeated, tag = "2")]
+    pub application_ids: ::prost::alloc::vec::Vec<super::super::common::v1alpha1::ApplicationId>,
+    /// Include/exclude rules for the entities of recordings, in `EntityPathFilter` syntax, e.g.:
+    /// ```text
+    /// + /world/**
+    /// - /world/debug/**
+    /// ```
+    ///
+    /// Blueprints are not affected.
+    #[prost(string, optional, tag = "3")]
+    pub entity_path_filter: ::core::option::Option<::prost::alloc::string::String>,
+    /// Only these timelines of recordings are sent.
+    ///
+    /// Temporal chunks that don't have any of these timelines are dropped altogether.
+    /// Static chunks and blueprints are not affected.
+    #[prost(message, repeated, tag = "4")]
+    pub timelines: ::prost::alloc::vec::Vec<super::super::common::v1alpha1::Timeline>,
+}
+impl ::prost::Name for ReadMessagesFilter {
+    const NAME: &'static str = "ReadMessagesFilter";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ReadMessagesFilter".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ReadMessagesFilter".into()
+    }
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReadMessagesResponse {
     #[prost(message, optional, tag = "1")]
@@ -1,12 +1,16 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 
 use ahash::HashMap;
 use nohash_hasher::IntSet;
 use parking_lot::RwLock;
 
+use re_byte_size::SizeBytes as _;
 use re_chunk::ChunkId;
 use re_chunk_store::{
     ChunkCompactionReport, ChunkStoreDiff, ChunkStoreEvent, ChunkStoreHandle, ChunkStoreSubscriber,
@@ -260,6 +264,81 @@ impl QueryCache {
         latest_at_per_cache_key.write().clear();
         range_per_cache_key.write().clear();
     }
+
+    /// Drops the least recently used caches until the total size of all caches fits in `max_bytes`.
+    ///
+    /// Sizes are measured the same way as [`crate::CachesStats::total_size_bytes`].
+    ///
+    /// Returns how many bytes were freed.
+    pub fn purge_least_recently_used(&self, max_bytes: u64) -> u64 {
+        re_tracing::profile_function!();
+
+        let mut latest_at_per_cache_key = self.latest_at_per_cache_key.write();
+        let mut range_per_cache_key = self.range_per_cache_key.write();
+
+        // (last_access, is_range, key, size_bytes)
+        let mut caches = itertools::chain!(
+            latest_at_per_cache_key.iter().map(|(key, cache)| {
+                let cache = cache.read();
+                let size_bytes = cache.per_query_time.total_size_bytes();
+                (cache.access.last_access, false, key.clone(), size_bytes)
+            }),
+            range_per_cache_key.iter().map(|(key, cache)| {
+                let cache = cache.read();
+                let size_bytes = cache.chunks.total_size_bytes();
+                (cache.access.last_access, true, key.clone(), size_bytes)
+            }),
+        )
+        .collect::<Vec<_>>();
+
+        let mut total_size_bytes: u64 = caches.iter().map(|(_, _, _, size)| size).sum();
+        if total_size_bytes <= max_bytes {
+            return 0;
+        }
+
+        caches.sort_by_key(|(last_access, _, _, _)| *last_access);
+
+        let mut freed_bytes = 0;
+        for (_, is_range, key, size_bytes) in caches {
+            if total_size_bytes <= max_bytes {
+                break;
+            }
+
+            if is_range {
+                range_per_cache_key.remove(&key);
+            } else {
+                latest_at_per_cache_key.remove(&key);
+            }
+
+            total_size_bytes -= size_bytes;
+            freed_bytes += size_bytes;
+        }
+
+        freed_bytes
+    }
+}
+
+/// How a single cache has been accessed so far.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheAccessStats {
+    /// Logical timestamp of the last access, shared by all caches.
+    ///
+    /// Used to find the least recently used caches, see [`QueryCache::purge_least_recently_used`].
+    pub last_access: u64,
+
+    /// How many lookups could be served from the cache.
+    pub hits: u64,
+
+    /// How many lookups had to go through the store and populate the cache.
+    pub misses: u64,
+}
+
+impl CacheAccessStats {
+    #[inline]
+    pub(crate) fn touch(&mut self) {
+        static ACCESS_CLOCK: AtomicU64 = AtomicU64::new(0);
+        self.last_access = ACCESS_CLOCK.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 impl ChunkStoreSubscriber for QueryCache {
@@ -33,6 +33,28 @@ impl CachesStats {
 
         latest_at_size_bytes + range_size_bytes
     }
+
+    /// Total number of cache hits, across all caches.
+    #[inline]
+    pub fn total_hits(&self) -> u64 {
+        let Self { latest_at, range } = self;
+        latest_at
+            .values()
+            .chain(range.values())
+            .map(|stats| stats.hits)
+            .sum()
+    }
+
+    /// Total number of cache misses, across all caches.
+    #[inline]
+    pub fn total_misses(&self) -> u64 {
+        let Self { latest_at, range } = self;
+        latest_at
+            .values()
+            .chain(range.values())
+            .map(|stats| stats.misses)
+            .sum()
+    }
 }
 
 /// Stats for a single `crate::RangeCache`.
@@ -47,6 +69,14 @@ pub struct CacheStats {
 
     /// What is the actual size of this cache after deduplication?
     pub total_actual_size_bytes: u64,
+
+    /// How many lookups were served from this cache?
+    ///
+    /// Counted per query for latest-at caches, and per chunk for range caches.
+    pub hits: u64,
+
+    /// How many lookups had to go through the store?
+    pub misses: u64,
 }
 
 impl QueryCache {
@@ -72,6 +102,8 @@ impl QueryCache {
                                 .map(|cached| cached.unit.total_size_bytes())
                                 .sum(),
                             total_actual_size_bytes: cache.per_query_time.total_size_bytes(),
+                            hits: cache.access.hits,
+                            misses: cache.access.misses,
                         },
                     )
                 })
@@ -97,6 +129,8 @@ impl QueryCache {
                                 .map(|cached| cached.chunk.total_size_bytes())
                                 .sum(),
                             total_actual_size_bytes: cache.chunks.total_size_bytes(),
+                            hits: cache.access.hits,
+                            misses: cache.access.misses,
                         },
                     )
                 })
@@ -16,7 +16,7 @@ use re_types_core::{
     ComponentDescriptor, ComponentName,
 };
 
-use crate::{QueryCache, QueryCacheKey, QueryError};
+use crate::{CacheAccessStats, QueryCache, QueryCacheKey, QueryError};
 
 // --- Public API ---
 
@@ -75,9 +75,49 @@ impl QueryCache {
         query: &LatestAtQuery,
         entity_path: &EntityPath,
         component_descrs: impl IntoIterator<Item = impl Into<MaybeTagged>>,
+    ) -> LatestAtResults {
+        self.latest_at_impl(query, entity_path, component_descrs, true)
+    }
+
+    /// Same as [`Self::latest_at`], query-time clears included, but reads straight from the store
+    /// and never populates the cache.
+    ///
+    /// Useful for one-shot queries, whose results would never be looked up again.
+    pub fn latest_at_uncached(
+        &self,
+        query: &LatestAtQuery,
+        entity_path: &EntityPath,
+        component_descrs: impl IntoIterator<Item = impl Into<MaybeTagged>>,
+    ) -> LatestAtResults {
+        self.latest_at_impl(query, entity_path, component_descrs, false)
+    }
+
+    fn latest_at_impl(
+        &self,
+        query: &LatestAtQuery,
+        entity_path: &EntityPath,
+        component_descrs: impl IntoIterator<Item = impl Into<MaybeTagged>>,
+        use_cache: bool,
     ) -> LatestAtResults {
         // This is called very frequently, don't put a profile scope here.
 
+        let lookup = |store: &ChunkStore, key: QueryCacheKey, entity_path: &EntityPath| {
+            if !use_cache {
+                return latest_at_uncached(store, query, entity_path, &key.component_descr);
+            }
+
+            let cache = Arc::clone(
+                self.latest_at_per_cache_key
+                    .write()
+                    .entry(key.clone())
+                    .or_insert_with(|| Arc::new(RwLock::new(LatestAtCache::new(key.clone())))),
+            );
+
+            let mut cache = cache.write();
+            cache.handle_pending_invalidation();
+            cache.latest_at(store, query, entity_path, &key.component_descr)
+        };
+
         let store = self.store.read();
 
         let mut results = LatestAtResults::empty(entity_path.clone(), query.clone());
@@ -157,27 +197,17 @@ impl QueryCache {
                     continue;
                 }
 
+                let clear_descr = archetypes::Clear::descriptor_is_recursive();
                 let key = QueryCacheKey::new(
                     clear_entity_path.clone(),
                     query.timeline(),
-                    archetypes::Clear::descriptor_is_recursive(),
-                );
-
-                let cache = Arc::clone(
-                    self.latest_at_per_cache_key
-                        .write()
-                        .entry(key.clone())
-                        .or_insert_with(|| Arc::new(RwLock::new(LatestAtCache::new(key.clone())))),
+                    clear_descr.clone(),
                 );
 
-                let mut cache = cache.write();
-                cache.handle_pending_invalidation();
-                if let Some(cached) =
-                    cache.latest_at(&store, query, &clear_entity_path, &key.component_descr)
-                {
+                if let Some(cached) = lookup(&store, key, &clear_entity_path) {
                     // TODO(andreas): Should clear also work if the component is not fully tagged?
                     let found_recursive_clear = cached
-                        .component_mono::<ClearIsRecursive>(&key.component_descr)
+                        .component_mono::<ClearIsRecursive>(&clear_descr)
                         .and_then(Result::ok)
                         == Some(ClearIsRecursive(true.into()));
                     // When checking the entity itself, any kind of `Clear` component
@@ -206,27 +236,21 @@ impl QueryCache {
         }
 
         for component_descr in component_descrs {
-            let key = QueryCacheKey::new(entity_path.clone(), query.timeline(), component_descr);
-
-            let cache = Arc::clone(
-                self.latest_at_per_cache_key
-                    .write()
-                    .entry(key.clone())
-                    .or_insert_with(|| Arc::new(RwLock::new(LatestAtCache::new(key.clone())))),
+            let key = QueryCacheKey::new(
+                entity_path.clone(),
+                query.timeline(),
+                component_descr.clone(),
             );
 
-            let mut cache = cache.write();
-            cache.handle_pending_invalidation();
-            if let Some(cached) = cache.latest_at(&store, query, entity_path, &key.component_descr)
-            {
+            if let Some(cached) = lookup(&store, key, entity_path) {
                 // 1. A `Clear` component doesn't shadow its own self.
                 // 2. If a `Clear` component was found with an index greater than or equal to the
                 //    component data, then we know for sure that it should shadow it.
                 if let Some(index) = cached.index(&query.timeline()) {
-                    if key.component_descr == archetypes::Clear::descriptor_is_recursive()
+                    if component_descr == archetypes::Clear::descriptor_is_recursive()
                         || compare_indices(index, max_clear_index) == std::cmp::Ordering::Greater
                     {
-                        results.add(key.component_descr, index, cached);
+                        results.add(component_descr, index, cached);
                     }
                 }
             }
@@ -669,6 +693,9 @@ pub struct LatestAtCache {
     /// Invalidation is deferred to query time because it is far more efficient that way: the frame
     /// time effectively behaves as a natural micro-batching mechanism.
     pub pending_invalidations: BTreeSet<TimeInt>,
+
+    /// Hit/miss bookkeeping, at the query level.
+    pub access: CacheAccessStats,
 }
 
 impl LatestAtCache {
@@ -678,6 +705,7 @@ impl LatestAtCache {
             cache_key,
             per_query_time: Default::default(),
             pending_invalidations: Default::default(),
+            access: Default::default(),
         }
     }
 }
@@ -689,6 +717,7 @@ impl std::fmt::Debug for LatestAtCache {
             cache_key: _,
             per_query_time,
             pending_invalidations: _,
+            access: _,
         } = self;
 
         let mut strings = Vec::new();
@@ -741,6 +770,7 @@ impl SizeBytes for LatestAtCache {
             cache_key: _,
             per_query_time,
             pending_invalidations,
+            access: _,
         } = self;
 
         let per_query_time = per_query_time.total_size_bytes();
@@ -768,22 +798,20 @@ impl LatestAtCache {
             cache_key: _,
             per_query_time,
             pending_invalidations: _,
+            access,
         } = self;
 
+        access.touch();
+
         if let Some(cached) = per_query_time.get(&query.at()) {
+            access.hits += 1;
             return Some(cached.unit.clone());
         }
 
-        let ((data_time, _row_id), unit) = store
-            .latest_at_relevant_chunks(query, entity_path, component_descr)
-            .into_iter()
-            .filter_map(|chunk| {
-                chunk
-                    .latest_at(query, component_descr)
-                    .into_unit()
-                    .and_then(|chunk| chunk.index(&query.timeline()).map(|index| (index, chunk)))
-            })
-            .max_by_key(|(index, _chunk)| *index)?;
+        access.misses += 1;
+
+        let unit = latest_at_uncached(store, query, entity_path, component_descr)?;
+        let (data_time, _row_id) = unit.index(&query.timeline())?;
 
         let cached = per_query_time
             .entry(data_time)
@@ -812,6 +840,7 @@ impl LatestAtCache {
             cache_key: _,
             per_query_time,
             pending_invalidations,
+            access: _,
         } = self;
 
         if let Some(oldest_data_time) = pending_invalidations.first() {
@@ -840,3 +869,25 @@ impl LatestAtCache {
         }
     }
 }
+
+/// The latest-at value of a single component, straight from the store.
+///
+/// Query-time clears are not taken into account, see [`QueryCache::latest_at`].
+fn latest_at_uncached(
+    store: &ChunkStore,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+    component_descr: &ComponentDescriptor,
+) -> Option<UnitChunkShared> {
+    store
+        .latest_at_relevant_chunks(query, entity_path, component_descr)
+        .into_iter()
+        .filter_map(|chunk| {
+            chunk
+                .latest_at(query, component_descr)
+                .into_unit()
+                .and_then(|chunk| chunk.index(&query.timeline()).map(|index| (index, chunk)))
+        })
+        .max_by_key(|(index, _chunk)| *index)
+        .map(|(_index, chunk)| chunk)
+}
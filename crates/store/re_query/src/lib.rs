@@ -9,7 +9,7 @@ mod storage_engine;
 pub mod clamped_zip;
 pub mod range_zip;
 
-pub use self::cache::{QueryCache, QueryCacheHandle, QueryCacheKey};
+pub use self::cache::{CacheAccessStats, QueryCache, QueryCacheHandle, QueryCacheKey};
 pub use self::cache_stats::{CacheStats, CachesStats};
 pub use self::clamped_zip::*;
 pub use self::latest_at::LatestAtResults;
@@ -10,7 +10,7 @@ use re_chunk_store::{ChunkStore, RangeQuery, TimeInt};
 use re_log_types::{EntityPath, ResolvedTimeRange};
 use re_types_core::{ComponentDescriptor, ComponentName, DeserializationError};
 
-use crate::{QueryCache, QueryCacheKey};
+use crate::{CacheAccessStats, QueryCache, QueryCacheKey};
 
 // --- Public API ---
 
@@ -161,6 +161,9 @@ pub struct RangeCache {
     /// Invalidation is deferred to query time because it is far more efficient that way: the frame
     /// time effectively behaves as a natural micro-batching mechanism.
     pub pending_invalidations: BTreeSet<ChunkId>,
+
+    /// Hit/miss bookkeeping, at the chunk level.
+    pub access: CacheAccessStats,
 }
 
 impl RangeCache {
@@ -170,6 +173,7 @@ impl RangeCache {
             cache_key,
             chunks: HashMap::default(),
             pending_invalidations: BTreeSet::default(),
+            access: CacheAccessStats::default(),
         }
     }
 
@@ -201,6 +205,7 @@ impl std::fmt::Debug for RangeCache {
             cache_key: _,
             chunks,
             pending_invalidations: _,
+            access: _,
         } = self;
 
         let mut strings: Vec<String> = Vec::new();
@@ -255,6 +260,7 @@ impl SizeBytes for RangeCache {
             cache_key,
             chunks,
             pending_invalidations,
+            access: _,
         } = self;
 
         cache_key.heap_size_bytes()
@@ -284,8 +290,16 @@ impl RangeCache {
         // For all relevant chunks that we find, we process them according to the [`QueryCacheKey`], and
         // cache them.
 
+        self.access.touch();
+
         let raw_chunks = store.range_relevant_chunks(query, entity_path, component_descr);
         for raw_chunk in &raw_chunks {
+            if self.chunks.contains_key(&raw_chunk.id()) {
+                self.access.hits += 1;
+                continue;
+            }
+
+            self.access.misses += 1;
             self.chunks
                 .entry(raw_chunk.id())
                 .or_insert_with(|| RangeCachedChunk {
@@ -327,6 +341,7 @@ impl RangeCache {
             cache_key: _,
             chunks,
             pending_invalidations,
+            access: _,
         } = self;
 
         chunks.retain(|chunk_id, _chunk| !pending_invalidations.contains(chunk_id));
@@ -641,6 +641,59 @@ fn static_invalidation() {
 
 // ---
 
+#[test]
+fn stats_and_least_recently_used_purge() {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let timepoint = [build_frame_nr(123)];
+    for entity_path in ["a", "b"] {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_archetype(
+                RowId::new(),
+                timepoint,
+                &MyPoints::new(vec![MyPoint::new(1.0, 2.0), MyPoint::new(3.0, 4.0)]),
+            )
+            .build()
+            .unwrap();
+        insert_and_react(&mut store.write(), &mut caches, &Arc::new(chunk));
+    }
+
+    let query = LatestAtQuery::new(*timepoint[0].0.name(), timepoint[0].1);
+    let descriptors = [MyPoints::descriptor_points()];
+    caches.latest_at(&query, &"a".into(), &descriptors);
+    caches.latest_at(&query, &"a".into(), &descriptors);
+    caches.latest_at(&query, &"b".into(), &descriptors);
+
+    let stats = caches.stats();
+    assert_eq!(1, stats.total_hits());
+    assert_eq!(2, stats.total_misses());
+
+    let total_size_bytes = stats.total_size_bytes();
+    assert!(total_size_bytes > 0);
+
+    // Already within budget: nothing to do.
+    assert_eq!(0, caches.purge_least_recently_used(total_size_bytes));
+
+    // `a` was accessed least recently, so it goes first.
+    let freed = caches.purge_least_recently_used(total_size_bytes - 1);
+    assert!(freed > 0);
+
+    let stats = caches.stats();
+    assert_eq!(total_size_bytes - freed, stats.total_size_bytes());
+    assert_eq!(
+        vec![EntityPath::from("b")],
+        stats
+            .latest_at
+            .keys()
+            .map(|key| key.entity_path.clone())
+            .collect::<Vec<_>>()
+    );
+}
+
 fn insert_and_react(store: &mut ChunkStore, caches: &mut QueryCache, chunk: &Arc<Chunk>) {
     caches.on_events(&store.insert_chunk(chunk).unwrap());
 }
@@ -0,0 +1,1084 @@
+//! The gRPC services, implemented on top of the on-disk [`Catalog`].
+
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow::array::RecordBatch;
+use parking_lot::Mutex;
+use tokio_stream::{Stream, StreamExt as _};
+use tonic::{Request, Response, Status};
+
+use re_chunk::{Chunk, ChunkId};
+use re_log_encoding::codec::wire::{decoder::Decode as _, encoder::Encode as _};
+use re_log_types::{EntityPath, EntryId};
+use re_protos::catalog::v1alpha1::{
+    catalog_service_server::CatalogService,
+    ext::{DatasetEntry, EntryDetails, ProviderDetails as _, SystemTable, TableEntry},
+    CreateDatasetEntryRequest, CreateDatasetEntryResponse, DeleteEntryRequest, DeleteEntryResponse,
+    EntryKind, FindEntriesRequest, FindEntriesResponse, ReadDatasetEntryRequest,
+    ReadDatasetEntryResponse, ReadTableEntryRequest, ReadTableEntryResponse, SystemTableKind,
+};
+use re_protos::common::v1alpha1::{
+    ext::{DatasetHandle, IfDuplicateBehavior, PartitionId, ScanParameters},
+    DataframePart, RerunChunk,
+};
+use re_protos::frontend::v1alpha1::{
+    frontend_service_server::FrontendService, CreateIndexRequest, GetChunksRequest,
+    GetDatasetSchemaRequest, GetPartitionTableSchemaRequest, GetTableSchemaRequest,
+    GetTableSchemaResponse, QueryDatasetRequest, ReIndexRequest, RegisterWithDatasetRequest,
+    ScanPartitionTableRequest, ScanTableRequest, ScanTableResponse, SearchDatasetRequest,
+    WriteChunksRequest,
+};
+use re_protos::manifest_registry::v1alpha1::{
+    self as manifest_registry,
+    ext::{DataSource, Query},
+    manifest_registry_service_server::ManifestRegistryService,
+    CreateIndexResponse, GetChunksResponse, GetDatasetSchemaResponse,
+    GetPartitionTableSchemaResponse, QueryDatasetResponse, ReIndexResponse,
+    RegisterWithDatasetResponse, ScanPartitionTableResponse, SearchDatasetResponse,
+    WriteChunksResponse,
+};
+use re_protos::redap_tasks::v1alpha1::{
+    FetchTaskOutputRequest, FetchTaskOutputResponse, QueryTasksOnCompletionRequest,
+    QueryTasksOnCompletionResponse, QueryTasksRequest, QueryTasksResponse,
+};
+
+use crate::query::{self, ChunkSelection};
+use crate::store::{Catalog, Dataset, Partition, StoreCache, ENTRIES_TABLE_NAME};
+use crate::tables::{self, TaskStatus};
+
+/// How many responses can be produced ahead of the client consuming them.
+const RESPONSE_STREAM_CAPACITY: usize = 16;
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = tonic::Result<T>> + Send>>;
+
+fn response_stream<T: Send + 'static>(items: Vec<tonic::Result<T>>) -> ResponseStream<T> {
+    Box::pin(tokio_stream::iter(items))
+}
+
+/// Sends the responses of a [`spawn_response_stream`].
+struct ResponseSender<T>(tokio::sync::mpsc::Sender<tonic::Result<T>>);
+
+impl<T> ResponseSender<T> {
+    /// Blocks until the client is ready for more, and fails if it went away.
+    fn send(&self, response: T) -> tonic::Result<()> {
+        self.0
+            .blocking_send(Ok(response))
+            .map_err(|_err| Status::cancelled("the client disconnected"))
+    }
+}
+
+/// Runs `f` on a blocking thread, streaming the responses it sends as they are produced.
+///
+/// If `f` fails, its error ends the stream.
+fn spawn_response_stream<T: Send + 'static>(
+    f: impl FnOnce(&ResponseSender<T>) -> tonic::Result<()> + Send + 'static,
+) -> ResponseStream<T> {
+    let (tx, rx) = tokio::sync::mpsc::channel(RESPONSE_STREAM_CAPACITY);
+
+    tokio::task::spawn_blocking(move || {
+        let tx = ResponseSender(tx);
+        if let Err(err) = f(&tx) {
+            tx.0.blocking_send(Err(err)).ok();
+        }
+    });
+
+    Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx))
+}
+
+fn internal(err: impl std::fmt::Display) -> Status {
+    Status::internal(err.to_string())
+}
+
+fn missing_field(field: &str) -> Status {
+    Status::invalid_argument(format!("missing field: {field}"))
+}
+
+fn entry_id(
+    id: Option<re_protos::common::v1alpha1::EntryId>,
+    field: &str,
+) -> tonic::Result<EntryId> {
+    Ok(id.ok_or_else(|| missing_field(field))?.try_into()?)
+}
+
+fn encode_dataframe(batch: &RecordBatch) -> tonic::Result<DataframePart> {
+    batch.encode().map_err(internal)
+}
+
+fn encode_schema(
+    schema: &arrow::datatypes::Schema,
+) -> tonic::Result<re_protos::common::v1alpha1::Schema> {
+    schema.try_into().map_err(internal)
+}
+
+fn scan_parameters(
+    scan_parameters: Option<re_protos::common::v1alpha1::ScanParameters>,
+) -> tonic::Result<Option<ScanParameters>> {
+    scan_parameters.map(TryInto::try_into).transpose()
+}
+
+fn dataset_details(dataset: &Dataset) -> EntryDetails {
+    EntryDetails {
+        id: dataset.id,
+        name: dataset.name.clone(),
+        kind: EntryKind::Dataset,
+        created_at: dataset.created_at,
+        updated_at: dataset.updated_at(),
+    }
+}
+
+fn dataset_entry(dataset: &Dataset) -> DatasetEntry {
+    DatasetEntry {
+        details: dataset_details(dataset),
+        handle: DatasetHandle {
+            id: Some(dataset.id),
+            url: dataset.url.clone(),
+        },
+    }
+}
+
+/// Encodes a chunk the way `GetChunks` clients expect it, i.e. with the id of the partition
+/// it belongs to in the schema metadata.
+fn encode_chunk(chunk: &Chunk, partition_id: &str) -> tonic::Result<GetChunksResponse> {
+    let batch = chunk.to_record_batch().map_err(internal)?;
+
+    let mut metadata = batch.schema().metadata().clone();
+    metadata.insert("rerun.partition_id".to_owned(), partition_id.to_owned());
+    let schema = Arc::new(batch.schema().as_ref().clone().with_metadata(metadata));
+    let batch = batch.with_schema(schema).map_err(internal)?;
+
+    let chunk: RerunChunk = batch.encode().map_err(internal)?;
+
+    Ok(GetChunksResponse { chunk: Some(chunk) })
+}
+
+impl ChunkSelection {
+    fn from_request(
+        partition_ids: Vec<re_protos::common::v1alpha1::PartitionId>,
+        chunk_ids: Vec<re_protos::common::v1alpha1::Tuid>,
+        entity_paths: Vec<re_protos::common::v1alpha1::EntityPath>,
+        query: Option<re_protos::manifest_registry::v1alpha1::Query>,
+    ) -> tonic::Result<Self> {
+        Ok(Self {
+            partition_ids: partition_ids
+                .into_iter()
+                .map(|id| PartitionId::try_from(id).map(|id| id.id))
+                .collect::<Result<_, _>>()?,
+            chunk_ids: chunk_ids
+                .into_iter()
+                .map(|id| re_tuid::Tuid::try_from(id).map(ChunkId::from_tuid))
+                .collect::<Result<_, _>>()?,
+            entity_paths: entity_paths
+                .into_iter()
+                .map(EntityPath::try_from)
+                .collect::<Result<_, _>>()?,
+            query: query.map(Query::try_from).transpose()?,
+        })
+    }
+}
+
+// ---
+
+/// Implements the `FrontendService` and `CatalogService` gRPC APIs for a local [`Catalog`].
+///
+/// All tasks (e.g. registering partitions) run to completion before the request that
+/// created them returns.
+#[derive(Clone)]
+pub struct LocalFrontend {
+    catalog: Arc<Mutex<Catalog>>,
+
+    /// The outcome of every task created since the server started, by task id.
+    tasks: Arc<Mutex<BTreeMap<String, TaskStatus>>>,
+
+    /// The `__entries` system table.
+    entries_table: EntryDetails,
+}
+
+impl LocalFrontend {
+    pub fn new(catalog: Catalog) -> Self {
+        let now = jiff::Timestamp::now();
+
+        Self {
+            catalog: Arc::new(Mutex::new(catalog)),
+            tasks: Default::default(),
+            entries_table: EntryDetails {
+                id: EntryId::new(),
+                name: ENTRIES_TABLE_NAME.to_owned(),
+                kind: EntryKind::Table,
+                created_at: now,
+                updated_at: now,
+            },
+        }
+    }
+
+    /// Runs `f` on a blocking thread: most catalog operations hit the filesystem.
+    async fn with_catalog<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut Catalog) -> tonic::Result<R> + Send + 'static,
+    ) -> tonic::Result<R> {
+        let catalog = self.catalog.clone();
+        tokio::task::spawn_blocking(move || f(&mut catalog.lock()))
+            .await
+            .map_err(internal)?
+    }
+
+    /// The partitions of a dataset, along with the cache to load them from.
+    ///
+    /// Loading partitions can take a while, and doesn't require locking the catalog.
+    async fn partitions(
+        &self,
+        dataset_id: EntryId,
+    ) -> tonic::Result<(Vec<Partition>, Arc<StoreCache>)> {
+        self.with_catalog(move |catalog| {
+            Ok((
+                catalog.dataset(dataset_id)?.partitions()?,
+                catalog.stores().clone(),
+            ))
+        })
+        .await
+    }
+
+    /// `WriteChunks`, for both the frontend and the manifest registry services.
+    async fn write_chunk_requests(
+        &self,
+        mut requests: impl Stream<Item = tonic::Result<WriteChunksRequest>> + Unpin,
+    ) -> tonic::Result<()> {
+        let mut chunks_per_partition: BTreeMap<(EntryId, String), Vec<Chunk>> = BTreeMap::new();
+        while let Some(request) = requests.next().await {
+            let request = request?;
+            let dataset_id = entry_id(request.dataset_id, "dataset_id")?;
+            let partition_id = PartitionId::try_from(
+                request
+                    .partition_id
+                    .ok_or_else(|| missing_field("partition_id"))?,
+            )?
+            .id;
+            let batch = request
+                .chunk
+                .ok_or_else(|| missing_field("chunk"))?
+                .decode()
+                .map_err(|err| Status::invalid_argument(err.to_string()))?;
+            let chunk = Chunk::from_record_batch(&batch)
+                .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+            chunks_per_partition
+                .entry((dataset_id, partition_id))
+                .or_default()
+                .push(chunk);
+        }
+
+        self.with_catalog(move |catalog| {
+            for ((dataset_id, partition_id), chunks) in chunks_per_partition {
+                catalog.write_chunks(dataset_id, &partition_id, &chunks)?;
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    fn entries(
+        catalog: &mut Catalog,
+        entries_table: &EntryDetails,
+    ) -> tonic::Result<Vec<EntryDetails>> {
+        catalog.refresh()?;
+        Ok(catalog
+            .datasets()
+            .map(dataset_details)
+            .chain(std::iter::once(entries_table.clone()))
+            .collect())
+    }
+
+    fn check_entries_table(&self, table_id: EntryId) -> tonic::Result<()> {
+        if table_id == self.entries_table.id {
+            Ok(())
+        } else {
+            Err(Status::not_found(format!("No table with id {table_id}")))
+        }
+    }
+
+    fn tasks_dataframe(
+        &self,
+        ids: &[re_protos::common::v1alpha1::TaskId],
+    ) -> tonic::Result<DataframePart> {
+        let tasks = self.tasks.lock();
+
+        let statuses = if ids.is_empty() {
+            tasks.values().cloned().collect::<Vec<_>>()
+        } else {
+            ids.iter()
+                .map(|id| {
+                    tasks
+                        .get(&id.id)
+                        .cloned()
+                        .ok_or_else(|| Status::not_found(format!("No task with id {:?}", id.id)))
+                })
+                .collect::<tonic::Result<Vec<_>>>()?
+        };
+
+        encode_dataframe(&tables::tasks_table(&statuses).map_err(internal)?)
+    }
+}
+
+#[tonic::async_trait]
+impl FrontendService for LocalFrontend {
+    // --- Catalog ---
+
+    async fn find_entries(
+        &self,
+        request: Request<FindEntriesRequest>,
+    ) -> tonic::Result<Response<FindEntriesResponse>> {
+        let filter = request.into_inner().filter.unwrap_or_default();
+        let id: Option<EntryId> = filter.id.map(TryInto::try_into).transpose()?;
+        let kind = filter
+            .entry_kind
+            .map(EntryKind::try_from)
+            .transpose()
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        let entries_table = self.entries_table.clone();
+        let mut entries = self
+            .with_catalog(move |catalog| Self::entries(catalog, &entries_table))
+            .await?;
+
+        entries.retain(|entry| {
+            id.is_none_or(|id| entry.id == id)
+                && filter.name.as_ref().is_none_or(|name| &entry.name == name)
+                && kind.is_none_or(|kind| entry.kind == kind)
+        });
+
+        Ok(Response::new(FindEntriesResponse {
+            entries: entries.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn delete_entry(
+        &self,
+        request: Request<DeleteEntryRequest>,
+    ) -> tonic::Result<Response<DeleteEntryResponse>> {
+        let id = entry_id(request.into_inner().id, "id")?;
+        if id == self.entries_table.id {
+            return Err(Status::failed_precondition(
+                "system tables cannot be deleted",
+            ));
+        }
+
+        self.with_catalog(move |catalog| Ok(catalog.delete_dataset(id)?))
+            .await?;
+
+        Ok(Response::new(DeleteEntryResponse {}))
+    }
+
+    async fn create_dataset_entry(
+        &self,
+        request: Request<CreateDatasetEntryRequest>,
+    ) -> tonic::Result<Response<CreateDatasetEntryResponse>> {
+        let name = request
+            .into_inner()
+            .name
+            .ok_or_else(|| missing_field("name"))?;
+
+        let dataset = self
+            .with_catalog(move |catalog| {
+                catalog.refresh()?;
+                Ok(catalog.create_dataset(&name)?)
+            })
+            .await?;
+
+        Ok(Response::new(CreateDatasetEntryResponse {
+            dataset: Some(dataset_entry(&dataset).into()),
+        }))
+    }
+
+    async fn read_dataset_entry(
+        &self,
+        request: Request<ReadDatasetEntryRequest>,
+    ) -> tonic::Result<Response<ReadDatasetEntryResponse>> {
+        let id = entry_id(request.into_inner().id, "id")?;
+
+        let dataset = self
+            .with_catalog(move |catalog| {
+                catalog.refresh()?;
+                Ok(catalog.dataset(id)?.clone())
+            })
+            .await?;
+
+        Ok(Response::new(ReadDatasetEntryResponse {
+            dataset: Some(dataset_entry(&dataset).into()),
+        }))
+    }
+
+    async fn read_table_entry(
+        &self,
+        request: Request<ReadTableEntryRequest>,
+    ) -> tonic::Result<Response<ReadTableEntryResponse>> {
+        let id = entry_id(request.into_inner().id, "id")?;
+        self.check_entries_table(id)?;
+
+        let table = TableEntry {
+            details: self.entries_table.clone(),
+            schema: tables::entries_table_schema(),
+            provider_details: SystemTable {
+                kind: SystemTableKind::Entries,
+            }
+            .try_as_any()?,
+        };
+
+        Ok(Response::new(ReadTableEntryResponse {
+            table: Some(table.try_into()?),
+        }))
+    }
+
+    // --- Manifest registry ---
+
+    async fn register_with_dataset(
+        &self,
+        request: Request<RegisterWithDatasetRequest>,
+    ) -> tonic::Result<Response<RegisterWithDatasetResponse>> {
+        let request = request.into_inner();
+        let dataset_id = entry_id(request.dataset_id, "dataset_id")?;
+        let on_duplicate: IfDuplicateBehavior =
+            re_protos::common::v1alpha1::IfDuplicateBehavior::try_from(request.on_duplicate)
+                .map_err(|err| Status::invalid_argument(err.to_string()))?
+                .into();
+        let data_sources = request
+            .data_sources
+            .into_iter()
+            .map(DataSource::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let results = self
+            .with_catalog(move |catalog| {
+                catalog.dataset(dataset_id)?;
+
+                Ok(data_sources
+                    .into_iter()
+                    .map(|data_source| {
+                        let result =
+                            catalog.register(dataset_id, &data_source.storage_url, on_duplicate);
+                        (data_source, result)
+                    })
+                    .collect::<Vec<_>>())
+            })
+            .await?;
+
+        let mut partition_ids = Vec::with_capacity(results.len());
+        let mut partition_types = Vec::with_capacity(results.len());
+        let mut storage_urls = Vec::with_capacity(results.len());
+        let mut task_ids = Vec::with_capacity(results.len());
+
+        let mut tasks = self.tasks.lock();
+        for (data_source, result) in results {
+            let task_id = re_tuid::Tuid::new().to_string();
+
+            let (partition_id, storage_url, error) = match result {
+                Ok(partition) => (partition.id, partition.url, None),
+                Err(err) => {
+                    re_log::warn!("Failed to register {}: {err}", data_source.storage_url);
+                    let partition_id =
+                        Catalog::partition_id_for_url(&data_source.storage_url).unwrap_or_default();
+                    (partition_id, data_source.storage_url, Some(err.to_string()))
+                }
+            };
+
+            partition_ids.push(partition_id);
+            partition_types.push("rrd".to_owned());
+            storage_urls.push(storage_url.to_string());
+            task_ids.push(task_id.clone());
+            tasks.insert(task_id.clone(), TaskStatus { id: task_id, error });
+        }
+        drop(tasks);
+
+        let batch = RegisterWithDatasetResponse::create_dataframe(
+            partition_ids,
+            partition_types,
+            storage_urls,
+            task_ids,
+        )
+        .map_err(internal)?;
+
+        Ok(Response::new(RegisterWithDatasetResponse {
+            data: Some(encode_dataframe(&batch)?),
+        }))
+    }
+
+    type WriteChunksStream = ResponseStream<WriteChunksResponse>;
+
+    /// Appends the chunks to their partitions, creating them as needed.
+    ///
+    /// Nothing is written until the whole request stream has been received and decoded.
+    async fn write_chunks(
+        &self,
+        request: Request<tonic::Streaming<WriteChunksRequest>>,
+    ) -> tonic::Result<Response<Self::WriteChunksStream>> {
+        self.write_chunk_requests(request.into_inner()).await?;
+        Ok(Response::new(response_stream(vec![])))
+    }
+
+    async fn get_partition_table_schema(
+        &self,
+        request: Request<GetPartitionTableSchemaRequest>,
+    ) -> tonic::Result<Response<GetPartitionTableSchemaResponse>> {
+        let dataset_id = entry_id(request.into_inner().dataset_id, "dataset_id")?;
+        self.with_catalog(move |catalog| Ok(catalog.dataset(dataset_id).map(|_| ())?))
+            .await?;
+
+        Ok(Response::new(GetPartitionTableSchemaResponse {
+            schema: Some(encode_schema(&tables::partition_table_schema())?),
+        }))
+    }
+
+    type ScanPartitionTableStream = ResponseStream<ScanPartitionTableResponse>;
+
+    async fn scan_partition_table(
+        &self,
+        request: Request<ScanPartitionTableRequest>,
+    ) -> tonic::Result<Response<Self::ScanPartitionTableStream>> {
+        let request = request.into_inner();
+        let dataset_id = entry_id(request.dataset_id, "dataset_id")?;
+        let scan_parameters = scan_parameters(request.scan_parameters)?;
+
+        let partitions = self
+            .with_catalog(move |catalog| Ok(catalog.dataset(dataset_id)?.partitions()?))
+            .await?;
+
+        let batch = tables::partition_table(&partitions).map_err(internal)?;
+        let batch = tables::apply_scan_parameters(batch, scan_parameters)?;
+
+        Ok(Response::new(response_stream(vec![Ok(
+            ScanPartitionTableResponse {
+                data: Some(encode_dataframe(&batch)?),
+            },
+        )])))
+    }
+
+    async fn get_dataset_schema(
+        &self,
+        request: Request<GetDatasetSchemaRequest>,
+    ) -> tonic::Result<Response<GetDatasetSchemaResponse>> {
+        let dataset_id = entry_id(request.into_inner().dataset_id, "dataset_id")?;
+
+        let (partitions, stores) = self.partitions(dataset_id).await?;
+        let schema = tokio::task::spawn_blocking(move || {
+            // Only keep one partition at a time alive, so that they can be evicted from the
+            // cache as we go.
+            let store_schemas = partitions
+                .iter()
+                .map(|partition| Ok(stores.load(partition)?.schema()))
+                .collect::<tonic::Result<Vec<_>>>()?;
+            Ok::<_, tonic::Status>(query::dataset_schema(store_schemas))
+        })
+        .await
+        .map_err(internal)??;
+
+        Ok(Response::new(GetDatasetSchemaResponse {
+            schema: Some(encode_schema(&schema)?),
+        }))
+    }
+
+    /// Always fails with [`Status::unimplemented`]: the local server has no query engine to
+    /// build indexes with.
+    async fn create_index(
+        &self,
+        _request: Request<CreateIndexRequest>,
+    ) -> tonic::Result<Response<CreateIndexResponse>> {
+        Err(Status::unimplemented(
+            "indexes are not supported by the local server",
+        ))
+    }
+
+    /// Always fails with [`Status::unimplemented`], see [`Self::create_index`].
+    async fn re_index(
+        &self,
+        _request: Request<ReIndexRequest>,
+    ) -> tonic::Result<Response<ReIndexResponse>> {
+        Err(Status::unimplemented(
+            "indexes are not supported by the local server",
+        ))
+    }
+
+    type SearchDatasetStream = ResponseStream<SearchDatasetResponse>;
+
+    /// Always fails with [`Status::unimplemented`]: searching requires an index, see
+    /// [`Self::create_index`].
+    async fn search_dataset(
+        &self,
+        _request: Request<SearchDatasetRequest>,
+    ) -> tonic::Result<Response<Self::SearchDatasetStream>> {
+        Err(Status::unimplemented(
+            "searching datasets is not supported by the local server",
+        ))
+    }
+
+    type QueryDatasetStream = ResponseStream<QueryDatasetResponse>;
+
+    async fn query_dataset(
+        &self,
+        request: Request<QueryDatasetRequest>,
+    ) -> tonic::Result<Response<Self::QueryDatasetStream>> {
+        let request = request.into_inner();
+        let dataset_id = entry_id(request.dataset_id, "dataset_id")?;
+        let scan_parameters = scan_parameters(request.scan_parameters)?;
+        let selection = ChunkSelection::from_request(
+            request.partition_ids,
+            request.chunk_ids,
+            request.entity_paths,
+            request.query,
+        )?;
+
+        let mut scan = tables::Scan::new(scan_parameters)?;
+        let (partitions, stores) = self.partitions(dataset_id).await?;
+
+        // One response per partition, so that only one partition at a time is kept in memory.
+        let stream = spawn_response_stream(move |tx| {
+            let mut is_empty = true;
+
+            for partition in partitions {
+                if scan.is_done() {
+                    break;
+                }
+                if !selection.includes_partition(&partition.id) {
+                    continue;
+                }
+
+                let store = stores.load(&partition)?;
+                let chunks = selection
+                    .select(&store)
+                    .into_iter()
+                    .map(|chunk| (partition.id.clone(), chunk))
+                    .collect::<Vec<_>>();
+                drop(store);
+
+                let batch = scan.apply(query::query_dataset_batch(&chunks).map_err(internal)?)?;
+                if batch.num_rows() > 0 {
+                    tx.send(QueryDatasetResponse {
+                        data: Some(encode_dataframe(&batch)?),
+                    })?;
+                    is_empty = false;
+                }
+            }
+
+            // Always return the schema of the results, even if there are none.
+            if is_empty {
+                let batch = RecordBatch::new_empty(Arc::new(query::query_dataset_schema()));
+                tx.send(QueryDatasetResponse {
+                    data: Some(encode_dataframe(&batch)?),
+                })?;
+            }
+
+            Ok(())
+        });
+
+        Ok(Response::new(stream))
+    }
+
+    type GetChunksStream = ResponseStream<GetChunksResponse>;
+
+    async fn get_chunks(
+        &self,
+        request: Request<GetChunksRequest>,
+    ) -> tonic::Result<Response<Self::GetChunksStream>> {
+        let request = request.into_inner();
+        let dataset_id = entry_id(request.dataset_id, "dataset_id")?;
+        let selection = ChunkSelection::from_request(
+            request.partition_ids,
+            request.chunk_ids,
+            request.entity_paths,
+            request.query,
+        )?;
+
+        let (partitions, stores) = self.partitions(dataset_id).await?;
+
+        let stream = spawn_response_stream(move |tx| {
+            for partition in partitions {
+                if !selection.includes_partition(&partition.id) {
+                    continue;
+                }
+
+                let store = stores.load(&partition)?;
+                for chunk in selection.select(&store) {
+                    tx.send(encode_chunk(&chunk, &partition.id)?)?;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(Response::new(stream))
+    }
+
+    // --- Tables ---
+
+    async fn get_table_schema(
+        &self,
+        request: Request<GetTableSchemaRequest>,
+    ) -> tonic::Result<Response<GetTableSchemaResponse>> {
+        let table_id = entry_id(request.into_inner().table_id, "table_id")?;
+        self.check_entries_table(table_id)?;
+
+        Ok(Response::new(GetTableSchemaResponse {
+            schema: Some(encode_schema(&tables::entries_table_schema())?),
+        }))
+    }
+
+    type ScanTableStream = ResponseStream<ScanTableResponse>;
+
+    async fn scan_table(
+        &self,
+        request: Request<ScanTableRequest>,
+    ) -> tonic::Result<Response<Self::ScanTableStream>> {
+        let table_id = entry_id(request.into_inner().table_id, "table_id")?;
+        self.check_entries_table(table_id)?;
+
+        let entries_table = self.entries_table.clone();
+        let entries = self
+            .with_catalog(move |catalog| Self::entries(catalog, &entries_table))
+            .await?;
+        let batch = tables::entries_table(&entries).map_err(internal)?;
+
+        Ok(Response::new(response_stream(vec![Ok(
+            ScanTableResponse {
+                dataframe_part: Some(encode_dataframe(&batch)?),
+            },
+        )])))
+    }
+
+    // --- Tasks ---
+
+    async fn query_tasks(
+        &self,
+        request: Request<QueryTasksRequest>,
+    ) -> tonic::Result<Response<QueryTasksResponse>> {
+        Ok(Response::new(QueryTasksResponse {
+            data: Some(self.tasks_dataframe(&request.into_inner().ids)?),
+        }))
+    }
+
+    async fn fetch_task_output(
+        &self,
+        request: Request<FetchTaskOutputRequest>,
+    ) -> tonic::Result<Response<FetchTaskOutputResponse>> {
+        let id = request.into_inner().id.ok_or_else(|| missing_field("id"))?;
+
+        Ok(Response::new(FetchTaskOutputResponse {
+            data: Some(self.tasks_dataframe(&[id])?),
+        }))
+    }
+
+    type QueryTasksOnCompletionStream = ResponseStream<QueryTasksOnCompletionResponse>;
+
+    async fn query_tasks_on_completion(
+        &self,
+        request: Request<QueryTasksOnCompletionRequest>,
+    ) -> tonic::Result<Response<Self::QueryTasksOnCompletionStream>> {
+        // Tasks are complete as soon as they are created, there is nothing to wait for.
+        let data = self.tasks_dataframe(&request.into_inner().ids)?;
+
+        Ok(Response::new(response_stream(vec![Ok(
+            QueryTasksOnCompletionResponse { data: Some(data) },
+        )])))
+    }
+}
+
+/// The catalog service is a subset of the frontend service.
+#[tonic::async_trait]
+impl CatalogService for LocalFrontend {
+    async fn find_entries(
+        &self,
+        request: Request<FindEntriesRequest>,
+    ) -> tonic::Result<Response<FindEntriesResponse>> {
+        FrontendService::find_entries(self, request).await
+    }
+
+    async fn delete_entry(
+        &self,
+        request: Request<DeleteEntryRequest>,
+    ) -> tonic::Result<Response<DeleteEntryResponse>> {
+        FrontendService::delete_entry(self, request).await
+    }
+
+    async fn create_dataset_entry(
+        &self,
+        request: Request<CreateDatasetEntryRequest>,
+    ) -> tonic::Result<Response<CreateDatasetEntryResponse>> {
+        FrontendService::create_dataset_entry(self, request).await
+    }
+
+    async fn read_dataset_entry(
+        &self,
+        request: Request<ReadDatasetEntryRequest>,
+    ) -> tonic::Result<Response<ReadDatasetEntryResponse>> {
+        FrontendService::read_dataset_entry(self, request).await
+    }
+
+    async fn read_table_entry(
+        &self,
+        request: Request<ReadTableEntryRequest>,
+    ) -> tonic::Result<Response<ReadTableEntryResponse>> {
+        FrontendService::read_table_entry(self, request).await
+    }
+}
+
+/// The manifest registry service is a subset of the frontend service, that identifies datasets
+/// by handle rather than by id.
+///
+/// Partition manifests are computed on the fly from the `.rrd` files, so they cannot be created
+/// ahead of time, and there are no chunk manifests since there are no indexes.
+#[tonic::async_trait]
+impl ManifestRegistryService for LocalFrontend {
+    async fn register_with_dataset(
+        &self,
+        request: Request<manifest_registry::RegisterWithDatasetRequest>,
+    ) -> tonic::Result<Response<RegisterWithDatasetResponse>> {
+        let request = request.into_inner();
+        FrontendService::register_with_dataset(
+            self,
+            Request::new(RegisterWithDatasetRequest {
+                dataset_id: dataset_id(request.entry),
+                data_sources: request.data_sources,
+                on_duplicate: request.on_duplicate,
+            }),
+        )
+        .await
+    }
+
+    /// Registration always completes before responding, see [`Self::register_with_dataset`].
+    async fn register_with_dataset_blocking(
+        &self,
+        request: Request<manifest_registry::RegisterWithDatasetBlockingRequest>,
+    ) -> tonic::Result<Response<manifest_registry::RegisterWithDatasetBlockingResponse>> {
+        let request = request.into_inner();
+        let response = FrontendService::register_with_dataset(
+            self,
+            Request::new(RegisterWithDatasetRequest {
+                dataset_id: dataset_id(request.entry),
+                data_sources: request.data_sources,
+                on_duplicate: request.on_duplicate,
+            }),
+        )
+        .await?;
+
+        Ok(Response::new(
+            manifest_registry::RegisterWithDatasetBlockingResponse {
+                data: response.into_inner().data,
+            },
+        ))
+    }
+
+    type WriteChunksStream = ResponseStream<WriteChunksResponse>;
+
+    async fn write_chunks(
+        &self,
+        request: Request<tonic::Streaming<manifest_registry::WriteChunksRequest>>,
+    ) -> tonic::Result<Response<Self::WriteChunksStream>> {
+        let requests = request.into_inner().map(|request| {
+            request.map(|request| WriteChunksRequest {
+                dataset_id: dataset_id(request.entry),
+                partition_id: request.partition_id,
+                chunk: request.chunk,
+            })
+        });
+
+        self.write_chunk_requests(requests).await?;
+        Ok(Response::new(response_stream(vec![])))
+    }
+
+    async fn get_partition_table_schema(
+        &self,
+        request: Request<manifest_registry::GetPartitionTableSchemaRequest>,
+    ) -> tonic::Result<Response<GetPartitionTableSchemaResponse>> {
+        FrontendService::get_partition_table_schema(
+            self,
+            Request::new(GetPartitionTableSchemaRequest {
+                dataset_id: dataset_id(request.into_inner().entry),
+            }),
+        )
+        .await
+    }
+
+    type ScanPartitionTableStream = ResponseStream<ScanPartitionTableResponse>;
+
+    async fn scan_partition_table(
+        &self,
+        request: Request<manifest_registry::ScanPartitionTableRequest>,
+    ) -> tonic::Result<Response<Self::ScanPartitionTableStream>> {
+        let request = request.into_inner();
+        FrontendService::scan_partition_table(
+            self,
+            Request::new(ScanPartitionTableRequest {
+                dataset_id: dataset_id(request.entry),
+                scan_parameters: request.scan_parameters,
+            }),
+        )
+        .await
+    }
+
+    async fn get_dataset_schema(
+        &self,
+        request: Request<manifest_registry::GetDatasetSchemaRequest>,
+    ) -> tonic::Result<Response<GetDatasetSchemaResponse>> {
+        FrontendService::get_dataset_schema(
+            self,
+            Request::new(GetDatasetSchemaRequest {
+                dataset_id: dataset_id(request.into_inner().entry),
+            }),
+        )
+        .await
+    }
+
+    async fn create_index(
+        &self,
+        request: Request<manifest_registry::CreateIndexRequest>,
+    ) -> tonic::Result<Response<CreateIndexResponse>> {
+        let request = request.into_inner();
+        FrontendService::create_index(
+            self,
+            Request::new(CreateIndexRequest {
+                dataset_id: dataset_id(request.entry),
+                partition_ids: request.partition_ids,
+                config: request.config,
+                on_duplicate: request.on_duplicate,
+            }),
+        )
+        .await
+    }
+
+    async fn re_index(
+        &self,
+        request: Request<manifest_registry::ReIndexRequest>,
+    ) -> tonic::Result<Response<ReIndexResponse>> {
+        FrontendService::re_index(
+            self,
+            Request::new(ReIndexRequest {
+                dataset_id: dataset_id(request.into_inner().entry),
+            }),
+        )
+        .await
+    }
+
+    type SearchDatasetStream = ResponseStream<SearchDatasetResponse>;
+
+    async fn search_dataset(
+        &self,
+        request: Request<manifest_registry::SearchDatasetRequest>,
+    ) -> tonic::Result<Response<Self::SearchDatasetStream>> {
+        let request = request.into_inner();
+        FrontendService::search_dataset(
+            self,
+            Request::new(SearchDatasetRequest {
+                dataset_id: dataset_id(request.entry),
+                column: request.column,
+                query: request.query,
+                properties: request.properties,
+                scan_parameters: request.scan_parameters,
+            }),
+        )
+        .await
+    }
+
+    type QueryDatasetStream = ResponseStream<QueryDatasetResponse>;
+
+    async fn query_dataset(
+        &self,
+        request: Request<manifest_registry::QueryDatasetRequest>,
+    ) -> tonic::Result<Response<Self::QueryDatasetStream>> {
+        let request = request.into_inner();
+        FrontendService::query_dataset(
+            self,
+            Request::new(QueryDatasetRequest {
+                dataset_id: dataset_id(request.entry),
+                partition_ids: request.partition_ids,
+                chunk_ids: request.chunk_ids,
+                entity_paths: request.entity_paths,
+                scan_parameters: request.scan_parameters,
+                query: request.query,
+            }),
+        )
+        .await
+    }
+
+    type GetChunksStream = ResponseStream<GetChunksResponse>;
+
+    async fn get_chunks(
+        &self,
+        request: Request<manifest_registry::GetChunksRequest>,
+    ) -> tonic::Result<Response<Self::GetChunksStream>> {
+        let request = request.into_inner();
+        FrontendService::get_chunks(
+            self,
+            Request::new(GetChunksRequest {
+                dataset_id: dataset_id(request.entry),
+                partition_ids: request.partition_ids,
+                chunk_ids: request.chunk_ids,
+                entity_paths: request.entity_paths,
+                query: request.query,
+            }),
+        )
+        .await
+    }
+
+    type FetchChunkManifestStream = ResponseStream<manifest_registry::FetchChunkManifestResponse>;
+
+    /// Always fails with [`Status::unimplemented`]: chunk manifests are built alongside indexes,
+    /// see [`FrontendService::create_index`].
+    async fn fetch_chunk_manifest(
+        &self,
+        _request: Request<manifest_registry::FetchChunkManifestRequest>,
+    ) -> tonic::Result<Response<Self::FetchChunkManifestStream>> {
+        Err(Status::unimplemented(
+            "indexes are not supported by the local server",
+        ))
+    }
+
+    /// Always fails with [`Status::unimplemented`]: partition manifests are computed when they
+    /// are fetched.
+    async fn create_partition_manifests(
+        &self,
+        _request: Request<manifest_registry::CreatePartitionManifestsRequest>,
+    ) -> tonic::Result<Response<manifest_registry::CreatePartitionManifestsResponse>> {
+        Err(Status::unimplemented(
+            "partition manifests cannot be created ahead of time by the local server",
+        ))
+    }
+
+    type FetchPartitionManifestStream =
+        ResponseStream<manifest_registry::FetchPartitionManifestResponse>;
+
+    /// The `QueryDataset` results of that one partition.
+    async fn fetch_partition_manifest(
+        &self,
+        request: Request<manifest_registry::FetchPartitionManifestRequest>,
+    ) -> tonic::Result<Response<Self::FetchPartitionManifestStream>> {
+        let request = request.into_inner();
+        let partition_id = request.id.ok_or_else(|| missing_field("id"))?;
+
+        let responses = FrontendService::query_dataset(
+            self,
+            Request::new(QueryDatasetRequest {
+                dataset_id: dataset_id(request.entry),
+                partition_ids: vec![partition_id],
+                chunk_ids: vec![],
+                entity_paths: vec![],
+                scan_parameters: request.scan_parameters,
+                query: None,
+            }),
+        )
+        .await?
+        .into_inner();
+
+        Ok(Response::new(Box::pin(responses.map(|response| {
+            response.map(
+                |response| manifest_registry::FetchPartitionManifestResponse {
+                    data: response.data,
+                },
+            )
+        }))))
+    }
+}
+
+/// The manifest registry identifies datasets by handle, the frontend by id.
+fn dataset_id(
+    entry: Option<re_protos::common::v1alpha1::DatasetHandle>,
+) -> Option<re_protos::common::v1alpha1::EntryId> {
+    entry.and_then(|entry| entry.entry_id)
+}
@@ -0,0 +1,408 @@
+//! A local, filesystem-backed implementation of the Rerun Data Platform catalog.
+//!
+//! Every sub-directory of the served root directory is a dataset, and every `.rrd` file within
+//! a dataset directory is one of its partitions (the partition id being the file stem).
+//!
+//! This implements the `FrontendService`, `CatalogService` and `ManifestRegistryService` gRPC
+//! APIs well enough for the Viewer's catalog browser, `re_datafusion` and the Python catalog SDK
+//! to be used without a hosted backend. Features that require a real query engine (custom
+//! indexes, vector and full-text search, Lance filters) are not supported: `CreateIndex`,
+//! `ReIndex`, `SearchDataset` and `FetchChunkManifest` fail with `Unimplemented`. `WriteChunks`
+//! appends the chunks to the `.rrd` file of their partition.
+//!
+//! The `ManifestRegistryService` delegates to the `FrontendService`. Partition manifests are
+//! computed when they are fetched, so `CreatePartitionManifests` fails with `Unimplemented` too.
+
+mod frontend;
+mod query;
+mod store;
+mod tables;
+
+pub use self::frontend::LocalFrontend;
+pub use self::store::{Catalog, Dataset, Error, Partition, StoreCache, ENTRIES_TABLE_NAME};
+
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tower_http::cors::CorsLayer;
+
+use re_grpc_server::shutdown;
+use re_protos::manifest_registry::v1alpha1::manifest_registry_service_server::ManifestRegistryServiceServer;
+
+/// Default port of the local catalog server.
+///
+/// This is the port `rerun+http://localhost/catalog` URIs resolve to.
+pub const DEFAULT_SERVER_PORT: u16 = re_uri::DEFAULT_REDAP_PORT;
+
+const MAX_DECODING_MESSAGE_SIZE: usize = u32::MAX as usize;
+const MAX_ENCODING_MESSAGE_SIZE: usize = MAX_DECODING_MESSAGE_SIZE;
+
+/// Start a local catalog server for the datasets in `root_dir`, listening on `addr`.
+///
+/// The directory is created if it doesn't exist yet.
+/// Besides the files already in `root_dir`, only the `.rrd` files within `import_dirs` can be
+/// registered, see [`Catalog::allow_imports_from`].
+/// At most `max_cached_bytes` worth of partitions are kept in memory, if set.
+///
+/// The server has no authentication, and lets any client delete datasets, which removes them
+/// from disk. Only bind it to non-loopback addresses on trusted networks.
+///
+/// The returned future must be polled for the server to make progress.
+pub async fn serve(
+    addr: SocketAddr,
+    root_dir: impl AsRef<Path>,
+    import_dirs: Vec<PathBuf>,
+    max_cached_bytes: Option<u64>,
+    shutdown: shutdown::Shutdown,
+) -> anyhow::Result<()> {
+    let tcp_listener = TcpListener::bind(addr).await?;
+    serve_from_listener(
+        tcp_listener,
+        root_dir,
+        import_dirs,
+        max_cached_bytes,
+        shutdown,
+    )
+    .await
+}
+
+/// Same as [`serve`], but listening on an existing TCP listener.
+pub async fn serve_from_listener(
+    tcp_listener: TcpListener,
+    root_dir: impl AsRef<Path>,
+    import_dirs: Vec<PathBuf>,
+    max_cached_bytes: Option<u64>,
+    shutdown: shutdown::Shutdown,
+) -> anyhow::Result<()> {
+    let mut catalog = Catalog::open_with_cache(root_dir, StoreCache::new(max_cached_bytes))?;
+    for dir in import_dirs {
+        catalog.allow_imports_from(dir)?;
+    }
+    let root_dir = catalog.root().to_owned();
+    let frontend = LocalFrontend::new(catalog);
+
+    let addr = tcp_listener.local_addr()?;
+    let incoming =
+        TcpIncoming::from_listener(tcp_listener, true, None).map_err(|err| anyhow::anyhow!(err))?;
+
+    let connect_addr = if addr.ip().is_loopback() || addr.ip().is_unspecified() {
+        format!("rerun+http://127.0.0.1:{}/catalog", addr.port())
+    } else {
+        format!("rerun+http://{addr}/catalog")
+    };
+    re_log::info!(
+        "Serving the datasets in {root_dir:?} on {addr}. Connect by running `rerun {connect_addr}`"
+    );
+    if !addr.ip().is_loopback() {
+        re_log::warn!(
+            "The catalog server is reachable from other machines and has no authentication: \
+            anyone on the network can read and delete the datasets in {root_dir:?}"
+        );
+    }
+
+    let cors = CorsLayer::very_permissive();
+    let grpc_web = tonic_web::GrpcWebLayer::new();
+
+    let routes = {
+        let mut routes_builder = tonic::service::Routes::builder();
+        routes_builder.add_service(
+            re_protos::frontend::v1alpha1::frontend_service_server::FrontendServiceServer::new(
+                frontend.clone(),
+            )
+            .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+            .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
+        );
+        routes_builder.add_service(
+            re_protos::catalog::v1alpha1::catalog_service_server::CatalogServiceServer::new(
+                frontend.clone(),
+            )
+            .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+            .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
+        );
+        routes_builder.add_service(
+            ManifestRegistryServiceServer::new(frontend)
+                .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+                .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
+        );
+        routes_builder.routes()
+    };
+
+    Server::builder()
+        .accept_http1(true) // Support `grpc-web` clients
+        .layer(cors) // Allow CORS requests from web clients
+        .layer(grpc_web) // Support `grpc-web` clients
+        .add_routes(routes)
+        .serve_with_incoming_shutdown(incoming, shutdown.wait())
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use tokio_stream::StreamExt as _;
+
+    use re_build_info::CrateVersion;
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{build_frame_nr, example_components::MyPoint, StoreId, StoreKind};
+    use re_protos::catalog::v1alpha1::{ext::DatasetEntry, CreateDatasetEntryRequest};
+    use re_protos::frontend::v1alpha1::{
+        GetChunksRequest, GetDatasetSchemaRequest, QueryDatasetRequest, RegisterWithDatasetRequest,
+    };
+    use re_protos::manifest_registry::v1alpha1::ext::DataSource;
+
+    use super::*;
+
+    /// Writes a recording to `path`, with one chunk per entity.
+    pub(crate) fn write_recording(path: &Path, entity_paths: &[&str]) {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let chunks = entity_paths.iter().map(|entity_path| {
+            Chunk::builder((*entity_path).into())
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(1)],
+                    [&[MyPoint::new(1.0, 2.0)] as _],
+                )
+                .build()
+                .unwrap()
+        });
+
+        let messages =
+            re_log_encoding::testing::recording_messages(&store_id, CrateVersion::LOCAL, chunks)
+                .unwrap();
+        re_log_encoding::testing::write_rrd_file(path, CrateVersion::LOCAL, &messages, false)
+            .unwrap();
+    }
+
+    /// Registers a partition and reads it back through `re_grpc_client`, like the Viewer does.
+    #[tokio::test]
+    async fn end_to_end() {
+        let root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+
+        let rrd_path = elsewhere.path().join("episode.v1.rrd");
+        write_recording(&rrd_path, &["/robot/arm", "/robot/leg", "/camera"]);
+
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = tcp_listener.local_addr().unwrap();
+        let (signal, shutdown) = shutdown::shutdown();
+        let server = tokio::spawn(serve_from_listener(
+            tcp_listener,
+            root.path().to_owned(),
+            vec![elsewhere.path().to_owned()],
+            None,
+            shutdown,
+        ));
+
+        let origin = format!("rerun+http://{addr}").parse().unwrap();
+        let mut client = re_grpc_client::redap::client(origin).await.unwrap();
+
+        let dataset = client
+            .create_dataset_entry(CreateDatasetEntryRequest {
+                name: Some("robots".to_owned()),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .dataset
+            .unwrap();
+        let dataset_id = DatasetEntry::try_from(dataset).unwrap().details.id;
+
+        client
+            .register_with_dataset(RegisterWithDatasetRequest {
+                dataset_id: Some(dataset_id.into()),
+                data_sources: vec![DataSource::new_rrd(
+                    url::Url::from_file_path(&rrd_path).unwrap(),
+                )
+                .unwrap()
+                .into()],
+                on_duplicate: re_protos::common::v1alpha1::IfDuplicateBehavior::Error as i32,
+            })
+            .await
+            .unwrap();
+
+        let schema = client
+            .get_dataset_schema(GetDatasetSchemaRequest {
+                dataset_id: Some(dataset_id.into()),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .schema
+            .unwrap();
+        let schema = arrow::datatypes::Schema::try_from(&schema).unwrap();
+        assert!(schema
+            .fields()
+            .iter()
+            .any(|field| field.name() == "frame_nr"));
+
+        let responses = client
+            .query_dataset(QueryDatasetRequest {
+                dataset_id: Some(dataset_id.into()),
+                partition_ids: vec![],
+                chunk_ids: vec![],
+                entity_paths: vec![],
+                scan_parameters: None,
+                query: None,
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .unwrap();
+        let num_rows: usize = responses
+            .iter()
+            .map(|response| {
+                use re_log_encoding::codec::wire::decoder::Decode as _;
+                response.data.as_ref().unwrap().decode().unwrap().num_rows()
+            })
+            .sum();
+        assert_eq!(num_rows, 3);
+
+        let response = client
+            .get_chunks(GetChunksRequest {
+                dataset_id: Some(dataset_id.into()),
+                partition_ids: vec!["episode.v1".to_owned().into()],
+                chunk_ids: vec![],
+                entity_paths: vec![],
+                query: None,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        let chunks = re_grpc_client::redap::get_chunks_response_to_chunk_and_partition_id(response)
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks
+            .iter()
+            .all(|(_, partition_id)| partition_id.as_deref() == Some("episode.v1")));
+
+        signal.stop();
+        server.await.unwrap().unwrap();
+    }
+
+    /// Registers and reads back a partition through the manifest registry service.
+    #[tokio::test]
+    async fn manifest_registry() {
+        use re_protos::common::v1alpha1::DatasetHandle;
+        use re_protos::manifest_registry::v1alpha1::{
+            manifest_registry_service_client::ManifestRegistryServiceClient,
+            FetchPartitionManifestRequest, GetChunksRequest, RegisterWithDatasetBlockingRequest,
+        };
+
+        let root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+
+        let rrd_path = elsewhere.path().join("episode.rrd");
+        write_recording(&rrd_path, &["/robot/arm", "/camera"]);
+
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = tcp_listener.local_addr().unwrap();
+        let (signal, shutdown) = shutdown::shutdown();
+        let server = tokio::spawn(serve_from_listener(
+            tcp_listener,
+            root.path().to_owned(),
+            vec![elsewhere.path().to_owned()],
+            None,
+            shutdown,
+        ));
+
+        let origin: re_uri::Origin = format!("rerun+http://{addr}").parse().unwrap();
+        let mut frontend = re_grpc_client::redap::client(origin.clone()).await.unwrap();
+        let mut manifest_registry = ManifestRegistryServiceClient::new(
+            re_grpc_client::redap::channel(origin).await.unwrap(),
+        );
+
+        let dataset = frontend
+            .create_dataset_entry(CreateDatasetEntryRequest {
+                name: Some("robots".to_owned()),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .dataset
+            .unwrap();
+        let dataset_id = DatasetEntry::try_from(dataset).unwrap().details.id;
+        let entry = || {
+            Some(DatasetHandle {
+                entry_id: Some(dataset_id.into()),
+                dataset_url: None,
+            })
+        };
+
+        manifest_registry
+            .register_with_dataset_blocking(RegisterWithDatasetBlockingRequest {
+                entry: entry(),
+                data_sources: vec![DataSource::new_rrd(
+                    url::Url::from_file_path(&rrd_path).unwrap(),
+                )
+                .unwrap()
+                .into()],
+                on_duplicate: re_protos::common::v1alpha1::IfDuplicateBehavior::Error as i32,
+            })
+            .await
+            .unwrap();
+
+        let responses = manifest_registry
+            .fetch_partition_manifest(FetchPartitionManifestRequest {
+                entry: entry(),
+                id: Some("episode".to_owned().into()),
+                scan_parameters: None,
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .unwrap();
+        let num_rows: usize = responses
+            .iter()
+            .map(|response| {
+                use re_log_encoding::codec::wire::decoder::Decode as _;
+                response.data.as_ref().unwrap().decode().unwrap().num_rows()
+            })
+            .sum();
+        assert_eq!(num_rows, 2);
+
+        let response = manifest_registry
+            .get_chunks(GetChunksRequest {
+                entry: entry(),
+                partition_ids: vec![],
+                chunk_ids: vec![],
+                entity_paths: vec![],
+                query: None,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        let chunks = re_grpc_client::redap::get_chunks_response_to_chunk_and_partition_id(response)
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 2);
+
+        // Requests must say which dataset they are about.
+        let err = manifest_registry
+            .get_chunks(GetChunksRequest {
+                entry: None,
+                partition_ids: vec![],
+                chunk_ids: vec![],
+                entity_paths: vec![],
+                query: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        signal.stop();
+        server.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,307 @@
+//! Chunk-level queries over the partitions of a dataset.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, RecordBatch, StringArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+
+use re_chunk::{Chunk, ChunkId, EntityPath, LatestAtQuery, RangeQuery, TimeInt, TimelineName};
+use re_chunk_store::ChunkStore;
+use re_protos::manifest_registry::v1alpha1::ext::Query;
+use re_sorbet::{BatchType, SorbetColumnDescriptors};
+
+/// Which chunks of a dataset are relevant to a `QueryDataset` or `GetChunks` request.
+///
+/// Empty lists mean "everything".
+#[derive(Debug, Default, Clone)]
+pub struct ChunkSelection {
+    pub partition_ids: Vec<String>,
+    pub chunk_ids: Vec<ChunkId>,
+    pub entity_paths: Vec<EntityPath>,
+    pub query: Option<Query>,
+}
+
+impl ChunkSelection {
+    pub fn includes_partition(&self, partition_id: &str) -> bool {
+        self.partition_ids.is_empty() || self.partition_ids.iter().any(|id| id == partition_id)
+    }
+
+    /// The chunks of `store` that are relevant to this selection, sorted by chunk id.
+    ///
+    /// Static chunks are always considered relevant to latest-at and range queries.
+    pub fn select(&self, store: &ChunkStore) -> Vec<Arc<Chunk>> {
+        re_tracing::profile_function!();
+
+        let latest_at = self
+            .query
+            .as_ref()
+            .and_then(|query| query.latest_at.as_ref());
+        let range = self.query.as_ref().and_then(|query| query.range.as_ref());
+
+        let mut chunks: BTreeMap<ChunkId, Arc<Chunk>> = BTreeMap::default();
+
+        if latest_at.is_none() && range.is_none() {
+            chunks.extend(
+                store
+                    .iter_chunks()
+                    .filter(|chunk| self.includes_entity(chunk.entity_path()))
+                    .map(|chunk| (chunk.id(), chunk.clone())),
+            );
+        } else {
+            let entity_paths = if self.entity_paths.is_empty() {
+                store.all_entities_sorted().into_iter().collect()
+            } else {
+                self.entity_paths.clone()
+            };
+
+            for entity_path in &entity_paths {
+                if let Some(latest_at) = latest_at {
+                    let query = LatestAtQuery::new(
+                        TimelineName::new(&latest_at.index),
+                        TimeInt::new_temporal(latest_at.at),
+                    );
+                    chunks.extend(
+                        store
+                            .latest_at_relevant_chunks_for_all_components(&query, entity_path, true)
+                            .into_iter()
+                            .filter(|chunk| {
+                                matches_fuzzy_descriptors(chunk, &latest_at.fuzzy_descriptors)
+                            })
+                            .map(|chunk| (chunk.id(), chunk)),
+                    );
+                }
+
+                if let Some(range) = range {
+                    let query = RangeQuery::new(TimelineName::new(&range.index), range.index_range);
+                    chunks.extend(
+                        store
+                            .range_relevant_chunks_for_all_components(&query, entity_path, true)
+                            .into_iter()
+                            .filter(|chunk| {
+                                matches_fuzzy_descriptors(chunk, &range.fuzzy_descriptors)
+                            })
+                            .map(|chunk| (chunk.id(), chunk)),
+                    );
+                }
+            }
+        }
+
+        if !self.chunk_ids.is_empty() {
+            chunks.retain(|chunk_id, _| self.chunk_ids.contains(chunk_id));
+        }
+
+        chunks.into_values().collect()
+    }
+
+    fn includes_entity(&self, entity_path: &EntityPath) -> bool {
+        self.entity_paths.is_empty() || self.entity_paths.contains(entity_path)
+    }
+}
+
+/// Whether any of the chunk's component descriptors contains any of the `fuzzy_descriptors`.
+///
+/// An empty list matches every chunk.
+fn matches_fuzzy_descriptors(chunk: &Chunk, fuzzy_descriptors: &[String]) -> bool {
+    fuzzy_descriptors.is_empty()
+        || chunk.component_descriptors().any(|descr| {
+            let descr = descr.to_string();
+            fuzzy_descriptors
+                .iter()
+                .any(|fuzzy| descr.contains(fuzzy.as_str()))
+        })
+}
+
+// ---
+
+/// The union of the schemas of the given partitions (see [`ChunkStore::schema`]), in dataframe
+/// form.
+///
+/// Columns are listed in the order they are first encountered.
+pub fn dataset_schema(store_schemas: impl IntoIterator<Item = SorbetColumnDescriptors>) -> Schema {
+    let mut schema = SorbetColumnDescriptors {
+        row_id: None,
+        indices: Vec::new(),
+        components: Vec::new(),
+    };
+
+    let mut seen_indices = HashSet::new();
+    let mut seen_components = HashSet::new();
+
+    for store_schema in store_schemas {
+        if schema.row_id.is_none() {
+            schema.row_id = store_schema.row_id;
+        }
+
+        for index in store_schema.indices {
+            if seen_indices.insert(index.clone()) {
+                schema.indices.push(index);
+            }
+        }
+
+        for component in store_schema.components {
+            if seen_components.insert(component.clone()) {
+                schema.components.push(component);
+            }
+        }
+    }
+
+    Schema::new_with_metadata(
+        schema.arrow_fields(BatchType::Dataframe),
+        Default::default(),
+    )
+}
+
+// ---
+
+pub const QUERY_DATASET_PARTITION_ID: &str = "rerun_partition_id";
+pub const QUERY_DATASET_CHUNK_ID: &str = "chunk_id";
+pub const QUERY_DATASET_ENTITY_PATH: &str = "chunk_entity_path";
+pub const QUERY_DATASET_IS_STATIC: &str = "chunk_is_static";
+pub const QUERY_DATASET_NUM_ROWS: &str = "chunk_num_rows";
+
+/// The schema of the results of a `QueryDataset` request: one row per relevant chunk.
+pub fn query_dataset_schema() -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new(QUERY_DATASET_PARTITION_ID, DataType::Utf8, false),
+            Field::new(QUERY_DATASET_CHUNK_ID, DataType::Utf8, false),
+            Field::new(QUERY_DATASET_ENTITY_PATH, DataType::Utf8, false),
+            Field::new(QUERY_DATASET_IS_STATIC, DataType::Boolean, false),
+            Field::new(QUERY_DATASET_NUM_ROWS, DataType::UInt64, false),
+        ],
+        Default::default(),
+    )
+}
+
+/// Describes the given `(partition_id, chunk)` pairs, as per [`query_dataset_schema`].
+pub fn query_dataset_batch(chunks: &[(String, Arc<Chunk>)]) -> arrow::error::Result<RecordBatch> {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            chunks.iter().map(|(partition_id, _)| partition_id.as_str()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            chunks.iter().map(|(_, chunk)| chunk.id().to_string()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            chunks
+                .iter()
+                .map(|(_, chunk)| chunk.entity_path().to_string()),
+        )),
+        Arc::new(BooleanArray::from(
+            chunks
+                .iter()
+                .map(|(_, chunk)| chunk.is_static())
+                .collect::<Vec<_>>(),
+        )),
+        Arc::new(UInt64Array::from_iter_values(
+            chunks.iter().map(|(_, chunk)| chunk.num_rows() as u64),
+        )),
+    ];
+
+    RecordBatch::try_new(Arc::new(query_dataset_schema()), columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::RowId;
+    use re_log_types::{
+        build_frame_nr, example_components::MyPoint, ResolvedTimeRange, StoreId, StoreKind,
+    };
+    use re_protos::manifest_registry::v1alpha1::ext::{QueryLatestAt, QueryRange};
+
+    use super::*;
+
+    fn chunk(entity_path: &str, frames: &[i64]) -> Arc<Chunk> {
+        let points = vec![MyPoint::new(1.0, 1.0)];
+        let mut builder = Chunk::builder(entity_path.into());
+        for &frame in frames {
+            builder = builder.with_component_batches(
+                RowId::new(),
+                [build_frame_nr(frame)],
+                [&points as _],
+            );
+        }
+        Arc::new(builder.build().unwrap())
+    }
+
+    #[test]
+    fn select_chunks() {
+        let mut store = ChunkStore::new(
+            StoreId::from_string(StoreKind::Recording, "test".to_owned()),
+            re_chunk_store::ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let early = chunk("a", &[1, 2]);
+        let late = chunk("a", &[10, 20]);
+        let other = chunk("b", &[1]);
+        for chunk in [&early, &late, &other] {
+            store.insert_chunk(chunk).unwrap();
+        }
+
+        let ids = |chunks: Vec<Arc<Chunk>>| chunks.iter().map(|c| c.id()).collect::<Vec<_>>();
+
+        let everything = ChunkSelection::default();
+        assert_eq!(everything.select(&store).len(), 3);
+
+        let only_a = ChunkSelection {
+            entity_paths: vec!["a".into()],
+            ..Default::default()
+        };
+        assert_eq!(only_a.select(&store).len(), 2);
+
+        let latest_at = ChunkSelection {
+            entity_paths: vec!["a".into()],
+            query: Some(Query {
+                latest_at: Some(QueryLatestAt {
+                    index: "frame_nr".to_owned(),
+                    at: 5,
+                    fuzzy_descriptors: vec![],
+                }),
+                range: None,
+                columns_always_include_everything: false,
+                columns_always_include_chunk_ids: false,
+                columns_always_include_byte_offsets: false,
+                columns_always_include_entity_paths: false,
+                columns_always_include_static_indexes: false,
+                columns_always_include_global_indexes: false,
+                columns_always_include_component_indexes: false,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(ids(latest_at.select(&store)), vec![early.id()]);
+
+        let range = ChunkSelection {
+            query: Some(Query {
+                latest_at: None,
+                range: Some(QueryRange {
+                    index: "frame_nr".to_owned(),
+                    index_range: ResolvedTimeRange::new(15, 30),
+                    fuzzy_descriptors: vec!["Unknown".to_owned()],
+                }),
+                ..latest_at.query.clone().unwrap()
+            }),
+            ..Default::default()
+        };
+        assert!(range.select(&store).is_empty());
+
+        let range = ChunkSelection {
+            query: Some(Query {
+                range: Some(QueryRange {
+                    index: "frame_nr".to_owned(),
+                    index_range: ResolvedTimeRange::new(15, 30),
+                    fuzzy_descriptors: vec!["MyPoint".to_owned()],
+                }),
+                ..range.query.clone().unwrap()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(ids(range.select(&store)), vec![late.id()]);
+
+        let by_id = ChunkSelection {
+            chunk_ids: vec![other.id()],
+            ..Default::default()
+        };
+        assert_eq!(ids(by_id.select(&store)), vec![other.id()]);
+    }
+}
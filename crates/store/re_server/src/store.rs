@@ -0,0 +1,879 @@
+//! The on-disk catalog: datasets are directories, partitions are `.rrd` files.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use parking_lot::Mutex;
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_log_types::{
+    ApplicationId, EntryId, LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreKind, StoreSource,
+};
+use re_protos::common::v1alpha1::ext::IfDuplicateBehavior;
+
+/// Name of the file holding the metadata of a dataset, within the dataset directory.
+const DATASET_MANIFEST_FILENAME: &str = ".rerun_dataset.json";
+
+/// File extension of the partitions of a dataset.
+const PARTITION_EXTENSION: &str = "rrd";
+
+/// Name of the system table listing all the entries of the catalog.
+pub const ENTRIES_TABLE_NAME: &str = "__entries";
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("I/O error on {path:?}: {err}")]
+    Io { path: PathBuf, err: std::io::Error },
+
+    #[error("Invalid dataset manifest {path:?}: {err}")]
+    Manifest {
+        path: PathBuf,
+        err: serde_json::Error,
+    },
+
+    #[error("Failed to load partition {path:?}: {err}")]
+    Load { path: PathBuf, err: anyhow::Error },
+
+    #[error("Failed to write to partition {path:?}: {err}")]
+    Write { path: PathBuf, err: anyhow::Error },
+
+    #[error("No dataset with id {0}")]
+    DatasetNotFound(EntryId),
+
+    #[error("No partition {partition_id:?} in dataset {dataset_id}")]
+    PartitionNotFound {
+        dataset_id: EntryId,
+        partition_id: String,
+    },
+
+    #[error("An entry named {0:?} already exists")]
+    EntryAlreadyExists(String),
+
+    #[error("Partition {0:?} already exists")]
+    PartitionAlreadyExists(String),
+
+    #[error("Invalid entry name {0:?}: names must be non-empty, must not start with '.' or '__', and must not contain path separators")]
+    InvalidEntryName(String),
+
+    #[error("Invalid partition id {0:?}: ids must be non-empty, must not start with '.', and must not contain path separators")]
+    InvalidPartitionId(String),
+
+    #[error(
+        "Unsupported storage URL {0:?}: only local `file://` URLs to .rrd files are supported"
+    )]
+    UnsupportedStorageUrl(String),
+
+    #[error("Cannot turn {0:?} into a URL: it is not an absolute path")]
+    NotAbsolutePath(PathBuf),
+
+    #[error("Cannot register {0:?}: it is neither in the catalog directory nor in one of the allowed import directories")]
+    StoragePathNotAllowed(PathBuf),
+}
+
+impl Error {
+    fn io(path: impl Into<PathBuf>) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.into();
+        move |err| Self::Io { path, err }
+    }
+}
+
+impl From<Error> for tonic::Status {
+    fn from(err: Error) -> Self {
+        match &err {
+            Error::DatasetNotFound(_) | Error::PartitionNotFound { .. } => {
+                Self::not_found(err.to_string())
+            }
+
+            Error::EntryAlreadyExists(_) | Error::PartitionAlreadyExists(_) => {
+                Self::already_exists(err.to_string())
+            }
+
+            Error::InvalidEntryName(_)
+            | Error::InvalidPartitionId(_)
+            | Error::UnsupportedStorageUrl(_) => Self::invalid_argument(err.to_string()),
+
+            Error::StoragePathNotAllowed(_) => Self::permission_denied(err.to_string()),
+
+            Error::Io { .. }
+            | Error::Manifest { .. }
+            | Error::Load { .. }
+            | Error::Write { .. }
+            | Error::NotAbsolutePath(_) => Self::internal(err.to_string()),
+        }
+    }
+}
+
+// ---
+
+/// The metadata of a dataset that cannot be derived from the filesystem, stored as JSON
+/// in [`DATASET_MANIFEST_FILENAME`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DatasetManifest {
+    id: EntryId,
+    created_at_ns: i64,
+}
+
+/// A dataset, i.e. a directory of `.rrd` partitions.
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    pub id: EntryId,
+
+    /// The name of the dataset, which is also the name of its directory.
+    pub name: String,
+
+    pub dir: PathBuf,
+
+    /// The URL of [`Self::dir`].
+    pub url: url::Url,
+
+    pub created_at: jiff::Timestamp,
+}
+
+impl Dataset {
+    /// Opens the dataset in `dir`, writing a new manifest if there is none yet.
+    fn open(dir: PathBuf) -> Result<Self, Error> {
+        let name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let manifest_path = dir.join(DATASET_MANIFEST_FILENAME);
+        let manifest = match std::fs::read(&manifest_path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|err| Error::Manifest {
+                path: manifest_path.clone(),
+                err,
+            })?,
+
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                let manifest = DatasetManifest {
+                    id: EntryId::new(),
+                    created_at_ns: jiff::Timestamp::now().as_nanosecond() as i64,
+                };
+                let bytes =
+                    serde_json::to_vec_pretty(&manifest).map_err(|err| Error::Manifest {
+                        path: manifest_path.clone(),
+                        err,
+                    })?;
+                std::fs::write(&manifest_path, bytes).map_err(Error::io(&manifest_path))?;
+                manifest
+            }
+
+            Err(err) => {
+                return Err(Error::Io {
+                    path: manifest_path,
+                    err,
+                })
+            }
+        };
+
+        let url = url::Url::from_directory_path(&dir)
+            .map_err(|()| Error::NotAbsolutePath(dir.clone()))?;
+
+        Ok(Self {
+            id: manifest.id,
+            name,
+            dir,
+            url,
+            created_at: jiff::Timestamp::from_nanosecond(manifest.created_at_ns.into())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// All the partitions of this dataset, sorted by id.
+    pub fn partitions(&self) -> Result<Vec<Partition>, Error> {
+        let mut partitions = Vec::new();
+
+        for entry in std::fs::read_dir(&self.dir).map_err(Error::io(&self.dir))? {
+            let entry = entry.map_err(Error::io(&self.dir))?;
+            let path = entry.path();
+            if path
+                .extension()
+                .is_some_and(|ext| ext == PARTITION_EXTENSION)
+                && path.is_file()
+            {
+                partitions.push(Partition::open(path)?);
+            }
+        }
+
+        partitions.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(partitions)
+    }
+
+    /// The partition with the given id.
+    pub fn partition(&self, partition_id: &str) -> Result<Partition, Error> {
+        let path = self.partition_path(partition_id)?;
+        if path.is_file() {
+            Partition::open(path)
+        } else {
+            Err(Error::PartitionNotFound {
+                dataset_id: self.id,
+                partition_id: partition_id.to_owned(),
+            })
+        }
+    }
+
+    /// Last time this dataset or any of its partitions was modified.
+    pub fn updated_at(&self) -> jiff::Timestamp {
+        self.partitions()
+            .ok()
+            .and_then(|partitions| partitions.iter().map(|p| p.modified_at).max())
+            .map_or(self.created_at, |modified_at| {
+                modified_at.max(self.created_at)
+            })
+    }
+
+    fn partition_path(&self, partition_id: &str) -> Result<PathBuf, Error> {
+        // Partition ids come from clients, they must not be able to escape the dataset directory.
+        let is_valid_id = !partition_id.is_empty()
+            && !partition_id.starts_with('.')
+            && !partition_id.contains(['/', '\\']);
+        if !is_valid_id {
+            return Err(Error::InvalidPartitionId(partition_id.to_owned()));
+        }
+
+        // NOTE: not `with_extension`, which would replace anything after a dot in the id.
+        Ok(self
+            .dir
+            .join(format!("{partition_id}.{PARTITION_EXTENSION}")))
+    }
+}
+
+/// A single `.rrd` file within a [`Dataset`].
+#[derive(Debug, Clone)]
+pub struct Partition {
+    /// The file stem of the `.rrd` file.
+    pub id: String,
+
+    pub path: PathBuf,
+
+    /// The URL of [`Self::path`].
+    pub url: url::Url,
+
+    pub size_bytes: u64,
+    pub modified_at: jiff::Timestamp,
+}
+
+impl Partition {
+    fn open(path: PathBuf) -> Result<Self, Error> {
+        let metadata = std::fs::metadata(&path).map_err(Error::io(&path))?;
+        let modified_at = metadata
+            .modified()
+            .ok()
+            .and_then(|time| jiff::Timestamp::try_from(time).ok())
+            .unwrap_or_default();
+        let url =
+            url::Url::from_file_path(&path).map_err(|()| Error::NotAbsolutePath(path.clone()))?;
+
+        Ok(Self {
+            id: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            size_bytes: metadata.len(),
+            modified_at,
+            path,
+            url,
+        })
+    }
+}
+
+// ---
+
+struct CachedStore {
+    modified_at: Option<SystemTime>,
+    store: Arc<ChunkStore>,
+    size_bytes: u64,
+
+    /// Value of [`CachedStores::clock`] the last time this store was used.
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CachedStores {
+    stores: HashMap<PathBuf, CachedStore>,
+
+    /// Incremented on every access, to find out which store was least recently used.
+    clock: u64,
+}
+
+/// The partitions that are currently loaded in memory, by path.
+///
+/// Once the loaded partitions take up more than the configured limit, the least recently used
+/// ones are evicted. Partitions are loaded without holding the cache lock, so that loading a
+/// large partition doesn't block requests for the ones that are already loaded.
+pub struct StoreCache {
+    /// `None` means unlimited.
+    max_bytes: Option<u64>,
+
+    stores: Mutex<CachedStores>,
+}
+
+impl StoreCache {
+    /// Memory limit used by [`Catalog::open`].
+    pub const DEFAULT_MAX_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+    /// A cache that keeps at most `max_bytes` worth of partitions in memory, if set.
+    pub fn new(max_bytes: Option<u64>) -> Self {
+        Self {
+            max_bytes,
+            stores: Default::default(),
+        }
+    }
+
+    /// Loads the recording stored in the given partition, or returns it from the cache if
+    /// the file hasn't changed since it was last loaded.
+    ///
+    /// If the file contains several recordings, they are all merged into the same store.
+    pub fn load(&self, partition: &Partition) -> Result<Arc<ChunkStore>, Error> {
+        re_tracing::profile_function!(&partition.id);
+
+        let modified_at = std::fs::metadata(&partition.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        {
+            let mut cached_stores = self.stores.lock();
+            let CachedStores { stores, clock } = &mut *cached_stores;
+            *clock += 1;
+            if let Some(cached) = stores.get_mut(&partition.path) {
+                if cached.modified_at == modified_at {
+                    cached.last_used = *clock;
+                    return Ok(cached.store.clone());
+                }
+            }
+        }
+
+        let store = Arc::new(load_recording(partition)?);
+        let size_bytes = store.stats().total().total_size_bytes;
+
+        let mut cached_stores = self.stores.lock();
+        cached_stores.clock += 1;
+        let last_used = cached_stores.clock;
+        cached_stores.stores.insert(
+            partition.path.clone(),
+            CachedStore {
+                modified_at,
+                store: store.clone(),
+                size_bytes,
+                last_used,
+            },
+        );
+        self.evict(&mut cached_stores.stores, &partition.path);
+
+        Ok(store)
+    }
+
+    /// Evicts the least recently used stores until the cache fits in its memory limit,
+    /// without ever evicting `keep`.
+    fn evict(&self, stores: &mut HashMap<PathBuf, CachedStore>, keep: &Path) {
+        let Some(max_bytes) = self.max_bytes else {
+            return;
+        };
+
+        let mut size_bytes: u64 = stores.values().map(|cached| cached.size_bytes).sum();
+        while size_bytes > max_bytes {
+            let Some(lru_path) = stores
+                .iter()
+                .filter(|(path, _)| path.as_path() != keep)
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(path, _)| path.clone())
+            else {
+                break;
+            };
+
+            if let Some(evicted) = stores.remove(&lru_path) {
+                re_log::debug!("Evicting {lru_path:?} from the partition cache");
+                size_bytes -= evicted.size_bytes;
+            }
+        }
+    }
+
+    /// Only keeps the stores whose path matches the predicate.
+    pub fn retain(&self, mut f: impl FnMut(&Path) -> bool) {
+        self.stores.lock().stores.retain(|path, _| f(path));
+    }
+
+    /// Number of partitions currently loaded in memory.
+    pub fn num_stores(&self) -> usize {
+        self.stores.lock().stores.len()
+    }
+
+    /// Total size of the partitions currently loaded in memory.
+    pub fn size_bytes(&self) -> u64 {
+        self.stores
+            .lock()
+            .stores
+            .values()
+            .map(|cached| cached.size_bytes)
+            .sum()
+    }
+}
+
+/// A catalog of datasets, backed by a directory on the local filesystem.
+///
+/// Every sub-directory of the root is a [`Dataset`], and every `.rrd` file within a dataset
+/// directory is one of its [`Partition`]s. Files can be added or removed while the catalog is
+/// open: the directory is re-scanned on [`Catalog::refresh`], and partitions are reloaded
+/// whenever their modification time changes.
+///
+/// Loaded partitions are kept in a [`StoreCache`].
+///
+/// Only `.rrd` files within the root, or within one of the directories allowed with
+/// [`Catalog::allow_imports_from`], can be registered.
+pub struct Catalog {
+    root: PathBuf,
+
+    /// Canonicalized directories outside of the root that files may be registered from.
+    import_dirs: Vec<PathBuf>,
+
+    datasets: BTreeMap<EntryId, Dataset>,
+    stores: Arc<StoreCache>,
+}
+
+impl Catalog {
+    /// Opens the catalog at `root`, creating the directory if needed.
+    ///
+    /// At most [`StoreCache::DEFAULT_MAX_BYTES`] worth of partitions are kept in memory.
+    pub fn open(root: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::open_with_cache(root, StoreCache::new(Some(StoreCache::DEFAULT_MAX_BYTES)))
+    }
+
+    /// Opens the catalog at `root`, creating the directory if needed.
+    pub fn open_with_cache(root: impl AsRef<Path>, stores: StoreCache) -> Result<Self, Error> {
+        let root = root.as_ref();
+        std::fs::create_dir_all(root).map_err(Error::io(root))?;
+        let root = std::fs::canonicalize(root).map_err(Error::io(root))?;
+
+        let mut catalog = Self {
+            root,
+            import_dirs: Vec::new(),
+            datasets: Default::default(),
+            stores: Arc::new(stores),
+        };
+        catalog.refresh()?;
+
+        Ok(catalog)
+    }
+
+    /// The root directory of the catalog.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Allows registering the `.rrd` files within `dir`, or any of its sub-directories.
+    pub fn allow_imports_from(&mut self, dir: impl AsRef<Path>) -> Result<(), Error> {
+        let dir = dir.as_ref();
+        let dir = std::fs::canonicalize(dir).map_err(Error::io(dir))?;
+        self.import_dirs.push(dir);
+        Ok(())
+    }
+
+    /// The partitions of this catalog that are loaded in memory.
+    ///
+    /// It is shared, so that partitions can be loaded without locking the whole catalog.
+    pub fn stores(&self) -> &Arc<StoreCache> {
+        &self.stores
+    }
+
+    /// Re-scans the root directory for datasets that were added or removed.
+    pub fn refresh(&mut self) -> Result<(), Error> {
+        re_tracing::profile_function!();
+
+        let mut datasets = BTreeMap::new();
+
+        for entry in std::fs::read_dir(&self.root).map_err(Error::io(&self.root))? {
+            let entry = entry.map_err(Error::io(&self.root))?;
+            let path = entry.path();
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if !path.is_dir() || is_hidden {
+                continue;
+            }
+
+            match Dataset::open(path) {
+                Ok(dataset) => {
+                    datasets.insert(dataset.id, dataset);
+                }
+                Err(err) => re_log::warn!("Skipping dataset: {err}"),
+            }
+        }
+
+        self.datasets = datasets;
+        self.stores.retain(|path| path.exists());
+
+        Ok(())
+    }
+
+    pub fn datasets(&self) -> impl Iterator<Item = &Dataset> {
+        self.datasets.values()
+    }
+
+    pub fn dataset(&self, id: EntryId) -> Result<&Dataset, Error> {
+        self.datasets.get(&id).ok_or(Error::DatasetNotFound(id))
+    }
+
+    /// Creates a new, empty dataset.
+    pub fn create_dataset(&mut self, name: &str) -> Result<Dataset, Error> {
+        let is_valid_name = !name.is_empty()
+            && !name.starts_with('.')
+            && !name.starts_with("__")
+            && !name.contains(['/', '\\']);
+        if !is_valid_name {
+            return Err(Error::InvalidEntryName(name.to_owned()));
+        }
+
+        let dir = self.root.join(name);
+        if dir.exists() {
+            return Err(Error::EntryAlreadyExists(name.to_owned()));
+        }
+        std::fs::create_dir(&dir).map_err(Error::io(&dir))?;
+
+        let dataset = Dataset::open(dir)?;
+        self.datasets.insert(dataset.id, dataset.clone());
+
+        Ok(dataset)
+    }
+
+    /// Deletes a dataset, including all of its partitions.
+    pub fn delete_dataset(&mut self, id: EntryId) -> Result<(), Error> {
+        let dataset = self
+            .datasets
+            .remove(&id)
+            .ok_or(Error::DatasetNotFound(id))?;
+        std::fs::remove_dir_all(&dataset.dir).map_err(Error::io(&dataset.dir))?;
+        self.stores.retain(|path| !path.starts_with(&dataset.dir));
+
+        Ok(())
+    }
+
+    /// The id the partition will get if `storage_url` is registered.
+    pub fn partition_id_for_url(storage_url: &url::Url) -> Result<String, Error> {
+        let path = storage_url
+            .to_file_path()
+            .map_err(|()| Error::UnsupportedStorageUrl(storage_url.to_string()))?;
+
+        if path
+            .extension()
+            .is_none_or(|ext| ext != PARTITION_EXTENSION)
+        {
+            return Err(Error::UnsupportedStorageUrl(storage_url.to_string()));
+        }
+
+        path.file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .ok_or_else(|| Error::UnsupportedStorageUrl(storage_url.to_string()))
+    }
+
+    /// Copies the `.rrd` file at `storage_url` into the dataset directory.
+    ///
+    /// Files that already live in the dataset directory are registered as-is.
+    /// Files outside of the catalog root must be in one of the allowed import directories, see
+    /// [`Self::allow_imports_from`].
+    pub fn register(
+        &self,
+        dataset_id: EntryId,
+        storage_url: &url::Url,
+        on_duplicate: IfDuplicateBehavior,
+    ) -> Result<Partition, Error> {
+        let dataset = self.dataset(dataset_id)?.clone();
+
+        let partition_id = Self::partition_id_for_url(storage_url)?;
+        let src_path = storage_url
+            .to_file_path()
+            .map_err(|()| Error::UnsupportedStorageUrl(storage_url.to_string()))?;
+        let src_path = std::fs::canonicalize(&src_path).map_err(Error::io(&src_path))?;
+        let is_allowed = std::iter::once(&self.root)
+            .chain(&self.import_dirs)
+            .any(|dir| src_path.starts_with(dir));
+        if !is_allowed {
+            return Err(Error::StoragePathNotAllowed(src_path));
+        }
+        let dst_path = dataset.partition_path(&partition_id)?;
+
+        if src_path != dst_path {
+            if dst_path.exists() {
+                match on_duplicate {
+                    IfDuplicateBehavior::Skip => return Partition::open(dst_path),
+                    IfDuplicateBehavior::Error => {
+                        return Err(Error::PartitionAlreadyExists(partition_id))
+                    }
+                    IfDuplicateBehavior::Overwrite => {}
+                }
+            }
+
+            std::fs::copy(&src_path, &dst_path).map_err(Error::io(&dst_path))?;
+            self.stores.retain(|path| path != dst_path);
+        }
+
+        Partition::open(dst_path)
+    }
+
+    /// Appends `chunks` to the given partition of a dataset, creating it if needed.
+    ///
+    /// The chunks are written as a new recording stream at the end of the `.rrd` file, which is
+    /// merged with the rest of the partition when loading it, see [`StoreCache::load`].
+    pub fn write_chunks(
+        &self,
+        dataset_id: EntryId,
+        partition_id: &str,
+        chunks: &[Chunk],
+    ) -> Result<Partition, Error> {
+        let dataset = self.dataset(dataset_id)?;
+        let path = dataset.partition_path(partition_id)?;
+
+        let store_id = StoreId::from_string(StoreKind::Recording, partition_id.to_owned());
+        let store_info = LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                application_id: ApplicationId(dataset.name.clone()),
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Other("re_server".to_owned()),
+                store_version: Some(re_build_info::CrateVersion::LOCAL),
+            },
+        });
+        let messages = std::iter::once(Ok(store_info)).chain(chunks.iter().map(|chunk| {
+            chunk
+                .to_arrow_msg()
+                .map(|msg| LogMsg::ArrowMsg(store_id.clone(), msg))
+        }));
+        let bytes = re_log_encoding::encoder::encode_as_bytes_local(messages).map_err(|err| {
+            Error::Write {
+                path: path.clone(),
+                err: err.into(),
+            }
+        })?;
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(Error::io(&path))?;
+        file.write_all(&bytes).map_err(Error::io(&path))?;
+        drop(file);
+
+        self.stores.retain(|p| p != path);
+
+        Partition::open(path)
+    }
+
+    /// Loads the recording stored in the given partition, see [`StoreCache::load`].
+    pub fn load_partition(&self, partition: &Partition) -> Result<Arc<ChunkStore>, Error> {
+        self.stores.load(partition)
+    }
+}
+
+fn load_recording(partition: &Partition) -> Result<ChunkStore, Error> {
+    let stores = ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, &partition.path)
+        .map_err(|err| Error::Load {
+            path: partition.path.clone(),
+            err,
+        })?;
+
+    let mut recordings = stores
+        .into_iter()
+        .filter(|(store_id, _)| store_id.kind == StoreKind::Recording)
+        .map(|(_, store)| store);
+
+    let Some(mut recording) = recordings.next() else {
+        return Ok(ChunkStore::new(
+            re_log_types::StoreId::from_string(StoreKind::Recording, partition.id.clone()),
+            ChunkStoreConfig::ALL_DISABLED,
+        ));
+    };
+
+    for other in recordings {
+        re_log::debug!(
+            "Partition {:?} contains several recordings, merging them",
+            partition.id
+        );
+        for chunk in other.iter_chunks() {
+            recording.insert_chunk(chunk).map_err(|err| Error::Load {
+                path: partition.path.clone(),
+                err: err.into(),
+            })?;
+        }
+    }
+
+    Ok(recording)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datasets_are_persisted() {
+        let root = tempfile::tempdir().unwrap();
+
+        let id = {
+            let mut catalog = Catalog::open(root.path()).unwrap();
+            let dataset = catalog.create_dataset("my_dataset").unwrap();
+            assert!(catalog.create_dataset("my_dataset").is_err());
+            assert!(catalog.create_dataset("../escape").is_err());
+            assert!(catalog.create_dataset(ENTRIES_TABLE_NAME).is_err());
+            dataset.id
+        };
+
+        // Datasets created by hand are picked up too.
+        std::fs::create_dir(root.path().join("other_dataset")).unwrap();
+
+        let catalog = Catalog::open(root.path()).unwrap();
+        let names = catalog
+            .datasets()
+            .map(|dataset| dataset.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"other_dataset"));
+        assert_eq!(catalog.dataset(id).unwrap().name, "my_dataset");
+    }
+
+    #[test]
+    fn register_partitions() {
+        let root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+
+        let rrd_path = elsewhere.path().join("episode_1.rrd");
+        std::fs::write(&rrd_path, b"not really an rrd").unwrap();
+        let rrd_url = url::Url::from_file_path(&rrd_path).unwrap();
+
+        let mut catalog = Catalog::open(root.path()).unwrap();
+        let dataset = catalog.create_dataset("robots").unwrap();
+
+        assert!(matches!(
+            catalog.register(dataset.id, &rrd_url, IfDuplicateBehavior::Error),
+            Err(Error::StoragePathNotAllowed(_))
+        ));
+        catalog.allow_imports_from(elsewhere.path()).unwrap();
+
+        let partition = catalog
+            .register(dataset.id, &rrd_url, IfDuplicateBehavior::Error)
+            .unwrap();
+        assert_eq!(partition.id, "episode_1");
+        assert!(partition.path.starts_with(&dataset.dir));
+
+        assert!(matches!(
+            catalog.register(dataset.id, &rrd_url, IfDuplicateBehavior::Error),
+            Err(Error::PartitionAlreadyExists(_))
+        ));
+        catalog
+            .register(dataset.id, &rrd_url, IfDuplicateBehavior::Skip)
+            .unwrap();
+
+        let partitions = dataset.partitions().unwrap();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].id, "episode_1");
+
+        // Dots in partition ids must survive the round-trip to the filesystem.
+        let dotted_path = elsewhere.path().join("episode_2.v1.rrd");
+        std::fs::write(&dotted_path, b"not really an rrd").unwrap();
+        let dotted_url = url::Url::from_file_path(&dotted_path).unwrap();
+        let partition = catalog
+            .register(dataset.id, &dotted_url, IfDuplicateBehavior::Error)
+            .unwrap();
+        assert_eq!(partition.id, "episode_2.v1");
+        assert_eq!(partition.path, dataset.dir.join("episode_2.v1.rrd"));
+        assert_eq!(
+            dataset.partition("episode_2.v1").unwrap().id,
+            "episode_2.v1"
+        );
+        assert_eq!(dataset.partitions().unwrap().len(), 2);
+
+        for partition_id in ["../robots/episode_1", "sub/episode", ".hidden", ""] {
+            assert!(matches!(
+                dataset.partition(partition_id),
+                Err(Error::InvalidPartitionId(_))
+            ));
+        }
+
+        let not_an_rrd = url::Url::parse("https://example.com/data.rrd").unwrap();
+        assert!(matches!(
+            catalog.register(dataset.id, &not_an_rrd, IfDuplicateBehavior::Error),
+            Err(Error::UnsupportedStorageUrl(_))
+        ));
+    }
+
+    #[test]
+    fn store_cache_eviction() {
+        let root = tempfile::tempdir().unwrap();
+        let mut catalog = Catalog::open(root.path()).unwrap();
+        let dataset = catalog.create_dataset("robots").unwrap();
+
+        for name in ["a", "b", "c"] {
+            crate::tests::write_recording(
+                &dataset.dir.join(format!("{name}.rrd")),
+                &["/robot/arm", "/robot/leg"],
+            );
+        }
+        let partitions = dataset.partitions().unwrap();
+
+        // Find out how large a single partition is, then only leave room for two of them.
+        let unlimited = StoreCache::new(None);
+        for partition in &partitions {
+            unlimited.load(partition).unwrap();
+        }
+        assert_eq!(unlimited.num_stores(), 3);
+        let partition_size = unlimited.size_bytes() / 3;
+
+        let cache = StoreCache::new(Some(2 * partition_size + partition_size / 2));
+        let a = cache.load(&partitions[0]).unwrap();
+        cache.load(&partitions[1]).unwrap();
+        assert!(Arc::ptr_eq(&a, &cache.load(&partitions[0]).unwrap()));
+
+        // `b` is now the least recently used one.
+        cache.load(&partitions[2]).unwrap();
+        assert_eq!(cache.num_stores(), 2);
+        assert!(Arc::ptr_eq(&a, &cache.load(&partitions[0]).unwrap()));
+
+        // A partition larger than the limit is still loaded, it just doesn't stay around.
+        let tiny = StoreCache::new(Some(1));
+        tiny.load(&partitions[0]).unwrap();
+        tiny.load(&partitions[1]).unwrap();
+        assert_eq!(tiny.num_stores(), 1);
+    }
+
+    #[test]
+    fn write_chunks() {
+        use re_log_types::{build_frame_nr, example_components::MyPoint};
+
+        let root = tempfile::tempdir().unwrap();
+        let mut catalog = Catalog::open(root.path()).unwrap();
+        let dataset = catalog.create_dataset("robots").unwrap();
+
+        let chunk = |entity_path: &str| {
+            Chunk::builder(entity_path.into())
+                .with_component_batches(
+                    RowId::new(),
+                    [build_frame_nr(1)],
+                    [&[MyPoint::new(1.0, 2.0)] as _],
+                )
+                .build()
+                .unwrap()
+        };
+
+        // Writing to a partition that doesn't exist yet creates it, and later writes append to it.
+        let partition = catalog
+            .write_chunks(dataset.id, "episode", &[chunk("/robot/arm")])
+            .unwrap();
+        assert_eq!(catalog.load_partition(&partition).unwrap().num_chunks(), 1);
+
+        let partition = catalog
+            .write_chunks(
+                dataset.id,
+                "episode",
+                &[chunk("/robot/arm"), chunk("/robot/leg")],
+            )
+            .unwrap();
+        assert_eq!(catalog.load_partition(&partition).unwrap().num_chunks(), 3);
+        assert_eq!(dataset.partitions().unwrap().len(), 1);
+
+        assert!(matches!(
+            catalog.write_chunks(dataset.id, "../escape", &[chunk("/robot/arm")]),
+            Err(Error::InvalidPartitionId(_))
+        ));
+    }
+}
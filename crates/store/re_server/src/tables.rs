@@ -0,0 +1,318 @@
+//! The dataframes returned by the table-like APIs: partition tables, system tables and tasks.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, RecordBatch, StringArray, TimestampNanosecondArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+
+use re_protos::catalog::v1alpha1::ext::EntryDetails;
+use re_protos::common::v1alpha1::ext::{IfMissingBehavior, ScanParameters};
+use re_protos::manifest_registry::v1alpha1::{
+    ext::PartitionType, DATASET_MANIFEST_ID_FIELD_NAME, DATASET_MANIFEST_RECORDING_TYPE_FIELD_NAME,
+    DATASET_MANIFEST_REGISTRATION_TIME_FIELD_NAME, DATASET_MANIFEST_STORAGE_URL_FIELD_NAME,
+};
+
+use crate::store::Partition;
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Nanosecond, None)
+}
+
+fn timestamp_array(timestamps: impl IntoIterator<Item = jiff::Timestamp>) -> ArrayRef {
+    Arc::new(TimestampNanosecondArray::from_iter_values(
+        timestamps
+            .into_iter()
+            .map(|timestamp| timestamp.as_nanosecond() as i64),
+    ))
+}
+
+// --- Partition table ---
+
+/// Size of the `.rrd` file backing the partition.
+pub const PARTITION_SIZE_BYTES_FIELD_NAME: &str = "rerun_size_bytes";
+
+pub fn partition_table_schema() -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new(DATASET_MANIFEST_ID_FIELD_NAME, DataType::Utf8, false),
+            Field::new(
+                DATASET_MANIFEST_RECORDING_TYPE_FIELD_NAME,
+                DataType::Utf8,
+                false,
+            ),
+            Field::new(
+                DATASET_MANIFEST_STORAGE_URL_FIELD_NAME,
+                DataType::Utf8,
+                false,
+            ),
+            Field::new(
+                DATASET_MANIFEST_REGISTRATION_TIME_FIELD_NAME,
+                timestamp_type(),
+                false,
+            ),
+            Field::new(PARTITION_SIZE_BYTES_FIELD_NAME, DataType::UInt64, false),
+        ],
+        Default::default(),
+    )
+}
+
+pub fn partition_table(partitions: &[Partition]) -> arrow::error::Result<RecordBatch> {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            partitions.iter().map(|partition| partition.id.as_str()),
+        )),
+        PartitionType::many_to_arrow(vec![PartitionType::Rrd; partitions.len()]),
+        Arc::new(StringArray::from_iter_values(
+            partitions.iter().map(|partition| partition.url.as_str()),
+        )),
+        timestamp_array(partitions.iter().map(|partition| partition.modified_at)),
+        Arc::new(UInt64Array::from_iter_values(
+            partitions.iter().map(|partition| partition.size_bytes),
+        )),
+    ];
+
+    RecordBatch::try_new(Arc::new(partition_table_schema()), columns)
+}
+
+// --- Entries table ---
+
+pub const ENTRY_ID_FIELD_NAME: &str = "id";
+pub const ENTRY_NAME_FIELD_NAME: &str = "name";
+pub const ENTRY_KIND_FIELD_NAME: &str = "entry_kind";
+pub const ENTRY_CREATED_AT_FIELD_NAME: &str = "created_at";
+pub const ENTRY_UPDATED_AT_FIELD_NAME: &str = "updated_at";
+
+pub fn entries_table_schema() -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new(ENTRY_ID_FIELD_NAME, DataType::Utf8, false),
+            Field::new(ENTRY_NAME_FIELD_NAME, DataType::Utf8, false),
+            Field::new(ENTRY_KIND_FIELD_NAME, DataType::Utf8, false),
+            Field::new(ENTRY_CREATED_AT_FIELD_NAME, timestamp_type(), false),
+            Field::new(ENTRY_UPDATED_AT_FIELD_NAME, timestamp_type(), false),
+        ],
+        Default::default(),
+    )
+}
+
+pub fn entries_table(entries: &[EntryDetails]) -> arrow::error::Result<RecordBatch> {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            entries.iter().map(|entry| entry.id.to_string()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            entries.iter().map(|entry| entry.name.as_str()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            entries.iter().map(|entry| entry.kind.as_str_name()),
+        )),
+        timestamp_array(entries.iter().map(|entry| entry.created_at)),
+        timestamp_array(entries.iter().map(|entry| entry.updated_at)),
+    ];
+
+    RecordBatch::try_new(Arc::new(entries_table_schema()), columns)
+}
+
+// --- Tasks ---
+
+/// The outcome of a task.
+///
+/// All tasks run synchronously on this server, so they are always complete by the time their
+/// id is handed out.
+#[derive(Debug, Clone)]
+pub struct TaskStatus {
+    pub id: String,
+    pub error: Option<String>,
+}
+
+impl TaskStatus {
+    /// Exec status of successful tasks, as understood by the clients.
+    const SUCCESS: &str = "success";
+    const FAILED: &str = "failed";
+}
+
+pub fn tasks_table_schema() -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new("task_id", DataType::Utf8, false),
+            Field::new("exec_status", DataType::Utf8, false),
+            Field::new("msgs", DataType::Utf8, false),
+        ],
+        Default::default(),
+    )
+}
+
+pub fn tasks_table(tasks: &[TaskStatus]) -> arrow::error::Result<RecordBatch> {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            tasks.iter().map(|task| task.id.as_str()),
+        )),
+        Arc::new(StringArray::from_iter_values(tasks.iter().map(|task| {
+            if task.error.is_some() {
+                TaskStatus::FAILED
+            } else {
+                TaskStatus::SUCCESS
+            }
+        }))),
+        Arc::new(StringArray::from_iter_values(
+            tasks
+                .iter()
+                .map(|task| task.error.as_deref().unwrap_or_default()),
+        )),
+    ];
+
+    RecordBatch::try_new(Arc::new(tasks_table_schema()), columns)
+}
+
+// --- Scans ---
+
+/// Applies the projection and limits of `scan_parameters` to `batch`.
+///
+/// Filters and ordering require a query engine and are rejected.
+pub fn apply_scan_parameters(
+    batch: RecordBatch,
+    scan_parameters: Option<ScanParameters>,
+) -> Result<RecordBatch, tonic::Status> {
+    Scan::new(scan_parameters)?.apply(batch)
+}
+
+/// Applies the projection and limits of [`ScanParameters`] to a table that is streamed as
+/// several consecutive record batches.
+///
+/// Filters and ordering require a query engine and are rejected.
+pub struct Scan {
+    columns: Vec<String>,
+    on_missing_columns: IfMissingBehavior,
+
+    /// Rows that still have to be skipped before the scan starts.
+    num_rows_to_skip: usize,
+
+    /// Rows that can still be returned, `None` meaning unlimited.
+    num_rows_to_take: Option<usize>,
+}
+
+impl Scan {
+    pub fn new(scan_parameters: Option<ScanParameters>) -> Result<Self, tonic::Status> {
+        let scan_parameters = scan_parameters.unwrap_or_default();
+
+        if scan_parameters.filter.is_some() || scan_parameters.order_by.is_some() {
+            return Err(tonic::Status::unimplemented(
+                "filtering and ordering scans is not supported by the local server",
+            ));
+        }
+
+        Ok(Self {
+            columns: scan_parameters.columns,
+            on_missing_columns: scan_parameters.on_missing_columns,
+            num_rows_to_skip: scan_parameters
+                .limit_offset
+                .map_or(0, |offset| offset.max(0) as usize),
+            num_rows_to_take: scan_parameters.limit_len.map(|len| len.max(0) as usize),
+        })
+    }
+
+    /// Whether the limit has been reached, i.e. no further rows would be returned.
+    pub fn is_done(&self) -> bool {
+        self.num_rows_to_take == Some(0)
+    }
+
+    /// Returns the part of the next batch of the table that belongs to the scan.
+    pub fn apply(&mut self, batch: RecordBatch) -> Result<RecordBatch, tonic::Status> {
+        let mut batch = batch;
+
+        if !self.columns.is_empty() {
+            let schema = batch.schema();
+            let mut indices = Vec::with_capacity(self.columns.len());
+            for column in &self.columns {
+                match schema.index_of(column) {
+                    Ok(index) => indices.push(index),
+                    Err(_) if self.on_missing_columns == IfMissingBehavior::Skip => {}
+                    Err(_) => {
+                        return Err(tonic::Status::invalid_argument(format!(
+                            "no such column: {column:?}"
+                        )))
+                    }
+                }
+            }
+            batch = batch
+                .project(&indices)
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        }
+
+        let num_rows = batch.num_rows();
+        let offset = self.num_rows_to_skip.min(num_rows);
+        let len = self
+            .num_rows_to_take
+            .map_or(num_rows - offset, |len| len.min(num_rows - offset));
+
+        self.num_rows_to_skip -= offset;
+        if let Some(num_rows_to_take) = &mut self.num_rows_to_take {
+            *num_rows_to_take -= len;
+        }
+
+        Ok(batch.slice(offset, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_parameters() {
+        let tasks = (0..5)
+            .map(|i| TaskStatus {
+                id: i.to_string(),
+                error: None,
+            })
+            .collect::<Vec<_>>();
+        let batch = tasks_table(&tasks).unwrap();
+
+        let scanned = apply_scan_parameters(
+            batch.clone(),
+            Some(ScanParameters {
+                columns: vec!["msgs".to_owned(), "task_id".to_owned(), "nope".to_owned()],
+                on_missing_columns: IfMissingBehavior::Skip,
+                limit_offset: Some(3),
+                limit_len: Some(10),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(scanned.num_columns(), 2);
+        assert_eq!(scanned.schema().field(1).name(), "task_id");
+        assert_eq!(scanned.num_rows(), 2);
+
+        assert!(apply_scan_parameters(
+            batch.clone(),
+            Some(ScanParameters {
+                columns: vec!["nope".to_owned()],
+                on_missing_columns: IfMissingBehavior::Error,
+                ..Default::default()
+            }),
+        )
+        .is_err());
+
+        assert!(apply_scan_parameters(
+            batch.clone(),
+            Some(ScanParameters {
+                filter: Some("task_id = '1'".to_owned()),
+                ..Default::default()
+            }),
+        )
+        .is_err());
+
+        // Limits apply to the table as a whole, even if it is streamed in several batches.
+        let mut scan = Scan::new(Some(ScanParameters {
+            limit_offset: Some(3),
+            limit_len: Some(4),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert_eq!(scan.apply(batch.clone()).unwrap().num_rows(), 2);
+        assert!(!scan.is_done());
+        assert_eq!(scan.apply(batch.clone()).unwrap().num_rows(), 2);
+        assert!(scan.is_done());
+        assert_eq!(scan.apply(batch).unwrap().num_rows(), 0);
+    }
+}
@@ -140,11 +140,23 @@ impl TryFrom<&ArrowRecordBatch> for ChunkBatch {
 
     /// Will automatically wrap data columns in `ListArrays` if they are not already.
     fn try_from(batch: &ArrowRecordBatch) -> Result<Self, Self::Error> {
+        Self::try_from_record_batch_recorded_by(batch, None)
+    }
+}
+
+impl ChunkBatch {
+    /// Same as `ChunkBatch::try_from`, but only applies the migrations that data written by the
+    /// given SDK version needs, if known.
+    pub fn try_from_record_batch_recorded_by(
+        batch: &ArrowRecordBatch,
+        recorded_version: Option<re_build_info::CrateVersion>,
+    ) -> Result<Self, SorbetError> {
         re_tracing::profile_function!();
 
-        Self::try_from(SorbetBatch::try_from_record_batch(
+        Self::try_from(SorbetBatch::try_from_record_batch_recorded_by(
             batch,
             crate::BatchType::Chunk,
+            recorded_version,
         )?)
     }
 }
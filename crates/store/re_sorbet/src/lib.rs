@@ -46,7 +46,10 @@ pub use self::{
         ArrowBatchMetadata, ArrowFieldMetadata, MetadataExt, MissingFieldMetadata,
         MissingMetadataKey,
     },
-    migration::migrate_record_batch,
+    migration::{
+        migrate_record_batch, migrate_record_batch_with_report, Migration, MigrationRelease,
+        MigrationReport, MIGRATIONS,
+    },
     row_id_column_descriptor::{RowIdColumnDescriptor, WrongDatatypeError},
     selectors::{
         ColumnSelector, ColumnSelectorParseError, ComponentColumnSelector, TimeColumnSelector,
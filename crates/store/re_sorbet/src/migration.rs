@@ -1,85 +1,365 @@
-//! Handles migrating old `re_types` to new ones.
+//! Handles migrating chunks written by older versions of the SDK to the current schema.
 //!
+//! Every SDK release that changes how data ends up on disk registers a [`MigrationRelease`] in
+//! [`MIGRATIONS`], with one [`Migration`] per change.
 //!
+//! Migrations are applied lazily, whenever a [`crate::SorbetBatch`] gets parsed from an
+//! [`ArrowRecordBatch`] (i.e. whenever a chunk gets decoded), and they are idempotent: data that
+//! is already up-to-date is left untouched.
+//!
+//! When the version of the SDK that wrote the data is known, only the releases that came after it
+//! are applied.
 use std::collections::BTreeMap;
 
 use arrow::{
     array::{ArrayRef as ArrowArrayRef, RecordBatch as ArrowRecordBatch, RecordBatchOptions},
     datatypes::{Field as ArrowField, FieldRef as ArrowFieldRef, Schema as ArrowSchema},
 };
+use itertools::Itertools as _;
 
-/// Migrate old renamed types to new types.
-pub fn migrate_record_batch(batch: &ArrowRecordBatch) -> ArrowRecordBatch {
-    re_tracing::profile_function!();
+use re_build_info::CrateVersion;
 
-    struct ArchetypeRename {
-        new_name: &'static str,
-        field_renames: BTreeMap<&'static str, &'static str>,
-    }
+use crate::ColumnKind;
 
-    let archetype_renames = BTreeMap::from([
-        (
-            "rerun.archetypes.Scalar",
-            ArchetypeRename {
-                new_name: "rerun.archetypes.Scalars",
-                field_renames: [("scalar", "scalars")].into(),
-            },
-        ),
-        (
-            "rerun.archetypes.SeriesLine",
-            ArchetypeRename {
-                new_name: "rerun.archetypes.SeriesLines",
-                field_renames: [("color", "colors"), ("width", "widths"), ("name", "names")].into(),
-            },
-        ),
-        (
-            "rerun.archetypes.SeriesPoint",
-            ArchetypeRename {
-                new_name: "rerun.archetypes.SeriesPoints",
-                field_renames: [
-                    ("color", "colors"),
-                    ("marker", "markers"),
-                    ("name", "names"),
-                    ("marker_size", "marker_sizes"),
-                ]
-                .into(),
+/// All the migrations, oldest release first.
+pub const MIGRATIONS: &[MigrationRelease] = &[MigrationRelease {
+    version: CrateVersion::parse("0.23.0"),
+    migrations: &[
+        Migration::RenameArchetype {
+            from: "rerun.archetypes.Scalar",
+            to: "rerun.archetypes.Scalars",
+            field_renames: &[("scalar", "scalars")],
+        },
+        Migration::RenameArchetype {
+            from: "rerun.archetypes.SeriesLine",
+            to: "rerun.archetypes.SeriesLines",
+            field_renames: &[("color", "colors"), ("width", "widths"), ("name", "names")],
+        },
+        Migration::RenameArchetype {
+            from: "rerun.archetypes.SeriesPoint",
+            to: "rerun.archetypes.SeriesPoints",
+            field_renames: &[
+                ("color", "colors"),
+                ("marker", "markers"),
+                ("name", "names"),
+                ("marker_size", "marker_sizes"),
+            ],
+        },
+    ],
+}];
+
+/// All the [`Migration`]s required by data written with SDKs older than `version`.
+#[derive(Debug, Clone, Copy)]
+pub struct MigrationRelease {
+    /// The SDK version that introduced these changes.
+    pub version: CrateVersion,
+
+    /// Applied in order.
+    pub migrations: &'static [Migration],
+}
+
+impl MigrationRelease {
+    /// Does data written by the given SDK version need these migrations?
+    ///
+    /// Data of unknown provenance always does. So does data written by pre-releases of
+    /// [`Self::version`], which might predate the changes.
+    pub fn applies_to(&self, recorded_version: Option<CrateVersion>) -> bool {
+        recorded_version.is_none_or(
+            |recorded_version| match recorded_version.cmp(&self.version) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => !recorded_version.is_release(),
+                std::cmp::Ordering::Greater => false,
             },
-        ),
-    ]);
+        )
+    }
+}
 
-    let num_columns = batch.num_columns();
-    let mut fields: Vec<ArrowFieldRef> = Vec::with_capacity(num_columns);
-    let mut columns: Vec<ArrowArrayRef> = Vec::with_capacity(num_columns);
+/// A single transformation of a component column.
+#[derive(Debug, Clone, Copy)]
+pub enum Migration {
+    /// Renames an archetype, and some of its fields.
+    RenameArchetype {
+        from: &'static str,
+        to: &'static str,
 
-    for (field, array) in itertools::izip!(batch.schema().fields(), batch.columns()) {
-        let mut metadata = field.metadata().clone();
-        if let Some(archetype) = metadata.get_mut("rerun.archetype") {
-            if let Some(archetype_rename) = archetype_renames.get(archetype.as_str()) {
-                re_log::debug_once!(
-                    "Migrating {archetype:?} to {:?}…",
-                    archetype_rename.new_name
-                );
+        /// `(old field name, new field name)`
+        field_renames: &'static [(&'static str, &'static str)],
+    },
+
+    /// Moves some fields of an archetype to other archetypes.
+    SplitArchetype {
+        from: &'static str,
+
+        /// `(old field name, new archetype, new field name)`
+        fields: &'static [(&'static str, &'static str, &'static str)],
+    },
+
+    /// Renames a component type.
+    RenameComponent {
+        from: &'static str,
+        to: &'static str,
+    },
+
+    /// Converts the data of a component to a new datatype.
+    ///
+    /// `migrate` is given the whole column (i.e. a list array) and must return `None` if the
+    /// column is already up-to-date.
+    ChangeDatatype {
+        component: &'static str,
+        migrate: fn(&ArrowArrayRef) -> Option<ArrowArrayRef>,
+    },
+
+    /// Drops the given indicator component.
+    RemoveIndicator { component: &'static str },
+}
+
+impl std::fmt::Display for Migration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RenameArchetype { from, to, .. } => write!(f, "rename archetype {from} to {to}"),
+            Self::SplitArchetype { from, fields } => {
+                let targets = fields
+                    .iter()
+                    .map(|(_, archetype, _)| *archetype)
+                    .collect::<std::collections::BTreeSet<_>>();
+                write!(f, "split archetype {from} into {targets:?}")
+            }
+            Self::RenameComponent { from, to } => write!(f, "rename component {from} to {to}"),
+            Self::ChangeDatatype { component, .. } => {
+                write!(f, "change the datatype of {component}")
+            }
+            Self::RemoveIndicator { component } => write!(f, "remove indicator {component}"),
+        }
+    }
+}
+
+/// What happened to a single column.
+enum Outcome {
+    Unchanged,
+    Changed,
+    Dropped,
+}
+
+impl Migration {
+    fn apply(&self, field: &mut ArrowField, array: &mut ArrowArrayRef) -> Outcome {
+        let archetype = field.metadata().get("rerun.archetype").map(String::as_str);
 
-                // Rename archetype:
-                *archetype = archetype_rename.new_name.to_owned();
+        // Only pay for cloning the metadata once we know that something has to change.
+        let mut metadata = match self {
+            Self::RenameArchetype { from, .. } | Self::SplitArchetype { from, .. } => {
+                if archetype != Some(*from) {
+                    return Outcome::Unchanged;
+                }
+                field.metadata().clone()
+            }
+
+            Self::RenameComponent { from, .. } => {
+                // Legacy data: the field name is the component name.
+                let component = field
+                    .metadata()
+                    .get("rerun.component")
+                    .unwrap_or(field.name());
+                if component != from {
+                    return Outcome::Unchanged;
+                }
+                field.metadata().clone()
+            }
+
+            Self::ChangeDatatype { .. } | Self::RemoveIndicator { .. } => Default::default(),
+        };
 
-                // Renmame fields:
+        let outcome = match self {
+            Self::RenameArchetype {
+                from: _,
+                to,
+                field_renames,
+            } => {
+                metadata.insert("rerun.archetype".to_owned(), (*to).to_owned());
                 if let Some(archetype_field) = metadata.get_mut("rerun.archetype_field") {
-                    if let Some(new_field_name) =
-                        archetype_rename.field_renames.get(archetype_field.as_str())
+                    if let Some((_, new_field_name)) =
+                        field_renames.iter().find(|(old, _)| old == archetype_field)
                     {
                         *archetype_field = (*new_field_name).to_owned();
                     }
                 }
+
+                Outcome::Changed
+            }
+
+            Self::SplitArchetype { from: _, fields } => {
+                let Some((_, new_archetype, new_field_name)) = metadata
+                    .get("rerun.archetype_field")
+                    .and_then(|archetype_field| {
+                        fields.iter().find(|(old, _, _)| old == archetype_field)
+                    })
+                else {
+                    return Outcome::Unchanged;
+                };
+
+                metadata.insert("rerun.archetype".to_owned(), (*new_archetype).to_owned());
+                metadata.insert(
+                    "rerun.archetype_field".to_owned(),
+                    (*new_field_name).to_owned(),
+                );
+
+                Outcome::Changed
+            }
+
+            Self::RenameComponent { from: _, to } => {
+                if let Some(component) = metadata.get_mut("rerun.component") {
+                    *component = (*to).to_owned();
+                } else {
+                    *field = field.clone().with_name(*to);
+                }
+
+                Outcome::Changed
+            }
+
+            Self::ChangeDatatype { component, migrate } => {
+                if component_name(field) != *component {
+                    return Outcome::Unchanged;
+                }
+
+                let Some(migrated) = migrate(array) else {
+                    return Outcome::Unchanged;
+                };
+
+                *field = field.clone().with_data_type(migrated.data_type().clone());
+                *array = migrated;
+
+                // The metadata is left as is.
+                return Outcome::Changed;
             }
+
+            Self::RemoveIndicator { component } => {
+                return if component_name(field) == *component {
+                    Outcome::Dropped
+                } else {
+                    Outcome::Unchanged
+                };
+            }
+        };
+
+        *field = field.clone().with_metadata(metadata);
+
+        outcome
+    }
+}
+
+fn component_name(field: &ArrowField) -> &str {
+    field
+        .metadata()
+        .get("rerun.component")
+        .map_or(field.name().as_str(), String::as_str)
+}
+
+/// Keeps track of which [`Migration`]s were applied, and to how many columns.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// `(release version, description) -> number of affected columns`
+    pub num_columns_per_migration: BTreeMap<(CrateVersion, String), u64>,
+}
+
+impl MigrationReport {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_columns_per_migration.is_empty()
+    }
+}
+
+impl std::fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("nothing to migrate");
+        }
+
+        for ((version, description), num_columns) in &self.num_columns_per_migration {
+            writeln!(f, "{version}: {description} ({num_columns} column(s))")?;
         }
 
-        let field = ArrowField::clone(field.as_ref())
-            .clone()
-            .with_metadata(metadata);
+        Ok(())
+    }
+}
+
+/// Migrate old data to the current schema, using all the registered [`MIGRATIONS`].
+///
+/// `recorded_version` is the version of the SDK that wrote the data, if known.
+/// See [`migrate_record_batch_with_report`].
+pub fn migrate_record_batch(
+    batch: &ArrowRecordBatch,
+    recorded_version: Option<CrateVersion>,
+) -> ArrowRecordBatch {
+    let mut report = MigrationReport::default();
+    let batch = migrate_record_batch_with_report(batch, MIGRATIONS, recorded_version, &mut report);
+
+    for (version, description) in report.num_columns_per_migration.keys() {
+        re_log::debug_once!("Migrating pre-{version} data: {description}…");
+    }
+
+    batch
+}
+
+/// Migrate old data using the given `releases`, keeping track of what was changed in `report`.
+///
+/// `recorded_version` is the version of the SDK that wrote the data, if known: releases up to and
+/// including it are skipped, see [`MigrationRelease::applies_to`].
+///
+/// Returns a cheap clone of the original batch if nothing had to be migrated.
+pub fn migrate_record_batch_with_report(
+    batch: &ArrowRecordBatch,
+    releases: &[MigrationRelease],
+    recorded_version: Option<CrateVersion>,
+    report: &mut MigrationReport,
+) -> ArrowRecordBatch {
+    re_tracing::profile_function!();
 
-        fields.push(field.into());
-        columns.push(array.clone());
+    let releases = releases
+        .iter()
+        .filter(|release| release.applies_to(recorded_version))
+        .collect_vec();
+    if releases.is_empty() {
+        return batch.clone();
+    }
+
+    let num_columns = batch.num_columns();
+    let mut fields: Vec<ArrowFieldRef> = Vec::with_capacity(num_columns);
+    let mut columns: Vec<ArrowArrayRef> = Vec::with_capacity(num_columns);
+    let mut any_changes = false;
+
+    'columns: for (field, array) in itertools::izip!(batch.schema().fields(), batch.columns()) {
+        if ColumnKind::try_from(field.as_ref()).ok() != Some(ColumnKind::Component) {
+            fields.push(field.clone());
+            columns.push(array.clone());
+            continue;
+        }
+
+        let mut field_migrated = ArrowField::clone(field);
+        let mut array_migrated = array.clone();
+
+        for release in &releases {
+            for migration in release.migrations {
+                let outcome = migration.apply(&mut field_migrated, &mut array_migrated);
+
+                if !matches!(outcome, Outcome::Unchanged) {
+                    any_changes = true;
+                    *report
+                        .num_columns_per_migration
+                        .entry((release.version, migration.to_string()))
+                        .or_default() += 1;
+                }
+
+                if matches!(outcome, Outcome::Dropped) {
+                    continue 'columns;
+                }
+            }
+        }
+
+        fields.push(field_migrated.into());
+        columns.push(array_migrated);
+    }
+
+    if !any_changes {
+        return batch.clone();
     }
 
     let schema = ArrowSchema::new_with_metadata(fields, batch.schema().metadata.clone());
@@ -91,3 +371,161 @@ pub fn migrate_record_batch(batch: &ArrowRecordBatch) -> ArrowRecordBatch {
     )
     .expect("Can't fail")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{Array as _, ListArray},
+        datatypes::{DataType, Int32Type},
+    };
+
+    use super::*;
+    use crate::MetadataExt as _;
+
+    fn component_field(archetype: &str, archetype_field: &str, component: &str) -> ArrowField {
+        ArrowField::new(component, DataType::new_list(DataType::Int32, true), true).with_metadata(
+            [
+                ("rerun.kind", "data"),
+                ("rerun.archetype", archetype),
+                ("rerun.archetype_field", archetype_field),
+                ("rerun.component", component),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn migrations_are_applied_in_order_and_reported() {
+        fn widen(array: &ArrowArrayRef) -> Option<ArrowArrayRef> {
+            let list = array.as_any().downcast_ref::<ListArray>()?;
+            if list.value_type() == DataType::Int64 {
+                return None;
+            }
+
+            let values = arrow::compute::cast(list.values(), &DataType::Int64).ok()?;
+            let field = Arc::new(ArrowField::new_list_field(DataType::Int64, true));
+            Some(Arc::new(ListArray::new(
+                field,
+                list.offsets().clone(),
+                values,
+                list.nulls().cloned(),
+            )))
+        }
+
+        const RELEASES: &[MigrationRelease] = &[
+            MigrationRelease {
+                version: CrateVersion::parse("0.1.0"),
+                migrations: &[Migration::RenameArchetype {
+                    from: "test.Old",
+                    to: "test.Thing",
+                    field_renames: &[("value", "values")],
+                }],
+            },
+            MigrationRelease {
+                version: CrateVersion::parse("0.2.0"),
+                migrations: &[
+                    Migration::SplitArchetype {
+                        from: "test.Thing",
+                        fields: &[("extra", "test.Extra", "extras")],
+                    },
+                    Migration::RenameComponent {
+                        from: "test.Value",
+                        to: "test.Values",
+                    },
+                    Migration::ChangeDatatype {
+                        component: "test.Values",
+                        migrate: widen,
+                    },
+                    Migration::RemoveIndicator {
+                        component: "test.ThingIndicator",
+                    },
+                ],
+            },
+        ];
+
+        let list = || {
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>([Some([
+                Some(1),
+                Some(2),
+            ])])) as ArrowArrayRef
+        };
+
+        let batch = ArrowRecordBatch::try_new(
+            Arc::new(ArrowSchema::new_with_metadata(
+                vec![
+                    component_field("test.Old", "value", "test.Value"),
+                    component_field("test.Old", "extra", "test.Extra"),
+                    component_field("test.Old", "", "test.ThingIndicator"),
+                ],
+                Default::default(),
+            )),
+            vec![list(), list(), list()],
+        )
+        .unwrap();
+
+        let mut report = MigrationReport::default();
+        let migrated = migrate_record_batch_with_report(&batch, RELEASES, None, &mut report);
+
+        assert_eq!(migrated.num_columns(), 2);
+
+        let schema = migrated.schema();
+        let values = schema.field(0);
+        assert_eq!(values.get_opt("rerun.archetype"), Some("test.Thing"));
+        assert_eq!(values.get_opt("rerun.archetype_field"), Some("values"));
+        assert_eq!(values.get_opt("rerun.component"), Some("test.Values"));
+        assert_eq!(
+            values.data_type(),
+            &DataType::new_list(DataType::Int64, true)
+        );
+
+        let extras = schema.field(1);
+        assert_eq!(extras.get_opt("rerun.archetype"), Some("test.Extra"));
+        assert_eq!(extras.get_opt("rerun.archetype_field"), Some("extras"));
+
+        assert_eq!(
+            report.num_columns_per_migration.values().sum::<u64>(),
+            3 + 1 + 1 + 1 + 1
+        );
+
+        // Migrations are idempotent.
+        report = MigrationReport::default();
+        let migrated_again =
+            migrate_record_batch_with_report(&migrated, RELEASES, None, &mut report);
+        assert!(report.is_empty(), "{report}");
+        assert_eq!(migrated, migrated_again);
+
+        // Data written by an SDK that already includes some releases skips them.
+        report = MigrationReport::default();
+        let migrated_from_0_1 = migrate_record_batch_with_report(
+            &batch,
+            RELEASES,
+            Some(CrateVersion::new(0, 1, 0)),
+            &mut report,
+        );
+        assert!(report
+            .num_columns_per_migration
+            .keys()
+            .all(|(version, _)| *version == CrateVersion::new(0, 2, 0)));
+        assert_eq!(
+            migrated_from_0_1
+                .schema()
+                .field(0)
+                .get_opt("rerun.archetype"),
+            Some("test.Old")
+        );
+
+        report = MigrationReport::default();
+        let untouched = migrate_record_batch_with_report(
+            &batch,
+            RELEASES,
+            Some(CrateVersion::new(0, 2, 0)),
+            &mut report,
+        );
+        assert!(report.is_empty(), "{report}");
+        assert_eq!(batch, untouched);
+    }
+}
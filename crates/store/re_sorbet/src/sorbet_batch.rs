@@ -160,12 +160,45 @@ impl SorbetBatch {
     pub fn try_from_record_batch(
         batch: &ArrowRecordBatch,
         batch_type: crate::BatchType,
+    ) -> Result<Self, SorbetError> {
+        Self::try_from_record_batch_recorded_by(batch, batch_type, None)
+    }
+
+    /// Same as [`Self::try_from_record_batch`], but only applies the migrations that data written
+    /// by the given SDK version needs, if known.
+    pub fn try_from_record_batch_recorded_by(
+        batch: &ArrowRecordBatch,
+        batch_type: crate::BatchType,
+        recorded_version: Option<re_build_info::CrateVersion>,
+    ) -> Result<Self, SorbetError> {
+        re_tracing::profile_function!();
+
+        let batch = make_all_data_columns_list_arrays(batch);
+        let batch = crate::migrate_record_batch(&batch, recorded_version);
+
+        Self::try_from_list_arrays(&batch, batch_type)
+    }
+
+    /// Same as [`Self::try_from_record_batch`], but doesn't apply any migrations: the batch is
+    /// read as it was written.
+    ///
+    /// See [`crate::migrate_record_batch_with_report`] to migrate it explicitly.
+    pub fn try_from_record_batch_unmigrated(
+        batch: &ArrowRecordBatch,
+        batch_type: crate::BatchType,
     ) -> Result<Self, SorbetError> {
         re_tracing::profile_function!();
 
         let batch = make_all_data_columns_list_arrays(batch);
-        let batch = crate::migrate_record_batch(&batch);
 
+        Self::try_from_list_arrays(&batch, batch_type)
+    }
+
+    /// Expects all data columns to already be `ListArrays`.
+    fn try_from_list_arrays(
+        batch: &ArrowRecordBatch,
+        batch_type: crate::BatchType,
+    ) -> Result<Self, SorbetError> {
         let sorbet_schema = SorbetSchema::try_from(batch.schema_ref().as_ref())?;
 
         for (field, column) in itertools::izip!(
@@ -0,0 +1,250 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/coordinate_frame.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Attaches an entity to a named coordinate frame.
+///
+/// Entities in a named frame are placed by their frame rather than by their entity path parent:
+/// the spatial views look up the transform from the frame of the view's origin to the frame of the entity
+/// by searching through all transforms logged between named frames, see `parent_frame` & `child_frame`
+/// on [`archetypes::Transform3D`][crate::archetypes::Transform3D].
+///
+/// The frame of an entity also applies to all its descendants that don't specify a frame of their own.
+/// Any transform logged at the entity itself is interpreted relative to its frame.
+/// If the view's origin isn't in a named frame, or the frames aren't connected, entities fall back to being
+/// placed by their entity path.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+#[repr(transparent)]
+pub struct CoordinateFrame {
+    /// The name of the frame this entity is in.
+    pub frame: Option<SerializedComponentBatch>,
+}
+
+impl CoordinateFrame {
+    /// Returns the [`ComponentDescriptor`] for [`Self::frame`].
+    #[inline]
+    pub fn descriptor_frame() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.CoordinateFrame".into()),
+            component_name: "rerun.components.CoordinateFrame".into(),
+            archetype_field_name: Some("frame".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.CoordinateFrame".into()),
+            component_name: "rerun.components.CoordinateFrameIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [CoordinateFrame::descriptor_frame()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [CoordinateFrame::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            CoordinateFrame::descriptor_frame(),
+            CoordinateFrame::descriptor_indicator(),
+        ]
+    });
+
+impl CoordinateFrame {
+    /// The total number of components in the archetype: 1 required, 1 recommended, 0 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+/// Indicator component for the [`CoordinateFrame`] [`::re_types_core::Archetype`]
+pub type CoordinateFrameIndicator = ::re_types_core::GenericIndicatorComponent<CoordinateFrame>;
+
+impl ::re_types_core::Archetype for CoordinateFrame {
+    type Indicator = CoordinateFrameIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.CoordinateFrame".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Coordinate frame"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        CoordinateFrameIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let frame = arrays_by_descr
+            .get(&Self::descriptor_frame())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_frame()));
+        Ok(Self { frame })
+    }
+}
+
+impl ::re_types_core::AsComponents for CoordinateFrame {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [Some(Self::indicator()), self.frame.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CoordinateFrame {}
+
+impl CoordinateFrame {
+    /// Create a new `CoordinateFrame`.
+    #[inline]
+    pub fn new(frame: impl Into<crate::components::CoordinateFrame>) -> Self {
+        Self {
+            frame: try_serialize_field(Self::descriptor_frame(), [frame]),
+        }
+    }
+
+    /// Update only some specific fields of a `CoordinateFrame`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CoordinateFrame`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            frame: Some(SerializedComponentBatch::new(
+                crate::components::CoordinateFrame::arrow_empty(),
+                Self::descriptor_frame(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [self
+            .frame
+            .map(|frame| frame.partitioned(_lengths.clone()))
+            .transpose()?];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_frame = self.frame.as_ref().map(|b| b.array.len());
+        let len = None.or(len_frame).unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The name of the frame this entity is in.
+    #[inline]
+    pub fn with_frame(mut self, frame: impl Into<crate::components::CoordinateFrame>) -> Self {
+        self.frame = try_serialize_field(Self::descriptor_frame(), [frame]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::CoordinateFrame`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_frame`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_frame(
+        mut self,
+        frame: impl IntoIterator<Item = impl Into<crate::components::CoordinateFrame>>,
+    ) -> Self {
+        self.frame = try_serialize_field(Self::descriptor_frame(), frame);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CoordinateFrame {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.frame.heap_size_bytes()
+    }
+}
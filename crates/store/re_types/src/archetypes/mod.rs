@@ -16,6 +16,7 @@ mod boxes3d;
 mod boxes3d_ext;
 mod capsules3d;
 mod capsules3d_ext;
+mod coordinate_frame;
 mod depth_image;
 mod depth_image_ext;
 mod ellipsoids3d;
@@ -71,6 +72,7 @@ pub use self::bar_chart::BarChart;
 pub use self::boxes2d::Boxes2D;
 pub use self::boxes3d::Boxes3D;
 pub use self::capsules3d::Capsules3D;
+pub use self::coordinate_frame::CoordinateFrame;
 pub use self::depth_image::DepthImage;
 pub use self::ellipsoids3d::Ellipsoids3D;
 pub use self::encoded_image::EncodedImage;
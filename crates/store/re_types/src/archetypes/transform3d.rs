@@ -31,6 +31,9 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 ///
 /// For transforms that affect only a single entity and do not propagate along the entity tree refer to [`archetypes::InstancePoses3D`][crate::archetypes::InstancePoses3D].
 ///
+/// By setting both `parent_frame` and `child_frame`, the transform instead connects two named coordinate frames,
+/// regardless of the entity it is logged at. See [`archetypes::CoordinateFrame`][crate::archetypes::CoordinateFrame] for how entities are placed in named frames.
+///
 /// ## Examples
 ///
 /// ### Variety of 3D transforms
@@ -351,6 +354,18 @@ pub struct Transform3D {
     /// Specifies the relation this transform establishes between this entity and its parent.
     pub relation: Option<SerializedComponentBatch>,
 
+    /// The frame this transform maps into.
+    ///
+    /// If both a parent and a child frame are set, this transform connects these two named frames
+    /// instead of applying to this entity and its descendants.
+    pub parent_frame: Option<SerializedComponentBatch>,
+
+    /// The frame this transform maps from.
+    ///
+    /// If both a parent and a child frame are set, this transform connects these two named frames
+    /// instead of applying to this entity and its descendants.
+    pub child_frame: Option<SerializedComponentBatch>,
+
     /// Visual length of the 3 axes.
     ///
     /// The length is interpreted in the local coordinate system of the transform.
@@ -419,6 +434,26 @@ impl Transform3D {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::parent_frame`].
+    #[inline]
+    pub fn descriptor_parent_frame() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.Transform3D".into()),
+            component_name: "rerun.components.ParentFrame".into(),
+            archetype_field_name: Some("parent_frame".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::child_frame`].
+    #[inline]
+    pub fn descriptor_child_frame() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.Transform3D".into()),
+            component_name: "rerun.components.ChildFrame".into(),
+            archetype_field_name: Some("child_frame".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::axis_length`].
     #[inline]
     pub fn descriptor_axis_length() -> ComponentDescriptor {
@@ -446,7 +481,7 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
     once_cell::sync::Lazy::new(|| [Transform3D::descriptor_indicator()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 9usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             Transform3D::descriptor_translation(),
@@ -455,11 +490,13 @@ static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]>
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
+            Transform3D::descriptor_parent_frame(),
+            Transform3D::descriptor_child_frame(),
             Transform3D::descriptor_axis_length(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 10usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             Transform3D::descriptor_indicator(),
@@ -469,13 +506,15 @@ static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
+            Transform3D::descriptor_parent_frame(),
+            Transform3D::descriptor_child_frame(),
             Transform3D::descriptor_axis_length(),
         ]
     });
 
 impl Transform3D {
-    /// The total number of components in the archetype: 0 required, 1 recommended, 7 optional
-    pub const NUM_COMPONENTS: usize = 8usize;
+    /// The total number of components in the archetype: 0 required, 1 recommended, 9 optional
+    pub const NUM_COMPONENTS: usize = 10usize;
 }
 
 /// Indicator component for the [`Transform3D`] [`::re_types_core::Archetype`]
@@ -551,6 +590,16 @@ impl ::re_types_core::Archetype for Transform3D {
         let relation = arrays_by_descr
             .get(&Self::descriptor_relation())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_relation()));
+        let parent_frame = arrays_by_descr
+            .get(&Self::descriptor_parent_frame())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_parent_frame())
+            });
+        let child_frame = arrays_by_descr
+            .get(&Self::descriptor_child_frame())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_child_frame())
+            });
         let axis_length = arrays_by_descr
             .get(&Self::descriptor_axis_length())
             .map(|array| {
@@ -563,6 +612,8 @@ impl ::re_types_core::Archetype for Transform3D {
             scale,
             mat3x3,
             relation,
+            parent_frame,
+            child_frame,
             axis_length,
         })
     }
@@ -580,6 +631,8 @@ impl ::re_types_core::AsComponents for Transform3D {
             self.scale.clone(),
             self.mat3x3.clone(),
             self.relation.clone(),
+            self.parent_frame.clone(),
+            self.child_frame.clone(),
             self.axis_length.clone(),
         ]
         .into_iter()
@@ -626,6 +679,14 @@ impl Transform3D {
                 crate::components::TransformRelation::arrow_empty(),
                 Self::descriptor_relation(),
             )),
+            parent_frame: Some(SerializedComponentBatch::new(
+                crate::components::ParentFrame::arrow_empty(),
+                Self::descriptor_parent_frame(),
+            )),
+            child_frame: Some(SerializedComponentBatch::new(
+                crate::components::ChildFrame::arrow_empty(),
+                Self::descriptor_child_frame(),
+            )),
             axis_length: Some(SerializedComponentBatch::new(
                 crate::components::AxisLength::arrow_empty(),
                 Self::descriptor_axis_length(),
@@ -670,6 +731,12 @@ impl Transform3D {
             self.relation
                 .map(|relation| relation.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.parent_frame
+                .map(|parent_frame| parent_frame.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.child_frame
+                .map(|child_frame| child_frame.partitioned(_lengths.clone()))
+                .transpose()?,
             self.axis_length
                 .map(|axis_length| axis_length.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -696,6 +763,8 @@ impl Transform3D {
         let len_scale = self.scale.as_ref().map(|b| b.array.len());
         let len_mat3x3 = self.mat3x3.as_ref().map(|b| b.array.len());
         let len_relation = self.relation.as_ref().map(|b| b.array.len());
+        let len_parent_frame = self.parent_frame.as_ref().map(|b| b.array.len());
+        let len_child_frame = self.child_frame.as_ref().map(|b| b.array.len());
         let len_axis_length = self.axis_length.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_translation)
@@ -704,6 +773,8 @@ impl Transform3D {
             .or(len_scale)
             .or(len_mat3x3)
             .or(len_relation)
+            .or(len_parent_frame)
+            .or(len_child_frame)
             .or(len_axis_length)
             .unwrap_or(0);
         self.columns(std::iter::repeat(1).take(len))
@@ -845,6 +916,58 @@ impl Transform3D {
         self
     }
 
+    /// The frame this transform maps into.
+    ///
+    /// If both a parent and a child frame are set, this transform connects these two named frames
+    /// instead of applying to this entity and its descendants.
+    #[inline]
+    pub fn with_parent_frame(
+        mut self,
+        parent_frame: impl Into<crate::components::ParentFrame>,
+    ) -> Self {
+        self.parent_frame = try_serialize_field(Self::descriptor_parent_frame(), [parent_frame]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ParentFrame`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_parent_frame`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_parent_frame(
+        mut self,
+        parent_frame: impl IntoIterator<Item = impl Into<crate::components::ParentFrame>>,
+    ) -> Self {
+        self.parent_frame = try_serialize_field(Self::descriptor_parent_frame(), parent_frame);
+        self
+    }
+
+    /// The frame this transform maps from.
+    ///
+    /// If both a parent and a child frame are set, this transform connects these two named frames
+    /// instead of applying to this entity and its descendants.
+    #[inline]
+    pub fn with_child_frame(
+        mut self,
+        child_frame: impl Into<crate::components::ChildFrame>,
+    ) -> Self {
+        self.child_frame = try_serialize_field(Self::descriptor_child_frame(), [child_frame]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ChildFrame`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_child_frame`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_child_frame(
+        mut self,
+        child_frame: impl IntoIterator<Item = impl Into<crate::components::ChildFrame>>,
+    ) -> Self {
+        self.child_frame = try_serialize_field(Self::descriptor_child_frame(), child_frame);
+        self
+    }
+
     /// Visual length of the 3 axes.
     ///
     /// The length is interpreted in the local coordinate system of the transform.
@@ -881,6 +1004,8 @@ impl ::re_byte_size::SizeBytes for Transform3D {
             + self.scale.heap_size_bytes()
             + self.mat3x3.heap_size_bytes()
             + self.relation.heap_size_bytes()
+            + self.parent_frame.heap_size_bytes()
+            + self.child_frame.heap_size_bytes()
             + self.axis_length.heap_size_bytes()
     }
 }
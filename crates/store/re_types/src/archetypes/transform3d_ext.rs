@@ -17,6 +17,8 @@ impl Transform3D {
         scale: None,
         mat3x3: None,
         relation: None,
+        parent_frame: None,
+        child_frame: None,
         axis_length: None,
     };
 
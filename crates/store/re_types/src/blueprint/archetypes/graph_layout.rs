@@ -0,0 +1,241 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Selects the algorithm that computes the layout of a graph view.
+///
+/// All algorithms except the force-directed one are deterministic.
+/// Nodes that are added over time are placed without moving the existing nodes, whenever possible.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct GraphLayout {
+    /// The layout algorithm.
+    ///
+    /// Defaults to a force-directed layout, which is configured by the force properties of the view.
+    pub algorithm: Option<SerializedComponentBatch>,
+
+    /// The direction of the edges in layered and tree layouts.
+    pub direction: Option<SerializedComponentBatch>,
+}
+
+impl GraphLayout {
+    /// Returns the [`ComponentDescriptor`] for [`Self::algorithm`].
+    #[inline]
+    pub fn descriptor_algorithm() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.GraphLayout".into()),
+            component_name: "rerun.blueprint.components.GraphLayoutAlgorithm".into(),
+            archetype_field_name: Some("algorithm".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::direction`].
+    #[inline]
+    pub fn descriptor_direction() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.GraphLayout".into()),
+            component_name: "rerun.blueprint.components.GraphLayoutDirection".into(),
+            archetype_field_name: Some("direction".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.GraphLayout".into()),
+            component_name: "rerun.blueprint.components.GraphLayoutIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [GraphLayout::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GraphLayout::descriptor_algorithm(),
+            GraphLayout::descriptor_direction(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GraphLayout::descriptor_indicator(),
+            GraphLayout::descriptor_algorithm(),
+            GraphLayout::descriptor_direction(),
+        ]
+    });
+
+impl GraphLayout {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`GraphLayout`] [`::re_types_core::Archetype`]
+pub type GraphLayoutIndicator = ::re_types_core::GenericIndicatorComponent<GraphLayout>;
+
+impl ::re_types_core::Archetype for GraphLayout {
+    type Indicator = GraphLayoutIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.GraphLayout".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Graph layout"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        GraphLayoutIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let algorithm = arrays_by_descr
+            .get(&Self::descriptor_algorithm())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_algorithm())
+            });
+        let direction = arrays_by_descr
+            .get(&Self::descriptor_direction())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_direction())
+            });
+        Ok(Self {
+            algorithm,
+            direction,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GraphLayout {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.algorithm.clone(),
+            self.direction.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GraphLayout {}
+
+impl GraphLayout {
+    /// Create a new `GraphLayout`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            algorithm: None,
+            direction: None,
+        }
+    }
+
+    /// Update only some specific fields of a `GraphLayout`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GraphLayout`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            algorithm: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::GraphLayoutAlgorithm::arrow_empty(),
+                Self::descriptor_algorithm(),
+            )),
+            direction: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::GraphLayoutDirection::arrow_empty(),
+                Self::descriptor_direction(),
+            )),
+        }
+    }
+
+    /// The layout algorithm.
+    ///
+    /// Defaults to a force-directed layout, which is configured by the force properties of the view.
+    #[inline]
+    pub fn with_algorithm(
+        mut self,
+        algorithm: impl Into<crate::blueprint::components::GraphLayoutAlgorithm>,
+    ) -> Self {
+        self.algorithm = try_serialize_field(Self::descriptor_algorithm(), [algorithm]);
+        self
+    }
+
+    /// The direction of the edges in layered and tree layouts.
+    #[inline]
+    pub fn with_direction(
+        mut self,
+        direction: impl Into<crate::blueprint::components::GraphLayoutDirection>,
+    ) -> Self {
+        self.direction = try_serialize_field(Self::descriptor_direction(), [direction]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GraphLayout {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.algorithm.heap_size_bytes() + self.direction.heap_size_bytes()
+    }
+}
@@ -25,8 +25,24 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 pub struct MapBackground {
     /// Map provider and style to use.
     ///
-    /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+    /// **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
     pub provider: Option<SerializedComponentBatch>,
+
+    /// URL template of the tile server used by the `CustomUrl` provider.
+    pub url_template: Option<SerializedComponentBatch>,
+
+    /// Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+    pub local_path: Option<SerializedComponentBatch>,
+
+    /// Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+    ///
+    /// For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+    pub attribution: Option<SerializedComponentBatch>,
+
+    /// Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+    ///
+    /// For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
+    pub max_zoom: Option<SerializedComponentBatch>,
 }
 
 impl MapBackground {
@@ -40,6 +56,46 @@ impl MapBackground {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::url_template`].
+    #[inline]
+    pub fn descriptor_url_template() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapBackground".into()),
+            component_name: "rerun.blueprint.components.TileUrlTemplate".into(),
+            archetype_field_name: Some("url_template".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::local_path`].
+    #[inline]
+    pub fn descriptor_local_path() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapBackground".into()),
+            component_name: "rerun.blueprint.components.TileSourcePath".into(),
+            archetype_field_name: Some("local_path".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::attribution`].
+    #[inline]
+    pub fn descriptor_attribution() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapBackground".into()),
+            component_name: "rerun.blueprint.components.TileAttribution".into(),
+            archetype_field_name: Some("attribution".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::max_zoom`].
+    #[inline]
+    pub fn descriptor_max_zoom() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapBackground".into()),
+            component_name: "rerun.blueprint.components.TileMaxZoom".into(),
+            archetype_field_name: Some("max_zoom".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for the associated indicator component.
     #[inline]
     pub fn descriptor_indicator() -> ComponentDescriptor {
@@ -57,20 +113,32 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
     once_cell::sync::Lazy::new(|| [MapBackground::descriptor_indicator()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
-    once_cell::sync::Lazy::new(|| [MapBackground::descriptor_provider()]);
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            MapBackground::descriptor_provider(),
+            MapBackground::descriptor_url_template(),
+            MapBackground::descriptor_local_path(),
+            MapBackground::descriptor_attribution(),
+            MapBackground::descriptor_max_zoom(),
+        ]
+    });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             MapBackground::descriptor_indicator(),
             MapBackground::descriptor_provider(),
+            MapBackground::descriptor_url_template(),
+            MapBackground::descriptor_local_path(),
+            MapBackground::descriptor_attribution(),
+            MapBackground::descriptor_max_zoom(),
         ]
     });
 
 impl MapBackground {
-    /// The total number of components in the archetype: 0 required, 1 recommended, 1 optional
-    pub const NUM_COMPONENTS: usize = 2usize;
+    /// The total number of components in the archetype: 0 required, 1 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 /// Indicator component for the [`MapBackground`] [`::re_types_core::Archetype`]
@@ -125,7 +193,31 @@ impl ::re_types_core::Archetype for MapBackground {
         let provider = arrays_by_descr
             .get(&Self::descriptor_provider())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_provider()));
-        Ok(Self { provider })
+        let url_template = arrays_by_descr
+            .get(&Self::descriptor_url_template())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_url_template())
+            });
+        let local_path = arrays_by_descr
+            .get(&Self::descriptor_local_path())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_local_path())
+            });
+        let attribution = arrays_by_descr
+            .get(&Self::descriptor_attribution())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_attribution())
+            });
+        let max_zoom = arrays_by_descr
+            .get(&Self::descriptor_max_zoom())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_max_zoom()));
+        Ok(Self {
+            provider,
+            url_template,
+            local_path,
+            attribution,
+            max_zoom,
+        })
     }
 }
 
@@ -133,10 +225,17 @@ impl ::re_types_core::AsComponents for MapBackground {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        [Some(Self::indicator()), self.provider.clone()]
-            .into_iter()
-            .flatten()
-            .collect()
+        [
+            Some(Self::indicator()),
+            self.provider.clone(),
+            self.url_template.clone(),
+            self.local_path.clone(),
+            self.attribution.clone(),
+            self.max_zoom.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -148,6 +247,10 @@ impl MapBackground {
     pub fn new(provider: impl Into<crate::blueprint::components::MapProvider>) -> Self {
         Self {
             provider: try_serialize_field(Self::descriptor_provider(), [provider]),
+            url_template: None,
+            local_path: None,
+            attribution: None,
+            max_zoom: None,
         }
     }
 
@@ -166,12 +269,28 @@ impl MapBackground {
                 crate::blueprint::components::MapProvider::arrow_empty(),
                 Self::descriptor_provider(),
             )),
+            url_template: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TileUrlTemplate::arrow_empty(),
+                Self::descriptor_url_template(),
+            )),
+            local_path: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TileSourcePath::arrow_empty(),
+                Self::descriptor_local_path(),
+            )),
+            attribution: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TileAttribution::arrow_empty(),
+                Self::descriptor_attribution(),
+            )),
+            max_zoom: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TileMaxZoom::arrow_empty(),
+                Self::descriptor_max_zoom(),
+            )),
         }
     }
 
     /// Map provider and style to use.
     ///
-    /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+    /// **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
     #[inline]
     pub fn with_provider(
         mut self,
@@ -180,11 +299,59 @@ impl MapBackground {
         self.provider = try_serialize_field(Self::descriptor_provider(), [provider]);
         self
     }
+
+    /// URL template of the tile server used by the `CustomUrl` provider.
+    #[inline]
+    pub fn with_url_template(
+        mut self,
+        url_template: impl Into<crate::blueprint::components::TileUrlTemplate>,
+    ) -> Self {
+        self.url_template = try_serialize_field(Self::descriptor_url_template(), [url_template]);
+        self
+    }
+
+    /// Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+    #[inline]
+    pub fn with_local_path(
+        mut self,
+        local_path: impl Into<crate::blueprint::components::TileSourcePath>,
+    ) -> Self {
+        self.local_path = try_serialize_field(Self::descriptor_local_path(), [local_path]);
+        self
+    }
+
+    /// Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+    ///
+    /// For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+    #[inline]
+    pub fn with_attribution(
+        mut self,
+        attribution: impl Into<crate::blueprint::components::TileAttribution>,
+    ) -> Self {
+        self.attribution = try_serialize_field(Self::descriptor_attribution(), [attribution]);
+        self
+    }
+
+    /// Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+    ///
+    /// For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
+    #[inline]
+    pub fn with_max_zoom(
+        mut self,
+        max_zoom: impl Into<crate::blueprint::components::TileMaxZoom>,
+    ) -> Self {
+        self.max_zoom = try_serialize_field(Self::descriptor_max_zoom(), [max_zoom]);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for MapBackground {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
         self.provider.heap_size_bytes()
+            + self.url_template.heap_size_bytes()
+            + self.local_path.heap_size_bytes()
+            + self.attribution.heap_size_bytes()
+            + self.max_zoom.heap_size_bytes()
     }
 }
@@ -9,16 +9,20 @@ mod force_collision_radius;
 mod force_link;
 mod force_many_body;
 mod force_position;
+mod graph_layout;
 mod line_grid3d;
 mod map_background;
 mod map_zoom;
 mod near_clip_plane;
 mod panel_blueprint;
 mod plot_legend;
+mod recording_retention;
 mod scalar_axis;
 mod tensor_scalar_mapping;
 mod tensor_slice_selection;
 mod tensor_view_fit;
+mod text_log_columns;
+mod text_log_filter;
 mod view_blueprint;
 mod view_contents;
 mod viewport_blueprint;
@@ -35,16 +39,20 @@ pub use self::force_collision_radius::ForceCollisionRadius;
 pub use self::force_link::ForceLink;
 pub use self::force_many_body::ForceManyBody;
 pub use self::force_position::ForcePosition;
+pub use self::graph_layout::GraphLayout;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
 pub use self::map_zoom::MapZoom;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_blueprint::PanelBlueprint;
 pub use self::plot_legend::PlotLegend;
+pub use self::recording_retention::RecordingRetention;
 pub use self::scalar_axis::ScalarAxis;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
 pub use self::tensor_slice_selection::TensorSliceSelection;
 pub use self::tensor_view_fit::TensorViewFit;
+pub use self::text_log_columns::TextLogColumns;
+pub use self::text_log_filter::TextLogFilter;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
 pub use self::viewport_blueprint::ViewportBlueprint;
@@ -0,0 +1,326 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_retention.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Which data the Viewer keeps around for the recordings of an application.
+///
+/// Data that falls outside of these rules is dropped as new data comes in, on top of whatever
+/// the memory limit requires. Each rule overrides the matching `RERUN_RETENTION_*` environment
+/// variable, and is itself overridden by the Viewer's `--retention-*` command line arguments.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct RecordingRetention {
+    /// The timeline that `time_window` applies to.
+    pub timeline: Option<SerializedComponentBatch>,
+
+    /// Only keep the data that was logged within this window of the most recent time on the timeline.
+    ///
+    /// Has no effect unless the timeline is set as well.
+    pub time_window: Option<SerializedComponentBatch>,
+
+    /// Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+    pub max_rows_per_entity: Option<SerializedComponentBatch>,
+
+    /// Never drop the data of these entities, nor of any of their descendants.
+    ///
+    /// This also applies to the garbage collection driven by the memory limit.
+    pub protected_entities: Option<SerializedComponentBatch>,
+}
+
+impl RecordingRetention {
+    /// Returns the [`ComponentDescriptor`] for [`Self::timeline`].
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingRetention".into()),
+            component_name: "rerun.blueprint.components.TimelineName".into(),
+            archetype_field_name: Some("timeline".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::time_window`].
+    #[inline]
+    pub fn descriptor_time_window() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingRetention".into()),
+            component_name: "rerun.blueprint.components.RetentionWindow".into(),
+            archetype_field_name: Some("time_window".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::max_rows_per_entity`].
+    #[inline]
+    pub fn descriptor_max_rows_per_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingRetention".into()),
+            component_name: "rerun.blueprint.components.MaxRowsPerEntity".into(),
+            archetype_field_name: Some("max_rows_per_entity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::protected_entities`].
+    #[inline]
+    pub fn descriptor_protected_entities() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingRetention".into()),
+            component_name: "rerun.components.EntityPath".into(),
+            archetype_field_name: Some("protected_entities".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingRetention".into()),
+            component_name: "rerun.blueprint.components.RecordingRetentionIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [RecordingRetention::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            RecordingRetention::descriptor_timeline(),
+            RecordingRetention::descriptor_time_window(),
+            RecordingRetention::descriptor_max_rows_per_entity(),
+            RecordingRetention::descriptor_protected_entities(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            RecordingRetention::descriptor_indicator(),
+            RecordingRetention::descriptor_timeline(),
+            RecordingRetention::descriptor_time_window(),
+            RecordingRetention::descriptor_max_rows_per_entity(),
+            RecordingRetention::descriptor_protected_entities(),
+        ]
+    });
+
+impl RecordingRetention {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`RecordingRetention`] [`::re_types_core::Archetype`]
+pub type RecordingRetentionIndicator =
+    ::re_types_core::GenericIndicatorComponent<RecordingRetention>;
+
+impl ::re_types_core::Archetype for RecordingRetention {
+    type Indicator = RecordingRetentionIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.RecordingRetention".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Recording retention"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        RecordingRetentionIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let timeline = arrays_by_descr
+            .get(&Self::descriptor_timeline())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_timeline()));
+        let time_window = arrays_by_descr
+            .get(&Self::descriptor_time_window())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_time_window())
+            });
+        let max_rows_per_entity = arrays_by_descr
+            .get(&Self::descriptor_max_rows_per_entity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_max_rows_per_entity())
+            });
+        let protected_entities = arrays_by_descr
+            .get(&Self::descriptor_protected_entities())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_protected_entities())
+            });
+        Ok(Self {
+            timeline,
+            time_window,
+            max_rows_per_entity,
+            protected_entities,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for RecordingRetention {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.timeline.clone(),
+            self.time_window.clone(),
+            self.max_rows_per_entity.clone(),
+            self.protected_entities.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for RecordingRetention {}
+
+impl RecordingRetention {
+    /// Create a new `RecordingRetention`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            timeline: None,
+            time_window: None,
+            max_rows_per_entity: None,
+            protected_entities: None,
+        }
+    }
+
+    /// Update only some specific fields of a `RecordingRetention`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `RecordingRetention`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            timeline: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimelineName::arrow_empty(),
+                Self::descriptor_timeline(),
+            )),
+            time_window: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::RetentionWindow::arrow_empty(),
+                Self::descriptor_time_window(),
+            )),
+            max_rows_per_entity: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::MaxRowsPerEntity::arrow_empty(),
+                Self::descriptor_max_rows_per_entity(),
+            )),
+            protected_entities: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_protected_entities(),
+            )),
+        }
+    }
+
+    /// The timeline that `time_window` applies to.
+    #[inline]
+    pub fn with_timeline(
+        mut self,
+        timeline: impl Into<crate::blueprint::components::TimelineName>,
+    ) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), [timeline]);
+        self
+    }
+
+    /// Only keep the data that was logged within this window of the most recent time on the timeline.
+    ///
+    /// Has no effect unless the timeline is set as well.
+    #[inline]
+    pub fn with_time_window(
+        mut self,
+        time_window: impl Into<crate::blueprint::components::RetentionWindow>,
+    ) -> Self {
+        self.time_window = try_serialize_field(Self::descriptor_time_window(), [time_window]);
+        self
+    }
+
+    /// Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+    #[inline]
+    pub fn with_max_rows_per_entity(
+        mut self,
+        max_rows_per_entity: impl Into<crate::blueprint::components::MaxRowsPerEntity>,
+    ) -> Self {
+        self.max_rows_per_entity = try_serialize_field(
+            Self::descriptor_max_rows_per_entity(),
+            [max_rows_per_entity],
+        );
+        self
+    }
+
+    /// Never drop the data of these entities, nor of any of their descendants.
+    ///
+    /// This also applies to the garbage collection driven by the memory limit.
+    #[inline]
+    pub fn with_protected_entities(
+        mut self,
+        protected_entities: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.protected_entities =
+            try_serialize_field(Self::descriptor_protected_entities(), protected_entities);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for RecordingRetention {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.timeline.heap_size_bytes()
+            + self.time_window.heap_size_bytes()
+            + self.max_rows_per_entity.heap_size_bytes()
+            + self.protected_entities.heap_size_bytes()
+    }
+}
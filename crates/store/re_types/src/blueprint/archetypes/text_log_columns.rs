@@ -0,0 +1,197 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configures the columns of a text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TextLogColumns {
+    /// The columns of the view, in the order they are shown.
+    ///
+    /// Timelines that are not listed are shown before all other columns, any other column that is not listed is shown last.
+    /// If unset, all timelines are shown, followed by the entity path, the log level, and the body.
+    pub text_log_columns: Option<SerializedComponentBatch>,
+}
+
+impl TextLogColumns {
+    /// Returns the [`ComponentDescriptor`] for [`Self::text_log_columns`].
+    #[inline]
+    pub fn descriptor_text_log_columns() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogColumns".into()),
+            component_name: "rerun.blueprint.components.TextLogColumn".into(),
+            archetype_field_name: Some("text_log_columns".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogColumns".into()),
+            component_name: "rerun.blueprint.components.TextLogColumnsIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TextLogColumns::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TextLogColumns::descriptor_text_log_columns()]);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogColumns::descriptor_indicator(),
+            TextLogColumns::descriptor_text_log_columns(),
+        ]
+    });
+
+impl TextLogColumns {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+/// Indicator component for the [`TextLogColumns`] [`::re_types_core::Archetype`]
+pub type TextLogColumnsIndicator = ::re_types_core::GenericIndicatorComponent<TextLogColumns>;
+
+impl ::re_types_core::Archetype for TextLogColumns {
+    type Indicator = TextLogColumnsIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogColumns".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log columns"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TextLogColumnsIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let text_log_columns = arrays_by_descr
+            .get(&Self::descriptor_text_log_columns())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_text_log_columns())
+            });
+        Ok(Self { text_log_columns })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogColumns {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [Some(Self::indicator()), self.text_log_columns.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogColumns {}
+
+impl TextLogColumns {
+    /// Create a new `TextLogColumns`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            text_log_columns: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogColumns`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogColumns`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            text_log_columns: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TextLogColumn::arrow_empty(),
+                Self::descriptor_text_log_columns(),
+            )),
+        }
+    }
+
+    /// The columns of the view, in the order they are shown.
+    ///
+    /// Timelines that are not listed are shown before all other columns, any other column that is not listed is shown last.
+    /// If unset, all timelines are shown, followed by the entity path, the log level, and the body.
+    #[inline]
+    pub fn with_text_log_columns(
+        mut self,
+        text_log_columns: impl IntoIterator<
+            Item = impl Into<crate::blueprint::components::TextLogColumn>,
+        >,
+    ) -> Self {
+        self.text_log_columns =
+            try_serialize_field(Self::descriptor_text_log_columns(), text_log_columns);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogColumns {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.text_log_columns.heap_size_bytes()
+    }
+}
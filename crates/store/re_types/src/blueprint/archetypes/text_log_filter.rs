@@ -0,0 +1,323 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configures which entries are shown in a text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TextLogFilter {
+    /// Only entries whose body matches this query are shown.
+    ///
+    /// If unset or empty, entries are not filtered by their body.
+    pub search: Option<SerializedComponentBatch>,
+
+    /// How the search query is matched against the entry bodies.
+    ///
+    /// Defaults to plain text matching.
+    pub search_mode: Option<SerializedComponentBatch>,
+
+    /// Whether the search distinguishes between upper and lower case.
+    ///
+    /// False by default.
+    pub case_sensitive: Option<SerializedComponentBatch>,
+
+    /// Entries with any of these log levels are hidden.
+    ///
+    /// Entries without a log level are always shown.
+    pub hidden_levels: Option<SerializedComponentBatch>,
+}
+
+impl TextLogFilter {
+    /// Returns the [`ComponentDescriptor`] for [`Self::search`].
+    #[inline]
+    pub fn descriptor_search() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component_name: "rerun.components.Text".into(),
+            archetype_field_name: Some("search".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::search_mode`].
+    #[inline]
+    pub fn descriptor_search_mode() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component_name: "rerun.blueprint.components.TextSearchMode".into(),
+            archetype_field_name: Some("search_mode".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::case_sensitive`].
+    #[inline]
+    pub fn descriptor_case_sensitive() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component_name: "rerun.blueprint.components.CaseSensitive".into(),
+            archetype_field_name: Some("case_sensitive".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::hidden_levels`].
+    #[inline]
+    pub fn descriptor_hidden_levels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component_name: "rerun.components.TextLogLevel".into(),
+            archetype_field_name: Some("hidden_levels".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component_name: "rerun.blueprint.components.TextLogFilterIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TextLogFilter::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogFilter::descriptor_search(),
+            TextLogFilter::descriptor_search_mode(),
+            TextLogFilter::descriptor_case_sensitive(),
+            TextLogFilter::descriptor_hidden_levels(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogFilter::descriptor_indicator(),
+            TextLogFilter::descriptor_search(),
+            TextLogFilter::descriptor_search_mode(),
+            TextLogFilter::descriptor_case_sensitive(),
+            TextLogFilter::descriptor_hidden_levels(),
+        ]
+    });
+
+impl TextLogFilter {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`TextLogFilter`] [`::re_types_core::Archetype`]
+pub type TextLogFilterIndicator = ::re_types_core::GenericIndicatorComponent<TextLogFilter>;
+
+impl ::re_types_core::Archetype for TextLogFilter {
+    type Indicator = TextLogFilterIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogFilter".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log filter"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TextLogFilterIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let search = arrays_by_descr
+            .get(&Self::descriptor_search())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_search()));
+        let search_mode = arrays_by_descr
+            .get(&Self::descriptor_search_mode())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_search_mode())
+            });
+        let case_sensitive = arrays_by_descr
+            .get(&Self::descriptor_case_sensitive())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_case_sensitive())
+            });
+        let hidden_levels = arrays_by_descr
+            .get(&Self::descriptor_hidden_levels())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_hidden_levels())
+            });
+        Ok(Self {
+            search,
+            search_mode,
+            case_sensitive,
+            hidden_levels,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogFilter {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.search.clone(),
+            self.search_mode.clone(),
+            self.case_sensitive.clone(),
+            self.hidden_levels.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogFilter {}
+
+impl TextLogFilter {
+    /// Create a new `TextLogFilter`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            search: None,
+            search_mode: None,
+            case_sensitive: None,
+            hidden_levels: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogFilter`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogFilter`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            search: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_search(),
+            )),
+            search_mode: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TextSearchMode::arrow_empty(),
+                Self::descriptor_search_mode(),
+            )),
+            case_sensitive: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::CaseSensitive::arrow_empty(),
+                Self::descriptor_case_sensitive(),
+            )),
+            hidden_levels: Some(SerializedComponentBatch::new(
+                crate::components::TextLogLevel::arrow_empty(),
+                Self::descriptor_hidden_levels(),
+            )),
+        }
+    }
+
+    /// Only entries whose body matches this query are shown.
+    ///
+    /// If unset or empty, entries are not filtered by their body.
+    #[inline]
+    pub fn with_search(mut self, search: impl Into<crate::components::Text>) -> Self {
+        self.search = try_serialize_field(Self::descriptor_search(), [search]);
+        self
+    }
+
+    /// How the search query is matched against the entry bodies.
+    ///
+    /// Defaults to plain text matching.
+    #[inline]
+    pub fn with_search_mode(
+        mut self,
+        search_mode: impl Into<crate::blueprint::components::TextSearchMode>,
+    ) -> Self {
+        self.search_mode = try_serialize_field(Self::descriptor_search_mode(), [search_mode]);
+        self
+    }
+
+    /// Whether the search distinguishes between upper and lower case.
+    ///
+    /// False by default.
+    #[inline]
+    pub fn with_case_sensitive(
+        mut self,
+        case_sensitive: impl Into<crate::blueprint::components::CaseSensitive>,
+    ) -> Self {
+        self.case_sensitive =
+            try_serialize_field(Self::descriptor_case_sensitive(), [case_sensitive]);
+        self
+    }
+
+    /// Entries with any of these log levels are hidden.
+    ///
+    /// Entries without a log level are always shown.
+    #[inline]
+    pub fn with_hidden_levels(
+        mut self,
+        hidden_levels: impl IntoIterator<Item = impl Into<crate::components::TextLogLevel>>,
+    ) -> Self {
+        self.hidden_levels = try_serialize_field(Self::descriptor_hidden_levels(), hidden_levels);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogFilter {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.search.heap_size_bytes()
+            + self.search_mode.heap_size_bytes()
+            + self.case_sensitive.heap_size_bytes()
+            + self.hidden_levels.heap_size_bytes()
+    }
+}
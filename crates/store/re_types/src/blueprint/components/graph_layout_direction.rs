@@ -0,0 +1,171 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/graph_layout_direction.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The direction in which the edges of a layered or tree graph layout point.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum GraphLayoutDirection {
+    /// Root nodes are at the top, edges point downwards.
+    #[default]
+    TopToBottom = 1,
+
+    /// Root nodes are at the bottom, edges point upwards.
+    BottomToTop = 2,
+
+    /// Root nodes are on the left, edges point to the right.
+    LeftToRight = 3,
+
+    /// Root nodes are on the right, edges point to the left.
+    RightToLeft = 4,
+}
+
+impl ::re_types_core::Component for GraphLayoutDirection {
+    #[inline]
+    fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor::new("rerun.blueprint.components.GraphLayoutDirection")
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(GraphLayoutDirection);
+
+impl ::re_types_core::Loggable for GraphLayoutDirection {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.blueprint.components.GraphLayoutDirection#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::TopToBottom)),
+                Some(2) => Ok(Some(Self::BottomToTop)),
+                Some(3) => Ok(Some(Self::LeftToRight)),
+                Some(4) => Ok(Some(Self::RightToLeft)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.blueprint.components.GraphLayoutDirection")?)
+    }
+}
+
+impl std::fmt::Display for GraphLayoutDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TopToBottom => write!(f, "TopToBottom"),
+            Self::BottomToTop => write!(f, "BottomToTop"),
+            Self::LeftToRight => write!(f, "LeftToRight"),
+            Self::RightToLeft => write!(f, "RightToLeft"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for GraphLayoutDirection {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[
+            Self::TopToBottom,
+            Self::BottomToTop,
+            Self::LeftToRight,
+            Self::RightToLeft,
+        ]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::TopToBottom => "Root nodes are at the top, edges point downwards.",
+            Self::BottomToTop => "Root nodes are at the bottom, edges point upwards.",
+            Self::LeftToRight => "Root nodes are on the left, edges point to the right.",
+            Self::RightToLeft => "Root nodes are on the right, edges point to the left.",
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GraphLayoutDirection {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
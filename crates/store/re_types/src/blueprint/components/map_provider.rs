@@ -35,6 +35,14 @@ pub enum MapProvider {
 
     /// Mapbox Satellite is a satellite map designed by Mapbox.
     MapboxSatellite = 4,
+
+    /// A custom raster tile server, configured with a URL template.
+    CustomUrl = 5,
+
+    /// Raster tiles read from an `MBTiles` file or a tile directory on the local file system.
+    ///
+    /// Doesn't require an internet connection. Not available on the web.
+    LocalTiles = 6,
 }
 
 impl ::re_types_core::Component for MapProvider {
@@ -113,6 +121,8 @@ impl ::re_types_core::Loggable for MapProvider {
                 Some(2) => Ok(Some(Self::MapboxStreets)),
                 Some(3) => Ok(Some(Self::MapboxDark)),
                 Some(4) => Ok(Some(Self::MapboxSatellite)),
+                Some(5) => Ok(Some(Self::CustomUrl)),
+                Some(6) => Ok(Some(Self::LocalTiles)),
                 None => Ok(None),
                 Some(invalid) => Err(DeserializationError::missing_union_arm(
                     Self::arrow_datatype(),
@@ -132,6 +142,8 @@ impl std::fmt::Display for MapProvider {
             Self::MapboxStreets => write!(f, "MapboxStreets"),
             Self::MapboxDark => write!(f, "MapboxDark"),
             Self::MapboxSatellite => write!(f, "MapboxSatellite"),
+            Self::CustomUrl => write!(f, "CustomUrl"),
+            Self::LocalTiles => write!(f, "LocalTiles"),
         }
     }
 }
@@ -144,6 +156,8 @@ impl ::re_types_core::reflection::Enum for MapProvider {
             Self::MapboxStreets,
             Self::MapboxDark,
             Self::MapboxSatellite,
+            Self::CustomUrl,
+            Self::LocalTiles,
         ]
     }
 
@@ -151,9 +165,19 @@ impl ::re_types_core::reflection::Enum for MapProvider {
     fn docstring_md(self) -> &'static str {
         match self {
             Self::OpenStreetMap => "`OpenStreetMap` is the default map provider.",
-            Self::MapboxStreets => "Mapbox Streets is a minimalistic map designed by Mapbox.",
+            Self::MapboxStreets => {
+                "Mapbox Streets is a minimalistic map designed by Mapbox."
+            }
             Self::MapboxDark => "Mapbox Dark is a dark-themed map designed by Mapbox.",
-            Self::MapboxSatellite => "Mapbox Satellite is a satellite map designed by Mapbox.",
+            Self::MapboxSatellite => {
+                "Mapbox Satellite is a satellite map designed by Mapbox."
+            }
+            Self::CustomUrl => {
+                "A custom raster tile server, configured with a URL template."
+            }
+            Self::LocalTiles => {
+                "Raster tiles read from an `MBTiles` file or a tile directory on the local file system.\n\nDoesn't require an internet connection. Not available on the web."
+            }
         }
     }
 }
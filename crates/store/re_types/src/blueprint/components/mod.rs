@@ -6,6 +6,7 @@ mod auto_layout;
 mod auto_layout_ext;
 mod auto_views;
 mod background_kind;
+mod case_sensitive;
 mod column_share;
 mod component_column_selector;
 mod component_column_selector_ext;
@@ -21,24 +22,35 @@ mod filter_is_not_null_ext;
 mod force_distance;
 mod force_iterations;
 mod force_strength;
+mod graph_layout_algorithm;
+mod graph_layout_direction;
 mod grid_columns;
 mod grid_spacing;
 mod grid_spacing_ext;
 mod included_content;
 mod lock_range_during_zoom;
 mod map_provider;
+mod max_rows_per_entity;
 mod near_clip_plane;
 mod near_clip_plane_ext;
 mod panel_state;
 mod panel_state_ext;
 mod query_expression;
+mod retention_window;
 mod root_container;
 mod row_share;
 mod selected_columns;
 mod tensor_dimension_index_slider;
 mod tensor_dimension_index_slider_ext;
+mod text_log_column;
+mod text_search_mode;
+mod tile_attribution;
+mod tile_max_zoom;
+mod tile_source_path;
+mod tile_url_template;
 mod timeline_name;
 mod timeline_name_ext;
+mod transform_interpolation;
 mod view_class;
 mod view_class_ext;
 mod view_fit;
@@ -58,6 +70,7 @@ pub use self::apply_latest_at::ApplyLatestAt;
 pub use self::auto_layout::AutoLayout;
 pub use self::auto_views::AutoViews;
 pub use self::background_kind::BackgroundKind;
+pub use self::case_sensitive::CaseSensitive;
 pub use self::column_share::ColumnShare;
 pub use self::component_column_selector::ComponentColumnSelector;
 pub use self::container_kind::ContainerKind;
@@ -68,19 +81,30 @@ pub use self::filter_is_not_null::FilterIsNotNull;
 pub use self::force_distance::ForceDistance;
 pub use self::force_iterations::ForceIterations;
 pub use self::force_strength::ForceStrength;
+pub use self::graph_layout_algorithm::GraphLayoutAlgorithm;
+pub use self::graph_layout_direction::GraphLayoutDirection;
 pub use self::grid_columns::GridColumns;
 pub use self::grid_spacing::GridSpacing;
 pub use self::included_content::IncludedContent;
 pub use self::lock_range_during_zoom::LockRangeDuringZoom;
 pub use self::map_provider::MapProvider;
+pub use self::max_rows_per_entity::MaxRowsPerEntity;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_state::PanelState;
 pub use self::query_expression::QueryExpression;
+pub use self::retention_window::RetentionWindow;
 pub use self::root_container::RootContainer;
 pub use self::row_share::RowShare;
 pub use self::selected_columns::SelectedColumns;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::text_log_column::TextLogColumn;
+pub use self::text_search_mode::TextSearchMode;
+pub use self::tile_attribution::TileAttribution;
+pub use self::tile_max_zoom::TileMaxZoom;
+pub use self::tile_source_path::TileSourcePath;
+pub use self::tile_url_template::TileUrlTemplate;
 pub use self::timeline_name::TimelineName;
+pub use self::transform_interpolation::TransformInterpolation;
 pub use self::view_class::ViewClass;
 pub use self::view_fit::ViewFit;
 pub use self::view_maximized::ViewMaximized;
@@ -0,0 +1,107 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_log_column.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: A column of the text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default, PartialEq)]
+#[repr(transparent)]
+pub struct TextLogColumn(pub crate::blueprint::datatypes::TextLogColumn);
+
+impl ::re_types_core::Component for TextLogColumn {
+    #[inline]
+    fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor::new("rerun.blueprint.components.TextLogColumn")
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(TextLogColumn);
+
+impl ::re_types_core::Loggable for TextLogColumn {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        crate::blueprint::datatypes::TextLogColumn::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        crate::blueprint::datatypes::TextLogColumn::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::blueprint::datatypes::TextLogColumn::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+}
+
+impl<T: Into<crate::blueprint::datatypes::TextLogColumn>> From<T> for TextLogColumn {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::blueprint::datatypes::TextLogColumn> for TextLogColumn {
+    #[inline]
+    fn borrow(&self) -> &crate::blueprint::datatypes::TextLogColumn {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for TextLogColumn {
+    type Target = crate::blueprint::datatypes::TextLogColumn;
+
+    #[inline]
+    fn deref(&self) -> &crate::blueprint::datatypes::TextLogColumn {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for TextLogColumn {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::blueprint::datatypes::TextLogColumn {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogColumn {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::datatypes::TextLogColumn>::is_pod()
+    }
+}
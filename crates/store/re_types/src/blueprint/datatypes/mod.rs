@@ -6,9 +6,13 @@ mod filter_by_range;
 mod filter_is_not_null;
 mod selected_columns;
 mod tensor_dimension_index_slider;
+mod text_log_column;
+mod text_log_column_kind;
 
 pub use self::component_column_selector::ComponentColumnSelector;
 pub use self::filter_by_range::FilterByRange;
 pub use self::filter_is_not_null::FilterIsNotNull;
 pub use self::selected_columns::SelectedColumns;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::text_log_column::TextLogColumn;
+pub use self::text_log_column_kind::TextLogColumnKind;
@@ -0,0 +1,388 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Datatype**: A column of the text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLogColumn {
+    /// What is shown in this column.
+    pub kind: crate::blueprint::datatypes::TextLogColumnKind,
+
+    /// The timeline shown by this column.
+    ///
+    /// Only used for columns of kind `Timeline`, empty otherwise.
+    pub timeline: crate::datatypes::Utf8,
+
+    /// Whether the column is shown.
+    pub visible: crate::datatypes::Bool,
+
+    /// The width of the column in ui points.
+    ///
+    /// If unset, the column is sized automatically.
+    pub width: Option<crate::datatypes::Float32>,
+}
+
+::re_types_core::macros::impl_into_cow!(TextLogColumn);
+
+impl ::re_types_core::Loggable for TextLogColumn {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::Struct(Fields::from(vec![
+            Field::new(
+                "kind",
+                <crate::blueprint::datatypes::TextLogColumnKind>::arrow_datatype(),
+                false,
+            ),
+            Field::new(
+                "timeline",
+                <crate::datatypes::Utf8>::arrow_datatype(),
+                false,
+            ),
+            Field::new("visible", <crate::datatypes::Bool>::arrow_datatype(), false),
+            Field::new("width", <crate::datatypes::Float32>::arrow_datatype(), true),
+        ]))
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let fields = Fields::from(vec![
+                Field::new(
+                    "kind",
+                    <crate::blueprint::datatypes::TextLogColumnKind>::arrow_datatype(),
+                    false,
+                ),
+                Field::new(
+                    "timeline",
+                    <crate::datatypes::Utf8>::arrow_datatype(),
+                    false,
+                ),
+                Field::new("visible", <crate::datatypes::Bool>::arrow_datatype(), false),
+                Field::new("width", <crate::datatypes::Float32>::arrow_datatype(), true),
+            ]);
+            let (somes, data): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(StructArray::new(
+                fields,
+                vec![
+                    {
+                        let (somes, kind): (Vec<_>, Vec<_>) = data
+                            .iter()
+                            .map(|datum| {
+                                let datum = datum.as_ref().map(|datum| datum.kind.clone());
+                                (datum.is_some(), datum)
+                            })
+                            .unzip();
+                        let kind_validity: Option<arrow::buffer::NullBuffer> = {
+                            let any_nones = somes.iter().any(|some| !*some);
+                            any_nones.then(|| somes.into())
+                        };
+                        {
+                            _ = kind_validity;
+                            crate::blueprint::datatypes::TextLogColumnKind::to_arrow_opt(kind)?
+                        }
+                    },
+                    {
+                        let (somes, timeline): (Vec<_>, Vec<_>) = data
+                            .iter()
+                            .map(|datum| {
+                                let datum = datum.as_ref().map(|datum| datum.timeline.clone());
+                                (datum.is_some(), datum)
+                            })
+                            .unzip();
+                        let timeline_validity: Option<arrow::buffer::NullBuffer> = {
+                            let any_nones = somes.iter().any(|some| !*some);
+                            any_nones.then(|| somes.into())
+                        };
+                        {
+                            let offsets = arrow::buffer::OffsetBuffer::<i32>::from_lengths(
+                                timeline.iter().map(|opt| {
+                                    opt.as_ref().map(|datum| datum.0.len()).unwrap_or_default()
+                                }),
+                            );
+                            #[allow(clippy::unwrap_used)]
+                            let capacity = offsets.last().copied().unwrap() as usize;
+                            let mut buffer_builder =
+                                arrow::array::builder::BufferBuilder::<u8>::new(capacity);
+                            for data in timeline.iter().flatten() {
+                                buffer_builder.append_slice(data.0.as_bytes());
+                            }
+                            let inner_data: arrow::buffer::Buffer = buffer_builder.finish();
+
+                            #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                            as_array_ref(unsafe {
+                                StringArray::new_unchecked(offsets, inner_data, timeline_validity)
+                            })
+                        }
+                    },
+                    {
+                        let (somes, visible): (Vec<_>, Vec<_>) = data
+                            .iter()
+                            .map(|datum| {
+                                let datum = datum.as_ref().map(|datum| datum.visible.clone());
+                                (datum.is_some(), datum)
+                            })
+                            .unzip();
+                        let visible_validity: Option<arrow::buffer::NullBuffer> = {
+                            let any_nones = somes.iter().any(|some| !*some);
+                            any_nones.then(|| somes.into())
+                        };
+                        as_array_ref(BooleanArray::new(
+                            BooleanBuffer::from(
+                                visible
+                                    .into_iter()
+                                    .map(|datum| datum.map(|datum| datum.0).unwrap_or_default())
+                                    .collect::<Vec<_>>(),
+                            ),
+                            visible_validity,
+                        ))
+                    },
+                    {
+                        let (somes, width): (Vec<_>, Vec<_>) = data
+                            .iter()
+                            .map(|datum| {
+                                let datum =
+                                    datum.as_ref().map(|datum| datum.width.clone()).flatten();
+                                (datum.is_some(), datum)
+                            })
+                            .unzip();
+                        let width_validity: Option<arrow::buffer::NullBuffer> = {
+                            let any_nones = somes.iter().any(|some| !*some);
+                            any_nones.then(|| somes.into())
+                        };
+                        as_array_ref(PrimitiveArray::<Float32Type>::new(
+                            ScalarBuffer::from(
+                                width
+                                    .into_iter()
+                                    .map(|datum| datum.map(|datum| datum.0).unwrap_or_default())
+                                    .collect::<Vec<_>>(),
+                            ),
+                            width_validity,
+                        ))
+                    },
+                ],
+                validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let arrow_data = arrow_data
+                .as_any()
+                .downcast_ref::<arrow::array::StructArray>()
+                .ok_or_else(|| {
+                    let expected = Self::arrow_datatype();
+                    let actual = arrow_data.data_type().clone();
+                    DeserializationError::datatype_mismatch(expected, actual)
+                })
+                .with_context("rerun.blueprint.datatypes.TextLogColumn")?;
+            if arrow_data.is_empty() {
+                Vec::new()
+            } else {
+                let (arrow_data_fields, arrow_data_arrays) =
+                    (arrow_data.fields(), arrow_data.columns());
+                let arrays_by_name: ::std::collections::HashMap<_, _> = arrow_data_fields
+                    .iter()
+                    .map(|field| field.name().as_str())
+                    .zip(arrow_data_arrays)
+                    .collect();
+                let kind = {
+                    if !arrays_by_name.contains_key("kind") {
+                        return Err(DeserializationError::missing_struct_field(
+                            Self::arrow_datatype(),
+                            "kind",
+                        ))
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn");
+                    }
+                    let arrow_data = &**arrays_by_name["kind"];
+                    crate::blueprint::datatypes::TextLogColumnKind::from_arrow_opt(arrow_data)
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn#kind")?
+                        .into_iter()
+                };
+                let timeline = {
+                    if !arrays_by_name.contains_key("timeline") {
+                        return Err(DeserializationError::missing_struct_field(
+                            Self::arrow_datatype(),
+                            "timeline",
+                        ))
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn");
+                    }
+                    let arrow_data = &**arrays_by_name["timeline"];
+                    {
+                        let arrow_data = arrow_data
+                            .as_any()
+                            .downcast_ref::<StringArray>()
+                            .ok_or_else(|| {
+                                let expected = DataType::Utf8;
+                                let actual = arrow_data.data_type().clone();
+                                DeserializationError::datatype_mismatch(expected, actual)
+                            })
+                            .with_context("rerun.blueprint.datatypes.TextLogColumn#timeline")?;
+                        let arrow_data_buf = arrow_data.values();
+                        let offsets = arrow_data.offsets();
+                        ZipValidity::new_with_validity(offsets.windows(2), arrow_data.nulls())
+                            .map(|elem| {
+                                elem.map(|window| {
+                                    let start = window[0] as usize;
+                                    let end = window[1] as usize;
+                                    let len = end - start;
+                                    if arrow_data_buf.len() < end {
+                                        return Err(DeserializationError::offset_slice_oob(
+                                            (start, end),
+                                            arrow_data_buf.len(),
+                                        ));
+                                    }
+
+                                    #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                                    let data = arrow_data_buf.slice_with_length(start, len);
+                                    Ok(data)
+                                })
+                                .transpose()
+                            })
+                            .map(|res_or_opt| {
+                                res_or_opt.map(|res_or_opt| {
+                                    res_or_opt.map(|v| {
+                                        crate::datatypes::Utf8(::re_types_core::ArrowString::from(
+                                            v,
+                                        ))
+                                    })
+                                })
+                            })
+                            .collect::<DeserializationResult<Vec<Option<_>>>>()
+                            .with_context("rerun.blueprint.datatypes.TextLogColumn#timeline")?
+                            .into_iter()
+                    }
+                };
+                let visible = {
+                    if !arrays_by_name.contains_key("visible") {
+                        return Err(DeserializationError::missing_struct_field(
+                            Self::arrow_datatype(),
+                            "visible",
+                        ))
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn");
+                    }
+                    let arrow_data = &**arrays_by_name["visible"];
+                    arrow_data
+                        .as_any()
+                        .downcast_ref::<BooleanArray>()
+                        .ok_or_else(|| {
+                            let expected = DataType::Boolean;
+                            let actual = arrow_data.data_type().clone();
+                            DeserializationError::datatype_mismatch(expected, actual)
+                        })
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn#visible")?
+                        .into_iter()
+                        .map(|res_or_opt| res_or_opt.map(crate::datatypes::Bool))
+                };
+                let width = {
+                    if !arrays_by_name.contains_key("width") {
+                        return Err(DeserializationError::missing_struct_field(
+                            Self::arrow_datatype(),
+                            "width",
+                        ))
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn");
+                    }
+                    let arrow_data = &**arrays_by_name["width"];
+                    arrow_data
+                        .as_any()
+                        .downcast_ref::<Float32Array>()
+                        .ok_or_else(|| {
+                            let expected = DataType::Float32;
+                            let actual = arrow_data.data_type().clone();
+                            DeserializationError::datatype_mismatch(expected, actual)
+                        })
+                        .with_context("rerun.blueprint.datatypes.TextLogColumn#width")?
+                        .into_iter()
+                        .map(|res_or_opt| res_or_opt.map(crate::datatypes::Float32))
+                };
+                ZipValidity::new_with_validity(
+                    ::itertools::izip!(kind, timeline, visible, width),
+                    arrow_data.nulls(),
+                )
+                .map(|opt| {
+                    opt.map(|(kind, timeline, visible, width)| {
+                        Ok(Self {
+                            kind: kind
+                                .ok_or_else(DeserializationError::missing_data)
+                                .with_context("rerun.blueprint.datatypes.TextLogColumn#kind")?,
+                            timeline: timeline
+                                .ok_or_else(DeserializationError::missing_data)
+                                .with_context("rerun.blueprint.datatypes.TextLogColumn#timeline")?,
+                            visible: visible
+                                .ok_or_else(DeserializationError::missing_data)
+                                .with_context("rerun.blueprint.datatypes.TextLogColumn#visible")?,
+                            width,
+                        })
+                    })
+                    .transpose()
+                })
+                .collect::<DeserializationResult<Vec<_>>>()
+                .with_context("rerun.blueprint.datatypes.TextLogColumn")?
+            }
+        })
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogColumn {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.kind.heap_size_bytes()
+            + self.timeline.heap_size_bytes()
+            + self.visible.heap_size_bytes()
+            + self.width.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::datatypes::TextLogColumnKind>::is_pod()
+            && <crate::datatypes::Utf8>::is_pod()
+            && <crate::datatypes::Bool>::is_pod()
+            && <Option<crate::datatypes::Float32>>::is_pod()
+    }
+}
@@ -28,6 +28,9 @@ pub struct GraphView {
     /// Somethings outside of these bounds may also be visible due to letterboxing.
     pub visual_bounds: crate::blueprint::archetypes::VisualBounds2D,
 
+    /// The algorithm that computes the position of the nodes.
+    pub layout: crate::blueprint::archetypes::GraphLayout,
+
     /// Allows to control the interaction between two nodes connected by an edge.
     pub force_link: crate::blueprint::archetypes::ForceLink,
 
@@ -55,6 +58,7 @@ impl ::re_byte_size::SizeBytes for GraphView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
         self.visual_bounds.heap_size_bytes()
+            + self.layout.heap_size_bytes()
             + self.force_link.heap_size_bytes()
             + self.force_many_body.heap_size_bytes()
             + self.force_position.heap_size_bytes()
@@ -65,6 +69,7 @@ impl ::re_byte_size::SizeBytes for GraphView {
     #[inline]
     fn is_pod() -> bool {
         <crate::blueprint::archetypes::VisualBounds2D>::is_pod()
+            && <crate::blueprint::archetypes::GraphLayout>::is_pod()
             && <crate::blueprint::archetypes::ForceLink>::is_pod()
             && <crate::blueprint::archetypes::ForceManyBody>::is_pod()
             && <crate::blueprint::archetypes::ForcePosition>::is_pod()
@@ -22,7 +22,13 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 ///
 /// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
 #[derive(Clone, Debug)]
-pub struct TextLogView {}
+pub struct TextLogView {
+    /// Configures which entries are shown.
+    pub filter: crate::blueprint::archetypes::TextLogFilter,
+
+    /// Configures the order, visibility and width of the columns.
+    pub columns: crate::blueprint::archetypes::TextLogColumns,
+}
 
 impl ::re_types_core::View for TextLogView {
     #[inline]
@@ -34,11 +40,12 @@ impl ::re_types_core::View for TextLogView {
 impl ::re_byte_size::SizeBytes for TextLogView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        0
+        self.filter.heap_size_bytes() + self.columns.heap_size_bytes()
     }
 
     #[inline]
     fn is_pod() -> bool {
-        true
+        <crate::blueprint::archetypes::TextLogFilter>::is_pod()
+            && <crate::blueprint::archetypes::TextLogColumns>::is_pod()
     }
 }
@@ -0,0 +1,9 @@
+use super::CoordinateFrame;
+
+impl CoordinateFrame {
+    /// Returns the name of the frame.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
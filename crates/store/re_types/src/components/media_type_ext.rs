@@ -53,6 +53,16 @@ impl MediaType {
     ///
     /// <https://www.iana.org/assignments/media-types/video/mp4>
     pub const MP4: &'static str = "video/mp4";
+
+    /// [Matroska video](https://en.wikipedia.org/wiki/Matroska): `video/x-matroska`.
+    ///
+    /// <https://www.matroska.org/technical/specs/notes.html#MIME>
+    pub const MKV: &'static str = "video/x-matroska";
+
+    /// [WebM video](https://en.wikipedia.org/wiki/WebM): `video/webm`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/video/webm>
+    pub const WEBM: &'static str = "video/webm";
 }
 
 impl MediaType {
@@ -118,6 +128,18 @@ impl MediaType {
     pub fn mp4() -> Self {
         Self(Self::MP4.into())
     }
+
+    /// `video/x-matroska`
+    #[inline]
+    pub fn mkv() -> Self {
+        Self(Self::MKV.into())
+    }
+
+    /// `video/webm`
+    #[inline]
+    pub fn webm() -> Self {
+        Self(Self::WEBM.into())
+    }
 }
 
 impl MediaType {
@@ -212,6 +234,7 @@ impl MediaType {
             // Special-case some where there are multiple extensions:
             Self::JPEG => Some("jpg"),
             Self::MARKDOWN => Some("md"),
+            Self::MKV => Some("mkv"),
             Self::STL => Some("stl"),
             Self::TEXT => Some("txt"),
 
@@ -256,9 +279,11 @@ fn test_media_type_extension() {
     assert_eq!(MediaType::glb().file_extension(), Some("glb"));
     assert_eq!(MediaType::gltf().file_extension(), Some("gltf"));
     assert_eq!(MediaType::jpeg().file_extension(), Some("jpg"));
+    assert_eq!(MediaType::mkv().file_extension(), Some("mkv"));
     assert_eq!(MediaType::mp4().file_extension(), Some("mp4"));
     assert_eq!(MediaType::markdown().file_extension(), Some("md"));
     assert_eq!(MediaType::plain_text().file_extension(), Some("txt"));
     assert_eq!(MediaType::png().file_extension(), Some("png"));
     assert_eq!(MediaType::stl().file_extension(), Some("stl"));
+    assert_eq!(MediaType::webm().file_extension(), Some("webm"));
 }
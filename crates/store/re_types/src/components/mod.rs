@@ -7,12 +7,16 @@ mod annotation_context;
 mod axis_length;
 mod axis_length_ext;
 mod blob;
+mod child_frame;
+mod child_frame_ext;
 mod class_id;
 mod class_id_ext;
 mod color;
 mod color_ext;
 mod colormap;
 mod colormap_ext;
+mod coordinate_frame;
+mod coordinate_frame_ext;
 mod depth_meter;
 mod depth_meter_ext;
 mod draw_order;
@@ -63,6 +67,8 @@ mod name;
 mod name_ext;
 mod opacity;
 mod opacity_ext;
+mod parent_frame;
+mod parent_frame_ext;
 mod pinhole_projection;
 mod pinhole_projection_ext;
 mod plane3d;
@@ -138,9 +144,11 @@ pub use self::albedo_factor::AlbedoFactor;
 pub use self::annotation_context::AnnotationContext;
 pub use self::axis_length::AxisLength;
 pub use self::blob::Blob;
+pub use self::child_frame::ChildFrame;
 pub use self::class_id::ClassId;
 pub use self::color::Color;
 pub use self::colormap::Colormap;
+pub use self::coordinate_frame::CoordinateFrame;
 pub use self::depth_meter::DepthMeter;
 pub use self::draw_order::DrawOrder;
 pub use self::entity_path::EntityPath;
@@ -168,6 +176,7 @@ pub use self::marker_size::MarkerSize;
 pub use self::media_type::MediaType;
 pub use self::name::Name;
 pub use self::opacity::Opacity;
+pub use self::parent_frame::ParentFrame;
 pub use self::pinhole_projection::PinholeProjection;
 pub use self::plane3d::Plane3D;
 pub use self::pose_rotation_axis_angle::PoseRotationAxisAngle;
@@ -0,0 +1,9 @@
+use super::ParentFrame;
+
+impl ParentFrame {
+    /// Returns the name of the frame.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
@@ -85,6 +85,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: BackgroundKind::verify_arrow_array,
             },
         ),
+        (
+            <CaseSensitive as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Whether a text search distinguishes between upper and lower case.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(CaseSensitive::default().to_arrow()?),
+                datatype: CaseSensitive::arrow_datatype(),
+                verify_arrow_array: CaseSensitive::verify_arrow_array,
+            },
+        ),
         (
             <ColumnShare as Component>::name(),
             ComponentReflection {
@@ -185,6 +195,26 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: ForceStrength::verify_arrow_array,
             },
         ),
+        (
+            <GraphLayoutAlgorithm as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The algorithm that is used to compute the layout of a graph.",
+                deprecation_summary: None,
+                custom_placeholder: Some(GraphLayoutAlgorithm::default().to_arrow()?),
+                datatype: GraphLayoutAlgorithm::arrow_datatype(),
+                verify_arrow_array: GraphLayoutAlgorithm::verify_arrow_array,
+            },
+        ),
+        (
+            <GraphLayoutDirection as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The direction in which the edges of a layered or tree graph layout point.",
+                deprecation_summary: None,
+                custom_placeholder: Some(GraphLayoutDirection::default().to_arrow()?),
+                datatype: GraphLayoutDirection::arrow_datatype(),
+                verify_arrow_array: GraphLayoutDirection::verify_arrow_array,
+            },
+        ),
         (
             <GridColumns as Component>::name(),
             ComponentReflection {
@@ -235,6 +265,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: MapProvider::verify_arrow_array,
             },
         ),
+        (
+            <MaxRowsPerEntity as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The maximum number of rows to keep for each entity.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(MaxRowsPerEntity::default().to_arrow()?),
+                datatype: MaxRowsPerEntity::arrow_datatype(),
+                verify_arrow_array: MaxRowsPerEntity::verify_arrow_array,
+            },
+        ),
         (
             <NearClipPlane as Component>::name(),
             ComponentReflection {
@@ -265,6 +305,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: QueryExpression::verify_arrow_array,
             },
         ),
+        (
+            <RetentionWindow as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The size of a retention window, in the native unit of its timeline.\n\nThat is nanoseconds for temporal timelines, and raw indices for sequence timelines.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(RetentionWindow::default().to_arrow()?),
+                datatype: RetentionWindow::arrow_datatype(),
+                verify_arrow_array: RetentionWindow::verify_arrow_array,
+            },
+        ),
         (
             <RootContainer as Component>::name(),
             ComponentReflection {
@@ -307,6 +357,66 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TensorDimensionIndexSlider::verify_arrow_array,
             },
         ),
+        (
+            <TextLogColumn as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A column of the text log view.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextLogColumn::default().to_arrow()?),
+                datatype: TextLogColumn::arrow_datatype(),
+                verify_arrow_array: TextLogColumn::verify_arrow_array,
+            },
+        ),
+        (
+            <TextSearchMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How a text search query is matched against text.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextSearchMode::default().to_arrow()?),
+                datatype: TextSearchMode::arrow_datatype(),
+                verify_arrow_array: TextSearchMode::verify_arrow_array,
+            },
+        ),
+        (
+            <TileAttribution as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Attribution text shown on top of the map for custom and local tile sources.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TileAttribution::default().to_arrow()?),
+                datatype: TileAttribution::arrow_datatype(),
+                verify_arrow_array: TileAttribution::verify_arrow_array,
+            },
+        ),
+        (
+            <TileMaxZoom as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Highest zoom level for which a tile source provides tiles.\n\nWhen zooming in further, the tiles of this zoom level are magnified.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TileMaxZoom::default().to_arrow()?),
+                datatype: TileMaxZoom::arrow_datatype(),
+                verify_arrow_array: TileMaxZoom::verify_arrow_array,
+            },
+        ),
+        (
+            <TileSourcePath as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Path to a local tile source on the viewer's file system.\n\nEither an `MBTiles` file (`.mbtiles`) or a directory of raster tiles laid out as `{z}/{x}/{y}.png`\n(`.jpg` and `.jpeg` tiles are supported as well).\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TileSourcePath::default().to_arrow()?),
+                datatype: TileSourcePath::arrow_datatype(),
+                verify_arrow_array: TileSourcePath::verify_arrow_array,
+            },
+        ),
+        (
+            <TileUrlTemplate as Component>::name(),
+            ComponentReflection {
+                docstring_md: "URL template of a custom raster tile server.\n\n`{z}`, `{x}` and `{y}` are replaced by the zoom level and the tile coordinates of the XYZ tiling scheme\n(e.g. `https://tiles.example.com/{z}/{x}/{y}.png`).\nUse `{-y}` instead of `{y}` for servers following the TMS tiling scheme, where the rows are counted from the south.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TileUrlTemplate::default().to_arrow()?),
+                datatype: TileUrlTemplate::arrow_datatype(),
+                verify_arrow_array: TileUrlTemplate::verify_arrow_array,
+            },
+        ),
         (
             <TimelineName as Component>::name(),
             ComponentReflection {
@@ -317,6 +427,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TimelineName::verify_arrow_array,
             },
         ),
+        (
+            <TransformInterpolation as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How the transforms of an entity are interpolated between logged samples.\n\nApplies to both `Transform3D` and `InstancePoses3D`.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TransformInterpolation::default().to_arrow()?),
+                datatype: TransformInterpolation::arrow_datatype(),
+                verify_arrow_array: TransformInterpolation::verify_arrow_array,
+            },
+        ),
         (
             <ViewClass as Component>::name(),
             ComponentReflection {
@@ -459,6 +579,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Blob::verify_arrow_array,
             },
         ),
+        (
+            <ChildFrame as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The name of the coordinate frame a transform maps from.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(ChildFrame::default().to_arrow()?),
+                datatype: ChildFrame::arrow_datatype(),
+                verify_arrow_array: ChildFrame::verify_arrow_array,
+            },
+        ),
         (
             <ClassId as Component>::name(),
             ComponentReflection {
@@ -499,6 +629,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Colormap::verify_arrow_array,
             },
         ),
+        (
+            <CoordinateFrame as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The name of a coordinate frame, e.g. `base_link` or `camera_optical`.\n\nFrames are connected by transforms that are logged with a parent & child frame,\nindependently of the entity path hierarchy.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(CoordinateFrame::default().to_arrow()?),
+                datatype: CoordinateFrame::arrow_datatype(),
+                verify_arrow_array: CoordinateFrame::verify_arrow_array,
+            },
+        ),
         (
             <DepthMeter as Component>::name(),
             ComponentReflection {
@@ -769,6 +909,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Opacity::verify_arrow_array,
             },
         ),
+        (
+            <ParentFrame as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The name of the coordinate frame a transform maps into.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(ParentFrame::default().to_arrow()?),
+                datatype: ParentFrame::arrow_datatype(),
+                verify_arrow_array: ParentFrame::verify_arrow_array,
+            },
+        ),
         (
             <PinholeProjection as Component>::name(),
             ComponentReflection {
@@ -1459,6 +1609,21 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.CoordinateFrame"),
+            ArchetypeReflection {
+                display_name: "Coordinate frame",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView", "Spatial2DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "frame", display_name : "Frame",
+                    component_name : "rerun.components.CoordinateFrame".into(),
+                    docstring_md : "The name of the frame this entity is in.",
+                    is_required : true, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.DepthImage"),
             ArchetypeReflection {
@@ -2239,6 +2404,14 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     .into(), docstring_md :
                     "Specifies the relation this transform establishes between this entity and its parent.",
                     is_required : false, }, ArchetypeFieldReflection { name :
+                    "parent_frame", display_name : "Parent frame", component_name :
+                    "rerun.components.ParentFrame".into(), docstring_md :
+                    "The frame this transform maps into.\n\nIf both a parent and a child frame are set, this transform connects these two named frames\ninstead of applying to this entity and its descendants.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "child_frame", display_name : "Child frame", component_name :
+                    "rerun.components.ChildFrame".into(), docstring_md :
+                    "The frame this transform maps from.\n\nIf both a parent and a child frame are set, this transform connects these two named frames\ninstead of applying to this entity and its descendants.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
                     "axis_length", display_name : "Axis length", component_name :
                     "rerun.components.AxisLength".into(), docstring_md :
                     "Visual length of the 3 axes.\n\nThe length is interpreted in the local coordinate system of the transform.\nIf the transform is scaled, the axes will be scaled accordingly.",
@@ -2505,6 +2678,28 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.GraphLayout"),
+            ArchetypeReflection {
+                display_name: "Graph layout",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "algorithm", display_name :
+                    "Algorithm", component_name :
+                    "rerun.blueprint.components.GraphLayoutAlgorithm".into(),
+                    docstring_md :
+                    "The layout algorithm.\n\nDefaults to a force-directed layout, which is configured by the force properties of the view.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "direction", display_name : "Direction", component_name :
+                    "rerun.blueprint.components.GraphLayoutDirection".into(),
+                    docstring_md :
+                    "The direction of the edges in layered and tree layouts.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.LineGrid3D"),
             ArchetypeReflection {
@@ -2547,7 +2742,23 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     ArchetypeFieldReflection { name : "provider", display_name :
                     "Provider", component_name : "rerun.blueprint.components.MapProvider"
                     .into(), docstring_md :
-                    "Map provider and style to use.\n\n**Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.",
+                    "Map provider and style to use.\n\n**Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "url_template", display_name : "Url template", component_name :
+                    "rerun.blueprint.components.TileUrlTemplate".into(), docstring_md :
+                    "URL template of the tile server used by the `CustomUrl` provider.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "local_path", display_name : "Local path", component_name :
+                    "rerun.blueprint.components.TileSourcePath".into(), docstring_md :
+                    "Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "attribution", display_name : "Attribution", component_name :
+                    "rerun.blueprint.components.TileAttribution".into(), docstring_md :
+                    "Attribution shown for the `CustomUrl` and `LocalTiles` providers.\n\nFor `MBTiles` files, defaults to the attribution stored in the file's metadata.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "max_zoom",
+                    display_name : "Max zoom", component_name :
+                    "rerun.blueprint.components.TileMaxZoom".into(), docstring_md :
+                    "Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.\n\nFor `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.",
                     is_required : false, },
                 ],
             },
@@ -2619,6 +2830,35 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.RecordingRetention"),
+            ArchetypeReflection {
+                display_name: "Recording retention",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "timeline", display_name :
+                    "Timeline", component_name :
+                    "rerun.blueprint.components.TimelineName".into(), docstring_md :
+                    "The timeline that `time_window` applies to.", is_required : false,
+                    }, ArchetypeFieldReflection { name : "time_window", display_name :
+                    "Time window", component_name :
+                    "rerun.blueprint.components.RetentionWindow".into(), docstring_md :
+                    "Only keep the data that was logged within this window of the most recent time on the timeline.\n\nHas no effect unless the timeline is set as well.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "max_rows_per_entity", display_name : "Max rows per entity",
+                    component_name : "rerun.blueprint.components.MaxRowsPerEntity"
+                    .into(), docstring_md :
+                    "Only keep (approximately) this many rows for each entity, dropping the oldest ones first.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "protected_entities", display_name : "Protected entities",
+                    component_name : "rerun.components.EntityPath".into(), docstring_md :
+                    "Never drop the data of these entities, nor of any of their descendants.\n\nThis also applies to the garbage collection driven by the memory limit.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ScalarAxis"),
             ArchetypeReflection {
@@ -2708,6 +2948,49 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogColumns"),
+            ArchetypeReflection {
+                display_name: "Text log columns",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "text_log_columns", display_name :
+                    "Text log columns", component_name :
+                    "rerun.blueprint.components.TextLogColumn".into(), docstring_md :
+                    "The columns of the view, in the order they are shown.\n\nTimelines that are not listed are shown before all other columns, any other column that is not listed is shown last.\nIf unset, all timelines are shown, followed by the entity path, the log level, and the body.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogFilter"),
+            ArchetypeReflection {
+                display_name: "Text log filter",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "search", display_name : "Search",
+                    component_name : "rerun.components.Text".into(), docstring_md :
+                    "Only entries whose body matches this query are shown.\n\nIf unset or empty, entries are not filtered by their body.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "search_mode", display_name : "Search mode", component_name :
+                    "rerun.blueprint.components.TextSearchMode".into(), docstring_md :
+                    "How the search query is matched against the entry bodies.\n\nDefaults to plain text matching.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "case_sensitive", display_name : "Case sensitive", component_name :
+                    "rerun.blueprint.components.CaseSensitive".into(), docstring_md :
+                    "Whether the search distinguishes between upper and lower case.\n\nFalse by default.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "hidden_levels", display_name : "Hidden levels", component_name :
+                    "rerun.components.TextLogLevel".into(), docstring_md :
+                    "Entries with any of these log levels are hidden.\n\nEntries without a log level are always shown.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ViewBlueprint"),
             ArchetypeReflection {
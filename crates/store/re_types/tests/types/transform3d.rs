@@ -2,7 +2,10 @@ use std::f32::consts::TAU;
 
 use re_types::{
     archetypes::Transform3D,
-    components::{RotationAxisAngle, Scale3D, TransformMat3x3, TransformRelation, Translation3D},
+    components::{
+        ChildFrame, ParentFrame, RotationAxisAngle, Scale3D, TransformMat3x3, TransformRelation,
+        Translation3D,
+    },
     datatypes::Angle,
     Archetype as _, AsComponents as _, ComponentBatch as _,
 };
@@ -31,6 +34,12 @@ fn roundtrip() {
     let relation_parent_from_child_serialized = TransformRelation::ParentFromChild
         .serialized()
         .map(|batch| batch.with_descriptor_override(Transform3D::descriptor_relation()));
+    let parent_frame_serialized = ParentFrame::from("world")
+        .serialized()
+        .map(|batch| batch.with_descriptor_override(Transform3D::descriptor_parent_frame()));
+    let child_frame_serialized = ChildFrame::from("camera")
+        .serialized()
+        .map(|batch| batch.with_descriptor_override(Transform3D::descriptor_child_frame()));
 
     let all_expected = [
         Transform3D::clear_fields(),
@@ -62,6 +71,12 @@ fn roundtrip() {
             relation: relation_parent_from_child_serialized.clone(),
             ..Transform3D::clear_fields()
         }, //
+        Transform3D {
+            translation: translation_serialized.clone(),
+            parent_frame: parent_frame_serialized.clone(),
+            child_frame: child_frame_serialized.clone(),
+            ..Transform3D::clear_fields()
+        }, //
     ];
 
     let all_arch = [
@@ -82,6 +97,9 @@ fn roundtrip() {
             .with_relation(TransformRelation::ChildFromParent),
         Transform3D::from_mat3x3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
             .with_relation(TransformRelation::ParentFromChild),
+        Transform3D::from_translation([1.0, 2.0, 3.0])
+            .with_parent_frame("world")
+            .with_child_frame("camera"),
     ];
 
     for (expected, arch) in all_expected.into_iter().zip(all_arch) {
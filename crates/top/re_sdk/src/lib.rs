@@ -75,6 +75,18 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl crate::sink::LogSink for re_log_encoding::RotatingFileSink {
+    fn send(&self, msg: re_log_types::LogMsg) {
+        Self::send(self, msg);
+    }
+
+    #[inline]
+    fn flush_blocking(&self) {
+        Self::flush_blocking(self);
+    }
+}
+
 // ---------------
 // Public modules:
 
@@ -84,12 +96,18 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
 /// sent over gRPC, written to file, etc.
 pub mod sink {
     pub use crate::binary_stream_sink::{BinaryStreamSink, BinaryStreamStorage};
-    pub use crate::log_sink::{BufferedSink, CallbackSink, LogSink, MemorySink, MemorySinkStorage};
+    pub use crate::log_sink::{
+        BufferedSink, CallbackSink, LogSink, MemorySink, MemorySinkStorage, SinkFilter, TeeSink,
+    };
 
     pub use crate::log_sink::GrpcSink;
+    pub use re_grpc_client::message_proxy::write::{
+        ClientStats as GrpcSinkStats, ClientStatsHandle as GrpcSinkStatsHandle,
+        Options as GrpcSinkOptions, ReconnectOptions, SpoolOptions,
+    };
 
     #[cfg(not(target_arch = "wasm32"))]
-    pub use re_log_encoding::{FileSink, FileSinkError};
+    pub use re_log_encoding::{FileSink, FileSinkError, RotatingFileSink, RotationOptions};
 }
 
 /// Things directly related to logging.
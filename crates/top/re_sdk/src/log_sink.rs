@@ -3,10 +3,12 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use parking_lot::Mutex;
-use re_grpc_client::message_proxy::write::{Client as MessageProxyClient, Options};
+use re_grpc_client::message_proxy::write::{
+    Client as MessageProxyClient, ClientStats, ClientStatsHandle, Options,
+};
 use re_log_encoding::encoder::encode_as_bytes_local;
 use re_log_encoding::encoder::{local_raw_encoder, EncodeError};
-use re_log_types::{BlueprintActivationCommand, LogMsg, StoreId};
+use re_log_types::{BlueprintActivationCommand, EntityPath, LogMsg, StoreId, StoreKind};
 
 use crate::RecordingStream;
 
@@ -333,6 +335,190 @@ impl LogSink for CallbackSink {
 
 // ----------------------------------------------------------------------------
 
+/// Decides which messages a sink of a [`TeeSink`] gets to see.
+///
+/// The default filter lets everything through.
+#[derive(Debug, Clone, Default)]
+pub struct SinkFilter {
+    entity_path_prefixes: Option<Vec<EntityPath>>,
+    store_kinds: Option<Vec<StoreKind>>,
+}
+
+impl SinkFilter {
+    /// Only forward chunks logged at, or below, one of the given entity paths.
+    ///
+    /// Messages that aren't tied to an entity (e.g. [`LogMsg::SetStoreInfo`]) are always forwarded.
+    #[inline]
+    pub fn with_entity_path_prefixes(
+        mut self,
+        prefixes: impl IntoIterator<Item = impl Into<EntityPath>>,
+    ) -> Self {
+        self.entity_path_prefixes = Some(prefixes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only forward messages that belong to stores of the given kinds.
+    #[inline]
+    pub fn with_store_kinds(mut self, kinds: impl IntoIterator<Item = StoreKind>) -> Self {
+        self.store_kinds = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Should the given message be forwarded?
+    pub fn matches(&self, msg: &LogMsg) -> bool {
+        if let Some(store_kinds) = &self.store_kinds {
+            if !store_kinds.contains(&msg.store_id().kind) {
+                return false;
+            }
+        }
+
+        if let (Some(prefixes), LogMsg::ArrowMsg(_, arrow_msg)) = (&self.entity_path_prefixes, msg)
+        {
+            let Some(entity_path) = arrow_msg
+                .batch
+                .schema_ref()
+                .metadata()
+                .get("rerun.entity_path")
+            else {
+                return false;
+            };
+
+            let entity_path = EntityPath::parse_forgiving(entity_path);
+            return prefixes
+                .iter()
+                .any(|prefix| entity_path.starts_with(prefix));
+        }
+
+        true
+    }
+}
+
+/// Forwards log messages to several other sinks at once, e.g. to stream live to a viewer while
+/// also saving everything to disk.
+///
+/// Each sink gets its own [`SinkFilter`].
+/// The sinks are otherwise fully independent: each of them buffers, flushes, and deals with its
+/// own failures (e.g. a disconnected viewer) without affecting the others.
+#[derive(Default)]
+pub struct TeeSink {
+    sinks: Vec<(Box<dyn LogSink>, SinkFilter)>,
+}
+
+impl TeeSink {
+    /// A sink that doesn't forward anything until sinks are added to it.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forward all messages to `sink`.
+    #[inline]
+    pub fn with_sink(self, sink: impl LogSink) -> Self {
+        self.with_filtered_sink(sink, SinkFilter::default())
+    }
+
+    /// Forward the messages matching `filter` to `sink`.
+    #[inline]
+    pub fn with_filtered_sink(mut self, sink: impl LogSink, filter: SinkFilter) -> Self {
+        self.push(Box::new(sink), filter);
+        self
+    }
+
+    /// Forward the messages matching `filter` to `sink`.
+    #[inline]
+    pub fn push(&mut self, sink: Box<dyn LogSink>, filter: SinkFilter) {
+        self.sinks.push((sink, filter));
+    }
+
+    /// The number of sinks messages are forwarded to.
+    #[inline]
+    pub fn num_sinks(&self) -> usize {
+        self.sinks.len()
+    }
+}
+
+impl LogSink for TeeSink {
+    fn send(&self, msg: LogMsg) {
+        for (sink, filter) in &self.sinks {
+            if filter.matches(&msg) {
+                sink.send(msg.clone());
+            }
+        }
+    }
+
+    fn send_all(&self, messages: Vec<LogMsg>) {
+        for (sink, filter) in &self.sinks {
+            let messages: Vec<_> = messages
+                .iter()
+                .filter(|msg| filter.matches(msg))
+                .cloned()
+                .collect();
+
+            if !messages.is_empty() {
+                sink.send_all(messages);
+            }
+        }
+    }
+
+    /// Drains the backlog of every sink, but only returns the first non-empty one.
+    ///
+    /// All sinks were sent the same messages (modulo their filters), so handing over the backlogs
+    /// of all of them would duplicate data.
+    fn drain_backlog(&self) -> Vec<LogMsg> {
+        let mut backlog = Vec::new();
+        for (sink, _) in &self.sinks {
+            let sink_backlog = sink.drain_backlog();
+            if backlog.is_empty() {
+                backlog = sink_backlog;
+            }
+        }
+        backlog
+    }
+
+    fn flush_blocking(&self) {
+        for (sink, _) in &self.sinks {
+            sink.flush_blocking();
+        }
+    }
+
+    fn drop_if_disconnected(&self) {
+        for (sink, _) in &self.sinks {
+            sink.drop_if_disconnected();
+        }
+    }
+
+    fn send_blueprint(&self, blueprint: Vec<LogMsg>, activation_cmd: BlueprintActivationCommand) {
+        for (sink, filter) in &self.sinks {
+            let blueprint: Vec<_> = blueprint
+                .iter()
+                .filter(|msg| filter.matches(msg))
+                .cloned()
+                .collect();
+
+            if !blueprint.is_empty() {
+                sink.send_blueprint(blueprint, activation_cmd.clone());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for TeeSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TeeSink")
+            .field(
+                "filters",
+                &self
+                    .sinks
+                    .iter()
+                    .map(|(_, filter)| filter)
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Stream log messages to an a remote Rerun server.
 pub struct GrpcSink {
     client: MessageProxyClient,
@@ -341,9 +527,12 @@ pub struct GrpcSink {
 impl GrpcSink {
     /// Connect to the in-memory storage node over HTTP.
     ///
-    /// `flush_timeout` is the minimum time the [`GrpcSink`] will wait during a flush
-    /// before potentially dropping data. Note: Passing `None` here can cause a
-    /// call to `flush` to block indefinitely if a connection cannot be established.
+    /// `flush_timeout` is the minimum time the [`GrpcSink`] will wait during a flush.
+    /// Data that couldn't be sent by then isn't dropped: it stays queued, and is sent as soon as
+    /// the connection is (re-)established. It is only lost if it is still queued when the sink
+    /// itself is dropped, or if it doesn't fit in the spool (see [`Self::with_options`]).
+    /// Note: Passing `None` here can cause a call to `flush` to block indefinitely if a
+    /// connection cannot be established.
     ///
     /// ### Example
     ///
@@ -360,6 +549,68 @@ impl GrpcSink {
             client: MessageProxyClient::new(uri, options),
         }
     }
+
+    /// Like [`Self::new`], but with a custom [`re_log_encoding::Compression`] for the
+    /// chunks sent over the wire.
+    ///
+    /// The default is [`re_log_encoding::Compression::LZ4`]. Note that the receiving end
+    /// must be running a version of Rerun that supports the chosen compression.
+    #[inline]
+    pub fn with_compression(
+        uri: re_uri::ProxyUri,
+        flush_timeout: Option<Duration>,
+        compression: re_log_encoding::Compression,
+    ) -> Self {
+        let options = Options {
+            compression,
+            flush_timeout,
+            ..Default::default()
+        };
+        Self {
+            client: MessageProxyClient::new(uri, options),
+        }
+    }
+
+    /// Like [`Self::new`], but with full control over compression, reconnection, and spooling.
+    ///
+    /// The connection is always re-established when lost, with an exponential backoff.
+    /// By default, messages logged in the meantime are kept in memory.
+    /// Use [`Options::spool`] to keep them in a bounded on-disk spool instead.
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// GrpcSink::with_options(
+    ///     "rerun+http://127.0.0.1:9434/proxy".parse()?,
+    ///     Options {
+    ///         spool: Some(SpoolOptions {
+    ///             path: "/var/spool/rerun.bin".into(),
+    ///             max_bytes: 1024 * 1024 * 1024,
+    ///         }),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_options(uri: re_uri::ProxyUri, options: Options) -> Self {
+        Self {
+            client: MessageProxyClient::new(uri, options),
+        }
+    }
+
+    /// How many times the connection was re-established, and how much data was spooled or
+    /// dropped while disconnected.
+    #[inline]
+    pub fn stats(&self) -> ClientStats {
+        self.client.stats()
+    }
+
+    /// Like [`Self::stats`], but can be kept around once the sink was handed over to a
+    /// [`RecordingStream`], see also [`RecordingStream::grpc_stats`].
+    #[inline]
+    pub fn stats_handle(&self) -> ClientStatsHandle {
+        self.client.stats_handle()
+    }
 }
 
 impl LogSink for GrpcSink {
@@ -371,3 +622,218 @@ impl LogSink for GrpcSink {
         self.client.flush();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use re_chunk::{Chunk, RowId};
+    use re_grpc_client::message_proxy::write::{ReconnectOptions, SpoolOptions};
+    use re_log_types::{StoreId, StoreKind, TimePoint};
+    use re_smart_channel::SmartMessagePayload;
+
+    use super::*;
+
+    /// Serves on `addr` from a runtime of its own, so that the server can be killed at any point
+    /// by shutting that runtime down.
+    fn spawn_server(
+        addr: SocketAddr,
+    ) -> (tokio::runtime::Runtime, re_smart_channel::Receiver<LogMsg>) {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let (log_rx, _table_rx) = {
+            let _guard = runtime.enter();
+            re_grpc_server::spawn_with_recv(
+                addr,
+                re_memory::MemoryLimit::UNLIMITED,
+                re_grpc_server::shutdown::never(),
+            )
+        };
+
+        (runtime, log_rx)
+    }
+
+    fn fake_chunks(store_id: &StoreId, n: usize) -> Vec<LogMsg> {
+        (0..n)
+            .map(|i| {
+                let chunk = Chunk::builder("points".into())
+                    .with_archetype(
+                        RowId::new(),
+                        TimePoint::default(),
+                        &re_types::archetypes::Points2D::new([(i as f32, i as f32)]),
+                    )
+                    .build()
+                    .unwrap();
+                LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+            })
+            .collect()
+    }
+
+    fn chunk_ids(msgs: &[LogMsg]) -> Vec<re_tuid::Tuid> {
+        msgs.iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(arrow_msg.chunk_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Waits for `n` chunks to come out of `log_rx`.
+    fn recv_chunk_ids(log_rx: &re_smart_channel::Receiver<LogMsg>, n: usize) -> Vec<re_tuid::Tuid> {
+        let mut msgs = Vec::new();
+        while msgs.len() < n {
+            let msg = log_rx
+                .recv_timeout(Duration::from_secs(10))
+                .expect("timed out waiting for messages");
+            if let SmartMessagePayload::Msg(msg) = msg.payload {
+                msgs.push(msg);
+            }
+        }
+        chunk_ids(&msgs)
+    }
+
+    #[test]
+    fn sink_filter_matches_entity_paths_and_store_kinds() {
+        let recording_id = StoreId::random(StoreKind::Recording);
+        let blueprint_id = StoreId::random(StoreKind::Blueprint);
+
+        let chunk_at = |store_id: &StoreId, entity_path: &str| {
+            let chunk = Chunk::builder(entity_path.into())
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default(),
+                    &re_types::archetypes::Points2D::new([(0.0, 0.0)]),
+                )
+                .build()
+                .unwrap();
+            LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+        };
+        let store_info = |store_id: &StoreId| {
+            LogMsg::SetStoreInfo(re_log_types::SetStoreInfo {
+                row_id: *RowId::new(),
+                info: re_log_types::StoreInfo {
+                    application_id: "rerun_example_sink_filter".into(),
+                    store_id: store_id.clone(),
+                    cloned_from: None,
+                    store_source: re_log_types::StoreSource::Unknown,
+                    store_version: None,
+                },
+            })
+        };
+
+        let world_points = chunk_at(&recording_id, "world/points");
+        let world = chunk_at(&recording_id, "world");
+        let worldly = chunk_at(&recording_id, "worldly");
+        let debug = chunk_at(&recording_id, "debug");
+        let blueprint_world = chunk_at(&blueprint_id, "world/points");
+
+        let everything = SinkFilter::default();
+        for msg in [
+            &world_points,
+            &debug,
+            &blueprint_world,
+            &store_info(&blueprint_id),
+        ] {
+            assert!(everything.matches(msg));
+        }
+
+        let by_prefix = SinkFilter::default().with_entity_path_prefixes(["world"]);
+        assert!(by_prefix.matches(&world_points));
+        assert!(by_prefix.matches(&world));
+        assert!(!by_prefix.matches(&worldly));
+        assert!(!by_prefix.matches(&debug));
+        assert!(by_prefix.matches(&blueprint_world));
+        assert!(by_prefix.matches(&store_info(&recording_id)));
+
+        let by_kind = SinkFilter::default().with_store_kinds([StoreKind::Recording]);
+        assert!(by_kind.matches(&world_points));
+        assert!(by_kind.matches(&debug));
+        assert!(!by_kind.matches(&blueprint_world));
+        assert!(by_kind.matches(&store_info(&recording_id)));
+        assert!(!by_kind.matches(&store_info(&blueprint_id)));
+
+        let both = by_prefix.with_store_kinds([StoreKind::Recording]);
+        assert!(both.matches(&world_points));
+        assert!(!both.matches(&debug));
+        assert!(!both.matches(&blueprint_world));
+        assert!(both.matches(&store_info(&recording_id)));
+        assert!(!both.matches(&store_info(&blueprint_id)));
+    }
+
+    #[test]
+    fn grpc_sink_survives_server_restart() {
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
+            re_uri::Scheme::RerunHttp,
+            addr,
+        ));
+
+        let spool_dir = tempfile::tempdir().unwrap();
+        let spool_path = spool_dir.path().join("spool.bin");
+
+        let (server, log_rx) = spawn_server(addr);
+
+        let sink = GrpcSink::with_options(
+            uri,
+            Options {
+                flush_timeout: Some(Duration::from_secs(10)),
+                reconnect: ReconnectOptions {
+                    initial_backoff: Duration::from_millis(10),
+                    max_backoff: Duration::from_millis(50),
+                    multiplier: 2.0,
+                },
+                spool: Some(SpoolOptions {
+                    path: spool_path.clone(),
+                    max_bytes: 64 * 1024 * 1024,
+                }),
+                ..Default::default()
+            },
+        );
+
+        let store_id = StoreId::random(StoreKind::Recording);
+
+        let before = fake_chunks(&store_id, 10);
+        for msg in &before {
+            sink.send(msg.clone());
+        }
+        sink.flush_blocking();
+        assert_eq!(recv_chunk_ids(&log_rx, before.len()), chunk_ids(&before));
+
+        // Kill the server, and log some more while it's gone.
+        // This waits for the server to be gone, so that its port is free again.
+        server.shutdown_timeout(Duration::from_secs(10));
+
+        let after = fake_chunks(&store_id, 10);
+        for msg in &after {
+            sink.send(msg.clone());
+        }
+
+        // Flushing while disconnected only returns once everything is in the spool.
+        sink.flush_blocking();
+        let stats = sink.stats();
+        assert!(stats.spooled_bytes > 0, "{stats:?}");
+        assert_eq!(stats.num_reconnects, 0, "{stats:?}");
+
+        // Everything logged while disconnected is replayed, in order, once the server is back.
+        let (_server, log_rx) = spawn_server(addr);
+        assert_eq!(recv_chunk_ids(&log_rx, after.len()), chunk_ids(&after));
+
+        sink.flush_blocking();
+
+        let stats = sink.stats();
+        assert_eq!(stats.num_reconnects, 1);
+        assert_eq!(stats.dropped_bytes, 0);
+        assert!(stats.spooled_bytes > 0);
+        assert!(
+            !spool_path.exists(),
+            "the spool should be removed once replayed"
+        );
+    }
+}
@@ -27,7 +27,7 @@ use re_types::{AsComponents, SerializationError, SerializedComponentColumn};
 use re_web_viewer_server::WebViewerServerPort;
 
 use crate::binary_stream_sink::BinaryStreamStorage;
-use crate::sink::{LogSink, MemorySinkStorage};
+use crate::sink::{GrpcSinkStats, GrpcSinkStatsHandle, LogSink, MemorySinkStorage};
 
 // ---
 
@@ -370,8 +370,9 @@ impl RecordingStreamBuilder {
     /// remote Rerun instance.
     ///
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
-    /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
-    /// call to `flush` to block indefinitely if a connection cannot be established.
+    /// wait during a flush, see [`crate::log_sink::GrpcSink::new`] for what happens to the data
+    /// that couldn't be sent by then. Note: Passing `None` here can cause a call to `flush` to
+    /// block indefinitely if a connection cannot be established.
     ///
     /// ## Example
     ///
@@ -392,12 +393,12 @@ impl RecordingStreamBuilder {
                 return Err(RecordingStreamError::NotAProxyEndpoint);
             };
 
-            RecordingStream::new(
-                store_info,
-                properties,
-                batcher_config,
-                Box::new(crate::log_sink::GrpcSink::new(uri, flush_timeout)),
-            )
+            let sink = crate::log_sink::GrpcSink::new(uri, flush_timeout);
+            let grpc_stats = sink.stats_handle();
+
+            let rec = RecordingStream::new(store_info, properties, batcher_config, Box::new(sink))?;
+            rec.set_grpc_stats(grpc_stats);
+            Ok(rec)
         } else {
             re_log::debug!("Rerun disabled - call to connect() ignored");
             Ok(RecordingStream::disabled())
@@ -494,6 +495,84 @@ impl RecordingStreamBuilder {
         }
     }
 
+    /// Creates a new [`RecordingStream`] that is pre-configured to both save the data to an RRD
+    /// file on disk and stream it through to a remote Rerun instance on the default address.
+    ///
+    /// The file always ends up with the complete recording, even if the remote instance is
+    /// unreachable.
+    /// See [`Self::save_and_connect_grpc_opts`] to configure the connection, and
+    /// [`crate::log_sink::TeeSink`] for more control (e.g. filtering what goes where).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_app")
+    ///     .save_and_connect_grpc("my_recording.rrd")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_and_connect_grpc(
+        self,
+        path: impl Into<std::path::PathBuf>,
+    ) -> RecordingStreamResult<RecordingStream> {
+        self.save_and_connect_grpc_opts(
+            path,
+            format!(
+                "rerun+http://127.0.0.1:{}/proxy",
+                re_grpc_server::DEFAULT_SERVER_PORT
+            ),
+            crate::default_flush_timeout(),
+        )
+    }
+
+    /// Creates a new [`RecordingStream`] that is pre-configured to both save the data to an RRD
+    /// file on disk and stream it through to a remote Rerun instance.
+    ///
+    /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
+    /// wait during a flush, see [`crate::log_sink::GrpcSink::new`] for what happens to the data
+    /// that couldn't be sent by then. Note: Passing `None` here can cause a call to `flush` to
+    /// block indefinitely if a connection cannot be established.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_app").save_and_connect_grpc_opts(
+    ///     "my_recording.rrd",
+    ///     "rerun+http://127.0.0.1:9876/proxy",
+    ///     re_sdk::default_flush_timeout(),
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_and_connect_grpc_opts(
+        self,
+        path: impl Into<std::path::PathBuf>,
+        url: impl Into<String>,
+        flush_timeout: Option<Duration>,
+    ) -> RecordingStreamResult<RecordingStream> {
+        let (enabled, store_info, properties, batcher_config) = self.into_args();
+
+        if enabled {
+            let url: String = url.into();
+            let re_uri::RedapUri::Proxy(uri) = url.as_str().parse()? else {
+                return Err(RecordingStreamError::NotAProxyEndpoint);
+            };
+
+            let grpc_sink = crate::log_sink::GrpcSink::new(uri, flush_timeout);
+            let grpc_stats = grpc_sink.stats_handle();
+            let sink = crate::log_sink::TeeSink::new()
+                .with_sink(crate::sink::FileSink::new(path)?)
+                .with_sink(grpc_sink);
+
+            let rec = RecordingStream::new(store_info, properties, batcher_config, Box::new(sink))?;
+            rec.set_grpc_stats(grpc_stats);
+            Ok(rec)
+        } else {
+            re_log::debug!("Rerun disabled - call to save_and_connect_grpc() ignored");
+            Ok(RecordingStream::disabled())
+        }
+    }
+
     /// Creates a new [`RecordingStream`] that is pre-configured to stream the data through to stdout.
     ///
     /// If there isn't any listener at the other end of the pipe, the [`RecordingStream`] will
@@ -556,8 +635,9 @@ impl RecordingStreamBuilder {
     /// If you're fine with the default behavior, refer to the simpler [`Self::spawn`].
     ///
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
-    /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
-    /// call to `flush` to block indefinitely if a connection cannot be established.
+    /// wait during a flush, see [`crate::log_sink::GrpcSink::new`] for what happens to the data
+    /// that couldn't be sent by then. Note: Passing `None` here can cause a call to `flush` to
+    /// block indefinitely if a connection cannot be established.
     ///
     /// ## Example
     ///
@@ -875,6 +955,11 @@ struct RecordingStreamInner {
     /// See [`RecordingStream::log_file_from_path`] and [`RecordingStream::log_file_from_contents`].
     dataloader_handles: Mutex<Vec<std::thread::JoinHandle<()>>>,
 
+    /// The stats of the current sink, if it was set up by one of the gRPC connection methods.
+    ///
+    /// See [`RecordingStream::grpc_stats`].
+    grpc_stats: Mutex<Option<GrpcSinkStatsHandle>>,
+
     pid_at_creation: u32,
 }
 
@@ -972,6 +1057,7 @@ impl RecordingStreamInner {
             batcher,
             batcher_to_sink_handle: Some(batcher_to_sink_handle),
             dataloader_handles: Mutex::new(Vec::new()),
+            grpc_stats: Mutex::new(None),
             pid_at_creation: std::process::id(),
         })
     }
@@ -1577,6 +1663,33 @@ impl RecordingStream {
         self.with(|inner| inner.info.clone())
     }
 
+    /// How many times the gRPC connection was re-established, and how much data was spooled or
+    /// dropped while disconnected.
+    ///
+    /// Only available if the current sink was set up by one of the gRPC connection methods,
+    /// e.g. [`Self::connect_grpc`] or [`Self::spawn`].
+    /// For a [`crate::log_sink::GrpcSink`] passed to [`Self::set_sink`], keep its
+    /// [`crate::log_sink::GrpcSink::stats_handle`] around instead.
+    #[inline]
+    pub fn grpc_stats(&self) -> Option<GrpcSinkStats> {
+        self.with(|inner| inner.grpc_stats.lock().as_ref().map(|stats| stats.load()))
+            .flatten()
+    }
+
+    /// Remember the stats of a freshly set up gRPC sink, see [`Self::grpc_stats`].
+    fn set_grpc_stats(&self, stats: GrpcSinkStatsHandle) {
+        // The sink is swapped for a file sink if that env-var is set, see `Self::new`.
+        let is_forced_to_file = self
+            .store_info()
+            .is_some_and(|info| info.store_id.kind == StoreKind::Recording)
+            && forced_sink_path().is_some();
+        if is_forced_to_file {
+            return;
+        }
+
+        self.with(|inner| *inner.grpc_stats.lock() = Some(stats));
+    }
+
     /// Determine whether a fork has happened since creating this `RecordingStream`. In general, this means our
     /// batcher/sink threads are gone and all data logged since the fork has been dropped.
     ///
@@ -1778,6 +1891,9 @@ impl RecordingStream {
             inner.cmds_tx.send(cmd).ok();
             oneshot.recv().ok();
             re_log::trace!("Sink swap completed.");
+
+            // The stats of the old sink no longer apply.
+            *inner.grpc_stats.lock() = None;
         };
 
         if self.with(f).is_none() {
@@ -1881,8 +1997,9 @@ impl RecordingStream {
     /// See [`Self::set_sink`] for more information.
     ///
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
-    /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
-    /// call to `flush` to block indefinitely if a connection cannot be established.
+    /// wait during a flush, see [`crate::log_sink::GrpcSink::new`] for what happens to the data
+    /// that couldn't be sent by then. Note: Passing `None` here can cause a call to `flush` to
+    /// block indefinitely if a connection cannot be established.
     pub fn connect_grpc_opts(
         &self,
         url: impl Into<String>,
@@ -1899,8 +2016,10 @@ impl RecordingStream {
         };
 
         let sink = crate::log_sink::GrpcSink::new(uri, flush_timeout);
+        let grpc_stats = sink.stats_handle();
 
         self.set_sink(Box::new(sink));
+        self.set_grpc_stats(grpc_stats);
         Ok(())
     }
 
@@ -1981,8 +2100,9 @@ impl RecordingStream {
     /// See [`Self::set_sink`] for more information.
     ///
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
-    /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
-    /// call to `flush` to block indefinitely if a connection cannot be established.
+    /// wait during a flush, see [`crate::log_sink::GrpcSink::new`] for what happens to the data
+    /// that couldn't be sent by then. Note: Passing `None` here can cause a call to `flush` to
+    /// block indefinitely if a connection cannot be established.
     pub fn spawn_opts(
         &self,
         opts: &crate::SpawnOptions,
@@ -2174,6 +2294,7 @@ impl fmt::Debug for RecordingStream {
                 batcher_to_sink_handle: _,
                 dataloader_handles,
                 pid_at_creation,
+                grpc_stats,
             } = inner;
 
             f.debug_struct("RecordingStream")
@@ -2182,6 +2303,7 @@ impl fmt::Debug for RecordingStream {
                 .field("tick", &tick)
                 .field("pending_dataloaders", &dataloader_handles.lock().len())
                 .field("pid_at_creation", &pid_at_creation)
+                .field("grpc_stats", &grpc_stats.lock())
                 .finish_non_exhaustive()
         };
 
@@ -2792,6 +2914,146 @@ mod tests {
         assert!(msgs.pop().is_none());
     }
 
+    #[test]
+    fn tee_sink() {
+        use crate::log_sink::{CallbackSink, SinkFilter, TeeSink};
+
+        let rec = RecordingStreamBuilder::new("rerun_example_tee_sink")
+            .enabled(true)
+            .send_properties(false)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .buffered()
+            .unwrap();
+
+        let collecting_sink = || {
+            let msgs = Arc::new(Mutex::new(Vec::new()));
+            let sink = CallbackSink::new({
+                let msgs = Arc::clone(&msgs);
+                move |new_msgs: &[LogMsg]| msgs.lock().extend_from_slice(new_msgs)
+            });
+            (sink, msgs)
+        };
+
+        let (all_sink, all_msgs) = collecting_sink();
+        let (prefix_sink, prefix_msgs) = collecting_sink();
+        let (blueprint_sink, blueprint_msgs) = collecting_sink();
+
+        rec.set_sink(Box::new(
+            TeeSink::new()
+                .with_sink(all_sink)
+                .with_filtered_sink(
+                    prefix_sink,
+                    SinkFilter::default().with_entity_path_prefixes(["a"]),
+                )
+                .with_filtered_sink(
+                    blueprint_sink,
+                    SinkFilter::default().with_store_kinds([StoreKind::Blueprint]),
+                ),
+        ));
+
+        for row in example_rows(false) {
+            rec.record_row("a/b".into(), row, false);
+        }
+        for row in example_rows(false) {
+            rec.record_row("c".into(), row, false);
+        }
+        rec.flush_blocking();
+
+        let entity_paths = |msgs: &Mutex<Vec<LogMsg>>| {
+            msgs.lock()
+                .iter()
+                .filter_map(|msg| match msg {
+                    LogMsg::ArrowMsg(_, msg) => Some(
+                        re_chunk::Chunk::from_arrow_msg(msg)
+                            .unwrap()
+                            .entity_path()
+                            .clone(),
+                    ),
+                    _ => None,
+                })
+                .collect::<std::collections::BTreeSet<_>>()
+        };
+
+        assert_eq!(
+            entity_paths(&all_msgs),
+            [EntityPath::from("a/b"), EntityPath::from("c")].into()
+        );
+        assert_eq!(entity_paths(&prefix_msgs), [EntityPath::from("a/b")].into());
+        assert!(matches!(
+            prefix_msgs.lock().first(),
+            Some(LogMsg::SetStoreInfo(_))
+        ));
+        assert!(blueprint_msgs.lock().is_empty());
+    }
+
+    #[test]
+    fn tee_sink_drains_backlog() {
+        use crate::log_sink::{BufferedSink, TeeSink};
+
+        let rec = RecordingStreamBuilder::new("rerun_example_tee_sink_backlog")
+            .enabled(true)
+            .send_properties(false)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .buffered()
+            .unwrap();
+
+        let tee = TeeSink::new()
+            .with_sink(BufferedSink::new())
+            .with_sink(BufferedSink::new());
+        rec.set_sink(Box::new(tee));
+
+        for row in example_rows(false) {
+            rec.record_row("a".into(), row, false);
+        }
+        rec.flush_blocking();
+
+        // Swapping the tee out must hand its backlog over to the new sink, exactly once.
+        let storage = rec.memory();
+        let msgs = storage.take();
+        let chunk_ids: Vec<_> = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(msg.chunk_id),
+                _ => None,
+            })
+            .collect();
+        assert!(!chunk_ids.is_empty());
+        assert_eq!(
+            chunk_ids.len(),
+            chunk_ids
+                .iter()
+                .collect::<std::collections::BTreeSet<_>>()
+                .len(),
+            "the backlog was handed over more than once"
+        );
+    }
+
+    #[test]
+    fn grpc_stats_follow_the_sink() {
+        let rec = RecordingStreamBuilder::new("rerun_example_grpc_stats")
+            .enabled(true)
+            .send_properties(false)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .buffered()
+            .unwrap();
+        assert_eq!(rec.grpc_stats(), None);
+
+        // Nothing listens there, which doesn't matter until something gets logged.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        rec.connect_grpc_opts(
+            format!("rerun+http://{addr}/proxy"),
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap();
+        assert_eq!(rec.grpc_stats(), Some(Default::default()));
+
+        rec.memory();
+        assert_eq!(rec.grpc_stats(), None);
+    }
+
     #[test]
     fn test_set_thread_local() {
         // Regression-test for https://github.com/rerun-io/rerun/issues/2889
@@ -21,12 +21,16 @@ use re_web_viewer_server::WebViewerServerPort;
 #[cfg(feature = "analytics")]
 use crate::commands::AnalyticsCommands;
 
+#[cfg(feature = "server")]
+use crate::commands::ServerCommand;
+
 // ---
 
 const LONG_ABOUT: &str = r#"
 The Rerun command-line interface:
 * Spawn viewers to visualize Rerun recordings and other supported formats.
 * Start a gRPC server to share recordings over the network, on native or web.
+* Host a local catalog of datasets.
 * Inspect, edit and filter Rerun recordings.
 "#;
 
@@ -125,6 +129,25 @@ Example: `16GB` or `50%` (of system total)."
     )]
     server_memory_limit: String,
 
+    #[clap(
+        long,
+        requires = "serve_grpc",
+        long_help = r"Only with `--serve-grpc`: instead of dropping the oldest data when `--server-memory-limit` is reached,
+move it to `.rrd` segments in this directory, so that late-arriving viewers still get the full history.
+Segments left over from a previous run are deleted on startup."
+    )]
+    persist_dir: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        default_value = "10GB",
+        requires = "persist_dir",
+        long_help = r"An upper limit on how much disk space `--persist-dir` should use.
+When this limit is reached, Rerun will delete the oldest data.
+Example: `100GB`."
+    )]
+    persist_max_bytes: String,
+
     #[clap(
         long,
         default_value_t = true,
@@ -145,6 +168,25 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     profile: bool,
 
+    /// Never garbage collect the given entities, nor any of their descendants, e.g. `/config`.
+    ///
+    /// Can be specified multiple times.
+    #[clap(long)]
+    retention_protect: Vec<String>,
+
+    /// Only keep (approximately) this many rows for each entity in the Viewer.
+    ///
+    /// Older rows are continuously dropped, regardless of `--memory-limit`.
+    #[clap(long)]
+    retention_max_rows_per_entity: Option<u64>,
+
+    /// Only keep the data logged within the given window on a timeline in the Viewer,
+    /// e.g. `log_time=30s` or `frame_nr=100`.
+    ///
+    /// Older data is continuously dropped, regardless of `--memory-limit`.
+    #[clap(long)]
+    retention_time_window: Option<re_chunk_store::RetentionTimeWindow>,
+
     /// Stream incoming log events to an .rrd file at the given path.
     #[clap(long)]
     save: Option<String>,
@@ -524,6 +566,9 @@ enum Command {
     #[command(subcommand)]
     Rrd(RrdCommands),
 
+    #[cfg(feature = "server")]
+    Server(ServerCommand),
+
     /// Reset the memory of the Rerun Viewer.
     ///
     /// Only run this if you're having trouble with the Viewer,
@@ -602,6 +647,9 @@ where
 
             Command::Rrd(rrd) => rrd.run(),
 
+            #[cfg(feature = "server")]
+            Command::Server(server) => server.run(tokio_runtime.handle()),
+
             #[cfg(feature = "native_viewer")]
             Command::Reset => re_viewer::reset_viewer_persistence(),
 
@@ -682,6 +730,7 @@ fn run_impl(
                 re_memory::MemoryLimit::parse(&args.memory_limit)
                     .map_err(|err| anyhow::format_err!("Bad --memory-limit: {err}"))?
             },
+            retention: retention_policy(&args),
             persist_state: args.persist_state,
             is_in_notebook: false,
             screenshot_to_path_then_quit: args.screenshot_to.clone(),
@@ -715,6 +764,21 @@ fn run_impl(
         re_memory::MemoryLimit::parse(&args.server_memory_limit)
             .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?
     };
+    #[cfg(feature = "server")]
+    let server_persist = if let Some(dir) = &args.persist_dir {
+        re_log::debug!("Parsing disk limit for gRPC server");
+        let max_bytes = re_format::parse_bytes(&args.persist_max_bytes)
+            .and_then(|max_bytes| u64::try_from(max_bytes).ok())
+            .ok_or_else(|| {
+                anyhow::format_err!("Bad --persist-max-bytes: {:?}", args.persist_max_bytes)
+            })?;
+        Some(re_grpc_server::PersistOptions {
+            dir: dir.clone(),
+            max_bytes,
+        })
+    } else {
+        None
+    };
 
     #[allow(unused_variables)]
     let (command_sender, command_receiver) = re_viewer_context::command_channel();
@@ -862,12 +926,24 @@ fn run_impl(
             let (signal, shutdown) = re_grpc_server::shutdown::shutdown();
             // Spawn a server which the Web Viewer can connect to.
             // All `rxs` are consumed by the server.
-            re_grpc_server::spawn_from_rx_set(
-                server_addr,
-                server_memory_limit,
-                shutdown,
-                ReceiveSet::new(rxs_log),
-            );
+            if let Some(persist) = server_persist {
+                let dir = persist.dir.clone();
+                re_grpc_server::spawn_from_rx_set_with_persistence(
+                    server_addr,
+                    server_memory_limit,
+                    persist,
+                    shutdown,
+                    ReceiveSet::new(rxs_log),
+                )
+                .map_err(|err| anyhow::format_err!("Bad --persist-dir {dir:?}: {err}"))?;
+            } else {
+                re_grpc_server::spawn_from_rx_set(
+                    server_addr,
+                    server_memory_limit,
+                    shutdown,
+                    ReceiveSet::new(rxs_log),
+                );
+            }
 
             // Gracefully shut down the server on SIGINT
             tokio_runtime_handle.block_on(tokio::signal::ctrl_c()).ok();
@@ -1143,6 +1219,31 @@ fn run_profiler(args: &Args) -> re_tracing::Profiler {
     profiler
 }
 
+/// The retention rules for the Viewer's recordings, as specified by the `--retention-*` flags.
+///
+/// Returns `None` if none of these flags were set. Otherwise, each flag that is set takes
+/// precedence over the matching rule of blueprints and of the `RERUN_RETENTION_*` environment
+/// variables.
+#[cfg(feature = "native_viewer")]
+fn retention_policy(args: &Args) -> Option<re_chunk_store::RetentionPolicy> {
+    if args.retention_time_window.is_none()
+        && args.retention_max_rows_per_entity.is_none()
+        && args.retention_protect.is_empty()
+    {
+        return None;
+    }
+
+    Some(re_chunk_store::RetentionPolicy {
+        time_window: args.retention_time_window,
+        max_rows_per_entity: args.retention_max_rows_per_entity,
+        protected_entities: args
+            .retention_protect
+            .iter()
+            .map(|entity_path| re_log_types::EntityPath::from(entity_path.as_str()))
+            .collect(),
+    })
+}
+
 #[cfg(feature = "native_viewer")]
 fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
     fn parse_size_inner(size: &str) -> Option<[f32; 2]> {
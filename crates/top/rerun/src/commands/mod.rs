@@ -28,9 +28,17 @@ mod stdio;
 #[cfg(feature = "analytics")]
 mod analytics;
 
+#[cfg(feature = "server")]
+mod server;
+
 pub use self::entrypoint::run;
 pub use self::rrd::RrdCommands;
-pub use self::stdio::read_rrd_streams_from_file_or_stdin;
+pub use self::stdio::{
+    read_rrd_streams_from_file_or_stdin, read_unmigrated_rrd_streams_from_file_or_stdin,
+};
 
 #[cfg(feature = "analytics")]
 pub(crate) use self::analytics::AnalyticsCommands;
+
+#[cfg(feature = "server")]
+pub(crate) use self::server::ServerCommand;
@@ -0,0 +1,1106 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use arrow::array::{Array as _, ArrayRef, AsArray as _, BooleanArray, RecordBatch, StringBuilder};
+use arrow::datatypes::{DataType, Field, Int64Type, Schema, SchemaRef};
+
+use re_chunk::{Chunk, ChunkId, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreHandle};
+use re_dataframe::{
+    ComponentDescriptor, EntityPathFilter, QueryEngine, QueryExpression, ResolvedTimeRange,
+    SparseFillStrategy, StorageEngine, StoreKind, TimeInt, TimelineName,
+};
+use re_log_encoding::{
+    decoder::indexed::IndexedDecoder,
+    footer::{ChunkIndexInfo, ChunkIndexQuery},
+};
+use re_log_types::{EntityPath, LogMsg, StoreId};
+use re_sorbet::{ColumnDescriptor, ColumnSelector, ComponentColumnSelector, TimeColumnSelector};
+
+// ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ExportFormat {
+    /// Apache Parquet. Durations (e.g. on duration timelines) are written out as integers.
+    Parquet,
+
+    /// Comma-separated values. Nested columns (e.g. lists of positions) are written out as text.
+    Csv,
+
+    /// The Arrow IPC file format, also known as Feather v2.
+    Arrow,
+}
+
+impl ExportFormat {
+    fn from_path(path: &str) -> Option<Self> {
+        let extension = std::path::Path::new(path).extension()?.to_str()?;
+        match extension.to_lowercase().as_str() {
+            "parquet" => Some(Self::Parquet),
+            "csv" => Some(Self::Csv),
+            "arrow" | "arrows" | "feather" | "ipc" => Some(Self::Arrow),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum SparseFill {
+    /// Nulls stay nulls.
+    None,
+
+    /// Fill nulls with the latest known value, from anywhere in the recording.
+    LatestAt,
+
+    /// Fill nulls with the latest known value, ignoring anything before `--from`.
+    LatestAtView,
+
+    /// Linearly interpolate floating-point values, and fill everything else like `latest-at`.
+    Linear,
+
+    /// Fill nulls with the closest value on the index, in either direction. See `--max-gap`.
+    Nearest,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCommand {
+    /// Path to read from.
+    path_to_input_rrd: String,
+
+    /// Path to write to.
+    #[arg(short = 'o', long = "output", value_name = "dst.(parquet|csv|arrow)")]
+    path_to_output: String,
+
+    /// Output format. Inferred from the extension of the output path if unspecified.
+    #[clap(long, value_enum)]
+    format: Option<ExportFormat>,
+
+    /// Which recording to export, if the file contains several of them.
+    #[clap(long = "recording-id")]
+    recording_id: Option<String>,
+
+    /// The timeline to use as the index: there is one row per distinct value on that timeline.
+    ///
+    /// Only static data is exported if unspecified.
+    #[clap(long)]
+    index: Option<String>,
+
+    /// Only export rows at or after this value on the index timeline.
+    #[clap(long, requires = "index")]
+    from: Option<i64>,
+
+    /// Only export rows at or before this value on the index timeline.
+    #[clap(long, requires = "index")]
+    to: Option<i64>,
+
+    /// Entity path filter rule, e.g. `+ /world/**` or `- /world/debug/**`. Can be repeated.
+    ///
+    /// All entities are exported if unspecified.
+    #[clap(long = "entity-path-filter")]
+    entity_path_filter: Vec<String>,
+
+    /// Only export these columns, in this order. Can be repeated.
+    ///
+    /// Either a timeline name, or `entity_path:component_name`, e.g. `/world/points:Position3D`.
+    #[clap(long = "column")]
+    columns: Vec<String>,
+
+    /// How to fill in the values that weren't logged at a given index value.
+    #[clap(long = "sparse-fill", value_enum, default_value_t = SparseFill::None)]
+    sparse_fill: SparseFill,
+
+    /// With `--sparse-fill nearest`: ignore values further away than this on the index timeline.
+    #[clap(long = "max-gap")]
+    max_gap: Option<u64>,
+
+    /// How many rows to write at once, at most.
+    #[clap(long = "batch-size", default_value_t = 4096)]
+    batch_size: usize,
+
+    /// Roughly how much data to load at once, e.g. `512MiB`.
+    ///
+    /// The rows are exported one window of the index timeline at a time, each window spanning
+    /// about this much data in the input. Only the current window is kept in memory, along with
+    /// the closest values on either side of it if `--sparse-fill` needs them.
+    #[clap(long = "max-window-size", default_value = "1GiB", value_parser = parse_window_size)]
+    max_window_size: u64,
+}
+
+impl ExportCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrd,
+            path_to_output,
+            format,
+            recording_id: _,
+            index,
+            from,
+            to,
+            entity_path_filter,
+            columns,
+            sparse_fill,
+            max_gap,
+            batch_size,
+            max_window_size: _,
+        } = self;
+
+        let format = format
+            .or_else(|| ExportFormat::from_path(path_to_output))
+            .with_context(|| {
+                format!("couldn't infer the output format of {path_to_output:?}, use --format")
+            })?;
+        anyhow::ensure!(*batch_size > 0, "--batch-size must be positive");
+        anyhow::ensure!(
+            max_gap.is_none() || *sparse_fill == SparseFill::Nearest,
+            "--max-gap only applies to --sparse-fill nearest"
+        );
+
+        let now = std::time::Instant::now();
+        re_log::info!(
+            src = path_to_input_rrd,
+            dst = path_to_output,
+            "export started"
+        );
+
+        let entity_path_filter = if entity_path_filter.is_empty() {
+            None
+        } else {
+            Some(
+                EntityPathFilter::parse_strict(entity_path_filter.join("\n"))
+                    .context("invalid --entity-path-filter")?,
+            )
+        };
+
+        let filtered_index = index.as_deref().map(TimelineName::new);
+        let filtered_index_range = (from.is_some() || to.is_some()).then(|| {
+            ResolvedTimeRange::new(
+                from.map_or(TimeInt::MIN, TimeInt::new_temporal),
+                to.map_or(TimeInt::MAX, TimeInt::new_temporal),
+            )
+        });
+
+        let windows = match &filtered_index {
+            Some(index) => self
+                .plan_windows(
+                    entity_path_filter.as_ref(),
+                    *index,
+                    filtered_index_range.unwrap_or(ResolvedTimeRange::EVERYTHING),
+                )?
+                .into_iter()
+                .map(|window| Some((*index, window)))
+                .collect(),
+
+            // Only static data is exported: there is nothing to window.
+            None => vec![None],
+        };
+        re_log::debug!(num_windows = windows.len(), "planned export windows");
+
+        let mut selection = if columns.is_empty() {
+            None
+        } else {
+            Some(
+                columns
+                    .iter()
+                    .map(|column| parse_column_selector(column))
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+            )
+        };
+
+        // Rows are assigned to windows using the index column, so it has to be queried even if it
+        // isn't selected. It is then left out of the output.
+        let num_selected_columns = selection.as_ref().map(Vec::len);
+        if let (Some(selection), Some(index)) = (&mut selection, filtered_index) {
+            let index_column = ColumnSelector::from(TimeColumnSelector::from(index));
+            if !selection.contains(&index_column) {
+                selection.push(index_column);
+            }
+        }
+
+        // NOTE: There is no need for `view_contents`: only the entities matching the filter are
+        // loaded in the first place.
+        let query = QueryExpression {
+            filtered_index,
+            filtered_index_range,
+            sparse_fill_strategy: match sparse_fill {
+                SparseFill::None => SparseFillStrategy::None,
+                SparseFill::LatestAt => SparseFillStrategy::LatestAtGlobal,
+                SparseFill::LatestAtView => SparseFillStrategy::LatestAtView,
+                SparseFill::Linear => SparseFillStrategy::Linear,
+                SparseFill::Nearest => SparseFillStrategy::Nearest { max_gap: *max_gap },
+            },
+            selection,
+            ..Default::default()
+        };
+
+        let mut writer: Option<(BatchWriter<_>, SchemaRef)> = None;
+        let mut num_rows = 0;
+        for window in windows {
+            let engine = self.load_engine(entity_path_filter.as_ref(), window)?;
+            let query_handle = engine.query(query.clone());
+
+            let index_column = window
+                .map(|(index, _)| {
+                    query_handle
+                        .selected_contents()
+                        .iter()
+                        .position(|(_, column)| match column {
+                            ColumnDescriptor::Time(column) => column.timeline_name() == index,
+                            ColumnDescriptor::Component(_) => false,
+                        })
+                        .with_context(|| {
+                            format!("there is no {index:?} timeline in {path_to_input_rrd:?}")
+                        })
+                })
+                .transpose()?;
+            let output_columns: Vec<usize> = (0..num_selected_columns
+                .unwrap_or(query_handle.selected_contents().len()))
+                .collect();
+
+            let query_schema = query_handle.schema().clone();
+            let output_schema = Arc::new(query_schema.project(&output_columns)?);
+
+            // Every window registers the columns of the whole recording, see `load_engine`.
+            let writer = if let Some((writer, schema)) = &mut writer {
+                anyhow::ensure!(
+                    *schema == output_schema,
+                    "the schema of the export changed between windows"
+                );
+                writer
+            } else {
+                let file = std::fs::File::create(path_to_output)
+                    .with_context(|| format!("{path_to_output:?}"))?;
+                let file_writer =
+                    BatchWriter::new(format, std::io::BufWriter::new(file), &output_schema)?;
+                &mut writer.insert((file_writer, output_schema)).0
+            };
+
+            // Rows are pulled from the query and written out batch by batch, so that the output
+            // never has to be kept in memory.
+            let mut rows = Vec::with_capacity(*batch_size);
+            let mut rows_iter = query_handle.batch_iter().fuse();
+            loop {
+                rows.clear();
+                rows.extend(rows_iter.by_ref().take(*batch_size));
+                if rows.is_empty() {
+                    break;
+                }
+
+                let mut batch = arrow::compute::concat_batches(&query_schema, &rows)
+                    .context("couldn't assemble rows")?;
+                if let (Some((_, window)), Some(index_column)) = (window, index_column) {
+                    batch = select_window(&batch, index_column, window)?;
+                }
+                let batch = batch.project(&output_columns)?;
+
+                if batch.num_rows() > 0 {
+                    writer.write(&batch)?;
+                    num_rows += batch.num_rows();
+                }
+            }
+        }
+
+        let (writer, schema) = writer.context("nothing to export")?;
+        writer.finish()?;
+
+        let output_size = std::fs::metadata(path_to_output)
+            .map(|metadata| re_format::format_bytes(metadata.len() as _))
+            .unwrap_or_else(|_| "<unknown>".to_owned());
+
+        re_log::info!(
+            dst = path_to_output,
+            dst_size_bytes = %output_size,
+            time = ?now.elapsed(),
+            num_rows = %re_format::format_uint(num_rows),
+            num_columns = %re_format::format_uint(schema.fields().len()),
+            "export finished"
+        );
+
+        Ok(())
+    }
+
+    /// Splits `range` of the `index` timeline into consecutive windows, each of which holds about
+    /// `--max-window-size` worth of chunks, so that the export never has to load much more than
+    /// that at once.
+    ///
+    /// Chunk sizes are read from the footer index if the input has one: nothing is decoded then.
+    /// Otherwise, the input is decoded once, and the in-memory size of the chunks is used instead.
+    fn plan_windows(
+        &self,
+        entity_path_filter: Option<&EntityPathFilter>,
+        index: TimelineName,
+        range: ResolvedTimeRange,
+    ) -> anyhow::Result<Vec<ResolvedTimeRange>> {
+        re_tracing::profile_function!();
+
+        let Self {
+            path_to_input_rrd,
+            max_window_size,
+            ..
+        } = self;
+
+        let query = self.chunk_query(entity_path_filter, Some((index, range)));
+        let mut decoder = self.open_input()?;
+
+        // Where each chunk starts within the range, and how big it is.
+        let mut chunks: Vec<(TimeInt, u64)> = Vec::new();
+        let mut add_chunk = |store_id: &StoreId, info: &ChunkIndexInfo, num_bytes: u64| {
+            if store_id.kind != StoreKind::Recording {
+                return;
+            }
+            if let Some(time_range) = info.time_ranges.get(&index) {
+                chunks.push((time_range.min().max(range.min()), num_bytes));
+            }
+        };
+
+        if let Some(footer) = decoder.footer() {
+            for entry in footer.query(&query) {
+                if let Some(info) = &entry.chunk {
+                    add_chunk(&entry.store_id, info, entry.byte_len);
+                }
+            }
+        } else {
+            for msg in decoder.query(&query) {
+                let msg = msg.with_context(|| format!("couldn't decode {path_to_input_rrd:?}"))?;
+                if let LogMsg::ArrowMsg(store_id, msg) = msg {
+                    let info = ChunkIndexInfo::from_record_batch(&msg.batch)
+                        .with_context(|| format!("couldn't decode chunk {path_to_input_rrd:?}"))?;
+                    add_chunk(&store_id, &info, msg.batch.get_array_memory_size() as u64);
+                }
+            }
+        }
+
+        chunks.sort();
+
+        let mut windows = Vec::new();
+        let mut window_start = range.min();
+        let mut window_size = 0_u64;
+        for (chunk_start, num_bytes) in chunks {
+            // NOTE: A window cannot be split in the middle of a single index value.
+            if window_size > 0
+                && window_size.saturating_add(num_bytes) > *max_window_size
+                && chunk_start > window_start
+            {
+                windows.push(ResolvedTimeRange::new(window_start, chunk_start.dec()));
+                window_start = chunk_start;
+                window_size = 0;
+            }
+            window_size = window_size.saturating_add(num_bytes);
+        }
+        windows.push(ResolvedTimeRange::new(window_start, range.max()));
+
+        Ok(windows)
+    }
+
+    /// The messages of the input that the export is about.
+    ///
+    /// Static chunks and messages that aren't chunks always match.
+    fn chunk_query(
+        &self,
+        entity_path_filter: Option<&EntityPathFilter>,
+        time_range: Option<(TimelineName, ResolvedTimeRange)>,
+    ) -> ChunkIndexQuery {
+        ChunkIndexQuery {
+            store_id: self
+                .recording_id
+                .clone()
+                .map(|recording_id| StoreId::from_string(StoreKind::Recording, recording_id)),
+            entity_path_filter: entity_path_filter
+                .map(|filter| filter.clone().resolve_without_substitutions()),
+            time_range,
+        }
+    }
+
+    fn open_input(&self) -> anyhow::Result<IndexedDecoder<std::io::BufReader<std::fs::File>>> {
+        let path_to_input_rrd = &self.path_to_input_rrd;
+        let rrd_file = std::fs::File::open(path_to_input_rrd)
+            .with_context(|| format!("couldn't open {path_to_input_rrd:?}"))?;
+        IndexedDecoder::new(std::io::BufReader::new(rrd_file))
+            .with_context(|| format!("couldn't decode {path_to_input_rrd:?}"))
+    }
+
+    /// Loads the selected recording, restricted to the entities of the export and to one window
+    /// of the index timeline, see [`Self::plan_windows`].
+    ///
+    /// The input is decoded message by message, and only what the export needs is kept around:
+    /// * static chunks are kept as-is;
+    /// * chunks with data in the window are kept as-is;
+    /// * all other chunks only add their columns to the schema, so that columns without any
+    ///   data in the window still show up in the output (as all-null columns);
+    /// * if `--sparse-fill` looks outside of the window, the closest value on either side of it is
+    ///   kept for each column, see [`RangeNeighbors`].
+    ///
+    /// Without a window, i.e. without an index, only static data is loaded.
+    ///
+    /// If the input has a footer index, the chunks of other entities and recordings aren't even
+    /// read from disk.
+    fn load_engine(
+        &self,
+        entity_path_filter: Option<&EntityPathFilter>,
+        window: Option<(TimelineName, ResolvedTimeRange)>,
+    ) -> anyhow::Result<QueryEngine<StorageEngine>> {
+        let Self {
+            path_to_input_rrd,
+            from,
+            sparse_fill,
+            max_gap,
+            ..
+        } = self;
+
+        // The values between `--from` and the start of the window are part of the view.
+        let view_start = from.map_or(TimeInt::MIN, TimeInt::new_temporal);
+
+        let (time_range, look_behind, look_ahead) = match (window, sparse_fill) {
+            (None, _) => (None, false, false),
+            (Some(range), SparseFill::None) => (Some(range), false, false),
+            (Some(range), SparseFill::LatestAtView) => {
+                let look_behind = range.1.min() > view_start;
+                (Some(range), look_behind, false)
+            }
+            (Some(range), SparseFill::LatestAt) => (Some(range), true, false),
+            (Some(range), SparseFill::Linear) => (Some(range), true, true),
+            (Some((index, range)), SparseFill::Nearest) => match max_gap {
+                Some(max_gap) => {
+                    let max_gap = (*max_gap).min(i64::MAX as u64) as i64;
+                    let range = ResolvedTimeRange::new(
+                        range.min().as_i64().saturating_sub(max_gap),
+                        range.max().as_i64().saturating_add(max_gap),
+                    );
+                    (Some((index, range)), false, false)
+                }
+                None => (Some((index, range)), true, true),
+            },
+        };
+
+        // We need to see the chunks outside of the window too, for their columns.
+        let query = self.chunk_query(entity_path_filter, None);
+        let mut decoder = self.open_input()?;
+
+        let mut stores: BTreeMap<StoreId, ChunkStore> = BTreeMap::new();
+        let mut neighbors = RangeNeighbors::default();
+
+        for msg in decoder.query(&query) {
+            let msg = msg.with_context(|| format!("couldn't decode {path_to_input_rrd:?}"))?;
+            match msg {
+                LogMsg::SetStoreInfo(info) => {
+                    let store_id = info.info.store_id.clone();
+                    stores
+                        .entry(store_id.clone())
+                        .or_insert_with(|| {
+                            ChunkStore::new(store_id, ChunkStoreConfig::ALL_DISABLED)
+                        })
+                        .set_info(info.info);
+                }
+
+                LogMsg::ArrowMsg(store_id, msg) => {
+                    let Some(store) = stores.get_mut(&store_id) else {
+                        anyhow::bail!("unknown store ID: {store_id}");
+                    };
+
+                    let chunk = Chunk::from_arrow_msg(&msg)
+                        .with_context(|| format!("couldn't decode chunk {path_to_input_rrd:?}"))?;
+
+                    let is_in_range = chunk.is_static()
+                        || time_range.as_ref().is_some_and(|(index, range)| {
+                            chunk
+                                .timelines()
+                                .get(index)
+                                .is_some_and(|column| column.time_range().intersects(*range))
+                        });
+
+                    if is_in_range {
+                        store.insert_chunk(&Arc::new(chunk)).with_context(|| {
+                            format!("couldn't insert chunk {path_to_input_rrd:?}")
+                        })?;
+                    } else {
+                        store.register_columns(&chunk);
+                        if let Some((index, range)) = &time_range {
+                            neighbors.add(
+                                &store_id,
+                                &chunk,
+                                index,
+                                *range,
+                                look_behind,
+                                look_ahead,
+                            );
+                        }
+                    }
+                }
+
+                LogMsg::BlueprintActivationCommand(_) => {}
+            }
+        }
+
+        for (store_id, chunk) in neighbors.into_chunks() {
+            if let Some(store) = stores.get_mut(&store_id) {
+                store
+                    .insert_chunk(&Arc::new(chunk))
+                    .with_context(|| format!("couldn't insert chunk {path_to_input_rrd:?}"))?;
+            }
+        }
+
+        stores.retain(|store_id, _| store_id.kind == StoreKind::Recording);
+
+        anyhow::ensure!(
+            stores.len() <= 1,
+            "{path_to_input_rrd:?} contains several recordings, pick one with --recording-id: {}",
+            stores
+                .keys()
+                .map(|store_id| store_id.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let Some((_, store)) = stores.pop_first() else {
+            anyhow::bail!("no matching recording found in {path_to_input_rrd:?}");
+        };
+
+        Ok(QueryEngine::from_store(ChunkStoreHandle::new(store)))
+    }
+}
+
+/// For each column, the closest non-null value on either side of a time range.
+///
+/// These are the only values outside of the range that sparse-filling it can ever pick up, so
+/// they're all we need to keep of the chunks that don't intersect it.
+#[derive(Default)]
+struct RangeNeighbors {
+    /// The latest value before the range, for each column.
+    before: HashMap<NeighborKey, Neighbor>,
+
+    /// The earliest value after the range, for each column.
+    after: HashMap<NeighborKey, Neighbor>,
+}
+
+/// Identifies a column of a recording.
+type NeighborKey = (StoreId, EntityPath, ComponentDescriptor);
+
+/// A single value of a column, as a unit chunk, along with where it sits on the index.
+type Neighbor = ((TimeInt, RowId), Chunk);
+
+impl RangeNeighbors {
+    fn add(
+        &mut self,
+        store_id: &StoreId,
+        chunk: &Chunk,
+        index: &TimelineName,
+        range: ResolvedTimeRange,
+        look_behind: bool,
+        look_ahead: bool,
+    ) {
+        let Some(time_column) = chunk.timelines().get(index) else {
+            return;
+        };
+
+        for (component_descr, list_array) in chunk.components().iter() {
+            let mut latest_before: Option<((TimeInt, RowId), usize)> = None;
+            let mut earliest_after: Option<((TimeInt, RowId), usize)> = None;
+
+            let indices = time_column.times().zip(chunk.row_ids()).enumerate();
+            for (row, (time, row_id)) in indices.filter(|(row, _)| list_array.is_valid(*row)) {
+                if look_behind && time < range.min() {
+                    if latest_before.is_none_or(|(latest, _)| latest < (time, row_id)) {
+                        latest_before = Some(((time, row_id), row));
+                    }
+                } else if look_ahead
+                    && time > range.max()
+                    && earliest_after.is_none_or(|(earliest, _)| (time, row_id) < earliest)
+                {
+                    earliest_after = Some(((time, row_id), row));
+                }
+            }
+
+            let key = (
+                store_id.clone(),
+                chunk.entity_path().clone(),
+                component_descr.clone(),
+            );
+
+            // NOTE: Each of these becomes a chunk of its own, hence the new chunk ID.
+            let unit_chunk = |row: usize| {
+                chunk
+                    .component_sliced(component_descr)
+                    .row_sliced(row, 1)
+                    .with_id(ChunkId::new())
+            };
+
+            if let Some((index_value, row)) = latest_before {
+                if self
+                    .before
+                    .get(&key)
+                    .is_none_or(|(latest, _)| *latest < index_value)
+                {
+                    self.before
+                        .insert(key.clone(), (index_value, unit_chunk(row)));
+                }
+            }
+
+            if let Some((index_value, row)) = earliest_after {
+                if self
+                    .after
+                    .get(&key)
+                    .is_none_or(|(earliest, _)| index_value < *earliest)
+                {
+                    self.after.insert(key, (index_value, unit_chunk(row)));
+                }
+            }
+        }
+    }
+
+    fn into_chunks(self) -> impl Iterator<Item = (StoreId, Chunk)> {
+        self.before
+            .into_iter()
+            .chain(self.after)
+            .map(|((store_id, _, _), (_, chunk))| (store_id, chunk))
+    }
+}
+
+fn parse_column_selector(column: &str) -> anyhow::Result<ColumnSelector> {
+    if column.contains(':') {
+        let selector: ComponentColumnSelector = column
+            .parse()
+            .with_context(|| format!("invalid --column {column:?}"))?;
+        Ok(selector.into())
+    } else {
+        Ok(TimeColumnSelector::from(TimelineName::new(column)).into())
+    }
+}
+
+fn parse_window_size(size: &str) -> Result<u64, String> {
+    match re_format::parse_bytes(size) {
+        Some(size) if size > 0 => Ok(size as u64),
+        _ => Err(format!(
+            "expected a positive number of bytes, e.g. 512MiB, got {size:?}"
+        )),
+    }
+}
+
+/// Drops the rows of `batch` that fall outside of `window`, on the index column at `index_column`.
+///
+/// The query yields rows for everything that was loaded, which includes the values that sparse
+/// filling needs on either side of the window: these rows belong to other windows.
+fn select_window(
+    batch: &RecordBatch,
+    index_column: usize,
+    window: ResolvedTimeRange,
+) -> anyhow::Result<RecordBatch> {
+    let times = arrow::compute::cast(batch.column(index_column), &DataType::Int64)?;
+    let is_in_window: BooleanArray = times
+        .as_primitive::<Int64Type>()
+        .iter()
+        .map(|time| Some(time.is_some_and(|time| window.contains(TimeInt::new_temporal(time)))))
+        .collect();
+
+    Ok(arrow::compute::filter_record_batch(batch, &is_in_window)?)
+}
+
+// ---
+
+enum BatchWriter<W: std::io::Write + Send> {
+    Parquet(parquet::arrow::ArrowWriter<W>),
+    Csv(Box<arrow::csv::Writer<W>>),
+    Arrow(arrow::ipc::writer::FileWriter<W>),
+}
+
+impl<W: std::io::Write + Send> BatchWriter<W> {
+    fn new(format: ExportFormat, write: W, schema: &SchemaRef) -> anyhow::Result<Self> {
+        Ok(match format {
+            ExportFormat::Parquet => Self::Parquet(
+                parquet::arrow::ArrowWriter::try_new(write, parquet_schema(schema), None)
+                    .context("couldn't init Parquet writer")?,
+            ),
+            ExportFormat::Csv => Self::Csv(Box::new(arrow::csv::Writer::new(write))),
+            ExportFormat::Arrow => Self::Arrow(
+                arrow::ipc::writer::FileWriter::try_new(write, schema)
+                    .context("couldn't init Arrow IPC writer")?,
+            ),
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> anyhow::Result<()> {
+        match self {
+            Self::Parquet(writer) => writer
+                .write(&durations_to_int64(batch)?)
+                .context("couldn't write Parquet"),
+            Self::Csv(writer) => writer
+                .write(&flatten_for_csv(batch)?)
+                .context("couldn't write CSV"),
+            Self::Arrow(writer) => writer.write(batch).context("couldn't write Arrow IPC"),
+        }
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        let mut write = match self {
+            Self::Parquet(writer) => writer.into_inner().context("couldn't finish Parquet")?,
+            Self::Csv(writer) => (*writer).into_inner(),
+            Self::Arrow(mut writer) => {
+                writer.finish().context("couldn't finish Arrow IPC")?;
+                writer.into_inner().context("couldn't finish Arrow IPC")?
+            }
+        };
+
+        write.flush().context("couldn't flush output")
+    }
+}
+
+/// Parquet has no duration type: these columns are written out as 64-bit integers.
+fn parquet_schema(schema: &SchemaRef) -> SchemaRef {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::Duration(_) => field.as_ref().clone().with_data_type(DataType::Int64),
+            _ => field.as_ref().clone(),
+        })
+        .collect();
+
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// See [`parquet_schema`].
+fn durations_to_int64(batch: &RecordBatch) -> anyhow::Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| match column.data_type() {
+            DataType::Duration(_) => arrow::compute::cast(column, &DataType::Int64),
+            _ => Ok(column.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(RecordBatch::try_new(
+        parquet_schema(&batch.schema()),
+        columns,
+    )?)
+}
+
+/// CSV has no notion of nested data: these columns are replaced with their textual representation.
+fn flatten_for_csv(batch: &RecordBatch) -> anyhow::Result<RecordBatch> {
+    let options = arrow::util::display::FormatOptions::default().with_null("");
+
+    let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = batch
+        .schema()
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            if !field.data_type().is_nested() {
+                return Ok((field.as_ref().clone(), column.clone()));
+            }
+
+            let formatter = arrow::util::display::ArrayFormatter::try_new(column, &options)?;
+            let mut builder = StringBuilder::with_capacity(column.len(), 0);
+            for i in 0..column.len() {
+                if column.is_null(i) {
+                    builder.append_null();
+                } else {
+                    builder.append_value(formatter.value(i).to_string());
+                }
+            }
+
+            Ok((
+                field.as_ref().clone().with_data_type(DataType::Utf8),
+                Arc::new(builder.finish()) as ArrayRef,
+            ))
+        })
+        .collect::<Result<Vec<_>, arrow::error::ArrowError>>()?
+        .into_iter()
+        .unzip();
+
+    let schema = Schema::new_with_metadata(fields, batch.schema().metadata().clone());
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::RecordBatchReader as _;
+    use re_dataframe::external::re_query::StorageEngineLike as _;
+
+    use super::*;
+
+    const NUM_FRAMES: i64 = 10;
+
+    /// Exports the test recording in the given format, and returns the path of the output along
+    /// with what a dataframe query against the same recording yields.
+    fn export(format: ExportFormat, dir: &std::path::Path) -> (std::path::PathBuf, RecordBatch) {
+        let path_to_input_rrd = dir.join("input.rrd");
+        super::super::write_test_recording(&path_to_input_rrd, NUM_FRAMES);
+
+        let path_to_output = dir.join("output");
+        ExportCommand {
+            path_to_input_rrd: path_to_input_rrd.to_string_lossy().into_owned(),
+            path_to_output: path_to_output.to_string_lossy().into_owned(),
+            format: Some(format),
+            recording_id: None,
+            index: Some("frame".to_owned()),
+            from: None,
+            to: None,
+            entity_path_filter: Vec::new(),
+            columns: Vec::new(),
+            sparse_fill: SparseFill::None,
+            max_gap: None,
+            batch_size: 3, // Not a divisor of the number of rows, on purpose.
+            max_window_size: u64::MAX,
+        }
+        .run()
+        .unwrap();
+
+        let mut engines =
+            QueryEngine::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, &path_to_input_rrd)
+                .unwrap();
+        let (_, engine) = engines.pop_first().unwrap();
+        let query_handle = engine.query(QueryExpression {
+            filtered_index: Some(TimelineName::new("frame")),
+            ..Default::default()
+        });
+        let schema = query_handle.schema().clone();
+        let expected =
+            arrow::compute::concat_batches(&schema, &query_handle.batch_iter().collect::<Vec<_>>())
+                .unwrap();
+        assert_eq!(expected.num_rows(), NUM_FRAMES as usize);
+
+        (path_to_output, expected)
+    }
+
+    #[test]
+    fn export_parquet_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (path, expected) = export(ExportFormat::Parquet, dir.path());
+
+        let reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+            std::fs::File::open(path).unwrap(),
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let actual = arrow::compute::concat_batches(&schema, &batches).unwrap();
+
+        // Nested types may come back slightly different from Parquet: compare values.
+        let expected = durations_to_int64(&expected).unwrap();
+        assert_eq!(
+            actual.schema().fields().len(),
+            expected.schema().fields().len()
+        );
+        assert_eq!(
+            flatten_for_csv(&actual).unwrap().columns(),
+            flatten_for_csv(&expected).unwrap().columns()
+        );
+    }
+
+    #[test]
+    fn export_csv_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (path, expected) = export(ExportFormat::Csv, dir.path());
+
+        let expected = flatten_for_csv(&expected).unwrap();
+        let reader = arrow::csv::ReaderBuilder::new(expected.schema())
+            .with_header(true)
+            .build(std::fs::File::open(path).unwrap())
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let actual = arrow::compute::concat_batches(&expected.schema(), &batches).unwrap();
+
+        assert_eq!(actual.columns(), expected.columns());
+    }
+
+    #[test]
+    fn export_arrow_ipc_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (path, expected) = export(ExportFormat::Arrow, dir.path());
+
+        let reader =
+            arrow::ipc::reader::FileReader::try_new(std::fs::File::open(path).unwrap(), None)
+                .unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let actual = arrow::compute::concat_batches(&schema, &batches).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn export_only_loads_what_it_needs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path_to_input_rrd = dir.path().join("input.rrd");
+        super::super::write_test_recording_with_footer_index(&path_to_input_rrd, NUM_FRAMES);
+
+        let command = |sparse_fill| ExportCommand {
+            path_to_input_rrd: path_to_input_rrd.to_string_lossy().into_owned(),
+            path_to_output: dir
+                .path()
+                .join("output.arrow")
+                .to_string_lossy()
+                .into_owned(),
+            format: None,
+            recording_id: None,
+            index: Some("frame".to_owned()),
+            from: Some(2),
+            to: Some(4),
+            entity_path_filter: vec!["+ /world/**".to_owned()],
+            columns: Vec::new(),
+            sparse_fill,
+            max_gap: None,
+            batch_size: 4096,
+            max_window_size: u64::MAX,
+        };
+        let load = |command: &ExportCommand, range| {
+            let filter = EntityPathFilter::parse_strict("+ /world/**").unwrap();
+            command
+                .load_engine(Some(&filter), Some((TimelineName::new("frame"), range)))
+                .unwrap()
+        };
+        let num_chunks = |command: &ExportCommand| {
+            load(command, ResolvedTimeRange::new(2, 4))
+                .engine
+                .with(|store, _cache| store.num_chunks())
+        };
+
+        // The static readme, plus the points of frames 2 to 4.
+        let bounded = command(SparseFill::None);
+        assert_eq!(num_chunks(&bounded), 1 + 3);
+        bounded.run().unwrap();
+
+        // Latest-at only needs the latest value of each column before the range, i.e. frame 1.
+        let num_point_columns =
+            load(&bounded, ResolvedTimeRange::new(2, 4))
+                .engine
+                .with(|store, _cache| {
+                    store
+                        .schema()
+                        .components
+                        .iter()
+                        .filter(|column| column.entity_path == "/world/points".into())
+                        .count()
+                });
+        assert_eq!(
+            num_chunks(&command(SparseFill::LatestAt)),
+            1 + 3 + num_point_columns
+        );
+
+        // Linear interpolation also needs the earliest value after it, i.e. frame 5.
+        assert_eq!(
+            num_chunks(&command(SparseFill::Linear)),
+            1 + 3 + 2 * num_point_columns
+        );
+
+        // Columns without any data in the range are still there.
+        let schema = |range| load(&bounded, range).schema();
+        let out_of_range = ResolvedTimeRange::new(NUM_FRAMES + 10, NUM_FRAMES + 20);
+        assert_eq!(schema(out_of_range), schema(ResolvedTimeRange::EVERYTHING));
+    }
+
+    /// Exporting one small window at a time yields exactly the same thing as exporting everything
+    /// at once, whatever the sparse-fill strategy.
+    #[test]
+    fn export_in_windows() {
+        use re_build_info::CrateVersion;
+        use re_log_types::{TimePoint, Timeline};
+
+        let dir = tempfile::tempdir().unwrap();
+
+        // Two columns logged at different rates, so that most rows need sparse filling.
+        let store_id = StoreId::random(StoreKind::Recording);
+        let mut chunks = Vec::new();
+        for (entity_path, step) in [("/a", 3), ("/b", 5)] {
+            for frame in (0..40).step_by(step).map(|frame| frame + step as i64 - 2) {
+                chunks.push(
+                    Chunk::builder(entity_path.into())
+                        .with_archetype(
+                            RowId::new(),
+                            TimePoint::default().with(Timeline::new_sequence("frame"), frame),
+                            &re_types::archetypes::Scalars::new([frame as f64]),
+                        )
+                        .build()
+                        .unwrap(),
+                );
+            }
+        }
+        let msgs =
+            re_log_encoding::testing::recording_messages(&store_id, CrateVersion::LOCAL, chunks)
+                .unwrap();
+
+        for footer_index in [false, true] {
+            let path_to_input_rrd = dir.path().join(format!("input_{footer_index}.rrd"));
+            re_log_encoding::testing::write_rrd_file(
+                &path_to_input_rrd,
+                CrateVersion::LOCAL,
+                &msgs,
+                footer_index,
+            )
+            .unwrap();
+
+            let command = |sparse_fill, max_gap, from, to, columns: &[&str]| ExportCommand {
+                path_to_input_rrd: path_to_input_rrd.to_string_lossy().into_owned(),
+                path_to_output: dir
+                    .path()
+                    .join("output.arrow")
+                    .to_string_lossy()
+                    .into_owned(),
+                format: None,
+                recording_id: None,
+                index: Some("frame".to_owned()),
+                from,
+                to,
+                entity_path_filter: Vec::new(),
+                columns: columns.iter().map(|column| (*column).to_owned()).collect(),
+                sparse_fill,
+                max_gap,
+                batch_size: 4,
+                max_window_size: u64::MAX,
+            };
+            let export = |command: &ExportCommand| {
+                command.run().unwrap();
+                let reader = arrow::ipc::reader::FileReader::try_new(
+                    std::fs::File::open(&command.path_to_output).unwrap(),
+                    None,
+                )
+                .unwrap();
+                let schema = reader.schema();
+                let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+                arrow::compute::concat_batches(&schema, &batches).unwrap()
+            };
+
+            let cases = [
+                (SparseFill::None, None),
+                (SparseFill::LatestAt, None),
+                (SparseFill::LatestAtView, None),
+                (SparseFill::Linear, None),
+                (SparseFill::Nearest, None),
+                (SparseFill::Nearest, Some(1)),
+            ];
+            for (sparse_fill, max_gap) in cases {
+                for (from, to) in [(None, None), (Some(10), Some(30))] {
+                    for columns in [&[][..], &["/a:Scalar"][..]] {
+                        let at_once = command(sparse_fill, max_gap, from, to, columns);
+                        let windowed = ExportCommand {
+                            max_window_size: 1,
+                            ..at_once.clone()
+                        };
+
+                        let num_windows = windowed
+                            .plan_windows(
+                                None,
+                                TimelineName::new("frame"),
+                                ResolvedTimeRange::new(
+                                    from.map_or(TimeInt::MIN, TimeInt::new_temporal),
+                                    to.map_or(TimeInt::MAX, TimeInt::new_temporal),
+                                ),
+                            )
+                            .unwrap()
+                            .len();
+                        assert!(num_windows > 5, "{num_windows}");
+
+                        let expected = export(&at_once);
+                        assert!(expected.num_rows() > 0);
+                        assert_eq!(
+                            export(&windowed),
+                            expected,
+                            "{sparse_fill:?} (max gap: {max_gap:?}) from {from:?} to {to:?}, \
+                            columns: {columns:?}, footer index: {footer_index}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
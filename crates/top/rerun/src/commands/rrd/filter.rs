@@ -32,6 +32,13 @@ pub struct FilterCommand {
     #[clap(long = "drop-entity")]
     dropped_entity_paths: Vec<String>,
 
+    /// If set, end the output file with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    /// Ignored when writing to standard output.
+    #[clap(long = "footer-index", default_value_t = false)]
+    footer_index: bool,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -44,10 +51,12 @@ impl FilterCommand {
             path_to_output_rrd,
             dropped_timelines,
             dropped_entity_paths,
+            footer_index,
             continue_on_error,
         } = self;
 
         let path_to_output_rrd = path_to_output_rrd.clone();
+        let footer_index = *footer_index;
         if path_to_output_rrd.is_none() {
             anyhow::ensure!(
                 !std::io::stdout().is_terminal(),
@@ -89,6 +98,9 @@ impl FilterCommand {
                     re_log_encoding::encoder::DroppableEncoder::new(version, options, &mut rrd_out)
                         .context("couldn't init encoder")?
                 };
+                if path_to_output_rrd.is_some() && footer_index {
+                    encoder = encoder.with_footer_index();
+                }
 
                 let mut size_bytes = 0;
                 for msg in rx_encoder {
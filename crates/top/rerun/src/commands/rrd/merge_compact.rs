@@ -21,6 +21,19 @@ pub struct MergeCommand {
     #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
     path_to_output_rrd: Option<String>,
 
+    /// Compression used for the output: `off`, `lz4`, `zstd` or `zstd:<level>` (1-22).
+    ///
+    /// Note that zstd-compressed recordings can only be read by Rerun 0.24 and newer.
+    #[arg(long = "compression", default_value = "lz4")]
+    compression: re_log_encoding::Compression,
+
+    /// If set, end the output file with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    /// Ignored when writing to standard output.
+    #[clap(long = "footer-index", default_value_t = false)]
+    footer_index: bool,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -31,6 +44,8 @@ impl MergeCommand {
         let Self {
             path_to_input_rrds,
             path_to_output_rrd,
+            compression,
+            footer_index,
             continue_on_error,
         } = self;
 
@@ -52,6 +67,8 @@ impl MergeCommand {
             &store_config,
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
+            *compression,
+            *footer_index,
         )
     }
 }
@@ -67,6 +84,12 @@ pub struct CompactCommand {
     #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
     path_to_output_rrd: Option<String>,
 
+    /// Compression used for the output: `off`, `lz4`, `zstd` or `zstd:<level>` (1-22).
+    ///
+    /// Note that zstd-compressed recordings can only be read by Rerun 0.24 and newer.
+    #[arg(long = "compression", default_value = "lz4")]
+    compression: re_log_encoding::Compression,
+
     /// What is the threshold, in bytes, after which a Chunk cannot be compacted any further?
     ///
     /// Overrides `RERUN_CHUNK_MAX_BYTES` if set.
@@ -87,6 +110,13 @@ pub struct CompactCommand {
     #[arg(long = "max-rows-if-unsorted")]
     max_rows_if_unsorted: Option<u64>,
 
+    /// If set, end the output file with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    /// Ignored when writing to standard output.
+    #[clap(long = "footer-index", default_value_t = false)]
+    footer_index: bool,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -97,9 +127,11 @@ impl CompactCommand {
         let Self {
             path_to_input_rrds,
             path_to_output_rrd,
+            compression,
             max_bytes,
             max_rows,
             max_rows_if_unsorted,
+            footer_index,
             continue_on_error,
         } = self;
 
@@ -130,6 +162,8 @@ impl CompactCommand {
             &store_config,
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
+            *compression,
+            *footer_index,
         )
     }
 }
@@ -139,6 +173,8 @@ fn merge_and_compact(
     store_config: &ChunkStoreConfig,
     path_to_input_rrds: &[String],
     path_to_output_rrd: Option<&String>,
+    compression: re_log_encoding::Compression,
+    footer_index: bool,
 ) -> anyhow::Result<()> {
     let file_size_to_string = |size: Option<u64>| {
         size.map_or_else(
@@ -152,6 +188,7 @@ fn merge_and_compact(
         max_rows = %re_format::format_uint(store_config.chunk_max_rows),
         max_rows_if_unsorted = %re_format::format_uint(store_config.chunk_max_rows_if_unsorted),
         max_bytes = %re_format::format_bytes(store_config.chunk_max_bytes as _),
+        %compression,
         srcs = ?path_to_input_rrds,
         "merge/compaction started"
     );
@@ -211,23 +248,32 @@ fn merge_and_compact(
         .filter(|entity_db| entity_db.store_kind() == StoreKind::Recording)
         .flat_map(|entity_db| entity_db.to_messages(None /* time selection */));
 
-    // TODO(cmc): encoding options should match the original.
-    let encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+    let encoding_options = re_log_encoding::EncodingOptions {
+        compression,
+        serializer: re_log_encoding::Serializer::Protobuf,
+    };
     let version = entity_dbs
         .values()
         .next()
         .and_then(|db| db.store_info())
         .and_then(|info| info.store_version)
         .unwrap_or(re_build_info::CrateVersion::LOCAL);
-    let rrd_out_size = re_log_encoding::encoder::encode(
-        version,
-        encoding_options,
-        // NOTE: We want to make sure all blueprints come first, so that the viewer can immediately
-        // set up the viewport correctly.
-        messages_rbl.chain(messages_rrd),
-        &mut rrd_out,
-    )
-    .context("couldn't encode messages")?;
+    let mut encoder =
+        re_log_encoding::encoder::DroppableEncoder::new(version, encoding_options, &mut rrd_out)
+            .context("couldn't init encoder")?;
+    if path_to_output_rrd.is_some() && footer_index {
+        encoder = encoder.with_footer_index();
+    }
+
+    let mut rrd_out_size = 0;
+    // NOTE: We want to make sure all blueprints come first, so that the viewer can immediately
+    // set up the viewport correctly.
+    for msg in messages_rbl.chain(messages_rrd) {
+        let msg = msg.context("couldn't encode messages")?;
+        rrd_out_size += encoder.append(&msg).context("couldn't encode messages")?;
+    }
+    encoder.finish().context("couldn't encode messages")?;
+    drop(encoder);
 
     rrd_out.flush().context("couldn't flush output")?;
 
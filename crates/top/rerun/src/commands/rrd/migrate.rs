@@ -0,0 +1,297 @@
+use std::io::IsTerminal as _;
+
+use anyhow::Context as _;
+use itertools::Either;
+
+use re_build_info::CrateVersion;
+use re_chunk::external::crossbeam;
+use re_sorbet::MigrationReport;
+
+use crate::commands::read_unmigrated_rrd_streams_from_file_or_stdin;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct MigrateCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Path to write to. Writes to standard output if unspecified.
+    #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
+    path_to_output_rrd: Option<String>,
+
+    /// If set, end the output file with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    /// Ignored when writing to standard output.
+    #[clap(long = "footer-index", default_value_t = false)]
+    footer_index: bool,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl MigrateCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_rrd,
+            footer_index,
+            continue_on_error,
+        } = self;
+
+        let path_to_output_rrd = path_to_output_rrd.clone();
+        let footer_index = *footer_index;
+        if path_to_output_rrd.is_none() {
+            anyhow::ensure!(
+                !std::io::stdout().is_terminal(),
+                "you must redirect the output to a file and/or stream"
+            );
+        }
+
+        let now = std::time::Instant::now();
+        re_log::info!(srcs = ?path_to_input_rrds, "migration started");
+
+        let (rx_decoder, rx_size_bytes) =
+            read_unmigrated_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        // TODO(cmc): might want to make this configurable at some point.
+        let (tx_encoder, rx_encoder) = crossbeam::channel::bounded(100);
+
+        let encoding_handle = std::thread::Builder::new()
+            .name("rerun-rrd-migrate-out".to_owned())
+            .spawn(move || -> anyhow::Result<u64> {
+                use std::io::Write as _;
+
+                let mut rrd_out = if let Some(path) = path_to_output_rrd.as_ref() {
+                    Either::Left(std::io::BufWriter::new(
+                        std::fs::File::create(path).with_context(|| format!("{path:?}"))?,
+                    ))
+                } else {
+                    Either::Right(std::io::BufWriter::new(std::io::stdout().lock()))
+                };
+
+                let mut encoder = {
+                    // The whole point is to end up with a file written by the current version.
+                    let version = CrateVersion::LOCAL;
+                    let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+                    re_log_encoding::encoder::DroppableEncoder::new(version, options, &mut rrd_out)
+                        .context("couldn't init encoder")?
+                };
+                if path_to_output_rrd.is_some() && footer_index {
+                    encoder = encoder.with_footer_index();
+                }
+
+                let mut size_bytes = 0;
+                for msg in rx_encoder {
+                    size_bytes += encoder.append(&msg).context("encoding failure")?;
+                }
+
+                drop(encoder);
+                rrd_out.flush().context("couldn't flush output")?;
+
+                Ok(size_bytes)
+            });
+
+        let mut report = MigrationReport::default();
+        let mut num_chunks = 0;
+
+        for (_source, res) in rx_decoder {
+            let mut is_success = true;
+
+            match res {
+                Ok((recorded_version, msg)) => {
+                    let msg = match msg {
+                        re_log_types::LogMsg::ArrowMsg(store_id, mut msg) => {
+                            num_chunks += 1;
+                            // Only apply the migrations that the version which encoded the
+                            // stream needs, just like the regular decoders do.
+                            msg.batch = re_sorbet::migrate_record_batch_with_report(
+                                &msg.batch,
+                                re_sorbet::MIGRATIONS,
+                                Some(recorded_version),
+                                &mut report,
+                            );
+                            re_log_types::LogMsg::ArrowMsg(store_id, msg)
+                        }
+
+                        msg @ (re_log_types::LogMsg::SetStoreInfo(_)
+                        | re_log_types::LogMsg::BlueprintActivationCommand(_)) => msg,
+                    };
+
+                    tx_encoder.send(msg).ok();
+                }
+
+                Err(err) => {
+                    re_log::error!(err = re_error::format(err));
+                    is_success = false;
+                }
+            }
+
+            if !*continue_on_error && !is_success {
+                anyhow::bail!(
+                    "one or more IO and/or decoding failures in the input stream (check logs)"
+                )
+            }
+        }
+
+        std::mem::drop(tx_encoder);
+        let rrd_out_size = encoding_handle
+            .context("couldn't spawn IO thread")?
+            .join()
+            .map_err(|err| anyhow::anyhow!("Unknown error: {err:?}"))??; // NOLINT: there is no `Display` for this `err`
+
+        let rrds_in_size = rx_size_bytes.recv().ok();
+
+        let file_size_to_string = |size: Option<u64>| {
+            size.map_or_else(
+                || "<unknown>".to_owned(),
+                |size| re_format::format_bytes(size as _),
+            )
+        };
+
+        if report.is_empty() {
+            re_log::info!("all {num_chunks} chunks were already up-to-date");
+        } else {
+            for ((version, description), num_columns) in &report.num_columns_per_migration {
+                re_log::info!("{version}: {description} ({num_columns} column(s))");
+            }
+        }
+
+        re_log::info!(
+            dst_size_bytes = %file_size_to_string(Some(rrd_out_size)),
+            time = ?now.elapsed(),
+            num_chunks,
+            srcs = ?path_to_input_rrds,
+            srcs_size_bytes = %file_size_to_string(rrds_in_size),
+            "migration finished"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::datatypes::{Field, Schema};
+    use re_chunk::{external::arrow::array::RecordBatch, Chunk, RowId};
+    use re_log_types::{LogMsg, StoreId, StoreKind, TimePoint, Timeline};
+    use re_sorbet::MetadataExt as _;
+
+    use super::*;
+
+    /// Rewrites the batch as if it had been logged before `Scalar` got renamed to `Scalars`.
+    fn into_legacy_scalar(batch: &RecordBatch) -> RecordBatch {
+        let fields = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                let mut metadata = field.metadata().clone();
+                if field.get_opt("rerun.archetype") == Some("rerun.archetypes.Scalars") {
+                    metadata.insert(
+                        "rerun.archetype".to_owned(),
+                        "rerun.archetypes.Scalar".to_owned(),
+                    );
+                    if field.get_opt("rerun.archetype_field") == Some("scalars") {
+                        metadata.insert("rerun.archetype_field".to_owned(), "scalar".to_owned());
+                    }
+                }
+                Field::clone(field).with_metadata(metadata)
+            })
+            .collect::<Vec<_>>();
+
+        let schema = Schema::new_with_metadata(fields, batch.schema().metadata.clone());
+        RecordBatch::try_new(Arc::new(schema), batch.columns().to_vec()).unwrap()
+    }
+
+    /// Writes a recording with legacy `Scalar` data, as if written by the given SDK version.
+    fn write_legacy_recording(path: &std::path::Path, version: CrateVersion) {
+        let chunks = (0..3).map(|frame| {
+            Chunk::builder("/plots/scalar".into())
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default().with(Timeline::new_sequence("frame"), frame),
+                    &re_types::archetypes::Scalars::new([frame as f64]),
+                )
+                .build()
+                .unwrap()
+        });
+
+        let store_id = StoreId::random(StoreKind::Recording);
+        let mut msgs =
+            re_log_encoding::testing::recording_messages(&store_id, version, chunks).unwrap();
+        for msg in &mut msgs {
+            if let LogMsg::ArrowMsg(_, msg) = msg {
+                msg.batch = into_legacy_scalar(&msg.batch);
+            }
+        }
+
+        re_log_encoding::testing::write_rrd_file(path, version, &msgs, false).unwrap();
+    }
+
+    /// Migrates a legacy recording written by the given SDK version, and returns the archetype
+    /// names found in the output.
+    fn migrate(version: CrateVersion) -> std::collections::BTreeSet<String> {
+        let dir = tempfile::tempdir().unwrap();
+        let path_to_input_rrd = dir.path().join("input.rrd");
+        let path_to_output_rrd = dir.path().join("output.rrd");
+        write_legacy_recording(&path_to_input_rrd, version);
+
+        MigrateCommand {
+            path_to_input_rrds: vec![path_to_input_rrd.to_string_lossy().into_owned()],
+            path_to_output_rrd: Some(path_to_output_rrd.to_string_lossy().into_owned()),
+            footer_index: false,
+            continue_on_error: false,
+        }
+        .run()
+        .unwrap();
+
+        let file = std::fs::File::open(path_to_output_rrd).unwrap();
+        let msgs = re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let mut num_chunks = 0;
+        let archetypes = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(msg),
+                _ => None,
+            })
+            .inspect(|_| num_chunks += 1)
+            .flat_map(|msg| {
+                msg.batch
+                    .schema()
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.get_opt("rerun.archetype").map(ToOwned::to_owned))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(num_chunks, 3);
+
+        archetypes
+    }
+
+    #[test]
+    fn migrate_legacy_recording() {
+        // Pre-releases of 0.23 might predate the rename.
+        let archetypes = migrate(CrateVersion::parse("0.23.0-alpha.1"));
+        assert_eq!(
+            archetypes,
+            std::collections::BTreeSet::from(["rerun.archetypes.Scalars".to_owned()])
+        );
+
+        // Data written by 0.23 and newer already uses the new names: nothing to migrate.
+        let archetypes = migrate(CrateVersion::new(0, 23, 0));
+        assert_eq!(
+            archetypes,
+            std::collections::BTreeSet::from(["rerun.archetypes.Scalar".to_owned()])
+        );
+    }
+}
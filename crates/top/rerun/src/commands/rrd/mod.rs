@@ -1,13 +1,21 @@
 mod compare;
+mod export;
 mod filter;
 mod merge_compact;
+mod migrate;
 mod print;
+mod split;
+mod stats;
 mod verify;
 
 use self::compare::CompareCommand;
+use self::export::ExportCommand;
 use self::filter::FilterCommand;
 use self::merge_compact::{CompactCommand, MergeCommand};
+use self::migrate::MigrateCommand;
 use self::print::PrintCommand;
+use self::split::SplitCommand;
+use self::stats::StatsCommand;
 use self::verify::VerifyCommand;
 
 // ---
@@ -71,6 +79,51 @@ pub enum RrdCommands {
     ///
     /// Example: `rerun filter --drop-timeline log_tick /my/recordings/*.rrd > output.rrd`
     Filter(FilterCommand),
+
+    /// Migrates the contents of .rrd/.rbl files/streams written by older versions of Rerun to the
+    /// current schema, and writes the result to standard output.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Logs a report of everything that was changed.
+    /// This will not affect the chunking of the data in any way.
+    ///
+    /// Example: `rerun rrd migrate /my/old/recording.rrd -o recording.rrd`
+    Migrate(MigrateCommand),
+
+    /// Exports the contents of an .rrd file to Parquet, CSV or Arrow IPC, by running a dataframe
+    /// query against it.
+    ///
+    /// The output format is inferred from the extension of the output path, unless `--format` is
+    /// specified.
+    ///
+    /// Only the entities selected by `--entity-path-filter`, and unless `--sparse-fill` needs more,
+    /// the chunks within `--from`/`--to` are loaded in memory. If the input has a footer index,
+    /// nothing else is even read from disk. The results are then queried and written out batch by
+    /// batch, so the output never has to fit in memory.
+    ///
+    /// Example: `rerun rrd export /my/recording.rrd --index frame --entity-path-filter '+ /world/**' -o world.parquet`
+    Export(ExportCommand),
+
+    /// Splits the contents of one or more .rrd/.rbl files/streams into several .rrd files.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// The data can be split by time (`--every`, `--boundary`), by top-level entity subtree
+    /// (`--by-entity`), or by size (`--max-bytes`).
+    ///
+    /// Store infos, blueprints and static data are replicated into every output, so that each of
+    /// them can be opened on its own. So is temporal data that isn't on the split timeline.
+    ///
+    /// Example: `rerun rrd split /my/recording.rrd --timeline sim_time --every 10000000000 -o episode.rrd`
+    Split(SplitCommand),
+
+    /// Reports where the bytes of an .rrd file go: chunk and row counts, in-memory, uncompressed
+    /// and compressed sizes, time ranges, and the ratio of unsorted chunks, per entity and per
+    /// component.
+    ///
+    /// Example: `rerun rrd stats /my/recording.rrd --format json`
+    Stats(StatsCommand),
 }
 
 impl RrdCommands {
@@ -87,6 +140,85 @@ impl RrdCommands {
             Self::Compact(compact_command) => compact_command.run(),
             Self::Merge(merge_command) => merge_command.run(),
             Self::Filter(drop_command) => drop_command.run(),
+            Self::Migrate(migrate_command) => migrate_command.run(),
+            Self::Export(export_command) => export_command.run(),
+            Self::Split(split_command) => split_command.run(),
+            Self::Stats(stats_command) => stats_command.run(),
         }
     }
 }
+
+/// Writes a small recording to `path`, for tests.
+///
+/// Each of the `num_frames` frames of the `frame` sequence timeline has a chunk of points under
+/// `/world/points` and a chunk of scalars under `/plots/scalar`. There is also a static text
+/// document under `/world/readme`.
+#[cfg(test)]
+fn write_test_recording(path: &std::path::Path, num_frames: i64) -> re_log_types::StoreId {
+    write_test_recording_impl(path, num_frames, false)
+}
+
+/// Same as [`write_test_recording`], with a footer index.
+#[cfg(test)]
+fn write_test_recording_with_footer_index(
+    path: &std::path::Path,
+    num_frames: i64,
+) -> re_log_types::StoreId {
+    write_test_recording_impl(path, num_frames, true)
+}
+
+#[cfg(test)]
+fn write_test_recording_impl(
+    path: &std::path::Path,
+    num_frames: i64,
+    footer_index: bool,
+) -> re_log_types::StoreId {
+    use re_build_info::CrateVersion;
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{StoreId, StoreKind, TimePoint, Timeline};
+
+    let store_id = StoreId::random(StoreKind::Recording);
+
+    let mut chunks = vec![Chunk::builder("/world/readme".into())
+        .with_archetype(
+            RowId::new(),
+            TimePoint::default(),
+            &re_types::archetypes::TextDocument::new("hello"),
+        )
+        .build()
+        .unwrap()];
+
+    for frame in 0..num_frames {
+        let timepoint = TimePoint::default().with(Timeline::new_sequence("frame"), frame);
+        chunks.push(
+            Chunk::builder("/world/points".into())
+                .with_archetype(
+                    RowId::new(),
+                    timepoint.clone(),
+                    &re_types::archetypes::Points2D::new([
+                        (frame as f32, 0.0),
+                        (0.0, frame as f32),
+                    ]),
+                )
+                .build()
+                .unwrap(),
+        );
+        chunks.push(
+            Chunk::builder("/plots/scalar".into())
+                .with_archetype(
+                    RowId::new(),
+                    timepoint,
+                    &re_types::archetypes::Scalars::new([frame as f64]),
+                )
+                .build()
+                .unwrap(),
+        );
+    }
+
+    let msgs = re_log_encoding::testing::recording_messages(&store_id, CrateVersion::LOCAL, chunks)
+        .unwrap();
+    re_log_encoding::testing::write_rrd_file(path, CrateVersion::LOCAL, &msgs, footer_index)
+        .unwrap();
+
+    store_id
+}
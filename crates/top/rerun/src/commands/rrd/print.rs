@@ -30,6 +30,13 @@ pub struct PrintCommand {
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = true)]
     continue_on_error: bool,
+
+    /// If set, print the footer index of each file instead of its contents.
+    ///
+    /// This is nearly instantaneous, regardless of the size of the file.
+    /// Only works on files (not standard input) that were written with a footer index.
+    #[clap(long, default_value_t = false)]
+    footer: bool,
 }
 
 impl PrintCommand {
@@ -38,8 +45,20 @@ impl PrintCommand {
             path_to_input_rrds,
             verbose,
             continue_on_error,
+            footer,
         } = self;
 
+        if *footer {
+            anyhow::ensure!(
+                !path_to_input_rrds.is_empty(),
+                "--footer requires paths to .rrd files, it cannot read from standard input"
+            );
+            for path in path_to_input_rrds {
+                print_footer(*verbose, path)?;
+            }
+            return Ok(());
+        }
+
         let (rx, _) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
 
         for (_source, res) in rx {
@@ -70,6 +89,64 @@ impl PrintCommand {
     }
 }
 
+fn print_footer(verbose: u8, path: &str) -> anyhow::Result<()> {
+    let mut file = std::fs::File::open(path).with_context(|| format!("couldn't open {path:?}"))?;
+    let Some(footer) = re_log_encoding::decoder::indexed::read_footer(&mut file)
+        .with_context(|| format!("couldn't read footer of {path:?}"))?
+    else {
+        println!("{path}: no footer index");
+        return Ok(());
+    };
+
+    println!(
+        "{path}: footer index with {} messages, {} chunks",
+        footer.entries.len(),
+        footer.chunks().count(),
+    );
+
+    for entry in &footer.entries {
+        let Some(chunk) = &entry.chunk else {
+            if verbose > 0 {
+                println!(
+                    "@{} ({}) - {} - not a chunk",
+                    entry.byte_offset,
+                    re_format::format_bytes(entry.byte_len as _),
+                    entry.store_id,
+                );
+            }
+            continue;
+        };
+
+        let time_ranges = if chunk.is_static {
+            "static".to_owned()
+        } else {
+            chunk
+                .time_ranges
+                .iter()
+                .map(|(timeline, range)| {
+                    format!(
+                        "{timeline}: [{}, {}]",
+                        range.min().as_i64(),
+                        range.max().as_i64()
+                    )
+                })
+                .join(", ")
+        };
+
+        println!(
+            "@{} ({}) - {} - Chunk({}) with {} rows - {:?} - {time_ranges}",
+            entry.byte_offset,
+            re_format::format_bytes(entry.byte_len as _),
+            entry.store_id,
+            chunk.chunk_id,
+            chunk.num_rows,
+            chunk.entity_path,
+        );
+    }
+
+    Ok(())
+}
+
 fn print_msg(verbose: u8, msg: LogMsg) -> anyhow::Result<()> {
     match msg {
         LogMsg::SetStoreInfo(msg) => {
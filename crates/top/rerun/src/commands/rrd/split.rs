@@ -0,0 +1,804 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+
+use re_build_info::CrateVersion;
+use re_chunk::{Chunk, ChunkId, TimelineName};
+use re_log_types::{EntityPath, LogMsg, StoreId, StoreKind};
+
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+#[clap(group(
+    clap::ArgGroup::new("split_by")
+        .required(true)
+        .args(["every", "boundaries", "by_entity", "max_bytes"]),
+))]
+pub struct SplitCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Where to write to: `dst.rrd` results in `dst.0000.rrd`, `dst.0001.rrd`, etc.
+    ///
+    /// Outputs are named after their subtree with `--by-entity` (`dst.world.rrd`), and after the
+    /// start of their time range with `--every` (`dst.frame_300.rrd`).
+    #[arg(short = 'o', long = "output", value_name = "dst.rrd")]
+    path_to_output_rrd: String,
+
+    /// The timeline to split on, see `--every` and `--boundary`.
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// Start a new file every N units of `--timeline`: nanoseconds for temporal timelines, steps
+    /// for sequence timelines.
+    #[clap(long, requires = "timeline")]
+    every: Option<u64>,
+
+    /// Start a new file at this value of `--timeline`, e.g. at an episode boundary. Can be repeated.
+    #[clap(long = "boundary", requires = "timeline")]
+    boundaries: Vec<i64>,
+
+    /// Write each top-level entity subtree (e.g. `/world`, `/sensors`) to its own file.
+    #[clap(long = "by-entity", default_value_t = false)]
+    by_entity: bool,
+
+    /// Start a new file before the current one grows past this size, e.g. `1GB`.
+    ///
+    /// This applies to the data itself: the footer index at the end of each file, if any, comes on
+    /// top of it.
+    #[clap(long = "max-bytes")]
+    max_bytes: Option<String>,
+
+    /// If set, end each output file with a footer index, making it randomly accessible.
+    ///
+    /// Note that files with a footer index can only be read by Rerun 0.24 and newer.
+    #[clap(long = "footer-index", default_value_t = false)]
+    footer_index: bool,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl SplitCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_rrd,
+            timeline,
+            every,
+            boundaries,
+            by_entity,
+            max_bytes,
+            footer_index,
+            continue_on_error,
+        } = self;
+
+        let split_by = if let Some(every) = every {
+            anyhow::ensure!(*every > 0, "--every must be positive");
+            SplitBy::Every(
+                TimelineName::new(timeline.as_deref().unwrap_or_default()),
+                *every,
+            )
+        } else if !boundaries.is_empty() {
+            let mut boundaries = boundaries.clone();
+            boundaries.sort_unstable();
+            boundaries.dedup();
+            SplitBy::Boundaries(
+                TimelineName::new(timeline.as_deref().unwrap_or_default()),
+                boundaries,
+            )
+        } else if *by_entity {
+            SplitBy::Entity
+        } else if let Some(max_bytes) = max_bytes {
+            let max_bytes = re_format::parse_bytes(max_bytes)
+                .and_then(|max_bytes| u64::try_from(max_bytes).ok())
+                .with_context(|| format!("invalid --max-bytes {max_bytes:?}"))?;
+            SplitBy::Size(max_bytes)
+        } else {
+            anyhow::bail!("nothing to split by");
+        };
+
+        let now = std::time::Instant::now();
+        re_log::info!(srcs = ?path_to_input_rrds, ?split_by, "split started");
+
+        let mut splitter = Splitter::new(path_to_output_rrd.into(), split_by, *footer_index);
+
+        let (rx_decoder, rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        for (_source, res) in rx_decoder {
+            let mut is_success = true;
+
+            match res {
+                Ok(msg) => splitter.push(msg)?,
+
+                Err(err) => {
+                    re_log::error!(err = re_error::format(err));
+                    is_success = false;
+                }
+            }
+
+            if !*continue_on_error && !is_success {
+                anyhow::bail!(
+                    "one or more IO and/or decoding failures in the input stream (check logs)"
+                )
+            }
+        }
+
+        let outputs = splitter.finish()?;
+
+        let rrds_in_size = rx_size_bytes.recv().ok();
+        let file_size_to_string = |size: Option<u64>| {
+            size.map_or_else(
+                || "<unknown>".to_owned(),
+                |size| re_format::format_bytes(size as _),
+            )
+        };
+
+        for (path, size_bytes) in &outputs {
+            re_log::info!(dst = ?path, dst_size_bytes = %file_size_to_string(Some(*size_bytes)));
+        }
+
+        re_log::info!(
+            num_dsts = outputs.len(),
+            time = ?now.elapsed(),
+            srcs = ?path_to_input_rrds,
+            srcs_size_bytes = %file_size_to_string(rrds_in_size),
+            "split finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+#[derive(Debug)]
+enum SplitBy {
+    /// One output per `N` units of the timeline.
+    Every(TimelineName, u64),
+
+    /// One output before the first boundary, then one per boundary.
+    Boundaries(TimelineName, Vec<i64>),
+
+    /// One output per top-level entity subtree.
+    Entity,
+
+    /// Start a new output before the current one grows past this many bytes.
+    Size(u64),
+}
+
+/// Identifies an output file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum OutputKey {
+    Index(u64),
+
+    /// The first value of the time range covered by the output.
+    Time(i64),
+
+    /// The first part of the entity paths covered by the output, or `None` for the root.
+    Entity(Option<String>),
+}
+
+impl OutputKey {
+    fn suffix(&self, split_by: &SplitBy) -> String {
+        match (self, split_by) {
+            (Self::Index(index), _) => format!("{index:04}"),
+            (Self::Time(time), SplitBy::Every(timeline, _)) => format!("{timeline}_{time}"),
+            (Self::Time(time), _) => time.to_string(),
+            (Self::Entity(Some(part)), _) => part
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect(),
+            (Self::Entity(None), _) => "_root".to_owned(),
+        }
+    }
+
+    /// Does data scoped to the given entity subtree belong in this output?
+    ///
+    /// Data that isn't scoped to a subtree (`None`) belongs in every output.
+    fn includes(&self, scope: Option<&Self>) -> bool {
+        scope.is_none_or(|scope| scope == self)
+    }
+}
+
+struct Output {
+    path: PathBuf,
+    encoder: re_log_encoding::encoder::DroppableEncoder<std::io::BufWriter<std::fs::File>>,
+    num_bytes: u64,
+
+    /// Whether anything other than the replicated data was written to it yet.
+    has_data: bool,
+}
+
+impl Output {
+    /// Closes the output, returning its path and size.
+    fn finish(mut self) -> anyhow::Result<(PathBuf, u64)> {
+        self.encoder.finish().context("encoding failure")?;
+        self.encoder
+            .flush_blocking()
+            .with_context(|| format!("couldn't flush {:?}", self.path))?;
+        let num_bytes =
+            std::fs::metadata(&self.path).map_or(self.num_bytes, |metadata| metadata.len());
+        Ok((self.path, num_bytes))
+    }
+}
+
+struct Splitter {
+    path_to_output_rrd: PathBuf,
+    split_by: SplitBy,
+    footer_index: bool,
+
+    /// The messages every output starts with, so that each of them is self-contained: store infos,
+    /// static data, blueprints…
+    ///
+    /// When splitting by entity, static data is only replicated to the output of its own subtree.
+    /// Outputs that were closed already don't get the data that is replicated after the fact.
+    replicated: Vec<(Option<OutputKey>, LogMsg)>,
+
+    /// The outputs currently being written to.
+    ///
+    /// Except when splitting by entity, an output is closed as soon as the split moves past it, so
+    /// that only a handful of them are ever open at once.
+    open: BTreeMap<OutputKey, Output>,
+
+    /// How many files were opened for each output.
+    ///
+    /// Data that comes in after the split has moved past its output goes to a new file.
+    num_files: BTreeMap<OutputKey, usize>,
+
+    /// The paths and sizes of the outputs that were closed already.
+    closed: Vec<(PathBuf, u64)>,
+
+    /// The output currently being filled, when splitting by size.
+    current_index: u64,
+}
+
+impl Splitter {
+    fn new(path_to_output_rrd: PathBuf, split_by: SplitBy, footer_index: bool) -> Self {
+        Self {
+            path_to_output_rrd,
+            split_by,
+            footer_index,
+            replicated: Vec::new(),
+            open: BTreeMap::new(),
+            num_files: BTreeMap::new(),
+            closed: Vec::new(),
+            current_index: 0,
+        }
+    }
+
+    fn push(&mut self, msg: LogMsg) -> anyhow::Result<()> {
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg else {
+            return self.replicate(None, msg);
+        };
+
+        if store_id.kind != StoreKind::Recording {
+            return self.replicate(None, msg);
+        }
+
+        let chunk = Chunk::from_arrow_msg(arrow_msg).context("couldn't decode chunk")?;
+
+        if chunk.is_static() {
+            let scope = match self.split_by {
+                SplitBy::Entity => Some(entity_key(chunk.entity_path()))
+                    .filter(|key| *key != OutputKey::Entity(None)),
+                _ => None,
+            };
+            return self.replicate(scope, msg);
+        }
+
+        match &self.split_by {
+            SplitBy::Every(timeline, every) => {
+                let every = *every as i64;
+                self.push_sliced(store_id, &chunk, *timeline, |time| {
+                    OutputKey::Time(time.div_euclid(every) * every)
+                })
+            }
+
+            SplitBy::Boundaries(timeline, boundaries) => {
+                let boundaries = boundaries.clone();
+                self.push_sliced(store_id, &chunk, *timeline, |time| {
+                    OutputKey::Index(boundaries.partition_point(|boundary| *boundary <= time) as _)
+                })
+            }
+
+            SplitBy::Entity => {
+                let key = entity_key(chunk.entity_path());
+                self.write(key, store_id, &chunk)
+            }
+
+            SplitBy::Size(max_bytes) => {
+                let max_bytes = *max_bytes;
+                let key = OutputKey::Index(self.current_index);
+
+                // We can't know the encoded size in advance: the in-memory size is a conservative estimate.
+                use re_byte_size::SizeBytes as _;
+                if let Some(output) = self.open.get(&key) {
+                    if output.has_data && max_bytes < output.num_bytes + chunk.total_size_bytes() {
+                        self.current_index += 1;
+                    }
+                }
+
+                self.write(OutputKey::Index(self.current_index), store_id, &chunk)
+            }
+        }
+    }
+
+    /// Writes the rows of `chunk` to the outputs their time on `timeline` maps to.
+    ///
+    /// Chunks that don't have `timeline` at all are replicated to all outputs.
+    fn push_sliced(
+        &mut self,
+        store_id: &StoreId,
+        chunk: &Chunk,
+        timeline: TimelineName,
+        key_for_time: impl Fn(i64) -> OutputKey,
+    ) -> anyhow::Result<()> {
+        if !chunk.timelines().contains_key(&timeline) {
+            let msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+            return self.replicate(None, msg);
+        }
+
+        let sorted = chunk.sorted_by_timeline_if_unsorted(&timeline);
+        let times = sorted
+            .timelines()
+            .get(&timeline)
+            .map(|time_column| time_column.times_raw())
+            .unwrap_or_default();
+
+        let mut slices = Vec::new();
+        let mut start = 0;
+        for i in 1..=times.len() {
+            let key = key_for_time(times[start]);
+            if i == times.len() || key_for_time(times[i]) != key {
+                slices.push((key, start, i - start));
+                start = i;
+            }
+        }
+
+        if let [(key, _, _)] = slices.as_slice() {
+            return self.write(key.clone(), store_id, chunk);
+        }
+
+        for (key, index, len) in slices {
+            // Each slice is a chunk of its own.
+            let slice = sorted.row_sliced(index, len).with_id(ChunkId::new());
+            self.write(key, store_id, &slice)?;
+        }
+
+        Ok(())
+    }
+
+    fn replicate(&mut self, scope: Option<OutputKey>, msg: LogMsg) -> anyhow::Result<()> {
+        if let SplitBy::Size(max_bytes) = self.split_by {
+            // Data that is replicated late must not push the current output past the limit either:
+            // start the next one instead, which begins with all the replicated data.
+            use re_byte_size::SizeBytes as _;
+            let key = OutputKey::Index(self.current_index);
+            if self.open.get(&key).is_some_and(|output| {
+                output.has_data && max_bytes < output.num_bytes + msg.total_size_bytes()
+            }) {
+                self.current_index += 1;
+                self.replicated.push((scope, msg));
+
+                // Open it right away, so that this data makes it to disk even if nothing follows.
+                self.output(OutputKey::Index(self.current_index))?;
+                return Ok(());
+            }
+        }
+
+        for (key, output) in &mut self.open {
+            if key.includes(scope.as_ref()) {
+                output.num_bytes += output.encoder.append(&msg).context("encoding failure")?;
+            }
+        }
+
+        self.replicated.push((scope, msg));
+
+        Ok(())
+    }
+
+    fn write(&mut self, key: OutputKey, store_id: &StoreId, chunk: &Chunk) -> anyhow::Result<()> {
+        let msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+
+        let output = self.output(key)?;
+        output.num_bytes += output.encoder.append(&msg).context("encoding failure")?;
+        output.has_data = true;
+
+        Ok(())
+    }
+
+    /// Returns the output for `key`, opening it if needed.
+    ///
+    /// Opening an output closes all the ones the split has moved past, except when splitting by
+    /// entity.
+    fn output(&mut self, key: OutputKey) -> anyhow::Result<&mut Output> {
+        if !self.open.contains_key(&key) && !matches!(self.split_by, SplitBy::Entity) {
+            let passed: Vec<OutputKey> = self
+                .open
+                .range(..&key)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for passed in passed {
+                if let Some(output) = self.open.remove(&passed) {
+                    self.closed.push(output.finish()?);
+                }
+            }
+        }
+
+        Ok(match self.open.entry(key) {
+            std::collections::btree_map::Entry::Occupied(entry) => entry.into_mut(),
+            std::collections::btree_map::Entry::Vacant(entry) => {
+                let num_files = self.num_files.entry(entry.key().clone()).or_default();
+                let mut suffix = entry.key().suffix(&self.split_by);
+                if *num_files > 0 {
+                    re_log::debug!(
+                        suffix,
+                        "data came in after its output was closed, writing it to a new file"
+                    );
+                    suffix = format!("{suffix}.{num_files}");
+                }
+                *num_files += 1;
+
+                let output = open_output(
+                    &self.path_to_output_rrd,
+                    &suffix,
+                    self.replicated
+                        .iter()
+                        .filter(|(scope, _)| entry.key().includes(scope.as_ref()))
+                        .map(|(_, msg)| msg),
+                    self.footer_index,
+                )?;
+                entry.insert(output)
+            }
+        })
+    }
+
+    /// Closes all outputs, returning their paths and sizes.
+    fn finish(mut self) -> anyhow::Result<Vec<(PathBuf, u64)>> {
+        if self.open.is_empty() && self.closed.is_empty() && !self.replicated.is_empty() {
+            // Nothing to split, but the data still has to go somewhere.
+            self.output(OutputKey::Index(0))?;
+        }
+
+        let Self {
+            open, mut closed, ..
+        } = self;
+
+        for output in open.into_values() {
+            closed.push(output.finish()?);
+        }
+        closed.sort();
+
+        Ok(closed)
+    }
+}
+
+/// The output an entity belongs to, when splitting by entity.
+fn entity_key(entity_path: &EntityPath) -> OutputKey {
+    OutputKey::Entity(
+        entity_path
+            .iter()
+            .next()
+            .map(|part| part.unescaped_str().to_owned()),
+    )
+}
+
+fn open_output<'a>(
+    path_to_output_rrd: &std::path::Path,
+    suffix: &str,
+    replicated: impl Iterator<Item = &'a LogMsg>,
+    footer_index: bool,
+) -> anyhow::Result<Output> {
+    let stem = path_to_output_rrd
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("split");
+    let path = path_to_output_rrd.with_file_name(format!("{stem}.{suffix}.rrd"));
+
+    let file = std::fs::File::create(&path).with_context(|| format!("{path:?}"))?;
+    let mut encoder = re_log_encoding::encoder::DroppableEncoder::new(
+        CrateVersion::LOCAL,
+        re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+        std::io::BufWriter::new(file),
+    )
+    .context("couldn't init encoder")?;
+    if footer_index {
+        encoder = encoder.with_footer_index();
+    }
+
+    let mut num_bytes = 0;
+    for msg in replicated {
+        num_bytes += encoder.append(msg).context("encoding failure")?;
+    }
+
+    Ok(Output {
+        path,
+        encoder,
+        num_bytes,
+        has_data: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use re_chunk::RowId;
+    use re_log_types::TimePoint;
+
+    use super::*;
+
+    const NUM_FRAMES: i64 = 10;
+
+    fn read_rrd(path: &std::path::Path) -> Vec<LogMsg> {
+        let file = std::fs::File::open(path).unwrap();
+        re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// Splits the test recording, and returns the contents of each output, by file name.
+    fn split(configure: impl FnOnce(&mut SplitCommand)) -> BTreeMap<String, Vec<LogMsg>> {
+        let dir = tempfile::tempdir().unwrap();
+        let path_to_input_rrd = dir.path().join("input.rrd");
+        super::super::write_test_recording(&path_to_input_rrd, NUM_FRAMES);
+
+        let mut command = SplitCommand {
+            path_to_input_rrds: vec![path_to_input_rrd.to_string_lossy().into_owned()],
+            path_to_output_rrd: dir.path().join("output.rrd").to_string_lossy().into_owned(),
+            timeline: None,
+            every: None,
+            boundaries: Vec::new(),
+            by_entity: false,
+            max_bytes: None,
+            footer_index: false,
+            continue_on_error: false,
+        };
+        configure(&mut command);
+        command.run().unwrap();
+
+        std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| *path != path_to_input_rrd)
+            .map(|path| {
+                let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                (name, read_rrd(&path))
+            })
+            .collect()
+    }
+
+    fn chunks(msgs: &[LogMsg]) -> Vec<Chunk> {
+        msgs.iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(Chunk::from_arrow_msg(arrow_msg).unwrap()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The values of the `frame` timeline, across all chunks.
+    fn frames(msgs: &[LogMsg]) -> Vec<i64> {
+        chunks(msgs)
+            .iter()
+            .filter_map(|chunk| chunk.timelines().get(&TimelineName::new("frame")).cloned())
+            .flat_map(|time_column| time_column.times_raw().to_vec())
+            .collect()
+    }
+
+    fn entity_paths(msgs: &[LogMsg]) -> BTreeSet<String> {
+        chunks(msgs)
+            .iter()
+            .map(|chunk| chunk.entity_path().to_string())
+            .collect()
+    }
+
+    /// Every output must be self-contained.
+    fn assert_self_contained(name: &str, msgs: &[LogMsg]) {
+        assert!(
+            matches!(msgs.first(), Some(LogMsg::SetStoreInfo(_))),
+            "{name} doesn't start with a store info"
+        );
+        assert!(
+            chunks(msgs).iter().any(|chunk| chunk.is_static()),
+            "{name} is missing static data"
+        );
+    }
+
+    fn frames_in(range: std::ops::Range<i64>) -> Vec<i64> {
+        range.flat_map(|frame| [frame, frame]).collect()
+    }
+
+    #[test]
+    fn split_every() {
+        let outputs = split(|command| {
+            command.timeline = Some("frame".to_owned());
+            command.every = Some(4);
+        });
+
+        assert_eq!(
+            outputs.keys().collect::<Vec<_>>(),
+            [
+                "output.frame_0.rrd",
+                "output.frame_4.rrd",
+                "output.frame_8.rrd"
+            ]
+        );
+        for (name, msgs) in &outputs {
+            assert_self_contained(name, msgs);
+        }
+
+        assert_eq!(frames(&outputs["output.frame_0.rrd"]), frames_in(0..4));
+        assert_eq!(frames(&outputs["output.frame_4.rrd"]), frames_in(4..8));
+        assert_eq!(frames(&outputs["output.frame_8.rrd"]), frames_in(8..10));
+    }
+
+    #[test]
+    fn split_boundaries() {
+        let outputs = split(|command| {
+            command.timeline = Some("frame".to_owned());
+            command.boundaries = vec![7, 3];
+        });
+
+        assert_eq!(
+            outputs.keys().collect::<Vec<_>>(),
+            ["output.0000.rrd", "output.0001.rrd", "output.0002.rrd"]
+        );
+        for (name, msgs) in &outputs {
+            assert_self_contained(name, msgs);
+        }
+
+        assert_eq!(frames(&outputs["output.0000.rrd"]), frames_in(0..3));
+        assert_eq!(frames(&outputs["output.0001.rrd"]), frames_in(3..7));
+        assert_eq!(frames(&outputs["output.0002.rrd"]), frames_in(7..10));
+    }
+
+    #[test]
+    fn split_every_late_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let path_to_input_rrd = dir.path().join("input.rrd");
+        let store_id = super::super::write_test_recording(&path_to_input_rrd, NUM_FRAMES);
+
+        let mut splitter = Splitter::new(
+            dir.path().join("output.rrd"),
+            SplitBy::Every(TimelineName::new("frame"), 4),
+            false,
+        );
+        for msg in read_rrd(&path_to_input_rrd) {
+            splitter.push(msg).unwrap();
+        }
+
+        // A chunk for an output that has been closed already.
+        let late = Chunk::builder("/plots/scalar".into())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(re_log_types::Timeline::new_sequence("frame"), 1),
+                &re_types::archetypes::Scalars::new([42.0]),
+            )
+            .build()
+            .unwrap();
+        splitter
+            .push(LogMsg::ArrowMsg(store_id, late.to_arrow_msg().unwrap()))
+            .unwrap();
+
+        let outputs = splitter.finish().unwrap();
+        let names: Vec<_> = outputs
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "output.frame_0.1.rrd",
+                "output.frame_0.rrd",
+                "output.frame_4.rrd",
+                "output.frame_8.rrd"
+            ]
+        );
+
+        let late_msgs = read_rrd(&outputs[0].0);
+        assert_self_contained(&names[0], &late_msgs);
+        assert_eq!(frames(&late_msgs), [1]);
+        assert_eq!(frames(&read_rrd(&outputs[1].0)), frames_in(0..4));
+    }
+
+    #[test]
+    fn split_by_entity() {
+        let outputs = split(|command| command.by_entity = true);
+
+        assert_eq!(
+            outputs.keys().collect::<Vec<_>>(),
+            ["output.plots.rrd", "output.world.rrd"]
+        );
+
+        // Static data only goes to the output of its own subtree.
+        assert_eq!(
+            entity_paths(&outputs["output.world.rrd"]),
+            BTreeSet::from(["/world/points".to_owned(), "/world/readme".to_owned()])
+        );
+        assert_eq!(
+            entity_paths(&outputs["output.plots.rrd"]),
+            BTreeSet::from(["/plots/scalar".to_owned()])
+        );
+
+        assert_eq!(
+            frames(&outputs["output.world.rrd"]),
+            (0..NUM_FRAMES).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            frames(&outputs["output.plots.rrd"]),
+            (0..NUM_FRAMES).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn split_max_bytes() {
+        const MAX_BYTES: u64 = 16_000;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path_to_input_rrd = dir.path().join("input.rrd");
+        let store_id = super::super::write_test_recording(&path_to_input_rrd, NUM_FRAMES);
+
+        let mut splitter = Splitter::new(
+            dir.path().join("output.rrd"),
+            SplitBy::Size(MAX_BYTES),
+            false,
+        );
+        for msg in read_rrd(&path_to_input_rrd) {
+            splitter.push(msg).unwrap();
+        }
+
+        // Static data that comes in late, and doesn't compress well.
+        let mut state = 1_u32;
+        let noise: String = (0..8_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                char::from(b'a' + ((state >> 16) % 26) as u8)
+            })
+            .collect();
+        let late = Chunk::builder("/world/notes".into())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &re_types::archetypes::TextDocument::new(noise),
+            )
+            .build()
+            .unwrap();
+        splitter
+            .push(LogMsg::ArrowMsg(store_id, late.to_arrow_msg().unwrap()))
+            .unwrap();
+
+        let outputs = splitter.finish().unwrap();
+        assert!(outputs.len() > 1, "{outputs:?}");
+        for (path, num_bytes) in &outputs {
+            assert!(*num_bytes <= MAX_BYTES, "{path:?} is {num_bytes} bytes");
+            assert_self_contained(&path.to_string_lossy(), &read_rrd(path));
+        }
+
+        // All the data made it, in order.
+        let all_frames: Vec<i64> = outputs
+            .iter()
+            .flat_map(|(path, _)| frames(&read_rrd(path)))
+            .collect();
+        assert_eq!(all_frames, frames_in(0..NUM_FRAMES));
+
+        // The late static data only made it to the last output.
+        let (last, others) = outputs.split_last().unwrap();
+        assert!(entity_paths(&read_rrd(&last.0)).contains("/world/notes"));
+        for (path, _) in others {
+            assert!(!entity_paths(&read_rrd(path)).contains("/world/notes"));
+        }
+    }
+}
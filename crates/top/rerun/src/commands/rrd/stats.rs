@@ -0,0 +1,509 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use arrow::array::Array as _;
+
+use re_build_info::CrateVersion;
+use re_chunk::{Chunk, EntityPath, TimelineName};
+use re_chunk_store::{ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig};
+use re_log_types::{LogMsg, ResolvedTimeRange, StoreId};
+use re_types::ComponentDescriptor;
+
+// ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// One human-readable table per store.
+    Table,
+
+    /// Machine-readable JSON.
+    Json,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct StatsCommand {
+    /// Path to read from.
+    path_to_input_rrd: String,
+
+    /// How to print the stats.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+impl StatsCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrd,
+            format,
+        } = self;
+
+        // No compaction: we want to see the chunks as they are in the file.
+        let stores =
+            ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, path_to_input_rrd)
+                .with_context(|| format!("{path_to_input_rrd:?}"))?;
+
+        let mut encoded_size = EncodedSize::new()?;
+
+        let stats = stores
+            .values()
+            .map(|store| StoreStats::new(store, &mut encoded_size))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        match format {
+            OutputFormat::Table => {
+                for (store, stats) in stores.values().zip(&stats) {
+                    println!("{}\n{}", stats.store_id, stats.to_table(&store.timelines()));
+                }
+            }
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Measures the size of chunks once encoded, with and without compression.
+struct EncodedSize {
+    uncompressed: re_log_encoding::encoder::Encoder<std::io::Sink>,
+    compressed: re_log_encoding::encoder::Encoder<std::io::Sink>,
+}
+
+impl EncodedSize {
+    fn new() -> anyhow::Result<Self> {
+        let encoder = |options| {
+            re_log_encoding::encoder::Encoder::new(CrateVersion::LOCAL, options, std::io::sink())
+                .context("couldn't init encoder")
+        };
+
+        Ok(Self {
+            uncompressed: encoder(re_log_encoding::EncodingOptions::PROTOBUF_UNCOMPRESSED)?,
+            compressed: encoder(re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED)?,
+        })
+    }
+
+    /// Returns the uncompressed and compressed sizes of `chunk`, in bytes.
+    fn measure(&mut self, store_id: &StoreId, chunk: &Chunk) -> anyhow::Result<(u64, u64)> {
+        let msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+        Ok((
+            self.uncompressed.append(&msg)?,
+            self.compressed.append(&msg)?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+struct TimeRange {
+    min: i64,
+    max: i64,
+}
+
+/// Stats about a collection of chunks, or of the columns of a component within them.
+#[derive(Debug, Default, serde::Serialize)]
+struct Stats {
+    num_chunks: u64,
+    num_rows: u64,
+
+    /// Size once loaded in memory, see [`ChunkStoreChunkStats::total_size_bytes`].
+    ///
+    /// Only measured for whole chunks, i.e. not for components.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory_bytes: Option<u64>,
+
+    /// Size once encoded in an .rrd file, without and with compression.
+    ///
+    /// For components, this is measured on chunks that only contain that component: it includes
+    /// the cost of their row IDs and timelines.
+    uncompressed_bytes: u64,
+    compressed_bytes: u64,
+
+    /// Chunks that aren't sorted by row ID, or on at least one of their timelines.
+    num_unsorted_chunks: u64,
+    unsorted_chunk_ratio: f64,
+
+    time_ranges: BTreeMap<String, TimeRange>,
+}
+
+impl Stats {
+    fn add_chunk(
+        &mut self,
+        num_rows: u64,
+        (uncompressed_bytes, compressed_bytes): (u64, u64),
+        chunk: &Chunk,
+    ) {
+        self.num_chunks += 1;
+        self.num_rows += num_rows;
+        self.uncompressed_bytes += uncompressed_bytes;
+        self.compressed_bytes += compressed_bytes;
+        if !chunk.is_sorted() || !chunk.is_time_sorted() {
+            self.num_unsorted_chunks += 1;
+        }
+        self.unsorted_chunk_ratio = self.num_unsorted_chunks as f64 / self.num_chunks as f64;
+    }
+
+    fn add_time_range(&mut self, timeline: TimelineName, time_range: ResolvedTimeRange) {
+        let (min, max) = (time_range.min().as_i64(), time_range.max().as_i64());
+        self.time_ranges
+            .entry(timeline.to_string())
+            .and_modify(|range| {
+                range.min = range.min.min(min);
+                range.max = range.max.max(max);
+            })
+            .or_insert(TimeRange { min, max });
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.num_chunks += other.num_chunks;
+        self.num_rows += other.num_rows;
+        if let Some(memory_bytes) = other.memory_bytes {
+            *self.memory_bytes.get_or_insert(0) += memory_bytes;
+        }
+        self.uncompressed_bytes += other.uncompressed_bytes;
+        self.compressed_bytes += other.compressed_bytes;
+        self.num_unsorted_chunks += other.num_unsorted_chunks;
+        if 0 < self.num_chunks {
+            self.unsorted_chunk_ratio = self.num_unsorted_chunks as f64 / self.num_chunks as f64;
+        }
+        for (timeline, range) in &other.time_ranges {
+            self.add_time_range(
+                TimelineName::new(timeline),
+                ResolvedTimeRange::new(range.min, range.max),
+            );
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ComponentStats {
+    component: String,
+
+    #[serde(skip)]
+    short_name: String,
+
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EntityStats {
+    entity_path: String,
+
+    #[serde(flatten)]
+    stats: Stats,
+
+    components: Vec<ComponentStats>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct StoreStats {
+    store_id: String,
+    total: Stats,
+    entities: Vec<EntityStats>,
+}
+
+impl StoreStats {
+    fn new(store: &ChunkStore, encoded_size: &mut EncodedSize) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        let store_id = store.id();
+
+        let mut entities: BTreeMap<EntityPath, (Stats, BTreeMap<ComponentDescriptor, Stats>)> =
+            BTreeMap::new();
+
+        for chunk in store.iter_chunks() {
+            let (entity_stats, component_stats) =
+                entities.entry(chunk.entity_path().clone()).or_default();
+
+            let chunk_stats = ChunkStoreChunkStats::from_chunk(chunk);
+            let chunk_encoded_size = encoded_size.measure(&store_id, chunk)?;
+            entity_stats.add_chunk(chunk_stats.num_rows, chunk_encoded_size, chunk);
+            *entity_stats.memory_bytes.get_or_insert(0) += chunk_stats.total_size_bytes;
+            for (timeline, time_column) in chunk.timelines() {
+                entity_stats.add_time_range(*timeline, time_column.time_range());
+            }
+
+            for (descr, list_array) in chunk.components().iter() {
+                let encoded_size = if chunk.num_components() == 1 {
+                    chunk_encoded_size
+                } else {
+                    encoded_size.measure(&store_id, &chunk.component_sliced(descr))?
+                };
+
+                let stats = component_stats.entry(descr.clone()).or_default();
+                stats.add_chunk(
+                    (list_array.len() - list_array.null_count()) as u64,
+                    encoded_size,
+                    chunk,
+                );
+            }
+
+            for (timeline, time_range_per_component) in chunk.time_range_per_component() {
+                for (descr, time_range) in time_range_per_component {
+                    if let Some(stats) = component_stats.get_mut(&descr) {
+                        stats.add_time_range(timeline, time_range);
+                    }
+                }
+            }
+        }
+
+        let mut total = Stats::default();
+        let entities = entities
+            .into_iter()
+            .map(|(entity_path, (stats, components))| {
+                total.add(&stats);
+                EntityStats {
+                    entity_path: entity_path.to_string(),
+                    stats,
+                    components: components
+                        .into_iter()
+                        .map(|(descr, stats)| ComponentStats {
+                            component: descr.full_name(),
+                            short_name: descr.short_name(),
+                            stats,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            store_id: store_id_to_string(&store_id),
+            total,
+            entities,
+        })
+    }
+
+    fn to_table(
+        &self,
+        timelines: &BTreeMap<TimelineName, re_log_types::Timeline>,
+    ) -> comfy_table::Table {
+        let mut table = comfy_table::Table::new();
+        table.load_preset(comfy_table::presets::UTF8_FULL);
+        table.set_content_arrangement(comfy_table::ContentArrangement::Disabled);
+        table.set_header([
+            "Entity / component",
+            "Chunks",
+            "Rows",
+            "Memory",
+            "Uncompressed",
+            "Compressed",
+            "Unsorted chunks",
+            "Time ranges",
+        ]);
+
+        let row = |name: String, stats: &Stats| {
+            let time_ranges = stats
+                .time_ranges
+                .iter()
+                .map(|(timeline_name, range)| {
+                    let range = ResolvedTimeRange::new(range.min, range.max);
+                    let range = timelines
+                        .get(&TimelineName::new(timeline_name))
+                        .map_or_else(
+                            || format!("{}..={}", range.min().as_i64(), range.max().as_i64()),
+                            |timeline| timeline.format_time_range_utc(&range),
+                        );
+                    format!("{timeline_name}: {range}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            vec![
+                name,
+                re_format::format_uint(stats.num_chunks),
+                re_format::format_uint(stats.num_rows),
+                stats
+                    .memory_bytes
+                    .map(|memory_bytes| re_format::format_bytes(memory_bytes as _))
+                    .unwrap_or_default(),
+                re_format::format_bytes(stats.uncompressed_bytes as _),
+                re_format::format_bytes(stats.compressed_bytes as _),
+                format!("{:.1}%", 100.0 * stats.unsorted_chunk_ratio),
+                time_ranges,
+            ]
+        };
+
+        table.add_row(row("TOTAL".to_owned(), &self.total));
+        for entity in &self.entities {
+            table.add_row(row(entity.entity_path.clone(), &entity.stats));
+            for component in &entity.components {
+                table.add_row(row(format!("  {}", component.short_name), &component.stats));
+            }
+        }
+
+        table
+    }
+}
+
+fn store_id_to_string(store_id: &StoreId) -> String {
+    format!("{}: {}", store_id.kind, store_id.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::archetypes::{Points2D, Scalars};
+
+    use super::*;
+
+    const NUM_FRAMES: i64 = 10;
+
+    fn test_stats() -> (ChunkStore, StoreStats) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.rrd");
+        super::super::write_test_recording(&path, NUM_FRAMES);
+
+        let mut stores =
+            ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, &path).unwrap();
+        let (_, store) = stores.pop_first().unwrap();
+        let stats = StoreStats::new(&store, &mut EncodedSize::new().unwrap()).unwrap();
+
+        (store, stats)
+    }
+
+    fn entity<'a>(stats: &'a StoreStats, entity_path: &str) -> &'a EntityStats {
+        stats
+            .entities
+            .iter()
+            .find(|entity| entity.entity_path == entity_path)
+            .unwrap()
+    }
+
+    fn component<'a>(entity: &'a EntityStats, descr: &ComponentDescriptor) -> &'a Stats {
+        &entity
+            .components
+            .iter()
+            .find(|component| component.component == descr.full_name())
+            .unwrap()
+            .stats
+    }
+
+    #[test]
+    fn stats_per_component() {
+        let (_store, stats) = test_stats();
+
+        assert_eq!(stats.total.num_chunks, 2 * NUM_FRAMES as u64 + 1);
+        assert_eq!(stats.total.num_rows, 2 * NUM_FRAMES as u64 + 1);
+        assert_eq!(
+            stats
+                .entities
+                .iter()
+                .map(|entity| entity.entity_path.as_str())
+                .collect::<Vec<_>>(),
+            ["/plots/scalar", "/world/points", "/world/readme"]
+        );
+
+        for (entity_path, descr) in [
+            ("/plots/scalar", Scalars::descriptor_scalars()),
+            ("/world/points", Points2D::descriptor_positions()),
+        ] {
+            let entity = entity(&stats, entity_path);
+            assert_eq!(entity.stats.num_chunks, NUM_FRAMES as u64);
+            assert_eq!(entity.stats.num_rows, NUM_FRAMES as u64);
+            assert_eq!(entity.stats.num_unsorted_chunks, 0);
+            assert_eq!(entity.stats.time_ranges["frame"].min, 0);
+            assert_eq!(entity.stats.time_ranges["frame"].max, NUM_FRAMES - 1);
+
+            let component = component(entity, &descr);
+            assert_eq!(component.num_chunks, NUM_FRAMES as u64);
+            assert_eq!(component.num_rows, NUM_FRAMES as u64);
+            assert!(component.memory_bytes.is_none());
+
+            // Measured on chunks that only contain that component.
+            assert!(0 < component.uncompressed_bytes);
+            assert!(component.uncompressed_bytes < entity.stats.uncompressed_bytes);
+            assert!(0 < component.compressed_bytes);
+            assert!(component.compressed_bytes < entity.stats.compressed_bytes);
+        }
+
+        let readme = entity(&stats, "/world/readme");
+        assert_eq!(readme.stats.num_chunks, 1);
+        assert!(readme.stats.time_ranges.is_empty());
+
+        let total_bytes: u64 = stats
+            .entities
+            .iter()
+            .map(|entity| entity.stats.uncompressed_bytes)
+            .sum();
+        assert_eq!(stats.total.uncompressed_bytes, total_bytes);
+        assert_eq!(
+            stats.total.memory_bytes,
+            stats
+                .entities
+                .iter()
+                .map(|entity| entity.stats.memory_bytes)
+                .sum()
+        );
+    }
+
+    #[test]
+    fn stats_table() {
+        let (store, stats) = test_stats();
+        let table = stats.to_table(&store.timelines()).to_string();
+
+        // The cells of the row whose first cell is `name`, separators and padding trimmed.
+        let row = |name: &str| {
+            table
+                .lines()
+                .map(|line| {
+                    line.trim_matches('│')
+                        .split('┆')
+                        .map(str::trim)
+                        .collect::<Vec<_>>()
+                })
+                .find(|cells| cells[0] == name)
+                .unwrap_or_else(|| panic!("no row for {name:?} in:\n{table}"))
+        };
+
+        let total = row("TOTAL");
+        assert_eq!(total[1..3], ["21", "21"]);
+        assert_eq!(total[6], "0.0%");
+
+        let points = row("/world/points");
+        assert_eq!(points[1..3], ["10", "10"]);
+        assert_eq!(points[7], "frame: #0..=#9");
+
+        let positions = row(&Points2D::descriptor_positions().short_name());
+        assert_eq!(positions[1..3], ["10", "10"]);
+        assert_eq!(positions[3], "", "memory is only measured for whole chunks");
+    }
+
+    #[test]
+    fn stats_json() {
+        let (store, stats) = test_stats();
+        let json = serde_json::to_value(&stats).unwrap();
+
+        assert_eq!(json["store_id"], store_id_to_string(&store.id()));
+        assert_eq!(json["total"]["num_chunks"], 2 * NUM_FRAMES + 1);
+        assert!(json["total"]["memory_bytes"].as_u64().unwrap() > 0);
+
+        let points = json["entities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entity| entity["entity_path"] == "/world/points")
+            .unwrap();
+        assert_eq!(points["num_rows"], NUM_FRAMES);
+        assert_eq!(points["time_ranges"]["frame"]["min"], 0);
+        assert_eq!(points["time_ranges"]["frame"]["max"], NUM_FRAMES - 1);
+
+        let positions = points["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|component| {
+                component["component"] == Points2D::descriptor_positions().full_name()
+            })
+            .unwrap();
+        assert_eq!(positions["num_chunks"], NUM_FRAMES);
+        assert_eq!(positions["num_rows"], NUM_FRAMES);
+        assert!(positions["uncompressed_bytes"].as_u64().unwrap() > 0);
+        assert!(positions["compressed_bytes"].as_u64().unwrap() > 0);
+
+        // Memory is only measured for whole chunks, and short names are only for display.
+        assert!(positions.get("memory_bytes").is_none());
+        assert!(positions.get("short_name").is_none());
+    }
+}
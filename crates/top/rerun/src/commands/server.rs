@@ -0,0 +1,75 @@
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+
+/// Host a local catalog of datasets, backed by a directory of .rrd files.
+///
+/// Every sub-directory of `--dir` is a dataset, and every .rrd file within a dataset
+/// directory is one of its partitions.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ServerCommand {
+    /// The directory holding the datasets.
+    ///
+    /// It is created if it doesn't exist yet.
+    #[clap(long, short = 'd', default_value = ".")]
+    dir: PathBuf,
+
+    /// A directory outside of `--dir` that clients may register .rrd files from.
+    ///
+    /// By default, only the files that are already within `--dir` can be registered.
+    /// Can be specified several times.
+    #[clap(long = "import-dir", value_name = "DIR")]
+    import_dirs: Vec<PathBuf>,
+
+    /// What bind address IP to use.
+    ///
+    /// The server has no authentication, and any client that can reach it can read the datasets
+    /// as well as delete them from disk. Only use a non-loopback address (e.g. `0.0.0.0`) on
+    /// trusted networks.
+    #[clap(long, default_value = "127.0.0.1")]
+    bind: IpAddr,
+
+    /// What port to listen to for catalog clients (Viewer, SDK) to connect to.
+    #[clap(long, default_value_t = re_server::DEFAULT_SERVER_PORT)]
+    port: u16,
+
+    /// An upper limit on how much memory the partitions loaded by the server can use.
+    ///
+    /// When this limit is reached, the least recently used partitions are unloaded.
+    /// Example: `16GB` or `50%` (of system total).
+    #[clap(long, default_value = "25%")]
+    memory_limit: String,
+}
+
+impl ServerCommand {
+    pub fn run(&self, tokio_runtime_handle: &tokio::runtime::Handle) -> anyhow::Result<()> {
+        let Self {
+            dir,
+            import_dirs,
+            bind,
+            port,
+            memory_limit,
+        } = self;
+
+        let memory_limit = re_memory::MemoryLimit::parse(memory_limit)
+            .map_err(|err| anyhow::format_err!("Bad --memory-limit: {err}"))?;
+        let max_cached_bytes = memory_limit
+            .max_bytes
+            .map(|max_bytes| max_bytes.max(0) as u64);
+
+        let (signal, shutdown) = re_grpc_server::shutdown::shutdown();
+
+        // Gracefully shut down the server on SIGINT
+        tokio_runtime_handle.spawn(async move {
+            tokio::signal::ctrl_c().await.ok();
+            signal.stop();
+        });
+
+        tokio_runtime_handle.block_on(re_server::serve(
+            SocketAddr::new(*bind, *port),
+            dir,
+            import_dirs.clone(),
+            max_cached_bytes,
+            shutdown,
+        ))
+    }
+}
@@ -4,6 +4,7 @@ use anyhow::Context as _;
 use crossbeam::channel;
 use itertools::Itertools as _;
 
+use re_build_info::CrateVersion;
 use re_chunk::external::crossbeam;
 use re_log_types::LogMsg;
 
@@ -24,6 +25,10 @@ impl std::fmt::Display for InputSource {
     }
 }
 
+/// The decoded messages of an RRD stream, or the errors faced while decoding them, along with
+/// where they were read from.
+pub type DecodedMsgReceiver<T> = channel::Receiver<(InputSource, anyhow::Result<T>)>;
+
 /// Asynchronously decodes potentially multiplexed RRD streams from the given `paths`, or standard
 /// input if none are specified.
 ///
@@ -39,10 +44,31 @@ impl std::fmt::Display for InputSource {
 /// This function is capable of decoding multiple independent recordings from a single stream.
 pub fn read_rrd_streams_from_file_or_stdin(
     paths: &[String],
+) -> (DecodedMsgReceiver<LogMsg>, channel::Receiver<u64>) {
+    read_rrd_streams_from_file_or_stdin_impl(paths, true, |_version, msg| msg)
+}
+
+/// Same as [`read_rrd_streams_from_file_or_stdin`], but the chunks are left as they were written,
+/// instead of being migrated to the current schema.
+///
+/// Every message comes with the version of Rerun that encoded the stream it was read from, which
+/// decides which migrations it needs.
+///
+/// See [`re_log_encoding::decoder::Decoder::without_migrations`].
+pub fn read_unmigrated_rrd_streams_from_file_or_stdin(
+    paths: &[String],
 ) -> (
-    channel::Receiver<(InputSource, anyhow::Result<LogMsg>)>,
+    DecodedMsgReceiver<(CrateVersion, LogMsg)>,
     channel::Receiver<u64>,
 ) {
+    read_rrd_streams_from_file_or_stdin_impl(paths, false, |version, msg| (version, msg))
+}
+
+fn read_rrd_streams_from_file_or_stdin_impl<T: Send + 'static>(
+    paths: &[String],
+    apply_migrations: bool,
+    wrap: fn(CrateVersion, LogMsg) -> T,
+) -> (DecodedMsgReceiver<T>, channel::Receiver<u64>) {
     let path_to_input_rrds = paths
         .iter()
         .filter(|s| !s.is_empty()) // Avoid a problem with `pixi run check-backwards-compatibility`
@@ -66,15 +92,18 @@ pub fn read_rrd_streams_from_file_or_stdin(
                 let mut decoder = match re_log_encoding::decoder::Decoder::new_concatenated(stdin)
                     .context("couldn't decode stdin stream -- skipping")
                 {
-                    Ok(decoder) => decoder,
+                    Ok(decoder) if apply_migrations => decoder,
+                    Ok(decoder) => decoder.without_migrations(),
                     Err(err) => {
                         tx.send((InputSource::Stdin, Err(err))).ok();
                         return;
                     }
                 };
 
-                for res in &mut decoder {
-                    let res = res.context("couldn't decode message from stdin -- skipping");
+                while let Some(res) = decoder.next() {
+                    let res = res
+                        .map(|msg| wrap(decoder.file_version(), msg))
+                        .context("couldn't decode message from stdin -- skipping");
                     tx.send((InputSource::Stdin, res)).ok();
                 }
 
@@ -97,7 +126,8 @@ pub fn read_rrd_streams_from_file_or_stdin(
                     let mut decoder = match re_log_encoding::decoder::Decoder::new(rrd_file)
                         .with_context(|| format!("couldn't decode {rrd_path:?} -- skipping"))
                     {
-                        Ok(decoder) => decoder,
+                        Ok(decoder) if apply_migrations => decoder,
+                        Ok(decoder) => decoder.without_migrations(),
                         Err(err) => {
                             tx.send((InputSource::File(rrd_path.clone()), Err(err)))
                                 .ok();
@@ -105,10 +135,13 @@ pub fn read_rrd_streams_from_file_or_stdin(
                         }
                     };
 
-                    for res in &mut decoder {
-                        let res = res.context("decode rrd message").with_context(|| {
-                            format!("couldn't decode message {rrd_path:?} -- skipping")
-                        });
+                    while let Some(res) = decoder.next() {
+                        let res = res
+                            .map(|msg| wrap(decoder.file_version(), msg))
+                            .context("decode rrd message")
+                            .with_context(|| {
+                                format!("couldn't decode message {rrd_path:?} -- skipping")
+                            });
                         tx.send((InputSource::File(rrd_path.clone()), res)).ok();
                     }
 
@@ -20,8 +20,8 @@ use parking_lot::Mutex;
 use crate::{
     decode::{
         ffmpeg_h264::{
-            nalu::{NalHeader, NalUnitType, NAL_START_CODE},
-            sps::H264Sps,
+            nalu::{H265NalHeader, H265NalUnitType, NalHeader, NalUnitType, NAL_START_CODE},
+            sps::{H264Sps, H265Sps, SpsParsingError},
             FFmpegVersion, FFMPEG_MINIMUM_VERSION_MAJOR, FFMPEG_MINIMUM_VERSION_MINOR,
         },
         AsyncDecoder, Chunk, Frame, FrameContent, FrameInfo, OutputCallback,
@@ -96,6 +96,108 @@ impl From<Error> for crate::decode::Error {
     }
 }
 
+/// The codec ffmpeg is decoding, along with its configuration from the mp4 container.
+#[derive(Clone)]
+enum CodecConfig {
+    H264(re_mp4::Avc1Box),
+    H265(re_mp4::HevcBox),
+}
+
+impl CodecConfig {
+    /// Name of the ffmpeg demuxer for the raw Annex B stream of this codec.
+    fn ffmpeg_format(&self) -> &'static str {
+        match self {
+            Self::H264(_) => "h264",
+            Self::H265(_) => "hevc",
+        }
+    }
+
+    fn pixel_layout(
+        &self,
+        debug_name: &str,
+    ) -> Result<crate::decode::YuvPixelLayout, SpsParsingError> {
+        match self {
+            Self::H264(avcc) => {
+                let sps = H264Sps::parse_from_avcc(avcc)?;
+                re_log::trace!("Successfully parsed SPS for {debug_name}:\n{sps:?}");
+                sps.pixel_layout()
+            }
+            Self::H265(hevc) => {
+                let sps = H265Sps::parse_from_hvcc(hevc)?;
+                re_log::trace!("Successfully parsed SPS for {debug_name}:\n{sps:?}");
+                sps.pixel_layout()
+            }
+        }
+    }
+
+    /// Size of the length prefix in front of every NAL unit in mp4 samples.
+    fn length_prefix_size(&self) -> usize {
+        match self {
+            Self::H264(avcc) => avcc.avcc.length_size_minus_one as usize + 1,
+            Self::H265(hevc) => hevc.hvcc.length_size_minus_one as usize + 1,
+        }
+    }
+
+    /// All the parameter set NAL units (VPS, SPS, PPS, …) of the configuration, in order.
+    fn parameter_sets(&self) -> Vec<&[u8]> {
+        match self {
+            Self::H264(avcc) => avcc
+                .avcc
+                .sequence_parameter_sets
+                .iter()
+                .chain(&avcc.avcc.picture_parameter_sets)
+                .map(|unit| unit.bytes.as_slice())
+                .collect(),
+            Self::H265(hevc) => hevc
+                .hvcc
+                .arrays
+                .iter()
+                .flat_map(|array| &array.nalus)
+                .map(|nalu| nalu.data.as_slice())
+                .collect(),
+        }
+    }
+
+    /// Access Unit Delimiter (AUD) NAL unit, without start code.
+    fn access_unit_delimiter(&self) -> Vec<u8> {
+        match self {
+            Self::H264(_) => vec![
+                NalHeader::new(NalUnitType::AccessUnitDelimiter, 3).0,
+                // Two arbitrary bytes? 0000 worked as well, but this is what
+                // https://stackoverflow.com/a/44394025/ uses. Couldn't figure out the rules for this.
+                0xFF,
+                0x80,
+            ],
+            Self::H265(_) => {
+                let [header0, header1] =
+                    H265NalHeader::new(H265NalUnitType::AccessUnitDelimiter, 0).0;
+                // `pic_type` of 2 (any slice type), followed by the rbsp stop bit.
+                vec![header0, header1, 0b0101_0000]
+            }
+        }
+    }
+
+    /// End of sequence & end of stream NAL units, with start codes.
+    fn end_nals(&self) -> Vec<u8> {
+        match self {
+            Self::H264(_) => [
+                NAL_START_CODE,
+                &[NalHeader::new(NalUnitType::EndSequence, 0).0],
+                NAL_START_CODE,
+                &[NalHeader::new(NalUnitType::EndStream, 0).0],
+            ]
+            .concat(),
+            Self::H265(_) => [
+                NAL_START_CODE,
+                &H265NalHeader::new(H265NalUnitType::EndSequence, 0).0,
+                NAL_START_CODE,
+                &H265NalHeader::new(H265NalUnitType::EndStream, 0).0,
+            ]
+            .concat(),
+        }
+    }
+}
+
 /// ffmpeg does not tell us the timestamp/duration of a given frame, so we need to remember it.
 #[derive(Clone, Debug)]
 struct FFmpegFrameInfo {
@@ -190,17 +292,12 @@ impl FFmpegProcessAndListener {
     fn new(
         debug_name: &str,
         on_output: Arc<OutputCallback>,
-        avcc: re_mp4::Avc1Box,
+        codec: CodecConfig,
         ffmpeg_path: Option<&std::path::Path>,
     ) -> Result<Self, Error> {
         re_tracing::profile_function!();
 
-        let sps_result = H264Sps::parse_from_avcc(&avcc);
-        if let Ok(sps) = &sps_result {
-            re_log::trace!("Successfully parsed SPS for {debug_name}:\n{sps:?}");
-        }
-
-        let (pixel_format, ffmpeg_pix_fmt) = match sps_result.and_then(|sps| sps.pixel_layout()) {
+        let (pixel_format, ffmpeg_pix_fmt) = match codec.pixel_layout(debug_name) {
             Ok(layout) => {
                 let pixel_format = PixelFormat::Yuv {
                     layout,
@@ -254,10 +351,10 @@ impl FFmpegProcessAndListener {
                 "0",
             ])
             // Keep in mind that all arguments that are about the input, need to go before!
-            .format("h264") // TODO(andreas): should we check ahead of time whether this is available?
+            .format(codec.ffmpeg_format()) // TODO(andreas): should we check ahead of time whether this is available?
             //.fps_mode("0")
             .input("-") // stdin is our input!
-            // h264 bitstreams doesn't have timestamp information. Whatever ffmpeg tries to make up about timing & framerates is wrong!
+            // h264/h265 bitstreams don't have timestamp information. Whatever ffmpeg tries to make up about timing & framerates is wrong!
             // If we don't tell it to just pass the frames through, variable framerate (VFR) video will just not play at all.
             .fps_mode("passthrough")
             .pix_fmt(ffmpeg_pix_fmt)
@@ -319,7 +416,7 @@ impl FFmpegProcessAndListener {
                         &mut ffmpeg_stdin,
                         &frame_data_rx,
                         on_output.as_ref(),
-                        &avcc,
+                        &codec,
                     );
                 }
             })
@@ -438,7 +535,7 @@ fn write_ffmpeg_input(
     ffmpeg_stdin: &mut dyn std::io::Write,
     frame_data_rx: &Receiver<FFmpegFrameData>,
     on_output: &Mutex<Option<Arc<OutputCallback>>>,
-    avcc: &re_mp4::Avc1Box,
+    codec: &CodecConfig,
 ) {
     let mut state = NaluStreamState::default();
 
@@ -448,14 +545,7 @@ fn write_ffmpeg_input(
             FFmpegFrameData::Quit => {
                 // Try to flush out the last frames from ffmpeg with an EndSequence/EndStream NAL units.
                 // Unfortunatelt this doesn't help, at least not for https://github.com/rerun-io/rerun/issues/8073
-                let end_nals: Vec<u8> = [
-                    NAL_START_CODE,
-                    &[NalHeader::new(NalUnitType::EndSequence, 0).0],
-                    NAL_START_CODE,
-                    &[NalHeader::new(NalUnitType::EndStream, 0).0],
-                ]
-                .concat();
-                write_bytes(ffmpeg_stdin, &end_nals).ok();
+                write_bytes(ffmpeg_stdin, &codec.end_nals()).ok();
 
                 // NOTE(emilk): I've also tried writing `NalUnitType::AccessUnitDelimiter` here, but to no avail.
 
@@ -465,7 +555,7 @@ fn write_ffmpeg_input(
             }
         };
 
-        if let Err(err) = write_avc_chunk_to_nalu_stream(avcc, ffmpeg_stdin, &chunk, &mut state) {
+        if let Err(err) = write_chunk_to_nalu_stream(codec, ffmpeg_stdin, &chunk, &mut state) {
             let on_output = on_output.lock();
             if let Some(on_output) = on_output.as_ref() {
                 let write_error = matches!(err, Error::FailedToWriteToFfmpeg(_));
@@ -790,12 +880,12 @@ fn read_ffmpeg_output(
     Some(())
 }
 
-/// Decode H.264 video via ffmpeg over CLI
+/// Decode H.264 or H.265 video via ffmpeg over CLI
 pub struct FFmpegCliH264Decoder {
     debug_name: String,
     // Restarted on reset
     ffmpeg: FFmpegProcessAndListener,
-    avcc: re_mp4::Avc1Box,
+    codec: CodecConfig,
     on_output: Arc<OutputCallback>,
     ffmpeg_path: Option<std::path::PathBuf>,
 }
@@ -806,6 +896,25 @@ impl FFmpegCliH264Decoder {
         avcc: re_mp4::Avc1Box,
         on_output: impl Fn(crate::decode::Result<Frame>) + Send + Sync + 'static,
         ffmpeg_path: Option<std::path::PathBuf>,
+    ) -> Result<Self, Error> {
+        Self::new_with_codec(debug_name, CodecConfig::H264(avcc), on_output, ffmpeg_path)
+    }
+
+    /// Decode H.265/HEVC, in either its `hvc1` or `hev1` flavor.
+    pub fn new_h265(
+        debug_name: String,
+        hevc: re_mp4::HevcBox,
+        on_output: impl Fn(crate::decode::Result<Frame>) + Send + Sync + 'static,
+        ffmpeg_path: Option<std::path::PathBuf>,
+    ) -> Result<Self, Error> {
+        Self::new_with_codec(debug_name, CodecConfig::H265(hevc), on_output, ffmpeg_path)
+    }
+
+    fn new_with_codec(
+        debug_name: String,
+        codec: CodecConfig,
+        on_output: impl Fn(crate::decode::Result<Frame>) + Send + Sync + 'static,
+        ffmpeg_path: Option<std::path::PathBuf>,
     ) -> Result<Self, Error> {
         re_tracing::profile_function!();
 
@@ -843,14 +952,14 @@ impl FFmpegCliH264Decoder {
         let ffmpeg = FFmpegProcessAndListener::new(
             &debug_name,
             on_output.clone(),
-            avcc.clone(),
+            codec.clone(),
             ffmpeg_path.as_deref(),
         )?;
 
         Ok(Self {
             debug_name,
             ffmpeg,
-            avcc,
+            codec,
             on_output,
             ffmpeg_path,
         })
@@ -885,7 +994,7 @@ impl AsyncDecoder for FFmpegCliH264Decoder {
         self.ffmpeg = FFmpegProcessAndListener::new(
             &self.debug_name,
             self.on_output.clone(),
-            self.avcc.clone(),
+            self.codec.clone(),
             self.ffmpeg_path.as_deref(),
         )?;
         Ok(())
@@ -911,28 +1020,22 @@ fn write_bytes(stream: &mut dyn std::io::Write, data: &[u8]) -> Result<(), Error
     stream.write_all(data).map_err(Error::FailedToWriteToFfmpeg)
 }
 
-fn write_avc_chunk_to_nalu_stream(
-    avcc: &re_mp4::Avc1Box,
+fn write_chunk_to_nalu_stream(
+    codec: &CodecConfig,
     nalu_stream: &mut dyn std::io::Write,
     chunk: &Chunk,
     state: &mut NaluStreamState,
 ) -> Result<(), Error> {
     re_tracing::profile_function!();
 
-    let avcc = &avcc.avcc;
-
     // We expect the stream of chunks to not have any SPS (Sequence Parameter Set) & PPS (Picture Parameter Set)
-    // just as it is the case with MP4 data.
-    // In order to have every IDR frame be able to be fully re-entrant, we need to prepend the SPS & PPS NAL units.
+    // (nor VPS, Video Parameter Set, for H.265) just as it is the case with MP4 data.
+    // In order to have every IDR frame be able to be fully re-entrant, we need to prepend the parameter set NAL units.
     // Otherwise the decoder is not able to get the necessary information about how the video stream is encoded.
     if chunk.is_sync && !state.previous_frame_was_idr {
-        for sps in &avcc.sequence_parameter_sets {
-            write_bytes(nalu_stream, NAL_START_CODE)?;
-            write_bytes(nalu_stream, &sps.bytes)?;
-        }
-        for pps in &avcc.picture_parameter_sets {
+        for parameter_set in codec.parameter_sets() {
             write_bytes(nalu_stream, NAL_START_CODE)?;
-            write_bytes(nalu_stream, &pps.bytes)?;
+            write_bytes(nalu_stream, parameter_set)?;
         }
         state.previous_frame_was_idr = true;
     } else {
@@ -948,7 +1051,7 @@ fn write_avc_chunk_to_nalu_stream(
 
         // Each NAL unit in mp4 is prefixed with a length prefix.
         // In Annex B this doesn't exist.
-        let length_prefix_size = avcc.length_size_minus_one as usize + 1;
+        let length_prefix_size = codec.length_prefix_size();
 
         if sample_end < buffer_offset + length_prefix_size {
             return Err(Error::BadVideoData(
@@ -1011,16 +1114,7 @@ fn write_avc_chunk_to_nalu_stream(
     // Write an Access Unit Delimiter (AUD) NAL unit to the stream to signal the end of an access unit.
     // This can help with ffmpeg picking up NALs right away before seeing the next chunk.
     write_bytes(nalu_stream, NAL_START_CODE)?;
-    write_bytes(
-        nalu_stream,
-        &[
-            NalHeader::new(NalUnitType::AccessUnitDelimiter, 3).0,
-            // Two arbitrary bytes? 0000 worked as well, but this is what
-            // https://stackoverflow.com/a/44394025/ uses. Couldn't figure out the rules for this.
-            0xFF,
-            0x80,
-        ],
-    )?;
+    write_bytes(nalu_stream, &codec.access_unit_delimiter())?;
 
     Ok(())
 }
@@ -1049,6 +1143,7 @@ fn should_ignore_log_msg(msg: &str) -> bool {
         // Size etc. *is* specified in SPS & PPS, unclear why it's missing that.
         // Observed on Windows FFmpeg 7.1, but not with the same version on Mac with the same video.
         "Could not find codec parameters for stream 0 (Video: h264, none): unspecified size",
+        "Could not find codec parameters for stream 0 (Video: hevc, none): unspecified size",
         // NOTE: We sometimes get a `[NULL @ 0x14f107150]`, which is not very actionable, but may be useful for debugging.
     ];
 
@@ -1089,7 +1184,246 @@ fn sanitize_ffmpeg_log_message(msg: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::sanitize_ffmpeg_log_message;
+    use crate::decode::{
+        ffmpeg_h264::sps::{tests::H265_SPS_720P, SpsParsingError},
+        Chunk, YuvPixelLayout,
+    };
+    use crate::Time;
+
+    use super::{
+        sanitize_ffmpeg_log_message, write_chunk_to_nalu_stream, CodecConfig, NaluStreamState,
+    };
+
+    const H265_VPS: &[u8] = &[0x40, 0x01, 0x0c, 0x01, 0xff, 0xff];
+    const H265_PPS: &[u8] = &[0x44, 0x01, 0xc1, 0x72, 0xb4, 0x62, 0x40];
+
+    /// Builds an `hvc1` sample entry with the given `(nal_unit_type, nal_units)` arrays in its `hvcC` box,
+    /// and a 4 byte length prefix.
+    fn hevc_box(arrays: &[(u8, &[&[u8]])]) -> re_mp4::HevcBox {
+        use re_mp4::ReadBox as _;
+
+        let mut hvcc = vec![
+            1,    // configuration_version
+            0x01, // profile space, tier, profile idc
+            0x60,
+            0,
+            0,
+            0, // profile compatibility flags
+            0x90,
+            0,
+            0,
+            0,
+            0,
+            0,  // constraint indicator flags
+            93, // level idc
+            0xF0,
+            0,    // min_spatial_segmentation_idc
+            0xFC, // parallelism type
+            0xFD, // chroma format idc
+            0xF8, // bit depth luma
+            0xF8, // bit depth chroma
+            0,
+            0,    // avg frame rate
+            0x0F, // …, length_size_minus_one
+            arrays.len() as u8,
+        ];
+        for (nal_unit_type, nalus) in arrays {
+            hvcc.push(0x80 | nal_unit_type);
+            hvcc.extend((nalus.len() as u16).to_be_bytes());
+            for nalu in *nalus {
+                hvcc.extend((nalu.len() as u16).to_be_bytes());
+                hvcc.extend(*nalu);
+            }
+        }
+
+        let mut entry = vec![0; 8]; // Header of the `hvc1` box itself, skipped by `read_box`.
+        entry.extend([0; 6]); // reserved
+        entry.extend(1u16.to_be_bytes()); // data_reference_index
+        entry.extend([0; 16]); // pre-defined & reserved
+        entry.extend(1280u16.to_be_bytes());
+        entry.extend(720u16.to_be_bytes());
+        entry.extend(0x0048_0000u32.to_be_bytes()); // horizresolution
+        entry.extend(0x0048_0000u32.to_be_bytes()); // vertresolution
+        entry.extend([0; 4]); // reserved
+        entry.extend(1u16.to_be_bytes()); // frame_count
+        entry.extend([0; 32]); // compressorname
+        entry.extend(24u16.to_be_bytes()); // depth
+        entry.extend((-1i16).to_be_bytes()); // pre-defined
+        entry.extend((8 + hvcc.len() as u32).to_be_bytes());
+        entry.extend(b"hvcC");
+        entry.extend(hvcc);
+
+        let size = entry.len() as u64;
+        let mut reader = std::io::Cursor::new(entry);
+        reader.set_position(8);
+        re_mp4::HevcBox::read_box(&mut reader, size).unwrap()
+    }
+
+    fn chunk(is_sync: bool, data: Vec<u8>) -> Chunk {
+        Chunk {
+            is_sync,
+            data,
+            sample_idx: 0,
+            frame_nr: 0,
+            decode_timestamp: Time::ZERO,
+            presentation_timestamp: Time::ZERO,
+            duration: Time::new(1),
+        }
+    }
+
+    #[test]
+    fn test_h265_pixel_layout() {
+        let codec = CodecConfig::H265(hevc_box(&[(32, &[H265_VPS]), (33, &[H265_SPS_720P])]));
+        assert_eq!(codec.pixel_layout("test"), Ok(YuvPixelLayout::Y_U_V420));
+
+        let codec = CodecConfig::H265(hevc_box(&[(32, &[H265_VPS])]));
+        assert_eq!(
+            codec.pixel_layout("test"),
+            Err(SpsParsingError::NoSpsInHvcc)
+        );
+
+        let codec = CodecConfig::H265(hevc_box(&[(33, &[H265_SPS_720P, H265_SPS_720P])]));
+        assert_eq!(
+            codec.pixel_layout("test"),
+            Err(SpsParsingError::MoreThanOneSpsInHvcc)
+        );
+    }
+
+    #[test]
+    fn test_h265_nalu_stream() {
+        let codec = CodecConfig::H265(hevc_box(&[
+            (32, &[H265_VPS]),
+            (33, &[H265_SPS_720P]),
+            (34, &[H265_PPS]),
+        ]));
+        assert_eq!(codec.ffmpeg_format(), "hevc");
+
+        // Two length-prefixed NAL units: an IDR slice & a trailing slice.
+        let idr_slice = [0x26, 0x01, 0xaf, 0x06];
+        let trail_slice = [0x02, 0x01, 0xd0];
+        let sample = [
+            &(idr_slice.len() as u32).to_be_bytes()[..],
+            &idr_slice,
+            &(trail_slice.len() as u32).to_be_bytes(),
+            &trail_slice,
+        ]
+        .concat();
+
+        let start_code = [0x00, 0x00, 0x00, 0x01];
+        let aud = [0x46, 0x01, 0x50];
+
+        let mut state = NaluStreamState::default();
+        let mut stream = Vec::new();
+        write_chunk_to_nalu_stream(
+            &codec,
+            &mut stream,
+            &chunk(true, sample.clone()),
+            &mut state,
+        )
+        .unwrap();
+        assert_eq!(
+            stream,
+            [
+                &start_code[..],
+                H265_VPS,
+                &start_code,
+                H265_SPS_720P,
+                &start_code,
+                H265_PPS,
+                &start_code,
+                &idr_slice,
+                &start_code,
+                &trail_slice,
+                &start_code,
+                &aud,
+            ]
+            .concat()
+        );
+
+        // Parameter sets are only prepended to sync samples.
+        let mut stream = Vec::new();
+        write_chunk_to_nalu_stream(&codec, &mut stream, &chunk(false, sample), &mut state).unwrap();
+        assert_eq!(
+            stream,
+            [
+                &start_code[..],
+                &idr_slice,
+                &start_code,
+                &trail_slice,
+                &start_code,
+                &aud,
+            ]
+            .concat()
+        );
+
+        assert_eq!(
+            codec.end_nals(),
+            [&start_code[..], &[0x48, 0x01], &start_code, &[0x4a, 0x01]].concat()
+        );
+    }
+
+    #[test]
+    fn test_h265_nalu_stream_truncated_sample() {
+        let codec = CodecConfig::H265(hevc_box(&[(33, &[H265_SPS_720P])]));
+
+        let mut stream = Vec::new();
+        let result = write_chunk_to_nalu_stream(
+            &codec,
+            &mut stream,
+            &chunk(true, vec![0x00, 0x00, 0x00, 0x08, 0x26, 0x01]),
+            &mut NaluStreamState::default(),
+        );
+        assert!(matches!(result, Err(super::Error::BadVideoData(_))));
+    }
+
+    /// Decodes the first GOP of an actual HEVC mp4 through ffmpeg.
+    ///
+    /// Skipped if ffmpeg isn't installed, or if the video wasn't fetched from git LFS.
+    #[test]
+    fn test_h265_decode_mp4() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../../tests/assets/video/Big_Buck_Bunny_1080_10s_h265.mp4");
+        let Ok(video_blob) = std::fs::read(&path) else {
+            eprintln!("Skipping: {path:?} not found");
+            return;
+        };
+        let Ok(video) = crate::VideoData::load_mp4(&video_blob) else {
+            eprintln!("Skipping: {path:?} is not an mp4, is git LFS set up?");
+            return;
+        };
+        if !ffmpeg_sidecar::command::ffmpeg_is_installed() {
+            eprintln!("Skipping: ffmpeg is not installed");
+            return;
+        }
+
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let mut decoder = crate::decode::new_decoder(
+            "test_h265_decode_mp4",
+            &video,
+            &crate::decode::DecodeSettings::default(),
+            move |frame| {
+                tx.send(frame).ok();
+            },
+        )
+        .unwrap();
+
+        let gop = &video.gops[0];
+        for sample in &video.samples[gop.sample_range_usize()] {
+            decoder
+                .submit_chunk(sample.get(&video_blob).unwrap())
+                .unwrap();
+        }
+        decoder.end_of_video().unwrap();
+
+        for _ in gop.sample_range_usize() {
+            let frame = rx
+                .recv_timeout(std::time::Duration::from_secs(30))
+                .expect("timed out waiting for a frame")
+                .unwrap();
+            assert_eq!(frame.content.width, video.config.coded_width as u32);
+            assert_eq!(frame.content.height, video.config.coded_height as u32);
+        }
+    }
 
     #[test]
     fn test_sanitize_ffmpeg_log_message() {
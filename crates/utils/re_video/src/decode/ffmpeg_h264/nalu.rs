@@ -56,7 +56,7 @@ pub enum NalUnitType {
     Other,
 }
 
-/// Header of the "Network Abstraction Layer" unit that is used by H.264/AVC & H.265/HEVC.
+/// Header of the "Network Abstraction Layer" unit that is used by H.264/AVC.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct NalHeader(pub u8);
 
@@ -91,3 +91,41 @@ impl NalHeader {
         (self.0 >> 5) & 0b11
     }
 }
+
+/// Possible values for `nal_unit_type` field in H.265/HEVC `nal_unit_header`.
+///
+/// Encodes to 6 bits. Only lists the types we need to know about.
+/// Via:
+/// * <https://github.com/FFmpeg/FFmpeg/blob/87068b9600daa522e3f45b5501ecd487a3c0be57/libavcodec/hevc.h>
+#[derive(PartialEq, Eq)]
+#[non_exhaustive]
+#[repr(u8)]
+#[derive(Copy, Clone, Debug)]
+pub enum H265NalUnitType {
+    /// Sequence parameter set
+    SequenceParameterSet = 33,
+
+    /// Marks the start of an access unit, i.e. of the NAL units making up one picture.
+    AccessUnitDelimiter = 35,
+
+    EndSequence = 36,
+    EndStream = 37,
+}
+
+/// Header of the "Network Abstraction Layer" unit that is used by H.265/HEVC.
+///
+/// Unlike its H.264 counterpart, it is two bytes long.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct H265NalHeader(pub [u8; 2]);
+
+impl H265NalHeader {
+    /// Header for a NAL unit in the base layer (`nuh_layer_id` of 0).
+    pub const fn new(unit_type: H265NalUnitType, temporal_id: u8) -> Self {
+        Self([(unit_type as u8) << 1, temporal_id + 1])
+    }
+
+    /// The raw `nal_unit_type`, since [`H265NalUnitType`] doesn't list all of them.
+    pub fn unit_type_raw(self) -> u8 {
+        (self.0[0] >> 1) & 0b11_1111
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::decode::YuvPixelLayout;
 
-use super::nalu::{NalHeader, NalUnitType};
+use super::nalu::{H265NalHeader, H265NalUnitType, NalHeader, NalUnitType};
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 pub enum SpsParsingError {
@@ -17,6 +17,12 @@ pub enum SpsParsingError {
 
     #[error("AVC configuration did not contain a SPS.")]
     NoSpsInAvcc,
+
+    #[error("More than one SPS in HEVC configuration.")]
+    MoreThanOneSpsInHvcc,
+
+    #[error("HEVC configuration did not contain a SPS.")]
+    NoSpsInHvcc,
 }
 
 /// Sequence Parameter Set for h264 video
@@ -247,23 +253,213 @@ impl H264Sps {
     pub fn pixel_layout(&self) -> Result<YuvPixelLayout, SpsParsingError> {
         // Section 6.1
         // http://wikil.lwwhome.cn:28080/wp-content/uploads/2018/08/T-REC-H.264-201704%E8%8B%B1%E6%96%87.pdf
+        pixel_layout_from_chroma_format_idc(self.chroma_format_idc)
+    }
+}
+
+/// Sequence Parameter Set for h265 video
+///
+/// Only parses up to the bit depths, which is all we need.
+/// Semantics are defined in [ITU-T H.265 (V10)](https://www.itu.int/rec/T-REC-H.265), section 7.3.2.2
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct H265Sps {
+    pub sps_video_parameter_set_id: u32,
+    pub sps_max_sub_layers_minus1: u32,
+    pub sps_temporal_id_nesting_flag: bool,
+
+    pub general_profile_idc: u32,
+    pub general_level_idc: u32,
+
+    pub sps_seq_parameter_set_id: u32,
+    pub chroma_format_idc: u32,
+    pub separate_colour_plane_flag: bool,
+    pub pic_width_in_luma_samples: u32,
+    pub pic_height_in_luma_samples: u32,
+
+    pub conf_win_left_offset: Option<u32>,
+    pub conf_win_right_offset: Option<u32>,
+    pub conf_win_top_offset: Option<u32>,
+    pub conf_win_bottom_offset: Option<u32>,
+
+    pub bit_depth_luma_minus8: u32,
+    pub bit_depth_chroma_minus8: u32,
+}
+
+impl H265Sps {
+    /// Parses a sequence parameter set from a buffer, starting right after the NAL unit header.
+    ///
+    /// The buffer is expected to still contain emulation prevention bytes, as is the case in mp4.
+    pub fn try_parse(buffer: &[u8]) -> Result<Self, SpsParsingError> {
+        let buffer = &remove_emulation_prevention_bytes(buffer);
+        let mut bit_read_pos = 0;
+
+        let sps_video_parameter_set_id = read_bits(&mut bit_read_pos, buffer, 4)?;
+        let sps_max_sub_layers_minus1 = read_bits(&mut bit_read_pos, buffer, 3)?;
+        let sps_temporal_id_nesting_flag = read_bits(&mut bit_read_pos, buffer, 1)? == 1;
+
+        // profile_tier_level( 1, sps_max_sub_layers_minus1 ), section 7.3.3
+        let _general_profile_space = read_bits(&mut bit_read_pos, buffer, 2)?;
+        let _general_tier_flag = read_bits(&mut bit_read_pos, buffer, 1)?;
+        let general_profile_idc = read_bits(&mut bit_read_pos, buffer, 5)?;
+        // general_profile_compatibility_flag[32], then 4 source flags and 44 bits of constraint flags.
+        skip_bits(&mut bit_read_pos, buffer, 32 + 4 + 43 + 1)?;
+        let general_level_idc = read_bits(&mut bit_read_pos, buffer, 8)?;
+
+        let mut sub_layer_profile_present_flags = Vec::new();
+        let mut sub_layer_level_present_flags = Vec::new();
+        for _ in 0..sps_max_sub_layers_minus1 {
+            sub_layer_profile_present_flags.push(read_bits(&mut bit_read_pos, buffer, 1)? == 1);
+            sub_layer_level_present_flags.push(read_bits(&mut bit_read_pos, buffer, 1)? == 1);
+        }
+        if sps_max_sub_layers_minus1 > 0 {
+            // reserved_zero_2bits for the remaining sub-layers up to 8.
+            skip_bits(
+                &mut bit_read_pos,
+                buffer,
+                2 * (8 - sps_max_sub_layers_minus1 as usize),
+            )?;
+        }
+        for (profile_present, level_present) in sub_layer_profile_present_flags
+            .into_iter()
+            .zip(sub_layer_level_present_flags)
+        {
+            if profile_present {
+                skip_bits(&mut bit_read_pos, buffer, 88)?;
+            }
+            if level_present {
+                skip_bits(&mut bit_read_pos, buffer, 8)?;
+            }
+        }
+
+        let sps_seq_parameter_set_id = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+        let chroma_format_idc = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+        let separate_colour_plane_flag =
+            chroma_format_idc == 3 && read_bits(&mut bit_read_pos, buffer, 1)? == 1;
+        let pic_width_in_luma_samples = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+        let pic_height_in_luma_samples = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+
+        let (
+            conf_win_left_offset,
+            conf_win_right_offset,
+            conf_win_top_offset,
+            conf_win_bottom_offset,
+        ) = if read_bits(&mut bit_read_pos, buffer, 1)? == 1 {
+            // conformance_window_flag
+            (
+                Some(read_exponential_golomb(&mut bit_read_pos, buffer)?),
+                Some(read_exponential_golomb(&mut bit_read_pos, buffer)?),
+                Some(read_exponential_golomb(&mut bit_read_pos, buffer)?),
+                Some(read_exponential_golomb(&mut bit_read_pos, buffer)?),
+            )
+        } else {
+            (None, None, None, None)
+        };
+
+        let bit_depth_luma_minus8 = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+        let bit_depth_chroma_minus8 = read_exponential_golomb(&mut bit_read_pos, buffer)?;
+
+        Ok(Self {
+            sps_video_parameter_set_id,
+            sps_max_sub_layers_minus1,
+            sps_temporal_id_nesting_flag,
+
+            general_profile_idc,
+            general_level_idc,
+
+            sps_seq_parameter_set_id,
+            chroma_format_idc,
+            separate_colour_plane_flag,
+            pic_width_in_luma_samples,
+            pic_height_in_luma_samples,
+
+            conf_win_left_offset,
+            conf_win_right_offset,
+            conf_win_top_offset,
+            conf_win_bottom_offset,
 
-        match self.chroma_format_idc {
-            0 => Ok(YuvPixelLayout::Y400),
-            1 => Ok(YuvPixelLayout::Y_U_V420),
-            2 => Ok(YuvPixelLayout::Y_U_V422),
+            bit_depth_luma_minus8,
+            bit_depth_chroma_minus8,
+        })
+    }
+
+    /// Parses a sequence parameter set from an HEVC configuration box.
+    pub fn parse_from_hvcc(hevc: &re_mp4::HevcBox) -> Result<Self, SpsParsingError> {
+        let mut sps_units = hevc
+            .hvcc
+            .arrays
+            .iter()
+            .flat_map(|array| &array.nalus)
+            .filter(|nalu| {
+                nalu.data.len() >= 2
+                    && H265NalHeader([nalu.data[0], nalu.data[1]]).unit_type_raw()
+                        == H265NalUnitType::SequenceParameterSet as u8
+            });
+
+        if let Some(sps_unit) = sps_units.next() {
+            if sps_units.next().is_some() {
+                Err(SpsParsingError::MoreThanOneSpsInHvcc)
+            } else {
+                Self::try_parse(&sps_unit.data[2..])
+            }
+        } else {
+            Err(SpsParsingError::NoSpsInHvcc)
+        }
+    }
 
-            // Spec says:
-            // In 4:4:4 sampling, depending on the value of `separate_color_plane_flag``, the following applies:
-            // – If `separate_color_plane_flag`` is equal to 0, each of the two chroma arrays has the same height and width as the luma array.
-            // – Otherwise (`separate_color_plane_flag`` is equal to 1), the three color planes are separately processed as monochrome sampled pictures
-            //
-            // So it's planar YUV4:4:4 in either case but in the second the pixel data is spread across frames.
-            3 => Ok(YuvPixelLayout::Y_U_V444),
+    /// Return the pixel layout specified in the SPS.
+    pub fn pixel_layout(&self) -> Result<YuvPixelLayout, SpsParsingError> {
+        // Section 6.2 of the H.265 spec, which is the same as for H.264.
+        pixel_layout_from_chroma_format_idc(self.chroma_format_idc)
+    }
+}
 
-            _ => Err(SpsParsingError::InvalidPixelLayout(self.chroma_format_idc)),
+fn pixel_layout_from_chroma_format_idc(
+    chroma_format_idc: u32,
+) -> Result<YuvPixelLayout, SpsParsingError> {
+    match chroma_format_idc {
+        0 => Ok(YuvPixelLayout::Y400),
+        1 => Ok(YuvPixelLayout::Y_U_V420),
+        2 => Ok(YuvPixelLayout::Y_U_V422),
+
+        // Spec says:
+        // In 4:4:4 sampling, depending on the value of `separate_color_plane_flag``, the following applies:
+        // – If `separate_color_plane_flag`` is equal to 0, each of the two chroma arrays has the same height and width as the luma array.
+        // – Otherwise (`separate_color_plane_flag`` is equal to 1), the three color planes are separately processed as monochrome sampled pictures
+        //
+        // So it's planar YUV4:4:4 in either case but in the second the pixel data is spread across frames.
+        3 => Ok(YuvPixelLayout::Y_U_V444),
+
+        _ => Err(SpsParsingError::InvalidPixelLayout(chroma_format_idc)),
+    }
+}
+
+/// Removes the emulation prevention bytes (`0x03` following two zero bytes) that are inserted
+/// into NAL units so that their payload never contains a start code.
+fn remove_emulation_prevention_bytes(buffer: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(buffer.len());
+    let mut num_zeros = 0;
+    for &byte in buffer {
+        if num_zeros >= 2 && byte == 0x03 {
+            num_zeros = 0;
+            continue;
         }
+        num_zeros = if byte == 0 { num_zeros + 1 } else { 0 };
+        result.push(byte);
     }
+    result
+}
+
+fn skip_bits(
+    bit_read_pos: &mut usize,
+    buffer: &[u8],
+    num_bits: usize,
+) -> Result<(), SpsParsingError> {
+    if buffer.len() * 8 < *bit_read_pos + num_bits {
+        return Err(SpsParsingError::UnexpectedEndOfSpsBuffer);
+    }
+    *bit_read_pos += num_bits;
+    Ok(())
 }
 
 fn read_bits(
@@ -316,10 +512,17 @@ fn read_exponential_golomb(
 }
 
 #[cfg(test)]
-mod tests {
-    use crate::decode::ffmpeg_h264::sps::SpsParsingError;
+pub(crate) mod tests {
+    use crate::decode::{ffmpeg_h264::sps::SpsParsingError, YuvPixelLayout};
+
+    use super::{read_bits, read_exponential_golomb, remove_emulation_prevention_bytes, H265Sps};
 
-    use super::{read_bits, read_exponential_golomb};
+    /// SPS NAL unit of a 1280x720 8-bit 4:2:0 H.265 video (Main profile, level 3.1), as found in its `hvcC` box.
+    pub(crate) const H265_SPS_720P: &[u8] = &[
+        0x42, 0x01, 0x01, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x03, 0x00, 0x5d, 0xa0, 0x02, 0x80, 0x80, 0x2d, 0x16, 0x59, 0x59, 0xa4, 0x93, 0x2b, 0xc0,
+        0x5a, 0x70, 0x80, 0x00, 0x01, 0xf4, 0x80, 0x00, 0x3a, 0x98, 0x04,
+    ];
 
     #[test]
     fn test_read_bits() {
@@ -383,4 +586,39 @@ mod tests {
             Err(SpsParsingError::UnexpectedEndOfSpsBuffer)
         );
     }
+
+    #[test]
+    fn test_remove_emulation_prevention_bytes() {
+        assert_eq!(
+            remove_emulation_prevention_bytes(&[
+                0x00, 0x00, 0x03, 0x01, 0x00, 0x03, 0x00, 0x00, 0x03
+            ]),
+            vec![0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00]
+        );
+        // Only the first 0x03 after two zeros is an emulation prevention byte.
+        assert_eq!(
+            remove_emulation_prevention_bytes(&[0x00, 0x00, 0x03, 0x03, 0x00]),
+            vec![0x00, 0x00, 0x03, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_parse_h265_sps() {
+        let sps = H265Sps::try_parse(&H265_SPS_720P[2..]).unwrap();
+
+        assert_eq!(sps.sps_max_sub_layers_minus1, 0);
+        assert_eq!(sps.general_profile_idc, 1);
+        assert_eq!(sps.general_level_idc, 93);
+        assert_eq!(sps.chroma_format_idc, 1);
+        assert_eq!(sps.pic_width_in_luma_samples, 1280);
+        assert_eq!(sps.pic_height_in_luma_samples, 720);
+        assert_eq!(sps.bit_depth_luma_minus8, 0);
+        assert_eq!(sps.bit_depth_chroma_minus8, 0);
+        assert_eq!(sps.pixel_layout(), Ok(YuvPixelLayout::Y_U_V420));
+
+        assert_eq!(
+            H265Sps::try_parse(&H265_SPS_720P[2..12]).unwrap_err(),
+            SpsParsingError::UnexpectedEndOfSpsBuffer
+        );
+    }
 }
@@ -226,6 +226,17 @@ pub fn new_decoder(
             )?))
         }
 
+        #[cfg(with_ffmpeg)]
+        re_mp4::StsdBoxContent::Hvc1(hevc_box) | re_mp4::StsdBoxContent::Hev1(hevc_box) => {
+            re_log::trace!("Decoding H.265…");
+            Ok(Box::new(ffmpeg_h264::FFmpegCliH264Decoder::new_h265(
+                debug_name.to_owned(),
+                hevc_box.clone(),
+                on_output,
+                decode_settings.ffmpeg_path.clone(),
+            )?))
+        }
+
         _ => Err(Error::UnsupportedCodec(video.human_readable_codec_string())),
     }
 }
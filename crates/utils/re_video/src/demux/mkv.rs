@@ -0,0 +1,1016 @@
+//! Matroska (`.mkv`) & WebM (`.webm`) demuxing.
+//!
+//! We only parse what's needed to index the frames of the first video track.
+//! See <https://www.matroska.org/technical/elements.html> for the full element list.
+
+use std::ops::Range;
+
+use super::{Config, Sample, VideoData, VideoLoadError};
+
+use crate::{Time, Timescale, TrackId, TrackKind};
+
+/// EBML element ids, including their length marker bits.
+mod ids {
+    pub const EBML: u32 = 0x1A45_DFA3;
+    pub const DOC_TYPE: u32 = 0x4282;
+    pub const SEGMENT: u32 = 0x1853_8067;
+
+    pub const SEEK_HEAD: u32 = 0x114D_9B74;
+    pub const INFO: u32 = 0x1549_A966;
+    pub const TRACKS: u32 = 0x1654_AE6B;
+    pub const CLUSTER: u32 = 0x1F43_B675;
+    pub const CUES: u32 = 0x1C53_BB6B;
+    pub const ATTACHMENTS: u32 = 0x1941_A469;
+    pub const CHAPTERS: u32 = 0x1043_A770;
+    pub const TAGS: u32 = 0x1254_C367;
+
+    /// Children of a segment. A cluster of unknown size ends at the first of these.
+    pub const SEGMENT_CHILDREN: [u32; 8] = [
+        SEEK_HEAD,
+        INFO,
+        TRACKS,
+        CLUSTER,
+        CUES,
+        ATTACHMENTS,
+        CHAPTERS,
+        TAGS,
+    ];
+
+    pub const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+    pub const DURATION: u32 = 0x4489;
+
+    pub const TRACK_ENTRY: u32 = 0xAE;
+    pub const TRACK_NUMBER: u32 = 0xD7;
+    pub const TRACK_TYPE: u32 = 0x83;
+    pub const CODEC_ID: u32 = 0x86;
+    pub const CODEC_PRIVATE: u32 = 0x63A2;
+    pub const DEFAULT_DURATION: u32 = 0x23_E383;
+    pub const VIDEO: u32 = 0xE0;
+    pub const PIXEL_WIDTH: u32 = 0xB0;
+    pub const PIXEL_HEIGHT: u32 = 0xBA;
+
+    pub const TIMESTAMP: u32 = 0xE7;
+    pub const SIMPLE_BLOCK: u32 = 0xA3;
+    pub const BLOCK_GROUP: u32 = 0xA0;
+    pub const BLOCK: u32 = 0xA1;
+    pub const BLOCK_DURATION: u32 = 0x9B;
+    pub const REFERENCE_BLOCK: u32 = 0xFB;
+}
+
+const TRACK_TYPE_VIDEO: u64 = 0x01;
+const TRACK_TYPE_AUDIO: u64 = 0x02;
+const TRACK_TYPE_SUBTITLE: u64 = 0x11;
+
+/// Nanoseconds per timestamp tick, unless the file says otherwise.
+const DEFAULT_TIMESTAMP_SCALE: u64 = 1_000_000;
+
+impl VideoData {
+    pub fn load_mkv(bytes: &[u8]) -> Result<Self, VideoLoadError> {
+        re_tracing::profile_function!();
+
+        let ebml = read_element_header(bytes, 0, bytes.len())
+            .filter(|header| header.id == ids::EBML)
+            .ok_or_else(|| VideoLoadError::ParseMkv("missing EBML header".to_owned()))?;
+        for child in children(bytes, &ebml) {
+            if child.id == ids::DOC_TYPE {
+                let doc_type = read_string(&bytes[child.data]);
+                if doc_type != "matroska" && doc_type != "webm" {
+                    return Err(VideoLoadError::ParseMkv(format!(
+                        "unsupported EBML document type {doc_type:?}"
+                    )));
+                }
+            }
+        }
+
+        let segment = read_element_header(bytes, ebml.data.end, bytes.len())
+            .filter(|header| header.id == ids::SEGMENT)
+            .ok_or_else(|| VideoLoadError::ParseMkv("missing segment".to_owned()))?;
+
+        let mut info = SegmentInfo::default();
+        let mut tracks = Vec::<TrackEntry>::new();
+        let mut blocks = Vec::<Block>::new();
+
+        {
+            re_tracing::profile_scope!("parse segment");
+
+            let mut pos = segment.data.start;
+            while pos < segment.data.end {
+                let Some(element) = read_element_header(bytes, pos, segment.data.end) else {
+                    re_log::warn_once!("Matroska data is truncated; ignoring the rest.");
+                    break;
+                };
+
+                pos = match element.id {
+                    ids::INFO => {
+                        info = SegmentInfo::parse(bytes, &element);
+                        element.data.end
+                    }
+                    ids::TRACKS => {
+                        tracks = children(bytes, &element)
+                            .filter(|child| child.id == ids::TRACK_ENTRY)
+                            .map(|child| TrackEntry::parse(bytes, &child))
+                            .collect();
+                        element.data.end
+                    }
+                    ids::CLUSTER => {
+                        let track_number = video_track(&tracks)?.number;
+                        parse_cluster(bytes, &element, track_number, &mut blocks)?
+                    }
+                    id => {
+                        if element.unknown_size {
+                            return Err(VideoLoadError::ParseMkv(format!(
+                                "element {id:#X} has unknown size"
+                            )));
+                        }
+                        element.data.end
+                    }
+                };
+            }
+        }
+
+        let track = video_track(&tracks)?;
+
+        let mp4_tracks = tracks
+            .iter()
+            .map(|track| {
+                let kind = match track.track_type {
+                    TRACK_TYPE_VIDEO => Some(TrackKind::Video),
+                    TRACK_TYPE_AUDIO => Some(TrackKind::Audio),
+                    TRACK_TYPE_SUBTITLE => Some(TrackKind::Subtitle),
+                    _ => None,
+                };
+                (track.number as TrackId, kind)
+            })
+            .collect();
+
+        let first_keyframe = blocks
+            .iter()
+            .find(|block| block.is_keyframe)
+            .map(|block| &bytes[block.data.clone()]);
+        let (contents, description) = codec_config(track, first_keyframe)?;
+
+        let config = Config {
+            stsd: re_mp4::StsdBox {
+                version: 0,
+                flags: 0,
+                contents,
+            },
+            description,
+            coded_width: track.width as u16,
+            coded_height: track.height as u16,
+        };
+
+        // Matroska timestamps are in units of `timestamp_scale` nanoseconds.
+        // Use that as our timescale if we can, otherwise fall back to nanoseconds.
+        let timestamp_scale = info.timestamp_scale.max(1);
+        let (timescale, ticks_to_time) = if 1_000_000_000 % timestamp_scale == 0 {
+            (Timescale::new(1_000_000_000 / timestamp_scale), 1)
+        } else {
+            (Timescale::new(1_000_000_000), timestamp_scale as i64)
+        };
+        let default_duration = track
+            .default_duration_ns
+            .map(|ns| (ns as f64 / timestamp_scale as f64).round() as i64);
+        let total_duration = info.duration.map(|duration| duration.round() as i64);
+
+        let mut samples = Vec::<Sample>::with_capacity(blocks.len());
+
+        {
+            re_tracing::profile_scope!("copy samples");
+
+            for (sample_idx, block) in blocks.iter().enumerate() {
+                let duration = block
+                    .duration
+                    .or_else(|| {
+                        blocks
+                            .get(sample_idx + 1)
+                            .map(|next| next.timestamp - block.timestamp)
+                    })
+                    .or(default_duration)
+                    .or_else(|| total_duration.map(|total| total - block.timestamp))
+                    .or_else(|| {
+                        samples
+                            .last()
+                            .map(|prev: &Sample| prev.duration.0 / ticks_to_time)
+                    })
+                    .unwrap_or(0)
+                    .max(0);
+
+                // Matroska only stores presentation timestamps, and we only support codecs
+                // without frame reordering, so decode order is presentation order.
+                let timestamp = Time::new(block.timestamp * ticks_to_time);
+
+                samples.push(Sample {
+                    is_sync: block.is_keyframe,
+                    sample_idx,
+                    frame_nr: 0, // filled in by `from_samples`
+                    decode_timestamp: timestamp,
+                    presentation_timestamp: timestamp,
+                    duration: Time::new(duration * ticks_to_time),
+                    byte_offset: block.data.start as u32,
+                    byte_length: block.data.len() as u32,
+                });
+            }
+        }
+
+        let duration = total_duration
+            .or_else(|| {
+                blocks
+                    .last()
+                    .zip(samples.last())
+                    .map(|(block, sample)| block.timestamp + sample.duration.0 / ticks_to_time)
+            })
+            .unwrap_or(0);
+        let duration = Time::new(duration * ticks_to_time);
+
+        Ok(Self::from_samples(
+            config, timescale, duration, samples, mp4_tracks,
+        ))
+    }
+}
+
+fn video_track(tracks: &[TrackEntry]) -> Result<&TrackEntry, VideoLoadError> {
+    tracks
+        .iter()
+        .find(|track| track.track_type == TRACK_TYPE_VIDEO)
+        .ok_or(VideoLoadError::NoVideoTrack)
+}
+
+/// The parts of the `Info` element we care about.
+struct SegmentInfo {
+    /// Nanoseconds per timestamp tick.
+    timestamp_scale: u64,
+
+    /// Duration of the segment, in timestamp ticks.
+    duration: Option<f64>,
+}
+
+impl Default for SegmentInfo {
+    fn default() -> Self {
+        Self {
+            timestamp_scale: DEFAULT_TIMESTAMP_SCALE,
+            duration: None,
+        }
+    }
+}
+
+impl SegmentInfo {
+    fn parse(bytes: &[u8], info: &ElementHeader) -> Self {
+        let mut result = Self::default();
+        for child in children(bytes, info) {
+            match child.id {
+                ids::TIMESTAMP_SCALE => result.timestamp_scale = read_uint(&bytes[child.data]),
+                ids::DURATION => result.duration = read_float(&bytes[child.data]),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+/// The parts of a `TrackEntry` element we care about.
+#[derive(Default)]
+struct TrackEntry {
+    number: u64,
+    track_type: u64,
+
+    /// E.g. `V_AV1` or `V_VP9`.
+    codec_id: String,
+
+    /// Codec specific configuration, e.g. the contents of an `av1C` box for AV1.
+    codec_private: Vec<u8>,
+
+    /// Duration of each frame, in nanoseconds.
+    default_duration_ns: Option<u64>,
+
+    width: u64,
+    height: u64,
+}
+
+impl TrackEntry {
+    fn parse(bytes: &[u8], entry: &ElementHeader) -> Self {
+        let mut result = Self::default();
+        for child in children(bytes, entry) {
+            let data = &bytes[child.data.clone()];
+            match child.id {
+                ids::TRACK_NUMBER => result.number = read_uint(data),
+                ids::TRACK_TYPE => result.track_type = read_uint(data),
+                ids::CODEC_ID => result.codec_id = read_string(data),
+                ids::CODEC_PRIVATE => result.codec_private = data.to_vec(),
+                ids::DEFAULT_DURATION => result.default_duration_ns = Some(read_uint(data)),
+                ids::VIDEO => {
+                    for video_child in children(bytes, &child) {
+                        let data = &bytes[video_child.data];
+                        match video_child.id {
+                            ids::PIXEL_WIDTH => result.width = read_uint(data),
+                            ids::PIXEL_HEIGHT => result.height = read_uint(data),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+/// A single frame of the video track.
+struct Block {
+    /// Presentation timestamp, in timestamp ticks.
+    timestamp: i64,
+
+    is_keyframe: bool,
+
+    /// Only set for blocks in a `BlockGroup` with an explicit `BlockDuration`.
+    duration: Option<i64>,
+
+    /// Where the frame data lives in the file.
+    data: Range<usize>,
+}
+
+/// Collects all blocks of the given track within the cluster.
+///
+/// Returns where the cluster ends, which for clusters of unknown size is
+/// only known once we hit the next top-level element.
+fn parse_cluster(
+    bytes: &[u8],
+    cluster: &ElementHeader,
+    track_number: u64,
+    blocks: &mut Vec<Block>,
+) -> Result<usize, VideoLoadError> {
+    let mut cluster_timestamp = 0;
+
+    let mut pos = cluster.data.start;
+    while pos < cluster.data.end {
+        let Some(element) = read_element_header(bytes, pos, cluster.data.end) else {
+            re_log::warn_once!("Matroska data is truncated; ignoring the rest.");
+            return Ok(cluster.data.end);
+        };
+        if cluster.unknown_size && ids::SEGMENT_CHILDREN.contains(&element.id) {
+            return Ok(pos);
+        }
+        if element.truncated {
+            // Most likely a recording that was cut short: keep what we've got so far.
+            re_log::warn_once!("Matroska data is truncated; ignoring the rest.");
+            return Ok(cluster.data.end);
+        }
+
+        match element.id {
+            ids::TIMESTAMP => cluster_timestamp = read_uint(&bytes[element.data.clone()]) as i64,
+
+            ids::SIMPLE_BLOCK => {
+                if let Some((mut block, flags)) =
+                    parse_block(bytes, element.data.clone(), cluster_timestamp, track_number)?
+                {
+                    block.is_keyframe = flags & 0x80 != 0;
+                    blocks.push(block);
+                }
+            }
+
+            ids::BLOCK_GROUP => {
+                let mut block = None;
+                let mut duration = None;
+                let mut has_reference = false;
+                for child in children(bytes, &element) {
+                    match child.id {
+                        ids::BLOCK => {
+                            block =
+                                parse_block(bytes, child.data, cluster_timestamp, track_number)?
+                                    .map(|(block, _flags)| block);
+                        }
+                        ids::BLOCK_DURATION => {
+                            duration = Some(read_uint(&bytes[child.data]) as i64);
+                        }
+                        ids::REFERENCE_BLOCK => has_reference = true,
+                        _ => {}
+                    }
+                }
+                if let Some(mut block) = block {
+                    // Blocks that don't reference any other block are keyframes.
+                    block.is_keyframe = !has_reference;
+                    block.duration = duration;
+                    blocks.push(block);
+                }
+            }
+
+            _ => {}
+        }
+
+        pos = element.data.end;
+    }
+
+    Ok(cluster.data.end)
+}
+
+/// Parses the header of a `SimpleBlock` or `Block`, returning the block & its flags.
+///
+/// Returns `None` if the block belongs to a different track.
+fn parse_block(
+    bytes: &[u8],
+    data: Range<usize>,
+    cluster_timestamp: i64,
+    track_number: u64,
+) -> Result<Option<(Block, u8)>, VideoLoadError> {
+    let invalid = || VideoLoadError::ParseMkv(format!("invalid block at byte {}", data.start));
+
+    let (track, track_len) = read_vint(bytes, data.start).ok_or_else(invalid)?;
+    let track = track & vint_value_mask(track_len);
+    let header_start = data.start + track_len;
+    let header = bytes
+        .get(header_start..header_start + 3)
+        .filter(|_| header_start + 3 <= data.end)
+        .ok_or_else(invalid)?;
+
+    if track != track_number {
+        return Ok(None);
+    }
+
+    let relative_timestamp = i16::from_be_bytes([header[0], header[1]]);
+    let flags = header[2];
+    if flags & 0x06 != 0 {
+        return Err(VideoLoadError::ParseMkv(
+            "laced video blocks are not supported".to_owned(),
+        ));
+    }
+
+    Ok(Some((
+        Block {
+            timestamp: cluster_timestamp + relative_timestamp as i64,
+            is_keyframe: false,
+            duration: None,
+            data: header_start + 3..data.end,
+        },
+        flags,
+    )))
+}
+
+/// Derives the mp4 sample description that the decoders expect from the Matroska track.
+///
+/// Returns the sample description contents and the raw codec configuration.
+fn codec_config(
+    track: &TrackEntry,
+    first_keyframe: Option<&[u8]>,
+) -> Result<(re_mp4::StsdBoxContent, Vec<u8>), VideoLoadError> {
+    let width = track.width as u16;
+    let height = track.height as u16;
+
+    match track.codec_id.as_str() {
+        "V_AV1" => {
+            // The CodecPrivate of an AV1 track is the content of an `av1C` box,
+            // so the easiest way to get at it is to wrap it into an `av01` sample entry.
+            use re_mp4::ReadBox as _;
+
+            if track.codec_private.is_empty() {
+                return Err(VideoLoadError::InvalidConfigFormat);
+            }
+
+            let mut entry = vec![0; 8]; // Header of the `av01` box itself, skipped by `read_box`.
+            entry.extend([0; 6]); // reserved
+            entry.extend(1u16.to_be_bytes()); // data_reference_index
+            entry.extend([0; 16]); // pre-defined & reserved
+            entry.extend(width.to_be_bytes());
+            entry.extend(height.to_be_bytes());
+            entry.extend(0x0048_0000u32.to_be_bytes()); // horizresolution: 72 dpi
+            entry.extend(0x0048_0000u32.to_be_bytes()); // vertresolution: 72 dpi
+            entry.extend([0; 4]); // reserved
+            entry.extend(1u16.to_be_bytes()); // frame_count
+            entry.extend([0; 32]); // compressorname
+            entry.extend(24u16.to_be_bytes()); // depth
+            entry.extend((-1i16).to_be_bytes()); // pre-defined
+            entry.extend((8 + track.codec_private.len() as u32).to_be_bytes());
+            entry.extend(b"av1C");
+            entry.extend(&track.codec_private);
+
+            let size = entry.len() as u64;
+            let mut reader = std::io::Cursor::new(entry);
+            reader.set_position(8);
+            let av01 = re_mp4::Av01Box::read_box(&mut reader, size)
+                .map_err(|_err| VideoLoadError::InvalidConfigFormat)?;
+
+            Ok((
+                re_mp4::StsdBoxContent::Av01(av01),
+                track.codec_private.clone(),
+            ))
+        }
+
+        "V_VP9" => {
+            let vpcc = vp9_config(&track.codec_private, first_keyframe);
+            Ok((
+                re_mp4::StsdBoxContent::Vp09(re_mp4::Vp09Box {
+                    data_reference_index: 1,
+                    width,
+                    height,
+                    vpcc: re_mp4::RawBox {
+                        contents: vpcc,
+                        raw: Vec::new(),
+                    },
+                    ..Default::default()
+                }),
+                Vec::new(),
+            ))
+        }
+
+        "V_VP8" => Ok((
+            re_mp4::StsdBoxContent::Vp08(re_mp4::Vp08Box {
+                data_reference_index: 1,
+                width,
+                height,
+                vpcc: re_mp4::RawBox {
+                    // VP8 only has a single profile: 8 bit 4:2:0.
+                    contents: re_mp4::VpccBox {
+                        version: re_mp4::VpccBox::DEFAULT_VERSION,
+                        level: 10,
+                        bit_depth: 8,
+                        chroma_subsampling: 1,
+                        ..Default::default()
+                    },
+                    raw: Vec::new(),
+                },
+                ..Default::default()
+            }),
+            Vec::new(),
+        )),
+
+        codec_id => Err(VideoLoadError::UnsupportedMkvCodec(codec_id.to_owned())),
+    }
+}
+
+/// Determines the VP9 profile, bit depth & chroma subsampling of a track.
+///
+/// These are optionally stored in the `CodecPrivate`, otherwise we read them from the first keyframe.
+fn vp9_config(codec_private: &[u8], first_keyframe: Option<&[u8]>) -> re_mp4::VpccBox {
+    let mut vpcc = re_mp4::VpccBox {
+        version: re_mp4::VpccBox::DEFAULT_VERSION,
+        level: 10,
+        bit_depth: 8,
+        chroma_subsampling: 1,
+        ..Default::default()
+    };
+
+    if let Some(header) = first_keyframe.and_then(Vp9KeyframeHeader::parse) {
+        vpcc.profile = header.profile;
+        vpcc.bit_depth = header.bit_depth;
+        vpcc.chroma_subsampling = header.chroma_subsampling;
+        vpcc.video_full_range_flag = header.full_range;
+    }
+
+    // https://www.webmproject.org/docs/container/#vp9-codec-feature-metadata-codecprivate
+    let mut features = codec_private;
+    while let [id, len, rest @ ..] = features {
+        let len = *len as usize;
+        let Some(value) = rest.get(..len) else {
+            break;
+        };
+        if let [value] = value {
+            match id {
+                1 => vpcc.profile = *value,
+                2 => vpcc.level = *value,
+                3 => vpcc.bit_depth = *value,
+                4 => vpcc.chroma_subsampling = *value,
+                _ => {}
+            }
+        }
+        features = &rest[len..];
+    }
+
+    vpcc
+}
+
+/// The parts of the uncompressed header of a VP9 keyframe we care about.
+///
+/// See section 6.2 of the VP9 bitstream specification.
+struct Vp9KeyframeHeader {
+    profile: u8,
+    bit_depth: u8,
+
+    /// Using the `vpcC` box values.
+    chroma_subsampling: u8,
+
+    full_range: bool,
+}
+
+impl Vp9KeyframeHeader {
+    fn parse(frame: &[u8]) -> Option<Self> {
+        const CS_RGB: u32 = 7;
+
+        let mut reader = BitReader::new(frame);
+
+        if reader.read(2)? != 2 {
+            return None; // frame_marker
+        }
+        let profile_low_bit = reader.read(1)?;
+        let profile_high_bit = reader.read(1)?;
+        let profile = ((profile_high_bit << 1) | profile_low_bit) as u8;
+        if profile == 3 {
+            reader.read(1)?; // reserved_zero
+        }
+        if reader.read(1)? != 0 {
+            return None; // show_existing_frame
+        }
+        if reader.read(1)? != 0 {
+            return None; // frame_type is not KEY_FRAME
+        }
+        reader.read(2)?; // show_frame, error_resilient_mode
+        if reader.read(24)? != 0x49_83_42 {
+            return None; // frame_sync_code
+        }
+
+        let bit_depth = if profile >= 2 {
+            if reader.read(1)? == 1 {
+                12
+            } else {
+                10
+            }
+        } else {
+            8
+        };
+
+        let color_space = reader.read(3)?;
+        let (full_range, subsampling_x, subsampling_y) = if color_space == CS_RGB {
+            (true, 0, 0)
+        } else {
+            let full_range = reader.read(1)? == 1;
+            if profile == 1 || profile == 3 {
+                (full_range, reader.read(1)?, reader.read(1)?)
+            } else {
+                (full_range, 1, 1)
+            }
+        };
+
+        // See `VideoData::subsampling_mode` for the meaning of these values.
+        let chroma_subsampling = match (subsampling_x, subsampling_y) {
+            (1, 1) => 1,
+            (1, 0) => 2,
+            (0, 0) => 3,
+            _ => return None,
+        };
+
+        Some(Self {
+            profile,
+            bit_depth,
+            chroma_subsampling,
+            full_range,
+        })
+    }
+}
+
+/// Reads big-endian bit fields.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, bit_pos: 0 }
+    }
+
+    /// Reads up to 32 bits, returning `None` if we run out of data.
+    fn read(&mut self, num_bits: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..num_bits {
+            let byte = self.bytes.get(self.bit_pos / 8)?;
+            let bit = (byte >> (7 - self.bit_pos % 8)) & 1;
+            value = (value << 1) | bit as u32;
+            self.bit_pos += 1;
+        }
+        Some(value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// EBML
+
+/// Header of an EBML element.
+#[derive(Clone, Debug)]
+struct ElementHeader {
+    /// Element id, including its length marker bits.
+    id: u32,
+
+    /// Where the element's data lives, clamped to the parent element.
+    data: Range<usize>,
+
+    /// Elements of unknown size extend to the end of their parent, or until an element
+    /// is encountered that can't be a child of theirs.
+    unknown_size: bool,
+
+    /// The element claims to extend beyond its parent.
+    truncated: bool,
+}
+
+/// Reads a variable size integer, returning its raw value (including length marker) and length in bytes.
+fn read_vint(bytes: &[u8], pos: usize) -> Option<(u64, usize)> {
+    let first = *bytes.get(pos)?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 {
+        return None;
+    }
+    let raw = bytes
+        .get(pos..pos + len)?
+        .iter()
+        .fold(0, |acc, &byte| (acc << 8) | byte as u64);
+    Some((raw, len))
+}
+
+/// Mask that removes the length marker from a variable size integer of the given length.
+fn vint_value_mask(len: usize) -> u64 {
+    (1 << (7 * len)) - 1
+}
+
+/// Reads the header of the element at `pos`, returning `None` if it's invalid or cut off.
+fn read_element_header(bytes: &[u8], pos: usize, parent_end: usize) -> Option<ElementHeader> {
+    let (id, id_len) = read_vint(bytes, pos).filter(|(_, len)| *len <= 4)?;
+    let (size, size_len) = read_vint(bytes, pos + id_len)?;
+
+    let data_start = pos + id_len + size_len;
+    if data_start > parent_end {
+        return None;
+    }
+
+    let size = size & vint_value_mask(size_len);
+    let unknown_size = size == vint_value_mask(size_len);
+    let (data_end, truncated) = if unknown_size {
+        (parent_end, false)
+    } else {
+        let end = data_start.saturating_add(size as usize);
+        (end.min(parent_end), parent_end < end)
+    };
+
+    Some(ElementHeader {
+        id: id as u32,
+        data: data_start..data_end,
+        unknown_size,
+        truncated,
+    })
+}
+
+/// Iterates over the child elements of `parent`, stopping at the first invalid one.
+fn children<'a>(
+    bytes: &'a [u8],
+    parent: &ElementHeader,
+) -> impl Iterator<Item = ElementHeader> + 'a {
+    let end = parent.data.end;
+    let mut pos = parent.data.start;
+    std::iter::from_fn(move || {
+        if pos >= end {
+            return None;
+        }
+        let child = read_element_header(bytes, pos, end)?;
+        pos = child.data.end;
+        Some(child)
+    })
+}
+
+fn read_uint(data: &[u8]) -> u64 {
+    data.iter()
+        .take(8)
+        .fold(0, |acc, &byte| (acc << 8) | byte as u64)
+}
+
+fn read_float(data: &[u8]) -> Option<f64> {
+    match *data {
+        [a, b, c, d] => Some(f32::from_be_bytes([a, b, c, d]) as f64),
+        [a, b, c, d, e, f, g, h] => Some(f64::from_be_bytes([a, b, c, d, e, f, g, h])),
+        _ => None,
+    }
+}
+
+fn read_string(data: &[u8]) -> String {
+    String::from_utf8_lossy(data)
+        .trim_end_matches('\0')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an EBML element with an 8 byte size.
+    fn element(id: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = id_bytes(id);
+        bytes.push(0x01);
+        bytes.extend(&(data.len() as u64).to_be_bytes()[1..]);
+        bytes.extend(data);
+        bytes
+    }
+
+    fn unknown_size_element(id: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = id_bytes(id);
+        bytes.extend([0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        bytes.extend(data);
+        bytes
+    }
+
+    fn id_bytes(id: u32) -> Vec<u8> {
+        id.to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect()
+    }
+
+    fn uint(id: u32, value: u64) -> Vec<u8> {
+        element(id, &value.to_be_bytes())
+    }
+
+    fn simple_block(timestamp: i16, keyframe: bool, frame: &[u8]) -> Vec<u8> {
+        let mut data = vec![0x81]; // track number 1
+        data.extend(timestamp.to_be_bytes());
+        data.push(if keyframe { 0x80 } else { 0x00 });
+        data.extend(frame);
+        element(ids::SIMPLE_BLOCK, &data)
+    }
+
+    fn block_group(
+        timestamp: i16,
+        reference: bool,
+        duration: Option<u64>,
+        frame: &[u8],
+    ) -> Vec<u8> {
+        let mut block = vec![0x81]; // track number 1
+        block.extend(timestamp.to_be_bytes());
+        block.push(0x00);
+        block.extend(frame);
+
+        let mut data = element(ids::BLOCK, &block);
+        if reference {
+            data.extend(element(ids::REFERENCE_BLOCK, &[0xFF]));
+        }
+        if let Some(duration) = duration {
+            data.extend(uint(ids::BLOCK_DURATION, duration));
+        }
+        element(ids::BLOCK_GROUP, &data)
+    }
+
+    fn mkv_file(codec_id: &str, codec_private: &[u8], segment_children: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = element(ids::EBML, &element(ids::DOC_TYPE, b"webm"));
+
+        let mut video = uint(ids::PIXEL_WIDTH, 1280);
+        video.extend(uint(ids::PIXEL_HEIGHT, 720));
+
+        let mut entry = uint(ids::TRACK_NUMBER, 1);
+        entry.extend(uint(ids::TRACK_TYPE, TRACK_TYPE_VIDEO));
+        entry.extend(element(ids::CODEC_ID, codec_id.as_bytes()));
+        if !codec_private.is_empty() {
+            entry.extend(element(ids::CODEC_PRIVATE, codec_private));
+        }
+        entry.extend(element(ids::VIDEO, &video));
+
+        let mut audio_entry = uint(ids::TRACK_NUMBER, 2);
+        audio_entry.extend(uint(ids::TRACK_TYPE, TRACK_TYPE_AUDIO));
+        audio_entry.extend(element(ids::CODEC_ID, b"A_OPUS"));
+
+        let mut tracks = element(ids::TRACK_ENTRY, &entry);
+        tracks.extend(element(ids::TRACK_ENTRY, &audio_entry));
+
+        let mut segment = element(ids::INFO, &uint(ids::TIMESTAMP_SCALE, 1_000_000));
+        segment.extend(element(ids::TRACKS, &tracks));
+        for child in segment_children {
+            segment.extend(child);
+        }
+        bytes.extend(unknown_size_element(ids::SEGMENT, &segment));
+
+        bytes
+    }
+
+    /// Start of a VP9 keyframe: profile 0, 8 bit, 4:2:0, limited range.
+    const VP9_KEYFRAME: &[u8] = &[0x82, 0x49, 0x83, 0x42, 0x00, 0x13, 0xF0];
+
+    #[test]
+    fn test_vint() {
+        assert_eq!(read_vint(&[0x81], 0), Some((0x81, 1)));
+        assert_eq!(read_vint(&[0x40, 0x02], 0), Some((0x4002, 2)));
+        assert_eq!(read_vint(&[0x40], 0), None);
+        assert_eq!(read_vint(&[0x00], 0), None);
+        assert_eq!(0x4002 & vint_value_mask(2), 2);
+    }
+
+    #[test]
+    fn test_vp9_keyframe_header() {
+        let header = Vp9KeyframeHeader::parse(VP9_KEYFRAME).expect("valid keyframe header");
+        assert_eq!(header.profile, 0);
+        assert_eq!(header.bit_depth, 8);
+        assert_eq!(header.chroma_subsampling, 1);
+        assert!(!header.full_range);
+
+        // Not a keyframe.
+        assert!(Vp9KeyframeHeader::parse(&[0x86, 0x00]).is_none());
+    }
+
+    #[test]
+    fn test_load_vp9() {
+        let mut cluster = uint(ids::TIMESTAMP, 1000);
+        cluster.extend(simple_block(0, true, VP9_KEYFRAME));
+        cluster.extend(simple_block(40, false, &[1, 2, 3]));
+        cluster.extend(block_group(80, true, None, &[4, 5]));
+        cluster.extend(block_group(120, false, Some(50), VP9_KEYFRAME));
+
+        let bytes = mkv_file("V_VP9", &[], &[element(ids::CLUSTER, &cluster)]);
+        let video = VideoData::load_mkv(&bytes).expect("valid mkv");
+
+        assert_eq!(video.human_readable_codec_string(), "VP9 (vp09.00.10.08)");
+        assert_eq!(video.dimensions(), [1280, 720]);
+        assert_eq!(video.bit_depth(), Some(8));
+        assert_eq!(
+            video.mp4_tracks,
+            [(1, Some(TrackKind::Video)), (2, Some(TrackKind::Audio))].into()
+        );
+
+        assert_eq!(video.timescale, Timescale::new(1000));
+        let timestamps = video
+            .samples
+            .iter()
+            .map(|s| (s.presentation_timestamp.0, s.duration.0, s.is_sync))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            timestamps,
+            [
+                (1000, 40, true),
+                (1040, 40, false),
+                (1080, 40, false),
+                (1120, 50, true)
+            ]
+        );
+        assert_eq!(video.duration, Time::new(1170));
+
+        let gop_ranges = video
+            .gops
+            .iter()
+            .map(|gop| gop.sample_range.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(gop_ranges, [0..3, 3..4]);
+
+        let first = &video.samples[0];
+        let range = first.byte_offset as usize..(first.byte_offset + first.byte_length) as usize;
+        assert_eq!(&bytes[range], VP9_KEYFRAME);
+        let second = &video.samples[2];
+        let range = second.byte_offset as usize..(second.byte_offset + second.byte_length) as usize;
+        assert_eq!(&bytes[range], &[4, 5]);
+    }
+
+    #[test]
+    fn test_load_av1_with_unknown_size_clusters() {
+        // Profile 0, level 8, 8 bit, 4:2:0.
+        let av1c = [0x81, 0x08, 0x0C, 0x00];
+
+        let mut first_cluster = uint(ids::TIMESTAMP, 0);
+        first_cluster.extend(simple_block(0, true, &[0x12, 0x00]));
+        first_cluster.extend(simple_block(33, false, &[0x12, 0x01]));
+
+        let mut second_cluster = uint(ids::TIMESTAMP, 66);
+        second_cluster.extend(simple_block(0, true, &[0x12, 0x02]));
+
+        let bytes = mkv_file(
+            "V_AV1",
+            &av1c,
+            &[
+                unknown_size_element(ids::CLUSTER, &first_cluster),
+                unknown_size_element(ids::CLUSTER, &second_cluster),
+            ],
+        );
+        let video = VideoData::load_mkv(&bytes).expect("valid mkv");
+
+        assert!(video.config.is_av1());
+        assert_eq!(video.human_readable_codec_string(), "AV1 (av01.0.08M.08)");
+        assert_eq!(video.config.description, av1c);
+        assert_eq!(video.num_samples(), 3);
+        assert_eq!(video.gops.len(), 2);
+
+        let timestamps = video
+            .samples
+            .iter()
+            .map(|s| s.presentation_timestamp.0)
+            .collect::<Vec<_>>();
+        assert_eq!(timestamps, [0, 33, 66]);
+    }
+
+    #[test]
+    fn test_truncated_file() {
+        let mut cluster = uint(ids::TIMESTAMP, 0);
+        cluster.extend(simple_block(0, true, VP9_KEYFRAME));
+        cluster.extend(simple_block(40, false, &[1, 2, 3, 4, 5, 6]));
+
+        let mut bytes = mkv_file("V_VP9", &[], &[element(ids::CLUSTER, &cluster)]);
+        bytes.truncate(bytes.len() - 3);
+
+        let video = VideoData::load_mkv(&bytes).expect("truncated but otherwise valid mkv");
+        assert_eq!(video.num_samples(), 1);
+    }
+
+    #[test]
+    fn test_unsupported_codec() {
+        let bytes = mkv_file("V_MPEG4/ISO/AVC", &[], &[]);
+        assert!(matches!(
+            VideoData::load_mkv(&bytes),
+            Err(VideoLoadError::UnsupportedMkvCodec(codec)) if codec == "V_MPEG4/ISO/AVC"
+        ));
+    }
+
+    #[test]
+    fn test_not_matroska() {
+        assert!(matches!(
+            VideoData::load_mkv(b"definitely not a video"),
+            Err(VideoLoadError::ParseMkv(_))
+        ));
+    }
+}
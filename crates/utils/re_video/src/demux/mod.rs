@@ -5,6 +5,7 @@
 //! The entry point is [`VideoData::load_from_bytes`]
 //! which produces an instance of [`VideoData`] from any supported video container.
 
+pub mod mkv;
 pub mod mp4;
 
 use std::{collections::BTreeMap, ops::Range};
@@ -66,7 +67,7 @@ pub struct VideoData {
     /// Meta information about the samples.
     pub samples_statistics: SamplesStatistics,
 
-    /// All the tracks in the mp4; not just the video track.
+    /// All the tracks in the container (mp4 or Matroska); not just the video track.
     ///
     /// Can be nice to show in a UI.
     pub mp4_tracks: BTreeMap<TrackId, Option<TrackKind>>,
@@ -125,6 +126,7 @@ impl VideoData {
         re_tracing::profile_function!();
         match media_type {
             "video/mp4" => Self::load_mp4(data),
+            "video/webm" | "video/x-matroska" | "video/matroska" => Self::load_mkv(data),
 
             media_type => {
                 if media_type.starts_with("video/") {
@@ -140,6 +142,77 @@ impl VideoData {
         }
     }
 
+    /// Assembles the video from its samples, given in decode order.
+    ///
+    /// Fills in the GOPs, frame numbers & statistics of the samples.
+    fn from_samples(
+        config: Config,
+        timescale: Timescale,
+        duration: Time,
+        mut samples: Vec<Sample>,
+        mp4_tracks: BTreeMap<TrackId, Option<TrackKind>>,
+    ) -> Self {
+        let mut gops = Vec::<GroupOfPictures>::new();
+
+        {
+            re_tracing::profile_scope!("build gops");
+
+            let mut gop_sample_start_index = 0;
+            for (sample_idx, sample) in samples.iter().enumerate() {
+                if sample.is_sync && sample_idx > 0 {
+                    gops.push(GroupOfPictures {
+                        decode_start_time: samples[gop_sample_start_index].decode_timestamp,
+                        sample_range: gop_sample_start_index as u32..sample_idx as u32,
+                    });
+                    gop_sample_start_index = sample_idx;
+                }
+            }
+
+            // Append the last GOP if there are any samples left:
+            if !samples.is_empty() {
+                gops.push(GroupOfPictures {
+                    decode_start_time: samples[gop_sample_start_index].decode_timestamp,
+                    sample_range: gop_sample_start_index as u32..samples.len() as u32,
+                });
+            }
+        }
+
+        {
+            re_tracing::profile_scope!("Sanity-check samples");
+            let mut samples_are_in_decode_order = true;
+            for window in samples.windows(2) {
+                samples_are_in_decode_order &=
+                    window[0].decode_timestamp <= window[1].decode_timestamp;
+            }
+            if !samples_are_in_decode_order {
+                re_log::warn!(
+                    "Video samples are NOT in decode order. This implies either invalid video data or a bug in parsing the container."
+                );
+            }
+        }
+
+        {
+            re_tracing::profile_scope!("Calculate frame numbers");
+            let mut samples_sorted_by_pts = samples.iter_mut().collect::<Vec<_>>();
+            samples_sorted_by_pts.sort_by_key(|s| s.presentation_timestamp);
+            for (frame_nr, sample) in samples_sorted_by_pts.into_iter().enumerate() {
+                sample.frame_nr = frame_nr;
+            }
+        }
+
+        let samples_statistics = SamplesStatistics::new(&samples);
+
+        Self {
+            config,
+            timescale,
+            duration,
+            samples_statistics,
+            gops,
+            samples,
+            mp4_tracks,
+        }
+    }
+
     /// Length of the video.
     #[inline]
     pub fn duration(&self) -> std::time::Duration {
@@ -534,6 +607,9 @@ pub enum VideoLoadError {
     #[error("Failed to determine media type from data: {0}")]
     ParseMp4(#[from] re_mp4::Error),
 
+    #[error("Failed to parse Matroska/WebM data: {0}")]
+    ParseMkv(String),
+
     #[error("Video file has no video tracks")]
     NoVideoTrack,
 
@@ -560,6 +636,10 @@ pub enum VideoLoadError {
     // `FourCC`'s debug impl doesn't quote the result
     #[error("Video track uses unsupported codec \"{0}\"")] // NOLINT
     UnsupportedCodec(re_mp4::FourCC),
+
+    /// Unlike mp4, Matroska identifies codecs with strings, e.g. `V_MPEG4/ISO/AVC`.
+    #[error("Video track uses unsupported Matroska codec \"{0}\"")] // NOLINT
+    UnsupportedMkvCodec(String),
 }
 
 impl std::fmt::Debug for VideoData {
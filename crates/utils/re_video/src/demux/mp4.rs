@@ -1,8 +1,8 @@
 #![allow(clippy::map_err_ignore)]
 
-use super::{Config, GroupOfPictures, Sample, VideoData, VideoLoadError};
+use super::{Config, Sample, VideoData, VideoLoadError};
 
-use crate::{demux::SamplesStatistics, Time, Timescale};
+use crate::{Time, Timescale};
 
 impl VideoData {
     pub fn load_mp4(bytes: &[u8]) -> Result<Self, VideoLoadError> {
@@ -39,23 +39,11 @@ impl VideoData {
         let timescale = Timescale::new(track.timescale);
         let duration = Time::new(track.duration as i64);
         let mut samples = Vec::<Sample>::new();
-        let mut gops = Vec::<GroupOfPictures>::new();
-        let mut gop_sample_start_index = 0;
 
         {
-            re_tracing::profile_scope!("copy samples & build gops");
+            re_tracing::profile_scope!("copy samples");
 
             for (sample_idx, sample) in track.samples.iter().enumerate() {
-                if sample.is_sync && !samples.is_empty() {
-                    let start = samples[gop_sample_start_index].decode_timestamp;
-                    let sample_range = gop_sample_start_index as u32..samples.len() as u32;
-                    gops.push(GroupOfPictures {
-                        decode_start_time: start,
-                        sample_range,
-                    });
-                    gop_sample_start_index = samples.len();
-                }
-
                 let decode_timestamp = Time::new(sample.decode_timestamp);
                 let presentation_timestamp = Time::new(sample.composition_timestamp);
                 let duration = Time::new(sample.duration as i64);
@@ -66,7 +54,7 @@ impl VideoData {
                 samples.push(Sample {
                     is_sync: sample.is_sync,
                     sample_idx,
-                    frame_nr: 0, // filled in after the loop
+                    frame_nr: 0, // filled in by `from_samples`
                     decode_timestamp,
                     presentation_timestamp,
                     duration,
@@ -96,50 +84,9 @@ impl VideoData {
             );
         }
 
-        // Append the last GOP if there are any samples left:
-        if !samples.is_empty() {
-            let start = samples[gop_sample_start_index].decode_timestamp;
-            let sample_range = gop_sample_start_index as u32..samples.len() as u32;
-            gops.push(GroupOfPictures {
-                decode_start_time: start,
-                sample_range,
-            });
-        }
-
-        {
-            re_tracing::profile_scope!("Sanity-check samples");
-            let mut samples_are_in_decode_order = true;
-            for window in samples.windows(2) {
-                samples_are_in_decode_order &=
-                    window[0].decode_timestamp <= window[1].decode_timestamp;
-            }
-            if !samples_are_in_decode_order {
-                re_log::warn!(
-                    "Video samples are NOT in decode order. This implies either invalid video data or a bug in parsing the mp4."
-                );
-            }
-        }
-
-        {
-            re_tracing::profile_scope!("Calculate frame numbers");
-            let mut samples_sorted_by_pts = samples.iter_mut().collect::<Vec<_>>();
-            samples_sorted_by_pts.sort_by_key(|s| s.presentation_timestamp);
-            for (frame_nr, sample) in samples_sorted_by_pts.into_iter().enumerate() {
-                sample.frame_nr = frame_nr;
-            }
-        }
-
-        let samples_statistics = SamplesStatistics::new(&samples);
-
-        Ok(Self {
-            config,
-            timescale,
-            duration,
-            samples_statistics,
-            gops,
-            samples,
-            mp4_tracks,
-        })
+        Ok(Self::from_samples(
+            config, timescale, duration, samples, mp4_tracks,
+        ))
     }
 }
 
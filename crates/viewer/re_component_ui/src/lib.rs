@@ -37,8 +37,10 @@ use re_types::blueprint::components::{RootContainer, ViewMaximized};
 use re_types::components::{SeriesVisible, Timestamp};
 use re_types::{
     blueprint::components::{
-        BackgroundKind, Corner2D, Enabled, ForceDistance, ForceIterations, ForceStrength,
-        GridSpacing, LockRangeDuringZoom, MapProvider, NearClipPlane, ViewFit,
+        BackgroundKind, CaseSensitive, Corner2D, Enabled, ForceDistance, ForceIterations,
+        ForceStrength, GraphLayoutAlgorithm, GraphLayoutDirection, GridSpacing,
+        LockRangeDuringZoom, MapProvider, NearClipPlane, TextSearchMode, TileAttribution,
+        TileMaxZoom, TileSourcePath, TileUrlTemplate, TransformInterpolation, ViewFit,
     },
     components::{
         AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
@@ -91,8 +93,12 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<ForceIterations>(|ctx, ui, value| {
         edit_u64_range(ctx, ui, value, 1..=5)
     });
+    registry.add_singleline_edit_or_view::<TileMaxZoom>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 0..=22)
+    });
 
     // Bool components:
+    registry.add_singleline_edit_or_view::<CaseSensitive>(edit_bool);
     registry.add_singleline_edit_or_view::<Enabled>(edit_bool);
     registry.add_singleline_edit_or_view::<LockRangeDuringZoom>(edit_bool);
     registry.add_singleline_edit_or_view::<ShowLabels>(edit_bool);
@@ -107,6 +113,9 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_multiline_edit_or_view::<Text>(edit_multiline_string);
     registry.add_singleline_edit_or_view::<Name>(edit_singleline_string);
     registry.add_multiline_edit_or_view::<Name>(edit_multiline_string);
+    registry.add_singleline_edit_or_view::<TileAttribution>(edit_singleline_string);
+    registry.add_singleline_edit_or_view::<TileSourcePath>(edit_singleline_string);
+    registry.add_singleline_edit_or_view::<TileUrlTemplate>(edit_singleline_string);
 
     // Enums:
     // TODO(#6974): Enums editors trivial and always the same, provide them automatically!
@@ -114,6 +123,8 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<BackgroundKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Corner2D>(edit_view_enum);
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<GraphLayoutAlgorithm>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<GraphLayoutDirection>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
     registry.add_singleline_edit_or_view::<MapProvider>(
         edit_view_enum_with_variant_available::<
@@ -122,6 +133,8 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
         >,
     );
     registry.add_singleline_edit_or_view::<MagnificationFilter>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<TextSearchMode>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<TransformInterpolation>(edit_view_enum);
     registry.add_singleline_edit_or_view::<TransformRelation>(edit_view_enum);
     registry.add_singleline_edit_or_view::<ViewFit>(edit_view_enum);
 
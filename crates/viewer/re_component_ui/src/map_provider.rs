@@ -21,12 +21,22 @@ impl VariantAvailableProvider<MapProvider> for MapProviderVariantAvailable {
             }
         };
 
+        let local_tiles_available = if cfg!(target_arch = "wasm32") {
+            VariantAvailable::No {
+                reason_markdown: "Local tiles can't be read from the web viewer.".to_owned(),
+            }
+        } else {
+            VariantAvailable::Yes
+        };
+
         match variant {
-            MapProvider::OpenStreetMap => VariantAvailable::Yes,
+            MapProvider::OpenStreetMap | MapProvider::CustomUrl => VariantAvailable::Yes,
 
             MapProvider::MapboxStreets | MapProvider::MapboxDark | MapProvider::MapboxSatellite => {
                 map_box_available
             }
+
+            MapProvider::LocalTiles => local_tiles_available,
         }
     }
 }
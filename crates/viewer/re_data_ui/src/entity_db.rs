@@ -1,7 +1,7 @@
 use re_byte_size::SizeBytes as _;
-use re_chunk_store::ChunkStoreConfig;
+use re_chunk_store::{ChunkStoreConfig, RetentionPolicy, RetentionTimeWindow};
 use re_entity_db::EntityDb;
-use re_log_types::StoreKind;
+use re_log_types::{StoreKind, TimeType};
 use re_ui::UiExt as _;
 use re_viewer_context::{UiLayout, ViewerContext};
 
@@ -102,6 +102,7 @@ impl crate::DataUi for EntityDb {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    retention: _,
                 } = self.storage_engine().store().config();
 
                 ui.grid_left_hand_label("Compaction");
@@ -149,6 +150,63 @@ impl crate::DataUi for EntityDb {
                 ui.end_row();
             }
 
+            {
+                let engine = self.storage_engine();
+                let store = engine.store();
+                let RetentionPolicy {
+                    time_window,
+                    max_rows_per_entity,
+                    protected_entities,
+                } = &store.config().retention;
+
+                let mut rules = Vec::new();
+                if let Some(RetentionTimeWindow { timeline, window }) = time_window {
+                    let window = match store.time_column_type(timeline) {
+                        Some(TimeType::Sequence) | None => re_format::format_uint(*window),
+                        Some(TimeType::DurationNs | TimeType::TimestampNs) => {
+                            re_log_types::Duration::from_nanos(*window as _).to_string()
+                        }
+                    };
+                    rules.push(format!("last {window} of {timeline:?}"));
+                }
+                if let Some(max_rows_per_entity) = max_rows_per_entity {
+                    rules.push(format!(
+                        "{} rows per entity",
+                        re_format::format_uint(*max_rows_per_entity)
+                    ));
+                }
+                if !protected_entities.is_empty() {
+                    rules.push(format!(
+                        "never drop {}",
+                        protected_entities
+                            .iter()
+                            .map(|entity_path| entity_path.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+
+                if !rules.is_empty() {
+                    ui.grid_left_hand_label("Retention");
+                    ui.label(rules.join("; ")).on_hover_text(unindent::unindent(&format!(
+                        "\
+                        Data outside of this retention window is continuously dropped from the viewer, \
+                        regardless of the memory limit.
+
+                        To modify the current configuration, use the `--retention-*` CLI flags, or set \
+                        these environment variables before starting the viewer:
+                        * {}
+                        * {}
+                        * {}
+                        ",
+                        ChunkStoreConfig::ENV_RETENTION_TIME_WINDOW,
+                        ChunkStoreConfig::ENV_RETENTION_MAX_ROWS_PER_ENTITY,
+                        ChunkStoreConfig::ENV_RETENTION_PROTECTED_ENTITIES,
+                    )));
+                    ui.end_row();
+                }
+            }
+
             if let Some(data_source) = &self.data_source {
                 ui.grid_left_hand_label("Data source");
                 data_source_button_ui(ctx, ui, data_source);
@@ -127,6 +127,7 @@ Configure in the selection panel:
 
         let query_engine = QueryEngine {
             engine: ctx.recording().storage_engine_arc(),
+            cache_policy: Default::default(),
         };
 
         let view_contents = query
@@ -151,6 +152,8 @@ Configure in the selection panel:
             // not yet unsupported by the dataframe view
             filtered_index_values: None,
             using_index_values: None,
+            index_value_policy: Default::default(),
+            bucketing: None,
             include_semantically_empty_columns: false,
             include_indicator_columns: false,
             include_tombstone_columns: false,
@@ -0,0 +1,816 @@
+//! Deterministic layouts for hierarchical graphs, such as behavior trees or state machines.
+//!
+//! In contrast to the force-based simulation, these layouts only depend on the structure of the
+//! graph, so they don't change between frames. When nodes are added (or removed) over time, we
+//! start from the previous layout: existing nodes keep their relative order and, whenever
+//! possible, their position.
+
+use std::f32::consts::TAU;
+
+use egui::{Pos2, Vec2};
+use re_types::blueprint::components::GraphLayoutDirection;
+
+use crate::graph::NodeId;
+
+use super::{provider::layout_from_positions, Layout, LayoutRequest};
+
+/// Space between two neighboring nodes in the same layer.
+const NODE_GAP: f32 = 20.0;
+
+/// Space between two consecutive layers (or rings, in radial layouts).
+const LAYER_GAP: f32 = 60.0;
+
+/// Number of sweeps that are performed to reduce edge crossings in layered layouts.
+const NUM_CROSSING_SWEEPS: usize = 4;
+
+/// The deterministic layout algorithms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeterministicLayout {
+    Layered(GraphLayoutDirection),
+    Tree(GraphLayoutDirection),
+    Radial,
+    Circular,
+}
+
+pub struct DeterministicLayoutProvider {
+    pub request: LayoutRequest,
+    positions: Vec<Pos2>,
+}
+
+impl DeterministicLayoutProvider {
+    /// Computes the layout right away.
+    ///
+    /// `previous` should only be set if it was computed with the same algorithm.
+    pub fn new(
+        request: LayoutRequest,
+        algorithm: DeterministicLayout,
+        previous: Option<&Layout>,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let structure = Structure::new(&request);
+        let previous = previous.map(|layout| {
+            structure
+                .ids
+                .iter()
+                .map(|id| layout.get_node(id).map(|rect| rect.center()))
+                .collect::<Vec<_>>()
+        });
+        let previous = previous.as_deref();
+
+        let mut positions = match algorithm {
+            DeterministicLayout::Layered(direction) => {
+                layered(&structure, previous, Axes(direction))
+            }
+            DeterministicLayout::Tree(direction) => tree(&structure, previous, Axes(direction)),
+            DeterministicLayout::Radial => radial(&structure, previous),
+            DeterministicLayout::Circular => circular(&structure, previous),
+        };
+
+        // Positions that were provided by the user always take precedence.
+        for (pos, (_, template)) in positions.iter_mut().zip(request.all_nodes()) {
+            if let Some(fixed) = template.fixed_position {
+                *pos = fixed;
+            }
+        }
+
+        Self { request, positions }
+    }
+
+    pub fn layout(&self) -> Layout {
+        layout_from_positions(&self.request, self.positions.iter().copied())
+    }
+}
+
+/// The nodes and edges of all graphs in a [`LayoutRequest`], where nodes are referred to by their index.
+struct Structure {
+    ids: Vec<NodeId>,
+    sizes: Vec<Vec2>,
+
+    /// The targets of the outgoing edges of each node, without self-edges.
+    children: Vec<Vec<usize>>,
+}
+
+impl Structure {
+    fn new(request: &LayoutRequest) -> Self {
+        let (ids, sizes): (Vec<_>, Vec<_>) = request
+            .all_nodes()
+            .map(|(id, template)| (id, template.size))
+            .unzip();
+        let index: ahash::HashMap<_, _> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut children = vec![Vec::new(); ids.len()];
+        for (edge, _) in request.all_edges() {
+            if edge.is_self_edge() {
+                continue;
+            }
+            if let (Some(&source), Some(&target)) =
+                (index.get(&edge.source), index.get(&edge.target))
+            {
+                children[source].push(target);
+            }
+        }
+
+        Self {
+            ids,
+            sizes,
+            children,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// The extent of the largest node in any direction.
+    fn max_node_extent(&self) -> f32 {
+        self.sizes
+            .iter()
+            .map(|size| size.max_elem())
+            .fold(0.0, f32::max)
+    }
+
+    /// Performs a depth-first traversal, starting at the nodes without incoming edges.
+    fn traverse(&self) -> Traversal {
+        let n = self.len();
+
+        let mut has_parent = vec![false; n];
+        for &child in self.children.iter().flatten() {
+            has_parent[child] = true;
+        }
+
+        // Graphs that consist of cycles only don't have any natural root, so we
+        // fall back to the first node that hasn't been visited yet.
+        let starts = (0..n)
+            .filter(|&node| !has_parent[node])
+            .chain(0..n)
+            .collect::<Vec<_>>();
+
+        #[derive(Clone, Copy, PartialEq)]
+        enum Visit {
+            New,
+            InProgress,
+            Done,
+        }
+
+        let mut visits = vec![Visit::New; n];
+        let mut traversal = Traversal {
+            preorder: Vec::with_capacity(n),
+            roots: Vec::new(),
+            tree_children: vec![Vec::new(); n],
+            dag_children: vec![Vec::new(); n],
+        };
+
+        for start in starts {
+            if visits[start] != Visit::New {
+                continue;
+            }
+
+            traversal.roots.push(start);
+            traversal.preorder.push(start);
+            visits[start] = Visit::InProgress;
+
+            // Holds the nodes of the current path, and the index of the next child to visit.
+            let mut stack = vec![(start, 0)];
+            while let Some((node, next_child)) = stack.last_mut() {
+                let node = *node;
+                let Some(&child) = self.children[node].get(*next_child) else {
+                    visits[node] = Visit::Done;
+                    stack.pop();
+                    continue;
+                };
+                *next_child += 1;
+
+                let visit = visits[child];
+                match visit {
+                    // Edges back into the current path would close a cycle.
+                    Visit::InProgress => {}
+                    Visit::Done => traversal.dag_children[node].push(child),
+                    Visit::New => {
+                        traversal.dag_children[node].push(child);
+                        traversal.tree_children[node].push(child);
+                        traversal.preorder.push(child);
+                        visits[child] = Visit::InProgress;
+                        stack.push((child, 0));
+                    }
+                }
+            }
+        }
+
+        traversal
+    }
+}
+
+struct Traversal {
+    /// All nodes in the order in which they were visited.
+    preorder: Vec<usize>,
+
+    /// The nodes at which the traversal started.
+    roots: Vec<usize>,
+
+    /// The children of each node in the spanning forest of the traversal.
+    tree_children: Vec<Vec<usize>>,
+
+    /// The children of each node, without the edges that would close a cycle.
+    dag_children: Vec<Vec<usize>>,
+}
+
+impl Traversal {
+    /// The depth of each node in the spanning forest.
+    fn depths(&self) -> Vec<usize> {
+        let mut depths = vec![0; self.preorder.len()];
+        for &node in &self.preorder {
+            for &child in &self.tree_children[node] {
+                depths[child] = depths[node] + 1;
+            }
+        }
+        depths
+    }
+}
+
+/// Maps the layout axes of hierarchical layouts to scene coordinates.
+///
+/// The main axis points along the edges, the cross axis runs along a layer.
+#[derive(Clone, Copy)]
+struct Axes(GraphLayoutDirection);
+
+impl Axes {
+    fn is_vertical(self) -> bool {
+        matches!(
+            self.0,
+            GraphLayoutDirection::TopToBottom | GraphLayoutDirection::BottomToTop
+        )
+    }
+
+    fn pos(self, main: f32, cross: f32) -> Pos2 {
+        match self.0 {
+            GraphLayoutDirection::TopToBottom => Pos2::new(cross, main),
+            GraphLayoutDirection::BottomToTop => Pos2::new(cross, -main),
+            GraphLayoutDirection::LeftToRight => Pos2::new(main, cross),
+            GraphLayoutDirection::RightToLeft => Pos2::new(-main, cross),
+        }
+    }
+
+    fn cross(self, pos: Pos2) -> f32 {
+        if self.is_vertical() {
+            pos.x
+        } else {
+            pos.y
+        }
+    }
+
+    fn main_extent(self, size: Vec2) -> f32 {
+        if self.is_vertical() {
+            size.y
+        } else {
+            size.x
+        }
+    }
+
+    fn cross_extent(self, size: Vec2) -> f32 {
+        if self.is_vertical() {
+            size.x
+        } else {
+            size.y
+        }
+    }
+}
+
+/// A Sugiyama-style layout: nodes are assigned to layers such that all edges (except the ones
+/// that close cycles) point to a later layer. Within a layer, nodes are ordered to reduce the
+/// number of edge crossings, and placed close to the nodes they are connected to.
+///
+/// Edges that span multiple layers are drawn as straight lines, so they can overlap with nodes.
+fn layered(structure: &Structure, previous: Option<&[Option<Pos2>]>, axes: Axes) -> Vec<Pos2> {
+    let n = structure.len();
+    let traversal = structure.traverse();
+    let children = &traversal.dag_children;
+
+    let mut parents = vec![Vec::new(); n];
+    for (node, node_children) in children.iter().enumerate() {
+        for &child in node_children {
+            parents[child].push(node);
+        }
+    }
+
+    // Assign layers via longest path, traversing the nodes in topological order.
+    let mut layer_of = vec![0; n];
+    let mut num_pending_parents = parents.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut ready = traversal
+        .preorder
+        .iter()
+        .copied()
+        .filter(|&node| parents[node].is_empty())
+        .collect::<std::collections::VecDeque<_>>();
+    while let Some(node) = ready.pop_front() {
+        for &child in &children[node] {
+            layer_of[child] = layer_of[child].max(layer_of[node] + 1);
+            num_pending_parents[child] -= 1;
+            if num_pending_parents[child] == 0 {
+                ready.push_back(child);
+            }
+        }
+    }
+
+    // Nodes without incoming edges move down to right above their closest child,
+    // so they don't pull long edges across the whole graph.
+    for node in 0..n {
+        if parents[node].is_empty() {
+            if let Some(min_child_layer) = children[node].iter().map(|&c| layer_of[c]).min() {
+                layer_of[node] = min_child_layer - 1;
+            }
+        }
+    }
+
+    let num_layers = layer_of.iter().max().map_or(0, |max| max + 1);
+    let mut layers = vec![Vec::new(); num_layers];
+    for &node in &traversal.preorder {
+        layers[layer_of[node]].push(node);
+    }
+
+    reduce_crossings(&mut layers, &parents, children);
+
+    if let Some(previous) = previous {
+        for layer in &mut layers {
+            sort_by_previous(layer, |node| previous[node].map(|pos| axes.cross(pos)));
+        }
+    }
+
+    let main = layer_coordinates(&layers, |node| axes.main_extent(structure.sizes[node]));
+
+    let mut cross = vec![0.0; n];
+    for layer in &layers {
+        // Existing nodes want to stay where they are, new nodes want to be close to their parents.
+        let desired = layer
+            .iter()
+            .map(|&node| {
+                previous
+                    .and_then(|previous| previous[node])
+                    .map(|pos| axes.cross(pos))
+                    .or_else(|| mean(parents[node].iter().map(|&parent| cross[parent])))
+            })
+            .collect();
+        let extents = layer
+            .iter()
+            .map(|&node| axes.cross_extent(structure.sizes[node]))
+            .collect::<Vec<_>>();
+
+        for (&node, pos) in layer.iter().zip(place_in_order(desired, &extents)) {
+            cross[node] = pos;
+        }
+    }
+
+    (0..n)
+        .map(|node| axes.pos(main[layer_of[node]], cross[node]))
+        .collect()
+}
+
+/// Reorders the nodes within each layer, by moving them towards the average position of their neighbors.
+fn reduce_crossings(layers: &mut [Vec<usize>], parents: &[Vec<usize>], children: &[Vec<usize>]) {
+    let mut rank = vec![0.0; parents.len()];
+    for layer in layers.iter() {
+        update_ranks(&mut rank, layer);
+    }
+
+    let sort_layer = |layer: &mut Vec<usize>, neighbors: &[Vec<usize>], rank: &mut [f32]| {
+        let mut keyed = layer
+            .iter()
+            .map(|&node| {
+                let key = mean(neighbors[node].iter().map(|&other| rank[other]));
+                (key.unwrap_or(rank[node]), node)
+            })
+            .collect::<Vec<_>>();
+        // Sorting is stable, so nodes with equal keys keep their relative order.
+        keyed.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        *layer = keyed.into_iter().map(|(_, node)| node).collect();
+        update_ranks(rank, layer);
+    };
+
+    for _ in 0..NUM_CROSSING_SWEEPS {
+        for layer in layers.iter_mut().skip(1) {
+            sort_layer(layer, parents, &mut rank);
+        }
+        for layer in layers.iter_mut().rev().skip(1) {
+            sort_layer(layer, children, &mut rank);
+        }
+    }
+}
+
+fn update_ranks(rank: &mut [f32], layer: &[usize]) {
+    for (i, &node) in layer.iter().enumerate() {
+        rank[node] = i as f32;
+    }
+}
+
+/// A tidy tree layout of the spanning forest of the graph: each node is centered above its children.
+fn tree(structure: &Structure, previous: Option<&[Option<Pos2>]>, axes: Axes) -> Vec<Pos2> {
+    let n = structure.len();
+    let mut traversal = structure.traverse();
+    if let Some(previous) = previous {
+        let cross = |node: usize| previous[node].map(|pos| axes.cross(pos));
+        sort_by_previous(&mut traversal.roots, cross);
+        for children in &mut traversal.tree_children {
+            sort_by_previous(children, cross);
+        }
+    }
+
+    let extent = |node: usize| axes.cross_extent(structure.sizes[node]);
+
+    // The space that each subtree occupies along the cross axis.
+    let mut widths = vec![0.0; n];
+    for &node in traversal.preorder.iter().rev() {
+        let children = &traversal.tree_children[node];
+        let children_width = children.iter().map(|&child| widths[child]).sum::<f32>()
+            + NODE_GAP * children.len().saturating_sub(1) as f32;
+        widths[node] = extent(node).max(children_width);
+    }
+
+    let mut cross = vec![0.0; n];
+    let total_width = traversal
+        .roots
+        .iter()
+        .map(|&root| widths[root])
+        .sum::<f32>()
+        + NODE_GAP * traversal.roots.len().saturating_sub(1) as f32;
+    let mut cursor = -total_width / 2.0;
+    for &root in &traversal.roots {
+        cross[root] = cursor + widths[root] / 2.0;
+        cursor += widths[root] + NODE_GAP;
+    }
+    for &node in &traversal.preorder {
+        let children = &traversal.tree_children[node];
+        let children_width = children.iter().map(|&child| widths[child]).sum::<f32>()
+            + NODE_GAP * children.len().saturating_sub(1) as f32;
+        let mut cursor = cross[node] - children_width / 2.0;
+        for &child in children {
+            cross[child] = cursor + widths[child] / 2.0;
+            cursor += widths[child] + NODE_GAP;
+        }
+    }
+
+    let depths = traversal.depths();
+    let num_layers = depths.iter().max().map_or(0, |max| max + 1);
+    let mut layers = vec![Vec::new(); num_layers];
+    for node in 0..n {
+        layers[depths[node]].push(node);
+    }
+    let main = layer_coordinates(&layers, |node| axes.main_extent(structure.sizes[node]));
+
+    (0..n)
+        .map(|node| axes.pos(main[depths[node]], cross[node]))
+        .collect()
+}
+
+/// Places the roots of the spanning forest in the center, and each level of the forest on a ring around it.
+///
+/// Each subtree gets an angular sector that is proportional to its number of leaves.
+fn radial(structure: &Structure, previous: Option<&[Option<Pos2>]>) -> Vec<Pos2> {
+    let n = structure.len();
+    let mut traversal = structure.traverse();
+    if let Some(previous) = previous {
+        let angle = |node: usize| previous[node].map(|pos| pos.to_vec2().angle().rem_euclid(TAU));
+        sort_by_previous(&mut traversal.roots, angle);
+        for children in &mut traversal.tree_children {
+            sort_by_previous(children, angle);
+        }
+    }
+
+    let mut leaves = vec![0.0; n];
+    for &node in traversal.preorder.iter().rev() {
+        let children = &traversal.tree_children[node];
+        leaves[node] = children
+            .iter()
+            .map(|&child| leaves[child])
+            .sum::<f32>()
+            .max(1.0);
+    }
+
+    // The start and the size of the sector of each node.
+    let mut sectors = vec![(0.0, 0.0); n];
+    let total_leaves = traversal
+        .roots
+        .iter()
+        .map(|&root| leaves[root])
+        .sum::<f32>();
+    let mut start = 0.0;
+    for &root in &traversal.roots {
+        let size = TAU * leaves[root] / total_leaves;
+        sectors[root] = (start, size);
+        start += size;
+    }
+    for &node in &traversal.preorder {
+        let (mut start, size) = sectors[node];
+        for &child in &traversal.tree_children[node] {
+            let child_size = size * leaves[child] / leaves[node];
+            sectors[child] = (start, child_size);
+            start += child_size;
+        }
+    }
+
+    // A single root sits in the center, multiple roots share the first ring.
+    let first_ring = usize::from(traversal.roots.len() > 1);
+    let rings = traversal
+        .depths()
+        .into_iter()
+        .map(|depth| depth + first_ring)
+        .collect::<Vec<_>>();
+
+    let num_rings = rings.iter().max().map_or(0, |max| max + 1);
+    let mut nodes_per_ring = vec![0; num_rings];
+    for &ring in &rings {
+        nodes_per_ring[ring] += 1;
+    }
+
+    // Rings are spaced evenly, but need to be large enough to fit all of their nodes.
+    let slot = structure.max_node_extent() + NODE_GAP;
+    let mut radii = vec![0.0; num_rings];
+    for ring in 1..num_rings {
+        radii[ring] = (radii[ring - 1] + structure.max_node_extent() + LAYER_GAP)
+            .max(nodes_per_ring[ring] as f32 * slot / TAU);
+    }
+
+    (0..n)
+        .map(|node| {
+            let (start, size) = sectors[node];
+            Pos2::ZERO + Vec2::angled(start + size / 2.0) * radii[rings[node]]
+        })
+        .collect()
+}
+
+/// Places all nodes on a circle, such that nodes that are connected are close to each other.
+fn circular(structure: &Structure, previous: Option<&[Option<Pos2>]>) -> Vec<Pos2> {
+    let n = structure.len();
+
+    // The circle starts at the top. We place nodes at the center of their slots, so that none
+    // of them sits right at the start, where its angle could wrap around.
+    let start = -TAU / 4.0;
+    let slot_angle = TAU / n as f32;
+
+    let mut order = structure.traverse().preorder;
+    if let Some(previous) = previous {
+        sort_by_previous(&mut order, |node| {
+            previous[node].map(|pos| (pos.to_vec2().angle() - start).rem_euclid(TAU))
+        });
+    }
+
+    let slot = structure.max_node_extent() + NODE_GAP;
+    let radius = (n as f32 * slot / TAU).max(slot);
+
+    let mut positions = vec![Pos2::ZERO; n];
+    for (i, &node) in order.iter().enumerate() {
+        let angle = start + (i as f32 + 0.5) * slot_angle;
+        positions[node] = Pos2::ZERO + Vec2::angled(angle) * radius;
+    }
+    positions
+}
+
+/// Stable sort of `nodes` by the key from a previous layout.
+///
+/// Nodes without a key (i.e. new nodes) stay right behind the node that they currently follow.
+fn sort_by_previous(nodes: &mut [usize], key: impl Fn(usize) -> Option<f32>) {
+    let mut last_key = f32::NEG_INFINITY;
+    let mut keyed = nodes
+        .iter()
+        .map(|&node| {
+            if let Some(key) = key(node) {
+                last_key = key;
+            }
+            (last_key, node)
+        })
+        .collect::<Vec<_>>();
+    keyed.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    for (slot, (_, node)) in nodes.iter_mut().zip(keyed) {
+        *slot = node;
+    }
+}
+
+/// Computes the main axis coordinate of each layer, such that layers with large nodes don't overlap.
+fn layer_coordinates(layers: &[Vec<usize>], main_extent: impl Fn(usize) -> f32) -> Vec<f32> {
+    let mut coordinates = Vec::with_capacity(layers.len());
+    let mut end_of_previous_layer: Option<f32> = None;
+    for layer in layers {
+        let half_extent = layer
+            .iter()
+            .map(|&node| main_extent(node) / 2.0)
+            .fold(0.0, f32::max);
+        let coordinate = end_of_previous_layer.map_or(0.0, |end| end + LAYER_GAP + half_extent);
+        coordinates.push(coordinate);
+        end_of_previous_layer = Some(coordinate + half_extent);
+    }
+    coordinates
+}
+
+/// Places the nodes of a layer along the cross axis, in the given order.
+///
+/// Each node is moved as close as possible to its desired position, without overlapping its neighbors.
+/// Nodes without a desired position are packed next to their neighbors.
+fn place_in_order(mut desired: Vec<Option<f32>>, extents: &[f32]) -> Vec<f32> {
+    let n = desired.len();
+    let separation = |i: usize| (extents[i] + extents[i + 1]) / 2.0 + NODE_GAP;
+
+    if desired.iter().all(Option::is_none) {
+        // Pack all nodes tightly, centered around the origin.
+        let mut positions = Vec::with_capacity(n);
+        for i in 0..n {
+            positions.push(if i == 0 {
+                0.0
+            } else {
+                positions[i - 1] + separation(i - 1)
+            });
+        }
+        let center = (positions.first().unwrap_or(&0.0) + positions.last().unwrap_or(&0.0)) / 2.0;
+        return positions.into_iter().map(|pos| pos - center).collect();
+    }
+
+    for i in 1..n {
+        if desired[i].is_none() {
+            desired[i] = desired[i - 1].map(|pos| pos + separation(i - 1));
+        }
+    }
+    for i in (0..n.saturating_sub(1)).rev() {
+        if desired[i].is_none() {
+            desired[i] = desired[i + 1].map(|pos| pos - separation(i));
+        }
+    }
+    let desired = desired.into_iter().flatten().collect::<Vec<_>>();
+
+    // Resolve overlaps once by pushing to the right, once by pushing to the left, and take the average.
+    // Both passes keep the order and enough distance, so their average does too.
+    let mut right = desired.clone();
+    for i in 1..n {
+        right[i] = right[i].max(right[i - 1] + separation(i - 1));
+    }
+    let mut left = desired;
+    for i in (0..n.saturating_sub(1)).rev() {
+        left[i] = left[i].min(left[i + 1] - separation(i));
+    }
+
+    left.into_iter()
+        .zip(right)
+        .map(|(left, right)| (left + right) / 2.0)
+        .collect()
+}
+
+fn mean(values: impl Iterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use re_chunk::EntityPath;
+    use re_types::components::GraphNode;
+
+    use crate::graph::EdgeId;
+    use crate::layout::{
+        request::{GraphTemplate, NodeTemplate},
+        EdgeTemplate,
+    };
+
+    use super::*;
+
+    fn node_id(node: &str) -> NodeId {
+        NodeId::from_entity_node(&EntityPath::from("graph"), &GraphNode::from(node))
+    }
+
+    fn request(nodes: &[&str], edges: &[(&str, &str)]) -> LayoutRequest {
+        let mut graph = GraphTemplate::default();
+        for node in nodes {
+            graph.nodes.insert(
+                node_id(node),
+                NodeTemplate {
+                    size: Vec2::new(40.0, 20.0),
+                    fixed_position: None,
+                },
+            );
+        }
+        for (source, target) in edges {
+            let (source, target) = (node_id(source), node_id(target));
+            graph
+                .edges
+                .entry(EdgeId { source, target })
+                .or_default()
+                .push(EdgeTemplate {
+                    source,
+                    target,
+                    target_arrow: true,
+                });
+        }
+
+        LayoutRequest {
+            graphs: BTreeMap::from([(EntityPath::from("graph"), graph)]),
+        }
+    }
+
+    fn center(layout: &Layout, node: &str) -> Pos2 {
+        layout.get_node(&node_id(node)).unwrap().center()
+    }
+
+    const ALL_ALGORITHMS: [DeterministicLayout; 4] = [
+        DeterministicLayout::Layered(GraphLayoutDirection::TopToBottom),
+        DeterministicLayout::Tree(GraphLayoutDirection::LeftToRight),
+        DeterministicLayout::Radial,
+        DeterministicLayout::Circular,
+    ];
+
+    #[test]
+    fn test_layered_layout() {
+        let request = request(
+            &["root", "a", "b", "c"],
+            &[("root", "a"), ("root", "b"), ("a", "c"), ("b", "c")],
+        );
+        let algorithm = DeterministicLayout::Layered(GraphLayoutDirection::TopToBottom);
+        let layout = DeterministicLayoutProvider::new(request, algorithm, None).layout();
+
+        let (root, a, b, c) = (
+            center(&layout, "root"),
+            center(&layout, "a"),
+            center(&layout, "b"),
+            center(&layout, "c"),
+        );
+        assert!(root.y < a.y && a.y == b.y && b.y < c.y);
+        assert!((a.x - b.x).abs() >= 40.0 + NODE_GAP);
+        assert_eq!(root.x, (a.x + b.x) / 2.0);
+        assert_eq!(c.x, root.x);
+    }
+
+    #[test]
+    fn test_layouts_are_deterministic() {
+        let nodes = ["a", "b", "c", "d", "e"];
+        let edges = [("a", "b"), ("b", "c"), ("c", "a"), ("a", "d"), ("d", "e")];
+
+        for algorithm in ALL_ALGORITHMS {
+            let first =
+                DeterministicLayoutProvider::new(request(&nodes, &edges), algorithm, None).layout();
+            let second =
+                DeterministicLayoutProvider::new(request(&nodes, &edges), algorithm, None).layout();
+
+            for node in nodes {
+                assert_eq!(center(&first, node), center(&second, node), "{algorithm:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_layout() {
+        let algorithm = DeterministicLayout::Layered(GraphLayoutDirection::TopToBottom);
+        let before = DeterministicLayoutProvider::new(
+            request(&["a", "b", "c"], &[("a", "b"), ("a", "c")]),
+            algorithm,
+            None,
+        )
+        .layout();
+        let after = DeterministicLayoutProvider::new(
+            request(&["a", "b", "c", "d"], &[("a", "b"), ("a", "c"), ("b", "d")]),
+            algorithm,
+            Some(&before),
+        )
+        .layout();
+
+        for node in ["a", "b", "c"] {
+            assert_eq!(center(&before, node), center(&after, node));
+        }
+        assert_eq!(center(&after, "d").x, center(&after, "b").x);
+
+        // Nodes that are added later on keep the relative order of the existing nodes.
+        for algorithm in ALL_ALGORITHMS {
+            let key = |layout: &Layout, node| {
+                let pos = center(layout, node);
+                match algorithm {
+                    DeterministicLayout::Layered(_) => pos.x,
+                    DeterministicLayout::Tree(_) => pos.y,
+                    DeterministicLayout::Radial | DeterministicLayout::Circular => {
+                        (pos.to_vec2().angle() + TAU / 4.0).rem_euclid(TAU)
+                    }
+                }
+            };
+            let order = |layout: &Layout| {
+                let mut children = vec!["b", "c", "d"];
+                children.sort_by(|x, y| key(layout, *x).total_cmp(&key(layout, *y)));
+                children
+            };
+
+            let edges = [("a", "b"), ("a", "c"), ("a", "d")];
+            let before = DeterministicLayoutProvider::new(
+                request(&["a", "b", "c", "d"], &edges),
+                algorithm,
+                None,
+            )
+            .layout();
+            let after = DeterministicLayoutProvider::new(
+                request(
+                    &["a", "b", "c", "d", "e"],
+                    &[("e", "a"), ("a", "b"), ("a", "c"), ("a", "d")],
+                ),
+                algorithm,
+                Some(&before),
+            )
+            .layout();
+
+            assert_eq!(order(&before), order(&after), "{algorithm:?}");
+        }
+    }
+}
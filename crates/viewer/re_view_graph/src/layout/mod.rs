@@ -1,3 +1,4 @@
+mod deterministic;
 mod geometry;
 mod params;
 mod provider;
@@ -6,7 +7,7 @@ mod result;
 mod slots;
 
 pub use geometry::{EdgeGeometry, PathGeometry};
-pub use params::ForceLayoutParams;
-pub use provider::ForceLayoutProvider;
+pub use params::LayoutParams;
+pub use provider::LayoutProvider;
 pub use request::{EdgeTemplate, LayoutRequest};
 pub use result::Layout;
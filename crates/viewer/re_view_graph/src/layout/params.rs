@@ -1,7 +1,11 @@
 use re_types::{
     blueprint::{
-        archetypes::{ForceCenter, ForceCollisionRadius, ForceLink, ForceManyBody, ForcePosition},
-        components::{Enabled, ForceDistance, ForceIterations, ForceStrength},
+        archetypes::{
+            ForceCenter, ForceCollisionRadius, ForceLink, ForceManyBody, ForcePosition, GraphLayout,
+        },
+        components::{
+            Enabled, ForceDistance, ForceIterations, ForceStrength, GraphLayoutAlgorithm,
+        },
     },
     components::Position2D,
     Archetype, Component,
@@ -9,6 +13,40 @@ use re_types::{
 use re_viewer_context::{ComponentFallbackProvider, ViewQuery, ViewState, ViewerContext};
 use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
 
+use super::deterministic::DeterministicLayout;
+
+/// The parameters of the layout algorithm that was selected via the [`GraphLayout`] view property.
+#[derive(Debug, PartialEq)]
+pub enum LayoutParams {
+    Force(ForceLayoutParams),
+    Deterministic(DeterministicLayout),
+}
+
+impl LayoutParams {
+    pub fn get(
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        provider: &dyn ComponentFallbackProvider,
+        view_state: &dyn ViewState,
+    ) -> Result<Self, ViewPropertyQueryError> {
+        let graph_layout = QueryArchetype::<GraphLayout>::new(ctx, query, provider, view_state);
+
+        Ok(match graph_layout.get()? {
+            GraphLayoutAlgorithm::ForceDirected => {
+                Self::Force(ForceLayoutParams::get(ctx, query, provider, view_state)?)
+            }
+            GraphLayoutAlgorithm::Layered => {
+                Self::Deterministic(DeterministicLayout::Layered(graph_layout.get()?))
+            }
+            GraphLayoutAlgorithm::Tree => {
+                Self::Deterministic(DeterministicLayout::Tree(graph_layout.get()?))
+            }
+            GraphLayoutAlgorithm::Radial => Self::Deterministic(DeterministicLayout::Radial),
+            GraphLayoutAlgorithm::Circular => Self::Deterministic(DeterministicLayout::Circular),
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ForceLayoutParams {
     // Link
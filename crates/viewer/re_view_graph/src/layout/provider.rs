@@ -1,9 +1,8 @@
 //! Performs the layout of the graph, i.e. converting an [`LayoutRequest`] into a [`Layout`].
 
-// For now we have only a single layout provider that is based on a force-directed model.
-// In the future, this could be expanded to support different (specialized layout alorithms).
-// Low-hanging fruit would be tree-based layouts. But we could also think about more complex
-// layouts, such as `dot` from `graphviz`.
+// The default layout provider is based on a force-directed model. For hierarchical graphs,
+// there are also deterministic layouts, see [`DeterministicLayoutProvider`].
+// In the future, we could also think about more complex layouts, such as `dot` from `graphviz`.
 
 use egui::{Pos2, Rect, Vec2};
 use fjadra::{self as fj, Simulation};
@@ -12,7 +11,8 @@ use re_log::error_once;
 use crate::graph::{EdgeId, NodeId};
 
 use super::{
-    params::ForceLayoutParams,
+    deterministic::DeterministicLayoutProvider,
+    params::{ForceLayoutParams, LayoutParams},
     request::NodeTemplate,
     slots::{slotted_edges, Slot, SlotKind},
     EdgeGeometry, EdgeTemplate, Layout, LayoutRequest, PathGeometry,
@@ -185,7 +185,7 @@ impl ForceLayoutProvider {
     fn layout(&self) -> Layout {
         // We make use of the fact here that the simulation is stable, i.e. the
         // order of the nodes is the same as in the `request`.
-        let mut positions = if let Some(simulation) = &self.simulation {
+        let positions = if let Some(simulation) = &self.simulation {
             itertools::Either::Left(
                 simulation
                     .positions()
@@ -201,42 +201,186 @@ impl ForceLayoutProvider {
             }))
         };
 
-        let mut layout = Layout::empty();
+        layout_from_positions(&self.request, positions)
+    }
+
+    /// Returns `true` if finished.
+    pub fn tick(&mut self) -> Layout {
+        if let Some(simulation) = self.simulation.as_mut() {
+            simulation.tick(1);
+        }
+
+        self.layout()
+    }
 
-        for (entity, graph) in &self.request.graphs {
-            let mut current_rect = Rect::NOTHING;
+    pub fn is_finished(&self) -> bool {
+        self.simulation.as_ref().is_none_or(|s| s.is_finished())
+    }
+}
+
+/// Computes the layout for the algorithm that was selected in the blueprint.
+pub enum LayoutProvider {
+    Force(ForceLayoutProvider),
+    Deterministic(DeterministicLayoutProvider),
+}
+
+impl LayoutProvider {
+    pub fn new(request: LayoutRequest, params: &LayoutParams) -> Self {
+        match params {
+            LayoutParams::Force(params) => Self::Force(ForceLayoutProvider::new(request, params)),
+            LayoutParams::Deterministic(algorithm) => {
+                Self::Deterministic(DeterministicLayoutProvider::new(request, *algorithm, None))
+            }
+        }
+    }
 
-            for (node, template) in &graph.nodes {
-                let pos = positions.next().unwrap_or_else(|| {
-                    debug_assert!(false, "not enough positions returned for layout request");
-                    error_once!("not enough positions returned for layout request");
-                    Pos2::ZERO
-                });
-                let extent = Rect::from_center_size(pos, template.size);
-                current_rect = current_rect.union(extent);
-                layout.nodes.insert(*node, extent);
+    /// Creates a provider that continues from the `layout` that was computed with `previous_params`.
+    pub fn new_with_previous(
+        request: LayoutRequest,
+        layout: &Layout,
+        previous_params: &LayoutParams,
+        params: &LayoutParams,
+    ) -> Self {
+        match params {
+            LayoutParams::Force(params) => Self::Force(ForceLayoutProvider::new_with_previous(
+                request, layout, params,
+            )),
+            LayoutParams::Deterministic(algorithm) => {
+                // Positions from other algorithms would only get in the way of a deterministic layout.
+                let previous = (previous_params == params).then_some(layout);
+                Self::Deterministic(DeterministicLayoutProvider::new(
+                    request, *algorithm, previous,
+                ))
             }
+        }
+    }
+
+    pub fn request(&self) -> &LayoutRequest {
+        match self {
+            Self::Force(provider) => &provider.request,
+            Self::Deterministic(provider) => &provider.request,
+        }
+    }
+
+    pub fn tick(&mut self) -> Layout {
+        match self {
+            Self::Force(provider) => provider.tick(),
+            Self::Deterministic(provider) => provider.layout(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match self {
+            Self::Force(provider) => provider.is_finished(),
+            Self::Deterministic(_) => true,
+        }
+    }
+}
+
+/// Assembles the final [`Layout`], given the positions of all nodes in the order of [`LayoutRequest::all_nodes`].
+pub(super) fn layout_from_positions(
+    request: &LayoutRequest,
+    mut positions: impl Iterator<Item = Pos2>,
+) -> Layout {
+    let mut layout = Layout::empty();
+
+    for (entity, graph) in &request.graphs {
+        let mut current_rect = Rect::NOTHING;
+
+        for (node, template) in &graph.nodes {
+            let pos = positions.next().unwrap_or_else(|| {
+                debug_assert!(false, "not enough positions returned for layout request");
+                error_once!("not enough positions returned for layout request");
+                Pos2::ZERO
+            });
+            let extent = Rect::from_center_size(pos, template.size);
+            current_rect = current_rect.union(extent);
+            layout.nodes.insert(*node, extent);
+        }
+
+        layout.entities.push((entity.clone(), current_rect));
+
+        // Multiple edges can occupy the same space in the layout.
+        for Slot { kind, edges } in
+            slotted_edges(graph.edges.values().flat_map(|ts| ts.iter())).values()
+        {
+            match kind {
+                SlotKind::SelfEdge { node } => {
+                    let rect = layout.nodes[node];
+                    let id = EdgeId::self_edge(*node);
+                    let geometries = layout.edges.entry(id).or_default();
+                    geometries.extend(layout_self_edges(rect, edges));
+                }
+                SlotKind::Regular {
+                    source: slot_source,
+                    target: slot_target,
+                } => {
+                    if let &[edge] = edges.as_slice() {
+                        // A single regular straight edge.
+                        let target_arrow = edge.target_arrow;
+                        let geometries = layout
+                            .edges
+                            .entry(EdgeId {
+                                source: edge.source,
+                                target: edge.target,
+                            })
+                            .or_default();
+
+                        let source = layout.nodes[&edge.source];
+                        let target = layout.nodes[&edge.target];
+
+                        // We only draw edges if they can be displayed meaningfully.
+                        if source.center() != target.center() && !source.intersects(target) {
+                            geometries.push(EdgeGeometry {
+                                target_arrow,
+                                path: line_segment(source, target),
+                            });
+                        }
+                    } else {
+                        // Multiple edges occupy the same space, so we fan them out.
+                        let num_edges = edges.len();
+
+                        for (i, edge) in edges.iter().enumerate() {
+                            let source_rect = layout.nodes[slot_source];
+                            let target_rect = layout.nodes[slot_target];
+
+                            if source_rect.center() == target_rect.center()
+                                || source_rect.intersects(target_rect)
+                            {
+                                // There is no meaningful geometry to draw here.
+                                // Keep in mind that self-edges are handled separately above.
+                                continue;
+                            }
+
+                            let d = (target_rect.center() - source_rect.center()).normalized();
+
+                            let source_pos = source_rect.intersects_ray_from_center(d);
+                            let target_pos = target_rect.intersects_ray_from_center(-d);
+
+                            let delta = target_pos - source_pos;
+
+                            // Controls the amount of space (in scene coordinates) that a slot can occupy.
+                            let fan_amount = (delta.length() * 0.3).min(40.);
+
+                            // How far along the edge should the control points be?
+                            let c1_base = source_pos + delta * 0.25;
+                            let c2_base = source_pos + delta * 0.75;
+
+                            let base_n = Vec2::new(-delta.y, delta.x).normalized();
+
+                            let c1_left = c1_base + base_n * (fan_amount / 2.);
+                            let c2_left = c2_base + base_n * (fan_amount / 2.);
+
+                            let c1_right = c1_base - base_n * (fan_amount / 2.);
+                            let c2_right = c2_base - base_n * (fan_amount / 2.);
+
+                            // Calculate an offset for the control points based on index `i`, spreading points equidistantly.
+                            let t = (i as f32) / (num_edges - 1) as f32;
+
+                            // Compute control points, `c1` and `c2`, based on the offset
+                            let c1 = c1_right + (c1_left - c1_right) * t;
+                            let c2 = c2_right + (c2_left - c2_right) * t;
 
-            layout.entities.push((entity.clone(), current_rect));
-
-            // Multiple edges can occupy the same space in the layout.
-            for Slot { kind, edges } in
-                slotted_edges(graph.edges.values().flat_map(|ts| ts.iter())).values()
-            {
-                match kind {
-                    SlotKind::SelfEdge { node } => {
-                        let rect = layout.nodes[node];
-                        let id = EdgeId::self_edge(*node);
-                        let geometries = layout.edges.entry(id).or_default();
-                        geometries.extend(layout_self_edges(rect, edges));
-                    }
-                    SlotKind::Regular {
-                        source: slot_source,
-                        target: slot_target,
-                    } => {
-                        if let &[edge] = edges.as_slice() {
-                            // A single regular straight edge.
-                            let target_arrow = edge.target_arrow;
                             let geometries = layout
                                 .edges
                                 .entry(EdgeId {
@@ -245,110 +389,33 @@ impl ForceLayoutProvider {
                                 })
                                 .or_default();
 
-                            let source = layout.nodes[&edge.source];
-                            let target = layout.nodes[&edge.target];
-
-                            // We only draw edges if they can be displayed meaningfully.
-                            if source.center() != target.center() && !source.intersects(target) {
-                                geometries.push(EdgeGeometry {
-                                    target_arrow,
-                                    path: line_segment(source, target),
-                                });
-                            }
-                        } else {
-                            // Multiple edges occupy the same space, so we fan them out.
-                            let num_edges = edges.len();
-
-                            for (i, edge) in edges.iter().enumerate() {
-                                let source_rect = layout.nodes[slot_source];
-                                let target_rect = layout.nodes[slot_target];
-
-                                if source_rect.center() == target_rect.center()
-                                    || source_rect.intersects(target_rect)
-                                {
-                                    // There is no meaningful geometry to draw here.
-                                    // Keep in mind that self-edges are handled separately above.
-                                    continue;
+                            // We potentially need to restore the direction of the edge, after we have used it's canonical form earlier.
+                            let path = if edge.source == *slot_source {
+                                PathGeometry::CubicBezier {
+                                    source: source_pos,
+                                    target: target_pos,
+                                    control: [c1, c2],
                                 }
+                            } else {
+                                PathGeometry::CubicBezier {
+                                    source: target_pos,
+                                    target: source_pos,
+                                    control: [c2, c1],
+                                }
+                            };
 
-                                let d = (target_rect.center() - source_rect.center()).normalized();
-
-                                let source_pos = source_rect.intersects_ray_from_center(d);
-                                let target_pos = target_rect.intersects_ray_from_center(-d);
-
-                                let delta = target_pos - source_pos;
-
-                                // Controls the amount of space (in scene coordinates) that a slot can occupy.
-                                let fan_amount = (delta.length() * 0.3).min(40.);
-
-                                // How far along the edge should the control points be?
-                                let c1_base = source_pos + delta * 0.25;
-                                let c2_base = source_pos + delta * 0.75;
-
-                                let base_n = Vec2::new(-delta.y, delta.x).normalized();
-
-                                let c1_left = c1_base + base_n * (fan_amount / 2.);
-                                let c2_left = c2_base + base_n * (fan_amount / 2.);
-
-                                let c1_right = c1_base - base_n * (fan_amount / 2.);
-                                let c2_right = c2_base - base_n * (fan_amount / 2.);
-
-                                // Calculate an offset for the control points based on index `i`, spreading points equidistantly.
-                                let t = (i as f32) / (num_edges - 1) as f32;
-
-                                // Compute control points, `c1` and `c2`, based on the offset
-                                let c1 = c1_right + (c1_left - c1_right) * t;
-                                let c2 = c2_right + (c2_left - c2_right) * t;
-
-                                let geometries = layout
-                                    .edges
-                                    .entry(EdgeId {
-                                        source: edge.source,
-                                        target: edge.target,
-                                    })
-                                    .or_default();
-
-                                // We potentially need to restore the direction of the edge, after we have used it's canonical form earlier.
-                                let path = if edge.source == *slot_source {
-                                    PathGeometry::CubicBezier {
-                                        source: source_pos,
-                                        target: target_pos,
-                                        control: [c1, c2],
-                                    }
-                                } else {
-                                    PathGeometry::CubicBezier {
-                                        source: target_pos,
-                                        target: source_pos,
-                                        control: [c2, c1],
-                                    }
-                                };
-
-                                geometries.push(EdgeGeometry {
-                                    target_arrow: edge.target_arrow,
-                                    path,
-                                });
-                            }
+                            geometries.push(EdgeGeometry {
+                                target_arrow: edge.target_arrow,
+                                path,
+                            });
                         }
                     }
                 }
             }
         }
-
-        layout
-    }
-
-    /// Returns `true` if finished.
-    pub fn tick(&mut self) -> Layout {
-        if let Some(simulation) = self.simulation.as_mut() {
-            simulation.tick(1);
-        }
-
-        self.layout()
     }
 
-    pub fn is_finished(&self) -> bool {
-        self.simulation.as_ref().is_none_or(|s| s.is_finished())
-    }
+    layout
 }
 
 /// Helper function to calculate the line segment between two rectangles.
@@ -4,7 +4,7 @@ use re_types::blueprint::components::VisualBounds2D;
 use re_ui::UiExt as _;
 use re_viewer_context::ViewState;
 
-use crate::layout::{ForceLayoutParams, ForceLayoutProvider, Layout, LayoutRequest};
+use crate::layout::{Layout, LayoutParams, LayoutProvider, LayoutRequest};
 
 /// View state for the custom view.
 ///
@@ -32,7 +32,13 @@ impl GraphViewState {
     }
 
     pub fn simulation_ui(&mut self, ui: &mut egui::Ui) {
-        if ui.button("Reset simulation").clicked() {
+        if ui
+            .button("Reset layout")
+            .on_hover_text(
+                "Computes the layout from scratch, without considering previous positions",
+            )
+            .clicked()
+        {
             self.layout_state.reset();
         }
     }
@@ -50,20 +56,20 @@ impl ViewState for GraphViewState {
 
 /// The following is a simple state machine that keeps track of the different
 /// layouts and if they need to be recomputed. It also holds the state of the
-/// force-based simulation.
+/// layout provider, e.g. the force-based simulation.
 #[derive(Default)]
 pub enum LayoutState {
     #[default]
     None,
     InProgress {
         layout: Layout,
-        provider: ForceLayoutProvider,
-        params: ForceLayoutParams,
+        provider: LayoutProvider,
+        params: LayoutParams,
     },
     Finished {
         layout: Layout,
-        provider: ForceLayoutProvider,
-        params: ForceLayoutParams,
+        provider: LayoutProvider,
+        params: LayoutParams,
     },
 }
 
@@ -86,19 +92,19 @@ impl LayoutState {
     }
 
     /// A simple state machine that keeps track of the different stages and if the layout needs to be recomputed.
-    fn update(self, new_request: LayoutRequest, new_params: ForceLayoutParams) -> Self {
+    fn update(self, new_request: LayoutRequest, new_params: LayoutParams) -> Self {
         match self {
             // Layout is up to date, nothing to do here.
             Self::Finished {
                 ref provider,
                 ref params,
                 ..
-            } if (provider.request == new_request) && (params == &new_params) => {
+            } if (provider.request() == &new_request) && (params == &new_params) => {
                 self // no op
             }
             // We need to recompute the layout.
             Self::None => {
-                let mut provider = ForceLayoutProvider::new(new_request, &new_params);
+                let mut provider = LayoutProvider::new(new_request, &new_params);
                 let layout = provider.tick();
                 Self::InProgress {
                     layout,
@@ -106,9 +112,9 @@ impl LayoutState {
                     params: new_params,
                 }
             }
-            Self::Finished { layout, .. } => {
+            Self::Finished { layout, params, .. } => {
                 let mut provider =
-                    ForceLayoutProvider::new_with_previous(new_request, &layout, &new_params);
+                    LayoutProvider::new_with_previous(new_request, &layout, &params, &new_params);
                 let layout = provider.tick();
                 Self::InProgress {
                     layout,
@@ -117,10 +123,12 @@ impl LayoutState {
                 }
             }
             Self::InProgress {
-                layout, provider, ..
-            } if provider.request != new_request => {
+                layout,
+                provider,
+                params,
+            } if provider.request() != &new_request => {
                 let mut provider =
-                    ForceLayoutProvider::new_with_previous(new_request, &layout, &new_params);
+                    LayoutProvider::new_with_previous(new_request, &layout, &params, &new_params);
                 let layout = provider.tick();
 
                 Self::InProgress {
@@ -146,8 +154,12 @@ impl LayoutState {
                     params: new_params,
                 },
                 _ => {
-                    let mut provider =
-                        ForceLayoutProvider::new_with_previous(new_request, &layout, &new_params);
+                    let mut provider = LayoutProvider::new_with_previous(
+                        new_request,
+                        &layout,
+                        &old_params,
+                        &new_params,
+                    );
                     let layout = provider.tick();
 
                     Self::InProgress {
@@ -161,7 +173,7 @@ impl LayoutState {
     }
 
     /// This method is lazy. A new layout is only computed if the current timestamp requires it.
-    pub fn get(&mut self, request: LayoutRequest, params: ForceLayoutParams) -> &mut Layout {
+    pub fn get(&mut self, request: LayoutRequest, params: LayoutParams) -> &mut Layout {
         *self = std::mem::take(self).update(request, params);
 
         match self {
@@ -4,8 +4,9 @@ use re_types::{
         self,
         archetypes::{
             ForceCenter, ForceCollisionRadius, ForceLink, ForceManyBody, ForcePosition,
-            VisualBounds2D,
+            GraphLayout, VisualBounds2D,
         },
+        components::GraphLayoutAlgorithm,
     },
     ViewClassIdentifier,
 };
@@ -23,7 +24,7 @@ use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     graph::Graph,
-    layout::{ForceLayoutParams, LayoutRequest},
+    layout::{LayoutParams, LayoutRequest},
     ui::{draw_graph, view_property_force_ui, GraphViewState, LevelOfDetail},
     visualizers::{merge, EdgesVisualizer, NodeVisualizer},
 };
@@ -129,6 +130,13 @@ impl ViewClass for GraphView {
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<GraphViewState>()?;
 
+        let algorithm: GraphLayoutAlgorithm = ViewProperty::from_archetype::<GraphLayout>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        )
+        .component_or_fallback(ctx, self, state)?;
+
         ui.selection_grid("graph_view_settings_ui").show(ui, |ui| {
             state.layout_ui(ui);
             state.simulation_ui(ui);
@@ -136,11 +144,16 @@ impl ViewClass for GraphView {
 
         re_ui::list_item::list_item_scope(ui, "graph_selection_ui", |ui| {
             view_property_ui::<VisualBounds2D>(ctx, ui, view_id, self, state);
-            view_property_force_ui::<ForceLink>(ctx, ui, view_id, self, state);
-            view_property_force_ui::<ForceManyBody>(ctx, ui, view_id, self, state);
-            view_property_force_ui::<ForcePosition>(ctx, ui, view_id, self, state);
-            view_property_force_ui::<ForceCenter>(ctx, ui, view_id, self, state);
-            view_property_force_ui::<ForceCollisionRadius>(ctx, ui, view_id, self, state);
+            view_property_ui::<GraphLayout>(ctx, ui, view_id, self, state);
+
+            // The forces only affect the force-directed layout.
+            if algorithm == GraphLayoutAlgorithm::ForceDirected {
+                view_property_force_ui::<ForceLink>(ctx, ui, view_id, self, state);
+                view_property_force_ui::<ForceManyBody>(ctx, ui, view_id, self, state);
+                view_property_force_ui::<ForcePosition>(ctx, ui, view_id, self, state);
+                view_property_force_ui::<ForceCenter>(ctx, ui, view_id, self, state);
+                view_property_force_ui::<ForceCollisionRadius>(ctx, ui, view_id, self, state);
+            }
         });
 
         Ok(())
@@ -168,7 +181,7 @@ impl ViewClass for GraphView {
 
         let state = state.downcast_mut::<GraphViewState>()?;
 
-        let params = ForceLayoutParams::get(ctx, query, self, state)?;
+        let params = LayoutParams::get(ctx, query, self, state)?;
 
         let bounds_property = ViewProperty::from_archetype::<VisualBounds2D>(
             ctx.blueprint_db(),
@@ -4,6 +4,7 @@
 
 mod map_overlays;
 mod map_view;
+mod tile_sources;
 mod visualizers;
 
 pub use map_view::MapView;
@@ -1,12 +1,16 @@
-use walkers::sources::Attribution;
+use crate::tile_sources::MapAttribution;
 
 pub fn acknowledgement_overlay(
     ui: &mut egui::Ui,
     map_rect: &egui::Rect,
-    attribution: &Attribution,
+    attribution: &MapAttribution,
 ) {
     const HEIGHT: f32 = 15.0;
 
+    if attribution.text.is_empty() {
+        return;
+    }
+
     let rect = egui::Rect::from_min_size(
         map_rect.left_bottom() - egui::vec2(0.0, HEIGHT),
         egui::vec2(map_rect.width(), HEIGHT),
@@ -19,7 +23,11 @@ pub fn acknowledgement_overlay(
         .fill(ui.visuals().window_fill)
         .inner_margin(egui::Margin::same(2))
         .show(&mut ui, |ui| {
-            let text = egui::WidgetText::from(attribution.text).small();
-            ui.hyperlink_to(text, attribution.url);
+            let text = egui::WidgetText::from(attribution.text.as_str()).small();
+            if let Some(url) = &attribution.url {
+                ui.hyperlink_to(text, url);
+            } else {
+                ui.label(text);
+            }
         });
 }
@@ -1,6 +1,6 @@
 use egui::{Context, Modifiers, NumExt as _, Rect, Response};
 use re_view::AnnotationSceneContext;
-use walkers::{HttpTiles, Map, MapMemory, Tiles};
+use walkers::{HttpTiles, Map, MapMemory, Tiles as _};
 
 use re_data_ui::{item_ui, DataUi as _};
 use re_entity_db::InstancePathHash;
@@ -9,27 +9,47 @@ use re_renderer::{RenderContext, ViewBuilder};
 use re_types::{
     blueprint::{
         archetypes::{MapBackground, MapZoom},
-        components::MapProvider,
-        components::ZoomLevel,
+        components::{
+            MapProvider, TileAttribution, TileMaxZoom, TileSourcePath, TileUrlTemplate, ZoomLevel,
+        },
     },
     View as _, ViewClassIdentifier,
 };
 use re_ui::{icon_text, icons, list_item, shortcut_with_icon, Help};
 use re_viewer_context::{
-    gpu_bridge, IdentifiedViewSystem as _, Item, SystemExecutionOutput, UiLayout, ViewClass,
-    ViewClassLayoutPriority, ViewClassRegistryError, ViewHighlights, ViewId, ViewQuery,
-    ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
-    ViewSystemRegistrator, ViewerContext,
+    gpu_bridge, IdentifiedViewSystem as _, Item, QueryContext, SystemExecutionOutput,
+    TypedComponentFallbackProvider, UiLayout, ViewClass, ViewClassLayoutPriority,
+    ViewClassRegistryError, ViewHighlights, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState,
+    ViewStateExt as _, ViewSystemExecutionError, ViewSystemRegistrator, ViewerContext,
 };
 use re_viewport_blueprint::ViewProperty;
 
 use crate::map_overlays;
+use crate::tile_sources::{self, MapAttribution, MapTiles, DEFAULT_MAX_ZOOM};
 use crate::visualizers::{update_span, GeoLineStringsVisualizer, GeoPointsVisualizer};
 
+/// Everything that determines which tiles are shown in the background of the map.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TileSourceSettings {
+    provider: MapProvider,
+
+    /// Used by [`MapProvider::CustomUrl`].
+    url_template: String,
+
+    /// Used by [`MapProvider::LocalTiles`].
+    local_path: String,
+
+    /// Overrides the attribution of [`MapProvider::CustomUrl`] and [`MapProvider::LocalTiles`].
+    attribution: Option<String>,
+
+    /// Overrides the max zoom of [`MapProvider::CustomUrl`] and [`MapProvider::LocalTiles`].
+    max_zoom: Option<u8>,
+}
+
 pub struct MapViewState {
-    tiles: Option<HttpTiles>,
+    tiles: Option<MapTiles>,
     map_memory: MapMemory,
-    selected_provider: MapProvider,
+    tile_source_settings: TileSourceSettings,
 
     last_center_position: walkers::Position,
 
@@ -43,7 +63,7 @@ impl Default for MapViewState {
         Self {
             tiles: None,
             map_memory: Default::default(),
-            selected_provider: Default::default(),
+            tile_source_settings: Default::default(),
 
             // default to Rerun HQ whenever we have no data (either now or historically) to provide
             // a better location
@@ -59,9 +79,9 @@ impl MapViewState {
         &mut self,
         ctx: &ViewerContext<'_>,
         egui_ctx: &egui::Context,
-    ) -> Result<(&mut HttpTiles, &mut MapMemory), ViewSystemExecutionError> {
+    ) -> Result<(&mut MapTiles, &mut MapMemory), ViewSystemExecutionError> {
         if self.tiles.is_none() {
-            let tiles = get_tile_manager(ctx, self.selected_provider, egui_ctx);
+            let tiles = get_tile_manager(ctx, &self.tile_source_settings, egui_ctx);
             self.tiles = Some(tiles);
         }
 
@@ -215,10 +235,26 @@ impl ViewClass for MapView {
         // Map Provider
         //
 
-        let map_provider = map_background.component_or_fallback::<MapProvider>(ctx, self, state)?;
-        if state.selected_provider != map_provider {
+        let tile_source_settings = TileSourceSettings {
+            provider: map_background.component_or_fallback::<MapProvider>(ctx, self, state)?,
+            url_template: map_background
+                .component_or_empty::<TileUrlTemplate>()?
+                .map(|template| template.as_str().trim().to_owned())
+                .unwrap_or_default(),
+            local_path: map_background
+                .component_or_empty::<TileSourcePath>()?
+                .map(|path| path.as_str().trim().to_owned())
+                .unwrap_or_default(),
+            attribution: map_background
+                .component_or_empty::<TileAttribution>()?
+                .map(|attribution| attribution.as_str().to_owned()),
+            max_zoom: map_background
+                .component_or_empty::<TileMaxZoom>()?
+                .map(|max_zoom| u8::try_from(**max_zoom).unwrap_or(u8::MAX)),
+        };
+        if state.tile_source_settings != tile_source_settings {
             state.tiles = None;
-            state.selected_provider = map_provider;
+            state.tile_source_settings = tile_source_settings;
         }
 
         //
@@ -270,11 +306,10 @@ impl ViewClass for MapView {
         //
 
         let (tiles, map_memory) = state.ensure_and_get_mut_refs(ctx, ui.ctx())?;
-        let attribution = tiles.attribution();
+        let attribution = tiles.attribution.clone();
 
-        let some_tiles_manager: Option<&mut dyn Tiles> = Some(tiles);
         let map_response = ui.add(Map::new(
-            some_tiles_manager,
+            tiles.tiles_mut(),
             map_memory,
             default_center_position,
         ));
@@ -518,14 +553,14 @@ fn http_options(_ctx: &ViewerContext<'_>) -> walkers::HttpOptions {
 
 fn get_tile_manager(
     ctx: &ViewerContext<'_>,
-    provider: MapProvider,
+    settings: &TileSourceSettings,
     egui_ctx: &Context,
-) -> HttpTiles {
+) -> MapTiles {
     let mapbox_access_token = ctx.app_options().mapbox_access_token().unwrap_or_default();
 
     let options = http_options(ctx);
 
-    match provider {
+    let tiles = match settings.provider {
         MapProvider::OpenStreetMap => {
             HttpTiles::with_options(walkers::sources::OpenStreetMap, options, egui_ctx.clone())
         }
@@ -556,10 +591,97 @@ fn get_tile_manager(
             options,
             egui_ctx.clone(),
         ),
+        MapProvider::CustomUrl => {
+            let attribution = custom_attribution(settings, None);
+            if settings.url_template.is_empty() {
+                return MapTiles::empty(attribution);
+            }
+
+            let max_zoom = settings.max_zoom.unwrap_or(DEFAULT_MAX_ZOOM);
+            let source = tile_sources::UrlTemplateSource {
+                template: settings.url_template.clone(),
+                max_zoom,
+            };
+            return MapTiles::new(
+                HttpTiles::with_options(source, options, egui_ctx.clone()),
+                attribution,
+                max_zoom,
+            );
+        }
+        MapProvider::LocalTiles => return get_local_tile_manager(settings, egui_ctx),
+    };
+
+    let attribution = tiles.attribution().into();
+    MapTiles::new(tiles, attribution, DEFAULT_MAX_ZOOM)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_local_tile_manager(settings: &TileSourceSettings, egui_ctx: &Context) -> MapTiles {
+    if settings.local_path.is_empty() {
+        return MapTiles::empty(custom_attribution(settings, None));
+    }
+
+    match tile_sources::LocalTiles::open(
+        std::path::Path::new(&settings.local_path),
+        egui_ctx.clone(),
+    ) {
+        Ok(mut tiles) => {
+            if let Some(max_zoom) = settings.max_zoom {
+                tiles.max_zoom = max_zoom;
+            }
+            let attribution = custom_attribution(settings, tiles.attribution.clone());
+            let max_zoom = tiles.max_zoom;
+            MapTiles::new(tiles, attribution, max_zoom)
+        }
+        Err(err) => {
+            re_log::warn!(
+                "Failed to open local map tiles at {:?}: {err}",
+                settings.local_path
+            );
+            MapTiles::empty(custom_attribution(settings, None))
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_local_tile_manager(settings: &TileSourceSettings, _egui_ctx: &Context) -> MapTiles {
+    re_log::warn_once!("Local map tiles are not available on the web.");
+    MapTiles::empty(custom_attribution(settings, None))
+}
+
+/// The attribution of a custom or local tile source, preferring the one set in the blueprint.
+fn custom_attribution(settings: &TileSourceSettings, default: Option<String>) -> MapAttribution {
+    MapAttribution {
+        text: settings.attribution.clone().or(default).unwrap_or_default(),
+        url: None,
+    }
+}
+
+impl TypedComponentFallbackProvider<TileAttribution> for MapView {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> TileAttribution {
+        ctx.view_state
+            .downcast_ref::<MapViewState>()
+            .ok()
+            .and_then(|state| state.tiles.as_ref())
+            .map(|tiles| tiles.attribution.text.clone())
+            .unwrap_or_default()
+            .into()
+    }
+}
+
+impl TypedComponentFallbackProvider<TileMaxZoom> for MapView {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> TileMaxZoom {
+        let max_zoom = ctx
+            .view_state
+            .downcast_ref::<MapViewState>()
+            .ok()
+            .and_then(|state| state.tiles.as_ref())
+            .map_or(DEFAULT_MAX_ZOOM, |tiles| tiles.max_zoom);
+        u64::from(max_zoom).into()
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(MapView => []);
+re_viewer_context::impl_component_fallback_provider!(MapView => [TileAttribution, TileMaxZoom]);
 
 // TODO(ab, andreas): this is a partial copy past of re_view_spatial::picking_gpu. Should be
 // turned into a utility function.
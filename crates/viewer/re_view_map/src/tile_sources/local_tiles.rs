@@ -0,0 +1,424 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use rusqlite::OptionalExtension as _;
+use walkers::{sources::Attribution, Texture, TextureWithUv, TileId, Tiles};
+
+use super::{magnified_tile, tms_row};
+
+/// Maximum number of tiles that are kept in memory.
+const MAX_CACHED_TILES: usize = 512;
+
+/// Maximum number of tiles waiting to be loaded.
+///
+/// Tiles that are no longer needed by the time they'd be loaded are simply not requested again,
+/// so keeping this low avoids loading tiles that were only visible while panning past them.
+const MAX_PENDING_TILES: usize = 8;
+
+/// Image formats that are looked up in tile directories, in order.
+const TILE_DIRECTORY_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+#[derive(thiserror::Error, Debug)]
+pub enum LocalTilesError {
+    #[error("Failed to open MBTiles file: {0}")]
+    MbTiles(#[from] rusqlite::Error),
+
+    #[error("Failed to read tile directory: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("MBTiles file contains {0:?} tiles, only raster tiles are supported")]
+    UnsupportedFormat(String),
+
+    #[error("No tiles found in {0:?}, expected a `{{z}}/{{x}}/{{y}}.png` layout")]
+    NoTiles(PathBuf),
+}
+
+enum TileStore {
+    /// An `MBTiles` file, i.e. an `SQLite` database with one row per tile.
+    ///
+    /// See <https://github.com/mapbox/mbtiles-spec>.
+    MbTiles(rusqlite::Connection),
+
+    /// A directory laid out as `{z}/{x}/{y}.png`.
+    Directory(PathBuf),
+}
+
+impl TileStore {
+    fn read_tile(&mut self, tile_id: TileId) -> Option<Vec<u8>> {
+        match self {
+            Self::MbTiles(connection) => connection
+                .query_row(
+                    "SELECT tile_data FROM tiles \
+                        WHERE zoom_level = ?1 AND tile_column = ?2 AND tile_row = ?3",
+                    (tile_id.zoom, tile_id.x, tms_row(tile_id) as i64),
+                    |row| row.get(0),
+                )
+                .optional()
+                .unwrap_or_else(|err| {
+                    re_log::warn_once!("Failed to read tile from MBTiles file: {err}");
+                    None
+                }),
+
+            Self::Directory(root) => {
+                let tile_dir = root
+                    .join(tile_id.zoom.to_string())
+                    .join(tile_id.x.to_string());
+                TILE_DIRECTORY_EXTENSIONS.iter().find_map(|extension| {
+                    std::fs::read(tile_dir.join(format!("{}.{extension}", tile_id.y))).ok()
+                })
+            }
+        }
+    }
+
+    fn load(&mut self, tile_id: TileId, egui_ctx: &egui::Context) -> Option<Texture> {
+        let bytes = self.read_tile(tile_id)?;
+        match Texture::new(&bytes, egui_ctx) {
+            Ok(texture) => Some(texture),
+            Err(err) => {
+                re_log::warn_once!("Failed to decode local map tile: {err}");
+                None
+            }
+        }
+    }
+}
+
+/// The most recently used tiles.
+///
+/// `None` for tiles that are missing or couldn't be decoded.
+#[derive(Default)]
+struct TileCache {
+    tiles: HashMap<TileId, Option<Texture>>,
+
+    /// Least recently used first.
+    order: VecDeque<TileId>,
+}
+
+impl TileCache {
+    /// Marks the tile as the most recently used one.
+    fn get(&mut self, tile_id: TileId) -> Option<&Option<Texture>> {
+        let texture = self.tiles.get(&tile_id)?;
+        if let Some(position) = self.order.iter().position(|id| *id == tile_id) {
+            self.order.remove(position);
+        }
+        self.order.push_back(tile_id);
+        Some(texture)
+    }
+
+    /// Evicts the least recently used tiles if there are more than [`MAX_CACHED_TILES`].
+    fn insert(&mut self, tile_id: TileId, texture: Option<Texture>) {
+        if self.tiles.insert(tile_id, texture).is_some() {
+            self.order.retain(|id| *id != tile_id);
+        }
+        self.order.push_back(tile_id);
+
+        while self.order.len() > MAX_CACHED_TILES {
+            if let Some(evicted) = self.order.pop_front() {
+                self.tiles.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// Raster tiles read from an `MBTiles` file or a tile directory on the local file system.
+///
+/// Just like with [`walkers::HttpTiles`], tiles are loaded in the background: the file system is
+/// only ever accessed from a dedicated thread, which exits once this is dropped.
+pub struct LocalTiles {
+    egui_ctx: egui::Context,
+    request_tx: crossbeam::channel::Sender<TileId>,
+    loaded_rx: crossbeam::channel::Receiver<(TileId, Option<Texture>)>,
+
+    /// The attribution stored in the `MBTiles` metadata, if any.
+    pub attribution: Option<String>,
+
+    /// The highest zoom level of the stored tiles.
+    pub max_zoom: u8,
+
+    cache: TileCache,
+
+    /// Tiles that were requested from the loader thread, but haven't arrived yet.
+    pending: HashSet<TileId>,
+}
+
+impl LocalTiles {
+    /// Opens an `MBTiles` file or a tile directory, depending on what `path` points to.
+    pub fn open(path: &Path, egui_ctx: egui::Context) -> Result<Self, LocalTilesError> {
+        let (store, attribution, max_zoom) = if path.is_dir() {
+            let max_zoom = directory_max_zoom(path)?;
+            (TileStore::Directory(path.to_owned()), None, max_zoom)
+        } else {
+            let connection = rusqlite::Connection::open_with_flags(
+                path,
+                rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY
+                    | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )?;
+
+            let format = mbtiles_metadata(&connection, "format")?;
+            if let Some(format) = format.filter(|format| format == "pbf") {
+                return Err(LocalTilesError::UnsupportedFormat(format));
+            }
+
+            let attribution = mbtiles_metadata(&connection, "attribution")?
+                .map(|attribution| strip_html_tags(&attribution))
+                .filter(|attribution| !attribution.is_empty());
+
+            let max_zoom = match mbtiles_metadata(&connection, "maxzoom")?
+                .and_then(|max_zoom| max_zoom.trim().parse().ok())
+            {
+                Some(max_zoom) => max_zoom,
+                None => connection
+                    .query_row("SELECT MAX(zoom_level) FROM tiles", (), |row| {
+                        row.get::<_, Option<u8>>(0)
+                    })?
+                    .ok_or_else(|| LocalTilesError::NoTiles(path.to_owned()))?,
+            };
+
+            (TileStore::MbTiles(connection), attribution, max_zoom)
+        };
+
+        let (request_tx, request_rx) = crossbeam::channel::bounded(MAX_PENDING_TILES);
+        let (loaded_tx, loaded_rx) = crossbeam::channel::unbounded();
+        std::thread::Builder::new()
+            .name("local_map_tiles".to_owned())
+            .spawn({
+                let mut store = store;
+                let egui_ctx = egui_ctx.clone();
+                move || {
+                    for tile_id in request_rx {
+                        let texture = store.load(tile_id, &egui_ctx);
+                        if loaded_tx.send((tile_id, texture)).is_err() {
+                            break;
+                        }
+                        egui_ctx.request_repaint();
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            egui_ctx,
+            request_tx,
+            loaded_rx,
+            attribution,
+            max_zoom,
+            cache: TileCache::default(),
+            pending: HashSet::default(),
+        })
+    }
+
+    /// Moves the tiles that finished loading into the cache.
+    fn receive_loaded_tiles(&mut self) {
+        for (tile_id, texture) in self.loaded_rx.try_iter() {
+            self.pending.remove(&tile_id);
+            self.cache.insert(tile_id, texture);
+        }
+    }
+
+    fn request(&mut self, tile_id: TileId) {
+        if self.pending.contains(&tile_id) {
+            return;
+        }
+
+        match self.request_tx.try_send(tile_id) {
+            Ok(()) => {
+                self.pending.insert(tile_id);
+            }
+            Err(crossbeam::channel::TrySendError::Full(_)) => {
+                // Ask again once the loader has caught up.
+                self.egui_ctx.request_repaint();
+            }
+            Err(crossbeam::channel::TrySendError::Disconnected(_)) => {
+                re_log::warn_once!("The local map tile loader stopped unexpectedly");
+            }
+        }
+    }
+}
+
+impl Tiles for LocalTiles {
+    fn at(&mut self, tile_id: TileId) -> Option<TextureWithUv> {
+        let (tile_id, uv) = magnified_tile(tile_id, self.max_zoom);
+
+        self.receive_loaded_tiles();
+
+        let texture = if let Some(texture) = self.cache.get(tile_id) {
+            texture.clone()
+        } else {
+            self.request(tile_id);
+            None
+        }?;
+        Some(TextureWithUv { texture, uv })
+    }
+
+    fn attribution(&self) -> Attribution {
+        // The attribution is shown from `MapTiles::attribution`.
+        Attribution {
+            text: "",
+            url: "",
+            logo_light: None,
+            logo_dark: None,
+        }
+    }
+
+    fn tile_size(&self) -> u32 {
+        256
+    }
+}
+
+fn mbtiles_metadata(
+    connection: &rusqlite::Connection,
+    name: &str,
+) -> Result<Option<String>, rusqlite::Error> {
+    connection
+        .query_row(
+            "SELECT value FROM metadata WHERE name = ?1",
+            [name],
+            |row| row.get(0),
+        )
+        .optional()
+}
+
+/// The highest zoom level of a `{z}/{x}/{y}.png` tile directory.
+fn directory_max_zoom(path: &Path) -> Result<u8, LocalTilesError> {
+    let mut max_zoom = None;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(zoom) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u8>().ok())
+        {
+            max_zoom = max_zoom.max(Some(zoom));
+        }
+    }
+    max_zoom.ok_or_else(|| LocalTilesError::NoTiles(path.to_owned()))
+}
+
+/// `MBTiles` attributions are often HTML snippets, but we only show plain text.
+fn strip_html_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&copy;", "©").trim().to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile_id(zoom: u8, x: u32, y: u32) -> TileId {
+        TileId { x, y, zoom }
+    }
+
+    #[test]
+    fn test_tile_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        for (zoom, x, y, extension) in [(0, 0, 0, "png"), (3, 2, 5, "jpg")] {
+            let tile_dir = dir.path().join(zoom.to_string()).join(x.to_string());
+            std::fs::create_dir_all(&tile_dir).unwrap();
+            std::fs::write(
+                tile_dir.join(format!("{y}.{extension}")),
+                format!("{zoom}/{x}/{y}"),
+            )
+            .unwrap();
+        }
+        std::fs::create_dir(dir.path().join("not_a_zoom_level")).unwrap();
+        std::fs::write(dir.path().join("7"), "not a directory").unwrap();
+
+        assert_eq!(directory_max_zoom(dir.path()).unwrap(), 3);
+
+        let mut store = TileStore::Directory(dir.path().to_owned());
+        assert_eq!(store.read_tile(tile_id(0, 0, 0)).unwrap(), b"0/0/0");
+        assert_eq!(store.read_tile(tile_id(3, 2, 5)).unwrap(), b"3/2/5");
+        assert_eq!(store.read_tile(tile_id(3, 5, 2)), None);
+
+        let tiles = LocalTiles::open(dir.path(), egui::Context::default()).unwrap();
+        assert_eq!(tiles.max_zoom, 3);
+        assert_eq!(tiles.attribution, None);
+
+        let empty_dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            LocalTiles::open(empty_dir.path(), egui::Context::default()),
+            Err(LocalTilesError::NoTiles(_))
+        ));
+    }
+
+    fn write_mbtiles(path: &Path, metadata: &[(&str, &str)], tiles: &[(u8, u32, u64, &[u8])]) {
+        let connection = rusqlite::Connection::open(path).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE metadata (name TEXT, value TEXT); \
+                 CREATE TABLE tiles (zoom_level INTEGER, tile_column INTEGER, tile_row INTEGER, tile_data BLOB);",
+            )
+            .unwrap();
+        for (name, value) in metadata {
+            connection
+                .execute("INSERT INTO metadata VALUES (?1, ?2)", (name, value))
+                .unwrap();
+        }
+        for (zoom, column, row, data) in tiles {
+            connection
+                .execute(
+                    "INSERT INTO tiles VALUES (?1, ?2, ?3, ?4)",
+                    (zoom, column, *row as i64, data),
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_mbtiles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tiles.mbtiles");
+
+        // Rows are stored bottom-up (TMS), unlike the XYZ tile ids.
+        write_mbtiles(
+            &path,
+            &[(
+                "attribution",
+                "<a href=\"https://example.com\">&copy; Example</a>",
+            )],
+            &[(2, 1, 3, b"top"), (2, 1, 0, b"bottom")],
+        );
+
+        let tiles = LocalTiles::open(&path, egui::Context::default()).unwrap();
+        assert_eq!(tiles.max_zoom, 2);
+        assert_eq!(tiles.attribution.as_deref(), Some("© Example"));
+
+        let mut store = TileStore::MbTiles(rusqlite::Connection::open(&path).unwrap());
+        assert_eq!(store.read_tile(tile_id(2, 1, 0)).unwrap(), b"top");
+        assert_eq!(store.read_tile(tile_id(2, 1, 3)).unwrap(), b"bottom");
+        assert_eq!(store.read_tile(tile_id(2, 0, 0)), None);
+
+        let vector_path = dir.path().join("vector.mbtiles");
+        write_mbtiles(&vector_path, &[("format", "pbf"), ("maxzoom", "14")], &[]);
+        assert!(matches!(
+            LocalTiles::open(&vector_path, egui::Context::default()),
+            Err(LocalTilesError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_tile_cache_is_lru() {
+        let mut cache = TileCache::default();
+        for x in 0..MAX_CACHED_TILES as u32 {
+            cache.insert(tile_id(10, x, 0), None);
+        }
+
+        // Still on screen, so it must outlive the tiles that weren't used since.
+        assert!(cache.get(tile_id(10, 0, 0)).is_some());
+
+        cache.insert(tile_id(10, 0, 1), None);
+        assert!(cache.get(tile_id(10, 0, 0)).is_some());
+        assert!(cache.get(tile_id(10, 1, 0)).is_none());
+        assert_eq!(cache.tiles.len(), MAX_CACHED_TILES);
+    }
+}
@@ -0,0 +1,195 @@
+//! Tile sources for the background map.
+//!
+//! Besides the built-in providers, the map view can fetch tiles from a custom tile server
+//! (see [`UrlTemplateSource`]) or read them from the local file system (see [`LocalTiles`]).
+
+#[cfg(not(target_arch = "wasm32"))]
+mod local_tiles;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use local_tiles::LocalTiles;
+
+use walkers::{sources::Attribution, TileId, Tiles};
+
+/// Highest zoom level assumed for tile sources that don't specify one.
+pub const DEFAULT_MAX_ZOOM: u8 = 19;
+
+/// Attribution shown on top of the map.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MapAttribution {
+    pub text: String,
+    pub url: Option<String>,
+}
+
+impl From<Attribution> for MapAttribution {
+    fn from(attribution: Attribution) -> Self {
+        Self {
+            text: attribution.text.to_owned(),
+            url: (!attribution.url.is_empty()).then(|| attribution.url.to_owned()),
+        }
+    }
+}
+
+/// The tile manager of a map view, along with its attribution and maximum zoom level.
+pub struct MapTiles {
+    /// `None` if the tile source isn't configured (yet), in which case no tiles are shown.
+    tiles: Option<Box<dyn Tiles + Send + Sync>>,
+    pub attribution: MapAttribution,
+    pub max_zoom: u8,
+}
+
+impl MapTiles {
+    pub fn new(
+        tiles: impl Tiles + Send + Sync + 'static,
+        attribution: MapAttribution,
+        max_zoom: u8,
+    ) -> Self {
+        Self {
+            tiles: Some(Box::new(tiles)),
+            attribution,
+            max_zoom,
+        }
+    }
+
+    /// A tile manager that doesn't show any tiles.
+    pub fn empty(attribution: MapAttribution) -> Self {
+        Self {
+            tiles: None,
+            attribution,
+            max_zoom: DEFAULT_MAX_ZOOM,
+        }
+    }
+
+    pub fn tiles_mut(&mut self) -> Option<&mut dyn Tiles> {
+        self.tiles
+            .as_mut()
+            .map(|tiles| tiles.as_mut() as &mut dyn Tiles)
+    }
+}
+
+/// A raster tile server, configured with a URL template.
+///
+/// See [`re_types::blueprint::components::TileUrlTemplate`] for the supported placeholders.
+pub struct UrlTemplateSource {
+    pub template: String,
+    pub max_zoom: u8,
+}
+
+impl walkers::sources::TileSource for UrlTemplateSource {
+    fn tile_url(&self, tile_id: TileId) -> String {
+        expand_url_template(&self.template, tile_id)
+    }
+
+    fn attribution(&self) -> Attribution {
+        // The attribution is configured by the user and shown from `MapTiles::attribution`.
+        Attribution {
+            text: "",
+            url: "",
+            logo_light: None,
+            logo_dark: None,
+        }
+    }
+
+    fn max_zoom(&self) -> u8 {
+        self.max_zoom
+    }
+}
+
+/// Replaces the `{z}`, `{x}`, `{y}` and `{-y}` placeholders of a tile URL template.
+pub fn expand_url_template(template: &str, tile_id: TileId) -> String {
+    template
+        .replace("{z}", &tile_id.zoom.to_string())
+        .replace("{x}", &tile_id.x.to_string())
+        .replace("{y}", &tile_id.y.to_string())
+        .replace("{-y}", &tms_row(tile_id).to_string())
+}
+
+/// The row of a tile in the TMS tiling scheme, which counts the rows from the south.
+pub fn tms_row(tile_id: TileId) -> u64 {
+    ((1_u64 << tile_id.zoom) - 1).saturating_sub(tile_id.y as u64)
+}
+
+/// The tile that covers `tile_id` at a zoom level of at most `max_zoom`, along with the part of it
+/// that corresponds to `tile_id`.
+///
+/// Used to magnify tiles beyond the highest zoom level of a tile source.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn magnified_tile(tile_id: TileId, max_zoom: u8) -> (TileId, egui::Rect) {
+    if tile_id.zoom <= max_zoom {
+        return (
+            tile_id,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+        );
+    }
+
+    let zoom_delta = tile_id.zoom - max_zoom;
+    let parent = TileId {
+        x: tile_id.x >> zoom_delta,
+        y: tile_id.y >> zoom_delta,
+        zoom: max_zoom,
+    };
+
+    let num_children = 1_u64 << zoom_delta;
+    let offset = |coordinate: u32| (coordinate as u64 % num_children) as f32;
+    let scale = num_children as f32;
+    let uv = egui::Rect::from_min_size(
+        egui::pos2(offset(tile_id.x) / scale, offset(tile_id.y) / scale),
+        egui::Vec2::splat(1.0 / scale),
+    );
+
+    (parent, uv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_url_template() {
+        let tile_id = TileId {
+            x: 3,
+            y: 1,
+            zoom: 2,
+        };
+
+        assert_eq!(
+            expand_url_template("https://tiles.example.com/{z}/{x}/{y}.png", tile_id),
+            "https://tiles.example.com/2/3/1.png"
+        );
+        assert_eq!(
+            expand_url_template("https://tiles.example.com/{z}/{x}/{-y}.png", tile_id),
+            "https://tiles.example.com/2/3/2.png"
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_magnified_tile() {
+        let tile_id = TileId {
+            x: 5,
+            y: 2,
+            zoom: 4,
+        };
+
+        let (same, uv) = magnified_tile(tile_id, 4);
+        assert_eq!(same, tile_id);
+        assert_eq!(
+            uv,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0))
+        );
+
+        let (parent, uv) = magnified_tile(tile_id, 2);
+        assert_eq!(
+            parent,
+            TileId {
+                x: 1,
+                y: 0,
+                zoom: 2,
+            }
+        );
+        assert_eq!(
+            uv,
+            egui::Rect::from_min_size(egui::pos2(0.25, 0.5), egui::Vec2::splat(0.25))
+        );
+    }
+}
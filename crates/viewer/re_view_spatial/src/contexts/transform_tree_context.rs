@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+
+use ahash::HashMap;
 use nohash_hasher::IntMap;
 
 use re_chunk_store::LatestAtQuery;
@@ -5,7 +8,8 @@ use re_entity_db::{EntityPath, EntityTree};
 use re_log_types::EntityPathHash;
 use re_types::{
     archetypes::{InstancePoses3D, Transform3D},
-    components::{ImagePlaneDistance, PinholeProjection},
+    blueprint::components::TransformInterpolation,
+    components::{CoordinateFrame, ImagePlaneDistance, PinholeProjection},
     Archetype as _, Component as _, ComponentNameSet,
 };
 use re_view::DataResultQuery as _;
@@ -111,7 +115,10 @@ impl TransformInfo {
 /// The resulting transforms are dependent on:
 /// * tree, pose, pinhole and view-coordinates transforms components as logged to the data store
 ///    * TODO(#6743): blueprint overrides aren't respected yet
+///    * except for [`TransformInterpolation`], which determines how tree & pose transforms are interpolated between samples
 /// * the view' spatial origin
+/// * named coordinate frames: entities that are in a named frame connected to the frame of the view's origin
+///   are placed via the transforms between frames rather than their entity path parent
 /// * the query time
 ///    * TODO(#723): ranges aren't taken into account yet
 /// * TODO(andreas): the queried entities. Right now we determine transforms for ALL entities in the scene.
@@ -131,6 +138,11 @@ pub struct TransformTreeContext {
 
     /// All reachable entities.
     transform_per_entity: IntMap<EntityPathHash, TransformInfo>,
+
+    /// Transforms from all named coordinate frames that are connected to the origin's frame into the reference space.
+    ///
+    /// Empty if the origin isn't in a named frame.
+    reference_from_frames: HashMap<CoordinateFrame, glam::Affine3A>,
 }
 
 impl IdentifiedViewSystem for TransformTreeContext {
@@ -144,6 +156,7 @@ impl Default for TransformTreeContext {
         Self {
             space_origin: EntityPath::root(),
             transform_per_entity: Default::default(),
+            reference_from_frames: Default::default(),
         }
     }
 }
@@ -160,6 +173,7 @@ impl ViewContextSystem for TransformTreeContext {
                 .map(|descr| descr.component_name)
                 .collect(),
             std::iter::once(PinholeProjection::name()).collect(),
+            std::iter::once(CoordinateFrame::name()).collect(),
         ]
     }
 
@@ -203,6 +217,9 @@ impl ViewContextSystem for TransformTreeContext {
         TransformCacheStoreSubscriber::access(&ctx.recording().store_id(), |cache| {
             let transforms = cache.transforms_for_timeline(query.timeline);
 
+            self.reference_from_frames =
+                reference_from_frames(query.space_origin, &time_query, transforms);
+
             // Child transforms of this space
             {
                 re_tracing::profile_scope!("gather_descendants_transforms");
@@ -260,19 +277,43 @@ impl TransformTreeContext {
 
             // Note that the transform at the reference is the first that needs to be inverted to "break out" of its hierarchy.
             // Generally, the transform _at_ a node isn't relevant to it's children, but only to get to its parent in turn!
+            let lookup_interpolation =
+                |p: &_| lookup_transform_interpolation(ctx, data_result_tree, p, time_query);
+
             let transforms_at_entity = transforms_at(
                 &current_tree.path,
                 time_query,
                 // TODO(#1025): See comment in transform_at. This is a workaround for precision issues
                 // and the fact that there is no meaningful image plane distance for 3D->2D views.
                 |_| 500.0,
+                lookup_interpolation,
                 &mut None, // Don't care about pinhole encounters.
                 transforms,
             );
-            let new_transform = transform_info_for_upward_propagation(
-                reference_from_ancestor,
-                &transforms_at_entity,
-            );
+            let new_transform = if let Some(reference_from_frame) =
+                self.reference_from_entity_frame(&parent_path, time_query, transforms)
+            {
+                // Ancestors in a connected named frame are placed by their frame as well.
+                let transforms_at_parent = transforms_at(
+                    &parent_path,
+                    time_query,
+                    |_| 500.0,
+                    lookup_interpolation,
+                    &mut None,
+                    transforms,
+                );
+                transform_info_for_downward_propagation(
+                    &parent_path,
+                    reference_from_frame,
+                    None,
+                    &transforms_at_parent,
+                )
+            } else {
+                transform_info_for_upward_propagation(
+                    reference_from_ancestor,
+                    &transforms_at_entity,
+                )
+            };
 
             reference_from_ancestor = new_transform.reference_from_entity;
 
@@ -316,6 +357,8 @@ impl TransformTreeContext {
 
             let lookup_image_plane =
                 |p: &_| lookup_image_plane_distance(ctx, data_result_tree, p, query);
+            let lookup_interpolation =
+                |p: &_| lookup_transform_interpolation(ctx, data_result_tree, p, query);
 
             let mut encountered_pinhole = twod_in_threed_info
                 .as_ref()
@@ -325,15 +368,28 @@ impl TransformTreeContext {
                 child_path,
                 query,
                 lookup_image_plane,
+                lookup_interpolation,
                 &mut encountered_pinhole,
                 transforms_for_timeline,
             );
-            let new_transform = transform_info_for_downward_propagation(
-                child_path,
-                reference_from_parent,
-                twod_in_threed_info.clone(),
-                &transforms_at_entity,
-            );
+            let new_transform = if let Some(reference_from_frame) =
+                self.reference_from_entity_frame(child_path, query, transforms_for_timeline)
+            {
+                // Entities in a connected named frame are placed by their frame, not their entity path parent.
+                transform_info_for_downward_propagation(
+                    child_path,
+                    reference_from_frame,
+                    None,
+                    &transforms_at_entity,
+                )
+            } else {
+                transform_info_for_downward_propagation(
+                    child_path,
+                    reference_from_parent,
+                    twod_in_threed_info.clone(),
+                    &transforms_at_entity,
+                )
+            };
 
             self.gather_descendants_transforms(
                 ctx,
@@ -346,6 +402,24 @@ impl TransformTreeContext {
         }
     }
 
+    /// Returns the transform from the named frame of the given entity into the reference space,
+    /// if the entity is in a named frame that is connected to the origin's frame.
+    fn reference_from_entity_frame(
+        &self,
+        entity_path: &EntityPath,
+        query: &LatestAtQuery,
+        transforms_for_timeline: &CachedTransformsForTimeline,
+    ) -> Option<glam::Affine3A> {
+        if self.reference_from_frames.is_empty() {
+            return None;
+        }
+
+        let frame = transforms_for_timeline
+            .entity_transforms(entity_path)?
+            .latest_at_coordinate_frame(query)?;
+        self.reference_from_frames.get(frame).copied()
+    }
+
     pub fn reference_path(&self) -> &EntityPath {
         &self.space_origin
     }
@@ -378,6 +452,68 @@ fn lookup_image_plane_distance(
         .into()
 }
 
+/// Determines the transforms from all named frames that are connected to the origin's frame into the reference space.
+///
+/// The origin's frame is the one of the origin itself or, if it has none, of its closest ancestor with a frame.
+/// Pinholes between the origin and that ancestor are ignored.
+fn reference_from_frames(
+    space_origin: &EntityPath,
+    query: &LatestAtQuery,
+    transforms_for_timeline: &CachedTransformsForTimeline,
+) -> HashMap<CoordinateFrame, glam::Affine3A> {
+    re_tracing::profile_function!();
+
+    let frame_graph = transforms_for_timeline.frame_graph(query);
+    if frame_graph.is_empty() {
+        return HashMap::default();
+    }
+
+    // The transform of an entity maps from the entity into its frame,
+    // so walk up until we find a frame, undoing all transforms on the way.
+    let mut reference_from_current = glam::Affine3A::IDENTITY;
+    let mut current = space_origin.clone();
+    let (origin_frame, reference_from_origin_frame) = loop {
+        let entity_transforms = transforms_for_timeline.entity_transforms(&current);
+        if let Some(entity_transforms) = entity_transforms {
+            reference_from_current *= entity_transforms.latest_at_tree_transform(query).inverse();
+            if let Some(frame) = entity_transforms.latest_at_coordinate_frame(query) {
+                break (frame, reference_from_current);
+            }
+        }
+        let Some(parent) = current.parent() else {
+            return HashMap::default();
+        };
+        current = parent;
+    };
+
+    frame_graph
+        .target_from_frames(origin_frame)
+        .into_iter()
+        .map(|(frame, origin_frame_from_frame)| {
+            (frame, reference_from_origin_frame * origin_frame_from_frame)
+        })
+        .collect()
+}
+
+fn lookup_transform_interpolation(
+    ctx: &ViewContext<'_>,
+    data_result_tree: &DataResultTree,
+    entity_path: &EntityPath,
+    query: &LatestAtQuery,
+) -> TransformInterpolation {
+    data_result_tree
+        .lookup_result_by_path(entity_path)
+        .cloned()
+        .map(|data_result| {
+            data_result
+                .latest_at_with_blueprint_resolved_data_for_component::<TransformInterpolation>(
+                    ctx, query,
+                )
+                .get_mono_with_fallback::<TransformInterpolation>()
+        })
+        .unwrap_or_default()
+}
+
 /// Compute transform info for when we walk up the tree from the reference.
 fn transform_info_for_upward_propagation(
     reference_from_ancestor: glam::Affine3A,
@@ -400,7 +536,7 @@ fn transform_info_for_upward_propagation(
     // Collect & compute poses.
     let (mut reference_from_instances, has_instance_transforms) =
         if let Ok(mut entity_from_instances) = SmallVec1::<[glam::Affine3A; 1]>::try_from_slice(
-            transforms_at_entity.entity_from_instance_poses,
+            &transforms_at_entity.entity_from_instance_poses,
         ) {
             for entity_from_instance in &mut entity_from_instances {
                 *entity_from_instance = reference_from_entity * entity_from_instance.inverse();
@@ -449,7 +585,7 @@ fn transform_info_for_downward_propagation(
     // Collect & compute poses.
     let (mut reference_from_instances, has_instance_transforms) =
         if let Ok(mut entity_from_instances) =
-            SmallVec1::try_from_slice(transforms_at_entity.entity_from_instance_poses)
+            SmallVec1::try_from_slice(&transforms_at_entity.entity_from_instance_poses)
         {
             for entity_from_instance in &mut entity_from_instances {
                 *entity_from_instance = reference_from_entity * (*entity_from_instance);
@@ -583,7 +719,7 @@ fn transform_from_pinhole_with_image_plane(
 #[derive(Default)]
 struct TransformsAtEntity<'a> {
     parent_from_entity_tree_transform: glam::Affine3A,
-    entity_from_instance_poses: &'a [glam::Affine3A],
+    entity_from_instance_poses: Cow<'a, [glam::Affine3A]>,
     instance_from_pinhole_image_plane: Option<glam::Affine3A>,
 }
 
@@ -591,6 +727,7 @@ fn transforms_at<'a>(
     entity_path: &EntityPath,
     query: &LatestAtQuery,
     pinhole_image_plane_distance: impl Fn(&EntityPath) -> f32,
+    transform_interpolation: impl Fn(&EntityPath) -> TransformInterpolation,
     encountered_pinhole: &mut Option<EntityPath>,
     transforms_for_timeline: &'a CachedTransformsForTimeline,
 ) -> TransformsAtEntity<'a> {
@@ -600,8 +737,14 @@ fn transforms_at<'a>(
         return TransformsAtEntity::default();
     };
 
-    let parent_from_entity_tree_transform = entity_transforms.latest_at_tree_transform(query);
-    let entity_from_instance_poses = entity_transforms.latest_at_instance_poses(query);
+    // Only look up the interpolation mode if there's actually something to interpolate.
+    let interpolation = std::cell::OnceCell::new();
+    let interpolation = || *interpolation.get_or_init(|| transform_interpolation(entity_path));
+
+    let parent_from_entity_tree_transform =
+        entity_transforms.interpolated_tree_transform(query, interpolation);
+    let entity_from_instance_poses =
+        entity_transforms.interpolated_instance_poses(query, interpolation);
     let instance_from_pinhole_image_plane =
         entity_transforms
             .latest_at_pinhole(query)
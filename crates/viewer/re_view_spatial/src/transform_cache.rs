@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use ahash::{HashMap, HashSet};
 use glam::Affine3A;
@@ -13,6 +13,7 @@ use re_entity_db::EntityDb;
 use re_log_types::{EntityPath, EntityPathHash, StoreId, TimeInt, TimelineName};
 use re_types::{
     archetypes::{self},
+    blueprint::components::TransformInterpolation,
     components::{self},
     Archetype as _, Component as _, ComponentName,
 };
@@ -29,6 +30,8 @@ use re_types::{
 ///   Instance poses that should be applied to the tree transforms (via [`crate::contexts::TransformTreeContext`]) but not propagate.
 /// * [`components::PinholeProjection`] and [`components::ViewCoordinates`]
 ///   Pinhole projections & associated view coordinates used for visualizing cameras in 3D and embedding 2D in 3D
+/// * [`archetypes::CoordinateFrame`] and [`archetypes::Transform3D`] with both a parent & child frame
+///   Named coordinate frames of entities & the transforms between them, see [`FrameGraph`].
 ///
 /// Most of what this construct does internally is to keep track at which points in time these sets of components
 /// change such that a latest-at query for them may (!) yield any new results.
@@ -93,6 +96,9 @@ bitflags::bitflags! {
 
         /// The entity has a clear component.
         const Clear = 1 << 3;
+
+        /// The entity has a named coordinate frame, i.e. [`components::CoordinateFrame`].
+        const CoordinateFrame = 1 << 4;
     }
 }
 
@@ -163,14 +169,30 @@ impl CachedTransformsForTimeline {
 
 type PoseTransformMap = BTreeMap<TimeInt, Vec<Affine3A>>;
 
+/// Maps from time to tree transform.
+///
+/// `None` marks clears, i.e. times at which there is no transform (anymore).
+/// These act like identity transforms, but are never interpolated into or out of.
+type TreeTransformMap = BTreeMap<TimeInt, Option<Affine3A>>;
+
 /// Maps from time to pinhole projection.
 ///
-/// Unlike with tree & pose transforms, there's no identity value that we can insert upon clears.
+/// Unlike with pose transforms, there's no empty value that we can insert upon clears.
 /// (clears here meaning that the user first logs a pinhole and then later either logs a clear or an empty pinhole array)
 /// Therefore, we instead store those events as `None` values to ensure that everything after a clear
 /// is properly marked as having no pinhole projection.
 type PinholeProjectionMap = BTreeMap<TimeInt, Option<ResolvedPinholeProjection>>;
 
+/// Maps from time to the transform between two named frames that is logged at an entity.
+///
+/// Just like with pinholes, `None` marks that there is no frame edge (anymore) at that time.
+type FrameEdgeMap = BTreeMap<TimeInt, Option<FrameEdge>>;
+
+/// Maps from time to the named coordinate frame an entity is in.
+///
+/// Just like with pinholes, `None` marks that the entity is not (anymore) in a named frame at that time.
+type CoordinateFrameMap = BTreeMap<TimeInt, Option<components::CoordinateFrame>>;
+
 /// Cached transforms for a single entity.
 ///
 /// Incorporates any static transforms that may apply to this entity.
@@ -180,11 +202,15 @@ pub struct TransformsForEntity {
     #[cfg(debug_assertions)]
     timeline: Option<TimelineName>,
 
-    tree_transforms: BTreeMap<TimeInt, Affine3A>,
+    tree_transforms: TreeTransformMap,
 
     // Pose transforms and pinhole projections are typically more rare, which is why we store them as optional boxes.
     pose_transforms: Option<Box<PoseTransformMap>>,
     pinhole_projections: Option<Box<PinholeProjectionMap>>,
+
+    // Named frames are even rarer, so they are stored the same way.
+    frame_edges: Option<Box<FrameEdgeMap>>,
+    coordinate_frames: Option<Box<CoordinateFrameMap>>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -199,11 +225,103 @@ pub struct ResolvedPinholeProjection {
     pub view_coordinates: components::ViewCoordinates,
 }
 
+/// A transform between two named coordinate frames.
+///
+/// Logged as an [`archetypes::Transform3D`] with both a parent & child frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameEdge {
+    pub parent: components::CoordinateFrame,
+    pub child: components::CoordinateFrame,
+    pub parent_from_child: Affine3A,
+}
+
+/// All transforms between named coordinate frames at a given time.
+///
+/// Frames can be connected in any direction, each transform can be walked both ways
+/// (as long as it is invertible).
+#[derive(Default, Debug)]
+pub struct FrameGraph {
+    /// For every frame, all directly connected frames and the transform from them to this frame.
+    neighbors: HashMap<components::CoordinateFrame, Vec<(components::CoordinateFrame, Affine3A)>>,
+}
+
+impl FrameGraph {
+    pub fn add_edge(&mut self, edge: &FrameEdge) {
+        let FrameEdge {
+            parent,
+            child,
+            parent_from_child,
+        } = edge;
+
+        self.neighbors
+            .entry(parent.clone())
+            .or_default()
+            .push((child.clone(), *parent_from_child));
+
+        let determinant = parent_from_child.matrix3.determinant();
+        if determinant != 0.0 && determinant.is_finite() {
+            self.neighbors
+                .entry(child.clone())
+                .or_default()
+                .push((parent.clone(), parent_from_child.inverse()));
+        } else {
+            self.neighbors.entry(child.clone()).or_default();
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.neighbors.is_empty()
+    }
+
+    /// Determines the transform into `target` for every frame that is connected to it, including `target` itself.
+    ///
+    /// Frames are found via a breadth first search, so if there are several ways to get from one frame to another,
+    /// the one with the fewest transforms is used.
+    pub fn target_from_frames(
+        &self,
+        target: &components::CoordinateFrame,
+    ) -> HashMap<components::CoordinateFrame, Affine3A> {
+        let mut target_from_frames = HashMap::default();
+        target_from_frames.insert(target.clone(), Affine3A::IDENTITY);
+
+        let mut queue = std::collections::VecDeque::from([(target, Affine3A::IDENTITY)]);
+        while let Some((frame, target_from_frame)) = queue.pop_front() {
+            let Some(neighbors) = self.neighbors.get(frame) else {
+                continue;
+            };
+            for (neighbor, frame_from_neighbor) in neighbors {
+                if target_from_frames.contains_key(neighbor) {
+                    continue;
+                }
+                let target_from_neighbor = target_from_frame * *frame_from_neighbor;
+                target_from_frames.insert(neighbor.clone(), target_from_neighbor);
+                queue.push_back((neighbor, target_from_neighbor));
+            }
+        }
+
+        target_from_frames
+    }
+}
+
 impl CachedTransformsForTimeline {
     #[inline]
     pub fn entity_transforms(&self, entity_path: &EntityPath) -> Option<&TransformsForEntity> {
         self.per_entity.get(entity_path)
     }
+
+    /// Collects all transforms between named coordinate frames at the given time.
+    pub fn frame_graph(&self, query: &LatestAtQuery) -> FrameGraph {
+        re_tracing::profile_function!();
+
+        let mut frame_graph = FrameGraph::default();
+        for entity_transforms in self.per_entity.values() {
+            if let Some(frame_edge) = entity_transforms.latest_at_frame_edge(query) {
+                frame_graph.add_edge(frame_edge);
+            }
+        }
+        frame_graph
+    }
 }
 
 impl TransformsForEntity {
@@ -216,11 +334,15 @@ impl TransformsForEntity {
         let mut tree_transforms = BTreeMap::new();
         let mut pose_transforms = None;
         let mut pinhole_projections = None;
+        let mut frame_edges = None;
+        let mut coordinate_frames = None;
 
         if let Some(static_transforms) = static_timeline.per_entity.get(entity_path) {
             tree_transforms = static_transforms.tree_transforms.clone();
             pose_transforms = static_transforms.pose_transforms.clone();
             pinhole_projections = static_transforms.pinhole_projections.clone();
+            frame_edges = static_transforms.frame_edges.clone();
+            coordinate_frames = static_transforms.coordinate_frames.clone();
         }
 
         let mut result = Self {
@@ -229,6 +351,8 @@ impl TransformsForEntity {
             pose_transforms,
             tree_transforms,
             pinhole_projections,
+            frame_edges,
+            coordinate_frames,
         };
 
         // Gather all times at which this entity is being cleared by one of its parent's recursive clears.
@@ -252,6 +376,8 @@ impl TransformsForEntity {
             pose_transforms: static_timeline_entry.pose_transforms.clone(),
             tree_transforms: static_timeline_entry.tree_transforms.clone(),
             pinhole_projections: static_timeline_entry.pinhole_projections.clone(),
+            frame_edges: static_timeline_entry.frame_edges.clone(),
+            coordinate_frames: static_timeline_entry.coordinate_frames.clone(),
         }
     }
 
@@ -262,6 +388,8 @@ impl TransformsForEntity {
             tree_transforms: BTreeMap::new(),
             pose_transforms: None,
             pinhole_projections: None,
+            frame_edges: None,
+            coordinate_frames: None,
         }
     }
 
@@ -271,13 +399,19 @@ impl TransformsForEntity {
         }
 
         self.tree_transforms
-            .extend(times.iter().map(|time| (*time, Affine3A::IDENTITY)));
+            .extend(times.iter().map(|time| (*time, None)));
         self.pose_transforms
             .get_or_insert(Default::default())
             .extend(times.iter().map(|time| (*time, vec![])));
         self.pinhole_projections
             .get_or_insert(Default::default())
             .extend(times.iter().map(|time| (*time, None)));
+        if let Some(frame_edges) = &mut self.frame_edges {
+            frame_edges.extend(times.iter().map(|time| (*time, None)));
+        }
+        if let Some(coordinate_frames) = &mut self.coordinate_frames {
+            coordinate_frames.extend(times.iter().map(|time| (*time, None)));
+        }
     }
 
     #[inline]
@@ -288,7 +422,7 @@ impl TransformsForEntity {
         self.tree_transforms
             .range(..query.at().inc())
             .next_back()
-            .map(|(_time, transform)| *transform)
+            .and_then(|(_time, transform)| *transform)
             .unwrap_or(Affine3A::IDENTITY)
     }
 
@@ -304,6 +438,74 @@ impl TransformsForEntity {
             .unwrap_or(&[])
     }
 
+    /// Like [`Self::latest_at_tree_transform`], but interpolates between the samples before & after the query time.
+    ///
+    /// Clears and degenerate transforms aren't eased into: the previous sample is held until they kick in.
+    /// Likewise, a cleared sample is held until the next sample kicks in.
+    /// Transforms between named frames (see [`Self::latest_at_frame_edge`]) are never interpolated.
+    ///
+    /// `interpolation` is only called if there is a later sample to interpolate towards.
+    pub fn interpolated_tree_transform(
+        &self,
+        query: &LatestAtQuery,
+        interpolation: impl FnOnce() -> TransformInterpolation,
+    ) -> Affine3A {
+        #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
+        debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
+
+        if let Some(samples) = BracketingSamples::new(&self.tree_transforms, query) {
+            // Clears are held, and never eased out of either.
+            if let (Some(previous), Some(next)) = (samples.previous.as_ref(), samples.next.as_ref())
+            {
+                let interpolation = interpolation();
+                if interpolation != TransformInterpolation::Off {
+                    let t = samples.interpolation_factor(query);
+                    return interpolate_transform(previous, next, t, interpolation);
+                }
+            }
+        }
+
+        self.latest_at_tree_transform(query)
+    }
+
+    /// Like [`Self::latest_at_instance_poses`], but interpolates between the samples before & after the query time.
+    ///
+    /// Poses are only interpolated if both samples have the same number of instances, which also rules out clears.
+    /// `interpolation` is only called if there is a later sample to interpolate towards.
+    pub fn interpolated_instance_poses(
+        &self,
+        query: &LatestAtQuery,
+        interpolation: impl FnOnce() -> TransformInterpolation,
+    ) -> Cow<'_, [Affine3A]> {
+        #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
+        debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
+
+        if let Some(samples) = self
+            .pose_transforms
+            .as_ref()
+            .and_then(|pose_transforms| BracketingSamples::new(pose_transforms, query))
+        {
+            if samples.previous.len() == samples.next.len() {
+                let interpolation = interpolation();
+                if interpolation != TransformInterpolation::Off {
+                    let t = samples.interpolation_factor(query);
+                    return Cow::Owned(
+                        samples
+                            .previous
+                            .iter()
+                            .zip(samples.next)
+                            .map(|(previous, next)| {
+                                interpolate_transform(previous, next, t, interpolation)
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        Cow::Borrowed(self.latest_at_instance_poses(query))
+    }
+
     #[inline]
     pub fn latest_at_pinhole(&self, query: &LatestAtQuery) -> Option<&ResolvedPinholeProjection> {
         #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
@@ -316,6 +518,104 @@ impl TransformsForEntity {
             })
             .and_then(|(_time, projection)| projection.as_ref())
     }
+
+    #[inline]
+    pub fn latest_at_frame_edge(&self, query: &LatestAtQuery) -> Option<&FrameEdge> {
+        #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
+        debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
+
+        self.frame_edges
+            .as_ref()
+            .and_then(|frame_edges| frame_edges.range(..query.at().inc()).next_back())
+            .and_then(|(_time, frame_edge)| frame_edge.as_ref())
+    }
+
+    #[inline]
+    pub fn latest_at_coordinate_frame(
+        &self,
+        query: &LatestAtQuery,
+    ) -> Option<&components::CoordinateFrame> {
+        #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
+        debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
+
+        self.coordinate_frames
+            .as_ref()
+            .and_then(|coordinate_frames| coordinate_frames.range(..query.at().inc()).next_back())
+            .and_then(|(_time, frame)| frame.as_ref())
+    }
+}
+
+/// The last sample before a query time and the first sample after it.
+struct BracketingSamples<'a, T> {
+    previous_time: TimeInt,
+    previous: &'a T,
+    next_time: TimeInt,
+    next: &'a T,
+}
+
+impl<'a, T> BracketingSamples<'a, T> {
+    /// Returns `None` if there's a sample exactly at the query time, the last sample is static, or there's no later sample.
+    fn new(samples: &'a BTreeMap<TimeInt, T>, query: &LatestAtQuery) -> Option<Self> {
+        let (previous_time, previous) = samples.range(..query.at().inc()).next_back()?;
+        if previous_time.is_static() || *previous_time == query.at() {
+            return None;
+        }
+        let (next_time, next) = samples.range(query.at().inc()..).next()?;
+
+        Some(Self {
+            previous_time: *previous_time,
+            previous,
+            next_time: *next_time,
+            next,
+        })
+    }
+
+    /// How far the query time is between the two samples, from 0.0 at the previous to 1.0 at the next one.
+    fn interpolation_factor(&self, query: &LatestAtQuery) -> f32 {
+        let elapsed = query.at().as_f64() - self.previous_time.as_f64();
+        let duration = self.next_time.as_f64() - self.previous_time.as_f64();
+        (elapsed / duration) as f32
+    }
+}
+
+/// Interpolates between two transforms, `t` ranging from 0.0 (`previous`) to 1.0 (`next`).
+fn interpolate_transform(
+    previous: &Affine3A,
+    next: &Affine3A,
+    t: f32,
+    interpolation: TransformInterpolation,
+) -> Affine3A {
+    // Degenerate transforms disconnect their subtree, and can't be decomposed either: hold those instead.
+    if is_degenerate(previous) || is_degenerate(next) {
+        return *previous;
+    }
+
+    match interpolation {
+        TransformInterpolation::Off => *previous,
+
+        TransformInterpolation::Linear => Affine3A {
+            matrix3: previous.matrix3 * (1.0 - t) + next.matrix3 * t,
+            translation: previous.translation.lerp(next.translation, t),
+        },
+
+        TransformInterpolation::Slerp => {
+            let (previous_scale, previous_rotation, previous_translation) =
+                previous.to_scale_rotation_translation();
+            let (next_scale, next_rotation, next_translation) =
+                next.to_scale_rotation_translation();
+            Affine3A::from_scale_rotation_translation(
+                previous_scale.lerp(next_scale, t),
+                previous_rotation.slerp(next_rotation, t),
+                previous_translation.lerp(next_translation, t),
+            )
+        }
+    }
+}
+
+/// Whether a transform collapses space, e.g. `Affine3A::ZERO` for invalid transforms.
+fn is_degenerate(transform: &Affine3A) -> bool {
+    let determinant = transform.matrix3.determinant();
+    determinant == 0.0 || !determinant.is_finite()
 }
 
 impl TransformCacheStoreSubscriber {
@@ -388,7 +688,15 @@ impl TransformCacheStoreSubscriber {
                 {
                     static_transforms
                         .tree_transforms
-                        .insert(TimeInt::STATIC, transform);
+                        .insert(TimeInt::STATIC, Some(transform));
+                }
+                if let Some(frame_edge) =
+                    query_and_resolve_frame_edge_at_entity(&entity_path, entity_db, &query)
+                {
+                    static_transforms.frame_edges = Some(Box::new(BTreeMap::from([(
+                        TimeInt::STATIC,
+                        Some(frame_edge),
+                    )])));
                 }
             }
             if aspects.contains(TransformAspect::Pose) {
@@ -409,6 +717,16 @@ impl TransformCacheStoreSubscriber {
                     )])));
                 }
             }
+            if aspects.contains(TransformAspect::CoordinateFrame) {
+                if let Some((_index, coordinate_frame)) = entity_db
+                    .latest_at_component::<components::CoordinateFrame>(&entity_path, &query)
+                {
+                    static_transforms.coordinate_frames = Some(Box::new(BTreeMap::from([(
+                        TimeInt::STATIC,
+                        Some(coordinate_frame),
+                    )])));
+                }
+            }
         }
 
         // Update dynamic transforms.
@@ -439,10 +757,20 @@ impl TransformCacheStoreSubscriber {
                             &entity_path,
                             entity_db,
                             &query,
-                        )
-                        .unwrap_or(Affine3A::IDENTITY);
-                        // If there's *no* transform, we have to put identity in, otherwise we'd miss clears!
+                        );
+                        // If there's *no* transform, we have to put a clear in, otherwise we'd miss clears!
                         entity_entry.tree_transforms.insert(time, transform);
+
+                        let frame_edge =
+                            query_and_resolve_frame_edge_at_entity(&entity_path, entity_db, &query);
+                        // Most tree transforms don't connect named frames,
+                        // so only allocate the map once there's an actual frame edge.
+                        if frame_edge.is_some() || entity_entry.frame_edges.is_some() {
+                            entity_entry
+                                .frame_edges
+                                .get_or_insert_with(Box::default)
+                                .insert(time, frame_edge);
+                        }
                     }
                     if aspects.intersects(TransformAspect::Pose | TransformAspect::Clear) {
                         let poses = query_and_resolve_instance_poses_at_entity(
@@ -471,6 +799,20 @@ impl TransformCacheStoreSubscriber {
                             .get_or_insert_with(Box::default)
                             .insert(time, pinhole_projection);
                     }
+                    if aspects.intersects(TransformAspect::CoordinateFrame | TransformAspect::Clear)
+                    {
+                        let coordinate_frame = entity_db
+                            .latest_at_component::<components::CoordinateFrame>(
+                                &entity_path,
+                                &query,
+                            )
+                            .map(|(_index, coordinate_frame)| coordinate_frame);
+                        // `None` values need to be inserted as well to clear out previous state.
+                        entity_entry
+                            .coordinate_frames
+                            .get_or_insert_with(Box::default)
+                            .insert(time, coordinate_frame);
+                    }
                 }
             }
         }
@@ -515,6 +857,10 @@ impl TransformCacheStoreSubscriber {
                     let invalidated_tree_transforms =
                         entity_entry.tree_transforms.split_off(&min_time);
                     invalidated_times.extend(invalidated_tree_transforms.into_keys());
+                    if let Some(frame_edges) = &mut entity_entry.frame_edges {
+                        let invalidated_frame_edges = frame_edges.split_off(&min_time);
+                        invalidated_times.extend(invalidated_frame_edges.into_keys());
+                    }
                 }
                 if aspects.intersects(TransformAspect::Pose | TransformAspect::Clear) {
                     if let Some(pose_transforms) = &mut entity_entry.pose_transforms {
@@ -531,6 +877,12 @@ impl TransformCacheStoreSubscriber {
                         invalidated_times.extend(invalidated_pinhole_projections.into_keys());
                     }
                 }
+                if aspects.intersects(TransformAspect::CoordinateFrame | TransformAspect::Clear) {
+                    if let Some(coordinate_frames) = &mut entity_entry.coordinate_frames {
+                        let invalidated_coordinate_frames = coordinate_frames.split_off(&min_time);
+                        invalidated_times.extend(invalidated_coordinate_frames.into_keys());
+                    }
+                }
             }
 
             if aspects.contains(TransformAspect::Clear) {
@@ -646,6 +998,20 @@ impl TransformCacheStoreSubscriber {
                         aspects,
                     });
             }
+            if aspects.contains(TransformAspect::CoordinateFrame) {
+                let mut times = vec![TimeInt::STATIC];
+                if let Some(coordinate_frames) = &entity_transforms.coordinate_frames {
+                    times.extend(coordinate_frames.keys().copied());
+                }
+
+                per_timeline_transforms
+                    .invalidated_transforms
+                    .push(InvalidatedTransforms {
+                        entity_path: entity_path.clone(),
+                        times,
+                        aspects,
+                    });
+            }
             // Don't care about clears here, they don't have any effect for keeping track of changes when logged static.
         }
     }
@@ -681,6 +1047,9 @@ impl TransformCacheStoreSubscriber {
                 for time in time_column.times() {
                     if aspects.contains(TransformAspect::Tree) {
                         per_entity.tree_transforms.remove(&time);
+                        if let Some(frame_edges) = &mut per_entity.frame_edges {
+                            frame_edges.remove(&time);
+                        }
                     }
                     if aspects.contains(TransformAspect::Pose) {
                         if let Some(pose_transforms) = &mut per_entity.pose_transforms {
@@ -692,6 +1061,11 @@ impl TransformCacheStoreSubscriber {
                             pinhole_projections.remove(&time);
                         }
                     }
+                    if aspects.contains(TransformAspect::CoordinateFrame) {
+                        if let Some(coordinate_frames) = &mut per_entity.coordinate_frames {
+                            coordinate_frames.remove(&time);
+                        }
+                    }
                 }
 
                 if per_entity.tree_transforms.is_empty()
@@ -703,6 +1077,14 @@ impl TransformCacheStoreSubscriber {
                         .pinhole_projections
                         .as_ref()
                         .is_none_or(|pinhole_projections| pinhole_projections.is_empty())
+                    && per_entity
+                        .frame_edges
+                        .as_ref()
+                        .is_none_or(|frame_edges| frame_edges.is_empty())
+                    && per_entity
+                        .coordinate_frames
+                        .as_ref()
+                        .is_none_or(|coordinate_frames| coordinate_frames.is_empty())
                 {
                     per_timeline.per_entity.remove(entity_path);
                 }
@@ -742,6 +1124,9 @@ impl PerStoreChunkSubscriber for TransformCacheStoreSubscriber {
                 if component_name == re_types::components::ClearIsRecursive::name() {
                     aspects |= TransformAspect::Clear;
                 }
+                if component_name == components::CoordinateFrame::name() {
+                    aspects |= TransformAspect::CoordinateFrame;
+                }
             }
             if aspects.is_empty() {
                 continue;
@@ -762,6 +1147,9 @@ impl PerStoreChunkSubscriber for TransformCacheStoreSubscriber {
 ///
 /// If any of the components yields an invalid transform, returns a `glam::Affine3A::ZERO`.
 /// (this effectively disconnects a subtree from the transform hierarchy!)
+///
+/// Transforms between two named frames don't affect the entity tree, for those this returns `None`.
+/// See [`query_and_resolve_frame_edge_at_entity`].
 // TODO(#3849): There's no way to discover invalid transforms right now (they can be intentional but often aren't).
 fn query_and_resolve_tree_transform_at_entity(
     entity_path: &EntityPath,
@@ -772,10 +1160,49 @@ fn query_and_resolve_tree_transform_at_entity(
     let components = archetypes::Transform3D::all_components();
     let component_names = components.iter().map(|descr| descr.component_name);
     let results = entity_db.latest_at(query, entity_path, component_names);
-    if results.components.is_empty() {
+    if results.components.is_empty() || resolve_frame_names(&results).is_some() {
         return None;
     }
 
+    Some(resolve_transform3d(entity_path, &results))
+}
+
+/// Queries all components of [`archetypes::Transform3D`], returning the transform between two named frames
+/// if both a parent and a child frame are set.
+fn query_and_resolve_frame_edge_at_entity(
+    entity_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> Option<FrameEdge> {
+    let components = archetypes::Transform3D::all_components();
+    let component_names = components.iter().map(|descr| descr.component_name);
+    let results = entity_db.latest_at(query, entity_path, component_names);
+    let (parent, child) = resolve_frame_names(&results)?;
+
+    Some(FrameEdge {
+        parent,
+        child,
+        parent_from_child: resolve_transform3d(entity_path, &results),
+    })
+}
+
+/// Returns the parent & child frame of a transform if both are set.
+fn resolve_frame_names(
+    results: &re_query::LatestAtResults,
+) -> Option<(components::CoordinateFrame, components::CoordinateFrame)> {
+    let mono_log_level = re_log::Level::Warn;
+    let parent =
+        results.component_mono_with_log_level::<components::ParentFrame>(mono_log_level)?;
+    let child = results.component_mono_with_log_level::<components::ChildFrame>(mono_log_level)?;
+
+    Some((
+        components::CoordinateFrame(parent.0),
+        components::CoordinateFrame(child.0),
+    ))
+}
+
+/// Resolves the components of a [`archetypes::Transform3D`] to a single affine transform from child to parent.
+fn resolve_transform3d(entity_path: &EntityPath, results: &re_query::LatestAtResults) -> Affine3A {
     let mut transform = Affine3A::IDENTITY;
 
     // It's an error if there's more than one component. Warn in that case.
@@ -793,7 +1220,7 @@ fn query_and_resolve_tree_transform_at_entity(
         if let Ok(axis_angle) = Affine3A::try_from(axis_angle) {
             transform *= axis_angle;
         } else {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
     }
     if let Some(quaternion) =
@@ -802,14 +1229,14 @@ fn query_and_resolve_tree_transform_at_entity(
         if let Ok(quaternion) = Affine3A::try_from(quaternion) {
             transform *= quaternion;
         } else {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
     }
     if let Some(scale) =
         results.component_mono_with_log_level::<components::Scale3D>(mono_log_level)
     {
         if scale.x() == 0.0 && scale.y() == 0.0 && scale.z() == 0.0 {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
         transform *= Affine3A::from(scale);
     }
@@ -818,7 +1245,7 @@ fn query_and_resolve_tree_transform_at_entity(
     {
         let affine_transform = Affine3A::from(mat3x3);
         if affine_transform.matrix3.determinant() == 0.0 {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
         transform *= affine_transform;
     }
@@ -839,7 +1266,7 @@ fn query_and_resolve_tree_transform_at_entity(
         }
     }
 
-    Some(transform)
+    transform
 }
 
 /// Queries all components that are part of pose transforms, returning the transform from child to parent.
@@ -1729,6 +2156,331 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_frame_edges() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        let timeline = Timeline::new_sequence("t");
+        let base_from_camera_path = EntityPath::from("tf/base_from_camera");
+        let chunk = Chunk::builder(base_from_camera_path.clone())
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 1)],
+                &archetypes::Transform3D::from_translation([1.0, 0.0, 0.0])
+                    .with_parent_frame("base")
+                    .with_child_frame("camera"),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 3)],
+                &archetypes::Transform3D::clear_fields(),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+        let chunk = Chunk::builder(EntityPath::from("tf/world_from_base"))
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 1)],
+                &archetypes::Transform3D::from_translation([0.0, 2.0, 0.0])
+                    .with_parent_frame("world")
+                    .with_child_frame("base"),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(&entity_db.store_id(), |cache| {
+            let timeline = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline);
+
+            let world = components::CoordinateFrame::from("world");
+            let base = components::CoordinateFrame::from("base");
+            let camera = components::CoordinateFrame::from("camera");
+
+            // Transforms between frames don't affect the entity tree.
+            let transforms = transforms_per_timeline
+                .entity_transforms(&base_from_camera_path)
+                .unwrap();
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline, 1)),
+                glam::Affine3A::IDENTITY
+            );
+            assert_eq!(
+                transforms.latest_at_frame_edge(&LatestAtQuery::new(timeline, 1)),
+                Some(&FrameEdge {
+                    parent: base.clone(),
+                    child: camera.clone(),
+                    parent_from_child: glam::Affine3A::from_translation(glam::Vec3::new(
+                        1.0, 0.0, 0.0
+                    )),
+                })
+            );
+
+            assert!(transforms_per_timeline
+                .frame_graph(&LatestAtQuery::new(timeline, 0))
+                .is_empty());
+
+            let frame_graph = transforms_per_timeline.frame_graph(&LatestAtQuery::new(timeline, 2));
+            assert_eq!(
+                frame_graph.target_from_frames(&world).get(&camera).copied(),
+                Some(glam::Affine3A::from_translation(glam::Vec3::new(
+                    1.0, 2.0, 0.0
+                )))
+            );
+            assert_eq!(
+                frame_graph.target_from_frames(&camera).get(&world).copied(),
+                Some(glam::Affine3A::from_translation(glam::Vec3::new(
+                    -1.0, -2.0, 0.0
+                )))
+            );
+            assert_eq!(
+                frame_graph.target_from_frames(&base).get(&base).copied(),
+                Some(glam::Affine3A::IDENTITY)
+            );
+            assert_eq!(
+                frame_graph
+                    .target_from_frames(&world)
+                    .get(&components::CoordinateFrame::from("lidar"))
+                    .copied(),
+                None
+            );
+
+            // The camera edge was cleared.
+            let frame_graph = transforms_per_timeline.frame_graph(&LatestAtQuery::new(timeline, 3));
+            assert_eq!(
+                frame_graph.target_from_frames(&world).get(&camera).copied(),
+                None
+            );
+            assert_eq!(
+                frame_graph.target_from_frames(&world).get(&base).copied(),
+                Some(glam::Affine3A::from_translation(glam::Vec3::new(
+                    0.0, 2.0, 0.0
+                )))
+            );
+        });
+    }
+
+    #[test]
+    fn test_coordinate_frames() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        let timeline = Timeline::new_sequence("t");
+        let path = EntityPath::from("robot");
+        let chunk = Chunk::builder(path.clone())
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 1)],
+                &archetypes::CoordinateFrame::new("base"),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 2)],
+                &archetypes::CoordinateFrame::new("odom"),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 3)],
+                &archetypes::Clear::new(false),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(&entity_db.store_id(), |cache| {
+            let timeline = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline);
+            let transforms = transforms_per_timeline.entity_transforms(&path).unwrap();
+
+            assert_eq!(
+                transforms.latest_at_coordinate_frame(&LatestAtQuery::new(timeline, 0)),
+                None
+            );
+            assert_eq!(
+                transforms.latest_at_coordinate_frame(&LatestAtQuery::new(timeline, 1)),
+                Some(&components::CoordinateFrame::from("base"))
+            );
+            assert_eq!(
+                transforms.latest_at_coordinate_frame(&LatestAtQuery::new(timeline, 2)),
+                Some(&components::CoordinateFrame::from("odom"))
+            );
+            assert_eq!(
+                transforms.latest_at_coordinate_frame(&LatestAtQuery::new(timeline, 3)),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        let timeline = Timeline::new_sequence("t");
+        let path = EntityPath::from("my_entity");
+        let chunk = Chunk::builder(path.clone())
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 0)],
+                &archetypes::Transform3D::from_translation_rotation(
+                    [0.0, 0.0, 0.0],
+                    glam::Quat::IDENTITY,
+                ),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 10)],
+                &archetypes::Transform3D::from_translation_rotation(
+                    [10.0, 0.0, 0.0],
+                    glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+                ),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 0)],
+                &archetypes::InstancePoses3D::new()
+                    .with_translations([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 10)],
+                &archetypes::InstancePoses3D::new()
+                    .with_translations([[0.0, 2.0, 0.0], [1.0, 4.0, 0.0]]),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(&entity_db.store_id(), |cache| {
+            let timeline = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline);
+            let transforms = transforms_per_timeline.entity_transforms(&path).unwrap();
+
+            let halfway = LatestAtQuery::new(timeline, 5);
+
+            // No interpolation holds the previous sample.
+            assert_eq!(
+                transforms.interpolated_tree_transform(&halfway, || TransformInterpolation::Off),
+                glam::Affine3A::IDENTITY
+            );
+            assert_eq!(
+                *transforms.interpolated_instance_poses(&halfway, || TransformInterpolation::Off),
+                [
+                    glam::Affine3A::IDENTITY,
+                    glam::Affine3A::from_translation(glam::vec3(1.0, 0.0, 0.0)),
+                ]
+            );
+
+            let expected = glam::Affine3A::from_rotation_translation(
+                glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+                glam::vec3(5.0, 0.0, 0.0),
+            );
+            let slerped =
+                transforms.interpolated_tree_transform(&halfway, || TransformInterpolation::Slerp);
+            assert!(slerped.abs_diff_eq(expected, 1e-5), "{slerped:?}");
+
+            // Linear interpolation of the rotation matrix doesn't preserve its length.
+            let lerped =
+                transforms.interpolated_tree_transform(&halfway, || TransformInterpolation::Linear);
+            assert_eq!(lerped.translation, glam::Vec3A::new(5.0, 0.0, 0.0));
+            assert!(lerped.matrix3.x_axis.length() < 1.0);
+
+            assert_eq!(
+                *transforms
+                    .interpolated_instance_poses(&halfway, || TransformInterpolation::Linear),
+                [
+                    glam::Affine3A::from_translation(glam::vec3(0.0, 1.0, 0.0)),
+                    glam::Affine3A::from_translation(glam::vec3(1.0, 2.0, 0.0)),
+                ]
+            );
+
+            // Samples themselves and times outside of the logged range aren't interpolated.
+            for time in [0, 10, 20] {
+                let query = LatestAtQuery::new(timeline, time);
+                assert_eq!(
+                    transforms.interpolated_tree_transform(&query, || {
+                        panic!("No interpolation mode should be needed")
+                    }),
+                    transforms.latest_at_tree_transform(&query)
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_interpolation_holds_clears_and_degenerate_transforms() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        let timeline = Timeline::new_sequence("t");
+        let path = EntityPath::from("my_entity");
+        let chunk = Chunk::builder(path.clone())
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 0)],
+                &archetypes::Transform3D::from_translation([1.0, 0.0, 0.0]),
+            )
+            .with_archetype(RowId::new(), [(timeline, 10)], &archetypes::Clear::flat())
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 20)],
+                &archetypes::Transform3D::from_translation([2.0, 0.0, 0.0]),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 30)],
+                &archetypes::Transform3D::from_scale(0.0),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 40)],
+                &archetypes::Transform3D::from_translation([4.0, 0.0, 0.0]),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 50)],
+                &archetypes::Transform3D::from_translation([0.0, 0.0, 0.0]),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(&entity_db.store_id(), |cache| {
+            let timeline = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline);
+            let transforms = transforms_per_timeline.entity_transforms(&path).unwrap();
+
+            for interpolation in [
+                TransformInterpolation::Linear,
+                TransformInterpolation::Slerp,
+            ] {
+                // Neither into (5) nor out of (15) the clear.
+                for time in [5, 15, 25, 35] {
+                    let query = LatestAtQuery::new(timeline, time);
+                    assert_eq!(
+                        transforms.interpolated_tree_transform(&query, || interpolation),
+                        transforms.latest_at_tree_transform(&query),
+                        "{interpolation:?} at {time}"
+                    );
+                }
+
+                // Logged identity transforms, unlike clears, are interpolated toward.
+                let query = LatestAtQuery::new(timeline, 45);
+                assert!(
+                    transforms
+                        .interpolated_tree_transform(&query, || interpolation)
+                        .abs_diff_eq(
+                            glam::Affine3A::from_translation(glam::vec3(2.0, 0.0, 0.0)),
+                            1e-5
+                        ),
+                    "{interpolation:?} at 45"
+                );
+            }
+        });
+    }
+
     #[test]
     fn test_out_of_order_updates() {
         let mut entity_db = new_entity_db_with_subscriber_registered();
@@ -0,0 +1,180 @@
+use re_log_types::TimelineName;
+use re_types::blueprint::{
+    archetypes::TextLogColumns,
+    components,
+    datatypes::{TextLogColumn, TextLogColumnKind},
+};
+use re_viewer_context::{ViewId, ViewSystemExecutionError, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+/// The columns of a text log view, as configured by its [`TextLogColumns`] property.
+pub struct ViewColumns {
+    property: ViewProperty,
+
+    /// All columns in the order they are shown, including hidden ones.
+    ///
+    /// May contain columns for timelines that don't exist in the current recording.
+    pub columns: Vec<TextLogColumn>,
+}
+
+impl ViewColumns {
+    pub fn from_blueprint(
+        ctx: &ViewerContext<'_>,
+        view_id: ViewId,
+    ) -> Result<Self, ViewSystemExecutionError> {
+        let property = ViewProperty::from_archetype::<TextLogColumns>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+
+        let stored = property.component_array_or_empty::<components::TextLogColumn>()?;
+        let columns = resolve_columns(
+            stored.into_iter().map(|column| column.0).collect(),
+            ctx.recording().timelines().into_keys(),
+        );
+
+        Ok(Self { property, columns })
+    }
+
+    /// Indices of all columns that exist in the current recording, in order.
+    pub fn available(&self, ctx: &ViewerContext<'_>) -> Vec<usize> {
+        let timelines = ctx.recording().timelines();
+        (0..self.columns.len())
+            .filter(|&index| {
+                let column = &self.columns[index];
+                column.kind != TextLogColumnKind::Timeline
+                    || timelines.contains_key(&TimelineName::new(column.timeline.as_str()))
+            })
+            .collect()
+    }
+
+    /// All columns that are shown in the table, in order.
+    pub fn shown(&self, ctx: &ViewerContext<'_>) -> Vec<TextLogColumn> {
+        self.available(ctx)
+            .into_iter()
+            .map(|index| &self.columns[index])
+            .filter(|column| *column.visible)
+            .cloned()
+            .collect()
+    }
+
+    pub fn save_to_blueprint(&self, ctx: &ViewerContext<'_>) {
+        let columns = self
+            .columns
+            .iter()
+            .cloned()
+            .map(components::TextLogColumn)
+            .collect::<Vec<_>>();
+        self.property.save_blueprint_component(ctx, &columns);
+    }
+}
+
+/// Orders the stored columns and adds all columns that are missing.
+///
+/// Timelines that aren't stored are shown first, any other missing column is added at the end.
+fn resolve_columns(
+    stored: Vec<TextLogColumn>,
+    timelines: impl IntoIterator<Item = TimelineName>,
+) -> Vec<TextLogColumn> {
+    let mut columns = Vec::with_capacity(stored.len() + 3);
+
+    for timeline in timelines {
+        let column = new_column(TextLogColumnKind::Timeline, timeline.as_str());
+        if !contains_column(&stored, &column) {
+            columns.push(column);
+        }
+    }
+
+    for column in stored {
+        if !contains_column(&columns, &column) {
+            columns.push(column);
+        }
+    }
+
+    for kind in [
+        TextLogColumnKind::EntityPath,
+        TextLogColumnKind::LogLevel,
+        TextLogColumnKind::Body,
+    ] {
+        let column = new_column(kind, "");
+        if !contains_column(&columns, &column) {
+            columns.push(column);
+        }
+    }
+
+    columns
+}
+
+fn new_column(kind: TextLogColumnKind, timeline: &str) -> TextLogColumn {
+    TextLogColumn {
+        kind,
+        timeline: timeline.into(),
+        visible: true.into(),
+        width: None,
+    }
+}
+
+/// Whether `columns` already has a column that shows the same thing as `column`.
+fn contains_column(columns: &[TextLogColumn], column: &TextLogColumn) -> bool {
+    columns.iter().any(|other| {
+        other.kind == column.kind
+            && (column.kind != TextLogColumnKind::Timeline || other.timeline == column.timeline)
+    })
+}
+
+/// The name of a column, as shown in the selection panel.
+pub fn column_label(column: &TextLogColumn) -> &str {
+    match column.kind {
+        TextLogColumnKind::Timeline => column.timeline.as_str(),
+        TextLogColumnKind::EntityPath => "Entity path",
+        TextLogColumnKind::LogLevel => "Log level",
+        TextLogColumnKind::Body => "Body",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_columns() {
+        let timelines = || [TimelineName::new("frame"), TimelineName::log_time()];
+
+        let default_columns = resolve_columns(Vec::new(), timelines());
+        assert_eq!(
+            default_columns,
+            vec![
+                new_column(TextLogColumnKind::Timeline, "frame"),
+                new_column(TextLogColumnKind::Timeline, "log_time"),
+                new_column(TextLogColumnKind::EntityPath, ""),
+                new_column(TextLogColumnKind::LogLevel, ""),
+                new_column(TextLogColumnKind::Body, ""),
+            ]
+        );
+
+        let mut hidden_level = new_column(TextLogColumnKind::LogLevel, "");
+        hidden_level.visible = false.into();
+        let mut wide_frame = new_column(TextLogColumnKind::Timeline, "frame");
+        wide_frame.width = Some(120.0.into());
+
+        let stored = vec![
+            new_column(TextLogColumnKind::Body, ""),
+            hidden_level.clone(),
+            wide_frame.clone(),
+            new_column(TextLogColumnKind::Timeline, "unknown"),
+            new_column(TextLogColumnKind::Body, ""),
+        ];
+        assert_eq!(
+            resolve_columns(stored, timelines()),
+            vec![
+                new_column(TextLogColumnKind::Timeline, "log_time"),
+                new_column(TextLogColumnKind::Body, ""),
+                hidden_level,
+                wide_frame,
+                new_column(TextLogColumnKind::Timeline, "unknown"),
+                new_column(TextLogColumnKind::EntityPath, ""),
+            ]
+        );
+    }
+}
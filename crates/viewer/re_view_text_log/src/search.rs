@@ -0,0 +1,99 @@
+use std::ops::Range;
+
+use re_types::blueprint::components::TextSearchMode;
+
+/// A compiled text search query, used to filter and highlight text log entries.
+#[derive(Debug, Clone)]
+pub struct TextSearch {
+    regex: regex::Regex,
+}
+
+impl TextSearch {
+    /// Compiles a search query.
+    ///
+    /// Returns `Ok(None)` for an empty query, which matches everything.
+    pub fn new(
+        query: &str,
+        mode: TextSearchMode,
+        case_sensitive: bool,
+    ) -> Result<Option<Self>, regex::Error> {
+        if query.is_empty() {
+            return Ok(None);
+        }
+
+        let pattern = match mode {
+            TextSearchMode::Plain => regex::escape(query),
+            TextSearchMode::Regex => query.to_owned(),
+        };
+
+        let regex = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(!case_sensitive)
+            .build()?;
+
+        Ok(Some(Self { regex }))
+    }
+
+    /// Byte ranges of all non-empty matches in `text`.
+    ///
+    /// Returns `None` if the query doesn't match at all.
+    pub fn find_matches(&self, text: &str) -> Option<Vec<Range<usize>>> {
+        let mut any_match = false;
+        let ranges = self
+            .regex
+            .find_iter(text)
+            .map(|m| m.range())
+            .filter(|range| {
+                any_match = true;
+                !range.is_empty()
+            })
+            .collect();
+
+        any_match.then_some(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_search() {
+        assert!(TextSearch::new("", TextSearchMode::Plain, false)
+            .unwrap()
+            .is_none());
+
+        let search = TextSearch::new("a.b", TextSearchMode::Plain, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(search.find_matches("A.B and a.b"), Some(vec![0..3, 8..11]));
+        assert_eq!(search.find_matches("axb"), None);
+
+        let search = TextSearch::new("a.b", TextSearchMode::Plain, true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            search.find_matches("A.B and a.b, a.b"),
+            Some(vec![8..11, 13..16])
+        );
+    }
+
+    #[test]
+    fn test_regex_search() {
+        let search = TextSearch::new(r"err(or)?\b", TextSearchMode::Regex, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            search.find_matches("Error: err erroneous"),
+            Some(vec![0..5, 7..10])
+        );
+        assert_eq!(search.find_matches("all good"), None);
+
+        // Matches of zero length still count as a match, but aren't highlighted.
+        let search = TextSearch::new("x*", TextSearchMode::Regex, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(search.find_matches("abc"), Some(vec![]));
+
+        assert!(TextSearch::new("(", TextSearchMode::Regex, false).is_err());
+    }
+}
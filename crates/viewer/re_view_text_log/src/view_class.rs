@@ -1,20 +1,29 @@
-use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use re_data_ui::item_ui;
-use re_log_types::{EntityPath, TimelineName};
+use re_log_types::{EntityPath, TimeInt, TimelineName};
+use re_types::blueprint::{
+    archetypes::TextLogFilter,
+    components::{CaseSensitive, TextSearchMode},
+    datatypes::{TextLogColumn, TextLogColumnKind},
+};
 use re_types::View as _;
-use re_types::{components::TextLogLevel, ViewClassIdentifier};
+use re_types::{
+    components::{Text, TextLogLevel},
+    ViewClassIdentifier,
+};
 use re_ui::{Help, UiExt as _};
 use re_viewer_context::{
     level_to_rich_text, IdentifiedViewSystem as _, ViewClass, ViewClassRegistryError, ViewId,
     ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
     ViewerContext,
 };
+use re_viewport_blueprint::ViewProperty;
 
+use super::columns::{column_label, ViewColumns};
 use super::visualizer_system::{Entry, TextLogSystem};
 
-// TODO(andreas): This should be a blueprint component.
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, PartialEq, Default)]
 pub struct TextViewState {
     /// Keeps track of the latest time selection made by the user.
     ///
@@ -22,8 +31,18 @@ pub struct TextViewState {
     /// text entry window however they please when the time cursor isn't moving.
     latest_time: i64,
 
-    pub filters: ViewTextFilters,
+    /// All log levels the view has seen so far, used to populate the level filter.
+    levels: BTreeSet<TextLogLevel>,
 
+    /// Widths of all resizable columns in the last frame.
+    column_widths: Vec<f32>,
+
+    /// Set while the user is resizing columns.
+    ///
+    /// Only written to the blueprint once they let go, to not flood it with intermediate widths.
+    pending_column_widths: Option<Vec<f32>>,
+
+    // TODO(andreas): This should be a blueprint component.
     monospace: bool,
 }
 
@@ -61,7 +80,9 @@ impl ViewClass for TextView {
             .markdown(
                 "TextLog entries over time.
 
-Filter message types and toggle column visibility in a selection panel.",
+Search the entries with the search bar at the top of the view. Jump between matches with the arrow buttons.
+
+Filter message types, toggle column visibility, and reorder columns in a selection panel.",
             )
     }
 
@@ -106,36 +127,46 @@ Filter message types and toggle column visibility in a selection panel.",
 
     fn selection_ui(
         &self,
-        _ctx: &ViewerContext<'_>,
+        ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
         _space_origin: &EntityPath,
-        _view_id: ViewId,
+        view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<TextViewState>()?;
 
-        let ViewTextFilters {
-            col_timelines,
-            col_entity_path,
-            col_log_level,
-            row_log_levels,
-        } = &mut state.filters;
+        let mut columns = ViewColumns::from_blueprint(ctx, view_id)?;
+        let filter_property = ViewProperty::from_archetype::<TextLogFilter>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        let mut hidden_levels = filter_property.component_array_or_empty::<TextLogLevel>()?;
 
         ui.selection_grid("log_config").show(ui, |ui| {
             ui.grid_left_hand_label("Columns");
             ui.vertical(|ui| {
-                for (timeline, visible) in col_timelines {
-                    ui.re_checkbox(visible, timeline.to_string());
+                if columns_ui(ctx, ui, &mut columns) {
+                    columns.save_to_blueprint(ctx);
                 }
-                ui.re_checkbox(col_entity_path, "Entity path");
-                ui.re_checkbox(col_log_level, "Log level");
             });
             ui.end_row();
 
             ui.grid_left_hand_label("Level Filter");
             ui.vertical(|ui| {
-                for (log_level, visible) in row_log_levels {
-                    ui.re_checkbox(visible, level_to_rich_text(ui, log_level));
+                for log_level in &state.levels {
+                    let mut visible = !hidden_levels.contains(log_level);
+                    if ui
+                        .re_checkbox(&mut visible, level_to_rich_text(ui, log_level))
+                        .changed()
+                    {
+                        if visible {
+                            hidden_levels.retain(|level| level != log_level);
+                        } else {
+                            hidden_levels.push(log_level.clone());
+                        }
+                        filter_property.save_blueprint_component(ctx, &hidden_levels);
+                    }
                 }
             });
             ui.end_row();
@@ -157,7 +188,7 @@ Filter message types and toggle column visibility in a selection panel.",
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
 
-        _query: &ViewQuery<'_>,
+        query: &ViewQuery<'_>,
         system_output: re_viewer_context::SystemExecutionOutput,
     ) -> Result<(), ViewSystemExecutionError> {
         re_tracing::profile_function!();
@@ -165,25 +196,38 @@ Filter message types and toggle column visibility in a selection panel.",
         let state = state.downcast_mut::<TextViewState>()?;
         let text = system_output.view_systems.get::<TextLogSystem>()?;
 
-        // TODO(andreas): Should filter text entries in the part-system instead.
-        // this likely requires a way to pass state into a context.
-        let entries = text
-            .entries
-            .iter()
-            .filter(|te| {
-                te.level
-                    .as_ref()
-                    .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
-            })
-            .collect::<Vec<_>>();
+        // Remember the levels for the level filter in the selection panel.
+        state.levels.extend(text.levels.iter().cloned());
+
+        let filter_property = ViewProperty::from_archetype::<TextLogFilter>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.view_id,
+        );
+        let search_query = filter_property.component_or_fallback::<Text>(ctx, text, state)?;
+        let search_mode =
+            filter_property.component_or_fallback::<TextSearchMode>(ctx, text, state)?;
+        let case_sensitive =
+            filter_property.component_or_fallback::<CaseSensitive>(ctx, text, state)?;
+
+        let columns = ViewColumns::from_blueprint(ctx, query.view_id)?;
 
         egui::Frame {
             inner_margin: re_ui::DesignTokens::view_padding().into(),
             ..egui::Frame::default()
         }
         .show(ui, |ui| {
-            // Update filters if necessary.
-            state.filters.update(ctx, &entries);
+            search_ui(
+                ctx,
+                ui,
+                &filter_property,
+                text,
+                search_query.as_str(),
+                search_mode,
+                **case_sensitive,
+            );
+
+            let entries = &text.entries;
 
             let time = ctx
                 .rec_cfg
@@ -206,7 +250,7 @@ Filter message types and toggle column visibility in a selection panel.",
             ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
                 egui::ScrollArea::horizontal().show(ui, |ui| {
                     re_tracing::profile_scope!("render table");
-                    table_ui(ctx, ui, state, &entries, scroll_to_row);
+                    table_ui(ctx, ui, state, columns, entries, scroll_to_row);
                 })
             });
         });
@@ -215,58 +259,163 @@ Filter message types and toggle column visibility in a selection panel.",
     }
 }
 
-// --- Filters ---
+// --- Search ---
 
-// TODO(cmc): implement "body contains <value>" filter.
-// TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ViewTextFilters {
-    // Column filters: which columns should be visible?
-    // Timelines are special: each one has a dedicated column.
-    pub col_timelines: BTreeMap<TimelineName, bool>,
-    pub col_entity_path: bool,
-    pub col_log_level: bool,
+fn search_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    filter_property: &ViewProperty,
+    text: &TextLogSystem,
+    search_query: &str,
+    search_mode: TextSearchMode,
+    case_sensitive: bool,
+) {
+    ui.horizontal(|ui| {
+        let mut edited_query = search_query.to_owned();
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut edited_query)
+                .hint_text("Search entry bodies")
+                .desired_width(240.0),
+        );
+        if response.changed() {
+            filter_property.save_blueprint_component(ctx, &Text::from(edited_query));
+        }
+        let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
 
-    // Row filters: which rows should be visible?
-    pub row_log_levels: BTreeMap<TextLogLevel, bool>,
-}
+        if ui
+            .selectable_label(case_sensitive, "Aa")
+            .on_hover_text("Match case")
+            .clicked()
+        {
+            filter_property.save_blueprint_component(ctx, &CaseSensitive::from(!case_sensitive));
+        }
 
-impl Default for ViewTextFilters {
-    fn default() -> Self {
-        Self {
-            col_entity_path: true,
-            col_log_level: true,
-            col_timelines: Default::default(),
-            row_log_levels: Default::default(),
+        let is_regex = search_mode == TextSearchMode::Regex;
+        if ui
+            .selectable_label(is_regex, ".*")
+            .on_hover_text("Use regular expression")
+            .clicked()
+        {
+            let search_mode = if is_regex {
+                TextSearchMode::Plain
+            } else {
+                TextSearchMode::Regex
+            };
+            filter_property.save_blueprint_component(ctx, &search_mode);
         }
-    }
+
+        if let Some(err) = &text.search_error {
+            ui.error_label("Invalid regular expression")
+                .on_hover_text(err.to_string());
+        } else if text.is_searching {
+            let current_time = ctx.rec_cfg.time_ctrl.read().time_int();
+            let (previous_match, next_match) = adjacent_matches(&text.entries, current_time);
+
+            if ui
+                .add_enabled(
+                    previous_match.is_some(),
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_UP),
+                )
+                .on_hover_text("Jump to the previous match")
+                .clicked()
+            {
+                jump_to_time(ctx, previous_match);
+            }
+            if ui
+                .add_enabled(
+                    next_match.is_some(),
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_DOWN),
+                )
+                .on_hover_text("Jump to the next match")
+                .clicked()
+                || enter_pressed
+            {
+                jump_to_time(ctx, next_match);
+            }
+
+            let num_matches = text.entries.len();
+            ui.label(format!(
+                "{num_matches} matching {}",
+                if num_matches == 1 { "entry" } else { "entries" }
+            ));
+        }
+    });
 }
 
-impl ViewTextFilters {
-    pub fn is_log_level_visible(&self, level: &str) -> bool {
-        self.row_log_levels.get(level).copied().unwrap_or(true)
-    }
+/// Times of the closest matching entries before and after the current time.
+///
+/// `entries` must be sorted by time.
+/// Without a current time, the first match is considered to be the next one.
+fn adjacent_matches(
+    entries: &[Entry],
+    current_time: Option<TimeInt>,
+) -> (Option<TimeInt>, Option<TimeInt>) {
+    // Static entries can't be jumped to. They sort before all temporal ones.
+    let entries = &entries[entries.partition_point(|entry| entry.time.is_static())..];
+
+    let Some(current_time) = current_time else {
+        return (None, entries.first().map(|entry| entry.time));
+    };
 
-    // Checks whether new values are available for any of the filters, and updates everything
-    // accordingly.
-    fn update(&mut self, ctx: &ViewerContext<'_>, entries: &[&Entry]) {
-        re_tracing::profile_function!();
+    let num_before = entries.partition_point(|entry| entry.time < current_time);
+    let num_until = entries.partition_point(|entry| entry.time <= current_time);
 
-        let Self {
-            col_timelines,
-            col_entity_path: _,
-            col_log_level: _,
-            row_log_levels,
-        } = self;
+    (
+        num_before.checked_sub(1).map(|index| entries[index].time),
+        entries.get(num_until).map(|entry| entry.time),
+    )
+}
 
-        for &timeline in ctx.recording().timelines().keys() {
-            col_timelines.entry(timeline).or_insert(true);
-        }
+fn jump_to_time(ctx: &ViewerContext<'_>, time: Option<TimeInt>) {
+    if let Some(time) = time {
+        let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+        time_ctrl.set_time(time);
+        time_ctrl.pause();
+    }
+}
 
-        for level in entries.iter().filter_map(|te| te.level.as_ref()) {
-            row_log_levels.entry(level.clone()).or_insert(true);
-        }
+// --- Columns ---
+
+/// Shows a checkbox and move buttons for every column.
+///
+/// Returns true if any column changed.
+fn columns_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, columns: &mut ViewColumns) -> bool {
+    // Columns of timelines that don't exist in this recording are kept, but not shown.
+    let available = columns.available(ctx);
+
+    let mut changed = false;
+    for (position, &index) in available.iter().enumerate() {
+        ui.horizontal(|ui| {
+            let column = &mut columns.columns[index];
+            let label = column_label(column).to_owned();
+            changed |= ui.re_checkbox(&mut column.visible.0, label).changed();
+
+            if ui
+                .add_enabled(
+                    position > 0,
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_UP),
+                )
+                .on_hover_text("Move column to the left")
+                .clicked()
+            {
+                columns.columns.swap(index, available[position - 1]);
+                changed = true;
+            }
+            if ui
+                .add_enabled(
+                    position + 1 < available.len(),
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_DOWN),
+                )
+                .on_hover_text("Move column to the right")
+                .clicked()
+            {
+                columns.columns.swap(index, available[position + 1]);
+                changed = true;
+            }
+        });
     }
+
+    changed
 }
 
 // ---
@@ -277,25 +426,28 @@ impl ViewTextFilters {
 fn table_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
-    state: &TextViewState,
-    entries: &[&Entry],
+    state: &mut TextViewState,
+    mut columns: ViewColumns,
+    entries: &[Entry],
     scroll_to_row: Option<usize>,
 ) {
-    let timelines = state
-        .filters
-        .col_timelines
-        .iter()
-        .filter_map(|(timeline, visible)| visible.then_some(timeline))
-        .collect::<Vec<_>>();
-
     use egui_extras::Column;
 
+    let shown_columns = columns.shown(ctx);
+
     let (global_timeline, global_time) = {
         let time_ctrl = ctx.rec_cfg.time_ctrl.read();
         (*time_ctrl.timeline(), time_ctrl.time_int())
     };
 
     let mut table_builder = egui_extras::TableBuilder::new(ui)
+        // Column state is kept per index, so start from scratch whenever the columns are rearranged.
+        .id_salt(
+            shown_columns
+                .iter()
+                .map(|column| (column.kind as u8, column.timeline.as_str()))
+                .collect::<Vec<_>>(),
+        )
         .resizable(true)
         .vscroll(true)
         .auto_shrink([false; 2]) // expand to take up the whole View
@@ -310,43 +462,50 @@ fn table_ui(
     let mut body_clip_rect = None;
     let mut current_time_y = None; // where to draw the current time indicator cursor
 
-    {
-        // timeline(s)
-        table_builder =
-            table_builder.columns(Column::auto().clip(true).at_least(32.0), timelines.len());
-
-        // entity path
-        if state.filters.col_entity_path {
-            table_builder = table_builder.column(Column::auto().clip(true).at_least(32.0));
-        }
-        // log level
-        if state.filters.col_log_level {
-            table_builder = table_builder.column(Column::auto().at_least(30.0));
-        }
-        // body
-        table_builder = table_builder.column(Column::remainder().at_least(100.0));
+    for (index, column) in shown_columns.iter().enumerate() {
+        let min_width = match column.kind {
+            TextLogColumnKind::Timeline | TextLogColumnKind::EntityPath => 32.0,
+            TextLogColumnKind::LogLevel => 30.0,
+            TextLogColumnKind::Body => 100.0,
+        };
+
+        // The last column takes up whatever space is left.
+        let table_column = if index + 1 == shown_columns.len() {
+            Column::remainder()
+        } else if let Some(width) = column.width {
+            Column::initial(width.0).clip(true)
+        } else {
+            Column::auto().clip(column.kind != TextLogColumnKind::LogLevel)
+        };
+        table_builder = table_builder.column(table_column.at_least(min_width));
     }
+
+    let mut column_widths = Vec::with_capacity(shown_columns.len());
+
     table_builder
         .header(re_ui::DesignTokens::table_header_height(), |mut header| {
             re_ui::DesignTokens::setup_table_header(&mut header);
-            for timeline in &timelines {
-                header.col(|ui| {
-                    item_ui::timeline_button(ctx, ui, timeline);
-                });
-            }
-            if state.filters.col_entity_path {
-                header.col(|ui| {
-                    ui.strong("Entity path");
-                });
-            }
-            if state.filters.col_log_level {
-                header.col(|ui| {
-                    ui.strong("Level");
+            for column in &shown_columns {
+                let (rect, _) = header.col(|ui| match column.kind {
+                    TextLogColumnKind::Timeline => {
+                        item_ui::timeline_button(
+                            ctx,
+                            ui,
+                            &TimelineName::new(column.timeline.as_str()),
+                        );
+                    }
+                    TextLogColumnKind::EntityPath => {
+                        ui.strong("Entity path");
+                    }
+                    TextLogColumnKind::LogLevel => {
+                        ui.strong("Level");
+                    }
+                    TextLogColumnKind::Body => {
+                        ui.strong("Body");
+                    }
                 });
+                column_widths.push(rect.width());
             }
-            header.col(|ui| {
-                ui.strong("Body");
-            });
         })
         .body(|mut body| {
             re_ui::DesignTokens::setup_table_body(&mut body);
@@ -355,77 +514,71 @@ fn table_ui(
 
             let query = ctx.current_query();
 
-            let row_heights = entries.iter().map(|te| calc_row_height(te));
+            let row_heights = entries.iter().map(calc_row_height);
             body.heterogeneous_rows(row_heights, |mut row| {
                 let entry = &entries[row.index()];
 
-                // timeline(s)
-                for &timeline in &timelines {
-                    row.col(|ui| {
-                        let row_time = entry
-                            .timepoint
-                            .get(timeline)
-                            .map(re_log_types::TimeInt::from)
-                            .unwrap_or(re_log_types::TimeInt::STATIC);
-                        item_ui::time_button(ctx, ui, timeline, row_time);
-
-                        if let Some(global_time) = global_time {
-                            if timeline == global_timeline.name() {
-                                #[allow(clippy::comparison_chain)]
-                                if global_time < row_time {
-                                    // We've past the global time - it is thus above this row.
-                                    if current_time_y.is_none() {
-                                        current_time_y = Some(ui.max_rect().top());
+                for column in &shown_columns {
+                    match column.kind {
+                        TextLogColumnKind::Timeline => {
+                            let timeline = TimelineName::new(column.timeline.as_str());
+                            row.col(|ui| {
+                                let row_time = entry
+                                    .timepoint
+                                    .get(&timeline)
+                                    .map(re_log_types::TimeInt::from)
+                                    .unwrap_or(re_log_types::TimeInt::STATIC);
+                                item_ui::time_button(ctx, ui, &timeline, row_time);
+
+                                if let Some(global_time) = global_time {
+                                    if timeline == *global_timeline.name() {
+                                        #[allow(clippy::comparison_chain)]
+                                        if global_time < row_time {
+                                            // We've past the global time - it is thus above this row.
+                                            if current_time_y.is_none() {
+                                                current_time_y = Some(ui.max_rect().top());
+                                            }
+                                        } else if global_time == row_time {
+                                            // This row is exactly at the current time.
+                                            // We could draw the current time exactly onto this row, but that would look bad,
+                                            // so let's draw it under instead. It looks better in the "following" mode.
+                                            current_time_y = Some(ui.max_rect().bottom());
+                                        }
                                     }
-                                } else if global_time == row_time {
-                                    // This row is exactly at the current time.
-                                    // We could draw the current time exactly onto this row, but that would look bad,
-                                    // so let's draw it under instead. It looks better in the "following" mode.
-                                    current_time_y = Some(ui.max_rect().bottom());
                                 }
-                            }
+                            });
                         }
-                    });
-                }
-
-                // path
-                if state.filters.col_entity_path {
-                    row.col(|ui| {
-                        item_ui::entity_path_button(
-                            ctx,
-                            &query,
-                            ctx.recording(),
-                            ui,
-                            None,
-                            &entry.entity_path,
-                        );
-                    });
-                }
 
-                // level
-                if state.filters.col_log_level {
-                    row.col(|ui| {
-                        if let Some(lvl) = &entry.level {
-                            ui.label(level_to_rich_text(ui, lvl));
-                        } else {
-                            ui.label("-");
+                        TextLogColumnKind::EntityPath => {
+                            row.col(|ui| {
+                                item_ui::entity_path_button(
+                                    ctx,
+                                    &query,
+                                    ctx.recording(),
+                                    ui,
+                                    None,
+                                    &entry.entity_path,
+                                );
+                            });
                         }
-                    });
-                }
 
-                // body
-                row.col(|ui| {
-                    let mut text = egui::RichText::new(entry.body.as_str());
+                        TextLogColumnKind::LogLevel => {
+                            row.col(|ui| {
+                                if let Some(lvl) = &entry.level {
+                                    ui.label(level_to_rich_text(ui, lvl));
+                                } else {
+                                    ui.label("-");
+                                }
+                            });
+                        }
 
-                    if state.monospace {
-                        text = text.monospace();
-                    }
-                    if let Some(color) = entry.color {
-                        text = text.color(color);
+                        TextLogColumnKind::Body => {
+                            row.col(|ui| {
+                                body_ui(ui, entry, state.monospace);
+                            });
+                        }
                     }
-
-                    ui.label(text);
-                });
+                }
             });
         });
 
@@ -438,6 +591,91 @@ fn table_ui(
             (1.0, egui::Color32::WHITE),
         );
     }
+
+    // The last column fills the remaining space, so its width is not worth remembering.
+    column_widths.pop();
+    update_column_widths(ctx, ui, state, &mut columns, &shown_columns, column_widths);
+}
+
+/// Writes column widths to the blueprint once the user is done resizing them.
+fn update_column_widths(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    state: &mut TextViewState,
+    columns: &mut ViewColumns,
+    shown_columns: &[TextLogColumn],
+    column_widths: Vec<f32>,
+) {
+    let is_dragging = ui.input(|i| i.pointer.primary_down());
+    let widths_changed = state.column_widths.len() == column_widths.len()
+        && state
+            .column_widths
+            .iter()
+            .zip(&column_widths)
+            .any(|(previous, current)| (previous - current).abs() > 0.5);
+
+    if is_dragging {
+        if widths_changed {
+            state.pending_column_widths = Some(column_widths.clone());
+        }
+    } else if let Some(pending_column_widths) = state.pending_column_widths.take() {
+        for (shown_column, width) in shown_columns.iter().zip(pending_column_widths) {
+            if let Some(column) = columns.columns.iter_mut().find(|column| {
+                column.kind == shown_column.kind && column.timeline == shown_column.timeline
+            }) {
+                column.width = Some(width.into());
+            }
+        }
+        columns.save_to_blueprint(ctx);
+    }
+
+    state.column_widths = column_widths;
+}
+
+/// Shows the body of an entry, with all search matches highlighted.
+fn body_ui(ui: &mut egui::Ui, entry: &Entry, monospace: bool) {
+    if entry.matches.is_empty() {
+        let mut text = egui::RichText::new(entry.body.as_str());
+
+        if monospace {
+            text = text.monospace();
+        }
+        if let Some(color) = entry.color {
+            text = text.color(color);
+        }
+
+        ui.label(text);
+        return;
+    }
+
+    let text_style = if monospace {
+        egui::TextStyle::Monospace
+    } else {
+        egui::TextStyle::Body
+    };
+    let format = egui::TextFormat::simple(
+        text_style.resolve(ui.style()),
+        entry
+            .color
+            .map_or_else(|| ui.visuals().text_color(), Into::into),
+    );
+    let highlight_format = egui::TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        color: ui.visuals().selection.stroke.color,
+        ..format.clone()
+    };
+
+    let body = entry.body.as_str();
+    let mut job = egui::text::LayoutJob::default();
+    let mut cursor = 0;
+    for range in &entry.matches {
+        job.append(&body[cursor..range.start], 0.0, format.clone());
+        job.append(&body[range.clone()], 0.0, highlight_format.clone());
+        cursor = range.end;
+    }
+    job.append(&body[cursor..], 0.0, format);
+
+    ui.label(job);
 }
 
 fn calc_row_height(entry: &Entry) -> f32 {
@@ -1,3 +1,6 @@
+use std::collections::BTreeSet;
+use std::ops::Range;
+
 use itertools::izip;
 use re_chunk_store::ResolvedTimeRange;
 use re_entity_db::EntityPath;
@@ -6,6 +9,10 @@ use re_log_types::TimePoint;
 use re_query::{clamped_zip_1x2, range_zip_1x2};
 use re_types::{
     archetypes::TextLog,
+    blueprint::{
+        archetypes::TextLogFilter,
+        components::{CaseSensitive, TextSearchMode},
+    },
     components::{Color, Text, TextLogLevel},
     Component as _,
 };
@@ -14,6 +21,9 @@ use re_viewer_context::{
     IdentifiedViewSystem, ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError,
     VisualizerQueryInfo, VisualizerSystem,
 };
+use re_viewport_blueprint::ViewProperty;
+
+use crate::search::TextSearch;
 
 #[derive(Debug, Clone)]
 pub struct Entry {
@@ -23,12 +33,32 @@ pub struct Entry {
     pub color: Option<Color>,
     pub body: Text,
     pub level: Option<TextLogLevel>,
+
+    /// Byte ranges of the search matches within [`Self::body`].
+    pub matches: Vec<Range<usize>>,
 }
 
 /// A text scene, with everything needed to render it.
 #[derive(Default)]
 pub struct TextLogSystem {
+    /// All entries that pass the view's [`TextLogFilter`], sorted by time.
     pub entries: Vec<Entry>,
+
+    /// All log levels encountered, including those of entries that were filtered out.
+    pub levels: BTreeSet<TextLogLevel>,
+
+    /// Whether entries are filtered by a search query.
+    pub is_searching: bool,
+
+    /// Set if the search query of the view is not a valid regular expression.
+    pub search_error: Option<regex::Error>,
+}
+
+/// The resolved [`TextLogFilter`] of a view.
+#[derive(Default)]
+struct EntryFilter {
+    search: Option<TextSearch>,
+    hidden_levels: BTreeSet<TextLogLevel>,
 }
 
 impl IdentifiedViewSystem for TextLogSystem {
@@ -50,12 +80,14 @@ impl VisualizerSystem for TextLogSystem {
     ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
         re_tracing::profile_function!();
 
+        let filter = self.entry_filter(ctx, view_query)?;
+
         let query =
             re_chunk_store::RangeQuery::new(view_query.timeline, ResolvedTimeRange::EVERYTHING)
                 .keep_extra_timelines(true);
 
         for data_result in view_query.iter_visible_data_results(Self::identifier()) {
-            self.process_entity(ctx, &query, data_result);
+            self.process_entity(ctx, &query, data_result, &filter);
         }
 
         {
@@ -77,11 +109,52 @@ impl VisualizerSystem for TextLogSystem {
 }
 
 impl TextLogSystem {
+    fn entry_filter(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+    ) -> Result<EntryFilter, ViewSystemExecutionError> {
+        let property = ViewProperty::from_archetype::<TextLogFilter>(
+            ctx.blueprint_db(),
+            ctx.viewer_ctx.blueprint_query,
+            view_query.view_id,
+        );
+
+        let query = property.component_or_fallback::<Text>(ctx.viewer_ctx, self, ctx.view_state)?;
+        let search_mode = property.component_or_fallback::<TextSearchMode>(
+            ctx.viewer_ctx,
+            self,
+            ctx.view_state,
+        )?;
+        let case_sensitive = property.component_or_fallback::<CaseSensitive>(
+            ctx.viewer_ctx,
+            self,
+            ctx.view_state,
+        )?;
+        let hidden_levels = property.component_array_or_empty::<TextLogLevel>()?;
+
+        let search = match TextSearch::new(query.as_str(), search_mode, **case_sensitive) {
+            Ok(search) => search,
+            Err(err) => {
+                // Don't filter by an invalid query, but let the user know what's wrong with it.
+                self.search_error = Some(err);
+                None
+            }
+        };
+        self.is_searching = search.is_some();
+
+        Ok(EntryFilter {
+            search,
+            hidden_levels: hidden_levels.into_iter().collect(),
+        })
+    }
+
     fn process_entity(
         &mut self,
         ctx: &ViewContext<'_>,
         query: &re_chunk_store::RangeQuery,
         data_result: &re_viewer_context::DataResult,
+        filter: &EntryFilter,
     ) {
         re_tracing::profile_function!();
 
@@ -133,13 +206,31 @@ impl TextLogSystem {
                 clamped_zip_1x2(bodies, levels, level_default_fn, colors, color_default_fn);
 
             for (text, level, color) in results {
+                let level: Option<TextLogLevel> = level.clone().map(Into::into);
+                if let Some(level) = &level {
+                    self.levels.insert(level.clone());
+                    if filter.hidden_levels.contains(level) {
+                        continue;
+                    }
+                }
+
+                let matches = if let Some(search) = &filter.search {
+                    let Some(matches) = search.find_matches(text.as_str()) else {
+                        continue;
+                    };
+                    matches
+                } else {
+                    Vec::new()
+                };
+
                 self.entries.push(Entry {
                     entity_path: data_result.entity_path.clone(),
                     time: data_time,
                     timepoint: timepoint.clone(),
                     color,
                     body: text.clone().into(),
-                    level: level.clone().map(Into::into),
+                    level,
+                    matches,
                 });
             }
         }
@@ -268,6 +268,14 @@ impl App {
             command_sender.send_ui(UICommand::ExpandBlueprintPanel);
         }
 
+        let mut store_hub = StoreHub::new(
+            blueprint_loader(),
+            &crate::app_blueprint::setup_welcome_screen_blueprint,
+        );
+        if let Some(retention) = startup_options.retention.clone() {
+            store_hub.set_retention_policy(retention);
+        }
+
         Self {
             main_thread_token,
             build_info,
@@ -291,10 +299,7 @@ impl App {
             open_files_promise: Default::default(),
             state,
             background_tasks: Default::default(),
-            store_hub: Some(StoreHub::new(
-                blueprint_loader(),
-                &crate::app_blueprint::setup_welcome_screen_blueprint,
-            )),
+            store_hub: Some(store_hub),
             notifications: notifications::NotificationUi::new(),
 
             memory_panel: Default::default(),
@@ -2173,6 +2178,8 @@ impl eframe::App for App {
             store_hub.gc_blueprints(&self.state.blueprint_undo_state);
         }
 
+        store_hub.enforce_retention();
+
         store_hub.purge_empty();
         self.state.cleanup(&store_hub);
 
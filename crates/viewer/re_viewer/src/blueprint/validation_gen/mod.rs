@@ -9,6 +9,7 @@ pub use re_types::blueprint::components::ApplyLatestAt;
 pub use re_types::blueprint::components::AutoLayout;
 pub use re_types::blueprint::components::AutoViews;
 pub use re_types::blueprint::components::BackgroundKind;
+pub use re_types::blueprint::components::CaseSensitive;
 pub use re_types::blueprint::components::ColumnShare;
 pub use re_types::blueprint::components::ComponentColumnSelector;
 pub use re_types::blueprint::components::ContainerKind;
@@ -19,19 +20,30 @@ pub use re_types::blueprint::components::FilterIsNotNull;
 pub use re_types::blueprint::components::ForceDistance;
 pub use re_types::blueprint::components::ForceIterations;
 pub use re_types::blueprint::components::ForceStrength;
+pub use re_types::blueprint::components::GraphLayoutAlgorithm;
+pub use re_types::blueprint::components::GraphLayoutDirection;
 pub use re_types::blueprint::components::GridColumns;
 pub use re_types::blueprint::components::GridSpacing;
 pub use re_types::blueprint::components::IncludedContent;
 pub use re_types::blueprint::components::LockRangeDuringZoom;
 pub use re_types::blueprint::components::MapProvider;
+pub use re_types::blueprint::components::MaxRowsPerEntity;
 pub use re_types::blueprint::components::NearClipPlane;
 pub use re_types::blueprint::components::PanelState;
 pub use re_types::blueprint::components::QueryExpression;
+pub use re_types::blueprint::components::RetentionWindow;
 pub use re_types::blueprint::components::RootContainer;
 pub use re_types::blueprint::components::RowShare;
 pub use re_types::blueprint::components::SelectedColumns;
 pub use re_types::blueprint::components::TensorDimensionIndexSlider;
+pub use re_types::blueprint::components::TextLogColumn;
+pub use re_types::blueprint::components::TextSearchMode;
+pub use re_types::blueprint::components::TileAttribution;
+pub use re_types::blueprint::components::TileMaxZoom;
+pub use re_types::blueprint::components::TileSourcePath;
+pub use re_types::blueprint::components::TileUrlTemplate;
 pub use re_types::blueprint::components::TimelineName;
+pub use re_types::blueprint::components::TransformInterpolation;
 pub use re_types::blueprint::components::ViewClass;
 pub use re_types::blueprint::components::ViewFit;
 pub use re_types::blueprint::components::ViewMaximized;
@@ -51,6 +63,7 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<AutoLayout>(blueprint)
         && validate_component::<AutoViews>(blueprint)
         && validate_component::<BackgroundKind>(blueprint)
+        && validate_component::<CaseSensitive>(blueprint)
         && validate_component::<ColumnShare>(blueprint)
         && validate_component::<ComponentColumnSelector>(blueprint)
         && validate_component::<ContainerKind>(blueprint)
@@ -61,19 +74,30 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<ForceDistance>(blueprint)
         && validate_component::<ForceIterations>(blueprint)
         && validate_component::<ForceStrength>(blueprint)
+        && validate_component::<GraphLayoutAlgorithm>(blueprint)
+        && validate_component::<GraphLayoutDirection>(blueprint)
         && validate_component::<GridColumns>(blueprint)
         && validate_component::<GridSpacing>(blueprint)
         && validate_component::<IncludedContent>(blueprint)
         && validate_component::<LockRangeDuringZoom>(blueprint)
         && validate_component::<MapProvider>(blueprint)
+        && validate_component::<MaxRowsPerEntity>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
         && validate_component::<PanelState>(blueprint)
         && validate_component::<QueryExpression>(blueprint)
+        && validate_component::<RetentionWindow>(blueprint)
         && validate_component::<RootContainer>(blueprint)
         && validate_component::<RowShare>(blueprint)
         && validate_component::<SelectedColumns>(blueprint)
         && validate_component::<TensorDimensionIndexSlider>(blueprint)
+        && validate_component::<TextLogColumn>(blueprint)
+        && validate_component::<TextSearchMode>(blueprint)
+        && validate_component::<TileAttribution>(blueprint)
+        && validate_component::<TileMaxZoom>(blueprint)
+        && validate_component::<TileSourcePath>(blueprint)
+        && validate_component::<TileUrlTemplate>(blueprint)
         && validate_component::<TimelineName>(blueprint)
+        && validate_component::<TransformInterpolation>(blueprint)
         && validate_component::<ViewClass>(blueprint)
         && validate_component::<ViewFit>(blueprint)
         && validate_component::<ViewMaximized>(blueprint)
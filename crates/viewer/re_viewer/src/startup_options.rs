@@ -7,6 +7,13 @@ pub struct StartupOptions {
     /// When the total process RAM reaches this limit, we GC old data.
     pub memory_limit: re_memory::MemoryLimit,
 
+    /// Retention rules for all recordings, e.g. set via the `--retention-*` command-line options.
+    ///
+    /// The rules that are set here take precedence over those of the blueprint, which in turn take
+    /// precedence over the `RERUN_RETENTION_*` environment variables
+    /// (see [`re_chunk_store::ChunkStoreConfig::from_env`]).
+    pub retention: Option<re_chunk_store::RetentionPolicy>,
+
     pub persist_state: bool,
 
     /// Whether or not the app is running in the context of a Jupyter Notebook.
@@ -84,6 +91,7 @@ impl Default for StartupOptions {
     fn default() -> Self {
         Self {
             memory_limit: re_memory::MemoryLimit::from_fraction_of_total(0.75),
+            retention: None,
             persist_state: true,
             is_in_notebook: false,
 
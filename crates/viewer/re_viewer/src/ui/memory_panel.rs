@@ -291,6 +291,8 @@ impl MemoryPanel {
                                     total_chunks,
                                     total_effective_size_bytes,
                                     total_actual_size_bytes,
+                                    hits: _,
+                                    misses: _,
                                 } = stats;
 
                                 ui.label(cache_key.entity_path.to_string());
@@ -329,6 +331,8 @@ impl MemoryPanel {
                                     total_chunks,
                                     total_effective_size_bytes,
                                     total_actual_size_bytes,
+                                    hits: _,
+                                    misses: _,
                                 } = stats;
 
                                 ui.label(cache_key.entity_path.to_string());
@@ -709,6 +709,7 @@ fn create_app(
             // On wasm32 we only have 4GB of memory to play around with.
             max_bytes: Some(2_500_000_000),
         },
+        retention: None,
         location: Some(cc.integration_info.web_info.location.clone()),
         persist_state: persist.unwrap_or(true),
         is_in_notebook: notebook.unwrap_or(false),
@@ -6,7 +6,7 @@ use itertools::Itertools as _;
 use nohash_hasher::IntMap;
 use re_chunk_store::{
     ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreStats, GarbageCollectionOptions,
-    GarbageCollectionTarget,
+    GarbageCollectionTarget, LatestAtQuery, RetentionPolicy, RetentionTimeWindow,
 };
 use re_entity_db::EntityDb;
 use re_log_types::{ApplicationId, ResolvedTimeRange, StoreId, StoreKind, TableId};
@@ -102,6 +102,22 @@ pub struct StoreHub {
 
     /// The [`ChunkStoreGeneration`] from when the [`EntityDb`] was last garbage collected
     blueprint_last_gc: HashMap<StoreId, ChunkStoreGeneration>,
+
+    /// Retention rules that take precedence over those of blueprints, e.g. from the CLI.
+    retention: Option<RetentionPolicy>,
+
+    /// The retention rules from the `RERUN_RETENTION_*` environment variables, which blueprints
+    /// and [`Self::retention`] override.
+    env_retention: RetentionPolicy,
+
+    /// The [`RetentionPolicy`] that was last applied to each recording.
+    ///
+    /// Recordings that are missing use whatever their [`ChunkStoreConfig`] specifies, until either
+    /// their blueprint or [`Self::retention`] configures retention.
+    recording_retention: HashMap<StoreId, RetentionPolicy>,
+
+    /// The [`ChunkStoreGeneration`] and time from when the retention policy of a recording was last enforced
+    recording_last_retention: HashMap<StoreId, (ChunkStoreGeneration, web_time::Instant)>,
 }
 
 /// Load a blueprint from persisted storage, e.g. disk.
@@ -204,6 +220,10 @@ impl StoreHub {
             caches_per_recording: Default::default(),
             blueprint_last_save: Default::default(),
             blueprint_last_gc: Default::default(),
+            retention: None,
+            env_retention: ChunkStoreConfig::from_env().unwrap_or_default().retention,
+            recording_retention: Default::default(),
+            recording_last_retention: Default::default(),
 
             table_stores,
         }
@@ -844,6 +864,121 @@ impl StoreHub {
         }
     }
 
+    /// Retention is enforced at most this often on any given recording.
+    ///
+    /// Finding the data that falls outside of a [`RetentionPolicy`] requires a full pass over the
+    /// store's indices, which is too costly to do every frame while data is streaming in.
+    const RETENTION_INTERVAL: web_time::Duration = web_time::Duration::from_millis(500);
+
+    /// Overrides the retention rules of all current and future recordings.
+    ///
+    /// The rules that are set in `retention` take precedence over those of the blueprint, which in
+    /// turn take precedence over the `RERUN_RETENTION_*` environment variables.
+    /// They are applied on the next call to [`Self::enforce_retention`].
+    pub fn set_retention_policy(&mut self, retention: RetentionPolicy) {
+        self.retention = Some(retention);
+    }
+
+    /// The retention rules that apply to `recording`, if anything overrides those of its store.
+    fn recording_retention_policy(&self, recording: &EntityDb) -> Option<RetentionPolicy> {
+        let blueprint_retention = recording
+            .app_id()
+            .and_then(|app_id| {
+                self.active_blueprint_for_app(app_id)
+                    .or_else(|| self.default_blueprint_for_app(app_id))
+            })
+            .map(blueprint_retention_policy)
+            .filter(|retention| !retention.is_none());
+
+        if blueprint_retention.is_none() && self.retention.is_none() {
+            // Nothing overrides the environment, but a blueprint may have done so in the past.
+            let store_id = recording.store_id();
+            return self
+                .recording_retention
+                .contains_key(&store_id)
+                .then(|| self.env_retention.clone());
+        }
+
+        let mut retention = self.env_retention.clone();
+        if let Some(blueprint_retention) = &blueprint_retention {
+            retention = retention.overridden_by(blueprint_retention);
+        }
+        if let Some(cli_retention) = &self.retention {
+            retention = retention.overridden_by(cli_retention);
+        }
+
+        Some(retention)
+    }
+
+    /// Call [`EntityDb::enforce_retention`] on every recording that changed since last time.
+    ///
+    /// This is throttled to [`Self::RETENTION_INTERVAL`], so slightly more data than the
+    /// retention rules allow may be kept around in between two enforcements.
+    pub fn enforce_retention(&mut self) {
+        re_tracing::profile_function!();
+
+        self.recording_last_retention
+            .retain(|store_id, _| self.store_bundle.contains(store_id));
+        self.recording_retention
+            .retain(|store_id, _| self.store_bundle.contains(store_id));
+
+        // Blueprints can change at any time, so check whether the rules changed on every call.
+        let changed_policies = self
+            .store_bundle
+            .recordings()
+            .filter_map(|recording| {
+                let retention = self.recording_retention_policy(recording)?;
+                let store_id = recording.store_id();
+                (self.recording_retention.get(&store_id) != Some(&retention))
+                    .then_some((store_id, retention))
+            })
+            .collect_vec();
+        for (store_id, retention) in changed_policies {
+            if let Some(recording) = self.store_bundle.get(&store_id) {
+                recording.set_retention_policy(retention.clone());
+            }
+
+            // Forces the new policy to be enforced right away.
+            self.recording_last_retention.remove(&store_id);
+            self.recording_retention.insert(store_id, retention);
+        }
+
+        let now = web_time::Instant::now();
+
+        for recording in self
+            .store_bundle
+            .entity_dbs_mut()
+            .filter(|db| db.store_kind() == StoreKind::Recording)
+        {
+            let store_id = recording.store_id();
+            match self.recording_last_retention.get(&store_id) {
+                Some((generation, _)) if *generation == recording.generation() => {
+                    continue; // no change since last time
+                }
+
+                Some((_, enforced_at)) if now - *enforced_at < Self::RETENTION_INTERVAL => {
+                    continue; // too soon
+                }
+
+                Some(_) | None => {}
+            }
+
+            let store_events = recording.enforce_retention();
+            if !store_events.is_empty() {
+                re_log::trace!(
+                    num_chunks_dropped = store_events.len(),
+                    "Enforced retention policy on recording"
+                );
+                if let Some(caches) = self.caches_per_recording.get_mut(&store_id) {
+                    caches.on_store_events(&store_events);
+                }
+            }
+
+            self.recording_last_retention
+                .insert(store_id, (recording.generation(), now));
+        }
+    }
+
     /// See `re_viewer_context::Cache::begin_frame`.
     pub fn begin_frame(&mut self, renderer_active_frame_idx: u64) {
         if let Some(store_id) = self.active_recording_id().cloned() {
@@ -988,3 +1123,48 @@ impl StoreHub {
         }
     }
 }
+
+/// Where the [`RecordingRetention`](re_types::blueprint::archetypes::RecordingRetention) of an
+/// application lives in its blueprint.
+pub const RECORDING_RETENTION_PATH: &str = "retention";
+
+/// The retention rules that are set in `blueprint`, see [`RECORDING_RETENTION_PATH`].
+fn blueprint_retention_policy(blueprint: &EntityDb) -> RetentionPolicy {
+    use re_types::{
+        blueprint::components::{MaxRowsPerEntity, RetentionWindow, TimelineName},
+        components::EntityPath,
+        Component as _,
+    };
+
+    let results = blueprint.latest_at(
+        &LatestAtQuery::latest(crate::blueprint_timeline()),
+        &RECORDING_RETENTION_PATH.into(),
+        [
+            TimelineName::name(),
+            RetentionWindow::name(),
+            MaxRowsPerEntity::name(),
+            EntityPath::name(),
+        ],
+    );
+
+    let timeline = results.component_mono_quiet::<TimelineName>();
+    let window = results.component_mono_quiet::<RetentionWindow>();
+
+    RetentionPolicy {
+        time_window: timeline
+            .zip(window)
+            .map(|(timeline, window)| RetentionTimeWindow {
+                timeline: timeline.into(),
+                window: window.0 .0,
+            }),
+        max_rows_per_entity: results
+            .component_mono_quiet::<MaxRowsPerEntity>()
+            .map(|max_rows| max_rows.0 .0),
+        protected_entities: results
+            .component_batch_quiet::<EntityPath>()
+            .unwrap_or_default()
+            .iter()
+            .map(|entity_path| entity_path.as_str().into())
+            .collect(),
+    }
+}
@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/coordinate_frame.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/coordinate_frame.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Attaches an entity to a named coordinate frame.
+    ///
+    /// Entities in a named frame are placed by their frame rather than by their entity path parent:
+    /// the spatial views look up the transform from the frame of the view's origin to the frame of the entity
+    /// by searching through all transforms logged between named frames, see `parent_frame` & `child_frame`
+    /// on `archetypes::Transform3D`.
+    ///
+    /// The frame of an entity also applies to all its descendants that don't specify a frame of their own.
+    /// Any transform logged at the entity itself is interpreted relative to its frame.
+    /// If the view's origin isn't in a named frame, or the frames aren't connected, entities fall back to being
+    /// placed by their entity path.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CoordinateFrame {
+        /// The name of the frame this entity is in.
+        std::optional<ComponentBatch> frame;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.CoordinateFrameIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.CoordinateFrame";
+
+        /// `ComponentDescriptor` for the `frame` field.
+        static constexpr auto Descriptor_frame = ComponentDescriptor(
+            ArchetypeName, "frame",
+            Loggable<rerun::components::CoordinateFrame>::Descriptor.component_name
+        );
+
+      public:
+        CoordinateFrame() = default;
+        CoordinateFrame(CoordinateFrame&& other) = default;
+        CoordinateFrame(const CoordinateFrame& other) = default;
+        CoordinateFrame& operator=(const CoordinateFrame& other) = default;
+        CoordinateFrame& operator=(CoordinateFrame&& other) = default;
+
+        explicit CoordinateFrame(rerun::components::CoordinateFrame _frame)
+            : frame(ComponentBatch::from_loggable(std::move(_frame), Descriptor_frame)
+                        .value_or_throw()) {}
+
+        /// Update only some specific fields of a `CoordinateFrame`.
+        static CoordinateFrame update_fields() {
+            return CoordinateFrame();
+        }
+
+        /// Clear all the fields of a `CoordinateFrame`.
+        static CoordinateFrame clear_fields();
+
+        /// The name of the frame this entity is in.
+        CoordinateFrame with_frame(const rerun::components::CoordinateFrame& _frame) && {
+            frame = ComponentBatch::from_loggable(_frame, Descriptor_frame).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `frame` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_frame` should
+        /// be used when logging a single row's worth of data.
+        CoordinateFrame with_many_frame(
+            const Collection<rerun::components::CoordinateFrame>& _frame
+        ) && {
+            frame = ComponentBatch::from_loggable(_frame, Descriptor_frame).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::CoordinateFrame> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::CoordinateFrame& archetype
+        );
+    };
+} // namespace rerun
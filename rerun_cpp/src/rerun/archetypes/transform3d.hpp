@@ -8,6 +8,8 @@
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
 #include "../components/axis_length.hpp"
+#include "../components/child_frame.hpp"
+#include "../components/parent_frame.hpp"
 #include "../components/rotation_axis_angle.hpp"
 #include "../components/rotation_quat.hpp"
 #include "../components/scale3d.hpp"
@@ -38,6 +40,9 @@ namespace rerun::archetypes {
     ///
     /// For transforms that affect only a single entity and do not propagate along the entity tree refer to `archetypes::InstancePoses3D`.
     ///
+    /// By setting both `parent_frame` and `child_frame`, the transform instead connects two named coordinate frames,
+    /// regardless of the entity it is logged at. See `archetypes::CoordinateFrame` for how entities are placed in named frames.
+    ///
     /// ## Examples
     ///
     /// ### Variety of 3D transforms
@@ -319,6 +324,18 @@ namespace rerun::archetypes {
         /// Specifies the relation this transform establishes between this entity and its parent.
         std::optional<ComponentBatch> relation;
 
+        /// The frame this transform maps into.
+        ///
+        /// If both a parent and a child frame are set, this transform connects these two named frames
+        /// instead of applying to this entity and its descendants.
+        std::optional<ComponentBatch> parent_frame;
+
+        /// The frame this transform maps from.
+        ///
+        /// If both a parent and a child frame are set, this transform connects these two named frames
+        /// instead of applying to this entity and its descendants.
+        std::optional<ComponentBatch> child_frame;
+
         /// Visual length of the 3 axes.
         ///
         /// The length is interpreted in the local coordinate system of the transform.
@@ -363,6 +380,16 @@ namespace rerun::archetypes {
             ArchetypeName, "relation",
             Loggable<rerun::components::TransformRelation>::Descriptor.component_name
         );
+        /// `ComponentDescriptor` for the `parent_frame` field.
+        static constexpr auto Descriptor_parent_frame = ComponentDescriptor(
+            ArchetypeName, "parent_frame",
+            Loggable<rerun::components::ParentFrame>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `child_frame` field.
+        static constexpr auto Descriptor_child_frame = ComponentDescriptor(
+            ArchetypeName, "child_frame",
+            Loggable<rerun::components::ChildFrame>::Descriptor.component_name
+        );
         /// `ComponentDescriptor` for the `axis_length` field.
         static constexpr auto Descriptor_axis_length = ComponentDescriptor(
             ArchetypeName, "axis_length",
@@ -868,6 +895,50 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The frame this transform maps into.
+        ///
+        /// If both a parent and a child frame are set, this transform connects these two named frames
+        /// instead of applying to this entity and its descendants.
+        Transform3D with_parent_frame(const rerun::components::ParentFrame& _parent_frame) && {
+            parent_frame = ComponentBatch::from_loggable(_parent_frame, Descriptor_parent_frame)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `parent_frame` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_parent_frame` should
+        /// be used when logging a single row's worth of data.
+        Transform3D with_many_parent_frame(
+            const Collection<rerun::components::ParentFrame>& _parent_frame
+        ) && {
+            parent_frame = ComponentBatch::from_loggable(_parent_frame, Descriptor_parent_frame)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The frame this transform maps from.
+        ///
+        /// If both a parent and a child frame are set, this transform connects these two named frames
+        /// instead of applying to this entity and its descendants.
+        Transform3D with_child_frame(const rerun::components::ChildFrame& _child_frame) && {
+            child_frame = ComponentBatch::from_loggable(_child_frame, Descriptor_child_frame)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `child_frame` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_child_frame` should
+        /// be used when logging a single row's worth of data.
+        Transform3D with_many_child_frame(
+            const Collection<rerun::components::ChildFrame>& _child_frame
+        ) && {
+            child_frame = ComponentBatch::from_loggable(_child_frame, Descriptor_child_frame)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Visual length of the 3 axes.
         ///
         /// The length is interpreted in the local coordinate system of the transform.
@@ -11,16 +11,20 @@
 #include "blueprint/archetypes/force_link.hpp"
 #include "blueprint/archetypes/force_many_body.hpp"
 #include "blueprint/archetypes/force_position.hpp"
+#include "blueprint/archetypes/graph_layout.hpp"
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
 #include "blueprint/archetypes/plot_legend.hpp"
+#include "blueprint/archetypes/recording_retention.hpp"
 #include "blueprint/archetypes/scalar_axis.hpp"
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
 #include "blueprint/archetypes/tensor_slice_selection.hpp"
 #include "blueprint/archetypes/tensor_view_fit.hpp"
+#include "blueprint/archetypes/text_log_columns.hpp"
+#include "blueprint/archetypes/text_log_filter.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
 #include "blueprint/archetypes/viewport_blueprint.hpp"
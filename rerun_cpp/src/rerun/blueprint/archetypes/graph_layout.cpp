@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout.fbs".
+
+#include "graph_layout.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    GraphLayout GraphLayout::clear_fields() {
+        auto archetype = GraphLayout();
+        archetype.algorithm =
+            ComponentBatch::empty<rerun::blueprint::components::GraphLayoutAlgorithm>(
+                Descriptor_algorithm
+            )
+                .value_or_throw();
+        archetype.direction =
+            ComponentBatch::empty<rerun::blueprint::components::GraphLayoutDirection>(
+                Descriptor_direction
+            )
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> GraphLayout::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (algorithm.has_value()) {
+            columns.push_back(algorithm.value().partitioned(lengths_).value_or_throw());
+        }
+        if (direction.has_value()) {
+            columns.push_back(direction.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<GraphLayout>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> GraphLayout::columns() {
+        if (algorithm.has_value()) {
+            return columns(std::vector<uint32_t>(algorithm.value().length(), 1));
+        }
+        if (direction.has_value()) {
+            return columns(std::vector<uint32_t>(direction.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::GraphLayout>::as_batches(
+        const blueprint::archetypes::GraphLayout& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.algorithm.has_value()) {
+            cells.push_back(archetype.algorithm.value());
+        }
+        if (archetype.direction.has_value()) {
+            cells.push_back(archetype.direction.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<GraphLayout>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
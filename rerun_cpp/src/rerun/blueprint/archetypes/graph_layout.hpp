@@ -0,0 +1,123 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/graph_layout_algorithm.hpp"
+#include "../../blueprint/components/graph_layout_direction.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Selects the algorithm that computes the layout of a graph view.
+    ///
+    /// All algorithms except the force-directed one are deterministic.
+    /// Nodes that are added over time are placed without moving the existing nodes, whenever possible.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct GraphLayout {
+        /// The layout algorithm.
+        ///
+        /// Defaults to a force-directed layout, which is configured by the force properties of the view.
+        std::optional<ComponentBatch> algorithm;
+
+        /// The direction of the edges in layered and tree layouts.
+        std::optional<ComponentBatch> direction;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.GraphLayoutIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.GraphLayout";
+
+        /// `ComponentDescriptor` for the `algorithm` field.
+        static constexpr auto Descriptor_algorithm = ComponentDescriptor(
+            ArchetypeName, "algorithm",
+            Loggable<rerun::blueprint::components::GraphLayoutAlgorithm>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `direction` field.
+        static constexpr auto Descriptor_direction = ComponentDescriptor(
+            ArchetypeName, "direction",
+            Loggable<rerun::blueprint::components::GraphLayoutDirection>::Descriptor.component_name
+        );
+
+      public:
+        GraphLayout() = default;
+        GraphLayout(GraphLayout&& other) = default;
+        GraphLayout(const GraphLayout& other) = default;
+        GraphLayout& operator=(const GraphLayout& other) = default;
+        GraphLayout& operator=(GraphLayout&& other) = default;
+
+        /// Update only some specific fields of a `GraphLayout`.
+        static GraphLayout update_fields() {
+            return GraphLayout();
+        }
+
+        /// Clear all the fields of a `GraphLayout`.
+        static GraphLayout clear_fields();
+
+        /// The layout algorithm.
+        ///
+        /// Defaults to a force-directed layout, which is configured by the force properties of the view.
+        GraphLayout with_algorithm(
+            const rerun::blueprint::components::GraphLayoutAlgorithm& _algorithm
+        ) && {
+            algorithm =
+                ComponentBatch::from_loggable(_algorithm, Descriptor_algorithm).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The direction of the edges in layered and tree layouts.
+        GraphLayout with_direction(
+            const rerun::blueprint::components::GraphLayoutDirection& _direction
+        ) && {
+            direction =
+                ComponentBatch::from_loggable(_direction, Descriptor_direction).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::GraphLayout> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::GraphLayout& archetype
+        );
+    };
+} // namespace rerun
@@ -11,15 +11,44 @@ namespace rerun::blueprint::archetypes {
         archetype.provider =
             ComponentBatch::empty<rerun::blueprint::components::MapProvider>(Descriptor_provider)
                 .value_or_throw();
+        archetype.url_template =
+            ComponentBatch::empty<rerun::blueprint::components::TileUrlTemplate>(
+                Descriptor_url_template
+            )
+                .value_or_throw();
+        archetype.local_path = ComponentBatch::empty<rerun::blueprint::components::TileSourcePath>(
+                                   Descriptor_local_path
+        )
+                                   .value_or_throw();
+        archetype.attribution =
+            ComponentBatch::empty<rerun::blueprint::components::TileAttribution>(
+                Descriptor_attribution
+            )
+                .value_or_throw();
+        archetype.max_zoom =
+            ComponentBatch::empty<rerun::blueprint::components::TileMaxZoom>(Descriptor_max_zoom)
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> MapBackground::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(2);
+        columns.reserve(6);
         if (provider.has_value()) {
             columns.push_back(provider.value().partitioned(lengths_).value_or_throw());
         }
+        if (url_template.has_value()) {
+            columns.push_back(url_template.value().partitioned(lengths_).value_or_throw());
+        }
+        if (local_path.has_value()) {
+            columns.push_back(local_path.value().partitioned(lengths_).value_or_throw());
+        }
+        if (attribution.has_value()) {
+            columns.push_back(attribution.value().partitioned(lengths_).value_or_throw());
+        }
+        if (max_zoom.has_value()) {
+            columns.push_back(max_zoom.value().partitioned(lengths_).value_or_throw());
+        }
         columns.push_back(
             ComponentColumn::from_indicators<MapBackground>(static_cast<uint32_t>(lengths_.size()))
                 .value_or_throw()
@@ -31,6 +60,18 @@ namespace rerun::blueprint::archetypes {
         if (provider.has_value()) {
             return columns(std::vector<uint32_t>(provider.value().length(), 1));
         }
+        if (url_template.has_value()) {
+            return columns(std::vector<uint32_t>(url_template.value().length(), 1));
+        }
+        if (local_path.has_value()) {
+            return columns(std::vector<uint32_t>(local_path.value().length(), 1));
+        }
+        if (attribution.has_value()) {
+            return columns(std::vector<uint32_t>(attribution.value().length(), 1));
+        }
+        if (max_zoom.has_value()) {
+            return columns(std::vector<uint32_t>(max_zoom.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::blueprint::archetypes
@@ -43,11 +84,23 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(2);
+        cells.reserve(6);
 
         if (archetype.provider.has_value()) {
             cells.push_back(archetype.provider.value());
         }
+        if (archetype.url_template.has_value()) {
+            cells.push_back(archetype.url_template.value());
+        }
+        if (archetype.local_path.has_value()) {
+            cells.push_back(archetype.local_path.value());
+        }
+        if (archetype.attribution.has_value()) {
+            cells.push_back(archetype.attribution.value());
+        }
+        if (archetype.max_zoom.has_value()) {
+            cells.push_back(archetype.max_zoom.value());
+        }
         {
             auto result = ComponentBatch::from_indicator<MapBackground>();
             RR_RETURN_NOT_OK(result.error);
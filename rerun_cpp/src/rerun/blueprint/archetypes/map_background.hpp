@@ -4,6 +4,10 @@
 #pragma once
 
 #include "../../blueprint/components/map_provider.hpp"
+#include "../../blueprint/components/tile_attribution.hpp"
+#include "../../blueprint/components/tile_max_zoom.hpp"
+#include "../../blueprint/components/tile_source_path.hpp"
+#include "../../blueprint/components/tile_url_template.hpp"
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
 #include "../../component_column.hpp"
@@ -23,9 +27,25 @@ namespace rerun::blueprint::archetypes {
     struct MapBackground {
         /// Map provider and style to use.
         ///
-        /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        /// **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
         std::optional<ComponentBatch> provider;
 
+        /// URL template of the tile server used by the `CustomUrl` provider.
+        std::optional<ComponentBatch> url_template;
+
+        /// Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+        std::optional<ComponentBatch> local_path;
+
+        /// Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+        ///
+        /// For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+        std::optional<ComponentBatch> attribution;
+
+        /// Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+        ///
+        /// For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
+        std::optional<ComponentBatch> max_zoom;
+
       public:
         static constexpr const char IndicatorComponentName[] =
             "rerun.blueprint.components.MapBackgroundIndicator";
@@ -40,6 +60,26 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "provider",
             Loggable<rerun::blueprint::components::MapProvider>::Descriptor.component_name
         );
+        /// `ComponentDescriptor` for the `url_template` field.
+        static constexpr auto Descriptor_url_template = ComponentDescriptor(
+            ArchetypeName, "url_template",
+            Loggable<rerun::blueprint::components::TileUrlTemplate>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `local_path` field.
+        static constexpr auto Descriptor_local_path = ComponentDescriptor(
+            ArchetypeName, "local_path",
+            Loggable<rerun::blueprint::components::TileSourcePath>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `attribution` field.
+        static constexpr auto Descriptor_attribution = ComponentDescriptor(
+            ArchetypeName, "attribution",
+            Loggable<rerun::blueprint::components::TileAttribution>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `max_zoom` field.
+        static constexpr auto Descriptor_max_zoom = ComponentDescriptor(
+            ArchetypeName, "max_zoom",
+            Loggable<rerun::blueprint::components::TileMaxZoom>::Descriptor.component_name
+        );
 
       public:
         MapBackground() = default;
@@ -62,13 +102,51 @@ namespace rerun::blueprint::archetypes {
 
         /// Map provider and style to use.
         ///
-        /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        /// **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
         MapBackground with_provider(const rerun::blueprint::components::MapProvider& _provider) && {
             provider =
                 ComponentBatch::from_loggable(_provider, Descriptor_provider).value_or_throw();
             return std::move(*this);
         }
 
+        /// URL template of the tile server used by the `CustomUrl` provider.
+        MapBackground with_url_template(
+            const rerun::blueprint::components::TileUrlTemplate& _url_template
+        ) && {
+            url_template = ComponentBatch::from_loggable(_url_template, Descriptor_url_template)
+                           .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+        MapBackground with_local_path(
+            const rerun::blueprint::components::TileSourcePath& _local_path
+        ) && {
+            local_path =
+                ComponentBatch::from_loggable(_local_path, Descriptor_local_path).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+        ///
+        /// For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+        MapBackground with_attribution(
+            const rerun::blueprint::components::TileAttribution& _attribution
+        ) && {
+            attribution = ComponentBatch::from_loggable(_attribution, Descriptor_attribution)
+                          .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+        ///
+        /// For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
+        MapBackground with_max_zoom(const rerun::blueprint::components::TileMaxZoom& _max_zoom) && {
+            max_zoom =
+                ComponentBatch::from_loggable(_max_zoom, Descriptor_max_zoom).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
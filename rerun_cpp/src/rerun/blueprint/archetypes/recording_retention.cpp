@@ -0,0 +1,99 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_retention.fbs".
+
+#include "recording_retention.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    RecordingRetention RecordingRetention::clear_fields() {
+        auto archetype = RecordingRetention();
+        archetype.timeline =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timeline)
+                .value_or_throw();
+        archetype.time_window =
+            ComponentBatch::empty<rerun::blueprint::components::RetentionWindow>(
+                Descriptor_time_window
+            )
+                .value_or_throw();
+        archetype.max_rows_per_entity =
+            ComponentBatch::empty<rerun::blueprint::components::MaxRowsPerEntity>(
+                Descriptor_max_rows_per_entity
+            )
+                .value_or_throw();
+        archetype.protected_entities =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_protected_entities)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> RecordingRetention::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (timeline.has_value()) {
+            columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
+        }
+        if (time_window.has_value()) {
+            columns.push_back(time_window.value().partitioned(lengths_).value_or_throw());
+        }
+        if (max_rows_per_entity.has_value()) {
+            columns.push_back(max_rows_per_entity.value().partitioned(lengths_).value_or_throw());
+        }
+        if (protected_entities.has_value()) {
+            columns.push_back(protected_entities.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(ComponentColumn::from_indicators<RecordingRetention>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> RecordingRetention::columns() {
+        if (timeline.has_value()) {
+            return columns(std::vector<uint32_t>(timeline.value().length(), 1));
+        }
+        if (time_window.has_value()) {
+            return columns(std::vector<uint32_t>(time_window.value().length(), 1));
+        }
+        if (max_rows_per_entity.has_value()) {
+            return columns(std::vector<uint32_t>(max_rows_per_entity.value().length(), 1));
+        }
+        if (protected_entities.has_value()) {
+            return columns(std::vector<uint32_t>(protected_entities.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::RecordingRetention>::as_batches(
+            const blueprint::archetypes::RecordingRetention& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.timeline.has_value()) {
+            cells.push_back(archetype.timeline.value());
+        }
+        if (archetype.time_window.has_value()) {
+            cells.push_back(archetype.time_window.value());
+        }
+        if (archetype.max_rows_per_entity.has_value()) {
+            cells.push_back(archetype.max_rows_per_entity.value());
+        }
+        if (archetype.protected_entities.has_value()) {
+            cells.push_back(archetype.protected_entities.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<RecordingRetention>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
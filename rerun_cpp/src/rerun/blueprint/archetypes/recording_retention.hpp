@@ -0,0 +1,170 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_retention.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/max_rows_per_entity.hpp"
+#include "../../blueprint/components/retention_window.hpp"
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Which data the Viewer keeps around for the recordings of an application.
+    ///
+    /// Data that falls outside of these rules is dropped as new data comes in, on top of whatever
+    /// the memory limit requires. Each rule overrides the matching `RERUN_RETENTION_*` environment
+    /// variable, and is itself overridden by the Viewer's `--retention-*` command line arguments.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct RecordingRetention {
+        /// The timeline that `time_window` applies to.
+        std::optional<ComponentBatch> timeline;
+
+        /// Only keep the data that was logged within this window of the most recent time on the timeline.
+        ///
+        /// Has no effect unless the timeline is set as well.
+        std::optional<ComponentBatch> time_window;
+
+        /// Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+        std::optional<ComponentBatch> max_rows_per_entity;
+
+        /// Never drop the data of these entities, nor of any of their descendants.
+        ///
+        /// This also applies to the garbage collection driven by the memory limit.
+        std::optional<ComponentBatch> protected_entities;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.RecordingRetentionIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.RecordingRetention";
+
+        /// `ComponentDescriptor` for the `timeline` field.
+        static constexpr auto Descriptor_timeline = ComponentDescriptor(
+            ArchetypeName, "timeline",
+            Loggable<rerun::blueprint::components::TimelineName>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `time_window` field.
+        static constexpr auto Descriptor_time_window = ComponentDescriptor(
+            ArchetypeName, "time_window",
+            Loggable<rerun::blueprint::components::RetentionWindow>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `max_rows_per_entity` field.
+        static constexpr auto Descriptor_max_rows_per_entity = ComponentDescriptor(
+            ArchetypeName, "max_rows_per_entity",
+            Loggable<rerun::blueprint::components::MaxRowsPerEntity>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `protected_entities` field.
+        static constexpr auto Descriptor_protected_entities = ComponentDescriptor(
+            ArchetypeName, "protected_entities",
+            Loggable<rerun::components::EntityPath>::Descriptor.component_name
+        );
+
+      public:
+        RecordingRetention() = default;
+        RecordingRetention(RecordingRetention&& other) = default;
+        RecordingRetention(const RecordingRetention& other) = default;
+        RecordingRetention& operator=(const RecordingRetention& other) = default;
+        RecordingRetention& operator=(RecordingRetention&& other) = default;
+
+        /// Update only some specific fields of a `RecordingRetention`.
+        static RecordingRetention update_fields() {
+            return RecordingRetention();
+        }
+
+        /// Clear all the fields of a `RecordingRetention`.
+        static RecordingRetention clear_fields();
+
+        /// The timeline that `time_window` applies to.
+        RecordingRetention with_timeline(const rerun::blueprint::components::TimelineName& _timeline
+        ) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only keep the data that was logged within this window of the most recent time on the timeline.
+        ///
+        /// Has no effect unless the timeline is set as well.
+        RecordingRetention with_time_window(
+            const rerun::blueprint::components::RetentionWindow& _time_window
+        ) && {
+            time_window = ComponentBatch::from_loggable(_time_window, Descriptor_time_window)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+        RecordingRetention with_max_rows_per_entity(
+            const rerun::blueprint::components::MaxRowsPerEntity& _max_rows_per_entity
+        ) && {
+            max_rows_per_entity = ComponentBatch::from_loggable(
+                                      _max_rows_per_entity,
+                                      Descriptor_max_rows_per_entity
+            )
+                                      .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Never drop the data of these entities, nor of any of their descendants.
+        ///
+        /// This also applies to the garbage collection driven by the memory limit.
+        RecordingRetention with_protected_entities(
+            const Collection<rerun::components::EntityPath>& _protected_entities
+        ) && {
+            protected_entities = ComponentBatch::from_loggable(
+                                     _protected_entities,
+                                     Descriptor_protected_entities
+            )
+                                     .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::RecordingRetention> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::RecordingRetention& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,97 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#include "text_log_filter.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TextLogFilter TextLogFilter::clear_fields() {
+        auto archetype = TextLogFilter();
+        archetype.search =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_search).value_or_throw();
+        archetype.search_mode = ComponentBatch::empty<rerun::blueprint::components::TextSearchMode>(
+                                    Descriptor_search_mode
+        )
+                                    .value_or_throw();
+        archetype.case_sensitive =
+            ComponentBatch::empty<rerun::blueprint::components::CaseSensitive>(
+                Descriptor_case_sensitive
+            )
+                .value_or_throw();
+        archetype.hidden_levels =
+            ComponentBatch::empty<rerun::components::TextLogLevel>(Descriptor_hidden_levels)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TextLogFilter::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (search.has_value()) {
+            columns.push_back(search.value().partitioned(lengths_).value_or_throw());
+        }
+        if (search_mode.has_value()) {
+            columns.push_back(search_mode.value().partitioned(lengths_).value_or_throw());
+        }
+        if (case_sensitive.has_value()) {
+            columns.push_back(case_sensitive.value().partitioned(lengths_).value_or_throw());
+        }
+        if (hidden_levels.has_value()) {
+            columns.push_back(hidden_levels.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<TextLogFilter>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> TextLogFilter::columns() {
+        if (search.has_value()) {
+            return columns(std::vector<uint32_t>(search.value().length(), 1));
+        }
+        if (search_mode.has_value()) {
+            return columns(std::vector<uint32_t>(search_mode.value().length(), 1));
+        }
+        if (case_sensitive.has_value()) {
+            return columns(std::vector<uint32_t>(case_sensitive.value().length(), 1));
+        }
+        if (hidden_levels.has_value()) {
+            return columns(std::vector<uint32_t>(hidden_levels.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TextLogFilter>::as_batches(
+            const blueprint::archetypes::TextLogFilter& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.search.has_value()) {
+            cells.push_back(archetype.search.value());
+        }
+        if (archetype.search_mode.has_value()) {
+            cells.push_back(archetype.search_mode.value());
+        }
+        if (archetype.case_sensitive.has_value()) {
+            cells.push_back(archetype.case_sensitive.value());
+        }
+        if (archetype.hidden_levels.has_value()) {
+            cells.push_back(archetype.hidden_levels.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TextLogFilter>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,168 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/case_sensitive.hpp"
+#include "../../blueprint/components/text_search_mode.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/text.hpp"
+#include "../../components/text_log_level.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configures which entries are shown in a text log view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogFilter {
+        /// Only entries whose body matches this query are shown.
+        ///
+        /// If unset or empty, entries are not filtered by their body.
+        std::optional<ComponentBatch> search;
+
+        /// How the search query is matched against the entry bodies.
+        ///
+        /// Defaults to plain text matching.
+        std::optional<ComponentBatch> search_mode;
+
+        /// Whether the search distinguishes between upper and lower case.
+        ///
+        /// False by default.
+        std::optional<ComponentBatch> case_sensitive;
+
+        /// Entries with any of these log levels are hidden.
+        ///
+        /// Entries without a log level are always shown.
+        std::optional<ComponentBatch> hidden_levels;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.TextLogFilterIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TextLogFilter";
+
+        /// `ComponentDescriptor` for the `search` field.
+        static constexpr auto Descriptor_search = ComponentDescriptor(
+            ArchetypeName, "search",
+            Loggable<rerun::components::Text>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `search_mode` field.
+        static constexpr auto Descriptor_search_mode = ComponentDescriptor(
+            ArchetypeName, "search_mode",
+            Loggable<rerun::blueprint::components::TextSearchMode>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `case_sensitive` field.
+        static constexpr auto Descriptor_case_sensitive = ComponentDescriptor(
+            ArchetypeName, "case_sensitive",
+            Loggable<rerun::blueprint::components::CaseSensitive>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `hidden_levels` field.
+        static constexpr auto Descriptor_hidden_levels = ComponentDescriptor(
+            ArchetypeName, "hidden_levels",
+            Loggable<rerun::components::TextLogLevel>::Descriptor.component_name
+        );
+
+      public:
+        TextLogFilter() = default;
+        TextLogFilter(TextLogFilter&& other) = default;
+        TextLogFilter(const TextLogFilter& other) = default;
+        TextLogFilter& operator=(const TextLogFilter& other) = default;
+        TextLogFilter& operator=(TextLogFilter&& other) = default;
+
+        /// Update only some specific fields of a `TextLogFilter`.
+        static TextLogFilter update_fields() {
+            return TextLogFilter();
+        }
+
+        /// Clear all the fields of a `TextLogFilter`.
+        static TextLogFilter clear_fields();
+
+        /// Only entries whose body matches this query are shown.
+        ///
+        /// If unset or empty, entries are not filtered by their body.
+        TextLogFilter with_search(const rerun::components::Text& _search) && {
+            search = ComponentBatch::from_loggable(_search, Descriptor_search).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How the search query is matched against the entry bodies.
+        ///
+        /// Defaults to plain text matching.
+        TextLogFilter with_search_mode(
+            const rerun::blueprint::components::TextSearchMode& _search_mode
+        ) && {
+            search_mode = ComponentBatch::from_loggable(_search_mode, Descriptor_search_mode)
+                          .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the search distinguishes between upper and lower case.
+        ///
+        /// False by default.
+        TextLogFilter with_case_sensitive(
+            const rerun::blueprint::components::CaseSensitive& _case_sensitive
+        ) && {
+            case_sensitive = ComponentBatch::from_loggable(
+                                 _case_sensitive,
+                                 Descriptor_case_sensitive
+            )
+                                 .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Entries with any of these log levels are hidden.
+        ///
+        /// Entries without a log level are always shown.
+        TextLogFilter with_hidden_levels(
+            const Collection<rerun::components::TextLogLevel>& _hidden_levels
+        ) && {
+            hidden_levels = ComponentBatch::from_loggable(_hidden_levels, Descriptor_hidden_levels)
+                            .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TextLogFilter> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TextLogFilter& archetype
+        );
+    };
+} // namespace rerun
@@ -7,6 +7,7 @@
 #include "blueprint/components/auto_layout.hpp"
 #include "blueprint/components/auto_views.hpp"
 #include "blueprint/components/background_kind.hpp"
+#include "blueprint/components/case_sensitive.hpp"
 #include "blueprint/components/column_share.hpp"
 #include "blueprint/components/component_column_selector.hpp"
 #include "blueprint/components/container_kind.hpp"
@@ -17,19 +18,30 @@
 #include "blueprint/components/force_distance.hpp"
 #include "blueprint/components/force_iterations.hpp"
 #include "blueprint/components/force_strength.hpp"
+#include "blueprint/components/graph_layout_algorithm.hpp"
+#include "blueprint/components/graph_layout_direction.hpp"
 #include "blueprint/components/grid_columns.hpp"
 #include "blueprint/components/grid_spacing.hpp"
 #include "blueprint/components/included_content.hpp"
 #include "blueprint/components/lock_range_during_zoom.hpp"
 #include "blueprint/components/map_provider.hpp"
+#include "blueprint/components/max_rows_per_entity.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
 #include "blueprint/components/panel_state.hpp"
 #include "blueprint/components/query_expression.hpp"
+#include "blueprint/components/retention_window.hpp"
 #include "blueprint/components/root_container.hpp"
 #include "blueprint/components/row_share.hpp"
 #include "blueprint/components/selected_columns.hpp"
 #include "blueprint/components/tensor_dimension_index_slider.hpp"
+#include "blueprint/components/text_log_column.hpp"
+#include "blueprint/components/text_search_mode.hpp"
+#include "blueprint/components/tile_attribution.hpp"
+#include "blueprint/components/tile_max_zoom.hpp"
+#include "blueprint/components/tile_source_path.hpp"
+#include "blueprint/components/tile_url_template.hpp"
 #include "blueprint/components/timeline_name.hpp"
+#include "blueprint/components/transform_interpolation.hpp"
 #include "blueprint/components/view_class.hpp"
 #include "blueprint/components/view_fit.hpp"
 #include "blueprint/components/view_maximized.hpp"
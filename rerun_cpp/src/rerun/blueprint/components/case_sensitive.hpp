@@ -0,0 +1,78 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/case_sensitive.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/bool.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Whether a text search distinguishes between upper and lower case.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CaseSensitive {
+        rerun::datatypes::Bool case_sensitive;
+
+      public:
+        CaseSensitive() = default;
+
+        CaseSensitive(rerun::datatypes::Bool case_sensitive_) : case_sensitive(case_sensitive_) {}
+
+        CaseSensitive& operator=(rerun::datatypes::Bool case_sensitive_) {
+            case_sensitive = case_sensitive_;
+            return *this;
+        }
+
+        CaseSensitive(bool value_) : case_sensitive(value_) {}
+
+        CaseSensitive& operator=(bool value_) {
+            case_sensitive = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Bool datatype
+        operator rerun::datatypes::Bool() const {
+            return case_sensitive;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Bool) == sizeof(blueprint::components::CaseSensitive));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::CaseSensitive> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.CaseSensitive";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Bool>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::CaseSensitive` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::CaseSensitive* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(
+                    &instances->case_sensitive,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/graph_layout_algorithm.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: The algorithm that is used to compute the layout of a graph.
+    enum class GraphLayoutAlgorithm : uint8_t {
+
+        /// A force-directed simulation that is configured by the force properties of the view.
+        ForceDirected = 1,
+
+        /// Places the nodes of a directed graph in layers, such that most edges point in the layout direction.
+        ///
+        /// Also known as a Sugiyama-style layout. Works best for directed acyclic graphs.
+        Layered = 2,
+
+        /// Places each node below its parent, starting from the root nodes of the graph.
+        Tree = 3,
+
+        /// Places the root nodes in the center and each level of the tree on a ring around them.
+        Radial = 4,
+
+        /// Places all nodes on a circle.
+        Circular = 5,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::GraphLayoutAlgorithm> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.GraphLayoutAlgorithm";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::GraphLayoutAlgorithm` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::GraphLayoutAlgorithm* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder,
+            const blueprint::components::GraphLayoutAlgorithm* elements, size_t num_elements
+        );
+    };
+} // namespace rerun
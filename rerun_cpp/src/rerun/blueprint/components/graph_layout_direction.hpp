@@ -0,0 +1,65 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/graph_layout_direction.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: The direction in which the edges of a layered or tree graph layout point.
+    enum class GraphLayoutDirection : uint8_t {
+
+        /// Root nodes are at the top, edges point downwards.
+        TopToBottom = 1,
+
+        /// Root nodes are at the bottom, edges point upwards.
+        BottomToTop = 2,
+
+        /// Root nodes are on the left, edges point to the right.
+        LeftToRight = 3,
+
+        /// Root nodes are on the right, edges point to the left.
+        RightToLeft = 4,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::GraphLayoutDirection> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.GraphLayoutDirection";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::GraphLayoutDirection` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::GraphLayoutDirection* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder,
+            const blueprint::components::GraphLayoutDirection* elements, size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -35,6 +35,14 @@ namespace rerun::blueprint::components {
 
         /// Mapbox Satellite is a satellite map designed by Mapbox.
         MapboxSatellite = 4,
+
+        /// A custom raster tile server, configured with a URL template.
+        CustomUrl = 5,
+
+        /// Raster tiles read from an `MBTiles` file or a tile directory on the local file system.
+        ///
+        /// Doesn't require an internet connection. Not available on the web.
+        LocalTiles = 6,
     };
 } // namespace rerun::blueprint::components
 
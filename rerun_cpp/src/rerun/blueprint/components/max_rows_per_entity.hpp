@@ -0,0 +1,80 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/max_rows_per_entity.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The maximum number of rows to keep for each entity.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct MaxRowsPerEntity {
+        rerun::datatypes::UInt64 max_rows;
+
+      public:
+        MaxRowsPerEntity() = default;
+
+        MaxRowsPerEntity(rerun::datatypes::UInt64 max_rows_) : max_rows(max_rows_) {}
+
+        MaxRowsPerEntity& operator=(rerun::datatypes::UInt64 max_rows_) {
+            max_rows = max_rows_;
+            return *this;
+        }
+
+        MaxRowsPerEntity(uint64_t value_) : max_rows(value_) {}
+
+        MaxRowsPerEntity& operator=(uint64_t value_) {
+            max_rows = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return max_rows;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::MaxRowsPerEntity)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::MaxRowsPerEntity> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.MaxRowsPerEntity";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::MaxRowsPerEntity` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::MaxRowsPerEntity* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->max_rows,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,82 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/retention_window.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The size of a retention window, in the native unit of its timeline.
+    ///
+    /// That is nanoseconds for temporal timelines, and raw indices for sequence timelines.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct RetentionWindow {
+        rerun::datatypes::UInt64 window;
+
+      public:
+        RetentionWindow() = default;
+
+        RetentionWindow(rerun::datatypes::UInt64 window_) : window(window_) {}
+
+        RetentionWindow& operator=(rerun::datatypes::UInt64 window_) {
+            window = window_;
+            return *this;
+        }
+
+        RetentionWindow(uint64_t value_) : window(value_) {}
+
+        RetentionWindow& operator=(uint64_t value_) {
+            window = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return window;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::RetentionWindow)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::RetentionWindow> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.RetentionWindow";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::RetentionWindow` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::RetentionWindow* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->window,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,80 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_attribution.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Attribution text shown on top of the map for custom and local tile sources.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TileAttribution {
+        rerun::datatypes::Utf8 text;
+
+      public:
+        TileAttribution() = default;
+
+        TileAttribution(rerun::datatypes::Utf8 text_) : text(std::move(text_)) {}
+
+        TileAttribution& operator=(rerun::datatypes::Utf8 text_) {
+            text = std::move(text_);
+            return *this;
+        }
+
+        TileAttribution(std::string value_) : text(std::move(value_)) {}
+
+        TileAttribution& operator=(std::string value_) {
+            text = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return text;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::TileAttribution));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TileAttribution> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.TileAttribution";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TileAttribution` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TileAttribution* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->text,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
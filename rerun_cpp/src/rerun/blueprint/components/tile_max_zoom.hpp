@@ -0,0 +1,79 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_max_zoom.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Highest zoom level for which a tile source provides tiles.
+    ///
+    /// When zooming in further, the tiles of this zoom level are magnified.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TileMaxZoom {
+        rerun::datatypes::UInt64 zoom;
+
+      public:
+        TileMaxZoom() = default;
+
+        TileMaxZoom(rerun::datatypes::UInt64 zoom_) : zoom(zoom_) {}
+
+        TileMaxZoom& operator=(rerun::datatypes::UInt64 zoom_) {
+            zoom = zoom_;
+            return *this;
+        }
+
+        TileMaxZoom(uint64_t value_) : zoom(value_) {}
+
+        TileMaxZoom& operator=(uint64_t value_) {
+            zoom = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return zoom;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::TileMaxZoom));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TileMaxZoom> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.TileMaxZoom";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TileMaxZoom` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TileMaxZoom* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->zoom,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
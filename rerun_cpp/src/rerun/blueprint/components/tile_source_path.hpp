@@ -0,0 +1,83 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_source_path.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Path to a local tile source on the viewer's file system.
+    ///
+    /// Either an `MBTiles` file (`.mbtiles`) or a directory of raster tiles laid out as `{z}/{x}/{y}.png`
+    /// (`.jpg` and `.jpeg` tiles are supported as well).
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TileSourcePath {
+        rerun::datatypes::Utf8 path;
+
+      public:
+        TileSourcePath() = default;
+
+        TileSourcePath(rerun::datatypes::Utf8 path_) : path(std::move(path_)) {}
+
+        TileSourcePath& operator=(rerun::datatypes::Utf8 path_) {
+            path = std::move(path_);
+            return *this;
+        }
+
+        TileSourcePath(std::string value_) : path(std::move(value_)) {}
+
+        TileSourcePath& operator=(std::string value_) {
+            path = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return path;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::TileSourcePath));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TileSourcePath> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.TileSourcePath";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TileSourcePath` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TileSourcePath* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->path,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
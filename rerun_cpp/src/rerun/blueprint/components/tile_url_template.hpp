@@ -0,0 +1,84 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_url_template.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: URL template of a custom raster tile server.
+    ///
+    /// `{z}`, `{x}` and `{y}` are replaced by the zoom level and the tile coordinates of the XYZ tiling scheme
+    /// (e.g. `https://tiles.example.com/{z}/{x}/{y}.png`).
+    /// Use `{-y}` instead of `{y}` for servers following the TMS tiling scheme, where the rows are counted from the south.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TileUrlTemplate {
+        rerun::datatypes::Utf8 url;
+
+      public:
+        TileUrlTemplate() = default;
+
+        TileUrlTemplate(rerun::datatypes::Utf8 url_) : url(std::move(url_)) {}
+
+        TileUrlTemplate& operator=(rerun::datatypes::Utf8 url_) {
+            url = std::move(url_);
+            return *this;
+        }
+
+        TileUrlTemplate(std::string value_) : url(std::move(value_)) {}
+
+        TileUrlTemplate& operator=(std::string value_) {
+            url = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return url;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::TileUrlTemplate));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TileUrlTemplate> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.TileUrlTemplate";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TileUrlTemplate` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TileUrlTemplate* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->url,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
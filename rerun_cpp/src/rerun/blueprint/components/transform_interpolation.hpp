@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/transform_interpolation.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: How the transforms of an entity are interpolated between logged samples.
+    ///
+    /// Applies to both `Transform3D` and `InstancePoses3D`.
+    enum class TransformInterpolation : uint8_t {
+
+        /// No interpolation, the last logged transform is held until the next one.
+        Off = 1,
+
+        /// Linear interpolation between the two surrounding transforms.
+        ///
+        /// Translations and 3x3 matrices are interpolated element-wise,
+        /// which may shrink rotating objects in between samples.
+        Linear = 2,
+
+        /// Like `Linear`, but rotations are interpolated spherically.
+        ///
+        /// Transforms are decomposed into scale, rotation and translation,
+        /// so transforms with shear are not interpolated correctly.
+        Slerp = 3,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TransformInterpolation> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.components.TransformInterpolation";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::TransformInterpolation` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TransformInterpolation* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder,
+            const blueprint::components::TransformInterpolation* elements, size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column.fbs".
+
+#include "text_log_column.hpp"
+
+#include "../../datatypes/bool.hpp"
+#include "../../datatypes/float32.hpp"
+#include "../../datatypes/utf8.hpp"
+#include "text_log_column_kind.hpp"
+
+#include <arrow/builder.h>
+#include <arrow/type_fwd.h>
+
+namespace rerun::blueprint::datatypes {}
+
+namespace rerun {
+    const std::shared_ptr<arrow::DataType>&
+        Loggable<blueprint::datatypes::TextLogColumn>::arrow_datatype() {
+        static const auto datatype = arrow::struct_({
+            arrow::field(
+                "kind",
+                Loggable<rerun::blueprint::datatypes::TextLogColumnKind>::arrow_datatype(),
+                false
+            ),
+            arrow::field("timeline", Loggable<rerun::datatypes::Utf8>::arrow_datatype(), false),
+            arrow::field("visible", Loggable<rerun::datatypes::Bool>::arrow_datatype(), false),
+            arrow::field("width", Loggable<rerun::datatypes::Float32>::arrow_datatype(), true),
+        });
+        return datatype;
+    }
+
+    Result<std::shared_ptr<arrow::Array>> Loggable<blueprint::datatypes::TextLogColumn>::to_arrow(
+        const blueprint::datatypes::TextLogColumn* instances, size_t num_instances
+    ) {
+        // TODO(andreas): Allow configuring the memory pool.
+        arrow::MemoryPool* pool = arrow::default_memory_pool();
+        auto datatype = arrow_datatype();
+
+        ARROW_ASSIGN_OR_RAISE(auto builder, arrow::MakeBuilder(datatype, pool))
+        if (instances && num_instances > 0) {
+            RR_RETURN_NOT_OK(
+                Loggable<blueprint::datatypes::TextLogColumn>::fill_arrow_array_builder(
+                    static_cast<arrow::StructBuilder*>(builder.get()),
+                    instances,
+                    num_instances
+                )
+            );
+        }
+        std::shared_ptr<arrow::Array> array;
+        ARROW_RETURN_NOT_OK(builder->Finish(&array));
+        return array;
+    }
+
+    rerun::Error Loggable<blueprint::datatypes::TextLogColumn>::fill_arrow_array_builder(
+        arrow::StructBuilder* builder, const blueprint::datatypes::TextLogColumn* elements,
+        size_t num_elements
+    ) {
+        if (builder == nullptr) {
+            return rerun::Error(ErrorCode::UnexpectedNullArgument, "Passed array builder is null.");
+        }
+        if (elements == nullptr) {
+            return rerun::Error(
+                ErrorCode::UnexpectedNullArgument,
+                "Cannot serialize null pointer to arrow array."
+            );
+        }
+
+        {
+            auto field_builder = static_cast<arrow::UInt8Builder*>(builder->field_builder(0));
+            ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
+            for (size_t elem_idx = 0; elem_idx < num_elements; elem_idx += 1) {
+                RR_RETURN_NOT_OK(
+                    Loggable<rerun::blueprint::datatypes::TextLogColumnKind>::
+                        fill_arrow_array_builder(field_builder, &elements[elem_idx].kind, 1)
+                );
+            }
+        }
+        {
+            auto field_builder = static_cast<arrow::StringBuilder*>(builder->field_builder(1));
+            ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
+            for (size_t elem_idx = 0; elem_idx < num_elements; elem_idx += 1) {
+                RR_RETURN_NOT_OK(Loggable<rerun::datatypes::Utf8>::fill_arrow_array_builder(
+                    field_builder,
+                    &elements[elem_idx].timeline,
+                    1
+                ));
+            }
+        }
+        {
+            auto field_builder = static_cast<arrow::BooleanBuilder*>(builder->field_builder(2));
+            ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
+            for (size_t elem_idx = 0; elem_idx < num_elements; elem_idx += 1) {
+                RR_RETURN_NOT_OK(Loggable<rerun::datatypes::Bool>::fill_arrow_array_builder(
+                    field_builder,
+                    &elements[elem_idx].visible,
+                    1
+                ));
+            }
+        }
+        {
+            auto field_builder = static_cast<arrow::FloatBuilder*>(builder->field_builder(3));
+            ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
+            for (size_t elem_idx = 0; elem_idx < num_elements; elem_idx += 1) {
+                const auto& element = elements[elem_idx];
+                if (element.width.has_value()) {
+                    RR_RETURN_NOT_OK(Loggable<rerun::datatypes::Float32>::fill_arrow_array_builder(
+                        field_builder,
+                        &element.width.value(),
+                        1
+                    ));
+                } else {
+                    ARROW_RETURN_NOT_OK(field_builder->AppendNull());
+                }
+            }
+        }
+        ARROW_RETURN_NOT_OK(builder->AppendValues(static_cast<int64_t>(num_elements), nullptr));
+
+        return Error::ok();
+    }
+} // namespace rerun
@@ -0,0 +1,73 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/bool.hpp"
+#include "../../datatypes/float32.hpp"
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+#include "text_log_column_kind.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <optional>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class StructBuilder;
+} // namespace arrow
+
+namespace rerun::blueprint::datatypes {
+    /// **Datatype**: A column of the text log view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogColumn {
+        /// What is shown in this column.
+        rerun::blueprint::datatypes::TextLogColumnKind kind;
+
+        /// The timeline shown by this column.
+        ///
+        /// Only used for columns of kind `Timeline`, empty otherwise.
+        rerun::datatypes::Utf8 timeline;
+
+        /// Whether the column is shown.
+        rerun::datatypes::Bool visible;
+
+        /// The width of the column in ui points.
+        ///
+        /// If unset, the column is sized automatically.
+        std::optional<rerun::datatypes::Float32> width;
+
+      public:
+        TextLogColumn() = default;
+    };
+} // namespace rerun::blueprint::datatypes
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::datatypes::TextLogColumn> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.datatypes.TextLogColumn";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: datatypes::TextLogColumn` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::datatypes::TextLogColumn* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::StructBuilder* builder, const blueprint::datatypes::TextLogColumn* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
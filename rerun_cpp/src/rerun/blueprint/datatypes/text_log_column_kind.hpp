@@ -0,0 +1,65 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column_kind.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::datatypes {
+    /// **Datatype**: The kind of a column in the text log view.
+    enum class TextLogColumnKind : uint8_t {
+
+        /// The time of the entry on a specific timeline.
+        Timeline = 1,
+
+        /// The entity path of the entry.
+        EntityPath = 2,
+
+        /// The log level of the entry.
+        LogLevel = 3,
+
+        /// The text body of the entry.
+        Body = 4,
+    };
+} // namespace rerun::blueprint::datatypes
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::datatypes::TextLogColumnKind> {
+        static constexpr ComponentDescriptor Descriptor =
+            "rerun.blueprint.datatypes.TextLogColumnKind";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: datatypes::TextLogColumnKind` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::datatypes::TextLogColumnKind* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::datatypes::TextLogColumnKind* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -7,10 +7,12 @@
 #include "components/annotation_context.hpp"
 #include "components/axis_length.hpp"
 #include "components/blob.hpp"
+#include "components/child_frame.hpp"
 #include "components/class_id.hpp"
 #include "components/clear_is_recursive.hpp"
 #include "components/color.hpp"
 #include "components/colormap.hpp"
+#include "components/coordinate_frame.hpp"
 #include "components/depth_meter.hpp"
 #include "components/draw_order.hpp"
 #include "components/entity_path.hpp"
@@ -38,6 +40,7 @@
 #include "components/media_type.hpp"
 #include "components/name.hpp"
 #include "components/opacity.hpp"
+#include "components/parent_frame.hpp"
 #include "components/pinhole_projection.hpp"
 #include "components/plane3d.hpp"
 #include "components/pose_rotation_axis_angle.hpp"
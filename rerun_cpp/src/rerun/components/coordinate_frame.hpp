@@ -0,0 +1,89 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/coordinate_frame.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/utf8.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::components {
+    /// **Component**: The name of a coordinate frame, e.g. `base_link` or `camera_optical`.
+    ///
+    /// Frames are connected by transforms that are logged with a parent & child frame,
+    /// independently of the entity path hierarchy.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CoordinateFrame {
+        rerun::datatypes::Utf8 value;
+
+      public: // START of extensions from coordinate_frame_ext.cpp:
+        /// Construct `CoordinateFrame` from a null-terminated UTF8 string.
+        CoordinateFrame(const char* str) : value(str) {}
+
+        const char* c_str() const {
+            return value.c_str();
+        }
+
+        // END of extensions from coordinate_frame_ext.cpp, start of generated code:
+
+      public:
+        CoordinateFrame() = default;
+
+        CoordinateFrame(rerun::datatypes::Utf8 value_) : value(std::move(value_)) {}
+
+        CoordinateFrame& operator=(rerun::datatypes::Utf8 value_) {
+            value = std::move(value_);
+            return *this;
+        }
+
+        CoordinateFrame(std::string value_) : value(std::move(value_)) {}
+
+        CoordinateFrame& operator=(std::string value_) {
+            value = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return value;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(components::CoordinateFrame));
+
+    /// \private
+    template <>
+    struct Loggable<components::CoordinateFrame> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.CoordinateFrame";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::CoordinateFrame` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::CoordinateFrame* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(&instances->value, num_instances);
+            }
+        }
+    };
+} // namespace rerun
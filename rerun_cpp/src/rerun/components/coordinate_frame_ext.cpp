@@ -0,0 +1,29 @@
+#include "coordinate_frame.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct CoordinateFrameExt {
+            std::string value;
+#define CoordinateFrame CoordinateFrameExt
+
+            // Don't provide a string_view constructor, std::string constructor exists and covers this.
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Construct `CoordinateFrame` from a null-terminated UTF8 string.
+            CoordinateFrame(const char* str) : value(str) {}
+
+            const char* c_str() const {
+                return value.c_str();
+            }
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
@@ -0,0 +1,29 @@
+#include "parent_frame.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct ParentFrameExt {
+            std::string value;
+#define ParentFrame ParentFrameExt
+
+            // Don't provide a string_view constructor, std::string constructor exists and covers this.
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Construct `ParentFrame` from a null-terminated UTF8 string.
+            ParentFrame(const char* str) : value(str) {}
+
+            const char* c_str() const {
+                return value.c_str();
+            }
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
@@ -252,6 +252,7 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Transform3D",
             "archetypes.InstancePoses3D",
             "archetypes.ViewCoordinates",
+            "archetypes.CoordinateFrame",
             "components.Scale3D",
             "datatypes.Quaternion",
             "datatypes.RotationAxisAngle",
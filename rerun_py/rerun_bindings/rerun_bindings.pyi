@@ -4,7 +4,7 @@ import os
 from collections.abc import Iterable, Iterator, Sequence
 from datetime import datetime
 from enum import Enum
-from typing import Any, Callable, Optional, Self
+from typing import Any, Callable, Literal, Optional, Self
 
 import pyarrow as pa
 
@@ -385,6 +385,30 @@ class RecordingView:
 
         """
 
+    def index_value_policy(self, policy: Literal["max", "min", "exact"]) -> RecordingView:
+        """
+        Choose which value to return for each index, when the cells of a row come from different times on that index.
+
+        This never affects the filtered index.
+
+        Parameters
+        ----------
+        policy : str
+            One of:
+            - `"max"` (default): the maximum value among the cells of the row.
+            - `"min"`: the minimum value among the cells of the row.
+            - `"exact"`: same as `"max"`, plus one extra `<component column>@<index>` column per
+              selected component and index, holding the exact index value of that specific cell.
+
+        Returns
+        -------
+        RecordingView
+            A new view with the index value policy applied.
+
+            The original view will not be modified.
+
+        """
+
     def select(self, *args: AnyColumn, columns: Optional[Sequence[AnyColumn]] = None) -> pa.RecordBatchReader:
         """
         Select the columns from the view.
@@ -1265,6 +1289,21 @@ class DataframeQueryView:
 
         """
 
+    def index_value_policy(self, policy: Literal["max", "min", "exact"]) -> Self:
+        """
+        Choose which value to return for each index, when the cells of a row come from different times on that index.
+
+        See `RecordingView.index_value_policy` for details.
+
+        Returns
+        -------
+        DataframeQueryView
+            A new view with the index value policy applied.
+
+            The original view will not be modified.
+
+        """
+
     def df(self) -> Any:
         """Register this view to the global DataFusion context and return a DataFrame."""
 
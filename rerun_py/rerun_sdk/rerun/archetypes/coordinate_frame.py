@@ -0,0 +1,183 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/coordinate_frame.fbs".
+
+# You can extend this class by creating a "CoordinateFrameExt" class in "coordinate_frame_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["CoordinateFrame"]
+
+
+@define(str=False, repr=False, init=False)
+class CoordinateFrame(Archetype):
+    """
+    **Archetype**: Attaches an entity to a named coordinate frame.
+
+    Entities in a named frame are placed by their frame rather than by their entity path parent:
+    the spatial views look up the transform from the frame of the view's origin to the frame of the entity
+    by searching through all transforms logged between named frames, see `parent_frame` & `child_frame`
+    on [`archetypes.Transform3D`][rerun.archetypes.Transform3D].
+
+    The frame of an entity also applies to all its descendants that don't specify a frame of their own.
+    Any transform logged at the entity itself is interpreted relative to its frame.
+    If the view's origin isn't in a named frame, or the frames aren't connected, entities fall back to being
+    placed by their entity path.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, frame: datatypes.Utf8Like) -> None:
+        """
+        Create a new instance of the CoordinateFrame archetype.
+
+        Parameters
+        ----------
+        frame:
+            The name of the frame this entity is in.
+
+        """
+
+        # You can define your own __init__ function as a member of CoordinateFrameExt in coordinate_frame_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(frame=frame)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            frame=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CoordinateFrame:
+        """Produce an empty CoordinateFrame, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        frame: datatypes.Utf8Like | None = None,
+    ) -> CoordinateFrame:
+        """
+        Update only some specific fields of a `CoordinateFrame`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        frame:
+            The name of the frame this entity is in.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "frame": frame,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CoordinateFrame:
+        """Clear all the fields of a `CoordinateFrame`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        frame: datatypes.Utf8ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        frame:
+            The name of the frame this entity is in.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                frame=frame,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"frame": frame}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().archetype_field_name]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        indicator_column = cls.indicator().partition(np.zeros(len(sizes)))
+        return ComponentColumnList([indicator_column] + columns)
+
+    frame: components.CoordinateFrameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.CoordinateFrameBatch._converter,  # type: ignore[misc]
+    )
+    # The name of the frame this entity is in.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
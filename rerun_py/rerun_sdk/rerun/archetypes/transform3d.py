@@ -36,6 +36,9 @@ class Transform3D(Transform3DExt, Archetype):
 
     For transforms that affect only a single entity and do not propagate along the entity tree refer to [`archetypes.InstancePoses3D`][rerun.archetypes.InstancePoses3D].
 
+    By setting both `parent_frame` and `child_frame`, the transform instead connects two named coordinate frames,
+    regardless of the entity it is logged at. See [`archetypes.CoordinateFrame`][rerun.archetypes.CoordinateFrame] for how entities are placed in named frames.
+
     Examples
     --------
     ### Variety of 3D transforms:
@@ -297,6 +300,8 @@ class Transform3D(Transform3DExt, Archetype):
             scale=None,
             mat3x3=None,
             relation=None,
+            parent_frame=None,
+            child_frame=None,
             axis_length=None,
         )
 
@@ -318,6 +323,8 @@ class Transform3D(Transform3DExt, Archetype):
         scale: datatypes.Vec3DLike | None = None,
         mat3x3: datatypes.Mat3x3Like | None = None,
         relation: components.TransformRelationLike | None = None,
+        parent_frame: datatypes.Utf8Like | None = None,
+        child_frame: datatypes.Utf8Like | None = None,
         axis_length: datatypes.Float32Like | None = None,
     ) -> Transform3D:
         """
@@ -339,6 +346,16 @@ class Transform3D(Transform3DExt, Archetype):
             3x3 transformation matrix.
         relation:
             Specifies the relation this transform establishes between this entity and its parent.
+        parent_frame:
+            The frame this transform maps into.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
+        child_frame:
+            The frame this transform maps from.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
         axis_length:
             Visual length of the 3 axes.
 
@@ -356,6 +373,8 @@ class Transform3D(Transform3DExt, Archetype):
                 "scale": scale,
                 "mat3x3": mat3x3,
                 "relation": relation,
+                "parent_frame": parent_frame,
+                "child_frame": child_frame,
                 "axis_length": axis_length,
             }
 
@@ -383,6 +402,8 @@ class Transform3D(Transform3DExt, Archetype):
         scale: datatypes.Vec3DArrayLike | None = None,
         mat3x3: datatypes.Mat3x3ArrayLike | None = None,
         relation: components.TransformRelationArrayLike | None = None,
+        parent_frame: datatypes.Utf8ArrayLike | None = None,
+        child_frame: datatypes.Utf8ArrayLike | None = None,
         axis_length: datatypes.Float32ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
@@ -407,6 +428,16 @@ class Transform3D(Transform3DExt, Archetype):
             3x3 transformation matrix.
         relation:
             Specifies the relation this transform establishes between this entity and its parent.
+        parent_frame:
+            The frame this transform maps into.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
+        child_frame:
+            The frame this transform maps from.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
         axis_length:
             Visual length of the 3 axes.
 
@@ -424,6 +455,8 @@ class Transform3D(Transform3DExt, Archetype):
                 scale=scale,
                 mat3x3=mat3x3,
                 relation=relation,
+                parent_frame=parent_frame,
+                child_frame=child_frame,
                 axis_length=axis_length,
             )
 
@@ -438,6 +471,8 @@ class Transform3D(Transform3DExt, Archetype):
             "scale": scale,
             "mat3x3": mat3x3,
             "relation": relation,
+            "parent_frame": parent_frame,
+            "child_frame": child_frame,
             "axis_length": axis_length,
         }
         columns = []
@@ -524,6 +559,30 @@ class Transform3D(Transform3DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    parent_frame: components.ParentFrameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ParentFrameBatch._converter,  # type: ignore[misc]
+    )
+    # The frame this transform maps into.
+    #
+    # If both a parent and a child frame are set, this transform connects these two named frames
+    # instead of applying to this entity and its descendants.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    child_frame: components.ChildFrameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ChildFrameBatch._converter,  # type: ignore[misc]
+    )
+    # The frame this transform maps from.
+    #
+    # If both a parent and a child frame are set, this transform connects these two named frames
+    # instead of applying to this entity and its descendants.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     axis_length: components.AxisLengthBatch | None = field(
         metadata={"component": True},
         default=None,
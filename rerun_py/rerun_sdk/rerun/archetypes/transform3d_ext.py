@@ -10,6 +10,7 @@ from rerun.datatypes import (
     QuaternionLike,
     RotationAxisAngle,
     RotationAxisAngleLike,
+    Utf8Like,
     Vec3DLike,
 )
 
@@ -31,6 +32,8 @@ class Transform3DExt:
         mat3x3: Mat3x3Like | None = None,
         from_parent: bool | None = None,
         relation: TransformRelationLike | None = None,
+        parent_frame: Utf8Like | None = None,
+        child_frame: Utf8Like | None = None,
         axis_length: Float32Like | None = None,
     ) -> None:
         """
@@ -70,6 +73,16 @@ class Transform3DExt:
             Otherwise, the transform maps from the space to its parent.
 
             Mutually exclusive with `from_parent`.
+        parent_frame:
+            The frame this transform maps into.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
+        child_frame:
+            The frame this transform maps from.
+
+            If both a parent and a child frame are set, this transform connects these two named frames
+            instead of applying to this entity and its descendants.
         axis_length:
             Visual length of the 3 axes.
 
@@ -136,6 +149,8 @@ class Transform3DExt:
                     scale=scale if scale is not None else [],
                     mat3x3=mat3x3 if mat3x3 is not None else [],
                     relation=relation if relation is not None else [],
+                    parent_frame=parent_frame if parent_frame is not None else [],
+                    child_frame=child_frame if child_frame is not None else [],
                     axis_length=axis_length if axis_length is not None else [],
                 )
             else:
@@ -146,6 +161,8 @@ class Transform3DExt:
                     scale=scale,
                     mat3x3=mat3x3,
                     relation=relation,
+                    parent_frame=parent_frame,
+                    child_frame=child_frame,
                     axis_length=axis_length,
                 )
             return
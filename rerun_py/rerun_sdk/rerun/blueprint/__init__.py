@@ -32,6 +32,7 @@ from .archetypes import (
     Background as Background,
     EntityBehavior as EntityBehavior,
     PlotLegend as PlotLegend,
+    RecordingRetention as RecordingRetention,
     ScalarAxis as ScalarAxis,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
@@ -11,7 +11,14 @@ from .._spawn import _spawn_viewer
 from ..datatypes import BoolLike, EntityPathLike, Float32ArrayLike, Utf8ArrayLike, Utf8Like
 from ..memory import MemoryRecording
 from ..recording_stream import RecordingStream
-from .archetypes import ContainerBlueprint, PanelBlueprint, ViewBlueprint, ViewContents, ViewportBlueprint
+from .archetypes import (
+    ContainerBlueprint,
+    PanelBlueprint,
+    RecordingRetention,
+    ViewBlueprint,
+    ViewContents,
+    ViewportBlueprint,
+)
 from .components import PanelState, PanelStateLike
 from .components.container_kind import ContainerKindLike
 
@@ -460,6 +467,7 @@ class Blueprint:
         auto_layout: bool | None = None,
         auto_views: bool | None = None,
         collapse_panels: bool = False,
+        retention: RecordingRetention | None = None,
     ) -> None:
         """
         Construct a new blueprint from the given parts.
@@ -495,11 +503,16 @@ class Blueprint:
             Whether to collapse panels in the viewer. Defaults to `False`.
 
             This fully hides the blueprint/selection panels, and shows the simplified time panel.
+        retention:
+            Which data the viewer keeps around for the recordings of this application.
+
+            Rules set via the viewer's `--retention-*` command line arguments take precedence over these.
 
         """
         from .containers import Tabs
 
         self.collapse_panels = collapse_panels
+        self.retention = retention
 
         contents: list[ContainerLike] = []
 
@@ -578,6 +591,9 @@ class Blueprint:
         elif self.collapse_panels:
             TimePanel(state="collapsed")._log_to_stream(stream)
 
+        if self.retention is not None:
+            stream.log("retention", self.retention)
+
     def _ipython_display_(self) -> None:
         from rerun.notebook import Viewer
 
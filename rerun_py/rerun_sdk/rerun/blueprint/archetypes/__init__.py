@@ -11,16 +11,20 @@ from .force_collision_radius import ForceCollisionRadius
 from .force_link import ForceLink
 from .force_many_body import ForceManyBody
 from .force_position import ForcePosition
+from .graph_layout import GraphLayout
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
 from .map_zoom import MapZoom
 from .near_clip_plane import NearClipPlane
 from .panel_blueprint import PanelBlueprint
 from .plot_legend import PlotLegend
+from .recording_retention import RecordingRetention
 from .scalar_axis import ScalarAxis
 from .tensor_scalar_mapping import TensorScalarMapping
 from .tensor_slice_selection import TensorSliceSelection
 from .tensor_view_fit import TensorViewFit
+from .text_log_columns import TextLogColumns
+from .text_log_filter import TextLogFilter
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
 from .viewport_blueprint import ViewportBlueprint
@@ -38,16 +42,20 @@ __all__ = [
     "ForceLink",
     "ForceManyBody",
     "ForcePosition",
+    "GraphLayout",
     "LineGrid3D",
     "MapBackground",
     "MapZoom",
     "NearClipPlane",
     "PanelBlueprint",
     "PlotLegend",
+    "RecordingRetention",
     "ScalarAxis",
     "TensorScalarMapping",
     "TensorSliceSelection",
     "TensorViewFit",
+    "TextLogColumns",
+    "TextLogFilter",
     "ViewBlueprint",
     "ViewContents",
     "ViewportBlueprint",
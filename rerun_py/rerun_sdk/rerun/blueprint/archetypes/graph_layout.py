@@ -0,0 +1,138 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout.fbs".
+
+# You can extend this class by creating a "GraphLayoutExt" class in "graph_layout_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["GraphLayout"]
+
+
+@define(str=False, repr=False, init=False)
+class GraphLayout(Archetype):
+    """
+    **Archetype**: Selects the algorithm that computes the layout of a graph view.
+
+    All algorithms except the force-directed one are deterministic.
+    Nodes that are added over time are placed without moving the existing nodes, whenever possible.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        algorithm: blueprint_components.GraphLayoutAlgorithmLike | None = None,
+        direction: blueprint_components.GraphLayoutDirectionLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the GraphLayout archetype.
+
+        Parameters
+        ----------
+        algorithm:
+            The layout algorithm.
+
+            Defaults to a force-directed layout, which is configured by the force properties of the view.
+        direction:
+            The direction of the edges in layered and tree layouts.
+
+        """
+
+        # You can define your own __init__ function as a member of GraphLayoutExt in graph_layout_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(algorithm=algorithm, direction=direction)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            algorithm=None,
+            direction=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GraphLayout:
+        """Produce an empty GraphLayout, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        algorithm: blueprint_components.GraphLayoutAlgorithmLike | None = None,
+        direction: blueprint_components.GraphLayoutDirectionLike | None = None,
+    ) -> GraphLayout:
+        """
+        Update only some specific fields of a `GraphLayout`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        algorithm:
+            The layout algorithm.
+
+            Defaults to a force-directed layout, which is configured by the force properties of the view.
+        direction:
+            The direction of the edges in layered and tree layouts.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "algorithm": algorithm,
+                "direction": direction,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GraphLayout:
+        """Clear all the fields of a `GraphLayout`."""
+        return cls.from_fields(clear_unset=True)
+
+    algorithm: blueprint_components.GraphLayoutAlgorithmBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.GraphLayoutAlgorithmBatch._converter,  # type: ignore[misc]
+    )
+    # The layout algorithm.
+    #
+    # Defaults to a force-directed layout, which is configured by the force properties of the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    direction: blueprint_components.GraphLayoutDirectionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.GraphLayoutDirectionBatch._converter,  # type: ignore[misc]
+    )
+    # The direction of the edges in layered and tree layouts.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -9,6 +9,7 @@ from typing import Any
 
 from attrs import define, field
 
+from ... import datatypes
 from ..._baseclasses import (
     Archetype,
 )
@@ -26,7 +27,15 @@ class MapBackground(Archetype):
     ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
     """
 
-    def __init__(self: Any, provider: blueprint_components.MapProviderLike) -> None:
+    def __init__(
+        self: Any,
+        provider: blueprint_components.MapProviderLike,
+        *,
+        url_template: datatypes.Utf8Like | None = None,
+        local_path: datatypes.Utf8Like | None = None,
+        attribution: datatypes.Utf8Like | None = None,
+        max_zoom: datatypes.UInt64Like | None = None,
+    ) -> None:
         """
         Create a new instance of the MapBackground archetype.
 
@@ -35,13 +44,31 @@ class MapBackground(Archetype):
         provider:
             Map provider and style to use.
 
-            **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+            **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        url_template:
+            URL template of the tile server used by the `CustomUrl` provider.
+        local_path:
+            Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+        attribution:
+            Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+
+            For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+        max_zoom:
+            Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+
+            For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
 
         """
 
         # You can define your own __init__ function as a member of MapBackgroundExt in map_background_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(provider=provider)
+            self.__attrs_init__(
+                provider=provider,
+                url_template=url_template,
+                local_path=local_path,
+                attribution=attribution,
+                max_zoom=max_zoom,
+            )
             return
         self.__attrs_clear__()
 
@@ -49,6 +76,10 @@ class MapBackground(Archetype):
         """Convenience method for calling `__attrs_init__` with all `None`s."""
         self.__attrs_init__(
             provider=None,
+            url_template=None,
+            local_path=None,
+            attribution=None,
+            max_zoom=None,
         )
 
     @classmethod
@@ -64,6 +95,10 @@ class MapBackground(Archetype):
         *,
         clear_unset: bool = False,
         provider: blueprint_components.MapProviderLike | None = None,
+        url_template: datatypes.Utf8Like | None = None,
+        local_path: datatypes.Utf8Like | None = None,
+        attribution: datatypes.Utf8Like | None = None,
+        max_zoom: datatypes.UInt64Like | None = None,
     ) -> MapBackground:
         """
         Update only some specific fields of a `MapBackground`.
@@ -75,7 +110,19 @@ class MapBackground(Archetype):
         provider:
             Map provider and style to use.
 
-            **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+            **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        url_template:
+            URL template of the tile server used by the `CustomUrl` provider.
+        local_path:
+            Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+        attribution:
+            Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+
+            For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+        max_zoom:
+            Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+
+            For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
 
         """
 
@@ -83,6 +130,10 @@ class MapBackground(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             kwargs = {
                 "provider": provider,
+                "url_template": url_template,
+                "local_path": local_path,
+                "attribution": attribution,
+                "max_zoom": max_zoom,
             }
 
             if clear_unset:
@@ -106,7 +157,47 @@ class MapBackground(Archetype):
     )
     # Map provider and style to use.
     #
-    # **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+    # **Note**: The Mapbox styles require a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    url_template: blueprint_components.TileUrlTemplateBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TileUrlTemplateBatch._converter,  # type: ignore[misc]
+    )
+    # URL template of the tile server used by the `CustomUrl` provider.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    local_path: blueprint_components.TileSourcePathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TileSourcePathBatch._converter,  # type: ignore[misc]
+    )
+    # Path to the `MBTiles` file or tile directory used by the `LocalTiles` provider.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    attribution: blueprint_components.TileAttributionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TileAttributionBatch._converter,  # type: ignore[misc]
+    )
+    # Attribution shown for the `CustomUrl` and `LocalTiles` providers.
+    #
+    # For `MBTiles` files, defaults to the attribution stored in the file's metadata.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    max_zoom: blueprint_components.TileMaxZoomBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TileMaxZoomBatch._converter,  # type: ignore[misc]
+    )
+    # Highest zoom level provided by the `CustomUrl` and `LocalTiles` providers.
+    #
+    # For `MBTiles` files, defaults to the maximum zoom level stored in the file's metadata.
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
@@ -0,0 +1,185 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_retention.fbs".
+
+# You can extend this class by creating a "RecordingRetentionExt" class in "recording_retention_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["RecordingRetention"]
+
+
+@define(str=False, repr=False, init=False)
+class RecordingRetention(Archetype):
+    """
+    **Archetype**: Which data the Viewer keeps around for the recordings of an application.
+
+    Data that falls outside of these rules is dropped as new data comes in, on top of whatever
+    the memory limit requires. Each rule overrides the matching `RERUN_RETENTION_*` environment
+    variable, and is itself overridden by the Viewer's `--retention-*` command line arguments.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        timeline: datatypes.Utf8Like | None = None,
+        time_window: datatypes.UInt64Like | None = None,
+        max_rows_per_entity: datatypes.UInt64Like | None = None,
+        protected_entities: datatypes.EntityPathArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the RecordingRetention archetype.
+
+        Parameters
+        ----------
+        timeline:
+            The timeline that `time_window` applies to.
+        time_window:
+            Only keep the data that was logged within this window of the most recent time on the timeline.
+
+            Has no effect unless the timeline is set as well.
+        max_rows_per_entity:
+            Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+        protected_entities:
+            Never drop the data of these entities, nor of any of their descendants.
+
+            This also applies to the garbage collection driven by the memory limit.
+
+        """
+
+        # You can define your own __init__ function as a member of RecordingRetentionExt in recording_retention_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                timeline=timeline,
+                time_window=time_window,
+                max_rows_per_entity=max_rows_per_entity,
+                protected_entities=protected_entities,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            timeline=None,
+            time_window=None,
+            max_rows_per_entity=None,
+            protected_entities=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> RecordingRetention:
+        """Produce an empty RecordingRetention, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        timeline: datatypes.Utf8Like | None = None,
+        time_window: datatypes.UInt64Like | None = None,
+        max_rows_per_entity: datatypes.UInt64Like | None = None,
+        protected_entities: datatypes.EntityPathArrayLike | None = None,
+    ) -> RecordingRetention:
+        """
+        Update only some specific fields of a `RecordingRetention`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        timeline:
+            The timeline that `time_window` applies to.
+        time_window:
+            Only keep the data that was logged within this window of the most recent time on the timeline.
+
+            Has no effect unless the timeline is set as well.
+        max_rows_per_entity:
+            Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+        protected_entities:
+            Never drop the data of these entities, nor of any of their descendants.
+
+            This also applies to the garbage collection driven by the memory limit.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "timeline": timeline,
+                "time_window": time_window,
+                "max_rows_per_entity": max_rows_per_entity,
+                "protected_entities": protected_entities,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> RecordingRetention:
+        """Clear all the fields of a `RecordingRetention`."""
+        return cls.from_fields(clear_unset=True)
+
+    timeline: blueprint_components.TimelineNameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimelineNameBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline that `time_window` applies to.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    time_window: blueprint_components.RetentionWindowBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.RetentionWindowBatch._converter,  # type: ignore[misc]
+    )
+    # Only keep the data that was logged within this window of the most recent time on the timeline.
+    #
+    # Has no effect unless the timeline is set as well.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    max_rows_per_entity: blueprint_components.MaxRowsPerEntityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.MaxRowsPerEntityBatch._converter,  # type: ignore[misc]
+    )
+    # Only keep (approximately) this many rows for each entity, dropping the oldest ones first.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    protected_entities: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # Never drop the data of these entities, nor of any of their descendants.
+    #
+    # This also applies to the garbage collection driven by the memory limit.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,117 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+# You can extend this class by creating a "TextLogColumnsExt" class in "text_log_columns_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components, datatypes as blueprint_datatypes
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogColumns"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogColumns(Archetype):
+    """
+    **Archetype**: Configures the columns of a text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, text_log_columns: blueprint_datatypes.TextLogColumnArrayLike | None = None) -> None:
+        """
+        Create a new instance of the TextLogColumns archetype.
+
+        Parameters
+        ----------
+        text_log_columns:
+            The columns of the view, in the order they are shown.
+
+            Timelines that are not listed are shown before all other columns, any other column that is not listed is shown last.
+            If unset, all timelines are shown, followed by the entity path, the log level, and the body.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogColumnsExt in text_log_columns_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(text_log_columns=text_log_columns)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            text_log_columns=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogColumns:
+        """Produce an empty TextLogColumns, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        text_log_columns: blueprint_datatypes.TextLogColumnArrayLike | None = None,
+    ) -> TextLogColumns:
+        """
+        Update only some specific fields of a `TextLogColumns`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        text_log_columns:
+            The columns of the view, in the order they are shown.
+
+            Timelines that are not listed are shown before all other columns, any other column that is not listed is shown last.
+            If unset, all timelines are shown, followed by the entity path, the log level, and the body.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "text_log_columns": text_log_columns,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogColumns:
+        """Clear all the fields of a `TextLogColumns`."""
+        return cls.from_fields(clear_unset=True)
+
+    text_log_columns: blueprint_components.TextLogColumnBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TextLogColumnBatch._converter,  # type: ignore[misc]
+    )
+    # The columns of the view, in the order they are shown.
+    #
+    # Timelines that are not listed are shown before all other columns, any other column that is not listed is shown last.
+    # If unset, all timelines are shown, followed by the entity path, the log level, and the body.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,193 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+# You can extend this class by creating a "TextLogFilterExt" class in "text_log_filter_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogFilter"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogFilter(Archetype):
+    """
+    **Archetype**: Configures which entries are shown in a text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        search: datatypes.Utf8Like | None = None,
+        search_mode: blueprint_components.TextSearchModeLike | None = None,
+        case_sensitive: datatypes.BoolLike | None = None,
+        hidden_levels: datatypes.Utf8ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TextLogFilter archetype.
+
+        Parameters
+        ----------
+        search:
+            Only entries whose body matches this query are shown.
+
+            If unset or empty, entries are not filtered by their body.
+        search_mode:
+            How the search query is matched against the entry bodies.
+
+            Defaults to plain text matching.
+        case_sensitive:
+            Whether the search distinguishes between upper and lower case.
+
+            False by default.
+        hidden_levels:
+            Entries with any of these log levels are hidden.
+
+            Entries without a log level are always shown.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogFilterExt in text_log_filter_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                search=search,
+                search_mode=search_mode,
+                case_sensitive=case_sensitive,
+                hidden_levels=hidden_levels,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            search=None,
+            search_mode=None,
+            case_sensitive=None,
+            hidden_levels=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogFilter:
+        """Produce an empty TextLogFilter, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        search: datatypes.Utf8Like | None = None,
+        search_mode: blueprint_components.TextSearchModeLike | None = None,
+        case_sensitive: datatypes.BoolLike | None = None,
+        hidden_levels: datatypes.Utf8ArrayLike | None = None,
+    ) -> TextLogFilter:
+        """
+        Update only some specific fields of a `TextLogFilter`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        search:
+            Only entries whose body matches this query are shown.
+
+            If unset or empty, entries are not filtered by their body.
+        search_mode:
+            How the search query is matched against the entry bodies.
+
+            Defaults to plain text matching.
+        case_sensitive:
+            Whether the search distinguishes between upper and lower case.
+
+            False by default.
+        hidden_levels:
+            Entries with any of these log levels are hidden.
+
+            Entries without a log level are always shown.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "search": search,
+                "search_mode": search_mode,
+                "case_sensitive": case_sensitive,
+                "hidden_levels": hidden_levels,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogFilter:
+        """Clear all the fields of a `TextLogFilter`."""
+        return cls.from_fields(clear_unset=True)
+
+    search: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # Only entries whose body matches this query are shown.
+    #
+    # If unset or empty, entries are not filtered by their body.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    search_mode: blueprint_components.TextSearchModeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TextSearchModeBatch._converter,  # type: ignore[misc]
+    )
+    # How the search query is matched against the entry bodies.
+    #
+    # Defaults to plain text matching.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    case_sensitive: blueprint_components.CaseSensitiveBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.CaseSensitiveBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the search distinguishes between upper and lower case.
+    #
+    # False by default.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    hidden_levels: components.TextLogLevelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogLevelBatch._converter,  # type: ignore[misc]
+    )
+    # Entries with any of these log levels are hidden.
+    #
+    # Entries without a log level are always shown.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -7,6 +7,7 @@ from .apply_latest_at import ApplyLatestAt, ApplyLatestAtBatch
 from .auto_layout import AutoLayout, AutoLayoutBatch
 from .auto_views import AutoViews, AutoViewsBatch
 from .background_kind import BackgroundKind, BackgroundKindArrayLike, BackgroundKindBatch, BackgroundKindLike
+from .case_sensitive import CaseSensitive, CaseSensitiveBatch
 from .column_share import ColumnShare, ColumnShareBatch
 from .component_column_selector import ComponentColumnSelector, ComponentColumnSelectorBatch
 from .container_kind import ContainerKind, ContainerKindArrayLike, ContainerKindBatch, ContainerKindLike
@@ -17,19 +18,45 @@ from .filter_is_not_null import FilterIsNotNull, FilterIsNotNullBatch
 from .force_distance import ForceDistance, ForceDistanceBatch
 from .force_iterations import ForceIterations, ForceIterationsBatch
 from .force_strength import ForceStrength, ForceStrengthBatch
+from .graph_layout_algorithm import (
+    GraphLayoutAlgorithm,
+    GraphLayoutAlgorithmArrayLike,
+    GraphLayoutAlgorithmBatch,
+    GraphLayoutAlgorithmLike,
+)
+from .graph_layout_direction import (
+    GraphLayoutDirection,
+    GraphLayoutDirectionArrayLike,
+    GraphLayoutDirectionBatch,
+    GraphLayoutDirectionLike,
+)
 from .grid_columns import GridColumns, GridColumnsBatch
 from .grid_spacing import GridSpacing, GridSpacingBatch
 from .included_content import IncludedContent, IncludedContentBatch
 from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatch
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
+from .max_rows_per_entity import MaxRowsPerEntity, MaxRowsPerEntityBatch
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
 from .query_expression import QueryExpression, QueryExpressionBatch
+from .retention_window import RetentionWindow, RetentionWindowBatch
 from .root_container import RootContainer, RootContainerBatch
 from .row_share import RowShare, RowShareBatch
 from .selected_columns import SelectedColumns, SelectedColumnsBatch
 from .tensor_dimension_index_slider import TensorDimensionIndexSlider, TensorDimensionIndexSliderBatch
+from .text_log_column import TextLogColumn, TextLogColumnBatch
+from .text_search_mode import TextSearchMode, TextSearchModeArrayLike, TextSearchModeBatch, TextSearchModeLike
+from .tile_attribution import TileAttribution, TileAttributionBatch
+from .tile_max_zoom import TileMaxZoom, TileMaxZoomBatch
+from .tile_source_path import TileSourcePath, TileSourcePathBatch
+from .tile_url_template import TileUrlTemplate, TileUrlTemplateBatch
 from .timeline_name import TimelineName, TimelineNameBatch
+from .transform_interpolation import (
+    TransformInterpolation,
+    TransformInterpolationArrayLike,
+    TransformInterpolationBatch,
+    TransformInterpolationLike,
+)
 from .view_class import ViewClass, ViewClassBatch
 from .view_fit import ViewFit, ViewFitArrayLike, ViewFitBatch, ViewFitLike
 from .view_maximized import ViewMaximized, ViewMaximizedBatch
@@ -53,6 +80,8 @@ __all__ = [
     "BackgroundKindArrayLike",
     "BackgroundKindBatch",
     "BackgroundKindLike",
+    "CaseSensitive",
+    "CaseSensitiveBatch",
     "ColumnShare",
     "ColumnShareBatch",
     "ComponentColumnSelector",
@@ -77,6 +106,14 @@ __all__ = [
     "ForceIterationsBatch",
     "ForceStrength",
     "ForceStrengthBatch",
+    "GraphLayoutAlgorithm",
+    "GraphLayoutAlgorithmArrayLike",
+    "GraphLayoutAlgorithmBatch",
+    "GraphLayoutAlgorithmLike",
+    "GraphLayoutDirection",
+    "GraphLayoutDirectionArrayLike",
+    "GraphLayoutDirectionBatch",
+    "GraphLayoutDirectionLike",
     "GridColumns",
     "GridColumnsBatch",
     "GridSpacing",
@@ -89,6 +126,8 @@ __all__ = [
     "MapProviderArrayLike",
     "MapProviderBatch",
     "MapProviderLike",
+    "MaxRowsPerEntity",
+    "MaxRowsPerEntityBatch",
     "NearClipPlane",
     "NearClipPlaneBatch",
     "PanelState",
@@ -97,6 +136,8 @@ __all__ = [
     "PanelStateLike",
     "QueryExpression",
     "QueryExpressionBatch",
+    "RetentionWindow",
+    "RetentionWindowBatch",
     "RootContainer",
     "RootContainerBatch",
     "RowShare",
@@ -105,8 +146,26 @@ __all__ = [
     "SelectedColumnsBatch",
     "TensorDimensionIndexSlider",
     "TensorDimensionIndexSliderBatch",
+    "TextLogColumn",
+    "TextLogColumnBatch",
+    "TextSearchMode",
+    "TextSearchModeArrayLike",
+    "TextSearchModeBatch",
+    "TextSearchModeLike",
+    "TileAttribution",
+    "TileAttributionBatch",
+    "TileMaxZoom",
+    "TileMaxZoomBatch",
+    "TileSourcePath",
+    "TileSourcePathBatch",
+    "TileUrlTemplate",
+    "TileUrlTemplateBatch",
     "TimelineName",
     "TimelineNameBatch",
+    "TransformInterpolation",
+    "TransformInterpolationArrayLike",
+    "TransformInterpolationBatch",
+    "TransformInterpolationLike",
     "ViewClass",
     "ViewClassBatch",
     "ViewFit",
@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/case_sensitive.fbs".
+
+# You can extend this class by creating a "CaseSensitiveExt" class in "case_sensitive_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["CaseSensitive", "CaseSensitiveBatch"]
+
+
+class CaseSensitive(datatypes.Bool, ComponentMixin):
+    """
+    **Component**: Whether a text search distinguishes between upper and lower case.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of CaseSensitiveExt in case_sensitive_ext.py
+
+    # Note: there are no fields here because CaseSensitive delegates to datatypes.Bool
+
+
+class CaseSensitiveBatch(datatypes.BoolBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.CaseSensitive")
+
+
+# This is patched in late to avoid circular dependencies.
+CaseSensitive._BATCH_TYPE = CaseSensitiveBatch  # type: ignore[assignment]
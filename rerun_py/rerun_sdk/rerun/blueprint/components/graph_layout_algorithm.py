@@ -0,0 +1,103 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/graph_layout_algorithm.fbs".
+
+# You can extend this class by creating a "GraphLayoutAlgorithmExt" class in "graph_layout_algorithm_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = [
+    "GraphLayoutAlgorithm",
+    "GraphLayoutAlgorithmArrayLike",
+    "GraphLayoutAlgorithmBatch",
+    "GraphLayoutAlgorithmLike",
+]
+
+
+from enum import Enum
+
+
+class GraphLayoutAlgorithm(Enum):
+    """**Component**: The algorithm that is used to compute the layout of a graph."""
+
+    ForceDirected = 1
+    """A force-directed simulation that is configured by the force properties of the view."""
+
+    Layered = 2
+    """
+    Places the nodes of a directed graph in layers, such that most edges point in the layout direction.
+
+    Also known as a Sugiyama-style layout. Works best for directed acyclic graphs.
+    """
+
+    Tree = 3
+    """Places each node below its parent, starting from the root nodes of the graph."""
+
+    Radial = 4
+    """Places the root nodes in the center and each level of the tree on a ring around them."""
+
+    Circular = 5
+    """Places all nodes on a circle."""
+
+    @classmethod
+    def auto(cls, val: str | int | GraphLayoutAlgorithm) -> GraphLayoutAlgorithm:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, GraphLayoutAlgorithm):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+GraphLayoutAlgorithmLike = Union[
+    GraphLayoutAlgorithm,
+    Literal[
+        "Circular",
+        "ForceDirected",
+        "Layered",
+        "Radial",
+        "Tree",
+        "circular",
+        "forcedirected",
+        "layered",
+        "radial",
+        "tree",
+    ],
+    int,
+]
+GraphLayoutAlgorithmArrayLike = Union[GraphLayoutAlgorithmLike, Sequence[GraphLayoutAlgorithmLike]]
+
+
+class GraphLayoutAlgorithmBatch(BaseBatch[GraphLayoutAlgorithmArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.GraphLayoutAlgorithm")
+
+    @staticmethod
+    def _native_to_pa_array(data: GraphLayoutAlgorithmArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (GraphLayoutAlgorithm, int, str)):
+            data = [data]
+
+        pa_data = [GraphLayoutAlgorithm.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
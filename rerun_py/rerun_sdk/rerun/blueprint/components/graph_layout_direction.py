@@ -0,0 +1,94 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/graph_layout_direction.fbs".
+
+# You can extend this class by creating a "GraphLayoutDirectionExt" class in "graph_layout_direction_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = [
+    "GraphLayoutDirection",
+    "GraphLayoutDirectionArrayLike",
+    "GraphLayoutDirectionBatch",
+    "GraphLayoutDirectionLike",
+]
+
+
+from enum import Enum
+
+
+class GraphLayoutDirection(Enum):
+    """**Component**: The direction in which the edges of a layered or tree graph layout point."""
+
+    TopToBottom = 1
+    """Root nodes are at the top, edges point downwards."""
+
+    BottomToTop = 2
+    """Root nodes are at the bottom, edges point upwards."""
+
+    LeftToRight = 3
+    """Root nodes are on the left, edges point to the right."""
+
+    RightToLeft = 4
+    """Root nodes are on the right, edges point to the left."""
+
+    @classmethod
+    def auto(cls, val: str | int | GraphLayoutDirection) -> GraphLayoutDirection:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, GraphLayoutDirection):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+GraphLayoutDirectionLike = Union[
+    GraphLayoutDirection,
+    Literal[
+        "BottomToTop",
+        "LeftToRight",
+        "RightToLeft",
+        "TopToBottom",
+        "bottomtotop",
+        "lefttoright",
+        "righttoleft",
+        "toptobottom",
+    ],
+    int,
+]
+GraphLayoutDirectionArrayLike = Union[GraphLayoutDirectionLike, Sequence[GraphLayoutDirectionLike]]
+
+
+class GraphLayoutDirectionBatch(BaseBatch[GraphLayoutDirectionArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.GraphLayoutDirection")
+
+    @staticmethod
+    def _native_to_pa_array(data: GraphLayoutDirectionArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (GraphLayoutDirection, int, str)):
+            data = [data]
+
+        pa_data = [GraphLayoutDirection.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -37,6 +37,16 @@ class MapProvider(Enum):
     MapboxSatellite = 4
     """Mapbox Satellite is a satellite map designed by Mapbox."""
 
+    CustomUrl = 5
+    """A custom raster tile server, configured with a URL template."""
+
+    LocalTiles = 6
+    """
+    Raster tiles read from an `MBTiles` file or a tile directory on the local file system.
+
+    Doesn't require an internet connection. Not available on the web.
+    """
+
     @classmethod
     def auto(cls, val: str | int | MapProvider) -> MapProvider:
         """Best-effort converter, including a case-insensitive string matcher."""
@@ -61,10 +71,14 @@ class MapProvider(Enum):
 MapProviderLike = Union[
     MapProvider,
     Literal[
+        "CustomUrl",
+        "LocalTiles",
         "MapboxDark",
         "MapboxSatellite",
         "MapboxStreets",
         "OpenStreetMap",
+        "customurl",
+        "localtiles",
         "mapboxdark",
         "mapboxsatellite",
         "mapboxstreets",
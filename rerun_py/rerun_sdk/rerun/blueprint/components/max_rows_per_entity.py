@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/max_rows_per_entity.fbs".
+
+# You can extend this class by creating a "MaxRowsPerEntityExt" class in "max_rows_per_entity_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["MaxRowsPerEntity", "MaxRowsPerEntityBatch"]
+
+
+class MaxRowsPerEntity(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: The maximum number of rows to keep for each entity.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of MaxRowsPerEntityExt in max_rows_per_entity_ext.py
+
+    # Note: there are no fields here because MaxRowsPerEntity delegates to datatypes.UInt64
+
+
+class MaxRowsPerEntityBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.MaxRowsPerEntity")
+
+
+# This is patched in late to avoid circular dependencies.
+MaxRowsPerEntity._BATCH_TYPE = MaxRowsPerEntityBatch  # type: ignore[assignment]
@@ -0,0 +1,38 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/retention_window.fbs".
+
+# You can extend this class by creating a "RetentionWindowExt" class in "retention_window_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["RetentionWindow", "RetentionWindowBatch"]
+
+
+class RetentionWindow(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: The size of a retention window, in the native unit of its timeline.
+
+    That is nanoseconds for temporal timelines, and raw indices for sequence timelines.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of RetentionWindowExt in retention_window_ext.py
+
+    # Note: there are no fields here because RetentionWindow delegates to datatypes.UInt64
+
+
+class RetentionWindowBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.RetentionWindow")
+
+
+# This is patched in late to avoid circular dependencies.
+RetentionWindow._BATCH_TYPE = RetentionWindowBatch  # type: ignore[assignment]
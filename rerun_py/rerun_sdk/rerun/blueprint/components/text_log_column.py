@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_log_column.fbs".
+
+# You can extend this class by creating a "TextLogColumnExt" class in "text_log_column_ext.py".
+
+from __future__ import annotations
+
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+from ...blueprint import datatypes as blueprint_datatypes
+
+__all__ = ["TextLogColumn", "TextLogColumnBatch"]
+
+
+class TextLogColumn(blueprint_datatypes.TextLogColumn, ComponentMixin):
+    """
+    **Component**: A column of the text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TextLogColumnExt in text_log_column_ext.py
+
+    # Note: there are no fields here because TextLogColumn delegates to datatypes.TextLogColumn
+
+
+class TextLogColumnBatch(blueprint_datatypes.TextLogColumnBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TextLogColumn")
+
+
+# This is patched in late to avoid circular dependencies.
+TextLogColumn._BATCH_TYPE = TextLogColumnBatch  # type: ignore[assignment]
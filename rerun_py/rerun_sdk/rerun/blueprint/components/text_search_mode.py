@@ -0,0 +1,70 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_search_mode.fbs".
+
+# You can extend this class by creating a "TextSearchModeExt" class in "text_search_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = ["TextSearchMode", "TextSearchModeArrayLike", "TextSearchModeBatch", "TextSearchModeLike"]
+
+
+from enum import Enum
+
+
+class TextSearchMode(Enum):
+    """**Component**: How a text search query is matched against text."""
+
+    Plain = 1
+    """The query is matched literally."""
+
+    Regex = 2
+    """The query is a regular expression."""
+
+    @classmethod
+    def auto(cls, val: str | int | TextSearchMode) -> TextSearchMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TextSearchMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TextSearchModeLike = Union[TextSearchMode, Literal["Plain", "Regex", "plain", "regex"], int]
+TextSearchModeArrayLike = Union[TextSearchModeLike, Sequence[TextSearchModeLike]]
+
+
+class TextSearchModeBatch(BaseBatch[TextSearchModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TextSearchMode")
+
+    @staticmethod
+    def _native_to_pa_array(data: TextSearchModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TextSearchMode, int, str)):
+            data = [data]
+
+        pa_data = [TextSearchMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_attribution.fbs".
+
+# You can extend this class by creating a "TileAttributionExt" class in "tile_attribution_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TileAttribution", "TileAttributionBatch"]
+
+
+class TileAttribution(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: Attribution text shown on top of the map for custom and local tile sources.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TileAttributionExt in tile_attribution_ext.py
+
+    # Note: there are no fields here because TileAttribution delegates to datatypes.Utf8
+
+
+class TileAttributionBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TileAttribution")
+
+
+# This is patched in late to avoid circular dependencies.
+TileAttribution._BATCH_TYPE = TileAttributionBatch  # type: ignore[assignment]
@@ -0,0 +1,38 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_max_zoom.fbs".
+
+# You can extend this class by creating a "TileMaxZoomExt" class in "tile_max_zoom_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TileMaxZoom", "TileMaxZoomBatch"]
+
+
+class TileMaxZoom(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: Highest zoom level for which a tile source provides tiles.
+
+    When zooming in further, the tiles of this zoom level are magnified.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TileMaxZoomExt in tile_max_zoom_ext.py
+
+    # Note: there are no fields here because TileMaxZoom delegates to datatypes.UInt64
+
+
+class TileMaxZoomBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TileMaxZoom")
+
+
+# This is patched in late to avoid circular dependencies.
+TileMaxZoom._BATCH_TYPE = TileMaxZoomBatch  # type: ignore[assignment]
@@ -0,0 +1,39 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_source_path.fbs".
+
+# You can extend this class by creating a "TileSourcePathExt" class in "tile_source_path_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TileSourcePath", "TileSourcePathBatch"]
+
+
+class TileSourcePath(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: Path to a local tile source on the viewer's file system.
+
+    Either an `MBTiles` file (`.mbtiles`) or a directory of raster tiles laid out as `{z}/{x}/{y}.png`
+    (`.jpg` and `.jpeg` tiles are supported as well).
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TileSourcePathExt in tile_source_path_ext.py
+
+    # Note: there are no fields here because TileSourcePath delegates to datatypes.Utf8
+
+
+class TileSourcePathBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TileSourcePath")
+
+
+# This is patched in late to avoid circular dependencies.
+TileSourcePath._BATCH_TYPE = TileSourcePathBatch  # type: ignore[assignment]
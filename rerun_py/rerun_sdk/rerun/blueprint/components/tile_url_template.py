@@ -0,0 +1,40 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_url_template.fbs".
+
+# You can extend this class by creating a "TileUrlTemplateExt" class in "tile_url_template_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TileUrlTemplate", "TileUrlTemplateBatch"]
+
+
+class TileUrlTemplate(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: URL template of a custom raster tile server.
+
+    `{z}`, `{x}` and `{y}` are replaced by the zoom level and the tile coordinates of the XYZ tiling scheme
+    (e.g. `https://tiles.example.com/{z}/{x}/{y}.png`).
+    Use `{-y}` instead of `{y}` for servers following the TMS tiling scheme, where the rows are counted from the south.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TileUrlTemplateExt in tile_url_template_ext.py
+
+    # Note: there are no fields here because TileUrlTemplate delegates to datatypes.Utf8
+
+
+class TileUrlTemplateBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TileUrlTemplate")
+
+
+# This is patched in late to avoid circular dependencies.
+TileUrlTemplate._BATCH_TYPE = TileUrlTemplateBatch  # type: ignore[assignment]
@@ -0,0 +1,96 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/transform_interpolation.fbs".
+
+# You can extend this class by creating a "TransformInterpolationExt" class in "transform_interpolation_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = [
+    "TransformInterpolation",
+    "TransformInterpolationArrayLike",
+    "TransformInterpolationBatch",
+    "TransformInterpolationLike",
+]
+
+
+from enum import Enum
+
+
+class TransformInterpolation(Enum):
+    """
+    **Component**: How the transforms of an entity are interpolated between logged samples.
+
+    Applies to both `Transform3D` and `InstancePoses3D`.
+    """
+
+    Off = 1
+    """No interpolation, the last logged transform is held until the next one."""
+
+    Linear = 2
+    """
+    Linear interpolation between the two surrounding transforms.
+
+    Translations and 3x3 matrices are interpolated element-wise,
+    which may shrink rotating objects in between samples.
+    """
+
+    Slerp = 3
+    """
+    Like `Linear`, but rotations are interpolated spherically.
+
+    Transforms are decomposed into scale, rotation and translation,
+    so transforms with shear are not interpolated correctly.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | TransformInterpolation) -> TransformInterpolation:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TransformInterpolation):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TransformInterpolationLike = Union[
+    TransformInterpolation, Literal["Linear", "Off", "Slerp", "linear", "off", "slerp"], int
+]
+TransformInterpolationArrayLike = Union[TransformInterpolationLike, Sequence[TransformInterpolationLike]]
+
+
+class TransformInterpolationBatch(BaseBatch[TransformInterpolationArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor(
+        "rerun.blueprint.components.TransformInterpolation"
+    )
+
+    @staticmethod
+    def _native_to_pa_array(data: TransformInterpolationArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TransformInterpolation, int, str)):
+            data = [data]
+
+        pa_data = [TransformInterpolation.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,131 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column.fbs".
+
+# You can extend this class by creating a "TextLogColumnExt" class in "text_log_column_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Any, Union
+
+import pyarrow as pa
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    BaseBatch,
+)
+from ...blueprint import datatypes as blueprint_datatypes
+from .text_log_column_ext import TextLogColumnExt
+
+__all__ = ["TextLogColumn", "TextLogColumnArrayLike", "TextLogColumnBatch", "TextLogColumnLike"]
+
+
+def _text_log_column__timeline__special_field_converter_override(x: datatypes.Utf8Like) -> datatypes.Utf8:
+    if isinstance(x, datatypes.Utf8):
+        return x
+    else:
+        return datatypes.Utf8(x)
+
+
+def _text_log_column__visible__special_field_converter_override(x: datatypes.BoolLike) -> datatypes.Bool:
+    if isinstance(x, datatypes.Bool):
+        return x
+    else:
+        return datatypes.Bool(x)
+
+
+def _text_log_column__width__special_field_converter_override(
+    x: datatypes.Float32Like | None,
+) -> datatypes.Float32 | None:
+    if x is None:
+        return None
+    elif isinstance(x, datatypes.Float32):
+        return x
+    else:
+        return datatypes.Float32(x)
+
+
+@define(init=False)
+class TextLogColumn(TextLogColumnExt):
+    """
+    **Datatype**: A column of the text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        kind: blueprint_datatypes.TextLogColumnKindLike,
+        timeline: datatypes.Utf8Like,
+        visible: datatypes.BoolLike,
+        width: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TextLogColumn datatype.
+
+        Parameters
+        ----------
+        kind:
+            What is shown in this column.
+        timeline:
+            The timeline shown by this column.
+
+            Only used for columns of kind `Timeline`, empty otherwise.
+        visible:
+            Whether the column is shown.
+        width:
+            The width of the column in ui points.
+
+            If unset, the column is sized automatically.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogColumnExt in text_log_column_ext.py
+        self.__attrs_init__(kind=kind, timeline=timeline, visible=visible, width=width)
+
+    kind: blueprint_datatypes.TextLogColumnKind = field()
+    # What is shown in this column.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    timeline: datatypes.Utf8 = field(converter=_text_log_column__timeline__special_field_converter_override)
+    # The timeline shown by this column.
+    #
+    # Only used for columns of kind `Timeline`, empty otherwise.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    visible: datatypes.Bool = field(converter=_text_log_column__visible__special_field_converter_override)
+    # Whether the column is shown.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    width: datatypes.Float32 | None = field(
+        default=None, converter=_text_log_column__width__special_field_converter_override
+    )
+    # The width of the column in ui points.
+    #
+    # If unset, the column is sized automatically.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+
+TextLogColumnLike = TextLogColumn
+TextLogColumnArrayLike = Union[
+    TextLogColumn,
+    Sequence[TextLogColumnLike],
+]
+
+
+class TextLogColumnBatch(BaseBatch[TextLogColumnArrayLike]):
+    _ARROW_DATATYPE = pa.struct([
+        pa.field("kind", pa.uint8(), nullable=False, metadata={}),
+        pa.field("timeline", pa.utf8(), nullable=False, metadata={}),
+        pa.field("visible", pa.bool_(), nullable=False, metadata={}),
+        pa.field("width", pa.float32(), nullable=True, metadata={}),
+    ])
+
+    @staticmethod
+    def _native_to_pa_array(data: TextLogColumnArrayLike, data_type: pa.DataType) -> pa.Array:
+        return TextLogColumnExt.native_to_pa_array_override(data, data_type)
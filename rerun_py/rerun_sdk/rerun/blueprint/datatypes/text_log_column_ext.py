@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+
+if TYPE_CHECKING:
+    from .text_log_column import TextLogColumnArrayLike
+
+
+class TextLogColumnExt:
+    """Extension for [TextLogColumn][rerun.blueprint.datatypes.TextLogColumn]."""
+
+    # Override needed so that unset widths are serialized as nulls rather than NaNs.
+    @staticmethod
+    def native_to_pa_array_override(input_data: TextLogColumnArrayLike, data_type: pa.DataType) -> pa.Array:
+        from ...datatypes import BoolBatch, Utf8Batch
+        from .text_log_column import TextLogColumn
+        from .text_log_column_kind import TextLogColumnKindBatch
+
+        if isinstance(input_data, TextLogColumn):
+            data: Sequence[TextLogColumn] = [input_data]
+        else:
+            data = input_data
+
+        return pa.StructArray.from_arrays(
+            [
+                TextLogColumnKindBatch([x.kind for x in data]).as_arrow_array(),
+                Utf8Batch([x.timeline for x in data]).as_arrow_array(),
+                BoolBatch([x.visible for x in data]).as_arrow_array(),
+                pa.array([None if x.width is None else float(x.width) for x in data], type=pa.float32()),
+            ],
+            fields=list(data_type),
+        )
@@ -0,0 +1,86 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/datatypes/text_log_column_kind.fbs".
+
+# You can extend this class by creating a "TextLogColumnKindExt" class in "text_log_column_kind_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+)
+
+__all__ = ["TextLogColumnKind", "TextLogColumnKindArrayLike", "TextLogColumnKindBatch", "TextLogColumnKindLike"]
+
+
+from enum import Enum
+
+
+class TextLogColumnKind(Enum):
+    """**Datatype**: The kind of a column in the text log view."""
+
+    Timeline = 1
+    """The time of the entry on a specific timeline."""
+
+    EntityPath = 2
+    """The entity path of the entry."""
+
+    LogLevel = 3
+    """The log level of the entry."""
+
+    Body = 4
+    """The text body of the entry."""
+
+    @classmethod
+    def auto(cls, val: str | int | TextLogColumnKind) -> TextLogColumnKind:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TextLogColumnKind):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TextLogColumnKindLike = Union[
+    TextLogColumnKind,
+    Literal[
+        "Body",
+        "EntityPath",
+        "LogLevel",
+        "Timeline",
+        "body",
+        "entitypath",
+        "loglevel",
+        "timeline",
+    ],
+    int,
+]
+TextLogColumnKindArrayLike = Union[TextLogColumnKindLike, Sequence[TextLogColumnKindLike]]
+
+
+class TextLogColumnKindBatch(BaseBatch[TextLogColumnKindArrayLike]):
+    _ARROW_DATATYPE = pa.uint8()
+
+    @staticmethod
+    def _native_to_pa_array(data: TextLogColumnKindArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TextLogColumnKind, int, str)):
+            data = [data]
+
+        pa_data = [TextLogColumnKind.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
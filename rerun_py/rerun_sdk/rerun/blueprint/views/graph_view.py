@@ -82,6 +82,7 @@ class GraphView(View):
         ]
         | None = None,
         visual_bounds: blueprint_archetypes.VisualBounds2D | None = None,
+        layout: blueprint_archetypes.GraphLayout | None = None,
         force_link: blueprint_archetypes.ForceLink | None = None,
         force_many_body: blueprint_archetypes.ForceManyBody | None = None,
         force_position: blueprint_archetypes.ForcePosition | None = None,
@@ -127,6 +128,8 @@ class GraphView(View):
             Everything within these bounds is guaranteed to be visible.
 
             Somethings outside of these bounds may also be visible due to letterboxing.
+        layout:
+            The algorithm that computes the position of the nodes.
         force_link:
             Allows to control the interaction between two nodes connected by an edge.
         force_many_body:
@@ -146,6 +149,11 @@ class GraphView(View):
                 visual_bounds = blueprint_archetypes.VisualBounds2D(visual_bounds)
             properties["VisualBounds2D"] = visual_bounds
 
+        if layout is not None:
+            if not isinstance(layout, blueprint_archetypes.GraphLayout):
+                layout = blueprint_archetypes.GraphLayout(layout)
+            properties["GraphLayout"] = layout
+
         if force_link is not None:
             if not isinstance(force_link, blueprint_archetypes.ForceLink):
                 force_link = blueprint_archetypes.ForceLink(force_link)
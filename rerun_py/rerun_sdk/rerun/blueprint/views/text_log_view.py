@@ -15,6 +15,7 @@ __all__ = ["TextLogView"]
 from ... import datatypes
 from ..._baseclasses import AsComponents
 from ...datatypes import EntityPathLike, Utf8Like
+from .. import archetypes as blueprint_archetypes
 from ..api import View, ViewContentsLike
 
 
@@ -71,6 +72,8 @@ class TextLogView(View):
             AsComponents | Iterable[DescribedComponentBatch | AsComponents | Iterable[DescribedComponentBatch]],
         ]
         | None = None,
+        filter: blueprint_archetypes.TextLogFilter | None = None,
+        columns: blueprint_archetypes.TextLogColumns | None = None,
     ) -> None:
         """
         Construct a blueprint for a new TextLogView view.
@@ -107,9 +110,24 @@ class TextLogView(View):
             do not yet support `$origin` relative paths or glob expressions.
             This will be addressed in <https://github.com/rerun-io/rerun/issues/6673>.
 
+        filter:
+            Configures which entries are shown.
+        columns:
+            Configures the order, visibility and width of the columns.
+
         """
 
         properties: dict[str, AsComponents] = {}
+        if filter is not None:
+            if not isinstance(filter, blueprint_archetypes.TextLogFilter):
+                filter = blueprint_archetypes.TextLogFilter(filter)
+            properties["TextLogFilter"] = filter
+
+        if columns is not None:
+            if not isinstance(columns, blueprint_archetypes.TextLogColumns):
+                columns = blueprint_archetypes.TextLogColumns(columns)
+            properties["TextLogColumns"] = columns
+
         super().__init__(
             class_identifier="TextLog",
             origin=origin,
@@ -17,10 +17,12 @@ from .annotation_context import (
 )
 from .axis_length import AxisLength, AxisLengthBatch
 from .blob import Blob, BlobBatch
+from .child_frame import ChildFrame, ChildFrameBatch
 from .class_id import ClassId, ClassIdBatch
 from .clear_is_recursive import ClearIsRecursive, ClearIsRecursiveBatch
 from .color import Color, ColorBatch
 from .colormap import Colormap, ColormapArrayLike, ColormapBatch, ColormapLike
+from .coordinate_frame import CoordinateFrame, CoordinateFrameBatch
 from .depth_meter import DepthMeter, DepthMeterBatch
 from .draw_order import DrawOrder, DrawOrderBatch
 from .entity_path import EntityPath, EntityPathBatch
@@ -53,6 +55,7 @@ from .marker_size import MarkerSize, MarkerSizeBatch
 from .media_type import MediaType, MediaTypeBatch
 from .name import Name, NameBatch
 from .opacity import Opacity, OpacityBatch
+from .parent_frame import ParentFrame, ParentFrameBatch
 from .pinhole_projection import PinholeProjection, PinholeProjectionBatch
 from .plane3d import Plane3D, Plane3DBatch
 from .pose_rotation_axis_angle import PoseRotationAxisAngle, PoseRotationAxisAngleBatch
@@ -111,6 +114,8 @@ __all__ = [
     "AxisLengthBatch",
     "Blob",
     "BlobBatch",
+    "ChildFrame",
+    "ChildFrameBatch",
     "ClassId",
     "ClassIdBatch",
     "ClearIsRecursive",
@@ -121,6 +126,8 @@ __all__ = [
     "ColormapArrayLike",
     "ColormapBatch",
     "ColormapLike",
+    "CoordinateFrame",
+    "CoordinateFrameBatch",
     "DepthMeter",
     "DepthMeterBatch",
     "DrawOrder",
@@ -189,6 +196,8 @@ __all__ = [
     "NameBatch",
     "Opacity",
     "OpacityBatch",
+    "ParentFrame",
+    "ParentFrameBatch",
     "PinholeProjection",
     "PinholeProjectionBatch",
     "Plane3D",
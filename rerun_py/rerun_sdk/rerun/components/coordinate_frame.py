@@ -0,0 +1,39 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/coordinate_frame.fbs".
+
+# You can extend this class by creating a "CoordinateFrameExt" class in "coordinate_frame_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["CoordinateFrame", "CoordinateFrameBatch"]
+
+
+class CoordinateFrame(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: The name of a coordinate frame, e.g. `base_link` or `camera_optical`.
+
+    Frames are connected by transforms that are logged with a parent & child frame,
+    independently of the entity path hierarchy.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of CoordinateFrameExt in coordinate_frame_ext.py
+
+    # Note: there are no fields here because CoordinateFrame delegates to datatypes.Utf8
+
+
+class CoordinateFrameBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.CoordinateFrame")
+
+
+# This is patched in late to avoid circular dependencies.
+CoordinateFrame._BATCH_TYPE = CoordinateFrameBatch  # type: ignore[assignment]
@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/parent_frame.fbs".
+
+# You can extend this class by creating a "ParentFrameExt" class in "parent_frame_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["ParentFrame", "ParentFrameBatch"]
+
+
+class ParentFrame(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: The name of the coordinate frame a transform maps into.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of ParentFrameExt in parent_frame_ext.py
+
+    # Note: there are no fields here because ParentFrame delegates to datatypes.Utf8
+
+
+class ParentFrameBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.ParentFrame")
+
+
+# This is patched in late to avoid circular dependencies.
+ParentFrame._BATCH_TYPE = ParentFrameBatch  # type: ignore[assignment]
@@ -10,7 +10,9 @@ use pyo3::types::{PyCapsule, PyDict, PyTuple};
 use pyo3::{pyclass, pymethods, Bound, Py, PyAny, PyRef, PyResult, Python};
 
 use re_chunk::ComponentName;
-use re_chunk_store::{ChunkStoreHandle, QueryExpression, SparseFillStrategy, ViewContentsSelector};
+use re_chunk_store::{
+    ChunkStoreHandle, IndexValuePolicy, QueryExpression, SparseFillStrategy, ViewContentsSelector,
+};
 use re_dataframe::{QueryCache, QueryEngine};
 use re_datafusion::DataframeQueryTableProvider;
 use re_log_types::{EntityPath, EntityPathFilter, ResolvedTimeRange};
@@ -18,7 +20,7 @@ use re_sdk::ComponentDescriptor;
 use re_sorbet::ColumnDescriptor;
 
 use crate::catalog::{to_py_err, PyDataset};
-use crate::dataframe::ComponentLike;
+use crate::dataframe::{parse_index_value_policy, ComponentLike};
 use crate::utils::get_tokio_runtime;
 
 /// View into a remote dataset acting as DataFusion table provider.
@@ -74,6 +76,8 @@ impl PyDataframeQueryView {
                 using_index_values: None,
                 filtered_is_not_null: None,
                 sparse_fill_strategy: SparseFillStrategy::None,
+                index_value_policy: IndexValuePolicy::Max,
+                bucketing: None,
                 selection: None,
             },
             partition_ids: vec![],
@@ -395,6 +399,24 @@ impl PyDataframeQueryView {
         })
     }
 
+    #[allow(rustdoc::private_doc_tests)]
+    /// Choose which value to return for each index, when the cells of a row come from different times on that index.
+    ///
+    /// See `RecordingView.index_value_policy` for details.
+    ///
+    /// Returns
+    /// -------
+    /// DataframeQueryView
+    ///     A new view with the index value policy applied.
+    ///
+    ///     The original view will not be modified.
+    fn index_value_policy(&self, py: Python<'_>, policy: &str) -> PyResult<Self> {
+        let policy = parse_index_value_policy(policy)?;
+        Ok(self.clone_with_new_query(py, |query_expression| {
+            query_expression.index_value_policy = policy;
+        }))
+    }
+
     /// Returns a DataFusion table provider capsule.
     fn __datafusion_table_provider__<'py>(
         self_: PyRef<'py, Self>,
@@ -26,7 +26,8 @@ use pyo3::{
 use re_arrow_util::ArrowArrayDowncastRef as _;
 use re_chunk_store::{
     ChunkStore, ChunkStoreConfig, ChunkStoreHandle, ColumnDescriptor, ComponentColumnDescriptor,
-    IndexColumnDescriptor, QueryExpression, SparseFillStrategy, ViewContentsSelector,
+    IndexColumnDescriptor, IndexValuePolicy, QueryExpression, SparseFillStrategy,
+    ViewContentsSelector,
 };
 use re_dataframe::{QueryEngine, StorageEngine};
 use re_log_types::{EntityPathFilter, ResolvedTimeRange};
@@ -67,6 +68,18 @@ fn py_rerun_warn(msg: &std::ffi::CStr) -> PyResult<()> {
     })
 }
 
+/// Parses the `policy` argument of the `index_value_policy` methods.
+pub(crate) fn parse_index_value_policy(policy: &str) -> PyResult<IndexValuePolicy> {
+    match policy {
+        "max" => Ok(IndexValuePolicy::Max),
+        "min" => Ok(IndexValuePolicy::Min),
+        "exact" => Ok(IndexValuePolicy::ExactPerComponent),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid index value policy {policy:?}, expected one of 'max', 'min' or 'exact'"
+        ))),
+    }
+}
+
 /// The descriptor of an index column.
 ///
 /// Index columns contain the index values for when the data was updated. They
@@ -1178,6 +1191,36 @@ impl PyRecordingView {
             query_expression,
         }
     }
+
+    #[allow(rustdoc::private_doc_tests)]
+    /// Choose which value to return for each index, when the cells of a row come from different times on that index.
+    ///
+    /// This never affects the filtered index.
+    ///
+    /// Parameters
+    /// ----------
+    /// policy : str
+    ///     One of:
+    ///     - `"max"` (default): the maximum value among the cells of the row.
+    ///     - `"min"`: the minimum value among the cells of the row.
+    ///     - `"exact"`: same as `"max"`, plus one extra `<component column>@<index>` column per
+    ///       selected component and index, holding the exact index value of that specific cell.
+    ///
+    /// Returns
+    /// -------
+    /// RecordingView
+    ///     A new view with the index value policy applied.
+    ///
+    ///     The original view will not be modified.
+    fn index_value_policy(&self, policy: &str) -> PyResult<Self> {
+        let mut query_expression = self.query_expression.clone();
+        query_expression.index_value_policy = parse_index_value_policy(policy)?;
+
+        Ok(Self {
+            recording: self.recording.clone(),
+            query_expression,
+        })
+    }
 }
 
 impl PyRecording {
@@ -1187,7 +1230,10 @@ impl PyRecording {
         #[allow(unsafe_code)]
         let engine = unsafe { StorageEngine::new(self.store.clone(), self.cache.clone()) };
 
-        QueryEngine { engine }
+        QueryEngine {
+            engine,
+            cache_policy: Default::default(),
+        }
     }
 
     fn find_best_component(&self, entity_path: &EntityPath, component_name: &str) -> ComponentName {
@@ -1388,6 +1434,8 @@ impl PyRecording {
             using_index_values: None,
             filtered_is_not_null: None,
             sparse_fill_strategy: SparseFillStrategy::None,
+            index_value_policy: IndexValuePolicy::Max,
+            bucketing: None,
             selection: None,
         };
 
@@ -24,8 +24,8 @@ use re_arrow_util::ArrowArrayDowncastRef as _;
 use re_chunk::{Chunk, TimelineName};
 use re_chunk_store::ChunkStore;
 use re_dataframe::{
-    ChunkStoreHandle, ComponentColumnSelector, QueryExpression, SparseFillStrategy,
-    TimeColumnSelector, ViewContentsSelector,
+    ChunkStoreHandle, ComponentColumnSelector, IndexValuePolicy, QueryExpression,
+    SparseFillStrategy, TimeColumnSelector, ViewContentsSelector,
 };
 use re_grpc_client::TonicStatusError;
 use re_log_encoding::codec::wire::{decoder::Decode as _, encoder::Encode as _};
@@ -1262,6 +1262,8 @@ impl PyRemoteRecording {
             using_index_values: None,
             filtered_is_not_null: None,
             sparse_fill_strategy: SparseFillStrategy::None,
+            index_value_policy: IndexValuePolicy::Max,
+            bucketing: None,
             selection: None,
         };
 